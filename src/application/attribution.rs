@@ -0,0 +1,57 @@
+//! 외부 요청 귀속 정보(`User-Agent`, 실행 ID).
+//!
+//! 실행 단위 값이라 포트마다 인자로 넘기지 않고 tokio task-local에 둔다. 리뷰 실행은 `scope` 안에서
+//! 돌고, 인프라의 HTTP 어댑터는 요청을 만들 때 `current()`를 읽어 헤더를 붙인다.
+
+use std::cell::RefCell;
+use std::future::Future;
+
+use crate::application::config::{Config, DEFAULT_USER_AGENT};
+
+/// 외부 요청에 붙일 귀속 정보.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequestAttribution {
+    pub user_agent: String,
+    /// 리뷰 실행 중이면 그 실행의 `run_id`(`X-Repopilot-Run-Id`)
+    pub run_id: Option<String>,
+}
+
+impl Default for RequestAttribution {
+    fn default() -> Self {
+        Self {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            run_id: None,
+        }
+    }
+}
+
+impl RequestAttribution {
+    /// 리뷰 실행 밖(업데이트 확인 등)의 요청용. 설정의 `User-Agent`만 쓴다.
+    pub fn for_config(config: &Config) -> Self {
+        Self {
+            user_agent: config.user_agent(),
+            run_id: None,
+        }
+    }
+}
+
+tokio::task_local! {
+    static ATTRIBUTION: RefCell<RequestAttribution>;
+}
+
+/// `future` 안에서 보내는 외부 요청에 `attribution`을 붙인다.
+pub async fn scope<F: Future>(attribution: RequestAttribution, future: F) -> F::Output {
+    ATTRIBUTION.scope(RefCell::new(attribution), future).await
+}
+
+/// 현재 범위의 `User-Agent`를 바꾼다(설정을 읽은 뒤 호출). 범위 밖이면 아무것도 하지 않는다.
+pub fn set_user_agent(user_agent: String) {
+    let _ = ATTRIBUTION.try_with(|attribution| attribution.borrow_mut().user_agent = user_agent);
+}
+
+/// 현재 범위의 귀속 정보. 범위 밖이면 기본 `User-Agent`만 쓴다.
+pub fn current() -> RequestAttribution {
+    ATTRIBUTION
+        .try_with(|attribution| attribution.borrow().clone())
+        .unwrap_or_default()
+}
@@ -2,18 +2,99 @@
 //!
 //! 주의: 파일/환경변수/프로세스 접근은 `infrastructure`에서만 수행한다.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
-use crate::domain::review::CommentLanguage;
+use crate::domain::comment_size::{CommentOverflow, MIN_COMMENT_CHARS};
+use crate::domain::policy::canonical_provider_id;
+use crate::domain::review::{
+    CommentLanguage, DedupePolicy, ReviewGranularity, Severity, SeverityLevel, SeverityTaxonomy,
+    SummaryCollapse, TokenPricing,
+};
+use crate::domain::target::ReviewTarget;
 
 pub const DEFAULT_MAX_DIFF_BYTES: usize = 120_000;
+pub const DEFAULT_REACTION_ROUNDS: usize = 1;
+pub const DEFAULT_CHUNK_CONCURRENCY: usize = 2;
+pub const DEFAULT_CLAIM_TTL_MINUTES: u64 = 60;
+pub const DEFAULT_COLLAPSE_FINDINGS_OVER: usize = 10;
+pub const DEFAULT_COLLAPSE_REACTION_LINES: usize = 10;
+pub const DEFAULT_LOW_CONFIDENCE_BELOW: f32 = 0.5;
+pub const DEFAULT_REMOTE_CONFIG_TTL_MINUTES: u64 = 60;
+pub const DEFAULT_RESPONSE_CACHE_TTL_HOURS: u64 = 24;
+pub const DEFAULT_UPDATE_CHECK_INTERVAL_HOURS: u64 = 24;
+/// 모든 외부 HTTP 요청의 기본 `User-Agent`.
+pub const DEFAULT_USER_AGENT: &str = concat!("repopilot/", env!("CARGO_PKG_VERSION"));
+pub const DEFAULT_PROVIDER_TIMEOUT_SECS: u64 = 600;
+/// `discover_review_guide` 사용 시 대상 저장소에서 찾는 기본 가이드 경로(발견된 파일 모두 합침).
+pub const DEFAULT_REMOTE_REVIEW_GUIDES: &[&str] = &[
+    ".repopilot/review-guide.md",
+    ".github/review-guide.md",
+    "CONTRIBUTING.md",
+    ".github/CONTRIBUTING.md",
+    "docs/CONTRIBUTING.md",
+];
+/// PR 메타 리뷰에서 설명 템플릿으로 찾는 기본 경로(처음 발견된 파일 하나만 씀).
+pub const DEFAULT_DESCRIPTION_TEMPLATES: &[&str] = &[
+    ".github/pull_request_template.md",
+    ".github/PULL_REQUEST_TEMPLATE.md",
+    "docs/pull_request_template.md",
+    "PULL_REQUEST_TEMPLATE.md",
+    ".gitlab/merge_request_templates/Default.md",
+];
+/// 테스트 누락 분석에서 테스트 파일로 보는 기본 경로 glob.
+pub const DEFAULT_TEST_FILE_GLOBS: &[&str] = &[
+    "**/tests/**",
+    "**/test/**",
+    "**/__tests__/**",
+    "**/spec/**",
+    "*_test.*",
+    "*.test.*",
+    "*_spec.*",
+    "*.spec.*",
+    "test_*.py",
+    "*Test.java",
+    "*Tests.java",
+    "*Test.kt",
+    "*Tests.cs",
+];
+/// `pr_types` 미지정 시 쓰는 PR 유형 규칙(이름, 라벨, 제목 정규식, 프롬프트 추가 지침). 위에서부터 먼저 일치한 규칙을 쓴다.
+pub const DEFAULT_PR_TYPES: &[(&str, &[&str], &str, &str)] = &[
+    (
+        "docs",
+        &["docs", "documentation"],
+        r"(?i)^docs?(\([^)]*\))?!?:",
+        "This PR changes documentation only. Focus on accuracy, clarity, broken links and examples that no longer match the code. Skip performance and security analysis unless the PR also changes code.",
+    ),
+    (
+        "bugfix",
+        &["bug", "bugfix", "fix"],
+        r"(?i)^(fix|bugfix|hotfix)(\([^)]*\))?!?:",
+        "This PR fixes a bug. Check that the change addresses the root cause rather than the symptom, that a regression test covers the fixed case, and that unrelated behavior is unchanged.",
+    ),
+    (
+        "refactor",
+        &["refactor", "refactoring", "tech-debt"],
+        r"(?i)^refactor(\([^)]*\))?!?:",
+        "This PR is a refactor and should not change behavior. Flag any behavior, public API or performance change, and do not ask for new features.",
+    ),
+    (
+        "feature",
+        &["feature", "enhancement"],
+        r"(?i)^feat(ure)?(\([^)]*\))?!?:",
+        "This PR adds a feature. Check that the new behavior is complete, handles edge cases and errors, is covered by tests, and is documented where users will find it.",
+    ),
+];
 pub const DEFAULT_SYSTEM_PROMPT: &str =
-    "You are a strict senior code reviewer. Output Markdown with sections: Critical, Major, Minor, Suggestions.";
+    "You are a strict senior code reviewer. Output Markdown with one section per severity level.";
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
+    /// 설정 스키마 버전(없으면 0으로 보고 로딩 시 현재 버전으로 마이그레이션)
+    pub config_version: Option<u64>,
     /// 전역 기본값
     #[serde(default)]
     pub defaults: DefaultsConfig,
@@ -23,9 +104,61 @@ pub struct Config {
     /// provider 실행 설정
     #[serde(default)]
     pub providers: ProvidersConfig,
+    /// 저장소별 재정의(키: `github.com/acme/backend` 형식의 저장소 경로)
+    #[serde(default)]
+    pub repos: HashMap<String, RepoConfig>,
+    /// `--profile`/`/profile`로 고르는 실행 설정 묶음
+    #[serde(default)]
+    pub profiles: HashMap<String, ProfileConfig>,
+    /// 리뷰 완료/실패 알림 채널(Slack/Teams/Discord webhook, 이메일)
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
+}
+
+/// 실행 시 선택하는 이름 있는 설정 묶음(예: quick/thorough).
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileConfig {
+    /// 전역/저장소별 defaults 위에 덮어쓸 값
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+    /// 사용할 provider id 목록(`--providers`를 지정하지 않았을 때 적용)
+    pub providers: Option<Vec<String>>,
+    /// provider id → 모델(`--model`이 우선)
+    pub models: Option<HashMap<String, String>>,
+    /// true면 `--dry-run` 없이도 dry-run으로 실행
+    pub dry_run: Option<bool>,
+}
+
+impl ProfileConfig {
+    pub fn merge_from(&mut self, other: ProfileConfig) {
+        self.defaults.merge_from(other.defaults);
+        if other.providers.is_some() {
+            self.providers = other.providers;
+        }
+        if let Some(models) = other.models {
+            self.models.get_or_insert_with(HashMap::new).extend(models);
+        }
+        if other.dry_run.is_some() {
+            self.dry_run = other.dry_run;
+        }
+    }
+}
+
+/// 특정 저장소 리뷰에만 적용하는 설정 재정의.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct RepoConfig {
+    /// 전역 defaults 위에 덮어쓸 값
+    #[serde(default)]
+    pub defaults: DefaultsConfig,
+    /// 전역 notifications 위에 덮어쓸 값(예: 저장소별 이메일 수신자)
+    #[serde(default)]
+    pub notifications: NotificationsConfig,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct DefaultsConfig {
     /// diff 최대 바이트
     pub max_diff_bytes: Option<usize>,
@@ -41,9 +174,144 @@ pub struct DefaultsConfig {
     pub update_download_url: Option<String>,
     /// 업데이트 확인 타임아웃(ms)
     pub update_timeout_ms: Option<u64>,
+    /// 시작 시 업데이트 확인 여부(`on` | `off`, 기본 on)
+    pub update_check: Option<String>,
+    /// 업데이트 확인 간격(시간, 기본 24, 0이면 매번 조회)
+    pub update_check_interval_hours: Option<u64>,
+    /// 최신 릴리스를 조회할 GitHub/GitLab 저장소 URL(미지정 시 RepoPilot 공식 저장소)
+    pub update_repository: Option<String>,
+    /// 릴리스 서명 검증용 Ed25519 공개 키(base64). 설정 시 self-update는 `.sig`를 요구한다.
+    pub update_public_key: Option<String>,
+    /// 교차 에이전트 반응 라운드 수(0이면 비활성화)
+    pub reaction_rounds: Option<usize>,
+    /// 최종 통합 리뷰를 작성할 provider id(openai/anthropic/gemini, 선택)
+    pub moderator_provider: Option<String>,
+    /// PR 제목/설명/커밋 메시지를 점검하는 메타 리뷰 provider id(선택, 미지정 시 단계 생략)
+    pub meta_review_provider: Option<String>,
+    /// 메타 리뷰에 쓸 설명 템플릿 탐색 경로 목록(미지정 시 기본 후보 사용)
+    pub description_templates: Option<Vec<String>>,
+    /// 보안 전용 프롬프트로 diff를 한 번 더 점검할지 여부(기본 false)
+    pub security_pass: Option<bool>,
+    /// 보안 점검을 맡을 provider id(미지정 시 활성화된 첫 provider)
+    pub security_provider: Option<String>,
+    /// 테스트 변경 없이 바뀐 함수를 찾아 "Missing tests" 체크리스트를 만들지 여부(기본 false)
+    pub test_gap_analysis: Option<bool>,
+    /// 테스트 누락 분석을 맡을 provider id(미지정 시 활성화된 첫 provider)
+    pub test_gap_provider: Option<String>,
+    /// 테스트 파일로 볼 경로 glob 목록(미지정 시 기본 후보 사용)
+    pub test_file_globs: Option<Vec<String>>,
+    /// 수정안이 있는 합의 지적사항을 해당 줄의 인라인 제안 코멘트로 게시할지 여부(기본 true)
+    pub inline_suggestions: Option<bool>,
+    /// `--autofix`에서 수정 패치를 만들 provider id(미지정 시 활성화된 첫 provider)
+    pub autofix_provider: Option<String>,
+    /// provider 코멘트/요약 섹션을 나열할 provider id 순서(빠진 provider는 설정 순서로 뒤에 붙음)
+    pub provider_order: Option<Vec<String>>,
+    /// CODEOWNERS와 변경 파일의 최근 커밋 작성자로 사람 리뷰어를 추천할지 여부(기본 false)
+    pub suggest_reviewers: Option<bool>,
+    /// 분할 리뷰 시 provider별 동시 실행 청크 수
+    pub chunk_concurrency: Option<usize>,
+    /// 여러 repopilot 프로세스(병렬 CI 작업)를 합친 provider별 동시 요청 한도(0이면 제한 없음)
+    pub global_concurrency: Option<usize>,
+    /// `global_concurrency` 슬롯 저장소: 디렉터리 경로 또는 `redis://` URL(미지정 시 상태 디렉터리)
+    pub global_concurrency_store: Option<String>,
+    /// 저장소 관리자가 아니어도 `/repopilot` 코멘트 명령을 따를 사용자 이름 목록
+    pub command_allowlist: Option<Vec<String>>,
+    /// 리뷰 단위(pr/file)
+    pub review_granularity: Option<String>,
+    /// 이 시간(분)보다 오래된 claim 코멘트는 중단된 실행으로 보고 회수(0이면 비활성화)
+    pub claim_ttl_minutes: Option<u64>,
+    /// 최종 요약의 합의 지적사항이 이 개수보다 많으면 `<details>`로 접음(0이면 접지 않음)
+    pub collapse_findings_over: Option<usize>,
+    /// 최종 요약의 교차 반응 본문이 이 줄 수보다 길면 `<details>`로 접음(0이면 접지 않음)
+    pub collapse_reaction_lines: Option<usize>,
+    /// 확신도가 이 값보다 낮은 지적사항은 버림(0.0~1.0, 기본 0.0)
+    pub min_finding_confidence: Option<f32>,
+    /// 확신도가 이 값보다 낮은 합의 지적사항은 "Needs Human Verification" 섹션에 따로 표시(0.0~1.0)
+    pub low_confidence_below: Option<f32>,
+    /// 기존 봇 코멘트 처리 방식(per_sha/single/always_new)
+    pub dedupe_policy: Option<String>,
+    /// 코멘트 본문 최대 글자 수(미지정 시 호스트 한도, 0이면 제한 없음)
+    pub max_comment_chars: Option<usize>,
+    /// 본문이 한도를 넘을 때 처리 방식(split/truncate)
+    pub comment_overflow: Option<String>,
+    /// 리뷰에 포함할 diff 파일 경로 glob 목록(비어 있으면 전체)
+    pub diff_include: Option<Vec<String>>,
+    /// 리뷰에서 제외할 diff 파일 경로 glob 목록(lockfile/생성 코드 등)
+    pub diff_exclude: Option<Vec<String>>,
+    /// 대상 저장소 기본 브랜치에서 리뷰 가이드를 찾아 시스템 프롬프트에 합칠지 여부
+    pub discover_review_guide: Option<bool>,
+    /// 가이드 탐색 경로 목록(미지정 시 기본 후보 사용)
+    pub remote_review_guides: Option<Vec<String>>,
+    /// 기본 프롬프트 템플릿을 대체할 파일(primary.md/cross_agent.md/moderator.md) 디렉터리
+    pub prompt_template_dir: Option<String>,
+    /// 코멘트 본문 템플릿을 대체할 파일(claim.md/agent.md/final.md) 디렉터리
+    pub comment_template_dir: Option<String>,
+    /// 에이전트/최종 요약 코멘트 끝에 실행 정보(버전/모델/소요 시간/게시 시각) footer를 붙일지 여부(기본 true)
+    pub comment_footer: Option<bool>,
+    /// 조직별 심각도 체계(심각한 순서). 미지정 시 Critical/Major/Minor/Suggestions
+    pub severity_levels: Option<Vec<SeverityLevelConfig>>,
+    /// provider로 보내기 전 diff/제목의 비밀값(키/토큰 등)을 가릴지 여부(기본 true)
+    pub redact_secrets: Option<bool>,
+    /// 기본 규칙에 더해 가릴 정규식 목록(`secret` 이름 그룹이 있으면 그 부분만 가림)
+    pub redact_patterns: Option<Vec<String>>,
+    /// 로컬 설정 아래에 깔 팀 공용 설정 URL(JSON/TOML/YAML)
+    pub remote_config_url: Option<String>,
+    /// 원격 팀 설정 캐시 유효 시간(분, 0이면 매번 조회)
+    pub remote_config_ttl_minutes: Option<u64>,
+    /// 변경 파일 경로에 따라 골라 넣을 리뷰 가이드 목록(모노레포 서비스별 가이드 등)
+    pub path_guides: Option<Vec<PathGuideConfig>>,
+    /// 라벨/제목으로 PR 유형을 판별해 유형별 지침을 프롬프트에 더하는 규칙(미지정 시 기본 규칙, 빈 목록이면 끔)
+    pub pr_types: Option<Vec<PrTypeConfig>>,
+    /// 실행 기록(대상/SHA/provider 사용량/소요 시간/결과)을 로컬 DB에 남길지 여부(기본 true)
+    pub run_history: Option<bool>,
+    /// 교차 반응/통합 리뷰 응답 캐시 유효 시간(시간, 0이면 캐시하지 않음)
+    pub response_cache_ttl_hours: Option<u64>,
+    /// 모든 외부 HTTP 요청(VCS/provider API/웹훅/업데이트 확인)의 `User-Agent`
+    pub user_agent: Option<String>,
+    /// provider로 보내기 전 리뷰 요청(diff/프롬프트)을 고치거나 리뷰를 거부하는 WASM 훅 모듈 경로
+    pub pre_prompt_hook: Option<String>,
+    /// 1차 리뷰 지적사항을 거르거나 고치는 WASM 훅 모듈 경로
+    pub post_findings_hook: Option<String>,
+}
+
+/// 경로 glob과 일치하는 파일이 diff에 있을 때만 넣는 리뷰 가이드.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PathGuideConfig {
+    /// 변경 파일 경로 glob(예: services/payments/**)
+    pub glob: String,
+    /// 가이드 markdown 파일 경로
+    pub guide: String,
+}
+
+/// PR 유형 판별 규칙. 라벨 중 하나가 일치하거나 제목이 정규식과 일치하면 이 유형으로 본다.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PrTypeConfig {
+    /// 보고/프롬프트에 쓰는 유형 이름(예: docs)
+    pub name: String,
+    /// 일치로 볼 라벨 이름 목록(대소문자 무시)
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// 제목 정규식(예: `(?i)^docs?(\(.*\))?:`)
+    pub title_pattern: Option<String>,
+    /// 이 유형일 때 리뷰 가이드 뒤에 붙일 지침
+    pub prompt: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeverityLevelConfig {
+    /// 섹션/출력 계약에 쓰는 이름(예: Blocker)
+    pub name: String,
+    /// 렌더링 시 이름 앞에 붙일 이모지(선택)
+    pub emoji: Option<String>,
+    /// 매핑할 내부 심각도(critical/major/minor/suggestion). 미지정 시 목록 순서로 매핑
+    pub maps_to: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct HostConfig {
     /// 고정 토큰(민감정보: 권장하지 않음)
     pub token: Option<String>,
@@ -51,19 +319,83 @@ pub struct HostConfig {
     pub token_env: Option<String>,
     /// 토큰을 stdout으로 출력하는 커맨드(예: ["gh","auth","token"])
     pub token_command: Option<Vec<String>>,
+    /// 토큰을 읽을 OS 키체인 항목(`<service>/<account>`, 예: "repopilot/github.com")
+    pub token_keyring: Option<String>,
     /// API base URL override(선택)
     pub api_base: Option<String>,
+    /// GitLab OAuth 애플리케이션 ID. 있으면 `auth gitlab`이 device flow로 로그인하고 그 토큰을 쓴다.
+    pub oauth_client_id: Option<String>,
 }
 
+/// 알림 채널별 설정(webhook 채널 + 이메일).
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationsConfig {
+    pub slack: Option<WebhookConfig>,
+    pub teams: Option<WebhookConfig>,
+    pub discord: Option<WebhookConfig>,
+    pub email: Option<EmailConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    /// 채널 사용 여부(기본 true)
+    pub enabled: Option<bool>,
+    /// Incoming webhook URL(비밀값)
+    pub webhook_url: Option<String>,
+    /// webhook URL을 읽을 환경변수 이름
+    pub webhook_url_env: Option<String>,
+    /// 알림 조건(`always` | `failure`, 기본 always)
+    pub on: Option<String>,
+}
+
+/// 최종 리뷰 요약을 메일로 보내는 SMTP 설정.
+#[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
+pub struct EmailConfig {
+    /// 채널 사용 여부(기본 true)
+    pub enabled: Option<bool>,
+    /// SMTP 서버 호스트
+    pub smtp_host: Option<String>,
+    /// SMTP 포트(기본: starttls 587, tls 465, none 25)
+    pub smtp_port: Option<u16>,
+    /// 연결 보안(`starttls` | `tls` | `none`, 기본 starttls)
+    pub smtp_security: Option<String>,
+    /// 서버 인증서 검증에 webpki 루트와 함께 신뢰할 PEM CA 번들 경로(사설 CA 릴레이)
+    pub smtp_ca_file: Option<String>,
+    /// SMTP 인증 사용자(없으면 인증하지 않음)
+    pub username: Option<String>,
+    /// SMTP 비밀번호(비밀값)
+    pub password: Option<String>,
+    /// 비밀번호를 읽을 환경변수 이름
+    pub password_env: Option<String>,
+    /// 보내는 사람 주소
+    pub from: Option<String>,
+    /// 받는 사람 주소 목록(`repos.<key>.notifications.email.to`로 저장소별 지정)
+    pub to: Option<Vec<String>>,
+    /// 메일 제목 앞에 붙일 문자열(기본 `[RepoPilot]`)
+    pub subject_prefix: Option<String>,
+    /// 알림 조건(`always` | `failure`, 기본 always)
+    pub on: Option<String>,
+}
+
+// `flatten`과 `deny_unknown_fields`는 함께 쓸 수 없다. 알 수 없는 키는 `config validate`가 잡는다.
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
 pub struct ProvidersConfig {
     pub openai: Option<ProviderConfig>,
     pub anthropic: Option<ProviderConfig>,
     pub gemini: Option<ProviderConfig>,
+    /// 그 밖의 이름은 외부 프로세스 플러그인(`kind: "plugin"`) 또는 mock(`kind: "mock"`) provider. 이름 순으로 실행한다.
+    #[serde(flatten)]
+    pub plugins: BTreeMap<String, ProviderConfig>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, Default)]
+#[serde(deny_unknown_fields)]
 pub struct ProviderConfig {
+    /// provider 종류(`plugin`이면 JSON-over-stdio 플러그인, `mock`이면 고정 응답, 기본 제공 provider는 생략)
+    pub kind: Option<String>,
     /// provider 활성화 여부(기본 true)
     pub enabled: Option<bool>,
     /// 실행할 로컬 명령
@@ -76,6 +408,8 @@ pub struct ProviderConfig {
     pub auto_auth: Option<bool>,
     /// OAuth/로그인용 커맨드 (예: ["codex","login"], ["claude","auth","login"], ["gemini"])
     pub auth_command: Option<Vec<String>>,
+    /// CLI/플러그인 명령 한 번의 실행 제한 시간(초, 기본 600)
+    pub timeout_secs: Option<u64>,
 
     /// API 모드에서 사용할 모델 식별자(선택)
     pub model: Option<String>,
@@ -85,6 +419,22 @@ pub struct ProviderConfig {
     pub api_key: Option<String>,
     /// API 모드 인증 키/토큰을 읽을 환경변수 이름
     pub api_key_env: Option<String>,
+    /// API 모드 인증 키/토큰을 읽을 OS 키체인 항목(`<service>/<account>`)
+    pub api_key_keyring: Option<String>,
+    /// API 모드에서 provider 측 프롬프트 캐시를 요청할지 여부(기본 true)
+    pub prompt_cache: Option<bool>,
+
+    /// 비용 추정용 입력 토큰 단가(USD, 100만 토큰당)
+    pub input_cost_per_mtok: Option<f64>,
+    /// 비용 추정용 출력 토큰 단가(USD, 100만 토큰당)
+    pub output_cost_per_mtok: Option<f64>,
+
+    /// mock provider가 돌려줄 마크다운(없으면 변경 파일을 짚는 기본 응답)
+    pub mock_response: Option<String>,
+    /// mock provider 응답 지연(ms, 기본 0)
+    pub mock_delay_ms: Option<u64>,
+    /// mock provider 호출이 실패할 확률(0.0~1.0, 기본 0)
+    pub mock_failure_rate: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -92,6 +442,7 @@ pub struct ProviderCommandSpec {
     pub command: String,
     pub args: Vec<String>,
     pub use_stdin: bool,
+    pub timeout: Duration,
 }
 
 impl Config {
@@ -108,6 +459,359 @@ impl Config {
             .unwrap_or_else(|| DEFAULT_SYSTEM_PROMPT.to_string())
     }
 
+    /// 교차 에이전트 반응 라운드 수를 반환한다.
+    pub fn reaction_rounds(&self) -> usize {
+        self.defaults
+            .reaction_rounds
+            .unwrap_or(DEFAULT_REACTION_ROUNDS)
+    }
+
+    /// provider별 동시 실행 청크 수(최소 1).
+    pub fn chunk_concurrency(&self) -> usize {
+        self.defaults
+            .chunk_concurrency
+            .unwrap_or(DEFAULT_CHUNK_CONCURRENCY)
+            .max(1)
+    }
+
+    /// 모든 repopilot 프로세스를 합친 provider별 동시 요청 한도. 0이면 제한하지 않는다.
+    pub fn global_concurrency(&self) -> usize {
+        self.defaults.global_concurrency.unwrap_or(0)
+    }
+
+    /// 전역 동시 요청 슬롯 저장소(디렉터리 경로 또는 `redis://` URL). 미지정/빈 값이면 None(상태 디렉터리 사용).
+    pub fn global_concurrency_store(&self) -> Option<&str> {
+        self.defaults
+            .global_concurrency_store
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 관리자 권한과 관계없이 코멘트 명령을 따를 사용자 이름(앞의 `@`는 뗀다).
+    pub fn command_allowlist(&self) -> Vec<String> {
+        self.defaults
+            .command_allowlist
+            .iter()
+            .flatten()
+            .map(|name| name.trim().trim_start_matches('@').to_string())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// claim 회수 기준 시간(분). 0이면 회수하지 않는다.
+    pub fn claim_ttl_minutes(&self) -> u64 {
+        self.defaults
+            .claim_ttl_minutes
+            .unwrap_or(DEFAULT_CLAIM_TTL_MINUTES)
+    }
+
+    /// 최종 요약에서 긴 섹션을 접는 기준.
+    pub fn summary_collapse(&self) -> SummaryCollapse {
+        SummaryCollapse {
+            findings_over: self
+                .defaults
+                .collapse_findings_over
+                .unwrap_or(DEFAULT_COLLAPSE_FINDINGS_OVER),
+            reaction_lines: self
+                .defaults
+                .collapse_reaction_lines
+                .unwrap_or(DEFAULT_COLLAPSE_REACTION_LINES),
+        }
+    }
+
+    /// 이 확신도보다 낮은 지적사항은 버린다(0이면 모두 유지).
+    pub fn min_finding_confidence(&self) -> f32 {
+        self.defaults
+            .min_finding_confidence
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0)
+    }
+
+    /// 이 확신도보다 낮은 합의 지적사항은 최종 요약에서 따로 모은다(0이면 나누지 않음).
+    pub fn low_confidence_below(&self) -> f32 {
+        self.defaults
+            .low_confidence_below
+            .unwrap_or(DEFAULT_LOW_CONFIDENCE_BELOW)
+            .clamp(0.0, 1.0)
+    }
+
+    /// diff 포함 glob 목록.
+    pub fn diff_include(&self) -> &[String] {
+        self.defaults.diff_include.as_deref().unwrap_or_default()
+    }
+
+    /// diff 제외 glob 목록.
+    pub fn diff_exclude(&self) -> &[String] {
+        self.defaults.diff_exclude.as_deref().unwrap_or_default()
+    }
+
+    /// 경로별 리뷰 가이드 목록.
+    pub fn path_guides(&self) -> &[PathGuideConfig] {
+        self.defaults.path_guides.as_deref().unwrap_or_default()
+    }
+
+    /// PR 유형 판별 규칙(먼저 일치한 규칙을 쓴다). 미지정이면 기본 규칙.
+    pub fn pr_types(&self) -> Vec<PrTypeConfig> {
+        match &self.defaults.pr_types {
+            Some(types) => types.clone(),
+            None => DEFAULT_PR_TYPES
+                .iter()
+                .map(|(name, labels, title_pattern, prompt)| PrTypeConfig {
+                    name: (*name).to_string(),
+                    labels: labels.iter().map(ToString::to_string).collect(),
+                    title_pattern: Some((*title_pattern).to_string()),
+                    prompt: (*prompt).to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    /// 시작 시 업데이트 확인 여부. `off`일 때만 끈다.
+    pub fn update_check_enabled(&self) -> bool {
+        !self
+            .defaults
+            .update_check
+            .as_deref()
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("off"))
+    }
+
+    /// 업데이트 확인 간격(시간).
+    pub fn update_check_interval_hours(&self) -> u64 {
+        self.defaults
+            .update_check_interval_hours
+            .unwrap_or(DEFAULT_UPDATE_CHECK_INTERVAL_HOURS)
+    }
+
+    /// 최신 릴리스를 조회할 저장소 URL. 미지정/빈 값이면 RepoPilot 공식 저장소.
+    pub fn update_repository(&self) -> &str {
+        self.defaults
+            .update_repository
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .unwrap_or(env!("CARGO_PKG_REPOSITORY"))
+    }
+
+    /// 릴리스 서명 검증용 공개 키. 미지정/빈 값이면 None.
+    pub fn update_public_key(&self) -> Option<&str> {
+        self.defaults
+            .update_public_key
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 원격 팀 설정 URL. 미지정/빈 값이면 None.
+    pub fn remote_config_url(&self) -> Option<&str> {
+        self.defaults
+            .remote_config_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 원격 팀 설정 캐시 유효 시간(분).
+    pub fn remote_config_ttl_minutes(&self) -> u64 {
+        self.defaults
+            .remote_config_ttl_minutes
+            .unwrap_or(DEFAULT_REMOTE_CONFIG_TTL_MINUTES)
+    }
+
+    /// 비밀값 마스킹 사용 여부.
+    pub fn redact_secrets(&self) -> bool {
+        self.defaults.redact_secrets.unwrap_or(true)
+    }
+
+    /// 사용자 정의 마스킹 정규식 목록.
+    pub fn redact_patterns(&self) -> &[String] {
+        self.defaults.redact_patterns.as_deref().unwrap_or_default()
+    }
+
+    /// 대상 저장소에서 찾을 리뷰 가이드 경로. 탐색이 꺼져 있으면 비어 있다.
+    pub fn remote_review_guides(&self) -> Vec<String> {
+        if !self.defaults.discover_review_guide.unwrap_or(false) {
+            return Vec::new();
+        }
+        match &self.defaults.remote_review_guides {
+            Some(paths) => paths.clone(),
+            None => DEFAULT_REMOTE_REVIEW_GUIDES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    /// 심각도 체계를 해석한다. `maps_to`가 없거나 잘못되면 목록 순서(critical, major, minor, suggestion...)로 매핑한다.
+    pub fn severity_taxonomy(&self) -> SeverityTaxonomy {
+        let Some(levels) = &self.defaults.severity_levels else {
+            return SeverityTaxonomy::default();
+        };
+        let levels: Vec<SeverityLevel> = levels
+            .iter()
+            .filter(|level| !level.name.trim().is_empty())
+            .enumerate()
+            .map(|(idx, level)| {
+                let positional = match idx {
+                    0 => Severity::Critical,
+                    1 => Severity::Major,
+                    2 => Severity::Minor,
+                    _ => Severity::Suggestion,
+                };
+                SeverityLevel {
+                    name: level.name.trim().to_string(),
+                    emoji: level
+                        .emoji
+                        .as_deref()
+                        .map(str::trim)
+                        .filter(|v| !v.is_empty())
+                        .map(ToString::to_string),
+                    severity: level
+                        .maps_to
+                        .as_deref()
+                        .and_then(Severity::parse)
+                        .unwrap_or(positional),
+                }
+            })
+            .collect();
+        if levels.is_empty() {
+            return SeverityTaxonomy::default();
+        }
+        SeverityTaxonomy { levels }
+    }
+
+    /// 리뷰 단위(pr/file)를 해석한다.
+    pub fn review_granularity(&self) -> ReviewGranularity {
+        ReviewGranularity::from_config(self.defaults.review_granularity.as_deref())
+    }
+
+    /// 기존 봇 코멘트 처리 방식(per_sha/single/always_new)을 해석한다.
+    pub fn dedupe_policy(&self) -> DedupePolicy {
+        DedupePolicy::from_config(self.defaults.dedupe_policy.as_deref())
+    }
+
+    /// 대상 호스트에 게시할 코멘트 본문 최대 글자 수. 설정값이 우선이며 0이면 제한하지 않는다(None).
+    pub fn max_comment_chars(&self, target: &ReviewTarget) -> Option<usize> {
+        match self.defaults.max_comment_chars {
+            Some(0) => None,
+            Some(limit) => Some(limit.max(MIN_COMMENT_CHARS)),
+            None => target.max_comment_chars(),
+        }
+    }
+
+    /// 코멘트가 한도를 넘을 때 처리 방식(split/truncate)을 해석한다.
+    pub fn comment_overflow(&self) -> CommentOverflow {
+        CommentOverflow::from_config(self.defaults.comment_overflow.as_deref())
+    }
+
+    /// 통합 리뷰(moderator) provider id를 반환한다. 미지정/빈 값이면 None.
+    pub fn moderator_provider(&self) -> Option<&str> {
+        self.defaults
+            .moderator_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// PR 메타 리뷰 provider id를 반환한다. 미지정/빈 값이면 None.
+    pub fn meta_review_provider(&self) -> Option<&str> {
+        self.defaults
+            .meta_review_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 보안 점검(security pass) 실행 여부.
+    pub fn security_pass(&self) -> bool {
+        self.defaults.security_pass.unwrap_or(false)
+    }
+
+    /// 보안 점검 provider id를 반환한다. 미지정/빈 값이면 None(활성화된 첫 provider 사용).
+    pub fn security_provider(&self) -> Option<&str> {
+        self.defaults
+            .security_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 테스트 누락 분석 실행 여부.
+    pub fn test_gap_analysis(&self) -> bool {
+        self.defaults.test_gap_analysis.unwrap_or(false)
+    }
+
+    /// 코멘트 끝에 실행 정보 footer를 붙일지 여부.
+    pub fn comment_footer(&self) -> bool {
+        self.defaults.comment_footer.unwrap_or(true)
+    }
+
+    /// 수정안이 있는 지적사항을 인라인 제안 코멘트로 게시할지 여부.
+    pub fn inline_suggestions(&self) -> bool {
+        self.defaults.inline_suggestions.unwrap_or(true)
+    }
+
+    /// 수정 패치 provider id를 반환한다. 미지정/빈 값이면 None(활성화된 첫 provider 사용).
+    pub fn autofix_provider(&self) -> Option<&str> {
+        self.defaults
+            .autofix_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// provider 결과를 나열할 순서(provider id). `defaults.provider_order`에 적은 provider를 먼저,
+    /// 나머지는 설정 순서(openai, anthropic, gemini, 플러그인 이름 순)로 붙인다.
+    pub fn provider_order(&self) -> Vec<String> {
+        let mut order: Vec<String> = Vec::new();
+        let configured = self.defaults.provider_order.iter().flatten();
+        for id in configured
+            .filter_map(|name| self.resolve_provider_id(name))
+            .chain(self.known_provider_ids())
+        {
+            if !order.contains(&id) {
+                order.push(id);
+            }
+        }
+        order
+    }
+
+    /// 사람 리뷰어 추천 여부.
+    pub fn suggest_reviewers(&self) -> bool {
+        self.defaults.suggest_reviewers.unwrap_or(false)
+    }
+
+    /// 테스트 누락 분석 provider id를 반환한다. 미지정/빈 값이면 None(활성화된 첫 provider 사용).
+    pub fn test_gap_provider(&self) -> Option<&str> {
+        self.defaults
+            .test_gap_provider
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+    }
+
+    /// 테스트 파일 경로 glob 목록.
+    pub fn test_file_globs(&self) -> Vec<String> {
+        match &self.defaults.test_file_globs {
+            Some(globs) => globs.clone(),
+            None => DEFAULT_TEST_FILE_GLOBS
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
+    /// 메타 리뷰 설명 템플릿 탐색 경로 목록.
+    pub fn description_templates(&self) -> Vec<String> {
+        match &self.defaults.description_templates {
+            Some(paths) => paths.clone(),
+            None => DEFAULT_DESCRIPTION_TEMPLATES
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+        }
+    }
+
     /// 리뷰 코멘트 출력 언어를 해석한다.
     pub fn comment_language(&self) -> CommentLanguage {
         CommentLanguage::from_config(self.defaults.comment_language.as_deref())
@@ -117,8 +821,91 @@ impl Config {
         self.hosts.get(host)
     }
 
+    /// provider id(openai/anthropic/gemini)의 비용 추정 단가.
+    pub fn token_pricing(&self, id: &str) -> Option<TokenPricing> {
+        let provider = match id {
+            "openai" => self.providers.openai.as_ref(),
+            "anthropic" => self.providers.anthropic.as_ref(),
+            "gemini" => self.providers.gemini.as_ref(),
+            _ => self.providers.plugins.get(id),
+        };
+        provider.and_then(ProviderConfig::token_pricing)
+    }
+
+    /// 실행 기록(`repopilot stats`)을 남길지 여부(기본 true).
+    pub fn run_history_enabled(&self) -> bool {
+        self.defaults.run_history.unwrap_or(true)
+    }
+
+    /// 교차 반응/통합 리뷰 응답 캐시 유효 시간(시간).
+    pub fn response_cache_ttl_hours(&self) -> u64 {
+        self.defaults
+            .response_cache_ttl_hours
+            .unwrap_or(DEFAULT_RESPONSE_CACHE_TTL_HOURS)
+    }
+
+    /// 외부 HTTP 요청의 `User-Agent`(미지정이거나 비어 있으면 `repopilot/<버전>`).
+    pub fn user_agent(&self) -> String {
+        self.defaults
+            .user_agent
+            .as_deref()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .unwrap_or(DEFAULT_USER_AGENT)
+            .to_string()
+    }
+
+    /// provider id(openai/anthropic/gemini 또는 플러그인 이름)의 설정에 적은 모델.
+    pub fn provider_model(&self, id: &str) -> Option<&str> {
+        let provider = match id {
+            "openai" => self.providers.openai.as_ref(),
+            "anthropic" => self.providers.anthropic.as_ref(),
+            "gemini" => self.providers.gemini.as_ref(),
+            _ => self.providers.plugins.get(id),
+        };
+        provider.and_then(|cfg| cfg.model.as_deref())
+    }
+
+    /// provider id(openai/anthropic/gemini 또는 플러그인 이름)로 provider 설정을 수정용으로 찾는다.
+    pub fn provider_config_mut(&mut self, id: &str) -> Option<&mut ProviderConfig> {
+        match id {
+            "openai" => self.providers.openai.as_mut(),
+            "anthropic" => self.providers.anthropic.as_mut(),
+            "gemini" => self.providers.gemini.as_mut(),
+            _ => self.providers.plugins.get_mut(id).filter(|cfg| cfg.is_custom()),
+        }
+    }
+
+    /// provider 이름/별칭을 provider id로 정규화한다. 기본 제공 provider가 아니면 플러그인 이름과 비교한다.
+    pub fn resolve_provider_id(&self, name: &str) -> Option<String> {
+        if let Some(id) = canonical_provider_id(name) {
+            return Some(id.to_string());
+        }
+        let name = name.trim();
+        self.providers
+            .plugin_providers()
+            .find(|(plugin, _)| plugin.eq_ignore_ascii_case(name))
+            .map(|(plugin, _)| plugin.to_string())
+    }
+
+    /// `--providers`/`--model`에 쓸 수 있는 provider id 목록(오류 안내용).
+    pub fn known_provider_ids(&self) -> Vec<String> {
+        ["openai", "anthropic", "gemini"]
+            .into_iter()
+            .map(ToString::to_string)
+            .chain(
+                self.providers
+                    .plugin_providers()
+                    .map(|(name, _)| name.to_string()),
+            )
+            .collect()
+    }
+
     /// 후순위(나중 파일) 값으로 덮어쓰는 병합 규칙.
     pub fn merge_from(&mut self, other: Config) {
+        if other.config_version.is_some() {
+            self.config_version = other.config_version;
+        }
         self.defaults.merge_from(other.defaults);
 
         for (host, incoming) in other.hosts {
@@ -130,7 +917,63 @@ impl Config {
         }
 
         self.providers.merge_from(other.providers);
+
+        for (repo, incoming) in other.repos {
+            if let Some(existing) = self.repos.get_mut(&repo) {
+                existing.defaults.merge_from(incoming.defaults);
+                existing.notifications.merge_from(incoming.notifications);
+            } else {
+                self.repos.insert(repo, incoming);
+            }
+        }
+
+        for (name, incoming) in other.profiles {
+            if let Some(existing) = self.profiles.get_mut(&name) {
+                existing.merge_from(incoming);
+            } else {
+                self.profiles.insert(name, incoming);
+            }
+        }
+
+        self.notifications.merge_from(other.notifications);
     }
+
+    /// 이름으로 프로필을 찾는다(대소문자 무시).
+    pub fn profile(&self, name: &str) -> Option<&ProfileConfig> {
+        let name = name.trim();
+        self.profiles
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, profile)| profile)
+    }
+
+    /// 정렬된 프로필 이름 목록.
+    pub fn profile_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.profiles.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    /// 대상 저장소 키와 일치하는 `repos` 재정의를 defaults/notifications에 적용하고, 적용한 설정 키를 반환한다.
+    /// 키 비교는 대소문자와 앞뒤 `/`, 끝의 `.git`을 무시한다.
+    pub fn apply_repo_overrides(&mut self, repository_key: &str) -> Option<String> {
+        let wanted = normalize_repo_key(repository_key);
+        let (key, repo) = self
+            .repos
+            .iter()
+            .find(|(key, _)| normalize_repo_key(key) == wanted)?;
+        let key = key.clone();
+        let overrides = repo.defaults.clone();
+        let notifications = repo.notifications.clone();
+        self.defaults.merge_from(overrides);
+        self.notifications.merge_from(notifications);
+        Some(key)
+    }
+}
+
+fn normalize_repo_key(key: &str) -> String {
+    let key = key.trim().trim_matches('/').to_ascii_lowercase();
+    key.strip_suffix(".git").map(ToString::to_string).unwrap_or(key)
 }
 
 impl DefaultsConfig {
@@ -156,6 +999,153 @@ impl DefaultsConfig {
         if other.update_timeout_ms.is_some() {
             self.update_timeout_ms = other.update_timeout_ms;
         }
+        if other.update_check.is_some() {
+            self.update_check = other.update_check;
+        }
+        if other.update_check_interval_hours.is_some() {
+            self.update_check_interval_hours = other.update_check_interval_hours;
+        }
+        if other.update_repository.is_some() {
+            self.update_repository = other.update_repository;
+        }
+        if other.update_public_key.is_some() {
+            self.update_public_key = other.update_public_key;
+        }
+        if other.reaction_rounds.is_some() {
+            self.reaction_rounds = other.reaction_rounds;
+        }
+        if other.moderator_provider.is_some() {
+            self.moderator_provider = other.moderator_provider;
+        }
+        if other.meta_review_provider.is_some() {
+            self.meta_review_provider = other.meta_review_provider;
+        }
+        if other.description_templates.is_some() {
+            self.description_templates = other.description_templates;
+        }
+        if other.security_pass.is_some() {
+            self.security_pass = other.security_pass;
+        }
+        if other.security_provider.is_some() {
+            self.security_provider = other.security_provider;
+        }
+        if other.test_gap_analysis.is_some() {
+            self.test_gap_analysis = other.test_gap_analysis;
+        }
+        if other.test_gap_provider.is_some() {
+            self.test_gap_provider = other.test_gap_provider;
+        }
+        if other.test_file_globs.is_some() {
+            self.test_file_globs = other.test_file_globs;
+        }
+        if other.inline_suggestions.is_some() {
+            self.inline_suggestions = other.inline_suggestions;
+        }
+        if other.autofix_provider.is_some() {
+            self.autofix_provider = other.autofix_provider;
+        }
+        if other.provider_order.is_some() {
+            self.provider_order = other.provider_order;
+        }
+        if other.suggest_reviewers.is_some() {
+            self.suggest_reviewers = other.suggest_reviewers;
+        }
+        if other.chunk_concurrency.is_some() {
+            self.chunk_concurrency = other.chunk_concurrency;
+        }
+        if other.global_concurrency.is_some() {
+            self.global_concurrency = other.global_concurrency;
+        }
+        if other.global_concurrency_store.is_some() {
+            self.global_concurrency_store = other.global_concurrency_store;
+        }
+        if other.command_allowlist.is_some() {
+            self.command_allowlist = other.command_allowlist;
+        }
+        if other.review_granularity.is_some() {
+            self.review_granularity = other.review_granularity;
+        }
+        if other.dedupe_policy.is_some() {
+            self.dedupe_policy = other.dedupe_policy;
+        }
+        if other.max_comment_chars.is_some() {
+            self.max_comment_chars = other.max_comment_chars;
+        }
+        if other.comment_overflow.is_some() {
+            self.comment_overflow = other.comment_overflow;
+        }
+        if other.claim_ttl_minutes.is_some() {
+            self.claim_ttl_minutes = other.claim_ttl_minutes;
+        }
+        if other.collapse_findings_over.is_some() {
+            self.collapse_findings_over = other.collapse_findings_over;
+        }
+        if other.collapse_reaction_lines.is_some() {
+            self.collapse_reaction_lines = other.collapse_reaction_lines;
+        }
+        if other.min_finding_confidence.is_some() {
+            self.min_finding_confidence = other.min_finding_confidence;
+        }
+        if other.low_confidence_below.is_some() {
+            self.low_confidence_below = other.low_confidence_below;
+        }
+        if other.diff_include.is_some() {
+            self.diff_include = other.diff_include;
+        }
+        if other.diff_exclude.is_some() {
+            self.diff_exclude = other.diff_exclude;
+        }
+        if other.discover_review_guide.is_some() {
+            self.discover_review_guide = other.discover_review_guide;
+        }
+        if other.remote_review_guides.is_some() {
+            self.remote_review_guides = other.remote_review_guides;
+        }
+        if other.prompt_template_dir.is_some() {
+            self.prompt_template_dir = other.prompt_template_dir;
+        }
+        if other.comment_template_dir.is_some() {
+            self.comment_template_dir = other.comment_template_dir;
+        }
+        if other.comment_footer.is_some() {
+            self.comment_footer = other.comment_footer;
+        }
+        if other.severity_levels.is_some() {
+            self.severity_levels = other.severity_levels;
+        }
+        if other.redact_secrets.is_some() {
+            self.redact_secrets = other.redact_secrets;
+        }
+        if other.redact_patterns.is_some() {
+            self.redact_patterns = other.redact_patterns;
+        }
+        if other.remote_config_url.is_some() {
+            self.remote_config_url = other.remote_config_url;
+        }
+        if other.remote_config_ttl_minutes.is_some() {
+            self.remote_config_ttl_minutes = other.remote_config_ttl_minutes;
+        }
+        if other.path_guides.is_some() {
+            self.path_guides = other.path_guides;
+        }
+        if other.pr_types.is_some() {
+            self.pr_types = other.pr_types;
+        }
+        if other.run_history.is_some() {
+            self.run_history = other.run_history;
+        }
+        if other.response_cache_ttl_hours.is_some() {
+            self.response_cache_ttl_hours = other.response_cache_ttl_hours;
+        }
+        if other.user_agent.is_some() {
+            self.user_agent = other.user_agent;
+        }
+        if other.pre_prompt_hook.is_some() {
+            self.pre_prompt_hook = other.pre_prompt_hook;
+        }
+        if other.post_findings_hook.is_some() {
+            self.post_findings_hook = other.post_findings_hook;
+        }
     }
 }
 
@@ -170,9 +1160,23 @@ impl HostConfig {
         if other.token_command.is_some() {
             self.token_command = other.token_command;
         }
+        if other.token_keyring.is_some() {
+            self.token_keyring = other.token_keyring;
+        }
         if other.api_base.is_some() {
             self.api_base = other.api_base;
         }
+        if other.oauth_client_id.is_some() {
+            self.oauth_client_id = other.oauth_client_id;
+        }
+    }
+
+    /// 비어 있지 않은 GitLab OAuth 애플리케이션 ID.
+    pub fn oauth_client_id(&self) -> Option<&str> {
+        self.oauth_client_id
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
     }
 }
 
@@ -181,10 +1185,42 @@ impl ProviderConfig {
         self.enabled.unwrap_or(true)
     }
 
+    /// 외부 프로세스 플러그인 provider인지(`kind: "plugin"`).
+    pub fn is_plugin(&self) -> bool {
+        self.kind
+            .as_deref()
+            .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("plugin"))
+    }
+
+    /// 외부 호출 없이 고정 응답을 돌려주는 mock provider인지(`kind: "mock"`).
+    pub fn is_mock(&self) -> bool {
+        self.kind
+            .as_deref()
+            .is_some_and(|kind| kind.trim().eq_ignore_ascii_case("mock"))
+    }
+
+    /// 이름을 붙여 정의하는 provider(플러그인 또는 mock)인지.
+    pub fn is_custom(&self) -> bool {
+        self.is_plugin() || self.is_mock()
+    }
+
     pub fn auto_auth(&self) -> bool {
         self.auto_auth.unwrap_or(true)
     }
 
+    pub fn prompt_cache(&self) -> bool {
+        self.prompt_cache.unwrap_or(true)
+    }
+
+    /// CLI/플러그인 명령 실행 제한 시간. 0이면 기본값을 쓴다.
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.timeout_secs
+                .filter(|secs| *secs > 0)
+                .unwrap_or(DEFAULT_PROVIDER_TIMEOUT_SECS),
+        )
+    }
+
     /// provider 실행 사양(명령/인자/stdin)을 정규화한다.
     pub fn command_spec(&self, default_command: &str) -> Option<ProviderCommandSpec> {
         if !self.is_enabled() {
@@ -198,10 +1234,14 @@ impl ProviderConfig {
                 .unwrap_or_else(|| default_command.to_string()),
             args: self.args.clone().unwrap_or_default(),
             use_stdin: self.use_stdin.unwrap_or(true),
+            timeout: self.timeout(),
         })
     }
 
     pub fn merge_from(&mut self, other: ProviderConfig) {
+        if other.kind.is_some() {
+            self.kind = other.kind;
+        }
         if other.enabled.is_some() {
             self.enabled = other.enabled;
         }
@@ -220,6 +1260,9 @@ impl ProviderConfig {
         if other.auth_command.is_some() {
             self.auth_command = other.auth_command;
         }
+        if other.timeout_secs.is_some() {
+            self.timeout_secs = other.timeout_secs;
+        }
 
         if other.api_key.is_some() {
             self.api_key = other.api_key;
@@ -227,12 +1270,201 @@ impl ProviderConfig {
         if other.api_key_env.is_some() {
             self.api_key_env = other.api_key_env;
         }
+        if other.api_key_keyring.is_some() {
+            self.api_key_keyring = other.api_key_keyring;
+        }
         if other.model.is_some() {
             self.model = other.model;
         }
         if other.api_base.is_some() {
             self.api_base = other.api_base;
         }
+        if other.prompt_cache.is_some() {
+            self.prompt_cache = other.prompt_cache;
+        }
+        if other.input_cost_per_mtok.is_some() {
+            self.input_cost_per_mtok = other.input_cost_per_mtok;
+        }
+        if other.output_cost_per_mtok.is_some() {
+            self.output_cost_per_mtok = other.output_cost_per_mtok;
+        }
+        if other.mock_response.is_some() {
+            self.mock_response = other.mock_response;
+        }
+        if other.mock_delay_ms.is_some() {
+            self.mock_delay_ms = other.mock_delay_ms;
+        }
+        if other.mock_failure_rate.is_some() {
+            self.mock_failure_rate = other.mock_failure_rate;
+        }
+    }
+
+    /// 입력/출력 단가가 모두 설정되어 있으면 비용 추정 단가를 반환한다.
+    pub fn token_pricing(&self) -> Option<TokenPricing> {
+        Some(TokenPricing {
+            input_per_mtok: self.input_cost_per_mtok?,
+            output_per_mtok: self.output_cost_per_mtok?,
+        })
+    }
+}
+
+impl NotificationsConfig {
+    /// 사용 중인 채널의 (채널 이름, 설정) 목록.
+    pub fn channels(&self) -> Vec<(&'static str, &WebhookConfig)> {
+        [
+            ("slack", self.slack.as_ref()),
+            ("teams", self.teams.as_ref()),
+            ("discord", self.discord.as_ref()),
+        ]
+        .into_iter()
+        .filter_map(|(name, cfg)| cfg.filter(|cfg| cfg.is_enabled()).map(|cfg| (name, cfg)))
+        .collect()
+    }
+
+    /// 사용 중인 이메일 채널 설정.
+    pub fn email_channel(&self) -> Option<&EmailConfig> {
+        self.email.as_ref().filter(|cfg| cfg.is_enabled())
+    }
+
+    /// 사용 중인 채널이 하나라도 있는지.
+    pub fn has_channels(&self) -> bool {
+        !self.channels().is_empty() || self.email_channel().is_some()
+    }
+
+    pub fn merge_from(&mut self, other: NotificationsConfig) {
+        merge_webhook_config(&mut self.slack, other.slack);
+        merge_webhook_config(&mut self.teams, other.teams);
+        merge_webhook_config(&mut self.discord, other.discord);
+        match (self.email.as_mut(), other.email) {
+            (Some(existing), Some(next)) => existing.merge_from(next),
+            (None, Some(next)) => self.email = Some(next),
+            _ => {}
+        }
+    }
+}
+
+impl WebhookConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    /// 실행 결과(성공 여부)에 대해 알림을 보내야 하는지.
+    pub fn wants(&self, succeeded: bool) -> bool {
+        notify_on(self.on.as_deref(), succeeded)
+    }
+
+    pub fn merge_from(&mut self, other: WebhookConfig) {
+        if other.enabled.is_some() {
+            self.enabled = other.enabled;
+        }
+        if other.webhook_url.is_some() {
+            self.webhook_url = other.webhook_url;
+        }
+        if other.webhook_url_env.is_some() {
+            self.webhook_url_env = other.webhook_url_env;
+        }
+        if other.on.is_some() {
+            self.on = other.on;
+        }
+    }
+}
+
+impl EmailConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+
+    /// 실행 결과(성공 여부)에 대해 메일을 보내야 하는지.
+    pub fn wants(&self, succeeded: bool) -> bool {
+        notify_on(self.on.as_deref(), succeeded)
+    }
+
+    /// 연결 보안 방식(소문자, 기본 `starttls`).
+    pub fn security(&self) -> String {
+        self.smtp_security
+            .as_deref()
+            .map(|v| v.trim().to_ascii_lowercase())
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| "starttls".to_string())
+    }
+
+    /// SMTP 포트(미설정이면 보안 방식별 기본 포트).
+    pub fn port(&self) -> u16 {
+        self.smtp_port.unwrap_or(match self.security().as_str() {
+            "tls" => 465,
+            "none" => 25,
+            _ => 587,
+        })
+    }
+
+    /// 비어 있지 않은 수신자 주소 목록.
+    pub fn recipients(&self) -> Vec<String> {
+        self.to
+            .iter()
+            .flatten()
+            .map(|addr| addr.trim())
+            .filter(|addr| !addr.is_empty())
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    pub fn subject_prefix(&self) -> &str {
+        self.subject_prefix.as_deref().unwrap_or("[RepoPilot]")
+    }
+
+    pub fn merge_from(&mut self, other: EmailConfig) {
+        if other.enabled.is_some() {
+            self.enabled = other.enabled;
+        }
+        if other.smtp_host.is_some() {
+            self.smtp_host = other.smtp_host;
+        }
+        if other.smtp_port.is_some() {
+            self.smtp_port = other.smtp_port;
+        }
+        if other.smtp_security.is_some() {
+            self.smtp_security = other.smtp_security;
+        }
+        if other.smtp_ca_file.is_some() {
+            self.smtp_ca_file = other.smtp_ca_file;
+        }
+        if other.username.is_some() {
+            self.username = other.username;
+        }
+        if other.password.is_some() {
+            self.password = other.password;
+        }
+        if other.password_env.is_some() {
+            self.password_env = other.password_env;
+        }
+        if other.from.is_some() {
+            self.from = other.from;
+        }
+        if other.to.is_some() {
+            self.to = other.to;
+        }
+        if other.subject_prefix.is_some() {
+            self.subject_prefix = other.subject_prefix;
+        }
+        if other.on.is_some() {
+            self.on = other.on;
+        }
+    }
+}
+
+// `on` 설정(`always` | `failure`)을 실행 결과에 적용한다.
+fn notify_on(on: Option<&str>, succeeded: bool) -> bool {
+    match on.map(str::trim) {
+        Some(on) if on.eq_ignore_ascii_case("failure") => !succeeded,
+        _ => true,
+    }
+}
+
+fn merge_webhook_config(target: &mut Option<WebhookConfig>, incoming: Option<WebhookConfig>) {
+    match (target.as_mut(), incoming) {
+        (Some(existing), Some(next)) => existing.merge_from(next),
+        (None, Some(next)) => *target = Some(next),
+        _ => {}
     }
 }
 
@@ -241,6 +1473,21 @@ impl ProvidersConfig {
         merge_provider_config(&mut self.openai, other.openai);
         merge_provider_config(&mut self.anthropic, other.anthropic);
         merge_provider_config(&mut self.gemini, other.gemini);
+        for (name, incoming) in other.plugins {
+            if let Some(existing) = self.plugins.get_mut(&name) {
+                existing.merge_from(incoming);
+            } else {
+                self.plugins.insert(name, incoming);
+            }
+        }
+    }
+
+    /// 플러그인/mock provider 설정(`kind: "plugin"` | `"mock"`인 항목만, 이름 순). 기본 제공 provider 별칭은 쓸 수 없다.
+    pub fn plugin_providers(&self) -> impl Iterator<Item = (&str, &ProviderConfig)> {
+        self.plugins
+            .iter()
+            .filter(|(name, cfg)| cfg.is_custom() && canonical_provider_id(name).is_none())
+            .map(|(name, cfg)| (name.as_str(), cfg))
     }
 }
 
@@ -1,22 +1,120 @@
 //! 애플리케이션 계층이 의존하는 포트(추상 인터페이스) 모음.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::domain::review::{
-    AgentComment, AgentReaction, ProviderResponse, ReviewComment, ReviewRequest,
+    AgentComment, AgentReaction, ConsolidatedReview, FileThread, Finding, ProviderResponse,
+    IssueRef, PreviousFinding, PreviousReview, ProviderRun, ReviewComment, ReviewRequest,
+    ReviewSummary, SeverityTaxonomy, StageReview, TokenUsage,
 };
-use crate::domain::target::ReviewTarget;
-use crate::application::config::{Config, HostConfig, ProviderConfig};
+use crate::domain::diff::{DiffStats, LinePosition};
+use crate::domain::patch::PatchedFile;
+use crate::domain::release::ReleaseAsset;
+use crate::domain::target::{OpenReview, RepositoryRef, ReviewTarget};
+use crate::domain::template::{CommentTemplates, PromptTemplates, TemplateSchema, TemplateVars};
+use crate::application::config::{Config, HostConfig, NotificationsConfig, ProviderConfig};
+use crate::application::usecases::review_pr::ReviewOutcome;
 
 /// 설정 로딩/점검을 담당하는 저장소 포트.
 pub trait ConfigRepository: Send + Sync {
     fn load(&self) -> Result<Config>;
-    fn inspect_pretty_json(&self) -> Result<String>;
+    /// 설정 점검 JSON. `show_secrets`가 false면 토큰/API 키 값은 `****`로 가린다.
+    fn inspect_pretty_json(&self, show_secrets: bool) -> Result<String>;
     /// 편집 가능한 설정 파일 경로를 반환한다.
     fn editable_config_path(&self) -> Result<PathBuf>;
+    /// 탐색 경로의 설정 파일을 스키마 기준으로 검증한다.
+    fn validate(&self) -> Result<ConfigValidation>;
+    /// 병합된 설정에서 점 표기 키(`providers.openai.model`)의 값을 읽는다(미설정이면 None).
+    /// `show_secrets`가 false면 `token`/`api_key`/`webhook_url`/`password` 값은 `****`로 가린다.
+    fn get_value(&self, key: &str, show_secrets: bool) -> Result<Option<serde_json::Value>>;
+    /// 편집 가능한 설정 파일에 키 값을 기록하고 그 경로를 반환한다.
+    /// 파일을 다시 쓰면 주석이 사라지는 경우(YAML)는 `force`일 때만 기록한다.
+    fn set_value(&self, key: &str, value: &str, force: bool) -> Result<PathBuf>;
+    /// 오래된 스키마 버전의 설정 파일을 현재 버전으로 다시 쓴다.
+    /// 다시 쓰면 주석이 사라지는 파일(YAML)은 `force`가 아니면 변환 결과를 옆 파일에 쓴다.
+    fn migrate(&self, force: bool) -> Result<Vec<ConfigMigration>>;
+    /// 프로젝트 설정 파일(`.repopilot/config.json`)과 리뷰 가이드를 생성한다(`repopilot init`).
+    fn init_project(&self, init: &ProjectInit) -> Result<InitializedProject>;
+    /// 실행 상태(REPL 입력 기록 등)를 두는 디렉터리. 정할 수 없으면 None.
+    fn state_dir(&self) -> Option<PathBuf>;
+}
+
+/// `repopilot init`에서 고른 프로젝트 설정 값.
+#[derive(Debug, Clone)]
+pub struct ProjectInit {
+    /// 토큰 설정을 넣을 VCS 호스트와 종류
+    pub hosts: Vec<(String, VcsAuthKind)>,
+    /// 활성화할 provider id(`openai`/`anthropic`/`gemini`)
+    pub providers: Vec<String>,
+    /// 리뷰 코멘트 언어(BCP-47 태그)
+    pub comment_language: String,
+    /// 기존 설정 파일을 덮어쓸지 여부
+    pub force: bool,
+}
+
+/// `repopilot init` 생성 결과.
+#[derive(Debug, Clone)]
+pub struct InitializedProject {
+    pub config_path: PathBuf,
+    pub guide_path: PathBuf,
+    /// 리뷰 가이드를 새로 만들었는지(이미 있으면 그대로 둔다)
+    pub guide_created: bool,
+}
+
+/// 설정 파일 하나의 스키마 마이그레이션 결과.
+#[derive(Debug, Clone)]
+pub struct ConfigMigration {
+    pub file: PathBuf,
+    pub output: MigrationOutput,
+    pub from_version: u64,
+    pub to_version: u64,
+    /// 적용된 변환 설명
+    pub changes: Vec<String>,
+}
+
+/// 마이그레이션 결과를 기록한 방식.
+#[derive(Debug, Clone)]
+pub enum MigrationOutput {
+    /// 원본 파일을 다시 쓰고 원본은 `backup`에 남겼다.
+    Rewritten { backup: PathBuf },
+    /// 다시 쓰면 주석이 사라지므로 원본은 그대로 두고 변환 결과를 `path`에 썼다.
+    WrittenBeside { path: PathBuf },
+}
+
+/// 설정 검증에서 발견한 문제 하나.
+#[derive(Debug, Clone)]
+pub struct ConfigIssue {
+    pub file: PathBuf,
+    /// 문제 위치(JSON pointer, 예: `/providers/openai/modle`). 파일 전체 문제면 빈 문자열.
+    pub pointer: String,
+    pub message: String,
+    /// 수정 제안(오타 키의 did-you-mean 등).
+    pub suggestion: Option<String>,
+}
+
+impl std::fmt::Display for ConfigIssue {
+    /// `파일#포인터: 메시지 (제안)` 형식의 한 줄.
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file.display())?;
+        if !self.pointer.is_empty() {
+            write!(f, "#{}", self.pointer)?;
+        }
+        write!(f, ": {}", self.message)?;
+        if let Some(suggestion) = &self.suggestion {
+            write!(f, " ({suggestion})")?;
+        }
+        Ok(())
+    }
+}
+
+/// 설정 파일 검증 결과.
+#[derive(Debug, Clone)]
+pub struct ConfigValidation {
+    pub checked_paths: Vec<PathBuf>,
+    pub issues: Vec<ConfigIssue>,
 }
 
 /// 호스트(VCS) 토큰 해석 결과.
@@ -33,21 +131,41 @@ pub trait HostTokenResolver: Send + Sync {
 }
 
 /// 시스템 프롬프트를 구성/해석하는 포트.
-/// - review guide 파일 접근 등 I/O는 구현체(인프라)에서 수행한다.
+/// - review guide/템플릿 파일 접근 등 I/O는 구현체(인프라)에서 수행한다.
 pub trait SystemPromptResolver: Send + Sync {
+    /// 기본 시스템 지시문.
     fn resolve(&self, config: &Config) -> Result<String>;
+    /// 로컬 리뷰 가이드 본문(미설정/빈 파일이면 None).
+    fn review_guide(&self, config: &Config) -> Result<Option<String>>;
+    /// `path_guides` 항목의 가이드 파일 본문(빈 파일이면 None).
+    fn path_guide(&self, path: &str) -> Result<Option<String>>;
+    /// 단계별 프롬프트 템플릿(`prompt_template_dir` 재정의 반영).
+    fn prompt_templates(&self, config: &Config) -> Result<PromptTemplates>;
+    /// 코멘트 종류별 본문 템플릿(`comment_template_dir` 재정의 반영).
+    fn comment_templates(&self, config: &Config) -> Result<CommentTemplates>;
 }
 
 /// VCS OAuth 인증 실행 종류.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VcsAuthKind {
     GitHub,
     GitLab,
 }
 
-/// VCS OAuth 인증을 실행하는 포트(예: gh/glab login).
+/// VCS OAuth 인증을 실행하는 포트(예: gh/glab login, GitLab device flow).
 pub trait VcsAuthenticator: Send + Sync {
-    fn authenticate(&self, kind: VcsAuthKind, host: &str) -> Result<()>;
+    fn authenticate(
+        &self,
+        kind: VcsAuthKind,
+        host: &str,
+        host_cfg: Option<&HostConfig>,
+    ) -> Result<()>;
+}
+
+/// 토큰/API 키를 OS 키체인 같은 비밀 저장소에 기록하는 포트.
+pub trait SecretStore: Send + Sync {
+    /// `entry`(`<service>/<account>`)에 비밀값을 저장한다.
+    fn store(&self, entry: &str, secret: &str) -> Result<()>;
 }
 
 /// Provider OAuth 인증 종류.
@@ -73,10 +191,73 @@ pub trait TargetResolver: Send + Sync {
 #[async_trait]
 pub trait VcsGateway: Send + Sync {
     async fn fetch_head_sha(&self) -> Result<String>;
+    /// PR/MR 제목.
+    async fn fetch_title(&self) -> Result<String>;
+    /// PR/MR에 붙은 라벨 이름 목록.
+    async fn fetch_labels(&self) -> Result<Vec<String>>;
+    /// PR/MR 설명 본문(없으면 빈 문자열).
+    async fn fetch_description(&self) -> Result<String>;
+    /// PR/MR에 포함된 커밋 메시지(오래된 순).
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>>;
+    /// PR/MR 작성자 사용자 이름.
+    async fn fetch_author(&self) -> Result<String>;
     async fn fetch_diff(&self) -> Result<String>;
     async fn list_comments(&self) -> Result<Vec<ReviewComment>>;
     async fn create_comment(&self, body: &str) -> Result<ReviewComment>;
     async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment>;
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment>;
+    /// 변경 후 파일의 줄 구간(diff hunk 안)에 인라인 리뷰 코멘트를 단다.
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment>;
+    /// 파일/줄 단위 리뷰 코멘트(파일 스레드, 인라인 제안) 목록.
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>>;
+    /// 파일/줄 단위 리뷰 코멘트 본문을 바꾼다.
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment>;
+    /// `/repopilot` 명령 코멘트를 처리했음을 반응(👍)으로 알린다.
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()>;
+    /// PR/MR에 리뷰어를 요청한다. 이름은 `@` 없는 사용자 이름이나 `org/team` 형식의 팀이다.
+    /// 기존 리뷰어는 유지한다.
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()>;
+    /// 대상 저장소에 이슈를 만든다(PR/MR에 묶이지 않는 후속 작업용).
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef>;
+    /// 대상 저장소 기본 브랜치의 파일 내용을 읽는다(파일이 없으면 None).
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>>;
+    /// 지정한 커밋 기준 파일 내용을 읽는다(파일이 없으면 None).
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>>;
+    /// 기본 브랜치에서 `path`를 바꾼 최근 커밋 `limit`개의 작성자 사용자 이름(최신순, 중복 제거).
+    /// 사용자 계정과 연결되지 않은 커밋은 건너뛴다.
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>>;
+    /// `base_sha` 위에 파일 변경을 커밋 하나로 올린 `branch`를 만들고(이미 있으면 강제로 덮어쓴다)
+    /// 브랜치 URL을 반환한다.
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String>;
+    /// 토큰이 유효하고 대상 저장소에 접근할 수 있는지, `write`면 코멘트 작성에 필요한 scope가
+    /// 있는지 미리 확인한다. scope를 조회할 수 없는 토큰은 유효성만 확인한다.
+    async fn check_token_access(&self, _write: bool) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 저장소 단위 VCS 조회 포트(PR/MR 번호가 정해지기 전 단계).
+#[async_trait]
+pub trait RepositoryGateway: Send + Sync {
+    /// 최근 갱신 순으로 열린 PR/MR을 최대 `limit`개 조회한다.
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>>;
 }
 
 /// 대상/호스트 설정에 맞는 VCS 게이트웨이를 생성하는 팩토리 포트.
@@ -87,13 +268,52 @@ pub trait VcsFactory: Send + Sync {
         host_cfg: Option<&HostConfig>,
         token: Option<String>,
     ) -> Box<dyn VcsGateway>;
+    /// 저장소 단위 게이트웨이를 생성한다.
+    fn build_repository(
+        &self,
+        repository: &RepositoryRef,
+        host_cfg: Option<&HostConfig>,
+        token: Option<String>,
+    ) -> Box<dyn RepositoryGateway>;
+}
+
+/// 작업 디렉터리 git 저장소의 원격 URL 조회 포트.
+pub trait GitRemoteReader: Send + Sync {
+    /// 원격 URL 목록(`origin` 우선). git 저장소가 아니면 빈 목록.
+    fn remote_urls(&self) -> Vec<String>;
+}
+
+/// 파일을 사용자 편집기로 여는 포트(REPL `/config edit`).
+pub trait EditorLauncher: Send + Sync {
+    /// 편집기가 끝날 때까지 기다린다. 편집기가 실패 코드로 끝나면 오류다.
+    fn open(&self, path: &Path) -> Result<()>;
+}
+
+/// 시스템 클립보드 텍스트 조회 포트.
+pub trait ClipboardReader: Send + Sync {
+    /// 클립보드 텍스트. 지원 도구가 없거나 비어 있으면 None.
+    fn read_text(&self) -> Option<String>;
+}
+
+/// 콘솔 로그 출력을 멈추거나 다시 켜는 포트(`--tui` 전체 화면 UI).
+/// 파일 로그는 계속 남긴다.
+pub trait ConsoleLogSwitch: Send + Sync {
+    fn set_muted(&self, muted: bool);
+}
+
+/// 현재 시각과 실행마다 다른 임의 id를 제공하는 포트.
+pub trait Clock: Send + Sync {
+    /// 현재 unix 시각(초).
+    fn unix_now(&self) -> u64;
+    /// 실행 id/claim nonce용 임의 id(16진수 16자리).
+    fn random_id(&self) -> String;
 }
 
 /// 개별 AI 제공자(에이전트) 실행 포트.
 #[async_trait]
 pub trait ProviderAgent: Send + Sync {
-    fn id(&self) -> &'static str;
-    fn name(&self) -> &'static str;
+    fn id(&self) -> &str;
+    fn name(&self) -> &str;
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse>;
     async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse>;
 }
@@ -103,17 +323,99 @@ pub trait ProviderFactory: Send + Sync {
     fn build(&self, config: &Config) -> Vec<Box<dyn ProviderAgent>>;
 }
 
+/// 리뷰 파이프라인 정책 훅 포트(`defaults.pre_prompt_hook` / `defaults.post_findings_hook`).
+pub trait ReviewHooks: Send + Sync {
+    /// provider로 보내기 전 요청(청크)의 diff/프롬프트를 고친다. 훅이 리뷰를 거부하면 오류를 낸다.
+    fn pre_prompt(&self, request: &mut ReviewRequest) -> Result<()>;
+    /// provider 하나의 1차 리뷰 지적사항을 거르거나 고친 목록을 돌려준다.
+    fn post_findings(
+        &self,
+        request: &ReviewRequest,
+        provider_id: &str,
+        findings: Vec<Finding>,
+    ) -> Result<Vec<Finding>>;
+}
+
+/// 설정의 훅 모듈을 불러오는 팩토리 포트. 설정된 훅이 없으면 None.
+pub trait ReviewHookFactory: Send + Sync {
+    fn build(&self, config: &Config) -> Result<Option<Box<dyn ReviewHooks>>>;
+}
+
+/// 프롬프트/코멘트 템플릿 엔진 포트.
+/// 템플릿 종류별 변수 목록과 모르는 변수를 거부하는 규칙은 `domain::template`에 있다.
+pub trait TemplateEngine: Send + Sync {
+    /// 템플릿을 렌더링한다. 정의되지 않은 변수를 출력하면 오류다.
+    fn render(&self, template: &str, vars: &TemplateVars<'_>) -> Result<String>;
+    /// 사용자 템플릿의 문법 오류와 `schema`에 없는 변수를 거부한다.
+    fn validate(&self, template: &str, schema: &TemplateSchema) -> Result<()>;
+}
+
 /// 리뷰 마크다운 렌더링 포트.
 pub trait MarkdownRenderer: Send + Sync {
-    fn render_claim(&self, sha: &str, target_url: &str) -> String;
-    fn render_agent(&self, sha: &str, target_url: &str, agent: &AgentComment) -> String;
-    fn render_final(
+    /// `claimed_at`은 claim 시각(unix 초)으로, 오래된 claim 회수 판단에 쓰인다.
+    /// `nonce`는 동시에 claim한 실행기를 구분하는 실행기별 값이다.
+    /// `template`은 마커 아래 본문 템플릿([`CommentTemplates`]의 해당 항목)이다.
+    fn render_claim(
         &self,
         sha: &str,
         target_url: &str,
-        reactions: &[AgentReaction],
-        agent_comment_refs: &[(String, String)],
+        claimed_at: u64,
+        nonce: &str,
+        template: &str,
+    ) -> Result<String>;
+    /// 다른 실행기에 밀려 철회한 claim 코멘트 본문(마커 없음).
+    fn render_claim_withdrawn(&self, sha: &str, target_url: &str) -> String;
+    /// 리뷰할 텍스트 변경이 없어 provider를 실행하지 않은 실행의 최종 요약 본문(최종 요약 마커 포함).
+    fn render_unreviewable(&self, sha: &str, target_url: &str, reason: &str) -> String;
+    fn render_agent(
+        &self,
+        sha: &str,
+        target_url: &str,
+        agent: &AgentComment,
+        template: &str,
+    ) -> Result<String>;
+    fn render_file_thread(&self, sha: &str, thread: &FileThread) -> String;
+    /// 수정안(`Finding::suggestion`)을 대상 플랫폼의 제안 블록으로 담은 인라인 코멘트 본문.
+    fn render_suggestion(
+        &self,
+        target_url: &str,
+        finding: &Finding,
+        taxonomy: &SeverityTaxonomy,
     ) -> String;
+    fn render_final(
+        &self,
+        sha: &str,
+        target_url: &str,
+        summary: &ReviewSummary,
+        template: &str,
+    ) -> Result<String>;
+}
+
+/// 리뷰 결과 전체를 보고서/문서로 직렬화하는 포트(`--format`/`--export`/MCP 응답).
+pub trait ReportRenderer: Send + Sync {
+    /// 개별 에이전트 코멘트와 최종 요약을 이은 Markdown 문서.
+    fn render_markdown(&self, outcome: &ReviewOutcome) -> Result<String>;
+    /// VCS 밖에서 공유할 단독 HTML 보고서.
+    fn render_html(&self, outcome: &ReviewOutcome) -> Result<String>;
+    /// 다른 도구가 소비할 JSON 문서.
+    fn render_json(&self, outcome: &ReviewOutcome) -> Result<String>;
+    /// 합의 지적사항의 GitLab Code Quality 리포트(JSON 배열).
+    fn render_code_quality(&self, outcome: &ReviewOutcome) -> Result<String>;
+}
+
+/// 리뷰 결과 문서를 파일로 기록하는 포트(`--output`, `--autofix`). 없는 상위 디렉터리는 만든다.
+pub trait OutputWriter: Send + Sync {
+    fn write(&self, path: &Path, content: &str) -> Result<()>;
+}
+
+/// HTML 보고서를 파일로 내보내는 포트(`--export html`). 없는 상위 디렉터리는 만든다.
+pub trait HtmlExporter: Send + Sync {
+    fn write_html(&self, html: &str, path: &Path) -> Result<()>;
+}
+
+/// HTML 보고서를 PDF 파일로 내보내는 포트(`--export pdf`). 없는 상위 디렉터리는 만든다.
+pub trait PdfExporter: Send + Sync {
+    fn write_pdf(&self, html: &str, path: &Path) -> Result<()>;
 }
 
 /// 사용자 확인 입력을 받는 포트.
@@ -122,22 +424,255 @@ pub trait UserConfirmer: Send + Sync {
     fn confirm(&self, message: &str) -> Result<bool>;
 }
 
+/// 지적사항 triage 결정.
+#[derive(Debug, Clone)]
+pub enum TriageDecision {
+    /// 그대로 게시
+    Accept,
+    /// 게시하지 않음
+    Drop,
+    /// 본문을 수정해 게시
+    Edit(String),
+}
+
+/// 게시 전 지적사항을 하나씩 검토받는 포트.
+pub trait FindingTriage: Send + Sync {
+    /// `index`/`total`은 1부터 시작하는 진행 위치다.
+    fn triage(
+        &self,
+        agent_name: &str,
+        finding: &Finding,
+        index: usize,
+        total: usize,
+    ) -> Result<TriageDecision>;
+}
+
+/// 게시 단계 재시도를 위해 보관하는 provider 실행 결과.
+#[derive(Debug, Clone)]
+pub struct RunJournalEntry {
+    pub target_url: String,
+    pub head_sha: String,
+    pub primary_results: Vec<ProviderRun>,
+    pub reactions: Vec<AgentReaction>,
+    pub consolidated: Option<ConsolidatedReview>,
+    /// 보조 리뷰 단계(PR 메타 리뷰 등) 결과
+    pub stage_reviews: Vec<StageReview>,
+    /// 리뷰 대상 diff 통계(이전 버전 저널에는 없다)
+    pub diff_stats: Option<DiffStats>,
+}
+
+/// provider 실행 결과를 로컬에 보관하는 저널 포트.
+pub trait RunJournal: Send + Sync {
+    /// 저널을 저장하고 저장 위치를 반환한다.
+    fn save(&self, entry: &RunJournalEntry) -> Result<String>;
+    fn load(&self, target_url: &str) -> Result<Option<RunJournalEntry>>;
+    fn remove(&self, target_url: &str) -> Result<()>;
+}
+
+/// 실행 기록 한 건(리뷰 실행 1회).
+#[derive(Debug, Clone)]
+pub struct RunRecord {
+    /// 시작 시각(unix 초)
+    pub started_at: u64,
+    /// 저장소 식별 키(`host/owner/repo`)
+    pub repository: String,
+    pub target_url: String,
+    /// HEAD SHA(조회 전에 실패했으면 None)
+    pub head_sha: Option<String>,
+    /// `posted` | `dry-run` | `skipped` | `gate-failed` | `failed` | `cancelled`
+    pub outcome: String,
+    pub duration_secs: f64,
+    pub providers: Vec<ProviderUsageRecord>,
+    /// 리뷰를 마친 실행의 합의 지적사항(스킵/실패한 실행이면 None)
+    pub findings: Option<Vec<PreviousFinding>>,
+}
+
+/// 실행 기록의 provider별 1차 리뷰 사용량.
+#[derive(Debug, Clone)]
+pub struct ProviderUsageRecord {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub duration_secs: Option<f64>,
+    pub prompt_tokens: Option<u64>,
+    pub completion_tokens: Option<u64>,
+    pub total_tokens: Option<u64>,
+    /// 설정된 단가로 추정한 비용(USD). 단가/토큰 정보가 없으면 None.
+    pub cost_usd: Option<f64>,
+}
+
+/// 저장소/provider 단위 집계 한 줄.
+#[derive(Debug, Clone)]
+pub struct UsageStatsRow {
+    pub name: String,
+    pub runs: u64,
+    /// 실패/취소된 실행 수(저장소 집계에만 채워짐)
+    pub failed: u64,
+    pub avg_secs: Option<f64>,
+    pub total_tokens: Option<u64>,
+    pub cost_usd: Option<f64>,
+}
+
+/// 기간 내 실행 기록 집계.
+#[derive(Debug, Clone)]
+pub struct RunStats {
+    /// 기록 저장 위치(안내용)
+    pub location: String,
+    pub repositories: Vec<UsageStatsRow>,
+    pub providers: Vec<UsageStatsRow>,
+}
+
+/// 최근 실행 목록의 한 줄(provider 사용량은 합계만).
+#[derive(Debug, Clone)]
+pub struct RecentRun {
+    /// 시작 시각(unix 초)
+    pub started_at: u64,
+    pub repository: String,
+    pub target_url: String,
+    pub head_sha: Option<String>,
+    pub outcome: String,
+    pub duration_secs: f64,
+    pub total_tokens: Option<u64>,
+    pub cost_usd: Option<f64>,
+}
+
+/// 리뷰 실행 기록 저장소 포트.
+pub trait RunHistory: Send + Sync {
+    fn record(&self, run: &RunRecord) -> Result<()>;
+    /// `since`(unix 초) 이후 실행을 저장소/provider별로 집계한다(None이면 전체 기간).
+    fn stats(&self, since: Option<u64>) -> Result<RunStats>;
+    /// 최근 실행을 새것부터 최대 `limit`건 반환한다.
+    fn recent(&self, limit: usize) -> Result<Vec<RecentRun>>;
+    /// 같은 대상에서 `head_sha`가 아닌 SHA로 게시까지 마친 가장 최근 리뷰의 합의 지적사항.
+    /// 지적사항을 기록하지 않은 실행(이 기능 이전 기록)은 건너뛴다.
+    fn previous_review(&self, target_url: &str, head_sha: &str) -> Result<Option<PreviousReview>>;
+}
+
+/// 리뷰 완료/실패 알림 내용.
+#[derive(Debug, Clone)]
+pub struct ReviewNotification {
+    pub target_url: String,
+    /// 저장소 식별 키(`host/owner/repo`)
+    pub repository: String,
+    pub head_sha: Option<String>,
+    /// `posted` | `gate-failed` | `failed` | `cancelled`
+    pub outcome: String,
+    /// 리뷰가 게시까지 끝났는지(게이트 실패도 게시는 끝난 것으로 본다)
+    pub succeeded: bool,
+    /// (심각도 라벨, 지적사항 수). 심각한 순서이며 0건인 심각도는 뺀다.
+    pub severity_counts: Vec<(String, usize)>,
+    /// 설정된 단가로 추정한 비용 합계(USD)
+    pub cost_usd: Option<f64>,
+    pub duration_secs: f64,
+    /// 실패 사유(성공이면 None)
+    pub error: Option<String>,
+    /// 렌더링된 최종 리뷰 요약 Markdown(성공이면 Some, 이메일 본문으로 쓴다)
+    pub summary_markdown: Option<String>,
+}
+
+/// 리뷰 결과 알림 포트(Slack/Teams/Discord webhook, 이메일 등).
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    /// 설정된 채널 중 알림 조건에 맞는 채널로 보내고, 보낸 채널 이름을 반환한다.
+    /// 일부 채널 전송이 실패하면 나머지는 계속 보내고 실패 내용을 오류로 묶어 반환한다.
+    async fn notify(
+        &self,
+        config: &NotificationsConfig,
+        notification: &ReviewNotification,
+    ) -> Result<Vec<String>>;
+}
+
 /// 업데이트 확인 결과 DTO.
 #[derive(Debug, Clone)]
 pub struct LatestVersionInfo {
     pub version: String,
     pub download_url: Option<String>,
+    /// 릴리스 첨부 파일(GitHub/GitLab 릴리스 응답일 때만 채워짐)
+    pub assets: Vec<ReleaseAsset>,
 }
 
 /// 원격 최신 버전 정보를 조회하는 포트.
 #[async_trait]
 pub trait UpdateChecker: Send + Sync {
+    /// 사용자 지정 endpoint(plain text 버전 또는 JSON)에서 조회한다.
     async fn fetch_latest(
         &self,
         url: &str,
         token: Option<&str>,
         timeout_ms: u64,
     ) -> Result<Option<LatestVersionInfo>>;
+    /// 저장소의 GitHub/GitLab 최신 릴리스를 조회한다.
+    async fn fetch_latest_release(
+        &self,
+        repository: &RepositoryRef,
+        host_cfg: Option<&HostConfig>,
+        token: Option<&str>,
+        timeout_ms: u64,
+    ) -> Result<Option<LatestVersionInfo>>;
+    /// 릴리스 asset을 내려받는다.
+    async fn download(&self, url: &str, token: Option<&str>, timeout_ms: u64) -> Result<Vec<u8>>;
+}
+
+/// 마지막 업데이트 확인 기록.
+#[derive(Debug, Clone)]
+pub struct UpdateCheckRecord {
+    /// 확인 시각(unix 초)
+    pub checked_at: u64,
+    /// 조회한 곳(endpoint URL 또는 저장소 URL). 설정이 바뀌면 기록을 쓰지 않는다.
+    pub source: String,
+    /// 조회한 최신 버전(조회 실패/릴리스 없음이면 None)
+    pub latest_version: Option<String>,
+    pub download_url: Option<String>,
+}
+
+/// 캐시에 보관한 provider 응답 본문.
+#[derive(Debug, Clone)]
+pub struct CachedResponse {
+    /// 저장 시각(unix 초)
+    pub saved_at: u64,
+    pub content: String,
+}
+
+/// provider 응답을 실행 간에 보관하는 캐시 포트. 키는 provider/모델/프롬프트로 만든 해시다.
+pub trait ResponseCache: Send + Sync {
+    fn load(&self, key: &str) -> Result<Option<CachedResponse>>;
+    fn save(&self, key: &str, response: &CachedResponse) -> Result<()>;
+}
+
+/// 업데이트 확인 결과를 실행 간에 보관하는 캐시 포트.
+pub trait UpdateCheckCache: Send + Sync {
+    fn load(&self) -> Option<UpdateCheckRecord>;
+    fn save(&self, record: &UpdateCheckRecord) -> Result<()>;
+}
+
+/// 내려받은 실행 파일을 검증하고 현재 바이너리를 교체하는 포트.
+pub trait BinaryInstaller: Send + Sync {
+    /// 바이트열의 SHA-256 hex(소문자).
+    fn sha256_hex(&self, bytes: &[u8]) -> String;
+    /// base64 Ed25519 공개 키로 서명(원본 64바이트 또는 base64 텍스트)을 검증한다.
+    fn verify_signature(&self, bytes: &[u8], signature: &[u8], public_key: &str) -> Result<()>;
+    /// 현재 실행 파일을 원자적으로 교체하고 그 경로를 반환한다.
+    fn replace_current_exe(&self, bytes: &[u8]) -> Result<PathBuf>;
+}
+
+/// 출력 상세도(`--quiet` / 기본 / `--verbose`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Verbosity {
+    /// 최종 결과(`Reporter::outcome`)만 출력
+    Quiet,
+    #[default]
+    Normal,
+    /// 진단 정보(`Reporter::detail`)까지 출력
+    Verbose,
+}
+
+/// 진행 상황 출력 형식(`--progress-format`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProgressFormat {
+    /// 사람이 읽는 콘솔 출력
+    #[default]
+    Text,
+    /// 한 줄에 JSON 이벤트 하나(NDJSON)
+    Ndjson,
 }
 
 /// 콘솔/로그 출력 추상화 포트.
@@ -145,6 +680,15 @@ pub trait Reporter: Send + Sync {
     fn section(&self, name: &str);
     fn kv(&self, key: &str, value: &str);
     fn status(&self, scope: &str, message: &str);
-    fn provider_status(&self, provider: &str, status: &str, extra: Option<&str>);
+    /// provider 진행 상태(`running`/`done`/`error`). 끝난 경우 소요 시간(초)을 함께 넘긴다.
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>);
+    /// provider 응답 본문과 토큰 사용량(1차 리뷰/교차 반응/통합 리뷰). 기본 구현은 무시한다.
+    fn provider_output(&self, _provider: &str, _body: &str, _usage: &TokenUsage) {}
     fn raw(&self, line: &str);
+    /// 최종 결과(게이트 판정, 요약 게시 위치, dry-run 요약 본문). `--quiet`에서도 출력한다.
+    fn outcome(&self, line: &str) {
+        self.raw(line);
+    }
+    /// 진단 정보(프롬프트 크기, VCS 호출 소요 시간). `--verbose`에서만 출력한다.
+    fn detail(&self, _scope: &str, _message: &str) {}
 }
@@ -2,16 +2,20 @@
 
 use anyhow::Result;
 
-use crate::application::ports::{VcsAuthKind, VcsAuthenticator};
+use crate::application::ports::{ConfigRepository, VcsAuthKind, VcsAuthenticator};
 
 /// gh/glab 등 OAuth 로그인을 수행한다.
 pub struct AuthVcsUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
     pub authenticator: &'a dyn VcsAuthenticator,
 }
 
 impl<'a> AuthVcsUseCase<'a> {
     pub fn execute(&self, kind: VcsAuthKind, host: &str) -> Result<()> {
-        self.authenticator.authenticate(kind, host)
+        // config는 호스트별 OAuth 애플리케이션/API base 조회 용도로만 사용한다.
+        let cfg = self.config_repo.load()?;
+        self.authenticator
+            .authenticate(kind, host, cfg.host_config(host))
     }
 }
 
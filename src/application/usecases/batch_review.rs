@@ -0,0 +1,127 @@
+//! 여러 PR/MR URL을 한 번에 리뷰하는 배치 유스케이스.
+
+use std::time::Instant;
+
+use futures::stream::{self, StreamExt};
+
+use crate::application::usecases::review_pr::ReviewPrUseCase;
+use crate::domain::review::RunOptions;
+
+/// 대상 하나의 배치 실행 결과 상태.
+#[derive(Debug, Clone)]
+pub enum BatchTargetStatus {
+    /// 리뷰 완료(또는 이미 리뷰된 SHA라 스킵)
+    Done,
+    /// 리뷰는 게시됐지만 `--fail-on` 게이트에 걸림
+    GateFailed(String),
+    /// 실행 오류
+    Failed(String),
+}
+
+impl BatchTargetStatus {
+    fn label(&self) -> &'static str {
+        match self {
+            Self::Done => "done",
+            Self::GateFailed(_) => "gate",
+            Self::Failed(_) => "failed",
+        }
+    }
+}
+
+/// 대상 하나의 배치 실행 결과.
+#[derive(Debug, Clone)]
+pub struct BatchTargetResult {
+    pub url: String,
+    pub status: BatchTargetStatus,
+    pub elapsed_secs: f32,
+}
+
+/// 배치 실행 전체 결과.
+#[derive(Debug, Clone, Default)]
+pub struct BatchReviewReport {
+    pub results: Vec<BatchTargetResult>,
+}
+
+impl BatchReviewReport {
+    /// 실행 오류가 난 대상이 있는지 확인한다.
+    pub fn has_failures(&self) -> bool {
+        self.results
+            .iter()
+            .any(|r| matches!(r.status, BatchTargetStatus::Failed(_)))
+    }
+
+    /// 심각도 게이트에 걸린 대상이 있는지 확인한다.
+    pub fn has_gate_failures(&self) -> bool {
+        self.results
+            .iter()
+            .any(|r| matches!(r.status, BatchTargetStatus::GateFailed(_)))
+    }
+}
+
+/// `ReviewPrUseCase`를 대상마다 순차(또는 `jobs`개 병렬) 실행하고 결과 표를 출력한다.
+pub struct BatchReviewUseCase<'a> {
+    pub review: ReviewPrUseCase<'a>,
+}
+
+impl<'a> BatchReviewUseCase<'a> {
+    /// 배치 실행 진입점. 개별 대상 실패는 전체를 중단하지 않는다.
+    pub async fn execute(&self, targets: Vec<RunOptions>, jobs: usize) -> BatchReviewReport {
+        let total = targets.len();
+        let results: Vec<BatchTargetResult> = stream::iter(targets.into_iter().enumerate())
+            .map(|(idx, options)| async move {
+                let url = options.url.clone();
+                self.review
+                    .reporter
+                    .section(&format!("Batch {}/{}: {}", idx + 1, total, url));
+                let started = Instant::now();
+                let status = match self.review.execute(options).await {
+                    Ok(outcome) => match outcome.gate {
+                        Some(gate) => BatchTargetStatus::GateFailed(gate.to_string()),
+                        None => BatchTargetStatus::Done,
+                    },
+                    Err(err) => BatchTargetStatus::Failed(format!("{err:#}")),
+                };
+                BatchTargetResult {
+                    url,
+                    status,
+                    elapsed_secs: started.elapsed().as_secs_f32(),
+                }
+            })
+            .buffered(jobs.max(1))
+            .collect()
+            .await;
+
+        let report = BatchReviewReport { results };
+        self.report_table(&report);
+        report
+    }
+
+    fn report_table(&self, report: &BatchReviewReport) {
+        let reporter = self.review.reporter;
+        reporter.section("Batch Results");
+        let url_width = report
+            .results
+            .iter()
+            .map(|r| r.url.len())
+            .max()
+            .unwrap_or(3)
+            .max(3);
+        reporter.outcome(&format!(
+            "{:<url_width$}  {:<6}  {:>7}  Detail",
+            "URL", "Status", "Time"
+        ));
+        for result in &report.results {
+            let detail = match &result.status {
+                BatchTargetStatus::Done => "",
+                BatchTargetStatus::GateFailed(msg) | BatchTargetStatus::Failed(msg) => msg,
+            };
+            reporter.outcome(&format!(
+                "{:<url_width$}  {:<6}  {:>6.1}s  {}",
+                result.url,
+                result.status.label(),
+                result.elapsed_secs,
+                detail.lines().next().unwrap_or_default()
+            ));
+        }
+    }
+}
@@ -1,10 +1,20 @@
 //! 최신 버전 확인 유스케이스.
+//!
+//! `defaults.update_check_url`이 있으면 그 endpoint를, 없으면 `defaults.update_repository`
+//! (기본: RepoPilot 공식 저장소)의 GitHub/GitLab 최신 릴리스를 조회한다.
+//! 결과는 상태 디렉터리에 기록해 `defaults.update_check_interval_hours` 동안 다시 조회하지 않는다.
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use url::Url;
 
-use crate::application::ports::{ConfigRepository, HostTokenResolver, UpdateChecker};
+use crate::application::attribution::{self, RequestAttribution};
+use crate::application::ports::{
+    Clock, ConfigRepository, HostTokenResolver, LatestVersionInfo, UpdateCheckCache,
+    UpdateCheckRecord, UpdateChecker,
+};
 use crate::application::config::Config;
+use crate::domain::release::is_newer_version;
+use crate::domain::target::RepositoryRef;
 
 /// 업데이트 안내 메시지 생성용 데이터.
 #[derive(Debug, Clone)]
@@ -19,48 +29,114 @@ pub struct CheckUpdateUseCase<'a> {
     pub config_repo: &'a dyn ConfigRepository,
     pub host_token_resolver: &'a dyn HostTokenResolver,
     pub update_checker: &'a dyn UpdateChecker,
+    pub cache: &'a dyn UpdateCheckCache,
+    pub clock: &'a dyn Clock,
 }
 
 impl<'a> CheckUpdateUseCase<'a> {
     /// 최신 버전이 있을 때만 안내 정보를 반환한다.
     /// - 설정/네트워크 오류는 사용자 실행 흐름을 막지 않기 위해 조용히 무시한다.
+    /// - `defaults.update_check = "off"`이면 조회하지 않는다.
     pub async fn execute(&self) -> Result<Option<UpdateNotice>> {
         let config = match self.config_repo.load() {
             Ok(cfg) => cfg,
             Err(_) => return Ok(None),
         };
-
-        let Some(check_url) = config.defaults.update_check_url.as_deref() else {
+        if !config.update_check_enabled() {
             return Ok(None);
+        }
+
+        let source = update_source(&config);
+        let now = self.clock.unix_now();
+        let interval_secs = config.update_check_interval_hours().saturating_mul(3600);
+        let cached = self.cache.load().filter(|record| {
+            record.source == source
+                && record.checked_at <= now
+                && now - record.checked_at < interval_secs
+        });
+        let record = match cached {
+            Some(record) => record,
+            None => {
+                let timeout_ms = config.defaults.update_timeout_ms.unwrap_or(1200);
+                let token = update_token(&config, self.host_token_resolver);
+                // 조회 실패도 기록해 오프라인 환경에서 매 실행마다 타임아웃을 기다리지 않게 한다.
+                let lookup =
+                    fetch_latest(&config, self.update_checker, token.as_deref(), timeout_ms);
+                let latest = attribution::scope(RequestAttribution::for_config(&config), lookup)
+                    .await
+                    .ok()
+                    .flatten();
+                let record = UpdateCheckRecord {
+                    checked_at: now,
+                    source,
+                    latest_version: latest.as_ref().map(|latest| latest.version.clone()),
+                    download_url: latest.and_then(|latest| latest.download_url),
+                };
+                let _ = self.cache.save(&record);
+                record
+            }
         };
 
-        let timeout_ms = config.defaults.update_timeout_ms.unwrap_or(1200);
-        let host_token = resolve_host_token(&config, check_url, self.host_token_resolver);
-        let Some(latest) = self
-            .update_checker
-            .fetch_latest(check_url, host_token.as_deref(), timeout_ms)
-            .await?
-        else {
+        let Some(latest_version) = record.latest_version else {
             return Ok(None);
         };
-
         let current = env!("CARGO_PKG_VERSION");
-        if !is_newer_version(current, &latest.version) {
+        if !is_newer_version(current, &latest_version) {
             return Ok(None);
         }
 
-        let download_url = latest
+        let download_url = record
             .download_url
             .or_else(|| config.defaults.update_download_url.clone());
 
         Ok(Some(UpdateNotice {
             current_version: current.to_string(),
-            latest_version: latest.version,
+            latest_version,
             download_url,
         }))
     }
 }
 
+/// 설정에 맞는 곳(사용자 endpoint 또는 저장소 최신 릴리스)에서 최신 버전을 조회한다.
+pub(crate) async fn fetch_latest(
+    config: &Config,
+    checker: &dyn UpdateChecker,
+    token: Option<&str>,
+    timeout_ms: u64,
+) -> Result<Option<LatestVersionInfo>> {
+    if let Some(check_url) = config.defaults.update_check_url.as_deref() {
+        return checker.fetch_latest(check_url, token, timeout_ms).await;
+    }
+
+    let raw = config.update_repository();
+    let Some(repository) = RepositoryRef::from_remote_url(raw) else {
+        bail!("invalid defaults.update_repository '{raw}' (expected https://host/owner/repo)");
+    };
+    let host_cfg = config.host_config(repository.host());
+    checker
+        .fetch_latest_release(&repository, host_cfg, token, timeout_ms)
+        .await
+}
+
+/// 캐시 기록을 구분하는 조회 대상(endpoint URL 또는 저장소 URL).
+fn update_source(config: &Config) -> String {
+    config
+        .defaults
+        .update_check_url
+        .clone()
+        .unwrap_or_else(|| config.update_repository().to_string())
+}
+
+/// 업데이트 조회/다운로드에 쓸 호스트 토큰.
+/// 공개 저장소인 기본 릴리스 저장소는 시작할 때마다 토큰 명령을 실행하지 않도록 익명으로 조회한다.
+pub(crate) fn update_token(config: &Config, resolver: &dyn HostTokenResolver) -> Option<String> {
+    if let Some(check_url) = config.defaults.update_check_url.as_deref() {
+        return resolve_host_token(config, check_url, resolver);
+    }
+    let repository = config.defaults.update_repository.as_deref()?;
+    resolve_host_token(config, repository, resolver)
+}
+
 fn resolve_host_token(
     config: &Config,
     raw_url: &str,
@@ -71,56 +147,3 @@ fn resolve_host_token(
     let host_cfg = config.host_config(host);
     resolver.resolve(host, host_cfg).ok()?.token
 }
-
-fn is_newer_version(current: &str, latest: &str) -> bool {
-    let Some(current_parts) = parse_version_parts(current) else {
-        return false;
-    };
-    let Some(latest_parts) = parse_version_parts(latest) else {
-        return false;
-    };
-
-    let len = current_parts.len().max(latest_parts.len());
-    for idx in 0..len {
-        let left = *current_parts.get(idx).unwrap_or(&0);
-        let right = *latest_parts.get(idx).unwrap_or(&0);
-        if right > left {
-            return true;
-        }
-        if right < left {
-            return false;
-        }
-    }
-
-    false
-}
-
-fn parse_version_parts(raw: &str) -> Option<Vec<u64>> {
-    let s = raw.trim().trim_start_matches('v');
-    let start = s.find(|c: char| c.is_ascii_digit())?;
-    let mut normalized = String::new();
-    for ch in s[start..].chars() {
-        if ch.is_ascii_digit() || ch == '.' {
-            normalized.push(ch);
-        } else {
-            break;
-        }
-    }
-
-    if normalized.is_empty() {
-        return None;
-    }
-
-    let mut out = Vec::new();
-    for part in normalized.split('.') {
-        if part.is_empty() {
-            continue;
-        }
-        let Ok(v) = part.parse::<u64>() else {
-            return None;
-        };
-        out.push(v);
-    }
-
-    if out.is_empty() { None } else { Some(out) }
-}
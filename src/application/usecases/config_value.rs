@@ -0,0 +1,31 @@
+//! 설정 키 단위 조회/수정 유스케이스(`config get`/`config set`).
+
+use std::path::PathBuf;
+
+use anyhow::Result;
+use serde_json::Value;
+
+use crate::application::ports::ConfigRepository;
+
+/// 점 표기 키로 설정 값을 읽고 쓴다.
+pub struct ConfigValueUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+}
+
+impl<'a> ConfigValueUseCase<'a> {
+    /// 병합된 설정 값을 반환한다(미설정이면 None). 토큰/API 키 값은 가려진다.
+    pub fn get(&self, key: &str) -> Result<Option<Value>> {
+        self.config_repo.get_value(key, false)
+    }
+
+    /// 토큰/API 키 값을 가리지 않고 병합된 설정 값을 반환한다.
+    pub fn get_with_secrets(&self, key: &str) -> Result<Option<Value>> {
+        self.config_repo.get_value(key, true)
+    }
+
+    /// 편집 대상 설정 파일에 값을 기록하고 파일 경로를 반환한다.
+    /// `force`이면 주석이 사라지는 파일(YAML)도 다시 쓴다.
+    pub fn set(&self, key: &str, value: &str, force: bool) -> Result<PathBuf> {
+        self.config_repo.set_value(key, value, force)
+    }
+}
@@ -1,14 +1,15 @@
-//! 설정 파일 편집 경로를 반환하는 유스케이스.
+//! 설정 파일 편집 경로를 반환하고 편집기로 여는 유스케이스.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
 
-use crate::application::ports::ConfigRepository;
+use crate::application::ports::{ConfigRepository, EditorLauncher};
 
-/// 편집 대상 설정 파일 경로를 조회한다.
+/// 편집 대상 설정 파일 경로를 조회하고 편집기로 연다.
 pub struct EditConfigUseCase<'a> {
     pub config_repo: &'a dyn ConfigRepository,
+    pub editor: &'a dyn EditorLauncher,
 }
 
 impl<'a> EditConfigUseCase<'a> {
@@ -16,4 +17,9 @@ impl<'a> EditConfigUseCase<'a> {
     pub fn execute(&self) -> Result<PathBuf> {
         self.config_repo.editable_config_path()
     }
+
+    /// 설정 파일을 편집기로 열고 끝날 때까지 기다린다.
+    pub fn open_in_editor(&self, path: &Path) -> Result<()> {
+        self.editor.open(path)
+    }
 }
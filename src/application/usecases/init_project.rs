@@ -0,0 +1,105 @@
+//! 프로젝트 설정 초기화 유스케이스(`repopilot init`).
+//!
+//! 선택한 호스트/provider로 `.repopilot/config.json`과 리뷰 가이드를 만들고,
+//! 생성한 설정으로 호스트 토큰을 해석해 현재 저장소 원격에 실제 API 호출로 확인한다.
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::application::ports::{
+    ConfigRepository, GitRemoteReader, HostTokenResolver, InitializedProject, ProjectInit,
+    VcsAuthKind, VcsFactory,
+};
+use crate::domain::target::RepositoryRef;
+
+// 토큰 확인용 API 호출 제한 시간
+const CHECK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 프로젝트 설정을 생성하고 자격 증명을 확인한다.
+pub struct InitProjectUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+    pub host_token_resolver: &'a dyn HostTokenResolver,
+    pub vcs_factory: &'a dyn VcsFactory,
+    pub git_remotes: &'a dyn GitRemoteReader,
+}
+
+/// 호스트 토큰 확인 결과.
+#[derive(Debug, Clone)]
+pub struct HostCheck {
+    pub host: String,
+    /// 토큰 출처(해석 실패/미설정이면 None)
+    pub token_source: Option<String>,
+    /// 현재 저장소 원격으로 호출한 API 결과(이 호스트의 원격이 없거나 토큰이 없으면 None)
+    pub api: Option<Result<(), String>>,
+}
+
+impl<'a> InitProjectUseCase<'a> {
+    /// 현재 저장소 git 원격에서 찾은 호스트(질문 기본값).
+    pub fn detected_hosts(&self) -> Vec<(String, VcsAuthKind)> {
+        let mut hosts: Vec<(String, VcsAuthKind)> = Vec::new();
+        for repo in self.remote_repositories() {
+            let kind = match repo {
+                RepositoryRef::GitHub { .. } => VcsAuthKind::GitHub,
+                RepositoryRef::GitLab { .. } => VcsAuthKind::GitLab,
+            };
+            if !hosts.iter().any(|(host, _)| host == repo.host()) {
+                hosts.push((repo.host().to_string(), kind));
+            }
+        }
+        hosts
+    }
+
+    /// 설정 파일과 리뷰 가이드를 생성한다.
+    pub fn execute(&self, init: &ProjectInit) -> Result<InitializedProject> {
+        self.config_repo.init_project(init)
+    }
+
+    /// 생성한 설정으로 호스트별 토큰을 해석하고 가능하면 API 호출로 확인한다.
+    pub async fn check_hosts(&self, hosts: &[(String, VcsAuthKind)]) -> Result<Vec<HostCheck>> {
+        let config = self.config_repo.load()?;
+        let repositories = self.remote_repositories();
+
+        let mut checks = Vec::new();
+        for (host, _) in hosts {
+            let host_cfg = config.host_config(host);
+            let resolution = self.host_token_resolver.resolve(host, host_cfg).ok();
+            let token_source = resolution
+                .as_ref()
+                .filter(|resolution| resolution.token.is_some())
+                .and_then(|resolution| resolution.source.clone());
+            let token = resolution.and_then(|resolution| resolution.token);
+
+            let api = match (repositories.iter().find(|repo| repo.host() == host), token) {
+                (Some(repo), Some(token)) => {
+                    let gateway = self
+                        .vcs_factory
+                        .build_repository(repo, host_cfg, Some(token));
+                    let result =
+                        tokio::time::timeout(CHECK_TIMEOUT, gateway.list_open_reviews(1)).await;
+                    Some(match result {
+                        Ok(Ok(_)) => Ok(()),
+                        Ok(Err(err)) => Err(format!("{err:#}")),
+                        Err(_) => Err("timed out".to_string()),
+                    })
+                }
+                _ => None,
+            };
+
+            checks.push(HostCheck {
+                host: host.clone(),
+                token_source,
+                api,
+            });
+        }
+        Ok(checks)
+    }
+
+    fn remote_repositories(&self) -> Vec<RepositoryRef> {
+        self.git_remotes
+            .remote_urls()
+            .iter()
+            .filter_map(|url| RepositoryRef::from_remote_url(url))
+            .collect()
+    }
+}
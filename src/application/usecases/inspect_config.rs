@@ -10,8 +10,13 @@ pub struct InspectConfigUseCase<'a> {
 }
 
 impl<'a> InspectConfigUseCase<'a> {
-    /// 설정 점검 결과 문자열을 생성한다.
+    /// 설정 점검 결과 문자열을 생성한다. 토큰/API 키 값은 가려진다.
     pub fn execute(&self) -> Result<String> {
-        self.config_repo.inspect_pretty_json()
+        self.config_repo.inspect_pretty_json(false)
+    }
+
+    /// 디버깅용으로 해석된 토큰/API 키 값을 그대로 포함한 점검 결과를 생성한다.
+    pub fn execute_with_secrets(&self) -> Result<String> {
+        self.config_repo.inspect_pretty_json(true)
     }
 }
@@ -0,0 +1,18 @@
+//! 설정 스키마 마이그레이션 유스케이스(`config migrate`).
+
+use anyhow::Result;
+
+use crate::application::ports::{ConfigMigration, ConfigRepository};
+
+/// 오래된 `config_version`의 설정 파일을 현재 스키마로 다시 쓴다.
+pub struct MigrateConfigUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+}
+
+impl<'a> MigrateConfigUseCase<'a> {
+    /// 다시 쓴 파일별 결과를 반환한다(이미 최신이면 빈 목록).
+    /// `force`이면 주석이 사라지는 파일(YAML)도 제자리에서 다시 쓴다.
+    pub fn execute(&self, force: bool) -> Result<Vec<ConfigMigration>> {
+        self.config_repo.migrate(force)
+    }
+}
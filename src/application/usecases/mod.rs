@@ -1,8 +1,18 @@
 //! 애플리케이션 유스케이스 모듈 진입점.
 
 pub mod check_update;
+pub mod config_value;
 pub mod auth_vcs;
 pub mod auth_provider;
+pub mod batch_review;
 pub mod edit_config;
+pub mod init_project;
 pub mod inspect_config;
+pub mod migrate_config;
 pub mod review_pr;
+pub mod run_stats;
+pub mod self_update;
+pub mod store_secret;
+pub mod suggest_targets;
+pub mod validate_config;
+pub mod watch_commands;
@@ -0,0 +1,305 @@
+//! `--autofix`: 합의 지적사항의 수정 패치를 provider 하나에 요청하고, HEAD 커밋에 적용되는지 검증하는 단계.
+//!
+//! 패치는 HEAD 커밋 기준 파일 내용과 함께 요청하고, 메모리 안에서 적용해 본 뒤 결과로 돌려준다
+//! (로컬 파일 기록은 인터페이스가 맡는다). `--autofix-push`면 HEAD 위에 패치를 커밋한
+//! `repopilot/fixes-<sha>` 브랜치를 만든다.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+
+use crate::application::ports::ProviderAgent;
+use crate::application::usecases::review_pr::ReviewPrUseCase;
+use crate::application::usecases::review_pr::context::ExecutionContext;
+use crate::application::usecases::review_pr::redact::{RedactionReport, SecretRedactor};
+use crate::domain::patch::{AppliedPatch, AutofixResult, apply_patch, extract_patch, parse_patch};
+use crate::domain::policy::{autofix_prompt_vars, finding_id};
+use crate::domain::review::{ConsensusFinding, RunOptions};
+
+/// 파일 위치가 있고 사람 확인이 필요한(확신도 낮은) 항목이 아닌 합의 지적사항의 수정 패치를 만든다.
+/// provider 호출/패치 검증/브랜치 생성 실패는 리뷰를 중단하지 않고 상태만 보고한다.
+pub(super) async fn run_autofix(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    consensus: &[ConsensusFinding],
+    low_confidence_below: f32,
+) -> Option<AutofixResult> {
+    if !options.autofix {
+        return None;
+    }
+
+    use_case.reporter.section("Autofix");
+    let candidates: Vec<&ConsensusFinding> = consensus
+        .iter()
+        .filter(|item| {
+            item.finding.file.is_some() && !item.finding.is_below_confidence(low_confidence_below)
+        })
+        .collect();
+    if candidates.is_empty() {
+        use_case
+            .reporter
+            .kv("Autofix", "skipped (no findings with a file location)");
+        return None;
+    }
+
+    let providers = use_case.provider_factory.build(&ctx.config);
+    let provider = select_provider(use_case, options, ctx, &providers)?;
+
+    let (files, findings) = match collect_head_files(use_case, ctx, &candidates).await {
+        Ok(collected) => collected,
+        Err(err) => {
+            use_case
+                .reporter
+                .status("Autofix", &format!("failed to read head files: {err:#}"));
+            return None;
+        }
+    };
+    if findings.is_empty() {
+        use_case
+            .reporter
+            .kv("Autofix", "skipped (no affected files can be sent)");
+        return None;
+    }
+    use_case
+        .reporter
+        .kv("Autofix Findings", &findings.len().to_string());
+
+    let template = match use_case
+        .system_prompt_resolver
+        .prompt_templates(&ctx.config)
+    {
+        Ok(templates) => templates.autofix,
+        Err(err) => {
+            use_case.reporter.status(
+                "Autofix",
+                &format!("failed to load prompt templates: {err:#}"),
+            );
+            return None;
+        }
+    };
+    let provider_name = provider.name().to_string();
+    let title = ctx
+        .review_inputs
+        .as_ref()
+        .map(|inputs| inputs.title.as_str())
+        .unwrap_or_default();
+    let language = ctx.config.comment_language();
+    let vars = autofix_prompt_vars(
+        ctx.target.url(),
+        &ctx.head_sha,
+        title,
+        &language,
+        &findings,
+        &files,
+    );
+    let prompt = use_case.templates.render(&template, &vars);
+    use_case
+        .reporter
+        .provider_status(&provider_name, "running", None);
+    let started = Instant::now();
+    let response = match prompt {
+        Ok(prompt) => provider.review_prompt(&prompt).await,
+        Err(err) => Err(err),
+    };
+    let response = match response {
+        Ok(response) => response,
+        Err(err) => {
+            let sec = started.elapsed().as_secs_f32();
+            use_case
+                .reporter
+                .provider_status(&provider_name, "error", Some(sec));
+            use_case
+                .reporter
+                .status("Autofix", &format!("patch generation failed: {err}"));
+            return None;
+        }
+    };
+    let sec = started.elapsed().as_secs_f32();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "done", Some(sec));
+
+    let Some(patch) = extract_patch(&response.content) else {
+        use_case.reporter.kv("Autofix", "no fixes proposed");
+        return None;
+    };
+    let applied = match validate_patch(ctx, &patch, &files).await {
+        Ok(applied) => applied,
+        Err(err) => {
+            use_case.reporter.status(
+                "Autofix",
+                &format!("patch does not apply to {}: {err:#}", ctx.head_sha),
+            );
+            return None;
+        }
+    };
+    let changed: Vec<String> = applied.files.iter().map(|file| file.path.clone()).collect();
+    use_case.reporter.kv("Autofix Files", &changed.join(", "));
+
+    let finding_ids: Vec<String> = findings
+        .iter()
+        .map(|item| finding_id(&item.finding))
+        .collect();
+    let mut branch_url = None;
+    if options.autofix_push {
+        let branch = format!("repopilot/fixes-{}", ctx.head_sha);
+        if options.dry_run {
+            use_case
+                .reporter
+                .kv("Autofix Branch", &format!("would push {branch}"));
+        } else {
+            let message = format!(
+                "Apply repopilot review fixes\n\nFindings: {}\nReview: {}",
+                finding_ids.join(", "),
+                ctx.target.url()
+            );
+            match ctx
+                .vcs
+                .push_branch(&branch, &ctx.head_sha, &message, &applied.files)
+                .await
+            {
+                Ok(url) => {
+                    use_case.reporter.kv("Autofix Branch", &url);
+                    branch_url = Some(url);
+                }
+                Err(err) => use_case
+                    .reporter
+                    .status("Autofix", &format!("failed to push {branch}: {err:#}")),
+            }
+        }
+    }
+
+    Some(AutofixResult {
+        provider_name,
+        finding_ids,
+        patch: applied.patch,
+        files: changed,
+        branch_url,
+    })
+}
+
+/// `defaults.autofix_provider`, 없으면 이번 실행에서 쓰는 첫 provider를 고른다.
+fn select_provider<'p>(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    providers: &'p [Box<dyn ProviderAgent>],
+) -> Option<&'p dyn ProviderAgent> {
+    let provider = match ctx.config.autofix_provider() {
+        Some(id) => providers.iter().find(|p| p.id() == id),
+        None => providers.iter().find(|p| {
+            options.providers.is_empty()
+                || options
+                    .providers
+                    .iter()
+                    .any(|name| ctx.config.resolve_provider_id(name).as_deref() == Some(p.id()))
+        }),
+    };
+    match provider {
+        Some(provider) => {
+            use_case.reporter.kv("Autofix Provider", provider.name());
+            Some(provider.as_ref())
+        }
+        None => {
+            let message = match ctx.config.autofix_provider() {
+                Some(id) => format!("provider '{id}' is not enabled; skipping autofix"),
+                None => "no provider enabled; skipping autofix".to_string(),
+            };
+            use_case.reporter.status("Autofix", &message);
+            None
+        }
+    }
+}
+
+/// 지적사항이 가리키는 파일을 HEAD 커밋 기준으로 읽는다. 없는 파일, 비밀값이 있는 파일,
+/// `max_diff_bytes`를 넘기는 파일은 보내지 않고 그 파일의 지적사항도 뺀다.
+async fn collect_head_files(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    candidates: &[&ConsensusFinding],
+) -> Result<(Vec<(String, String)>, Vec<ConsensusFinding>)> {
+    let redactor = SecretRedactor::from_config(&ctx.config)?;
+    let max_bytes = ctx.config.max_diff_bytes();
+    let mut total = 0;
+    let mut files: Vec<(String, String)> = Vec::new();
+    let mut skipped: Vec<String> = Vec::new();
+    for item in candidates {
+        let Some(path) = item.finding.file.as_deref() else {
+            continue;
+        };
+        if files.iter().any(|(p, _)| p == path) || skipped.iter().any(|p| p == path) {
+            continue;
+        }
+        let content = ctx.vcs.fetch_file_at(path, &ctx.head_sha).await?;
+        let reason = match &content {
+            None => Some("not found at head"),
+            Some(content) if total + content.len() > max_bytes => Some("exceeds max_diff_bytes"),
+            Some(content)
+                if redactor.as_ref().is_some_and(|redactor| {
+                    matches!(
+                        redactor.redact(content, path, &mut RedactionReport::default()),
+                        Cow::Owned(_)
+                    )
+                }) =>
+            {
+                Some("contains secrets")
+            }
+            Some(_) => None,
+        };
+        match (reason, content) {
+            (None, Some(content)) => {
+                total += content.len();
+                files.push((path.to_string(), content));
+            }
+            (reason, _) => {
+                use_case.reporter.status(
+                    "Autofix",
+                    &format!("skipping {path} ({})", reason.unwrap_or_default()),
+                );
+                skipped.push(path.to_string());
+            }
+        }
+    }
+
+    let findings = candidates
+        .iter()
+        .filter(|item| {
+            item.finding
+                .file
+                .as_deref()
+                .is_some_and(|path| files.iter().any(|(p, _)| p == path))
+        })
+        .map(|item| (*item).clone())
+        .collect();
+    Ok((files, findings))
+}
+
+/// 패치를 HEAD 커밋 기준 파일에 적용해 본다. 프롬프트에 보내지 않은 파일은 HEAD에서 더 읽는다.
+/// 결과 패치는 provider가 쓴 줄 번호 대신 실제로 일치한 위치를 쓴다.
+async fn validate_patch(
+    ctx: &ExecutionContext,
+    patch: &str,
+    files: &[(String, String)],
+) -> Result<AppliedPatch> {
+    let patches = parse_patch(patch)?;
+    let mut originals: BTreeMap<String, String> = files.iter().cloned().collect();
+    for file_patch in &patches {
+        for path in file_patch.touched_paths() {
+            if originals.contains_key(path) {
+                continue;
+            }
+            if let Some(content) = ctx
+                .vcs
+                .fetch_file_at(path, &ctx.head_sha)
+                .await
+                .with_context(|| format!("failed to read {path}"))?
+            {
+                originals.insert(path.to_string(), content);
+            }
+        }
+    }
+    apply_patch(&patches, &originals)
+}
@@ -0,0 +1,73 @@
+//! 교차 반응/통합 리뷰 응답 캐시(`defaults.response_cache_ttl_hours`).
+//!
+//! 두 단계의 프롬프트는 1차 리뷰 결과(와 이전 라운드 반응)로만 만들어지므로, `--force` 재실행에서
+//! 1차 리뷰 결과가 같으면 프롬프트도 같다. provider/모델/프롬프트 해시가 같은 응답이 유효 시간 안에
+//! 있으면 provider를 다시 호출하지 않고 쓴다.
+
+use crate::application::config::Config;
+use crate::application::ports::CachedResponse;
+use crate::application::usecases::review_pr::ReviewPrUseCase;
+use ring::digest::{SHA256, digest};
+
+/// provider/모델/프롬프트로 만든 캐시 키. 서로 다른 프롬프트가 같은 키로 묶이면 다른 PR의 응답을
+/// 재사용하게 되므로 64bit 해시가 아니라 SHA-256 다이제스트를 쓴다.
+pub(super) fn response_cache_key(config: &Config, provider_id: &str, prompt: &str) -> String {
+    let model = config.provider_model(provider_id).unwrap_or_default();
+    let hashed = digest(
+        &SHA256,
+        format!("{provider_id}\n{model}\n{prompt}").as_bytes(),
+    );
+    let hex: String = hashed
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("{provider_id}-{hex}")
+}
+
+/// 유효 시간 안의 캐시된 응답 본문. 캐시를 끈 경우와 조회 실패는 캐시가 없는 것으로 본다.
+pub(super) fn load_cached_response(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    key: &str,
+) -> Option<String> {
+    let ttl_secs = config.response_cache_ttl_hours().saturating_mul(3600);
+    if ttl_secs == 0 {
+        return None;
+    }
+    match use_case.response_cache.load(key) {
+        Ok(Some(cached))
+            if use_case.clock.unix_now().saturating_sub(cached.saved_at) < ttl_secs =>
+        {
+            Some(cached.content)
+        }
+        Ok(_) => None,
+        Err(err) => {
+            use_case
+                .reporter
+                .detail("Cache", &format!("failed to read response cache: {err:#}"));
+            None
+        }
+    }
+}
+
+/// 응답 본문을 캐시에 남긴다. 실패해도 리뷰는 계속한다.
+pub(super) fn save_cached_response(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    key: &str,
+    content: &str,
+) {
+    if config.response_cache_ttl_hours() == 0 {
+        return;
+    }
+    let response = CachedResponse {
+        saved_at: use_case.clock.unix_now(),
+        content: content.to_string(),
+    };
+    if let Err(err) = use_case.response_cache.save(key, &response) {
+        use_case
+            .reporter
+            .detail("Cache", &format!("failed to write response cache: {err:#}"));
+    }
+}
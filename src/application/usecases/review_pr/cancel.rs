@@ -0,0 +1,46 @@
+//! 리뷰 실행 취소 처리.
+
+use std::fmt;
+use std::future::Future;
+
+use anyhow::Result;
+use tokio_util::sync::CancellationToken;
+
+/// 사용자가 리뷰를 취소했음을 나타내는 에러.
+/// 인터페이스 계층은 이를 실패가 아닌 취소로 구분해 안내한다.
+#[derive(Debug, Clone)]
+pub struct ReviewCancelled {
+    /// 취소 시점에 현재 SHA의 claim 코멘트가 이미 게시되어 있었는지 여부
+    pub claim_posted: bool,
+}
+
+impl fmt::Display for ReviewCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.claim_posted {
+            write!(
+                f,
+                "review cancelled; the claim comment for this SHA was left in place, rerun with --force to review again"
+            )
+        } else {
+            write!(f, "review cancelled")
+        }
+    }
+}
+
+impl std::error::Error for ReviewCancelled {}
+
+/// 취소되면 진행 중인 future를 버리고(하위 HTTP 요청/자식 프로세스 포함) `ReviewCancelled`를 반환한다.
+pub(super) async fn cancellable<T>(
+    cancel: &CancellationToken,
+    claim_comment_id: Option<&str>,
+    future: impl Future<Output = Result<T>>,
+) -> Result<T> {
+    tokio::select! {
+        biased;
+        _ = cancel.cancelled() => Err(ReviewCancelled {
+            claim_posted: claim_comment_id.is_some(),
+        }
+        .into()),
+        result = future => result,
+    }
+}
@@ -1,14 +1,20 @@
 //! 리뷰 실행 컨텍스트(설정/대상/VCS 상태) 준비 단계.
 
+use std::collections::BTreeMap;
 use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::{Context, Result, bail};
 
+use crate::application::attribution;
 use crate::application::ports::VcsGateway;
 use crate::application::usecases::review_pr::ReviewPrUseCase;
 use crate::application::config::{Config, ProviderConfig};
+use crate::domain::marker::collect_issue_links;
 use crate::domain::review::{ReviewComment, RunOptions};
 use crate::domain::target::ReviewTarget;
+use crate::domain::template::CommentTemplates;
 
 /// 리뷰 유스케이스 전 구간에서 공유되는 실행 상태.
 pub(super) struct ExecutionContext {
@@ -17,15 +23,37 @@ pub(super) struct ExecutionContext {
     pub vcs: Box<dyn VcsGateway>,
     pub head_sha: String,
     pub existing_comments: Vec<ReviewComment>,
+    /// 지적사항 id → 후속 이슈 URL. 같은 SHA를 다시 리뷰하면 claim이 이전 요약을 덮어쓰므로 미리 모아 둔다.
+    pub linked_issues: BTreeMap<String, String>,
+    /// 리뷰 요청 구성에 쓰는 제목/라벨/diff. 게시만 다시 하는 `resume`에서는 조회하지 않는다.
+    pub review_inputs: Option<ReviewInputs>,
+    /// 이번 실행이 claim 코멘트에 쓴 nonce. 최종 요약 게시 전에 claim을 아직 갖고 있는지 확인한다.
+    pub claim_nonce: Option<String>,
+    /// claim/에이전트/최종 요약 코멘트 본문 템플릿(`comment_template_dir` 재정의 반영)
+    pub comment_templates: CommentTemplates,
+    /// 컨텍스트 준비를 시작한 시각. 최종 요약 footer의 소요 시간 기준
+    pub started: Instant,
+}
+
+/// HEAD SHA와 함께 미리 받아 둔 리뷰 대상 제목, 라벨과 원본 diff.
+pub(super) struct ReviewInputs {
+    /// 조회에 실패하면 빈 문자열(제목 없이 리뷰를 계속한다)
+    pub title: String,
+    /// 라벨로 판별하는 PR 유형 규칙이 없거나 조회에 실패하면 빈 목록
+    pub labels: Vec<String>,
+    pub diff: Arc<str>,
 }
 
 /// 설정 로딩, 대상 파싱, VCS 인증/HEAD SHA 조회까지 선행한다.
+/// HEAD SHA, 기존 코멘트, 제목/라벨/diff(`fetch_review_inputs`일 때)는 서로 독립이므로 동시에 조회한다.
 pub(super) async fn load_execution_context(
     use_case: &ReviewPrUseCase<'_>,
     options: &RunOptions,
+    fetch_review_inputs: bool,
 ) -> Result<ExecutionContext> {
+    let started = Instant::now();
     use_case.reporter.section("Load Config");
-    let config = use_case
+    let mut config = use_case
         .config_repo
         .load()
         .context("failed to load repopilot config")?;
@@ -35,6 +63,26 @@ pub(super) async fn load_execution_context(
         .parse(&options.url)
         .context("failed to parse target URL")?;
 
+    // 전역 < 저장소별 < 프로필 < 실행 단위 재정의(--model/--lang) 순으로 덮어쓴다.
+    if let Some(key) = config.apply_repo_overrides(&target.repository_key()) {
+        use_case.reporter.kv("Repo Override", &key);
+    }
+    if let Some(profile) = options
+        .profile
+        .as_deref()
+        .and_then(|name| config.profile(name))
+        .cloned()
+    {
+        config.defaults.merge_from(profile.defaults);
+    }
+    apply_run_overrides(use_case, &mut config, options)?;
+    attribution::set_user_agent(config.user_agent());
+    // 템플릿 파일 오류는 provider를 실행하기 전에 알린다.
+    let comment_templates = use_case
+        .system_prompt_resolver
+        .comment_templates(&config)
+        .context("failed to load comment templates")?;
+
     let host_cfg = config.host_config(target.host());
     let token_resolution = use_case
         .host_token_resolver
@@ -48,14 +96,17 @@ pub(super) async fn load_execution_context(
         use_case.reporter.kv("Host Token Source", source);
     }
 
-    if !options.dry_run && token.is_none() {
-        let host = target.host();
-        let auth_hint = match &target {
-            ReviewTarget::GitHub { .. } => format!("repopilot auth github --host {host}"),
-            ReviewTarget::GitLab { .. } => format!("repopilot auth gitlab --host {host}"),
-        };
+    let host = target.host();
+    let auth_hint = match &target {
+        ReviewTarget::GitHub { .. } => Some(format!("repopilot auth github --host {host}")),
+        ReviewTarget::GitLab { .. } => Some(format!("repopilot auth gitlab --host {host}")),
+        // mock 대상은 토큰 없이 fixture 상태 파일에 게시한다.
+        ReviewTarget::Mock { .. } => None,
+    };
+    if let Some(auth_hint) = auth_hint.filter(|_| !options.dry_run && token.is_none()) {
         bail!(
-            "missing VCS token for host '{}'. Configure hosts.{}.token / hosts.{}.token_env / hosts.{}.token_command (OAuth), run `{auth_hint}`, or use --dry-run",
+            "missing VCS token for host '{}'. Configure hosts.{}.token / hosts.{}.token_env / hosts.{}.token_keyring / hosts.{}.token_command (OAuth), run `{auth_hint}`, or use --dry-run",
+            target.host(),
             target.host(),
             target.host(),
             target.host(),
@@ -67,10 +118,81 @@ pub(super) async fn load_execution_context(
 
     use_case.reporter.section("Fetch Target");
     use_case.reporter.kv("Host", target.host());
-    use_case.reporter.status("VCS", "fetching head SHA");
-    let head_sha = match vcs.fetch_head_sha().await {
+    if token_resolved {
+        // provider 실행 전에 토큰/권한 문제를 게시 단계보다 먼저 정확한 이유로 알린다.
+        use_case.reporter.status("VCS", "checking token access");
+        let started = Instant::now();
+        if let Err(err) = vcs.check_token_access(!options.dry_run).await {
+            use_case
+                .reporter
+                .kv("Host Token Valid", "no (token access check failed)");
+            return Err(err);
+        }
+        use_case.reporter.detail(
+            "VCS",
+            &format!(
+                "token access checked in {:.2}s",
+                started.elapsed().as_secs_f32()
+            ),
+        );
+    }
+    use_case.reporter.status(
+        "VCS",
+        if fetch_review_inputs {
+            "fetching head SHA, comments, title and diff"
+        } else {
+            "fetching head SHA and comments"
+        },
+    );
+    // 라벨은 PR 유형 규칙 중 라벨로 판별하는 것이 있을 때만 조회한다.
+    let fetch_labels = fetch_review_inputs
+        && config
+            .pr_types()
+            .iter()
+            .any(|pr_type| !pr_type.labels.is_empty());
+    let (
+        (head_sha, head_sha_secs),
+        (comments, comments_secs),
+        (title, title_secs),
+        (labels, labels_secs),
+        (diff, diff_secs),
+    ) = futures::join!(
+        timed(vcs.fetch_head_sha()),
+        timed(async {
+            if options.dry_run {
+                None
+            } else {
+                Some(vcs.list_comments().await)
+            }
+        }),
+        timed(async {
+            if fetch_review_inputs {
+                Some(vcs.fetch_title().await)
+            } else {
+                None
+            }
+        }),
+        timed(async {
+            if fetch_labels {
+                Some(vcs.fetch_labels().await)
+            } else {
+                None
+            }
+        }),
+        timed(async {
+            if fetch_review_inputs {
+                Some(vcs.fetch_diff().await)
+            } else {
+                None
+            }
+        }),
+    );
+
+    let head_sha = match head_sha {
         Ok(sha) => {
-            use_case.reporter.kv("Host Token Valid", "yes (API access ok)");
+            use_case
+                .reporter
+                .kv("Host Token Valid", "yes (API access ok)");
             sha
         }
         Err(err) => {
@@ -87,11 +209,64 @@ pub(super) async fn load_execution_context(
         }
     };
     use_case.reporter.kv("Head SHA", &head_sha);
+    use_case
+        .reporter
+        .detail("VCS", &format!("head SHA fetched in {head_sha_secs:.2}s"));
 
-    let existing_comments = if options.dry_run {
-        Vec::new()
-    } else {
-        vcs.list_comments().await?
+    let existing_comments = match comments {
+        Some(comments) => {
+            let comments = comments?;
+            use_case.reporter.detail(
+                "VCS",
+                &format!("{} comments listed in {comments_secs:.2}s", comments.len()),
+            );
+            comments
+        }
+        None => Vec::new(),
+    };
+
+    let review_inputs = match (title, diff) {
+        (Some(title), Some(diff)) => {
+            let diff = diff?;
+            use_case
+                .reporter
+                .detail("VCS", &format!("diff fetched in {diff_secs:.2}s"));
+            let title = match title {
+                Ok(title) => {
+                    use_case
+                        .reporter
+                        .detail("VCS", &format!("title fetched in {title_secs:.2}s"));
+                    title
+                }
+                Err(err) => {
+                    use_case
+                        .reporter
+                        .status("VCS", &format!("failed to fetch title: {err:#}"));
+                    String::new()
+                }
+            };
+            let labels = match labels {
+                Some(Ok(labels)) => {
+                    use_case
+                        .reporter
+                        .detail("VCS", &format!("labels fetched in {labels_secs:.2}s"));
+                    labels
+                }
+                Some(Err(err)) => {
+                    use_case
+                        .reporter
+                        .status("VCS", &format!("failed to fetch labels: {err:#}"));
+                    Vec::new()
+                }
+                None => Vec::new(),
+            };
+            Some(ReviewInputs {
+                title,
+                labels,
+                diff: diff.into(),
+            })
+        }
+        _ => None,
     };
 
     Ok(ExecutionContext {
@@ -99,10 +274,97 @@ pub(super) async fn load_execution_context(
         target,
         vcs,
         head_sha,
+        linked_issues: collect_issue_links(&existing_comments),
         existing_comments,
+        review_inputs,
+        claim_nonce: None,
+        comment_templates,
+        started,
     })
 }
 
+/// future를 기다리며 걸린 시간(초)을 함께 돌려준다.
+async fn timed<T>(future: impl Future<Output = T>) -> (T, f32) {
+    let started = Instant::now();
+    let value = future.await;
+    (value, started.elapsed().as_secs_f32())
+}
+
+/// `--profile`의 실행 옵션(provider 목록, 모델, dry-run 기본값)을 반영한다.
+/// 명시한 `--providers`/`--model`이 프로필보다 우선하며, defaults 재정의는 `load_execution_context`에서 적용한다.
+pub(super) fn apply_profile_options(
+    use_case: &ReviewPrUseCase<'_>,
+    mut options: RunOptions,
+) -> Result<RunOptions> {
+    let Some(name) = options.profile.clone() else {
+        return Ok(options);
+    };
+    let config = use_case
+        .config_repo
+        .load()
+        .context("failed to load repopilot config")?;
+    let Some(profile) = config.profile(&name) else {
+        let available = config.profile_names();
+        bail!(
+            "unknown profile '{name}' (available: {})",
+            if available.is_empty() {
+                "none".to_string()
+            } else {
+                available.join(", ")
+            }
+        );
+    };
+
+    if profile.dry_run == Some(true) {
+        options.dry_run = true;
+    }
+    if options.providers.is_empty()
+        && let Some(providers) = &profile.providers
+    {
+        options.providers = providers.clone();
+    }
+    if let Some(models) = &profile.models {
+        // 나중에 적용된 값이 이기므로 프로필 모델을 앞에 둔다.
+        let mut model_overrides: Vec<(String, String)> = models
+            .iter()
+            .map(|(provider, model)| (provider.clone(), model.clone()))
+            .collect();
+        model_overrides.sort();
+        model_overrides.append(&mut options.model_overrides);
+        options.model_overrides = model_overrides;
+    }
+    use_case.reporter.kv("Profile", &name);
+    Ok(options)
+}
+
+/// `--model`/`--lang` 실행 단위 재정의를 설정에 반영한다(설정 파일은 수정하지 않음).
+fn apply_run_overrides(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &mut Config,
+    options: &RunOptions,
+) -> Result<()> {
+    for (provider, model) in &options.model_overrides {
+        let Some(id) = config.resolve_provider_id(provider) else {
+            bail!(
+                "unknown provider '{provider}' in --model (expected {})",
+                config.known_provider_ids().join(", ")
+            );
+        };
+        let Some(provider_cfg) = config.provider_config_mut(&id) else {
+            bail!("--model {provider}={model}: providers.{id} is not configured");
+        };
+        provider_cfg.model = Some(model.clone());
+        use_case
+            .reporter
+            .kv("Model Override", &format!("{id}={model}"));
+    }
+
+    if let Some(language) = &options.comment_language {
+        config.defaults.comment_language = Some(language.code().to_string());
+    }
+    Ok(())
+}
+
 fn render_status_dashboard(
     use_case: &ReviewPrUseCase<'_>,
     config: &Config,
@@ -117,6 +379,8 @@ fn render_status_dashboard(
         "Host Token",
         if token_resolved {
             "resolved"
+        } else if matches!(target, ReviewTarget::Mock { .. }) {
+            "not needed (mock target)"
         } else if dry_run {
             "missing (dry-run allows continue)"
         } else {
@@ -150,11 +414,18 @@ fn render_status_dashboard(
 }
 
 fn provider_lines(config: &Config) -> Vec<String> {
-    vec![
+    let mut lines = vec![
         provider_line("openai", config.providers.openai.as_ref(), "codex"),
         provider_line("anthropic", config.providers.anthropic.as_ref(), "claude"),
         provider_line("gemini", config.providers.gemini.as_ref(), "gemini"),
-    ]
+    ];
+    lines.extend(
+        config
+            .providers
+            .plugin_providers()
+            .map(|(name, cfg)| provider_line(name, Some(cfg), "-")),
+    );
+    lines
 }
 
 fn provider_line(
@@ -167,10 +438,15 @@ fn provider_line(
     };
 
     let enabled = cfg.is_enabled();
-    let has_api_hint = cfg.api_key.is_some() || cfg.api_key_env.is_some();
+    let has_api_hint =
+        cfg.api_key.is_some() || cfg.api_key_env.is_some() || cfg.api_key_keyring.is_some();
     let state = if enabled { "enabled" } else { "disabled" };
+    if cfg.is_mock() {
+        return format!("  - {id:<10} {state:<8} mode=mock");
+    }
+    let cli_mode = if cfg.is_plugin() { "plugin" } else { "cli" };
 
-    if has_api_hint {
+    if has_api_hint && !cfg.is_plugin() {
         let model = cfg
             .model
             .as_deref()
@@ -186,10 +462,10 @@ fn provider_line(
         .unwrap_or(default_command);
     let args = cfg.args.clone().unwrap_or_default().join(" ");
     if args.is_empty() {
-        format!("  - {id:<10} {state:<8} mode=cli cmd={command}")
+        format!("  - {id:<10} {state:<8} mode={cli_mode} cmd={command}")
     } else {
         format!(
-            "  - {id:<10} {state:<8} mode=cli cmd={} {}",
+            "  - {id:<10} {state:<8} mode={cli_mode} cmd={} {}",
             command, args
         )
     }
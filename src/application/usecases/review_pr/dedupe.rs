@@ -1,12 +1,23 @@
 //! SHA 기반 중복 방지와 claim 코멘트 처리 단계.
+//!
+//! 같은 PR을 여러 CI 작업이 동시에 리뷰할 수 있으므로 claim은 낙관적 동시성으로 잡는다.
+//! - claim을 새로 만들기 직전에 코멘트를 다시 읽어 그 사이 생긴 claim/최종 코멘트가 있으면 물러난다.
+//! - claim 마커에 실행기마다 다른 nonce를 넣고, 게시 후 다시 읽어 먼저 생성된 claim이 자신의 것이
+//!   아니면 자신의 claim을 철회하고 물러난다.
+//!
+//! 기존 코멘트 재사용 범위는 `defaults.dedupe_policy`를 따른다.
+//! - `per_sha`: 같은 SHA의 claim/최종 코멘트만 재사용한다.
+//! - `single`: 다른 SHA의 요약 코멘트도 새 claim으로 갱신해 PR/MR마다 코멘트 하나를 유지한다.
+//! - `always_new`: 중복 검사 없이 실행마다 새 claim 코멘트를 만든다.
 
 use anyhow::Result;
 
 use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
-use crate::domain::policy::{
-    find_comment_with_marker, markers_for_sha, upsert_comment_cache,
+use crate::domain::marker::{
+    find_claim_comment, find_final_comment, find_latest_summary_comment, parse_marker,
 };
-use crate::domain::review::RunOptions;
+use crate::domain::policy::{is_claim_stale, upsert_comment_cache};
+use crate::domain::review::{DedupePolicy, RunOptions};
 
 /// claim 단계의 판단 결과.
 pub(super) enum ClaimDecision {
@@ -26,41 +37,183 @@ pub(super) async fn prepare_claim_comment(
         });
     }
 
-    let markers = markers_for_sha(&ctx.head_sha);
-    let final_comment = find_comment_with_marker(&ctx.existing_comments, &markers.final_marker);
-    let claim_comment = find_comment_with_marker(&ctx.existing_comments, &markers.claim_marker);
+    let policy = ctx.config.dedupe_policy();
+    let now = use_case.clock.unix_now();
+    let nonce = use_case.clock.random_id();
+    let claim_markdown = use_case.renderer.render_claim(
+        &ctx.head_sha,
+        ctx.target.url(),
+        now,
+        &nonce,
+        &ctx.comment_templates.claim,
+    )?;
 
-    if !options.force && (final_comment.is_some() || claim_comment.is_some()) {
-        use_case
-            .reporter
-            .status("Dedup", "already claimed/reviewed for current SHA; skipping");
+    if !policy.skips_reviewed_sha() {
+        let created = ctx.vcs.create_comment(&claim_markdown).await?;
+        let id = created.id.clone();
+        upsert_comment_cache(&mut ctx.existing_comments, created);
+        use_case.reporter.status("Claim", "created claim comment");
+        ctx.claim_nonce = Some(nonce);
+        return Ok(ClaimDecision::Continue {
+            claim_comment_id: Some(id),
+        });
+    }
+
+    // 이전 버전 형식의 마커도 같은 SHA면 같은 리뷰로 본다.
+    let final_comment = find_final_comment(&ctx.existing_comments, &ctx.head_sha);
+    let claim = find_claim_comment(&ctx.existing_comments, &ctx.head_sha);
+    let claim_comment = claim.as_ref().map(|(c, _)| *c);
+
+    let stale_claim = final_comment.is_none()
+        && claim.as_ref().is_some_and(|(_, marker)| {
+            is_claim_stale(marker.claimed_at, now, ctx.config.claim_ttl_minutes())
+        });
+
+    if !options.force && !stale_claim && (final_comment.is_some() || claim_comment.is_some()) {
+        use_case.reporter.outcome(&format!(
+            "skipped: {} is already claimed/reviewed for {} (use --force to re-run)",
+            ctx.target.url(),
+            ctx.head_sha
+        ));
         return Ok(ClaimDecision::Skip);
     }
+    if stale_claim {
+        use_case.reporter.status(
+            "Dedup",
+            "existing claim is older than claim_ttl_minutes; reclaiming",
+        );
+    }
 
     let chosen_comment_id = claim_comment
         .or(if options.force { final_comment } else { None })
+        .or_else(|| match policy {
+            DedupePolicy::Single => find_latest_summary_comment(&ctx.existing_comments),
+            _ => None,
+        })
         .map(|c| c.id.clone());
 
-    let claim_markdown = use_case
-        .renderer
-        .render_claim(&ctx.head_sha, ctx.target.url());
-
-    if let Some(comment_id) = chosen_comment_id {
+    let claim_comment_id = if let Some(comment_id) = chosen_comment_id {
         let updated = ctx.vcs.update_comment(&comment_id, &claim_markdown).await?;
         upsert_comment_cache(&mut ctx.existing_comments, updated);
         use_case
             .reporter
             .status("Claim", "updated existing claim comment");
-        Ok(ClaimDecision::Continue {
-            claim_comment_id: Some(comment_id),
-        })
+        comment_id
     } else {
+        // 처음 조회한 뒤 다른 실행기가 claim/리뷰했을 수 있으므로 생성 직전에 다시 확인한다.
+        ctx.existing_comments = ctx.vcs.list_comments().await?;
+        let claimed = find_final_comment(&ctx.existing_comments, &ctx.head_sha).is_some()
+            || find_claim_comment(&ctx.existing_comments, &ctx.head_sha).is_some();
+        if claimed && !options.force {
+            use_case.reporter.outcome(&format!(
+                "skipped: another runner claimed {} for {} (use --force to re-run)",
+                ctx.target.url(),
+                ctx.head_sha
+            ));
+            return Ok(ClaimDecision::Skip);
+        }
         let created = ctx.vcs.create_comment(&claim_markdown).await?;
         let id = created.id.clone();
         upsert_comment_cache(&mut ctx.existing_comments, created);
         use_case.reporter.status("Claim", "created claim comment");
-        Ok(ClaimDecision::Continue {
-            claim_comment_id: Some(id),
-        })
+        id
+    };
+
+    if !confirm_claim(use_case, ctx, &claim_comment_id, &nonce).await? {
+        return Ok(ClaimDecision::Skip);
     }
+    ctx.claim_nonce = Some(nonce);
+    Ok(ClaimDecision::Continue {
+        claim_comment_id: Some(claim_comment_id),
+    })
+}
+
+/// 게시한 claim이 유효한지 코멘트를 다시 읽어 확인한다.
+/// 다른 실행기의 claim이 먼저 생성됐으면 자신이 만든 claim 코멘트를 철회하고 false를 돌려준다.
+async fn confirm_claim(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &mut ExecutionContext,
+    claim_comment_id: &str,
+    nonce: &str,
+) -> Result<bool> {
+    ctx.existing_comments = ctx.vcs.list_comments().await?;
+    let Some((winner, marker)) = find_claim_comment(&ctx.existing_comments, &ctx.head_sha) else {
+        // 목록에 아직 반영되지 않았으면 자신의 claim으로 진행한다.
+        return Ok(true);
+    };
+    if winner.id == claim_comment_id && marker.nonce.as_deref() == Some(nonce) {
+        return Ok(true);
+    }
+
+    // 같은 코멘트를 다른 실행기가 덮어썼으면 그 실행기의 claim이므로 건드리지 않는다.
+    if winner.id != claim_comment_id {
+        let withdrawn = use_case
+            .renderer
+            .render_claim_withdrawn(&ctx.head_sha, ctx.target.url());
+        let updated = ctx.vcs.update_comment(claim_comment_id, &withdrawn).await?;
+        upsert_comment_cache(&mut ctx.existing_comments, updated);
+    }
+    use_case.reporter.status(
+        "Claim",
+        "another runner claimed this head SHA first; backing off",
+    );
+    use_case.reporter.outcome(&format!(
+        "skipped: {} is being reviewed by another runner for {}",
+        ctx.target.url(),
+        ctx.head_sha
+    ));
+    Ok(false)
+}
+
+/// 최종 요약으로 claim 코멘트를 덮어쓰기 전에, 그 사이 다른 실행기가 claim을 가져가지 않았는지 확인한다.
+/// 이번 실행에서 claim을 쓰지 않았으면(재사용한 claim) 확인하지 않는다.
+pub(super) async fn claim_still_owned(
+    ctx: &mut ExecutionContext,
+    claim_comment_id: &str,
+) -> Result<bool> {
+    let Some(nonce) = ctx.claim_nonce.as_deref() else {
+        return Ok(true);
+    };
+    let comments = ctx.vcs.list_comments().await?;
+    let current = comments
+        .iter()
+        .find(|c| c.id == claim_comment_id)
+        .and_then(|c| parse_marker(&c.body));
+    let owned = current.is_none_or(|marker| marker.nonce.as_deref() == Some(nonce));
+    ctx.existing_comments = comments;
+    Ok(owned)
+}
+
+/// 재게시(resume) 시 현재 SHA의 claim/최종 코멘트를 찾고, 없으면 claim 코멘트를 새로 만든다.
+pub(super) async fn find_or_create_claim_comment(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &mut ExecutionContext,
+) -> Result<Option<String>> {
+    if options.dry_run {
+        return Ok(None);
+    }
+
+    let existing = find_claim_comment(&ctx.existing_comments, &ctx.head_sha)
+        .map(|(c, _)| c)
+        .or_else(|| find_final_comment(&ctx.existing_comments, &ctx.head_sha));
+    if let Some(comment) = existing {
+        use_case.reporter.status("Claim", "reusing existing claim comment");
+        return Ok(Some(comment.id.clone()));
+    }
+
+    let nonce = use_case.clock.random_id();
+    let claim_markdown = use_case.renderer.render_claim(
+        &ctx.head_sha,
+        ctx.target.url(),
+        use_case.clock.unix_now(),
+        &nonce,
+        &ctx.comment_templates.claim,
+    )?;
+    let created = ctx.vcs.create_comment(&claim_markdown).await?;
+    let id = created.id.clone();
+    upsert_comment_cache(&mut ctx.existing_comments, created);
+    use_case.reporter.status("Claim", "created claim comment");
+    ctx.claim_nonce = Some(nonce);
+    Ok(Some(id))
 }
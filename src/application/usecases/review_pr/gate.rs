@@ -0,0 +1,72 @@
+//! 심각도 게이트(CI 차단) 판정 단계.
+
+use std::fmt;
+
+use super::ReviewPrUseCase;
+use super::context::ExecutionContext;
+use crate::domain::bot_command::{BotCommand, CommandPolicy, parse_bot_command};
+use crate::domain::policy::count_findings_at_or_above;
+use crate::domain::review::{ConsensusFinding, Severity};
+
+/// 임계 심각도 이상 지적사항이 있어 실행을 실패로 처리해야 함을 나타내는 판정 결과.
+/// `ReviewOutcome::gate`로 반환되며, 인터페이스 계층은 런타임 오류와 다른 종료 코드를 사용한다.
+#[derive(Debug, Clone)]
+pub struct SeverityGateError {
+    pub threshold: Severity,
+    pub count: usize,
+}
+
+impl fmt::Display for SeverityGateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "severity gate failed: {} finding(s) at or above '{}'",
+            self.count,
+            self.threshold.code()
+        )
+    }
+}
+
+impl std::error::Error for SeverityGateError {}
+
+/// 임계값이 설정되어 있고 해당 지적사항이 있으면 게이트 에러를 반환한다.
+pub(super) fn evaluate_severity_gate(
+    threshold: Option<Severity>,
+    consensus: &[ConsensusFinding],
+) -> Option<SeverityGateError> {
+    let threshold = threshold?;
+    let count = count_findings_at_or_above(consensus, threshold);
+    (count > 0).then_some(SeverityGateError { threshold, count })
+}
+
+/// PR 코멘트 명령에 적용할 정책. `/repopilot ignore`가 있을 때만 PR 작성자를 조회하며,
+/// 조회에 실패하면 작성자를 모르는 것으로 두어 허용 목록 사용자의 `ignore`만 게이트에 반영한다.
+pub(super) async fn command_policy(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+) -> CommandPolicy {
+    let has_ignore = ctx.existing_comments.iter().any(|comment| {
+        matches!(
+            parse_bot_command(&comment.body),
+            Some(BotCommand::Ignore { .. })
+        )
+    });
+    let pr_author = if has_ignore {
+        match ctx.vcs.fetch_author().await {
+            Ok(author) => Some(author),
+            Err(err) => {
+                use_case.reporter.status(
+                    "Ignore",
+                    &format!("failed to read PR author; only allowlisted ignores apply: {err:#}"),
+                );
+                None
+            }
+        }
+    } else {
+        None
+    };
+    CommandPolicy {
+        allowlist: ctx.config.command_allowlist(),
+        pr_author,
+    }
+}
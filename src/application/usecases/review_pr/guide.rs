@@ -0,0 +1,146 @@
+//! 대상 저장소에 버전 관리되는 리뷰 가이드와 경로별 가이드를 시스템 프롬프트에 합치는 단계.
+
+use anyhow::Result;
+
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::diff::path_matches;
+
+/// 가이드 파일 하나당 프롬프트에 넣는 최대 바이트.
+const MAX_GUIDE_BYTES: usize = 16 * 1024;
+
+/// `defaults.discover_review_guide`가 켜져 있으면 기본 브랜치의 가이드 파일을 읽어 가이드 본문 뒤에 붙인다.
+/// 조회 실패는 리뷰를 중단하지 않고 경고만 남긴다.
+pub(super) async fn append_remote_review_guides(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    guide_text: &mut String,
+) {
+    let paths = ctx.config.remote_review_guides();
+    if paths.is_empty() {
+        return;
+    }
+
+    let mut found = Vec::new();
+    for path in &paths {
+        match ctx.vcs.fetch_repository_file(path).await {
+            Ok(Some(content)) => {
+                let guide = truncate_guide(content.trim());
+                if guide.is_empty() {
+                    continue;
+                }
+                if !guide_text.is_empty() {
+                    guide_text.push_str("\n\n");
+                }
+                guide_text.push_str(&format!("Repository guide `{path}`:\n{guide}"));
+                found.push(path.as_str());
+            }
+            Ok(None) => {}
+            Err(err) => use_case
+                .reporter
+                .status("Guide", &format!("failed to fetch {path}: {err:#}")),
+        }
+    }
+
+    use_case.reporter.kv(
+        "Remote Guide",
+        &if found.is_empty() {
+            "not found".to_string()
+        } else {
+            found.join(", ")
+        },
+    );
+}
+
+/// diff에 변경 파일이 있는 `path_guides` 항목의 가이드 본문.
+#[derive(Default)]
+pub(super) struct PathGuides {
+    entries: Vec<PathGuide>,
+}
+
+struct PathGuide {
+    globs: Vec<String>,
+    path: String,
+    text: String,
+}
+
+impl PathGuides {
+    /// 변경 파일과 일치하는 항목의 가이드 파일만 읽는다. 같은 파일을 가리키는 항목은 하나로 묶는다.
+    pub(super) fn load(
+        use_case: &ReviewPrUseCase<'_>,
+        ctx: &ExecutionContext,
+        changed_files: &[String],
+    ) -> Result<Self> {
+        let mut entries: Vec<PathGuide> = Vec::new();
+        for entry in ctx.config.path_guides() {
+            if !changed_files
+                .iter()
+                .any(|file| path_matches(&entry.glob, file))
+            {
+                continue;
+            }
+            if let Some(existing) = entries.iter_mut().find(|guide| guide.path == entry.guide) {
+                existing.globs.push(entry.glob.clone());
+                continue;
+            }
+            let Some(text) = use_case.system_prompt_resolver.path_guide(&entry.guide)? else {
+                continue;
+            };
+            entries.push(PathGuide {
+                globs: vec![entry.glob.clone()],
+                path: entry.guide.clone(),
+                text: truncate_guide(&text).to_string(),
+            });
+        }
+
+        if !ctx.config.path_guides().is_empty() {
+            use_case.reporter.kv(
+                "Path Guides",
+                &if entries.is_empty() {
+                    "no matching files".to_string()
+                } else {
+                    entries
+                        .iter()
+                        .map(|guide| guide.path.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                },
+            );
+        }
+        Ok(Self { entries })
+    }
+
+    /// 기본 가이드 뒤에 `files`와 관련된 경로별 가이드를 붙인 본문을 만든다.
+    pub(super) fn guide_for(&self, base: &str, files: &[String]) -> String {
+        let mut guide = base.to_string();
+        for entry in &self.entries {
+            let relevant = entry
+                .globs
+                .iter()
+                .any(|glob| files.iter().any(|file| path_matches(glob, file)));
+            if !relevant {
+                continue;
+            }
+            if !guide.is_empty() {
+                guide.push_str("\n\n");
+            }
+            guide.push_str(&format!(
+                "Guide for `{}` (`{}`):\n{}",
+                entry.globs.join("`, `"),
+                entry.path,
+                entry.text
+            ));
+        }
+        guide
+    }
+}
+
+fn truncate_guide(guide: &str) -> &str {
+    if guide.len() <= MAX_GUIDE_BYTES {
+        return guide;
+    }
+    let mut end = MAX_GUIDE_BYTES;
+    while !guide.is_char_boundary(end) {
+        end -= 1;
+    }
+    &guide[..end]
+}
@@ -0,0 +1,148 @@
+//! 실행 기록(run history) 수집/저장.
+//!
+//! 실행이 실패해도 그때까지 알게 된 정보(HEAD SHA, provider 사용량)를 남기기 위해
+//! 실행 중에 [`RunTrace`]를 채우고, 끝난 뒤 결과와 함께 한 건으로 기록한다.
+//! 기록한 합의 지적사항은 같은 PR/MR의 다음 SHA 리뷰에서 진행 상황(해결/미해결/신규) 비교에 쓴다.
+
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::ReviewPrUseCase;
+use super::cancel::ReviewCancelled;
+use super::outcome::ReviewOutcome;
+use crate::application::config::Config;
+use crate::application::ports::{ProviderUsageRecord, RunRecord};
+use crate::domain::policy::{compare_with_previous, snapshot_findings};
+use crate::domain::review::{ConsensusFinding, FindingProgress, ProviderRun};
+
+/// 실행 중 기록용으로 모으는 정보.
+#[derive(Default)]
+pub(super) struct RunTrace {
+    /// 대상/저장소별 재정의까지 반영된 설정(컨텍스트 로딩 전에 실패하면 None)
+    pub config: Option<Config>,
+    pub repository: Option<String>,
+    pub head_sha: Option<String>,
+    pub dry_run: bool,
+    pub primary_results: Vec<ProviderRun>,
+}
+
+/// 실행 결과 라벨(`posted` | `dry-run` | `skipped` | `gate-failed` | `failed` | `cancelled`).
+pub(super) fn outcome_label(trace: &RunTrace, result: &Result<ReviewOutcome>) -> &'static str {
+    match result {
+        Ok(outcome) if outcome.skipped => "skipped",
+        Ok(outcome) if outcome.gate.is_some() => "gate-failed",
+        Ok(_) if trace.dry_run => "dry-run",
+        Ok(_) => "posted",
+        Err(err) if err.downcast_ref::<ReviewCancelled>().is_some() => "cancelled",
+        Err(_) => "failed",
+    }
+}
+
+/// 대상 저장소 키. 대상 URL조차 해석하지 못한 실행이면 None.
+pub(super) fn run_repository(
+    use_case: &ReviewPrUseCase<'_>,
+    url: &str,
+    trace: &RunTrace,
+) -> Option<String> {
+    trace.repository.clone().or_else(|| {
+        use_case
+            .target_resolver
+            .parse(url)
+            .ok()
+            .map(|target| target.repository_key())
+    })
+}
+
+/// 실행 결과를 기록한다. 기록 실패는 리뷰 결과에 영향을 주지 않는다.
+pub(super) fn record_run(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    url: &str,
+    started_at: u64,
+    elapsed: Duration,
+    trace: &RunTrace,
+    result: &Result<ReviewOutcome>,
+) {
+    if !config.run_history_enabled() {
+        return;
+    }
+    // 집계할 저장소가 없는 실행은 남기지 않는다.
+    let Some(repository) = run_repository(use_case, url, trace) else {
+        return;
+    };
+    let providers = trace
+        .primary_results
+        .iter()
+        .map(|run| ProviderUsageRecord {
+            provider_id: run.id.clone(),
+            provider_name: run.name.clone(),
+            duration_secs: run.elapsed_secs.map(f64::from),
+            prompt_tokens: run.usage.prompt_tokens,
+            completion_tokens: run.usage.completion_tokens,
+            total_tokens: run.usage.total(),
+            cost_usd: config
+                .token_pricing(&run.id)
+                .and_then(|pricing| run.usage.estimated_cost(&pricing)),
+        })
+        .collect();
+
+    let record = RunRecord {
+        started_at,
+        repository,
+        target_url: url.to_string(),
+        head_sha: trace.head_sha.clone(),
+        outcome: outcome_label(trace, result).to_string(),
+        duration_secs: elapsed.as_secs_f64(),
+        providers,
+        findings: result
+            .as_ref()
+            .ok()
+            .filter(|outcome| !outcome.skipped)
+            .map(|outcome| snapshot_findings(&outcome.summary.consensus)),
+    };
+    if let Err(err) = use_case.run_history.record(&record) {
+        use_case
+            .reporter
+            .detail("History", &format!("failed to record run: {err:#}"));
+    }
+}
+
+/// 같은 PR/MR의 이전 SHA 리뷰와 이번 합의 지적사항을 비교한다(실행 기록을 끈 경우/이전 리뷰가 없으면 None).
+/// `/repopilot ignore`로 제외한 지적사항은 해결된 것으로 보지 않도록 이전 목록에서도 뺀다.
+pub(super) fn compare_previous_run(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    target_url: &str,
+    head_sha: &str,
+    consensus: &[ConsensusFinding],
+    ignored: &BTreeSet<String>,
+) -> Option<FindingProgress> {
+    if !config.run_history_enabled() {
+        return None;
+    }
+    let mut previous = match use_case.run_history.previous_review(target_url, head_sha) {
+        Ok(previous) => previous?,
+        Err(err) => {
+            use_case.reporter.detail(
+                "History",
+                &format!("failed to load previous review: {err:#}"),
+            );
+            return None;
+        }
+    };
+    previous.findings.retain(|f| !ignored.contains(&f.id));
+    let progress = compare_with_previous(&previous, consensus);
+    use_case.reporter.kv(
+        "Progress",
+        &format!(
+            "since {}: {} resolved, {} still open, {} new",
+            progress.previous_sha,
+            progress.resolved.len(),
+            progress.still_open.len(),
+            progress.new.len()
+        ),
+    );
+    Some(progress)
+}
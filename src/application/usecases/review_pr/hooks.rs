@@ -0,0 +1,91 @@
+//! 정책 훅 단계(`defaults.pre_prompt_hook` / `defaults.post_findings_hook`).
+
+use anyhow::Result;
+
+use crate::application::config::Config;
+use crate::application::ports::ReviewHooks;
+use crate::application::usecases::review_pr::{ReviewPrUseCase, providers::PrimaryReviewOutcome};
+use crate::domain::policy::{findings_to_file_reviews, findings_to_markdown};
+use crate::domain::review::{ProviderRun, ReviewGranularity, ReviewRequest, SeverityTaxonomy};
+
+/// 설정된 훅 모듈을 불러온다. 훅이 하나도 없으면 None.
+pub(super) fn load_review_hooks(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+) -> Result<Option<Box<dyn ReviewHooks>>> {
+    let hooks = use_case.review_hooks.build(config)?;
+    if hooks.is_some() {
+        let paths: Vec<String> = [
+            ("pre_prompt", &config.defaults.pre_prompt_hook),
+            ("post_findings", &config.defaults.post_findings_hook),
+        ]
+        .into_iter()
+        .filter_map(|(name, path)| {
+            let path = path.as_deref()?.trim();
+            (!path.is_empty()).then(|| format!("{name}={path}"))
+        })
+        .collect();
+        use_case.reporter.kv("Hooks", &paths.join(", "));
+    }
+    Ok(hooks)
+}
+
+/// provider로 보내기 전 모든 요청(청크)에 `pre_prompt` 훅을 적용한다.
+/// 훅이 리뷰를 거부하면 provider를 호출하지 않고 실패한다.
+pub(super) fn apply_pre_prompt_hook(
+    hooks: &dyn ReviewHooks,
+    requests: &mut [ReviewRequest],
+) -> Result<()> {
+    for request in requests {
+        hooks.pre_prompt(request)?;
+    }
+    Ok(())
+}
+
+/// 에이전트별 1차 리뷰 지적사항에 `post_findings` 훅을 적용한다.
+/// 훅이 목록을 바꾸면 에이전트 코멘트를 triage처럼 남은 지적사항으로 다시 구성하고,
+/// 모두 걸러지면 그 에이전트 코멘트를 게시하지 않는다.
+/// 구조화 지적사항이 없는 에이전트는 훅에 넘길 것이 없으므로 그대로 둔다.
+pub(super) fn apply_post_findings_hook(
+    use_case: &ReviewPrUseCase<'_>,
+    hooks: &dyn ReviewHooks,
+    request: &ReviewRequest,
+    outcome: &mut PrimaryReviewOutcome,
+    granularity: ReviewGranularity,
+    taxonomy: &SeverityTaxonomy,
+) -> Result<()> {
+    let mut kept_runs: Vec<ProviderRun> = Vec::new();
+    for mut run in std::mem::take(&mut outcome.primary_results) {
+        if run.findings.is_empty() {
+            kept_runs.push(run);
+            continue;
+        }
+        let before = run.findings.len();
+        let findings = hooks.post_findings(request, &run.id, run.findings.clone())?;
+        if findings == run.findings {
+            kept_runs.push(run);
+            continue;
+        }
+
+        use_case.reporter.status(
+            &run.name,
+            &format!(
+                "post_findings hook kept {} of {} finding(s)",
+                findings.len(),
+                before
+            ),
+        );
+        if findings.is_empty() {
+            continue;
+        }
+        run.body = findings_to_markdown(&findings, taxonomy);
+        if granularity == ReviewGranularity::File {
+            run.file_reviews = findings_to_file_reviews(&findings, taxonomy);
+        }
+        run.findings = findings;
+        kept_runs.push(run);
+    }
+
+    outcome.primary_results = kept_runs;
+    Ok(())
+}
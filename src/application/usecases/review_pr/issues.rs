@@ -0,0 +1,115 @@
+//! `--create-issues`: 임계 심각도 이상 합의 지적사항을 후속 이슈로 남기는 단계.
+//!
+//! PR/MR이 머지된 뒤에도 막지 않은(non-blocking) 문제가 잊히지 않도록 저장소 이슈를 만들고,
+//! 최종 요약에 지적사항 id와 이슈 URL 연결을 주석으로 남겨 다음 실행에서는 같은 이슈를 재사용한다
+//! (연결은 컨텍스트 로딩 시 [`ExecutionContext::linked_issues`]로 모아 둔다).
+
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::policy::finding_id;
+use crate::domain::review::{ConsensusFinding, FollowUpIssue, ReviewSummary, RunOptions};
+
+/// 임계 심각도 이상이고 사람 확인이 필요한(확신도 낮은) 항목이 아닌 합의 지적사항마다 이슈를 연결한다.
+/// 이전 요약에 연결된 이슈가 있으면 재사용하고, dry-run이면 만들지 않고 대상만 보고한다.
+/// 이슈 생성 실패는 리뷰를 중단하지 않고 상태만 보고한다.
+pub(super) async fn link_follow_up_issues(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    summary: &ReviewSummary,
+) -> Vec<FollowUpIssue> {
+    let Some(threshold) = options.create_issues else {
+        return Vec::new();
+    };
+    let candidates: Vec<&ConsensusFinding> = summary
+        .consensus
+        .iter()
+        .filter(|item| {
+            item.finding.severity.is_at_least(threshold)
+                && !item
+                    .finding
+                    .is_below_confidence(summary.low_confidence_below)
+        })
+        .collect();
+    if candidates.is_empty() {
+        return Vec::new();
+    }
+
+    use_case.reporter.section("Follow-up Issues");
+    let mut issues = Vec::new();
+    for item in candidates {
+        let id = finding_id(&item.finding);
+        let title = item.finding.title.clone();
+        if let Some(url) = ctx.linked_issues.get(&id) {
+            use_case
+                .reporter
+                .status(&id, &format!("already linked to {url}"));
+            issues.push(FollowUpIssue {
+                finding_id: id,
+                title,
+                url: url.clone(),
+                created: false,
+            });
+            continue;
+        }
+        if options.dry_run {
+            use_case.reporter.status(&id, "would create issue");
+            continue;
+        }
+
+        let body = issue_body(ctx, summary, item, &id);
+        match ctx.vcs.create_issue(&title, &body).await {
+            Ok(issue) => {
+                use_case.reporter.status(
+                    &id,
+                    &format!("created issue #{} ({})", issue.number, issue.url),
+                );
+                issues.push(FollowUpIssue {
+                    finding_id: id,
+                    title,
+                    url: issue.url,
+                    created: true,
+                });
+            }
+            Err(err) => use_case
+                .reporter
+                .status(&id, &format!("failed to create issue: {err:#}")),
+        }
+    }
+    issues
+}
+
+/// 이슈 본문. PR/MR과 지적사항 위치(HEAD SHA 기준 파일 링크)로 돌아갈 수 있게 한다.
+fn issue_body(
+    ctx: &ExecutionContext,
+    summary: &ReviewSummary,
+    item: &ConsensusFinding,
+    id: &str,
+) -> String {
+    let finding = &item.finding;
+    let mut out = format!(
+        "Follow-up for a review finding on {}.\n\n- Finding: `{id}` ({})\n",
+        ctx.target.url(),
+        summary.severity_taxonomy.label(finding.severity)
+    );
+    if let Some(location) = finding.location() {
+        let link = finding
+            .file
+            .as_deref()
+            .and_then(|path| ctx.target.blob_url(&ctx.head_sha, path, finding.line_range));
+        match link {
+            Some(url) => out.push_str(&format!("- Location: [`{location}`]({url})\n")),
+            None => out.push_str(&format!("- Location: `{location}`\n")),
+        }
+    }
+    out.push_str(&format!("- Head SHA: `{}`\n", ctx.head_sha));
+    if !item.agents.is_empty() {
+        out.push_str(&format!("- Reported by: {}\n", item.agents.join(", ")));
+    }
+    let body = finding.body.trim();
+    if !body.is_empty() {
+        out.push('\n');
+        out.push_str(body);
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,136 @@
+//! PR 제목/설명/커밋 메시지의 품질(Conventional Commits, 설명 템플릿 준수)을 provider 하나가 점검하는
+//! 메타 리뷰 단계. 코드 리뷰와 별개로 최종 요약에 자체 섹션으로 들어간다.
+
+use std::time::Instant;
+
+use anyhow::Result;
+
+use crate::application::ports::ProviderAgent;
+use crate::application::usecases::review_pr::redact::{RedactionReport, SecretRedactor};
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::policy::meta_review_prompt_vars;
+use crate::domain::review::{ReviewRequest, ReviewStage, StageReview};
+
+/// `defaults.meta_review_provider`로 메타 리뷰를 실행한다.
+/// 미지정/비활성/실패 시에는 None을 반환하고, 설명/커밋/템플릿 조회 실패는 그 항목 없이 계속한다.
+/// 설명과 커밋 메시지의 비밀값은 provider로 보내기 전에 가린다.
+pub(super) async fn run_meta_review(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    providers: &[Box<dyn ProviderAgent>],
+    request: &ReviewRequest,
+) -> Result<Option<StageReview>> {
+    let Some(provider_id) = ctx.config.meta_review_provider() else {
+        return Ok(None);
+    };
+
+    use_case.reporter.section("Providers (Meta Review)");
+    let Some(provider) = providers.iter().find(|p| p.id() == provider_id) else {
+        use_case.reporter.status(
+            "Meta Review",
+            &format!("provider '{provider_id}' is not enabled; skipping meta review"),
+        );
+        return Ok(None);
+    };
+
+    let (description, commit_messages) =
+        futures::join!(ctx.vcs.fetch_description(), ctx.vcs.fetch_commit_messages());
+    let mut description = description.unwrap_or_else(|err| {
+        use_case
+            .reporter
+            .status("VCS", &format!("failed to fetch description: {err:#}"));
+        String::new()
+    });
+    let mut commit_messages = commit_messages.unwrap_or_else(|err| {
+        use_case
+            .reporter
+            .status("VCS", &format!("failed to fetch commit messages: {err:#}"));
+        Vec::new()
+    });
+    use_case
+        .reporter
+        .kv("Commits", &commit_messages.len().to_string());
+    let description_template = fetch_description_template(use_case, ctx).await;
+
+    if let Some(redactor) = SecretRedactor::from_config(&ctx.config)? {
+        let mut redactions = RedactionReport::default();
+        description = redactor
+            .redact(&description, "description", &mut redactions)
+            .into_owned();
+        for message in &mut commit_messages {
+            *message = redactor
+                .redact(message, "commits", &mut redactions)
+                .into_owned();
+        }
+        if !redactions.is_empty() {
+            use_case.reporter.kv("Redacted", &redactions.summary());
+        }
+    }
+
+    let provider_name = provider.name().to_string();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "running", None);
+    let vars = meta_review_prompt_vars(
+        request,
+        &description,
+        &commit_messages,
+        description_template.as_deref(),
+    );
+    let prompt = use_case
+        .templates
+        .render(&request.prompt_templates.meta_review, &vars);
+
+    let started = Instant::now();
+    let response = match prompt {
+        Ok(prompt) => provider.review_prompt(&prompt).await,
+        Err(err) => Err(err),
+    };
+    match response {
+        Ok(resp) => {
+            let sec = started.elapsed().as_secs_f32();
+            use_case
+                .reporter
+                .provider_status(&provider_name, "done", Some(sec));
+            Ok(Some(StageReview {
+                stage: ReviewStage::Meta,
+                provider_id: provider.id().to_string(),
+                provider_name,
+                body: resp.content,
+                findings: Vec::new(),
+                usage: resp.usage,
+            }))
+        }
+        Err(err) => {
+            let sec = started.elapsed().as_secs_f32();
+            use_case
+                .reporter
+                .provider_status(&provider_name, "error", Some(sec));
+            use_case
+                .reporter
+                .status("Meta Review", &format!("meta review failed: {err}"));
+            Ok(None)
+        }
+    }
+}
+
+/// 기본 브랜치에서 처음 발견된 설명 템플릿을 읽는다. 조회 실패는 경고만 남긴다.
+async fn fetch_description_template(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+) -> Option<String> {
+    for path in ctx.config.description_templates() {
+        match ctx.vcs.fetch_repository_file(&path).await {
+            Ok(Some(content)) if !content.trim().is_empty() => {
+                use_case.reporter.kv("Description Template", &path);
+                return Some(content);
+            }
+            Ok(_) => {}
+            Err(err) => use_case
+                .reporter
+                .status("Meta Review", &format!("failed to fetch {path}: {err:#}")),
+        }
+    }
+    use_case.reporter.kv("Description Template", "not found");
+    None
+}
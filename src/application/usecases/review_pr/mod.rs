@@ -1,24 +1,71 @@
 //! PR/MR 리뷰 실행의 전체 오케스트레이션 유스케이스.
 
+mod autofix;
+mod cache;
+mod cancel;
 mod context;
 mod dedupe;
+mod gate;
+mod guide;
+mod history;
+mod hooks;
+mod issues;
+mod meta;
+mod notify;
+mod outcome;
+mod pr_type;
 mod providers;
 mod publish;
+mod redact;
+mod reviewers;
+mod security;
+mod test_gaps;
+mod triage;
 
-use anyhow::Result;
+use std::time::Instant;
 
+use anyhow::{Context, Result, bail};
+use tokio_util::sync::CancellationToken;
+use tracing::Instrument;
+
+use crate::application::attribution::{self, RequestAttribution};
 use crate::application::ports::{
-    ConfigRepository, HostTokenResolver, MarkdownRenderer, ProviderFactory, Reporter,
-    SystemPromptResolver, TargetResolver, UserConfirmer, VcsFactory,
+    Clock, ConfigRepository, FindingTriage, HostTokenResolver, MarkdownRenderer, Notifier,
+    ProviderFactory, Reporter, ResponseCache, ReviewHookFactory, RunHistory, RunJournal,
+    RunJournalEntry, SystemPromptResolver, TargetResolver, TemplateEngine, UserConfirmer,
+    VcsFactory,
 };
-use crate::domain::review::RunOptions;
+use crate::domain::bot_command::collect_ignored_findings;
+use crate::domain::policy::{cluster_findings, finding_id, group_file_threads, usage_breakdown};
+use crate::domain::review::{AgentComment, AgentSeverities, ReviewSummary, RunOptions};
+
+pub use cancel::ReviewCancelled;
+pub use gate::SeverityGateError;
+pub use outcome::ReviewOutcome;
 
-use context::load_execution_context;
-use dedupe::{ClaimDecision, prepare_claim_comment};
+use autofix::run_autofix;
+use cancel::cancellable;
+use context::{ExecutionContext, apply_profile_options, load_execution_context};
+use dedupe::{ClaimDecision, find_or_create_claim_comment, prepare_claim_comment};
+use gate::{command_policy, evaluate_severity_gate};
+use history::{RunTrace, compare_previous_run, record_run};
+use hooks::{apply_post_findings_hook, apply_pre_prompt_hook, load_review_hooks};
+use issues::link_follow_up_issues;
+use meta::run_meta_review;
+use notify::notify_run;
 use providers::{
-    build_enabled_providers, build_review_request, run_cross_agent_reactions, run_primary_reviews,
+    ReviewRequests, build_enabled_providers, build_review_requests, detect_unreviewable_diff,
+    report_usage, run_cross_agent_reactions, run_moderator_synthesis, run_primary_reviews,
+    sort_by_provider_order,
+};
+use publish::{
+    publish_agent_comments, publish_file_threads, publish_final_summary, publish_suggestions,
+    publish_unreviewable_summary, run_footer,
 };
-use publish::{publish_agent_comments, publish_final_summary};
+use reviewers::suggest_human_reviewers;
+use security::run_security_pass;
+use test_gaps::run_test_gap_analysis;
+use triage::triage_primary_results;
 
 /// URL 입력부터 VCS/제공자 호출, 코멘트 업서트까지 전체 흐름을 조율한다.
 pub struct ReviewPrUseCase<'a> {
@@ -28,17 +75,101 @@ pub struct ReviewPrUseCase<'a> {
     pub target_resolver: &'a dyn TargetResolver,
     pub vcs_factory: &'a dyn VcsFactory,
     pub provider_factory: &'a dyn ProviderFactory,
+    pub review_hooks: &'a dyn ReviewHookFactory,
     pub renderer: &'a dyn MarkdownRenderer,
+    pub templates: &'a dyn TemplateEngine,
     pub reporter: &'a dyn Reporter,
     pub confirmer: &'a dyn UserConfirmer,
+    pub finding_triage: &'a dyn FindingTriage,
+    pub run_journal: &'a dyn RunJournal,
+    pub run_history: &'a dyn RunHistory,
+    pub response_cache: &'a dyn ResponseCache,
+    pub notifier: &'a dyn Notifier,
+    pub clock: &'a dyn Clock,
+    /// 취소되면 진행 중인 VCS/provider 호출을 중단하고 `ReviewCancelled`로 끝낸다.
+    pub cancel: CancellationToken,
 }
 
 impl<'a> ReviewPrUseCase<'a> {
+    /// 외부(예: REPL의 Ctrl-C)에서 취소할 수 있는 토큰을 연결한다.
+    pub fn with_cancellation(mut self, cancel: CancellationToken) -> Self {
+        self.cancel = cancel;
+        self
+    }
+
+    /// 실행 단위로 진행 상황 출력을 바꿀 때(예: REPL 상태판) 리포터를 교체한다.
+    pub fn with_reporter(mut self, reporter: &'a dyn Reporter) -> Self {
+        self.reporter = reporter;
+        self
+    }
+
     /// 리뷰 본 실행 진입점.
     /// dry-run/force 옵션을 반영해 중복 방지, 코멘트 게시, 최종 요약 게시를 수행한다.
-    pub async fn execute(&self, options: RunOptions) -> Result<()> {
+    /// 심각도 게이트 판정은 에러가 아니라 결과의 `gate`로 반환한다.
+    /// 취소는 게시 전까지만 반영한다(코멘트 일부만 게시된 상태를 남기지 않기 위함).
+    /// 성공/실패와 관계없이 실행 한 건을 실행 기록(`repopilot stats`)에 남기고 알림 채널에 알린다.
+    /// 실행 중 로그 이벤트와 외부 HTTP 요청(`X-Repopilot-Run-Id`)에는 모두 이 실행의 `run_id`가 붙는다.
+    pub async fn execute(&self, options: RunOptions) -> Result<ReviewOutcome> {
+        let run_id = self.clock.random_id();
+        let attribution = RequestAttribution {
+            run_id: Some(run_id.clone()),
+            ..RequestAttribution::default()
+        };
+        attribution::scope(attribution, self.execute_attributed(options, run_id)).await
+    }
+
+    async fn execute_attributed(
+        &self,
+        options: RunOptions,
+        run_id: String,
+    ) -> Result<ReviewOutcome> {
+        let url = options.url.clone();
+        let span = tracing::info_span!("review", run_id = %run_id, url = %url);
+        let started = Instant::now();
+        let started_at = self.clock.unix_now();
+        let mut trace = RunTrace::default();
+        let result = self
+            .execute_traced(options, &run_id, &mut trace)
+            .instrument(span.clone())
+            .await;
+
+        let elapsed = started.elapsed();
+        async {
+            match &result {
+                Ok(outcome) => tracing::info!(
+                    skipped = outcome.skipped,
+                    gate_failed = outcome.gate.is_some(),
+                    elapsed_secs = elapsed.as_secs_f64(),
+                    "review finished"
+                ),
+                Err(err) => tracing::error!(
+                    error = %format!("{err:#}"),
+                    elapsed_secs = elapsed.as_secs_f64(),
+                    "review failed"
+                ),
+            }
+            // 컨텍스트 로딩 전에 실패했으면 전역 설정으로 기록/알림 여부를 판단한다.
+            let Some(config) = trace.config.take().or_else(|| self.config_repo.load().ok()) else {
+                return;
+            };
+            record_run(self, &config, &url, started_at, elapsed, &trace, &result);
+            notify_run(self, &config, &url, elapsed, &trace, &result).await;
+        }
+        .instrument(span)
+        .await;
+        result
+    }
+
+    async fn execute_traced(
+        &self,
+        options: RunOptions,
+        run_id: &str,
+        trace: &mut RunTrace,
+    ) -> Result<ReviewOutcome> {
         self.reporter.section("Session");
         self.reporter.kv("Target", &options.url);
+        self.reporter.kv("Run ID", run_id);
+        let options = apply_profile_options(self, options)?;
         self.reporter.kv(
             "Mode",
             if options.dry_run {
@@ -50,40 +181,360 @@ impl<'a> ReviewPrUseCase<'a> {
         if options.force {
             self.reporter.kv("Force", "enabled");
         }
+        if options.interactive_publish {
+            self.reporter.kv("Triage", "interactive");
+        }
+        if let Some(threshold) = options.fail_on {
+            self.reporter.kv("Fail On", threshold.code());
+        }
+        if let Some(threshold) = options.create_issues {
+            self.reporter.kv("Create Issues", threshold.code());
+        }
+        if options.request_reviewers {
+            self.reporter.kv("Request Reviewers", "on");
+        }
+        if options.autofix {
+            self.reporter.kv(
+                "Autofix",
+                if options.autofix_push {
+                    "patch + branch"
+                } else {
+                    "patch"
+                },
+            );
+        }
 
-        let mut ctx = load_execution_context(self, &options).await?;
+        trace.dry_run = options.dry_run;
+        let mut ctx = cancellable(&self.cancel, None, load_execution_context(self, &options, true)).await?;
+        trace.config = Some(ctx.config.clone());
+        trace.repository = Some(ctx.target.repository_key());
+        trace.head_sha = Some(ctx.head_sha.clone());
 
-        let claim_comment_id = match prepare_claim_comment(self, &options, &mut ctx).await? {
-            ClaimDecision::Skip => return Ok(()),
+        let claim_comment_id = match cancellable(
+            &self.cancel,
+            None,
+            prepare_claim_comment(self, &options, &mut ctx),
+        )
+        .await?
+        {
+            ClaimDecision::Skip => {
+                return Ok(ReviewOutcome::skipped(&options.url, &ctx.head_sha));
+            }
             ClaimDecision::Continue { claim_comment_id } => claim_comment_id,
         };
 
-        let request = build_review_request(self, &ctx).await?;
-        let providers = build_enabled_providers(self, &ctx)?;
-        let primary_outcome = run_primary_reviews(self, &providers, &request).await;
+        // 빈 PR/MR이나 바이너리만 바뀐 경우 provider 출력이 혼란스러우므로 안내 요약으로 끝낸다.
+        if let Some(kind) = detect_unreviewable_diff(&options, &ctx) {
+            let summary_markdown = publish_unreviewable_summary(
+                self,
+                &options,
+                &mut ctx,
+                claim_comment_id.as_deref(),
+                kind,
+            )
+            .await?;
+            return Ok(ReviewOutcome {
+                target_url: options.url.clone(),
+                head_sha: ctx.head_sha.clone(),
+                summary_markdown,
+                summary_comment_id: claim_comment_id,
+                ..ReviewOutcome::default()
+            });
+        }
+
+        let entry = cancellable(
+            &self.cancel,
+            claim_comment_id.as_deref(),
+            self.run_providers(&options, &ctx),
+        )
+        .await?;
+        trace.primary_results = entry.primary_results.clone();
+        if !options.dry_run {
+            // 게시 단계가 실패해도 `repopilot resume`으로 provider 재호출 없이 재시도할 수 있게 보관한다.
+            match self.run_journal.save(&entry) {
+                Ok(path) => self.reporter.kv("Run Journal", &path),
+                Err(err) => self
+                    .reporter
+                    .status("Journal", &format!("failed to save run journal: {err:#}")),
+            }
+        }
 
-        let agent_comment_refs =
-            publish_agent_comments(self, &options, &mut ctx, &primary_outcome.agent_comments)
-                .await?;
+        self.publish_results(&options, &mut ctx, claim_comment_id.as_deref(), entry)
+            .await
+    }
+
+    /// diff/프롬프트 구성부터 1차 리뷰, 교차 반응, 통합 리뷰, 보안 점검, PR 메타 리뷰까지 provider 단계를 실행한다.
+    async fn run_providers(
+        &self,
+        options: &RunOptions,
+        ctx: &ExecutionContext,
+    ) -> Result<RunJournalEntry> {
+        let ReviewRequests {
+            mut requests,
+            diff_stats,
+        } = build_review_requests(self, options, ctx).await?;
+        let hooks = load_review_hooks(self, &ctx.config)?;
+        if let Some(hooks) = &hooks {
+            apply_pre_prompt_hook(hooks.as_ref(), &mut requests)?;
+        }
+        // 교차 반응/통합 단계는 대상 메타데이터만 사용하므로 첫 요청을 기준으로 한다.
+        let request = &requests[0];
+        let providers = build_enabled_providers(self, options, ctx)?;
+        let mut primary_outcome = run_primary_reviews(
+            self,
+            &providers,
+            &requests,
+            ctx.config.chunk_concurrency(),
+        )
+        .await?;
+        sort_by_provider_order(
+            &ctx.config.provider_order(),
+            &mut primary_outcome.primary_results,
+            |run| &run.id,
+        );
+        if let Some(hooks) = &hooks {
+            apply_post_findings_hook(
+                self,
+                hooks.as_ref(),
+                request,
+                &mut primary_outcome,
+                ctx.config.review_granularity(),
+                &ctx.config.severity_taxonomy(),
+            )?;
+        }
+        if options.interactive_publish {
+            triage_primary_results(
+                self,
+                &mut primary_outcome,
+                ctx.config.review_granularity(),
+                &ctx.config.severity_taxonomy(),
+            )?;
+        }
 
         let reactions = run_cross_agent_reactions(
             self,
+            &ctx.config,
             &providers,
-            &request,
+            request,
             &primary_outcome.primary_results,
+            ctx.config.reaction_rounds(),
         )
         .await;
 
-        publish_final_summary(
+        let consolidated = run_moderator_synthesis(
             self,
-            &options,
-            &mut ctx,
-            claim_comment_id.as_deref(),
+            &ctx.config,
+            &providers,
+            request,
+            &primary_outcome.primary_results,
             &reactions,
-            &agent_comment_refs,
+            ctx.config.moderator_provider(),
         )
-        .await?;
+        .await;
+        let mut stage_reviews = Vec::new();
+        if let Some(security) = run_security_pass(self, ctx, &providers, &requests).await {
+            stage_reviews.push(security);
+        }
+        if let Some(test_gaps) = run_test_gap_analysis(self, ctx, &providers, &requests).await {
+            stage_reviews.push(test_gaps);
+        }
+        if let Some(meta) = run_meta_review(self, ctx, &providers, request).await? {
+            stage_reviews.push(meta);
+        }
+        report_usage(
+            self,
+            &usage_breakdown(
+                &primary_outcome.primary_results,
+                &reactions,
+                consolidated.as_ref(),
+                &stage_reviews,
+            ),
+        );
+
+        Ok(RunJournalEntry {
+            target_url: options.url.clone(),
+            head_sha: ctx.head_sha.clone(),
+            primary_results: primary_outcome.primary_results,
+            reactions,
+            consolidated,
+            stage_reviews,
+            diff_stats: Some(diff_stats),
+        })
+    }
+
+    /// 저장된 실행 저널로 게시 단계(개별 코멘트/파일 스레드/최종 요약)만 다시 수행한다.
+    /// provider는 다시 호출하지 않으며, 저널 이후 HEAD가 바뀌었으면 실패한다.
+    pub async fn resume(&self, options: RunOptions) -> Result<ReviewOutcome> {
+        self.reporter.section("Session");
+        self.reporter.kv("Target", &options.url);
+        self.reporter.kv(
+            "Mode",
+            if options.dry_run {
+                "resume (dry-run)"
+            } else {
+                "resume"
+            },
+        );
+
+        let entry = self
+            .run_journal
+            .load(&options.url)?
+            .with_context(|| format!("no saved run journal for {}", options.url))?;
+
+        let mut ctx = load_execution_context(self, &options, false).await?;
+        if entry.head_sha != ctx.head_sha {
+            bail!(
+                "head SHA changed since the saved run ({} -> {}); run a fresh review instead",
+                entry.head_sha,
+                ctx.head_sha
+            );
+        }
+
+        let claim_comment_id = find_or_create_claim_comment(self, &options, &mut ctx).await?;
+        self.publish_results(&options, &mut ctx, claim_comment_id.as_deref(), entry)
+            .await
+    }
+
+    /// provider 실행이 끝난 결과를 게시하고 심각도 게이트를 판정한다.
+    async fn publish_results(
+        &self,
+        options: &RunOptions,
+        ctx: &mut ExecutionContext,
+        claim_comment_id: Option<&str>,
+        mut entry: RunJournalEntry,
+    ) -> Result<ReviewOutcome> {
+        // 이전 버전 저널이나 순서 설정이 바뀐 뒤의 `resume`도 같은 순서로 게시한다.
+        let provider_order = ctx.config.provider_order();
+        sort_by_provider_order(&provider_order, &mut entry.primary_results, |run| &run.id);
+        sort_by_provider_order(&provider_order, &mut entry.reactions, |reaction| {
+            &reaction.provider_id
+        });
+        entry.reactions.sort_by_key(|reaction| reaction.round);
+
+        // 확신도 하한보다 낮은 지적사항은 요약/게이트/통계에서 뺀다(확신도를 보고하지 않은 항목은 유지).
+        let floor = ctx.config.min_finding_confidence();
+        if floor > 0.0 {
+            let mut dropped = 0;
+            for run in &mut entry.primary_results {
+                let before = run.findings.len();
+                run.findings.retain(|f| !f.is_below_confidence(floor));
+                dropped += before - run.findings.len();
+            }
+            for stage in &mut entry.stage_reviews {
+                let before = stage.findings.len();
+                stage.findings.retain(|f| !f.is_below_confidence(floor));
+                dropped += before - stage.findings.len();
+            }
+            if dropped > 0 {
+                self.reporter
+                    .kv("Low-Confidence Dropped", &dropped.to_string());
+            }
+        }
+
+        let agent_comments: Vec<AgentComment> = entry
+            .primary_results
+            .iter()
+            .map(|run| AgentComment {
+                footer: run_footer(self, ctx, [run], run.elapsed_secs),
+                ..AgentComment::from_run(run)
+            })
+            .collect();
+        let agent_comment_refs =
+            publish_agent_comments(self, options, ctx, &agent_comments).await?;
+        let file_threads = group_file_threads(&entry.primary_results);
+        publish_file_threads(self, options, ctx, &file_threads).await;
+
+        // PR 코멘트의 `/repopilot ignore <id>`로 제외된 지적사항은 요약/게이트에서 뺀다.
+        // 명령은 관리자/허용 목록 사용자가 쓴 것만 따른다(`CommandPolicy`).
+        let mut consensus = cluster_findings(&entry.primary_results);
+        let policy = command_policy(self, ctx).await;
+        let ignored = collect_ignored_findings(&ctx.existing_comments, &policy);
+        if !ignored.is_empty() {
+            let before = consensus.len();
+            consensus.retain(|item| !ignored.contains(&finding_id(&item.finding)));
+            self.reporter
+                .kv("Ignored Findings", &(before - consensus.len()).to_string());
+        }
+        let progress = compare_previous_run(
+            self,
+            &ctx.config,
+            &options.url,
+            &ctx.head_sha,
+            &consensus,
+            &ignored,
+        );
+        publish_suggestions(
+            self,
+            options,
+            ctx,
+            &consensus,
+            ctx.config.low_confidence_below(),
+            &ctx.config.severity_taxonomy(),
+        )
+        .await;
+        let autofix = run_autofix(
+            self,
+            options,
+            ctx,
+            &consensus,
+            ctx.config.low_confidence_below(),
+        )
+        .await;
+
+        let mut summary = ReviewSummary {
+            consolidated: entry.consolidated,
+            stage_reviews: entry.stage_reviews,
+            consensus,
+            reactions: entry.reactions,
+            agent_comment_refs,
+            severity_taxonomy: ctx.config.severity_taxonomy(),
+            agent_severities: entry
+                .primary_results
+                .iter()
+                .map(AgentSeverities::from_run)
+                .collect(),
+            collapse: ctx.config.summary_collapse(),
+            diff_stats: entry.diff_stats,
+            low_confidence_below: ctx.config.low_confidence_below(),
+            follow_up_issues: Vec::new(),
+            suggested_reviewers: Vec::new(),
+            progress,
+            footer: None,
+        };
+        summary.follow_up_issues = link_follow_up_issues(self, options, ctx, &summary).await;
+        summary.suggested_reviewers = suggest_human_reviewers(self, options, ctx).await;
+        summary.footer = run_footer(
+            self,
+            ctx,
+            &entry.primary_results,
+            Some(ctx.started.elapsed().as_secs_f32()),
+        );
+
+        let summary_markdown =
+            publish_final_summary(self, options, ctx, claim_comment_id, &summary).await?;
+        if !options.dry_run
+            && let Err(err) = self.run_journal.remove(&entry.target_url)
+        {
+            self.reporter
+                .status("Journal", &format!("failed to remove run journal: {err:#}"));
+        }
+
+        // 게시가 끝난 뒤 판정해야 CI에서도 리뷰 결과가 남는다.
+        let gate = evaluate_severity_gate(options.fail_on, &summary.consensus);
+        if let Some(gate) = &gate {
+            self.reporter.outcome(&format!("Gate: {gate}"));
+        }
 
-        Ok(())
+        Ok(ReviewOutcome {
+            target_url: entry.target_url,
+            head_sha: entry.head_sha,
+            skipped: false,
+            primary_results: entry.primary_results,
+            summary,
+            summary_markdown,
+            summary_comment_id: claim_comment_id.map(ToString::to_string),
+            gate,
+            autofix,
+        })
     }
 }
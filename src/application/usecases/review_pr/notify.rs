@@ -0,0 +1,91 @@
+//! 리뷰 완료/실패 알림(`notifications` 설정의 Slack/Teams/Discord/이메일 채널).
+
+use std::time::Duration;
+
+use anyhow::Result;
+
+use super::ReviewPrUseCase;
+use super::history::{RunTrace, outcome_label, run_repository};
+use super::outcome::ReviewOutcome;
+use crate::application::config::Config;
+use crate::application::ports::ReviewNotification;
+use crate::domain::review::Severity;
+
+/// 알림 채널이 설정되어 있으면 실행 결과를 알린다.
+/// dry-run과 이미 리뷰된 SHA라 건너뛴 실행은 알리지 않는다. 전송 실패는 리뷰 결과에 영향을 주지 않는다.
+pub(super) async fn notify_run(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    url: &str,
+    elapsed: Duration,
+    trace: &RunTrace,
+    result: &Result<ReviewOutcome>,
+) {
+    if !config.notifications.has_channels() || trace.dry_run {
+        return;
+    }
+    let outcome = outcome_label(trace, result);
+    if outcome == "skipped" {
+        return;
+    }
+    let Some(repository) = run_repository(use_case, url, trace) else {
+        return;
+    };
+
+    let notification = ReviewNotification {
+        target_url: url.to_string(),
+        repository,
+        head_sha: trace.head_sha.clone(),
+        outcome: outcome.to_string(),
+        succeeded: result.is_ok(),
+        severity_counts: result.as_ref().map(severity_counts).unwrap_or_default(),
+        cost_usd: trace
+            .primary_results
+            .iter()
+            .filter_map(|run| {
+                config
+                    .token_pricing(&run.id)
+                    .and_then(|pricing| run.usage.estimated_cost(&pricing))
+            })
+            .reduce(|a, b| a + b),
+        duration_secs: elapsed.as_secs_f64(),
+        error: result.as_ref().err().map(|err| format!("{err:#}")),
+        summary_markdown: result
+            .as_ref()
+            .ok()
+            .map(|outcome| outcome.summary_markdown.clone()),
+    };
+
+    match use_case
+        .notifier
+        .notify(&config.notifications, &notification)
+        .await
+    {
+        Ok(sent) if !sent.is_empty() => use_case
+            .reporter
+            .detail("Notify", &format!("sent to {}", sent.join(", "))),
+        Ok(_) => {}
+        Err(err) => use_case.reporter.status("Notify", &format!("{err:#}")),
+    }
+}
+
+/// 합의 지적사항의 심각도별 개수(심각한 순서, 0건 제외).
+fn severity_counts(outcome: &ReviewOutcome) -> Vec<(String, usize)> {
+    [
+        Severity::Critical,
+        Severity::Major,
+        Severity::Minor,
+        Severity::Suggestion,
+    ]
+    .into_iter()
+    .filter_map(|severity| {
+        let count = outcome
+            .summary
+            .consensus
+            .iter()
+            .filter(|finding| finding.finding.severity == severity)
+            .count();
+        (count > 0).then(|| (severity.code().to_string(), count))
+    })
+    .collect()
+}
@@ -0,0 +1,57 @@
+//! 리뷰 실행 결과(라이브러리/인터페이스 반환값).
+
+use crate::application::usecases::review_pr::SeverityGateError;
+use crate::domain::patch::AutofixResult;
+use crate::domain::policy::usage_breakdown;
+use crate::domain::review::{ProviderRun, ReviewSummary, UsageBreakdown, UsageTotals};
+
+/// 리뷰 한 번의 실행 결과.
+#[derive(Debug, Clone, Default)]
+pub struct ReviewOutcome {
+    pub target_url: String,
+    pub head_sha: String,
+    /// 이미 리뷰/claim된 SHA라 실행하지 않은 경우 true
+    pub skipped: bool,
+    /// provider별 1차 리뷰 본문/지적사항/사용량
+    pub primary_results: Vec<ProviderRun>,
+    /// 통합 리뷰/합의 지적사항/상호 코멘트/개별 코멘트 id
+    pub summary: ReviewSummary,
+    /// 최종 요약 코멘트 Markdown
+    pub summary_markdown: String,
+    /// 최종 요약 코멘트 id(dry-run이면 None)
+    pub summary_comment_id: Option<String>,
+    /// `fail_on` 임계값 이상 지적사항이 있으면 게이트 판정 결과
+    pub gate: Option<SeverityGateError>,
+    /// `--autofix`로 만든 수정 패치(요청하지 않았거나 만들지 못했으면 None)
+    pub autofix: Option<AutofixResult>,
+}
+
+impl ReviewOutcome {
+    /// 스킵된 실행 결과를 만든다.
+    pub(super) fn skipped(target_url: &str, head_sha: &str) -> Self {
+        Self {
+            target_url: target_url.to_string(),
+            head_sha: head_sha.to_string(),
+            skipped: true,
+            ..Self::default()
+        }
+    }
+
+    /// provider별 토큰 사용량 합계(1차 리뷰 + 교차 반응 + 통합 리뷰 + 보조 리뷰 단계).
+    pub fn usage_totals(&self) -> UsageTotals {
+        self.usage_breakdown()
+            .into_iter()
+            .map(|row| (row.provider_id, (row.provider_name, row.total)))
+            .collect()
+    }
+
+    /// provider별 단계(1차 리뷰/교차 반응/전체) 토큰 사용량.
+    pub fn usage_breakdown(&self) -> Vec<UsageBreakdown> {
+        usage_breakdown(
+            &self.primary_results,
+            &self.summary.reactions,
+            self.summary.consolidated.as_ref(),
+            &self.summary.stage_reviews,
+        )
+    }
+}
@@ -0,0 +1,82 @@
+//! 라벨/제목 규칙으로 PR 유형(feature/bugfix/refactor/docs 등)을 판별해 유형별 지침을 리뷰 가이드에 더하는 단계.
+//!
+//! 규칙은 `defaults.pr_types` 순서대로 확인하고 먼저 일치한 규칙 하나만 쓴다.
+
+use anyhow::{Result, anyhow};
+use regex_automata::meta::Regex;
+
+use crate::application::config::PrTypeConfig;
+use crate::application::usecases::review_pr::ReviewPrUseCase;
+use crate::application::usecases::review_pr::context::{ExecutionContext, ReviewInputs};
+
+/// 판별된 PR 유형과 근거.
+struct PrTypeMatch {
+    rule: PrTypeConfig,
+    /// 보고용 근거(`label bug` 또는 `title`)
+    reason: String,
+}
+
+/// PR 유형을 판별해 `PR Type`으로 보고하고, 일치한 유형의 지침을 가이드 본문 뒤에 붙인다.
+/// 제목 정규식이 잘못되면 오류로 중단한다.
+pub(super) fn append_pr_type_guide(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    inputs: &ReviewInputs,
+    guide_text: &mut String,
+) -> Result<()> {
+    let rules = ctx.config.pr_types();
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    let Some(found) = detect_pr_type(rules, &inputs.title, &inputs.labels)? else {
+        use_case.reporter.kv("PR Type", "not detected");
+        return Ok(());
+    };
+    use_case.reporter.kv(
+        "PR Type",
+        &format!("{} ({})", found.rule.name, found.reason),
+    );
+
+    let prompt = found.rule.prompt.trim();
+    if prompt.is_empty() {
+        return Ok(());
+    }
+    if !guide_text.is_empty() {
+        guide_text.push_str("\n\n");
+    }
+    guide_text.push_str(&format!("PR type `{}`:\n{prompt}", found.rule.name));
+    Ok(())
+}
+
+/// 규칙 순서대로 라벨(대소문자 무시) 또는 제목 정규식이 일치하는 첫 규칙을 찾는다.
+fn detect_pr_type(
+    rules: Vec<PrTypeConfig>,
+    title: &str,
+    labels: &[String],
+) -> Result<Option<PrTypeMatch>> {
+    for (idx, rule) in rules.into_iter().enumerate() {
+        let label = rule.labels.iter().find(|wanted| {
+            labels
+                .iter()
+                .any(|label| label.trim().eq_ignore_ascii_case(wanted.trim()))
+        });
+        if let Some(label) = label {
+            let reason = format!("label {label}");
+            return Ok(Some(PrTypeMatch { rule, reason }));
+        }
+
+        let Some(pattern) = &rule.title_pattern else {
+            continue;
+        };
+        let regex = Regex::new(pattern).map_err(|err| match err.syntax_error() {
+            Some(detail) => anyhow!("invalid defaults.pr_types[{idx}].title_pattern:\n{detail}"),
+            None => anyhow!("invalid defaults.pr_types[{idx}].title_pattern '{pattern}': {err}"),
+        })?;
+        if !title.is_empty() && regex.is_match(title) {
+            let reason = "title".to_string();
+            return Ok(Some(PrTypeMatch { rule, reason }));
+        }
+    }
+    Ok(None)
+}
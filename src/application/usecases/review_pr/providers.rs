@@ -1,72 +1,300 @@
 //! Provider 실행(1차 리뷰/교차 반응) 단계.
+//! 교차 반응/통합 리뷰 응답은 프롬프트가 같으면 캐시(`cache.rs`)에서 재사용한다.
 
+use std::sync::Arc;
 use std::time::Instant;
 
 use anyhow::{Context, Result, bail};
-use futures::stream::{FuturesUnordered, StreamExt};
+use futures::stream::{self, FuturesUnordered, StreamExt};
 
+use crate::application::config::Config;
 use crate::application::ports::ProviderAgent;
+use crate::application::usecases::review_pr::cache::{
+    load_cached_response, response_cache_key, save_cached_response,
+};
+use crate::application::usecases::review_pr::guide::{PathGuides, append_remote_review_guides};
+use crate::application::usecases::review_pr::pr_type::append_pr_type_guide;
+use crate::application::usecases::review_pr::redact::{RedactionReport, SecretRedactor};
 use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
-use crate::domain::policy::build_cross_agent_prompt;
-use crate::domain::review::{AgentComment, AgentReaction, ProviderRun, ReviewRequest, TokenUsage};
+use crate::domain::diff::{
+    DiffChunk, DiffStats, UnreviewableDiff, chunk_diff_files, filter_diff_files, split_diff_by_file,
+};
+use crate::domain::policy::{cross_agent_prompt_vars, moderator_prompt_vars};
+use crate::domain::review::{
+    AgentReaction, ConsolidatedReview, FileReview, ProviderResponse, ProviderRun,
+    ReviewGranularity, ReviewRequest, RunOptions, TokenUsage, UsageBreakdown,
+};
 
 /// 1차 리뷰 실행 결과 묶음.
 pub(super) struct PrimaryReviewOutcome {
     pub primary_results: Vec<ProviderRun>,
-    pub agent_comments: Vec<AgentComment>,
+}
+
+/// 리뷰 요청 묶음과 리뷰 대상(필터 적용 후) diff 통계.
+pub(super) struct ReviewRequests {
+    pub requests: Vec<ReviewRequest>,
+    pub diff_stats: DiffStats,
+}
+
+/// include/exclude 필터를 적용한 뒤 리뷰할 텍스트 변경이 없으면 그 종류를 돌려준다.
+/// 이때는 provider를 호출하지 않고 안내 요약만 게시한다.
+pub(super) fn detect_unreviewable_diff(
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+) -> Option<UnreviewableDiff> {
+    let inputs = ctx.review_inputs.as_ref()?;
+    let (files, _) = filter_diff_files(
+        split_diff_by_file(&inputs.diff),
+        ctx.config.diff_include(),
+        &diff_exclude(options, ctx),
+    );
+    UnreviewableDiff::detect(&files)
+}
+
+/// 설정의 `diff_exclude`와 실행 옵션 `--exclude`를 합친 제외 glob.
+fn diff_exclude(options: &RunOptions, ctx: &ExecutionContext) -> Vec<String> {
+    ctx.config
+        .diff_exclude()
+        .iter()
+        .chain(&options.exclude)
+        .cloned()
+        .collect()
 }
 
 /// 리뷰 요청 객체를 구성한다(diff + system prompt).
-pub(super) async fn build_review_request(
+/// include/exclude glob에 걸린 파일 구간은 diff에서 통째로 제외한다.
+/// 남은 diff와 제목의 비밀값은 provider로 보내기 전에 가린다.
+/// 라벨/제목으로 판별한 PR 유형의 지침은 리뷰 가이드 뒤에 붙인다.
+/// diff가 `max_diff_bytes`를 넘으면 잘라내지 않고 파일 단위 청크 요청들로 나눈다.
+/// `review_granularity = "file"`이면 크기와 무관하게 변경 파일마다 요청을 만든다.
+/// `path_guides`는 요청(청크)마다 그 요청에 담긴 파일과 관련된 가이드만 넣는다.
+/// 필터 적용 후 남은 파일의 diff 통계를 `Diff Stats`로 보고하고 함께 반환한다.
+pub(super) async fn build_review_requests(
     use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
     ctx: &ExecutionContext,
-) -> Result<ReviewRequest> {
-    use_case.reporter.status("VCS", "fetching diff");
-    let diff = ctx.vcs.fetch_diff().await?;
+) -> Result<ReviewRequests> {
+    let inputs = ctx
+        .review_inputs
+        .as_ref()
+        .context("review title/diff were not fetched")?;
+    let diff = Arc::clone(&inputs.diff);
     use_case.reporter.kv("Diff Bytes", &diff.len().to_string());
 
+    // 파일 구간은 원본 diff를 빌려 쓰므로 필터/마스킹으로 바뀐 경우에만 diff를 새로 만든다.
+    let mut files = split_diff_by_file(&inputs.diff);
+    let include = ctx.config.diff_include();
+    let exclude = diff_exclude(options, ctx);
+    let diff = if include.is_empty() && exclude.is_empty() {
+        diff
+    } else {
+        let (kept, dropped) = filter_diff_files(files, include, &exclude);
+        files = kept;
+        if !dropped.is_empty() {
+            use_case.reporter.kv("Excluded Files", &dropped.join(", "));
+        }
+        let filtered: String = files.iter().map(|f| f.text.as_ref()).collect();
+        use_case
+            .reporter
+            .kv("Filtered Diff Bytes", &filtered.len().to_string());
+        if filtered.trim().is_empty() {
+            bail!("no diff left to review after applying diff_include/diff_exclude filters");
+        }
+        Arc::from(filtered)
+    };
+    let diff_stats = DiffStats::from_files(&files);
+    use_case.reporter.kv("Diff Stats", &diff_stats.summary());
+
+    let redactor = SecretRedactor::from_config(&ctx.config)?;
+    let mut redactions = RedactionReport::default();
+    let diff = match &redactor {
+        Some(redactor) => {
+            redactor.redact_files(&mut files, &mut redactions);
+            if redactions.is_empty() {
+                diff
+            } else {
+                Arc::from(files.iter().map(|f| f.text.as_ref()).collect::<String>())
+            }
+        }
+        None => diff,
+    };
+
+    let changed_files: Vec<String> = files.iter().map(|f| f.path.clone()).collect();
     let max = ctx.config.max_diff_bytes();
-    if diff.len() > max {
+    let granularity = ctx.config.review_granularity();
+    let chunks = if granularity == ReviewGranularity::File {
+        files
+            .into_iter()
+            .map(|file| DiffChunk {
+                files: vec![file.path],
+                text: file.text,
+            })
+            .collect()
+    } else if diff.len() > max {
+        let chunks = chunk_diff_files(&files, max);
         let msg = format!(
-            "warning: diff size ({} bytes) exceeds max_diff_bytes ({} bytes).",
+            "warning: diff size ({} bytes) exceeds max_diff_bytes ({} bytes); it will be reviewed in {} chunks.",
             diff.len(),
-            max
+            max,
+            chunks.len()
         );
         if !use_case.confirmer.confirm(&msg)? {
             bail!("cancelled by user");
         }
-    }
+        chunks
+    } else {
+        Vec::new()
+    };
 
     use_case.reporter.section("Prompt");
     let system_prompt = use_case
         .system_prompt_resolver
         .resolve(&ctx.config)
-        .context("failed to resolve system prompt with review guide")?;
+        .context("failed to resolve system prompt")?;
+    let mut review_guide = use_case
+        .system_prompt_resolver
+        .review_guide(&ctx.config)
+        .context("failed to resolve review guide")?
+        .unwrap_or_default();
+    let prompt_templates = use_case
+        .system_prompt_resolver
+        .prompt_templates(&ctx.config)
+        .context("failed to load prompt templates")?;
+    if let Some(dir) = &ctx.config.defaults.prompt_template_dir {
+        use_case.reporter.kv("Templates", dir);
+    }
 
     if let Some(path) = &ctx.config.defaults.review_guide_path {
         use_case.reporter.kv("Guide", path);
     } else {
         use_case.reporter.kv("Guide", "not set");
     }
+    append_remote_review_guides(use_case, ctx, &mut review_guide).await;
+    append_pr_type_guide(use_case, ctx, inputs, &mut review_guide)?;
+    let path_guides = PathGuides::load(use_case, ctx, &changed_files)?;
+
+    let title = match &redactor {
+        Some(redactor) => redactor
+            .redact(&inputs.title, "title", &mut redactions)
+            .into_owned(),
+        None => inputs.title.clone(),
+    };
+    if !redactions.is_empty() {
+        use_case.reporter.kv("Redacted", &redactions.summary());
+    }
 
-    Ok(ReviewRequest {
+    let base = ReviewRequest {
         target_url: ctx.target.url().to_string(),
         head_sha: ctx.head_sha.clone(),
-        diff,
+        title,
+        diff: Arc::from(""),
         system_prompt,
+        review_guide,
+        prompt_templates,
+        severity_taxonomy: ctx.config.severity_taxonomy(),
         comment_language: ctx.config.comment_language(),
+        granularity,
+        chunk_index: 1,
+        chunk_total: 1,
+        chunk_files: Vec::new(),
+    };
+
+    if granularity == ReviewGranularity::Pr && chunks.len() <= 1 {
+        let requests = vec![ReviewRequest {
+            diff,
+            review_guide: path_guides.guide_for(&base.review_guide, &changed_files),
+            ..base
+        }];
+        report_request_sizes(use_case, &requests);
+        return Ok(ReviewRequests {
+            requests,
+            diff_stats,
+        });
+    }
+    if chunks.is_empty() {
+        bail!("no changed files found in diff");
+    }
+
+    let label = match granularity {
+        ReviewGranularity::Pr => "Diff Chunks",
+        ReviewGranularity::File => "Files",
+    };
+    use_case.reporter.kv(label, &chunks.len().to_string());
+    let total = chunks.len();
+    let requests: Vec<ReviewRequest> = chunks
+        .into_iter()
+        .enumerate()
+        .map(|(idx, chunk)| ReviewRequest {
+            review_guide: path_guides.guide_for(&base.review_guide, &chunk.files),
+            diff: Arc::from(chunk.text.as_ref()),
+            chunk_index: idx + 1,
+            chunk_total: total,
+            chunk_files: chunk.files,
+            ..base.clone()
+        })
+        .collect();
+    report_request_sizes(use_case, &requests);
+    Ok(ReviewRequests {
+        requests,
+        diff_stats,
     })
 }
 
+/// 요청(청크)별 프롬프트 구성 요소 크기를 `--verbose` 진단으로 남긴다.
+fn report_request_sizes(use_case: &ReviewPrUseCase<'_>, requests: &[ReviewRequest]) {
+    for request in requests {
+        use_case.reporter.detail(
+            "Prompt",
+            &format!(
+                "request {}/{}: system {} B, guide {} B, diff {} B, title {} B",
+                request.chunk_index,
+                request.chunk_total,
+                request.system_prompt.len(),
+                request.review_guide.len(),
+                request.diff.len(),
+                request.title.len()
+            ),
+        );
+    }
+}
+
 /// 설정에서 활성 provider를 구성한다.
+/// `--providers`가 지정되면 해당 provider만 남긴다.
 pub(super) fn build_enabled_providers(
     use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
     ctx: &ExecutionContext,
 ) -> Result<Vec<Box<dyn ProviderAgent>>> {
-    let providers = use_case.provider_factory.build(&ctx.config);
+    let mut providers = use_case.provider_factory.build(&ctx.config);
     if providers.is_empty() {
         bail!(
-            "no providers enabled. Configure providers.<name>.api_key(_env) for API mode or providers.<name>.command for CLI mode"
+            "no providers enabled. Configure providers.<name>.api_key(_env) for API mode or providers.<name>.command for CLI mode, or run `repopilot init`"
+        );
+    }
+
+    if !options.providers.is_empty() {
+        let mut selected: Vec<String> = Vec::new();
+        for name in &options.providers {
+            let Some(id) = ctx.config.resolve_provider_id(name) else {
+                bail!(
+                    "unknown provider '{name}' (expected {})",
+                    ctx.config.known_provider_ids().join(", ")
+                );
+            };
+            selected.push(id);
+        }
+        let available: Vec<String> = providers.iter().map(|p| p.id().to_string()).collect();
+        providers.retain(|p| selected.iter().any(|id| id == p.id()));
+        if providers.is_empty() {
+            bail!(
+                "none of the selected providers ({}) are enabled; enabled: {}",
+                options.providers.join(", "),
+                available.join(", ")
+            );
+        }
+        use_case.reporter.kv(
+            "Selected",
+            &providers.iter().map(|p| p.id()).collect::<Vec<_>>().join(", "),
         );
     }
 
@@ -76,11 +304,14 @@ pub(super) fn build_enabled_providers(
 }
 
 /// provider 1차 리뷰를 병렬 실행한다.
+/// 청크가 여러 개면 provider별로 `chunk_concurrency`개씩 실행한 뒤 결과를 합친다.
+/// 실패한 provider는 오류를 콘솔에만 남기고 결과(교차 반응/게시/사용량 집계)에서 뺀다.
 pub(super) async fn run_primary_reviews(
     use_case: &ReviewPrUseCase<'_>,
     providers: &[Box<dyn ProviderAgent>],
-    request: &ReviewRequest,
-) -> PrimaryReviewOutcome {
+    requests: &[ReviewRequest],
+    chunk_concurrency: usize,
+) -> Result<PrimaryReviewOutcome> {
     let mut primary_futures = FuturesUnordered::new();
 
     for provider in providers {
@@ -89,145 +320,397 @@ pub(super) async fn run_primary_reviews(
         use_case
             .reporter
             .provider_status(&provider_name, "running", None);
-        let provider_request = request.clone();
         primary_futures.push(async move {
             let started = Instant::now();
-            match provider.review(&provider_request).await {
-                Ok(resp) => {
-                    let display_name = provider_name.clone();
-                    (
-                        display_name,
-                        ProviderRun {
-                            id: provider_id,
-                            name: provider_name,
-                            body: resp.content,
-                            usage: resp.usage,
-                        },
-                        false,
-                        started.elapsed().as_secs_f32(),
-                    )
-                }
-                Err(err) => {
-                    let display_name = provider_name.clone();
-                    (
-                        display_name,
-                        ProviderRun {
-                            id: provider_id,
-                            name: provider_name,
-                            body: format!("_Error: {}_", err),
-                            usage: TokenUsage::default(),
-                        },
-                        true,
-                        started.elapsed().as_secs_f32(),
-                    )
-                }
-            }
+            let result =
+                review_chunks(use_case, provider.as_ref(), requests, chunk_concurrency).await;
+            let sec = started.elapsed().as_secs_f32();
+            let run = result.map(|(resp, file_reviews)| ProviderRun {
+                id: provider_id,
+                name: provider_name.clone(),
+                body: resp.content,
+                usage: resp.usage,
+                findings: resp.findings,
+                file_reviews,
+                elapsed_secs: Some(sec),
+            });
+            (provider_name, run, sec)
         });
     }
 
     let mut primary_results = Vec::new();
-    while let Some((name, run, is_error, sec)) = primary_futures.next().await {
-        if is_error {
-            use_case
-                .reporter
-                .provider_status(&name, "error", Some(&format!("{sec:.1}s")));
-        } else {
-            use_case
-                .reporter
-                .provider_status(&name, "done", Some(&format!("{sec:.1}s")));
+    let mut failures = Vec::new();
+    while let Some((name, run, sec)) = primary_futures.next().await {
+        match run {
+            Ok(run) => {
+                use_case.reporter.provider_status(&name, "done", Some(sec));
+                use_case
+                    .reporter
+                    .provider_output(&name, &run.body, &run.usage);
+                primary_results.push(run);
+            }
+            Err(err) => {
+                use_case.reporter.provider_status(&name, "error", Some(sec));
+                use_case
+                    .reporter
+                    .status(&name, &format!("excluded from this review: {err:#}"));
+                failures.push(name);
+            }
         }
-        primary_results.push(run);
     }
 
-    let agent_comments: Vec<AgentComment> = primary_results
-        .iter()
-        .map(|r| AgentComment {
-            provider_id: r.id.clone(),
-            provider_name: r.name.clone(),
-            body: r.body.clone(),
-            usage: r.usage.clone(),
-        })
-        .collect();
+    if primary_results.is_empty() {
+        bail!("all providers failed ({})", failures.join(", "));
+    }
+    Ok(PrimaryReviewOutcome { primary_results })
+}
+
+/// provider 결과를 `defaults.provider_order` 순서로 정렬한다(안정 정렬, 순서에 없는 id는 뒤로).
+/// 병렬 실행의 완료 순서가 실행마다 달라도 게시/렌더링 순서는 같게 한다.
+pub(super) fn sort_by_provider_order<T>(
+    order: &[String],
+    items: &mut [T],
+    provider_id: impl Fn(&T) -> &str,
+) {
+    items.sort_by_key(|item| {
+        order
+            .iter()
+            .position(|id| id == provider_id(item))
+            .unwrap_or(order.len())
+    });
+}
+
+/// 청크 요청들을 실행하고 하나의 응답으로 합친다.
+/// 파일 단위 모드에서는 파일별 본문도 함께 반환한다.
+/// 일부 청크만 실패하면 그 부분은 검토하지 못했다고만 적고(파일 스레드는 만들지 않음) 오류는 콘솔에 남긴다.
+/// 모든 청크가 실패한 경우에만 에러를 반환한다.
+async fn review_chunks(
+    use_case: &ReviewPrUseCase<'_>,
+    provider: &dyn ProviderAgent,
+    requests: &[ReviewRequest],
+    concurrency: usize,
+) -> Result<(ProviderResponse, Vec<FileReview>)> {
+    if let [single] = requests
+        && single.granularity == ReviewGranularity::Pr
+    {
+        return Ok((provider.review(single).await?, Vec::new()));
+    }
+
+    let results: Vec<Result<ProviderResponse>> = stream::iter(requests)
+        .map(|request| provider.review(request))
+        .buffered(concurrency)
+        .collect()
+        .await;
+
+    if results.iter().all(|r| r.is_err()) {
+        let first = results.into_iter().find_map(|r| r.err());
+        return Err(first.unwrap_or_else(|| anyhow::anyhow!("no diff chunks to review")));
+    }
 
-    PrimaryReviewOutcome {
-        primary_results,
-        agent_comments,
+    let mut merged = ProviderResponse {
+        content: String::new(),
+        usage: TokenUsage::default(),
+        findings: Vec::new(),
+    };
+    let mut file_reviews = Vec::new();
+    for (request, result) in requests.iter().zip(results) {
+        let files = request.chunk_files.join(", ");
+        let header = match request.granularity {
+            ReviewGranularity::Pr => format!(
+                "### Part {}/{} ({})",
+                request.chunk_index, request.chunk_total, files
+            ),
+            ReviewGranularity::File => format!("### `{}`", files),
+        };
+        let body = match result {
+            Ok(resp) => {
+                merged.usage.add_from(&resp.usage);
+                merged.findings.extend(resp.findings);
+                resp.content.trim().to_string()
+            }
+            Err(err) => {
+                use_case.reporter.status(
+                    provider.name(),
+                    &format!("{header} was not reviewed: {err:#}"),
+                );
+                merged.content.push_str(&format!(
+                    "{}\n\n_Not reviewed (provider request failed)._\n\n",
+                    header
+                ));
+                continue;
+            }
+        };
+        merged.content.push_str(&format!("{}\n\n{}\n\n", header, body));
+        if request.granularity == ReviewGranularity::File {
+            file_reviews.push(FileReview { path: files, body });
+        }
     }
+    merged.content = merged.content.trim_end().to_string();
+    Ok((merged, file_reviews))
 }
 
-/// provider 간 상호 코멘트를 병렬 실행한다.
+/// provider 간 상호 코멘트를 설정된 라운드 수만큼 실행한다.
+/// N번째 라운드는 N-1번째 라운드의 반응을 입력으로 받는다.
 pub(super) async fn run_cross_agent_reactions(
     use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
     providers: &[Box<dyn ProviderAgent>],
     request: &ReviewRequest,
     primary_results: &[ProviderRun],
+    rounds: usize,
 ) -> Vec<AgentReaction> {
-    if providers.len() <= 1 {
+    // 1차 리뷰에 실패해 결과에서 빠진 provider는 반응 라운드에도 참여시키지 않는다.
+    let providers: Vec<&dyn ProviderAgent> = providers
+        .iter()
+        .map(|provider| provider.as_ref())
+        .filter(|provider| primary_results.iter().any(|run| run.id == provider.id()))
+        .collect();
+    if providers.len() <= 1 || rounds == 0 {
         return Vec::new();
     }
 
-    use_case.reporter.section("Providers (Cross-Agent Reactions)");
+    let mut reactions = Vec::new();
+    let mut previous: Vec<AgentReaction> = Vec::new();
+    for round in 1..=rounds {
+        if rounds == 1 {
+            use_case.reporter.section("Providers (Cross-Agent Reactions)");
+        } else {
+            use_case.reporter.section(&format!(
+                "Providers (Cross-Agent Reactions, Round {round}/{rounds})"
+            ));
+        }
 
+        let current = run_reaction_round(
+            use_case,
+            config,
+            &providers,
+            request,
+            primary_results,
+            &previous,
+            round,
+        )
+        .await;
+        reactions.extend(current.iter().cloned());
+        previous = current;
+    }
+
+    reactions
+}
+
+/// 단일 반응 라운드를 병렬 실행한다. 실패한 반응은 오류를 콘솔에만 남기고 뺀다.
+/// 캐시된 반응은 provider를 호출하지 않으며 토큰 사용량도 0으로 본다.
+async fn run_reaction_round(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    providers: &[&dyn ProviderAgent],
+    request: &ReviewRequest,
+    primary_results: &[ProviderRun],
+    previous: &[AgentReaction],
+    round: usize,
+) -> Vec<AgentReaction> {
     let mut reaction_futures = FuturesUnordered::new();
+    let mut reactions = Vec::new();
 
     for provider in providers {
+        let provider_id = provider.id().to_string();
         let provider_name = provider.name().to_string();
-        use_case
-            .reporter
-            .provider_status(&provider_name, "running", None);
-        let prompt = build_cross_agent_prompt(
-            &request.target_url,
-            &request.head_sha,
+        let vars = cross_agent_prompt_vars(
+            request,
             provider.id(),
             &provider_name,
-            request.comment_language,
             primary_results,
+            previous,
         );
+        let prompt = match use_case
+            .templates
+            .render(&request.prompt_templates.cross_agent, &vars)
+        {
+            Ok(prompt) => prompt,
+            Err(err) => {
+                use_case
+                    .reporter
+                    .status(&provider_name, &format!("reaction excluded: {err:#}"));
+                continue;
+            }
+        };
+        let cache_key = response_cache_key(config, &provider_id, &prompt);
+        if let Some(body) = load_cached_response(use_case, config, &cache_key) {
+            use_case
+                .reporter
+                .provider_status(&provider_name, "cached", None);
+            use_case
+                .reporter
+                .provider_output(&provider_name, &body, &TokenUsage::default());
+            reactions.push(AgentReaction {
+                provider_id,
+                provider_name,
+                round,
+                body,
+                usage: TokenUsage::default(),
+            });
+            continue;
+        }
+        use_case
+            .reporter
+            .provider_status(&provider_name, "running", None);
 
         reaction_futures.push(async move {
             let started = Instant::now();
-            match provider.review_prompt(&prompt).await {
-                Ok(resp) => {
-                    let display_name = provider_name.clone();
-                    (
-                        display_name,
-                        AgentReaction {
-                            provider_name,
-                            body: resp.content,
-                        },
-                        false,
-                        started.elapsed().as_secs_f32(),
-                    )
-                }
-                Err(err) => {
-                    let display_name = provider_name.clone();
-                    (
-                        display_name,
-                        AgentReaction {
-                            provider_name,
-                            body: format!("_Error: {}_", err),
-                        },
-                        true,
-                        started.elapsed().as_secs_f32(),
-                    )
-                }
+            let reaction = provider
+                .review_prompt(&prompt)
+                .await
+                .map(|resp| AgentReaction {
+                    provider_id,
+                    provider_name: provider_name.clone(),
+                    round,
+                    body: resp.content,
+                    usage: resp.usage,
+                });
+            (
+                provider_name,
+                cache_key,
+                reaction,
+                started.elapsed().as_secs_f32(),
+            )
+        });
+    }
+
+    while let Some((name, cache_key, reaction, sec)) = reaction_futures.next().await {
+        match reaction {
+            Ok(reaction) => {
+                use_case.reporter.provider_status(&name, "done", Some(sec));
+                use_case
+                    .reporter
+                    .provider_output(&name, &reaction.body, &reaction.usage);
+                save_cached_response(use_case, config, &cache_key, &reaction.body);
+                reactions.push(reaction);
+            }
+            Err(err) => {
+                use_case.reporter.provider_status(&name, "error", Some(sec));
+                use_case
+                    .reporter
+                    .status(&name, &format!("reaction excluded: {err:#}"));
             }
+        }
+    }
+    sort_by_provider_order(&config.provider_order(), &mut reactions, |reaction| {
+        &reaction.provider_id
+    });
+
+    reactions
+}
+
+/// 지정된 moderator provider로 모든 의견을 하나의 통합 리뷰로 합성한다.
+/// 미지정/비활성/실패 시에는 None을 반환하고 기존 요약 형식으로 폴백한다.
+pub(super) async fn run_moderator_synthesis(
+    use_case: &ReviewPrUseCase<'_>,
+    config: &Config,
+    providers: &[Box<dyn ProviderAgent>],
+    request: &ReviewRequest,
+    primary_results: &[ProviderRun],
+    reactions: &[AgentReaction],
+    moderator_id: Option<&str>,
+) -> Option<ConsolidatedReview> {
+    let moderator_id = moderator_id?;
+
+    use_case.reporter.section("Providers (Moderator Synthesis)");
+    let Some(provider) = providers.iter().find(|p| p.id() == moderator_id) else {
+        use_case.reporter.status(
+            "Moderator",
+            &format!("provider '{moderator_id}' is not enabled; skipping synthesis"),
+        );
+        return None;
+    };
+
+    let provider_name = provider.name().to_string();
+    let vars = moderator_prompt_vars(request, primary_results, reactions);
+    let prompt = match use_case
+        .templates
+        .render(&request.prompt_templates.moderator, &vars)
+    {
+        Ok(prompt) => prompt,
+        Err(err) => {
+            use_case
+                .reporter
+                .status("Moderator", &format!("synthesis failed: {err}"));
+            return None;
+        }
+    };
+    let cache_key = response_cache_key(config, provider.id(), &prompt);
+    if let Some(body) = load_cached_response(use_case, config, &cache_key) {
+        use_case
+            .reporter
+            .provider_status(&provider_name, "cached", None);
+        use_case
+            .reporter
+            .provider_output(&provider_name, &body, &TokenUsage::default());
+        return Some(ConsolidatedReview {
+            provider_id: provider.id().to_string(),
+            provider_name,
+            body,
+            usage: TokenUsage::default(),
         });
     }
+    use_case
+        .reporter
+        .provider_status(&provider_name, "running", None);
 
-    let mut reactions = Vec::new();
-    while let Some((name, reaction, is_error, sec)) = reaction_futures.next().await {
-        if is_error {
+    let started = Instant::now();
+    match provider.review_prompt(&prompt).await {
+        Ok(resp) => {
+            let sec = started.elapsed().as_secs_f32();
             use_case
                 .reporter
-                .provider_status(&name, "error", Some(&format!("{sec:.1}s")));
-        } else {
+                .provider_status(&provider_name, "done", Some(sec));
+            use_case
+                .reporter
+                .provider_output(&provider_name, &resp.content, &resp.usage);
+            save_cached_response(use_case, config, &cache_key, &resp.content);
+            Some(ConsolidatedReview {
+                provider_id: provider.id().to_string(),
+                provider_name,
+                body: resp.content,
+                usage: resp.usage,
+            })
+        }
+        Err(err) => {
+            let sec = started.elapsed().as_secs_f32();
+            use_case
+                .reporter
+                .provider_status(&provider_name, "error", Some(sec));
             use_case
                 .reporter
-                .provider_status(&name, "done", Some(&format!("{sec:.1}s")));
+                .status("Moderator", &format!("synthesis failed: {err}"));
+            None
         }
-        reactions.push(reaction);
     }
+}
+
+/// provider별 단계 토큰 사용량 표를 출력한다. 사용량을 보고한 provider가 없으면 생략한다.
+/// 전체(Total)에는 통합 리뷰(moderator)와 보조 리뷰 단계(PR 메타 리뷰 등) 사용량도 포함된다.
+pub(super) fn report_usage(use_case: &ReviewPrUseCase<'_>, rows: &[UsageBreakdown]) {
+    if rows.iter().all(|row| row.total.total().is_none()) {
+        return;
+    }
+    let tokens = |usage: &TokenUsage| {
+        usage
+            .total()
+            .map(|tokens| tokens.to_string())
+            .unwrap_or_else(|| "-".to_string())
+    };
 
-    reactions
+    use_case.reporter.section("Token Usage");
+    use_case.reporter.raw(&format!(
+        "{:<24} {:>10} {:>10} {:>10}",
+        "PROVIDER", "PRIMARY", "REACTION", "TOTAL"
+    ));
+    for row in rows {
+        use_case.reporter.raw(&format!(
+            "{:<24} {:>10} {:>10} {:>10}",
+            row.provider_name,
+            tokens(&row.primary),
+            tokens(&row.reaction),
+            tokens(&row.total)
+        ));
+    }
 }
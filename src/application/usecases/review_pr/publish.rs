@@ -1,10 +1,49 @@
 //! 개별/최종 코멘트 렌더링 및 게시 단계.
 
+use std::time::Instant;
+
 use anyhow::{Context, Result};
 
+use crate::application::usecases::review_pr::dedupe::claim_still_owned;
 use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
-use crate::domain::policy::{agent_marker, find_comment_with_marker, upsert_comment_cache};
-use crate::domain::review::{AgentComment, AgentReaction, RunOptions};
+use crate::domain::comment_size::{
+    CommentOverflow, PART_HEADER_RESERVE, comment_chars, split_comment, truncate_comment,
+};
+use crate::domain::diff::{DiffLineMap, UnreviewableDiff, split_diff_by_file};
+use crate::domain::marker::{
+    FINAL_PART_PARENT, agent_part_parent, find_agent_comment, find_file_thread_comment,
+    find_latest_agent_comment, find_part_comments, find_suggestion_comment, part_marker,
+    same_comment_body,
+};
+use crate::domain::policy::{finding_id, upsert_comment_cache};
+use crate::domain::review::{
+    AgentComment, ConsensusFinding, DedupePolicy, FileThread, ProviderRun, ReviewComment,
+    ReviewSummary, RunFooter, RunOptions, SeverityTaxonomy,
+};
+
+/// 코멘트 끝에 붙일 실행 정보. `defaults.comment_footer`가 false면 None.
+pub(super) fn run_footer<'r>(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    runs: impl IntoIterator<Item = &'r ProviderRun>,
+    elapsed_secs: Option<f32>,
+) -> Option<RunFooter> {
+    if !ctx.config.comment_footer() {
+        return None;
+    }
+    Some(RunFooter {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        models: runs
+            .into_iter()
+            .map(|run| {
+                let model = ctx.config.provider_model(&run.id).map(ToString::to_string);
+                (run.name.clone(), model)
+            })
+            .collect(),
+        elapsed_secs,
+        posted_at: use_case.clock.unix_now(),
+    })
+}
 
 /// 개별 에이전트 코멘트를 출력(dry-run) 또는 게시(upsert)한다.
 pub(super) async fn publish_agent_comments(
@@ -21,9 +60,13 @@ pub(super) async fn publish_agent_comments(
             use_case
                 .reporter
                 .raw(&format!("--- {} ---", agent.provider_name));
-            let markdown = use_case
-                .renderer
-                .render_agent(&ctx.head_sha, ctx.target.url(), agent);
+            let markdown = use_case.renderer.render_agent(
+                &ctx.head_sha,
+                ctx.target.url(),
+                agent,
+                &ctx.comment_templates.agent,
+            )?;
+            fit_comment(use_case, ctx, &agent.provider_name, &markdown);
             use_case.reporter.raw(&markdown);
         }
         return Ok(agent_comment_refs);
@@ -31,58 +74,461 @@ pub(super) async fn publish_agent_comments(
 
     use_case.reporter.section("Post Individual Comments");
     for agent in agent_comments {
-        let marker = agent_marker(&agent.provider_id, &ctx.head_sha);
-        let markdown = use_case
-            .renderer
-            .render_agent(&ctx.head_sha, ctx.target.url(), agent);
-        let existing = find_comment_with_marker(&ctx.existing_comments, &marker).map(|c| c.id.clone());
+        let markdown = use_case.renderer.render_agent(
+            &ctx.head_sha,
+            ctx.target.url(),
+            agent,
+            &ctx.comment_templates.agent,
+        )?;
+        let (markdown, continuations) = fit_comment(use_case, ctx, &agent.provider_name, &markdown);
+        let existing = match ctx.config.dedupe_policy() {
+            DedupePolicy::PerSha => {
+                find_agent_comment(&ctx.existing_comments, &agent.provider_id, &ctx.head_sha)
+            }
+            DedupePolicy::Single => {
+                find_latest_agent_comment(&ctx.existing_comments, &agent.provider_id)
+            }
+            DedupePolicy::AlwaysNew => None,
+        }
+        .cloned();
 
-        let posted = if let Some(comment_id) = existing {
-            use_case
-                .reporter
-                .status(&agent.provider_name, "updating comment");
-            ctx.vcs.update_comment(&comment_id, &markdown).await?
-        } else {
-            use_case
-                .reporter
-                .status(&agent.provider_name, "creating comment");
-            ctx.vcs.create_comment(&markdown).await?
+        let posted = match existing {
+            Some(comment) if same_comment_body(&comment.body, &markdown) => {
+                use_case
+                    .reporter
+                    .status(&agent.provider_name, "comment unchanged; skipping update");
+                comment
+            }
+            Some(comment) => {
+                use_case
+                    .reporter
+                    .status(&agent.provider_name, "updating comment");
+                ctx.vcs.update_comment(&comment.id, &markdown).await?
+            }
+            None => {
+                use_case
+                    .reporter
+                    .status(&agent.provider_name, "creating comment");
+                ctx.vcs.create_comment(&markdown).await?
+            }
         };
 
+        let first_id = posted.id.clone();
         agent_comment_refs.push((agent.provider_name.clone(), posted.id.clone()));
         upsert_comment_cache(&mut ctx.existing_comments, posted);
+        publish_continuations(
+            use_case,
+            ctx,
+            &agent.provider_name,
+            &agent_part_parent(&agent.provider_id),
+            &first_id,
+            &continuations,
+        )
+        .await?;
     }
 
     Ok(agent_comment_refs)
 }
 
+/// 파일 단위 리뷰 스레드를 출력(dry-run) 또는 게시한다.
+/// 에이전트 코멘트와 같은 `dedupe_policy`로 이미 있는 스레드(파일 마커)를 찾아 수정하고, 없을 때만 만든다.
+/// 스레드 생성/수정 실패는 전체 리뷰를 중단하지 않고 상태만 보고한다.
+pub(super) async fn publish_file_threads(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    threads: &[FileThread],
+) {
+    if threads.is_empty() {
+        return;
+    }
+
+    if options.dry_run {
+        use_case.reporter.section("Dry Run: File Threads");
+        for thread in threads {
+            use_case.reporter.raw(&format!("--- {} ---", thread.path));
+            let markdown = use_case.renderer.render_file_thread(&ctx.head_sha, thread);
+            use_case.reporter.raw(&markdown);
+        }
+        return;
+    }
+
+    use_case.reporter.section("Post File Threads");
+    let existing_threads = match ctx.config.dedupe_policy() {
+        DedupePolicy::AlwaysNew => Vec::new(),
+        _ => match ctx.vcs.list_review_comments().await {
+            Ok(comments) => comments,
+            Err(err) => {
+                use_case.reporter.status(
+                    "File Threads",
+                    &format!("failed to list review comments; creating new threads: {err:#}"),
+                );
+                Vec::new()
+            }
+        },
+    };
+    for thread in threads {
+        let mut markdown = use_case.renderer.render_file_thread(&ctx.head_sha, thread);
+        // 파일 스레드는 이어지는 코멘트를 달 수 없어 항상 자른다.
+        if let Some(limit) = ctx.config.max_comment_chars(&ctx.target)
+            && comment_chars(&markdown) > limit
+        {
+            use_case.reporter.status(
+                &thread.path,
+                &format!("thread exceeds the {limit}-character limit; truncated"),
+            );
+            markdown = truncate_comment(&markdown, limit);
+        }
+        let sha = match ctx.config.dedupe_policy() {
+            DedupePolicy::PerSha => Some(ctx.head_sha.as_str()),
+            _ => None,
+        };
+        let existing = find_file_thread_comment(&existing_threads, &thread.path, sha);
+        let result = match existing {
+            Some(comment) if same_comment_body(&comment.body, &markdown) => {
+                use_case
+                    .reporter
+                    .status(&thread.path, "thread unchanged; skipping update");
+                continue;
+            }
+            Some(comment) => ctx
+                .vcs
+                .update_review_comment(&comment.id, &markdown)
+                .await
+                .map(|_| "thread updated"),
+            None => ctx
+                .vcs
+                .create_file_comment(&ctx.head_sha, &thread.path, &markdown)
+                .await
+                .map(|_| "thread created"),
+        };
+        match result {
+            Ok(status) => use_case.reporter.status(&thread.path, status),
+            Err(err) => use_case
+                .reporter
+                .status(&thread.path, &format!("thread failed: {err}")),
+        }
+    }
+}
+
+/// 수정안이 있는 합의 지적사항을 지적 구간의 인라인 제안 코멘트로 출력(dry-run) 또는 게시한다.
+/// 확신도가 낮아 사람 확인이 필요한 항목과 구간이 한 diff hunk 안에 있지 않은 항목은 건너뛴다.
+/// 같은 지적사항/줄 구간의 제안(suggestion 마커)이 이미 있으면 다시 달지 않고 바뀐 경우에만 수정한다.
+/// 코멘트 생성/수정 실패는 전체 리뷰를 중단하지 않고 상태만 보고한다.
+pub(super) async fn publish_suggestions(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    consensus: &[ConsensusFinding],
+    low_confidence_below: f32,
+    taxonomy: &SeverityTaxonomy,
+) {
+    if !ctx.config.inline_suggestions() {
+        return;
+    }
+    let candidates: Vec<_> = consensus
+        .iter()
+        .map(|item| &item.finding)
+        .filter(|f| f.suggestion.is_some() && !f.is_below_confidence(low_confidence_below))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    // `resume`은 diff를 다시 받지 않으므로 이때만 조회한다(저널 저장 후 HEAD SHA가 같음을 확인했다).
+    let diff = match &ctx.review_inputs {
+        Some(inputs) => inputs.diff.clone(),
+        None => match ctx.vcs.fetch_diff().await {
+            Ok(diff) => diff.into(),
+            Err(err) => {
+                use_case.reporter.status(
+                    "Suggestions",
+                    &format!("failed to fetch diff; skipping suggestions: {err:#}"),
+                );
+                return;
+            }
+        },
+    };
+    let files = split_diff_by_file(&diff);
+
+    use_case.reporter.section(if options.dry_run {
+        "Dry Run: Suggestions"
+    } else {
+        "Post Suggestions"
+    });
+    let existing_suggestions = if options.dry_run
+        || matches!(ctx.config.dedupe_policy(), DedupePolicy::AlwaysNew)
+    {
+        Vec::new()
+    } else {
+        match ctx.vcs.list_review_comments().await {
+            Ok(comments) => comments,
+            Err(err) => {
+                use_case.reporter.status(
+                    "Suggestions",
+                    &format!("failed to list review comments; posting new suggestions: {err:#}"),
+                );
+                Vec::new()
+            }
+        }
+    };
+    for finding in candidates {
+        let (Some(path), Some(range)) = (finding.file.as_deref(), finding.line_range) else {
+            continue;
+        };
+        let scope = finding.location().unwrap_or_else(|| path.to_string());
+        let Some(position) = files
+            .iter()
+            .find(|file| file.path == path)
+            .and_then(|file| DiffLineMap::from_file(file).position(range))
+        else {
+            use_case
+                .reporter
+                .status(&scope, "lines are outside the diff; suggestion skipped");
+            continue;
+        };
+        let markdown = use_case
+            .renderer
+            .render_suggestion(ctx.target.url(), finding, taxonomy);
+        if options.dry_run {
+            use_case.reporter.raw(&format!("--- {scope} ---"));
+            use_case.reporter.raw(&markdown);
+            continue;
+        }
+        let existing = find_suggestion_comment(&existing_suggestions, &finding_id(finding), range);
+        let result = match existing {
+            Some(comment) if same_comment_body(&comment.body, &markdown) => {
+                use_case
+                    .reporter
+                    .status(&scope, "suggestion already posted; skipping");
+                continue;
+            }
+            Some(comment) => ctx
+                .vcs
+                .update_review_comment(&comment.id, &markdown)
+                .await
+                .map(|_| "suggestion updated"),
+            None => ctx
+                .vcs
+                .create_line_comment(&ctx.head_sha, path, &position, &markdown)
+                .await
+                .map(|_| "suggestion posted"),
+        };
+        match result {
+            Ok(status) => use_case.reporter.status(&scope, status),
+            Err(err) => use_case
+                .reporter
+                .status(&scope, &format!("suggestion failed: {err}")),
+        }
+    }
+}
+
 /// 최종 요약 코멘트를 출력(dry-run) 또는 claim 코멘트를 갱신한다.
 pub(super) async fn publish_final_summary(
     use_case: &ReviewPrUseCase<'_>,
     options: &RunOptions,
     ctx: &mut ExecutionContext,
     claim_comment_id: Option<&str>,
-    reactions: &[AgentReaction],
-    agent_comment_refs: &[(String, String)],
-) -> Result<()> {
+    summary: &ReviewSummary,
+) -> Result<String> {
     let final_markdown = use_case.renderer.render_final(
         &ctx.head_sha,
         ctx.target.url(),
-        reactions,
-        agent_comment_refs,
-    );
+        summary,
+        &ctx.comment_templates.final_summary,
+    )?;
+    post_final_markdown(use_case, options, ctx, claim_comment_id, final_markdown).await
+}
 
+/// 리뷰할 텍스트 변경이 없을 때 provider 없이 안내 요약만 출력(dry-run)하거나 claim 코멘트를 갱신한다.
+pub(super) async fn publish_unreviewable_summary(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &mut ExecutionContext,
+    claim_comment_id: Option<&str>,
+    kind: UnreviewableDiff,
+) -> Result<String> {
+    use_case.reporter.section("Summary");
+    let reason = kind.describe();
+    use_case
+        .reporter
+        .status("Review", &format!("skipping providers: {reason}"));
+    let markdown = use_case
+        .renderer
+        .render_unreviewable(&ctx.head_sha, ctx.target.url(), reason);
+    post_final_markdown(use_case, options, ctx, claim_comment_id, markdown).await
+}
+
+async fn post_final_markdown(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &mut ExecutionContext,
+    claim_comment_id: Option<&str>,
+    final_markdown: String,
+) -> Result<String> {
     if options.dry_run {
         use_case.reporter.section("Dry Run: Final Summary Comment");
-        use_case.reporter.raw(&final_markdown);
-        return Ok(());
+        fit_comment(use_case, ctx, "Summary", &final_markdown);
+        use_case.reporter.outcome(&final_markdown);
+        return Ok(final_markdown);
     }
 
     let claim_comment_id = claim_comment_id
         .context("internal error: missing claim comment id for non-dry-run")?;
 
-    ctx.vcs.update_comment(claim_comment_id, &final_markdown).await?;
+    if !claim_still_owned(ctx, claim_comment_id).await? {
+        use_case.reporter.status(
+            "Claim",
+            "claim was taken over by another runner; final summary not posted",
+        );
+        use_case.reporter.outcome(&format!(
+            "final summary skipped: another runner took over the review of {}",
+            ctx.target.url()
+        ));
+        return Ok(final_markdown);
+    }
+
+    let started = Instant::now();
+    let (first_markdown, continuations) = fit_comment(use_case, ctx, "Summary", &final_markdown);
+    ctx.vcs
+        .update_comment(claim_comment_id, &first_markdown)
+        .await?;
+    publish_continuations(
+        use_case,
+        ctx,
+        "Summary",
+        FINAL_PART_PARENT,
+        claim_comment_id,
+        &continuations,
+    )
+    .await?;
+    use_case.reporter.detail(
+        "VCS",
+        &format!(
+            "final summary updated in {:.2}s",
+            started.elapsed().as_secs_f32()
+        ),
+    );
     use_case.reporter.section("Done");
-    use_case.reporter.status("VCS", "final summary comment posted");
+    use_case.reporter.outcome(&format!(
+        "final summary comment posted: {}",
+        ctx.target.url()
+    ));
+    Ok(final_markdown)
+}
+
+/// 호스트 코멘트 길이 한도에 맞춰 본문을 나누거나(`split`) 자른다(`truncate`).
+/// 반환값은 (첫 코멘트 본문, 이어지는 조각 본문들). 조각에는 아직 마커/머리말이 없다.
+fn fit_comment(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    label: &str,
+    markdown: &str,
+) -> (String, Vec<String>) {
+    let Some(limit) = ctx.config.max_comment_chars(&ctx.target) else {
+        return (markdown.to_string(), Vec::new());
+    };
+    if comment_chars(markdown) <= limit {
+        return (markdown.to_string(), Vec::new());
+    }
+
+    match ctx.config.comment_overflow() {
+        CommentOverflow::Truncate => {
+            use_case.reporter.status(
+                label,
+                &format!("comment exceeds the {limit}-character limit; truncated"),
+            );
+            (truncate_comment(markdown, limit), Vec::new())
+        }
+        CommentOverflow::Split => {
+            let mut parts = split_comment(markdown, limit - PART_HEADER_RESERVE);
+            let total = parts.len();
+            use_case.reporter.status(
+                label,
+                &format!(
+                    "comment exceeds the {limit}-character limit; split into {total} comments"
+                ),
+            );
+            let mut first = parts.remove(0);
+            first.truncate(first.trim_end().len());
+            first.push_str(&format!(
+                "\n\n_Continued in the next comment (part 1/{total})._\n"
+            ));
+            (first, parts)
+        }
+    }
+}
+
+/// 이어지는 조각 코멘트를 게시/갱신한다. 조각은 `kind=part` 마커와 첫 코멘트 링크로 연결된다.
+/// 이전 실행보다 조각 수가 줄었으면 남은 조각 코멘트는 더 이상 쓰지 않는다는 안내로 바꾼다.
+async fn publish_continuations(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &mut ExecutionContext,
+    label: &str,
+    parent: &str,
+    first_comment_id: &str,
+    parts: &[String],
+) -> Result<()> {
+    let existing: Vec<(usize, ReviewComment)> = match ctx.config.dedupe_policy() {
+        DedupePolicy::PerSha => {
+            find_part_comments(&ctx.existing_comments, parent, Some(&ctx.head_sha))
+        }
+        DedupePolicy::Single => find_part_comments(&ctx.existing_comments, parent, None),
+        DedupePolicy::AlwaysNew => Default::default(),
+    }
+    .into_iter()
+    .map(|(index, comment)| (index, comment.clone()))
+    .collect();
+
+    let total = parts.len() + 1;
+    let first_link = match ctx.target.comment_url(first_comment_id) {
+        Some(url) => format!("[the first comment]({url})"),
+        None => format!("the first comment (id `{first_comment_id}`)"),
+    };
+    for (offset, part) in parts.iter().enumerate() {
+        let index = offset + 2;
+        let mut body = format!(
+            "{}\n\n_Part {index}/{total}, continued from {first_link}._\n\n{}",
+            part_marker(parent, index, &ctx.head_sha),
+            part.trim_end()
+        );
+        if index < total {
+            body.push_str(&format!(
+                "\n\n_Continued in the next comment (part {index}/{total})._"
+            ));
+        }
+        body.push('\n');
+
+        let existing_comment = existing
+            .iter()
+            .find(|(i, _)| *i == index)
+            .map(|(_, comment)| comment);
+        let posted = match existing_comment {
+            Some(comment) if same_comment_body(&comment.body, &body) => {
+                use_case
+                    .reporter
+                    .status(label, &format!("part {index}/{total} unchanged"));
+                continue;
+            }
+            Some(comment) => ctx.vcs.update_comment(&comment.id, &body).await?,
+            None => ctx.vcs.create_comment(&body).await?,
+        };
+        use_case
+            .reporter
+            .status(label, &format!("posted part {index}/{total}"));
+        upsert_comment_cache(&mut ctx.existing_comments, posted);
+    }
+
+    for (index, comment) in existing.iter().filter(|(index, _)| *index > total) {
+        let body = format!(
+            "{}\n\n_This continuation is no longer used; see {first_link}._\n",
+            part_marker(parent, *index, &ctx.head_sha)
+        );
+        if same_comment_body(&comment.body, &body) {
+            continue;
+        }
+        let posted = ctx.vcs.update_comment(&comment.id, &body).await?;
+        upsert_comment_cache(&mut ctx.existing_comments, posted);
+    }
     Ok(())
 }
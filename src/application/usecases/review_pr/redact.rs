@@ -0,0 +1,150 @@
+//! provider로 보내기 전 diff/메타데이터의 비밀값 마스킹 단계.
+//!
+//! 기본 규칙(AWS 키, 개인 키 블록, JWT, VCS 토큰, `.env` 형식 대입)에
+//! `defaults.redact_patterns` 정규식을 더해 일치 구간을 `[REDACTED:<rule>]`로 바꾼다.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use anyhow::{Result, anyhow};
+use regex_automata::meta::Regex;
+use regex_automata::{Input, PatternID};
+
+use crate::application::config::Config;
+use crate::domain::diff::DiffFile;
+
+/// 기본 마스킹 규칙(이름, 정규식). `secret` 이름 그룹이 있으면 그 부분만 가린다.
+const BUILTIN_RULES: &[(&str, &str)] = &[
+    (
+        "private-key",
+        r"-----BEGIN [A-Z0-9 ]*PRIVATE KEY-----(?s:.*?)(?:-----END [A-Z0-9 ]*PRIVATE KEY-----|\z)",
+    ),
+    ("aws-access-key", r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b"),
+    (
+        "aws-secret-key",
+        r#"(?i)aws_?secret_?access_?key["']?\s*[:=]\s*["']?(?P<secret>[A-Za-z0-9/+=]{40,})"#,
+    ),
+    (
+        "jwt",
+        r"\beyJ[A-Za-z0-9_-]{8,}\.eyJ[A-Za-z0-9_-]{8,}\.[A-Za-z0-9_-]{8,}",
+    ),
+    (
+        "vcs-token",
+        r"\b(?:gh[pousr]_[A-Za-z0-9]{30,}|github_pat_[A-Za-z0-9_]{30,}|glpat-[A-Za-z0-9_-]{20,})",
+    ),
+    (
+        "env-assignment",
+        r#"(?m)^[+ -]?\s*(?:export\s+)?[A-Za-z0-9_]*(?i:secret|token|passw(?:or)?d|api_?key|private_?key|access_?key)[A-Za-z0-9_]*["']?\s*[=:]\s*["']?(?P<secret>[^\s"'#(){}$\[\]]{6,})["']?\s*$"#,
+    ),
+];
+
+/// 마스킹 규칙 묶음.
+pub(super) struct SecretRedactor {
+    rules: Vec<(String, Regex)>,
+}
+
+/// 규칙별로 가린 횟수와 위치(파일 경로/`title`).
+#[derive(Default)]
+pub(super) struct RedactionReport {
+    hits: BTreeMap<String, Vec<String>>,
+}
+
+impl SecretRedactor {
+    /// 설정에서 규칙을 구성한다. `defaults.redact_secrets = false`이면 None.
+    pub(super) fn from_config(config: &Config) -> Result<Option<Self>> {
+        if !config.redact_secrets() {
+            return Ok(None);
+        }
+        let mut rules = Vec::new();
+        for (name, pattern) in BUILTIN_RULES {
+            let regex = Regex::new(pattern).expect("builtin redaction pattern must compile");
+            rules.push(((*name).to_string(), regex));
+        }
+        for (idx, pattern) in config.redact_patterns().iter().enumerate() {
+            let regex = Regex::new(pattern).map_err(|err| match err.syntax_error() {
+                Some(detail) => anyhow!("invalid defaults.redact_patterns[{idx}]:\n{detail}"),
+                None => anyhow!("invalid defaults.redact_patterns[{idx}] '{pattern}': {err}"),
+            })?;
+            rules.push((format!("custom-{}", idx + 1), regex));
+        }
+        Ok(Some(Self { rules }))
+    }
+
+    /// diff 파일 구간마다 비밀값을 가린다. 가린 값이 없는 구간은 원본을 계속 빌려 쓴다.
+    pub(super) fn redact_files(&self, files: &mut [DiffFile<'_>], report: &mut RedactionReport) {
+        for file in files {
+            let location = if file.path.is_empty() {
+                "diff".to_string()
+            } else {
+                file.path.clone()
+            };
+            if let Cow::Owned(redacted) = self.redact(&file.text, &location, report) {
+                file.text = Cow::Owned(redacted);
+            }
+        }
+    }
+
+    /// 문자열 하나의 비밀값을 가린다. `location`은 보고용 위치 이름이다.
+    /// 가린 값이 없으면 복사하지 않고 원문을 빌려 돌려준다.
+    pub(super) fn redact<'t>(
+        &self,
+        text: &'t str,
+        location: &str,
+        report: &mut RedactionReport,
+    ) -> Cow<'t, str> {
+        let mut current = Cow::Borrowed(text);
+        for (name, regex) in &self.rules {
+            let secret_group = regex.group_info().to_index(PatternID::ZERO, "secret");
+            let mut out = String::with_capacity(current.len());
+            let mut last = 0;
+            let mut caps = regex.create_captures();
+            let mut start = 0;
+            while start <= current.len() {
+                regex.search_captures(&Input::new(current.as_ref()).range(start..), &mut caps);
+                let Some(whole) = caps.get_match() else {
+                    break;
+                };
+                let span = secret_group
+                    .and_then(|idx| caps.get_group(idx))
+                    .unwrap_or_else(|| whole.span());
+                if span.start >= span.end {
+                    start = whole.end().max(whole.start() + 1);
+                    continue;
+                }
+                out.push_str(&current[last..span.start]);
+                out.push_str(&format!("[REDACTED:{name}]"));
+                last = span.end;
+                report
+                    .hits
+                    .entry(name.clone())
+                    .or_default()
+                    .push(location.to_string());
+                start = whole.end().max(whole.start() + 1);
+            }
+            if last > 0 {
+                out.push_str(&current[last..]);
+                current = Cow::Owned(out);
+            }
+        }
+        current
+    }
+}
+
+impl RedactionReport {
+    pub(super) fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+
+    /// 예: `aws-access-key x2 (src/config.rs), jwt x1 (title)`
+    pub(super) fn summary(&self) -> String {
+        self.hits
+            .iter()
+            .map(|(rule, locations)| {
+                let mut unique: Vec<&str> = locations.iter().map(String::as_str).collect();
+                unique.dedup();
+                format!("{rule} x{} ({})", locations.len(), unique.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
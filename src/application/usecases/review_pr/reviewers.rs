@@ -0,0 +1,179 @@
+//! 사람 리뷰어 추천 단계(`defaults.suggest_reviewers`, `--request-reviewers`).
+//!
+//! 기본 브랜치의 CODEOWNERS와 변경 파일의 최근 커밋 작성자를 VCS API로 조회해 최종 요약의
+//! `Suggested Reviewers` 섹션을 채우고, `--request-reviewers`면 PR/MR 리뷰 요청까지 보낸다.
+
+use std::sync::Arc;
+
+use futures::future::join_all;
+
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::codeowners::{CODEOWNERS_PATHS, CodeOwners, suggest_reviewers};
+use crate::domain::diff::split_diff_by_file;
+use crate::domain::review::{RunOptions, SuggestedReviewer};
+
+/// 최근 작성자를 조회할 변경 파일 수(diff 순서로 앞쪽부터).
+const MAX_HISTORY_PATHS: usize = 10;
+/// 파일마다 볼 최근 커밋 수.
+const RECENT_COMMITS_PER_PATH: usize = 5;
+/// 코드 소유자가 아닌 최근 작성자 추천 인원.
+const MAX_RECENT_REVIEWERS: usize = 3;
+
+/// 조회 실패는 리뷰를 중단하지 않고 상태만 보고하며, 얻은 정보만으로 추천한다.
+pub(super) async fn suggest_human_reviewers(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+) -> Vec<SuggestedReviewer> {
+    if !ctx.config.suggest_reviewers() && !options.request_reviewers {
+        return Vec::new();
+    }
+    use_case.reporter.section("Suggested Reviewers");
+
+    // `resume`은 diff를 다시 받지 않으므로 이때만 조회한다.
+    let diff: Arc<str> = match &ctx.review_inputs {
+        Some(inputs) => inputs.diff.clone(),
+        None => match ctx.vcs.fetch_diff().await {
+            Ok(diff) => diff.into(),
+            Err(err) => {
+                use_case.reporter.status(
+                    "Reviewers",
+                    &format!("failed to fetch diff; skipping suggestions: {err:#}"),
+                );
+                return Vec::new();
+            }
+        },
+    };
+    let changed: Vec<String> = split_diff_by_file(&diff)
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+    if changed.is_empty() {
+        use_case
+            .reporter
+            .kv("Reviewers", "skipped (no changed files)");
+        return Vec::new();
+    }
+
+    let author = match ctx.vcs.fetch_author().await {
+        Ok(author) => Some(author),
+        Err(err) => {
+            use_case
+                .reporter
+                .status("Reviewers", &format!("failed to read PR author: {err:#}"));
+            None
+        }
+    };
+    let owners = load_code_owners(use_case, ctx).await;
+
+    let history = join_all(
+        changed
+            .iter()
+            .take(MAX_HISTORY_PATHS)
+            .map(|path| async move {
+                let result = ctx
+                    .vcs
+                    .fetch_recent_authors(path, RECENT_COMMITS_PER_PATH)
+                    .await;
+                (path.clone(), result)
+            }),
+    )
+    .await;
+    let mut recent = Vec::new();
+    for (path, result) in history {
+        match result {
+            Ok(authors) => recent.push((path, authors)),
+            Err(err) => use_case.reporter.status(
+                "Reviewers",
+                &format!("failed to read history of {path}: {err:#}"),
+            ),
+        }
+    }
+
+    let exclude: Vec<String> = author.into_iter().collect();
+    let mut reviewers = suggest_reviewers(
+        owners.as_ref(),
+        &changed,
+        &recent,
+        &exclude,
+        MAX_RECENT_REVIEWERS,
+    );
+    if reviewers.is_empty() {
+        use_case.reporter.kv("Reviewers", "none found");
+        return reviewers;
+    }
+    let handles: Vec<&str> = reviewers.iter().map(|r| r.handle.as_str()).collect();
+    use_case.reporter.kv("Reviewers", &handles.join(", "));
+
+    if options.request_reviewers {
+        request_suggested(use_case, options, ctx, &mut reviewers).await;
+    }
+    reviewers
+}
+
+/// 기본 브랜치에서 처음 찾은 CODEOWNERS를 읽는다(없거나 규칙이 없으면 None).
+async fn load_code_owners(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+) -> Option<CodeOwners> {
+    for path in CODEOWNERS_PATHS {
+        match ctx.vcs.fetch_repository_file(path).await {
+            Ok(Some(text)) => {
+                let owners = CodeOwners::parse(&text);
+                if owners.is_empty() {
+                    return None;
+                }
+                use_case.reporter.kv("Code Owners", path);
+                return Some(owners);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                use_case
+                    .reporter
+                    .status("Reviewers", &format!("failed to read {path}: {err:#}"));
+                return None;
+            }
+        }
+    }
+    None
+}
+
+/// 사용자 이름이 있는 추천 리뷰어(이메일 소유자 제외)에게 리뷰를 요청한다.
+async fn request_suggested(
+    use_case: &ReviewPrUseCase<'_>,
+    options: &RunOptions,
+    ctx: &ExecutionContext,
+    reviewers: &mut [SuggestedReviewer],
+) {
+    let usernames: Vec<String> = reviewers
+        .iter()
+        .filter_map(|r| r.username().map(ToString::to_string))
+        .collect();
+    if usernames.is_empty() {
+        use_case
+            .reporter
+            .kv("Review Requests", "skipped (no usernames to request)");
+        return;
+    }
+    if options.dry_run {
+        use_case.reporter.kv(
+            "Review Requests",
+            &format!("would request {}", usernames.join(", ")),
+        );
+        return;
+    }
+    match ctx.vcs.request_reviewers(&usernames).await {
+        Ok(()) => {
+            for reviewer in reviewers.iter_mut() {
+                reviewer.requested = reviewer.username().is_some();
+            }
+            use_case
+                .reporter
+                .kv("Review Requests", &usernames.join(", "));
+        }
+        Err(err) => use_case.reporter.status(
+            "Reviewers",
+            &format!("failed to request reviewers: {err:#}"),
+        ),
+    }
+}
@@ -0,0 +1,86 @@
+//! 보안 전용 프롬프트(OWASP 체크리스트)로 diff를 한 번 더 점검하는 보안 점검(security pass) 단계.
+//!
+//! 1차 리뷰와 같은 요청(청크)과 출력 계약 파싱을 쓰되 프롬프트만 `security` 템플릿으로 바꾼다.
+//! 결과는 최종 요약의 Security 섹션에 심각도를 붙인 지적사항 목록으로 들어간다.
+
+use std::time::Instant;
+
+use crate::application::ports::ProviderAgent;
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::review::{ReviewRequest, ReviewStage, StageReview, TokenUsage};
+
+/// `defaults.security_pass`가 켜져 있으면 보안 점검 provider로 요청(청크)마다 한 번씩 호출한다.
+/// provider를 찾지 못하거나 한 청크라도 실패하면 부분 결과를 남기지 않고 None을 반환한다.
+pub(super) async fn run_security_pass(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    providers: &[Box<dyn ProviderAgent>],
+    requests: &[ReviewRequest],
+) -> Option<StageReview> {
+    if !ctx.config.security_pass() {
+        return None;
+    }
+
+    use_case.reporter.section("Providers (Security Pass)");
+    let provider = match ctx.config.security_provider() {
+        Some(id) => {
+            let Some(provider) = providers.iter().find(|p| p.id() == id) else {
+                use_case.reporter.status(
+                    "Security",
+                    &format!("provider '{id}' is not enabled; skipping security pass"),
+                );
+                return None;
+            };
+            provider
+        }
+        None => providers.first()?,
+    };
+
+    let provider_name = provider.name().to_string();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "running", None);
+    let started = Instant::now();
+    let mut bodies = Vec::new();
+    let mut findings = Vec::new();
+    let mut usage = TokenUsage::default();
+    for request in requests {
+        let mut request = request.clone();
+        request.prompt_templates.primary = request.prompt_templates.security.clone();
+        match provider.review(&request).await {
+            Ok(resp) => {
+                if !resp.content.trim().is_empty() {
+                    bodies.push(resp.content);
+                }
+                findings.extend(resp.findings);
+                usage.add_from(&resp.usage);
+            }
+            Err(err) => {
+                let sec = started.elapsed().as_secs_f32();
+                use_case
+                    .reporter
+                    .provider_status(&provider_name, "error", Some(sec));
+                use_case
+                    .reporter
+                    .status("Security", &format!("security pass failed: {err}"));
+                return None;
+            }
+        }
+    }
+    let sec = started.elapsed().as_secs_f32();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "done", Some(sec));
+    use_case
+        .reporter
+        .kv("Security Findings", &findings.len().to_string());
+
+    Some(StageReview {
+        stage: ReviewStage::Security,
+        provider_id: provider.id().to_string(),
+        provider_name,
+        body: bodies.join("\n\n"),
+        findings,
+        usage,
+    })
+}
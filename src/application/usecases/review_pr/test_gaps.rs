@@ -0,0 +1,151 @@
+//! 변경된 함수 중 대응하는 테스트 변경이 없는 것을 provider 하나가 찾아
+//! 최종 요약의 "Missing Tests" 체크리스트로 만드는 테스트 누락 분석 단계.
+//!
+//! 테스트 파일 여부는 `defaults.test_file_globs` 경로 휴리스틱으로 나누고,
+//! 같은 파일 안의 테스트(예: Rust `#[cfg(test)]`)는 provider가 diff를 보고 판단한다.
+
+use std::time::Instant;
+
+use crate::application::ports::ProviderAgent;
+use crate::application::usecases::review_pr::{ReviewPrUseCase, context::ExecutionContext};
+use crate::domain::diff::{path_matches, split_diff_by_file};
+use crate::domain::markdown::task_list_items;
+use crate::domain::policy::test_gap_prompt_vars;
+use crate::domain::review::{ReviewRequest, ReviewStage, StageReview, TokenUsage};
+
+/// `defaults.test_gap_analysis`가 켜져 있으면 테스트 누락 분석 provider로 요청(청크)마다 한 번씩 호출한다.
+/// 테스트가 아닌 변경 파일이 없으면 건너뛰고, provider를 찾지 못하거나 한 청크라도 실패하면 None을 반환한다.
+pub(super) async fn run_test_gap_analysis(
+    use_case: &ReviewPrUseCase<'_>,
+    ctx: &ExecutionContext,
+    providers: &[Box<dyn ProviderAgent>],
+    requests: &[ReviewRequest],
+) -> Option<StageReview> {
+    if !ctx.config.test_gap_analysis() {
+        return None;
+    }
+
+    use_case.reporter.section("Providers (Test Gap Analysis)");
+    let (source_files, test_files) =
+        classify_changed_files(requests, &ctx.config.test_file_globs());
+    use_case.reporter.kv(
+        "Changed Files",
+        &format!("{} source, {} test", source_files.len(), test_files.len()),
+    );
+    if source_files.is_empty() {
+        use_case
+            .reporter
+            .kv("Missing Tests", "skipped (no source changes)");
+        return None;
+    }
+
+    let provider = match ctx.config.test_gap_provider() {
+        Some(id) => {
+            let Some(provider) = providers.iter().find(|p| p.id() == id) else {
+                use_case.reporter.status(
+                    "Test Gaps",
+                    &format!("provider '{id}' is not enabled; skipping test gap analysis"),
+                );
+                return None;
+            };
+            provider
+        }
+        None => providers.first()?,
+    };
+
+    let provider_name = provider.name().to_string();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "running", None);
+    let started = Instant::now();
+    let mut items: Vec<String> = Vec::new();
+    let mut prose = Vec::new();
+    let mut usage = TokenUsage::default();
+    for request in requests {
+        let vars = test_gap_prompt_vars(request, &source_files, &test_files);
+        let response = match use_case
+            .templates
+            .render(&request.prompt_templates.test_gaps, &vars)
+        {
+            Ok(prompt) => provider.review_prompt(&prompt).await,
+            Err(err) => Err(err),
+        };
+        match response {
+            Ok(resp) => {
+                let found = task_list_items(&resp.content);
+                let content = resp.content.trim();
+                if found.is_empty() && !is_no_missing_tests(content) {
+                    prose.push(content.to_string());
+                }
+                for item in found {
+                    if !items.contains(&item) {
+                        items.push(item);
+                    }
+                }
+                usage.add_from(&resp.usage);
+            }
+            Err(err) => {
+                let sec = started.elapsed().as_secs_f32();
+                use_case
+                    .reporter
+                    .provider_status(&provider_name, "error", Some(sec));
+                use_case
+                    .reporter
+                    .status("Test Gaps", &format!("test gap analysis failed: {err}"));
+                return None;
+            }
+        }
+    }
+    let sec = started.elapsed().as_secs_f32();
+    use_case
+        .reporter
+        .provider_status(&provider_name, "done", Some(sec));
+    use_case
+        .reporter
+        .kv("Missing Tests", &items.len().to_string());
+
+    // 체크리스트를 하나도 못 찾았으면 형식을 따르지 않은 응답 본문을 그대로 남긴다.
+    let body = if items.is_empty() {
+        prose.join("\n\n")
+    } else {
+        items.join("\n")
+    };
+    Some(StageReview {
+        stage: ReviewStage::TestGaps,
+        provider_id: provider.id().to_string(),
+        provider_name,
+        body,
+        findings: Vec::new(),
+        usage,
+    })
+}
+
+/// 모든 요청(청크)의 diff에서 변경 파일을 모아 (테스트가 아닌 파일, 테스트 파일)로 나눈다.
+fn classify_changed_files(
+    requests: &[ReviewRequest],
+    globs: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let mut source_files = Vec::new();
+    let mut test_files = Vec::new();
+    for request in requests {
+        for file in split_diff_by_file(&request.diff) {
+            let bucket = if globs.iter().any(|g| path_matches(g, &file.path)) {
+                &mut test_files
+            } else {
+                &mut source_files
+            };
+            if !bucket.contains(&file.path) {
+                bucket.push(file.path);
+            }
+        }
+    }
+    (source_files, test_files)
+}
+
+/// 템플릿이 요구한 "누락 없음" 응답인지 판단한다.
+fn is_no_missing_tests(content: &str) -> bool {
+    content.is_empty()
+        || content
+            .trim_end_matches('.')
+            .eq_ignore_ascii_case("no missing tests")
+}
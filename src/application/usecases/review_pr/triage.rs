@@ -0,0 +1,75 @@
+//! 게시 전 지적사항 triage 단계.
+
+use anyhow::Result;
+
+use crate::application::ports::TriageDecision;
+use crate::application::usecases::review_pr::{ReviewPrUseCase, providers::PrimaryReviewOutcome};
+use crate::domain::policy::{findings_to_file_reviews, findings_to_markdown};
+use crate::domain::review::{ProviderRun, ReviewGranularity, SeverityTaxonomy};
+
+/// 에이전트별 지적사항을 사용자에게 하나씩 보여주고 승인된 항목만 남긴다.
+/// 승인된 에이전트 코멘트는 provider 원문 대신 확정된 지적사항으로 다시 구성하고,
+/// 구조화 지적사항이 없는 에이전트는 원문 전체의 게시 여부를 묻는다.
+pub(super) fn triage_primary_results(
+    use_case: &ReviewPrUseCase<'_>,
+    outcome: &mut PrimaryReviewOutcome,
+    granularity: ReviewGranularity,
+    taxonomy: &SeverityTaxonomy,
+) -> Result<()> {
+    use_case.reporter.section("Finding Triage");
+
+    let total: usize = outcome
+        .primary_results
+        .iter()
+        .map(|r| r.findings.len())
+        .sum();
+    let mut index = 0;
+    let mut kept_runs: Vec<ProviderRun> = Vec::new();
+
+    for mut run in std::mem::take(&mut outcome.primary_results) {
+        if run.findings.is_empty() {
+            let msg = format!(
+                "{} returned no structured findings; post its full review as-is?",
+                run.name
+            );
+            if use_case.confirmer.confirm(&msg)? {
+                kept_runs.push(run);
+            } else {
+                use_case.reporter.status(&run.name, "dropped");
+            }
+            continue;
+        }
+
+        let mut accepted = Vec::new();
+        for mut finding in std::mem::take(&mut run.findings) {
+            index += 1;
+            match use_case
+                .finding_triage
+                .triage(&run.name, &finding, index, total)?
+            {
+                TriageDecision::Accept => accepted.push(finding),
+                TriageDecision::Drop => {}
+                TriageDecision::Edit(body) => {
+                    finding.body = body;
+                    accepted.push(finding);
+                }
+            }
+        }
+
+        use_case
+            .reporter
+            .status(&run.name, &format!("{} finding(s) accepted", accepted.len()));
+        if accepted.is_empty() {
+            continue;
+        }
+        run.body = findings_to_markdown(&accepted, taxonomy);
+        if granularity == ReviewGranularity::File {
+            run.file_reviews = findings_to_file_reviews(&accepted, taxonomy);
+        }
+        run.findings = accepted;
+        kept_runs.push(run);
+    }
+
+    outcome.primary_results = kept_runs;
+    Ok(())
+}
@@ -0,0 +1,24 @@
+//! 실행 기록 집계 유스케이스(`repopilot stats`).
+
+use anyhow::Result;
+
+use crate::application::ports::{Clock, RecentRun, RunHistory, RunStats};
+
+/// 저장소/provider별 실행 횟수, 평균 소요 시간, 토큰 사용량, 추정 비용을 집계한다.
+pub struct RunStatsUseCase<'a> {
+    pub run_history: &'a dyn RunHistory,
+    pub clock: &'a dyn Clock,
+}
+
+impl<'a> RunStatsUseCase<'a> {
+    /// `since_secs`가 있으면 최근 그 기간(초) 안의 실행만 집계한다.
+    pub fn execute(&self, since_secs: Option<u64>) -> Result<RunStats> {
+        let since = since_secs.map(|secs| self.clock.unix_now().saturating_sub(secs));
+        self.run_history.stats(since)
+    }
+
+    /// 최근 실행을 새것부터 최대 `limit`건 반환한다.
+    pub fn recent(&self, limit: usize) -> Result<Vec<RecentRun>> {
+        self.run_history.recent(limit)
+    }
+}
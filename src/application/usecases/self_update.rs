@@ -0,0 +1,167 @@
+//! 바이너리 자체 업데이트 유스케이스(`repopilot self-update`).
+//!
+//! 최신 릴리스에서 현재 플랫폼용 실행 파일을 내려받아 SHA-256 체크섬(필수)과
+//! Ed25519 서명(`defaults.update_public_key` 설정 시 필수)을 확인한 뒤 현재 바이너리를 교체한다.
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, bail};
+
+use crate::application::attribution::{self, RequestAttribution};
+use crate::application::config::Config;
+use crate::application::ports::{
+    BinaryInstaller, ConfigRepository, HostTokenResolver, UpdateChecker,
+};
+use crate::application::usecases::check_update::{fetch_latest, update_token};
+use crate::domain::release::{
+    checksum_asset, find_checksum, is_newer_version, platform_asset, signature_asset,
+};
+
+// 시작 시 확인과 달리 실제 파일을 받으므로 넉넉하게 잡는다.
+const LOOKUP_TIMEOUT_MS: u64 = 15_000;
+const DOWNLOAD_TIMEOUT_MS: u64 = 300_000;
+
+/// 최신 릴리스로 현재 바이너리를 교체한다.
+pub struct SelfUpdateUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+    pub host_token_resolver: &'a dyn HostTokenResolver,
+    pub update_checker: &'a dyn UpdateChecker,
+    pub installer: &'a dyn BinaryInstaller,
+}
+
+/// 자체 업데이트 결과.
+#[derive(Debug, Clone)]
+pub enum SelfUpdateOutcome {
+    /// 이미 최신 버전
+    UpToDate { current: String },
+    /// `--check`: 새 버전과 설치할 asset 이름만 확인
+    Available {
+        current: String,
+        latest: String,
+        asset: String,
+    },
+    /// 교체 완료
+    Updated {
+        from: String,
+        to: String,
+        path: PathBuf,
+        signature_verified: bool,
+    },
+}
+
+impl<'a> SelfUpdateUseCase<'a> {
+    /// `check_only`이면 내려받지 않고 새 버전/asset만 확인한다.
+    pub async fn execute(&self, check_only: bool) -> Result<SelfUpdateOutcome> {
+        let config = self.config_repo.load()?;
+        let attribution = RequestAttribution::for_config(&config);
+        attribution::scope(attribution, self.execute_with(&config, check_only)).await
+    }
+
+    async fn execute_with(&self, config: &Config, check_only: bool) -> Result<SelfUpdateOutcome> {
+        let token = update_token(config, self.host_token_resolver);
+        let current = env!("CARGO_PKG_VERSION").to_string();
+
+        let Some(latest) = fetch_latest(
+            config,
+            self.update_checker,
+            token.as_deref(),
+            LOOKUP_TIMEOUT_MS,
+        )
+        .await?
+        else {
+            bail!("no release found (check defaults.update_repository / update_check_url)");
+        };
+        if !is_newer_version(&current, &latest.version) {
+            return Ok(SelfUpdateOutcome::UpToDate { current });
+        }
+
+        let (os, arch) = (std::env::consts::OS, std::env::consts::ARCH);
+        let Some(binary) = platform_asset(&latest.assets, os, arch) else {
+            let names = latest
+                .assets
+                .iter()
+                .map(|asset| asset.name.as_str())
+                .collect::<Vec<_>>();
+            bail!(
+                "release {} has no executable for {os}/{arch} (assets: {})",
+                latest.version,
+                if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(", ")
+                }
+            );
+        };
+        if check_only {
+            return Ok(SelfUpdateOutcome::Available {
+                current,
+                latest: latest.version,
+                asset: binary.name.clone(),
+            });
+        }
+
+        let Some(checksum) = checksum_asset(&latest.assets, &binary.name) else {
+            bail!(
+                "release {} has no checksum for {} (expected {}.sha256 or SHA256SUMS)",
+                latest.version,
+                binary.name,
+                binary.name
+            );
+        };
+        let public_key = config.update_public_key();
+        let signature = match public_key {
+            Some(_) => match signature_asset(&latest.assets, &binary.name) {
+                Some(asset) => Some(asset),
+                None => bail!(
+                    "defaults.update_public_key is set but release {} has no {}.sig",
+                    latest.version,
+                    binary.name
+                ),
+            },
+            None => None,
+        };
+
+        let token = token.as_deref();
+        let bytes = self
+            .update_checker
+            .download(&binary.url, token, DOWNLOAD_TIMEOUT_MS)
+            .await?;
+        let sums = self
+            .update_checker
+            .download(&checksum.url, token, LOOKUP_TIMEOUT_MS)
+            .await?;
+        let sums = String::from_utf8_lossy(&sums);
+        let Some(expected) = find_checksum(&sums, &binary.name) else {
+            bail!(
+                "{} does not list a SHA-256 for {}",
+                checksum.name,
+                binary.name
+            );
+        };
+        let actual = self.installer.sha256_hex(&bytes);
+        if actual != expected {
+            bail!(
+                "checksum mismatch for {}: expected {expected}, got {actual}",
+                binary.name
+            );
+        }
+
+        if let (Some(asset), Some(public_key)) = (signature, public_key) {
+            let sig = self
+                .update_checker
+                .download(&asset.url, token, LOOKUP_TIMEOUT_MS)
+                .await?;
+            self.installer
+                .verify_signature(&bytes, &sig, public_key)
+                .with_context(|| format!("refusing to install {}", binary.name))?;
+        }
+
+        let path = self.installer.replace_current_exe(&bytes)?;
+        Ok(SelfUpdateOutcome::Updated {
+            from: current,
+            to: latest.version,
+            path,
+            signature_verified: public_key.is_some(),
+        })
+    }
+}
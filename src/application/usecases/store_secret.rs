@@ -0,0 +1,20 @@
+//! 토큰/API 키를 비밀 저장소에 기록하는 유스케이스(`auth store`).
+
+use anyhow::{Result, bail};
+
+use crate::application::ports::SecretStore;
+
+/// 평문 토큰을 설정 파일/환경변수 대신 OS 키체인에 보관한다.
+pub struct StoreSecretUseCase<'a> {
+    pub secret_store: &'a dyn SecretStore,
+}
+
+impl<'a> StoreSecretUseCase<'a> {
+    pub fn execute(&self, entry: &str, secret: &str) -> Result<()> {
+        let secret = secret.trim();
+        if secret.is_empty() {
+            bail!("secret is empty");
+        }
+        self.secret_store.store(entry, secret)
+    }
+}
@@ -0,0 +1,100 @@
+//! REPL `/review` URL 자동 완성 후보 유스케이스.
+//!
+//! 작업 디렉터리 git 원격 저장소의 열린 PR/MR과 클립보드의 PR/MR URL을 후보로 제공한다.
+
+use std::time::Duration;
+
+use futures::future::join_all;
+
+use crate::application::ports::{
+    ClipboardReader, ConfigRepository, GitRemoteReader, HostTokenResolver, RepositoryGateway,
+    TargetResolver, VcsFactory,
+};
+use crate::domain::target::{OpenReview, RepositoryRef};
+
+// 저장소마다 조회하는 열린 PR/MR 수
+const OPEN_REVIEW_LIMIT: usize = 20;
+// 목록 조회 제한 시간(자동 완성이 늦게 도착해도 입력을 막지 않는다)
+const LIST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 자동 완성 후보를 모은다.
+pub struct SuggestTargetsUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+    pub host_token_resolver: &'a dyn HostTokenResolver,
+    pub target_resolver: &'a dyn TargetResolver,
+    pub vcs_factory: &'a dyn VcsFactory,
+    pub git_remotes: &'a dyn GitRemoteReader,
+    pub clipboard: &'a dyn ClipboardReader,
+}
+
+/// 조회 준비가 끝난 열린 PR/MR 목록 요청.
+/// 유스케이스를 빌리지 않으므로 백그라운드 작업으로 넘길 수 있다.
+pub struct OpenReviewQuery {
+    gateways: Vec<Box<dyn RepositoryGateway>>,
+}
+
+impl<'a> SuggestTargetsUseCase<'a> {
+    /// 원격 저장소별 게이트웨이를 준비한다. 설정/토큰 해석 실패는 후보 없음으로 처리한다.
+    pub fn open_reviews(&self) -> OpenReviewQuery {
+        let repositories: Vec<RepositoryRef> = self
+            .git_remotes
+            .remote_urls()
+            .iter()
+            .filter_map(|url| RepositoryRef::from_remote_url(url))
+            .fold(Vec::new(), |mut acc, repo| {
+                if !acc.contains(&repo) {
+                    acc.push(repo);
+                }
+                acc
+            });
+        if repositories.is_empty() {
+            return OpenReviewQuery {
+                gateways: Vec::new(),
+            };
+        }
+        let Ok(config) = self.config_repo.load() else {
+            return OpenReviewQuery {
+                gateways: Vec::new(),
+            };
+        };
+
+        let gateways = repositories
+            .iter()
+            .map(|repo| {
+                let host_cfg = config.host_config(repo.host());
+                let token = self
+                    .host_token_resolver
+                    .resolve(repo.host(), host_cfg)
+                    .ok()
+                    .and_then(|resolution| resolution.token);
+                self.vcs_factory.build_repository(repo, host_cfg, token)
+            })
+            .collect();
+        OpenReviewQuery { gateways }
+    }
+
+    /// 클립보드 내용이 PR/MR URL이면 반환한다.
+    pub fn clipboard_url(&self) -> Option<String> {
+        let text = self.clipboard.read_text()?;
+        let candidate = text.lines().next()?.trim();
+        self.target_resolver
+            .parse(candidate)
+            .ok()
+            .map(|target| target.url().to_string())
+    }
+}
+
+impl OpenReviewQuery {
+    /// 저장소별 목록을 동시에 조회해 합친다. 실패한 저장소는 건너뛴다.
+    pub async fn fetch(self) -> Vec<OpenReview> {
+        let lists = join_all(self.gateways.iter().map(|gateway| async move {
+            tokio::time::timeout(LIST_TIMEOUT, gateway.list_open_reviews(OPEN_REVIEW_LIMIT))
+                .await
+                .ok()
+                .and_then(Result::ok)
+                .unwrap_or_default()
+        }))
+        .await;
+        lists.into_iter().flatten().collect()
+    }
+}
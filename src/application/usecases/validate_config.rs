@@ -0,0 +1,17 @@
+//! 설정 파일 스키마 검증 유스케이스.
+
+use anyhow::Result;
+
+use crate::application::ports::{ConfigRepository, ConfigValidation};
+
+/// 탐색 경로의 설정 파일을 검증한다.
+pub struct ValidateConfigUseCase<'a> {
+    pub config_repo: &'a dyn ConfigRepository,
+}
+
+impl<'a> ValidateConfigUseCase<'a> {
+    /// 파일별 검증 결과(문제 위치/제안 포함)를 반환한다.
+    pub fn execute(&self) -> Result<ConfigValidation> {
+        self.config_repo.validate()
+    }
+}
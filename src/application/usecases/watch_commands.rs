@@ -0,0 +1,142 @@
+//! PR/MR 코멘트의 `/repopilot` 명령을 주기적으로 확인해 실행하는 watch 유스케이스.
+
+use std::collections::HashSet;
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+
+use crate::application::config::Config;
+use crate::application::ports::VcsGateway;
+use crate::application::usecases::review_pr::ReviewPrUseCase;
+use crate::domain::bot_command::{BotCommand, CommandPolicy, parse_bot_command};
+use crate::domain::review::{ReviewComment, RunOptions};
+
+/// 대상 PR/MR의 새 코멘트를 폴링하며 `/repopilot review`, `/repopilot ignore` 명령에 반응한다.
+pub struct WatchCommandsUseCase<'a> {
+    pub review: ReviewPrUseCase<'a>,
+}
+
+impl<'a> WatchCommandsUseCase<'a> {
+    /// watch 진입점. 시작 시점에 이미 있던 코멘트는 처리하지 않으며, 종료(Ctrl-C) 전까지 반복한다.
+    /// `base`는 명령으로 시작되는 리뷰 실행의 기본 옵션이다.
+    pub async fn execute(&self, base: RunOptions, interval: Duration) -> Result<()> {
+        let reporter = self.review.reporter;
+        reporter.section("Watch");
+        reporter.kv("Target", &base.url);
+        reporter.kv("Interval", &format!("{}s", interval.as_secs()));
+
+        let (vcs, config) = self.connect(&base.url)?;
+        // PR 작성자는 `/repopilot ignore`를 처음 만났을 때 한 번만 조회한다.
+        let mut pr_author: Option<Option<String>> = None;
+        let mut seen: HashSet<String> = vcs
+            .list_comments()
+            .await
+            .context("failed to list existing comments")?
+            .into_iter()
+            .map(|comment| comment.id)
+            .collect();
+        reporter.status("Watch", "waiting for /repopilot commands");
+
+        loop {
+            tokio::time::sleep(interval).await;
+            let comments = match vcs.list_comments().await {
+                Ok(comments) => comments,
+                Err(err) => {
+                    reporter.status("Watch", &format!("failed to list comments: {err:#}"));
+                    continue;
+                }
+            };
+
+            let new_comments: Vec<ReviewComment> = comments
+                .into_iter()
+                .filter(|comment| seen.insert(comment.id.clone()))
+                .collect();
+            for comment in new_comments {
+                let Some(command) = parse_bot_command(&comment.body) else {
+                    continue;
+                };
+                if matches!(command, BotCommand::Ignore { .. }) && pr_author.is_none() {
+                    pr_author = Some(vcs.fetch_author().await.ok());
+                }
+                let policy = CommandPolicy {
+                    allowlist: config.command_allowlist(),
+                    pr_author: pr_author.clone().flatten(),
+                };
+                if !policy.allows(&comment, &command) {
+                    let author = comment
+                        .author
+                        .as_ref()
+                        .map_or("unknown user", |author| author.username.as_str());
+                    reporter.status(
+                        "Watch",
+                        &format!(
+                            "ignoring /repopilot command from {author} (not a maintainer or in defaults.command_allowlist)"
+                        ),
+                    );
+                    continue;
+                }
+                if let Err(err) = vcs.acknowledge_comment(&comment.id).await {
+                    reporter.status("Watch", &format!("failed to acknowledge comment: {err:#}"));
+                }
+                self.handle(&base, command).await;
+            }
+        }
+    }
+
+    async fn handle(&self, base: &RunOptions, command: BotCommand) {
+        let reporter = self.review.reporter;
+        match command {
+            BotCommand::Review { providers } => {
+                reporter.section("Watch: /repopilot review");
+                // 명시적 재리뷰 요청이므로 이미 리뷰된 SHA여도 다시 실행한다.
+                let mut options = base.clone();
+                options.force = true;
+                if !providers.is_empty() {
+                    options.providers = providers;
+                }
+                match self.review.execute(options).await {
+                    Ok(outcome) => {
+                        if let Some(gate) = outcome.gate {
+                            reporter.status("Gate", &gate.to_string());
+                        }
+                    }
+                    Err(err) => reporter.status("Watch", &format!("review failed: {err:#}")),
+                }
+            }
+            BotCommand::Ignore { finding_id } => {
+                reporter.status(
+                    "Watch",
+                    &format!("finding `{finding_id}` will be ignored from the next review"),
+                );
+            }
+        }
+    }
+
+    fn connect(&self, url: &str) -> Result<(Box<dyn VcsGateway>, Config)> {
+        let config = self
+            .review
+            .config_repo
+            .load()
+            .context("failed to load repopilot config")?;
+        let target = self
+            .review
+            .target_resolver
+            .parse(url)
+            .context("failed to parse target URL")?;
+        let host_cfg = config.host_config(target.host());
+        let token = self
+            .review
+            .host_token_resolver
+            .resolve(target.host(), host_cfg)
+            .context("failed to resolve VCS host token")?
+            .token;
+        if token.is_none() {
+            bail!(
+                "missing VCS token for host '{}'; watch mode needs a token to read comments",
+                target.host()
+            );
+        }
+        let vcs = self.review.vcs_factory.build(&target, host_cfg, token);
+        Ok((vcs, config))
+    }
+}
@@ -0,0 +1,134 @@
+//! PR/MR 코멘트로 전달되는 `/repopilot` 명령 파싱 정책.
+
+use std::collections::BTreeSet;
+
+use crate::domain::review::{CommentAuthor, ReviewComment};
+
+/// 코멘트 명령 접두어.
+pub const BOT_COMMAND_PREFIX: &str = "/repopilot";
+
+/// 코멘트에서 해석한 봇 명령.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BotCommand {
+    /// `/repopilot review [--providers <ids>]`: 현재 HEAD를 다시 리뷰한다.
+    Review { providers: Vec<String> },
+    /// `/repopilot ignore <finding-id>`: 이후 요약/게이트에서 해당 지적사항을 제외한다.
+    Ignore { finding_id: String },
+}
+
+/// 코멘트 본문에서 `/repopilot`으로 시작하는 첫 줄을 명령으로 해석한다.
+/// 인용(`>`)이나 코드 블록 안의 줄, 알 수 없는 명령은 무시한다.
+pub fn parse_bot_command(body: &str) -> Option<BotCommand> {
+    let mut in_code_block = false;
+    for line in body.lines() {
+        let line = line.trim();
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+        let Some(rest) = line.strip_prefix(BOT_COMMAND_PREFIX) else {
+            continue;
+        };
+        if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+        return parse_command_args(rest);
+    }
+    None
+}
+
+fn parse_command_args(rest: &str) -> Option<BotCommand> {
+    let mut tokens = rest.split_whitespace();
+    match tokens.next()?.to_ascii_lowercase().as_str() {
+        "review" => {
+            let mut providers = Vec::new();
+            while let Some(token) = tokens.next() {
+                let value = match token.strip_prefix("--providers") {
+                    Some("") => tokens.next()?,
+                    Some(inline) => inline.strip_prefix('=')?,
+                    None => return None,
+                };
+                providers.extend(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|id| !id.is_empty())
+                        .map(ToString::to_string),
+                );
+            }
+            Some(BotCommand::Review { providers })
+        }
+        "ignore" => {
+            let finding_id = tokens.next()?.trim_matches('`').to_ascii_lowercase();
+            if finding_id.is_empty() || tokens.next().is_some() {
+                return None;
+            }
+            Some(BotCommand::Ignore { finding_id })
+        }
+        _ => None,
+    }
+}
+
+/// 코멘트 명령을 따를지 정하는 정책.
+///
+/// 아무나 명령을 쓸 수 있으면 PR 작성자가 자기 지적사항을 `ignore`해 `--fail-on` 게이트를 넘기거나,
+/// 외부 사용자가 과금되는 재리뷰를 반복시킬 수 있다. 그래서 저장소 관리자와 허용 목록 사용자의 명령만
+/// 따르고, PR 작성자 본인의 `ignore`는 허용 목록에 있을 때만 따른다.
+#[derive(Debug, Clone, Default)]
+pub struct CommandPolicy {
+    /// `defaults.command_allowlist`
+    pub allowlist: Vec<String>,
+    /// PR/MR 작성자. None이면 확인하지 못한 것으로 보고 허용 목록 사용자의 `ignore`만 따른다.
+    pub pr_author: Option<String>,
+}
+
+impl CommandPolicy {
+    /// `comment`의 `command`를 따라도 되는지.
+    pub fn allows(&self, comment: &ReviewComment, command: &BotCommand) -> bool {
+        let Some(author) = &comment.author else {
+            return false;
+        };
+        if self.is_allowlisted(author) {
+            return true;
+        }
+        match command {
+            BotCommand::Review { .. } => author.maintainer,
+            BotCommand::Ignore { .. } => {
+                author.maintainer
+                    && self
+                        .pr_author
+                        .as_deref()
+                        .is_some_and(|pr_author| !pr_author.eq_ignore_ascii_case(&author.username))
+            }
+        }
+    }
+
+    fn is_allowlisted(&self, author: &CommentAuthor) -> bool {
+        self.allowlist
+            .iter()
+            .any(|name| name.eq_ignore_ascii_case(&author.username))
+    }
+}
+
+/// 기존 코멘트의 `/repopilot ignore` 명령 중 `policy`가 허용한 것에서 무시할 지적사항 id를 모은다.
+pub fn collect_ignored_findings(
+    comments: &[ReviewComment],
+    policy: &CommandPolicy,
+) -> BTreeSet<String> {
+    comments
+        .iter()
+        .filter_map(|comment| {
+            let command = parse_bot_command(&comment.body)?;
+            if !policy.allows(comment, &command) {
+                return None;
+            }
+            match command {
+                BotCommand::Ignore { finding_id } => Some(finding_id),
+                BotCommand::Review { .. } => None,
+            }
+        })
+        .collect()
+}
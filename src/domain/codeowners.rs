@@ -0,0 +1,209 @@
+//! CODEOWNERS 해석과 사람 리뷰어 추천 정책.
+//!
+//! GitHub/GitLab 공통 문법(gitignore 형식 경로 패턴 + 소유자 목록, 마지막으로 일치한 규칙 우선)을
+//! 지원한다. GitLab 섹션(`[Section]`, `^[Section]`)은 섹션마다 마지막으로 일치한 규칙을 모두 적용하고,
+//! 섹션 헤더 뒤의 기본 소유자는 소유자를 적지 않은 규칙에 쓴다.
+
+use std::collections::BTreeMap;
+
+use crate::domain::diff::glob_match;
+use crate::domain::review::SuggestedReviewer;
+
+/// CODEOWNERS 탐색 경로(GitHub/GitLab이 읽는 위치, 앞쪽 우선).
+pub const CODEOWNERS_PATHS: &[&str] = &[
+    ".github/CODEOWNERS",
+    ".gitlab/CODEOWNERS",
+    "CODEOWNERS",
+    "docs/CODEOWNERS",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<OwnerRule>,
+}
+
+#[derive(Debug, Clone)]
+struct OwnerRule {
+    /// 규칙이 속한 섹션 순번(섹션이 없는 GitHub 형식은 0)
+    section: usize,
+    pattern: String,
+    /// 비어 있으면 일치한 경로의 소유자를 지운다
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// CODEOWNERS 본문을 해석한다. 해석할 수 없는 줄은 건너뛴다.
+    pub fn parse(text: &str) -> Self {
+        let mut rules = Vec::new();
+        let mut section = 0;
+        let mut section_owners: Vec<String> = Vec::new();
+        for line in text.lines() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let header = line.strip_prefix('^').unwrap_or(line);
+            if let Some(rest) = header.strip_prefix('[') {
+                let Some(end) = rest.find(']') else {
+                    continue;
+                };
+                // `[Section][2]`처럼 승인 수가 붙은 헤더도 허용한다.
+                let mut tail = &rest[end + 1..];
+                if let Some(count) = tail.strip_prefix('[')
+                    && let Some(close) = count.find(']')
+                {
+                    tail = &count[close + 1..];
+                }
+                section += 1;
+                section_owners = tail.split_whitespace().map(ToString::to_string).collect();
+                continue;
+            }
+
+            let mut tokens = line.split_whitespace();
+            let Some(pattern) = tokens.next() else {
+                continue;
+            };
+            let mut owners: Vec<String> = tokens.map(ToString::to_string).collect();
+            if owners.is_empty() {
+                owners = section_owners.clone();
+            }
+            rules.push(OwnerRule {
+                section,
+                pattern: pattern.to_string(),
+                owners,
+            });
+        }
+        Self { rules }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// 경로의 소유자 목록(섹션마다 마지막으로 일치한 규칙, 중복 제거).
+    pub fn owners_of(&self, path: &str) -> Vec<&str> {
+        let mut by_section: BTreeMap<usize, &OwnerRule> = BTreeMap::new();
+        for rule in &self.rules {
+            if pattern_matches(&rule.pattern, path) {
+                by_section.insert(rule.section, rule);
+            }
+        }
+        let mut owners: Vec<&str> = Vec::new();
+        for rule in by_section.values() {
+            for owner in &rule.owners {
+                if !owners
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(owner))
+                {
+                    owners.push(owner);
+                }
+            }
+        }
+        owners
+    }
+}
+
+/// 줄 끝 주석(`#`)을 뗀다. `\#`는 경로의 일부로 본다.
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    for (i, b) in bytes.iter().enumerate() {
+        if *b == b'#' && (i == 0 || bytes[i - 1] != b'\\') {
+            return &line[..i];
+        }
+    }
+    line
+}
+
+/// gitignore 형식 패턴 일치 여부. `/`로 시작하거나 중간에 `/`가 있으면 저장소 루트 기준,
+/// 아니면 어느 깊이에서든 일치한다. 디렉터리와 일치하면 그 아래 파일 전체가 포함되지만,
+/// `docs/*`처럼 `/*`로 끝나는 패턴은 바로 아래 파일만 포함한다.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let directory_only = pattern.ends_with('/');
+    let trimmed = pattern.trim_end_matches('/');
+    let anchored = trimmed.contains('/');
+    let core = trimmed.trim_start_matches('/').replace("\\#", "#");
+    if core.is_empty() {
+        return false;
+    }
+    let glob = if anchored { core } else { format!("**/{core}") };
+    if !directory_only && glob_match(glob.as_bytes(), path.as_bytes()) {
+        return true;
+    }
+    !glob.ends_with("/*") && glob_match(format!("{glob}/**").as_bytes(), path.as_bytes())
+}
+
+/// 변경 파일의 코드 소유자와 최근 커밋 작성자로 사람 리뷰어를 추천한다.
+///
+/// `recent`는 변경 파일별 최근 커밋 작성자 사용자 이름(최신순)이고, `exclude`(PR 작성자 등)와
+/// 대소문자 구분 없이 같은 사용자는 뺀다. 코드 소유자는 모두 넣고(소유 파일이 많은 순),
+/// 소유자가 아닌 최근 작성자는 관련 파일이 많은 순으로 `max_recent`명까지 덧붙인다.
+pub fn suggest_reviewers(
+    owners: Option<&CodeOwners>,
+    changed: &[String],
+    recent: &[(String, Vec<String>)],
+    exclude: &[String],
+    max_recent: usize,
+) -> Vec<SuggestedReviewer> {
+    let excluded = |handle: &str| {
+        let name = handle.strip_prefix('@').unwrap_or(handle);
+        exclude.iter().any(|user| user.eq_ignore_ascii_case(name))
+    };
+
+    let mut owned: Vec<SuggestedReviewer> = Vec::new();
+    if let Some(owners) = owners {
+        for path in changed {
+            for owner in owners.owners_of(path) {
+                if excluded(owner) {
+                    continue;
+                }
+                match owned
+                    .iter_mut()
+                    .find(|r| r.handle.eq_ignore_ascii_case(owner))
+                {
+                    Some(reviewer) => reviewer.owned_paths.push(path.clone()),
+                    None => owned.push(SuggestedReviewer {
+                        handle: owner.to_string(),
+                        owned_paths: vec![path.clone()],
+                        recent_paths: Vec::new(),
+                        requested: false,
+                    }),
+                }
+            }
+        }
+    }
+
+    let mut authors: Vec<SuggestedReviewer> = Vec::new();
+    for (path, users) in recent {
+        for user in users {
+            let handle = format!("@{user}");
+            if excluded(&handle) {
+                continue;
+            }
+            let reviewer = match owned
+                .iter_mut()
+                .chain(authors.iter_mut())
+                .find(|r| r.handle.eq_ignore_ascii_case(&handle))
+            {
+                Some(reviewer) => reviewer,
+                None => {
+                    authors.push(SuggestedReviewer {
+                        handle,
+                        owned_paths: Vec::new(),
+                        recent_paths: Vec::new(),
+                        requested: false,
+                    });
+                    authors.last_mut().expect("just pushed")
+                }
+            };
+            if !reviewer.recent_paths.contains(path) {
+                reviewer.recent_paths.push(path.clone());
+            }
+        }
+    }
+
+    // 정렬은 안정 정렬이라 같은 개수면 먼저 나온 순서를 유지한다.
+    owned.sort_by_key(|r| std::cmp::Reverse(r.owned_paths.len()));
+    authors.sort_by_key(|r| std::cmp::Reverse(r.recent_paths.len()));
+    owned.extend(authors.into_iter().take(max_recent));
+    owned
+}
@@ -0,0 +1,137 @@
+//! 호스트 코멘트 길이 한도에 맞춘 본문 분할/절단.
+//!
+//! GitHub 코멘트는 65536자, GitLab 노트는 1MB를 넘으면 게시가 거부된다. 긴 본문은 줄 경계에서
+//! 나누고, 코드 펜스 안에서 나뉘면 앞 조각에서 펜스를 닫고 다음 조각에서 같은 여는 줄로 다시 연다.
+
+use crate::domain::markdown::{fence_run, next_fence};
+
+/// 한도를 이보다 작게 잡으면 이 값으로 올린다(조각 머리말/꼬리말과 펜스를 넣을 공간).
+pub const MIN_COMMENT_CHARS: usize = 1_000;
+
+/// 이어지는 코멘트의 머리말/꼬리말(마커, 파트 번호, 링크)에 남겨 두는 글자 수.
+pub const PART_HEADER_RESERVE: usize = 400;
+
+/// 본문이 한도를 넘을 때의 처리 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentOverflow {
+    /// 이어지는 코멘트로 나눠 게시한다.
+    Split,
+    /// 한도에 맞춰 자르고 잘렸다는 안내를 붙인다.
+    Truncate,
+}
+
+impl CommentOverflow {
+    /// 설정 문자열을 정책으로 변환한다. 지원값: split, truncate (기본 split)
+    pub fn from_config(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("truncate") => Self::Truncate,
+            _ => Self::Split,
+        }
+    }
+
+    /// inspection 출력용 코드값.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Split => "split",
+            Self::Truncate => "truncate",
+        }
+    }
+}
+
+/// 본문 글자 수(호스트 한도는 바이트가 아니라 글자 기준).
+pub fn comment_chars(body: &str) -> usize {
+    body.chars().count()
+}
+
+/// 본문을 조각마다 `limit`자 이하가 되도록 줄 경계에서 나눈다.
+/// 한 줄이 한도의 절반보다 길면 글자 경계에서 자른다.
+/// 호출자는 조각에 붙일 머리말/꼬리말만큼 뺀 한도를 넘긴다.
+pub fn split_comment(body: &str, limit: usize) -> Vec<String> {
+    let limit = limit.max(MIN_COMMENT_CHARS / 2);
+    let max_piece = limit / 2;
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+    // 열린 코드 펜스의 여는 줄(줄바꿈 제외)
+    let mut fence: Option<String> = None;
+
+    for line in body.split_inclusive('\n') {
+        let line_len = comment_chars(line);
+        let pieces = if line_len > max_piece {
+            split_chars(line, max_piece)
+        } else {
+            vec![line.to_string()]
+        };
+        let fence_after = if pieces.len() == 1 {
+            next_fence(fence.as_deref(), line)
+        } else {
+            fence.clone()
+        };
+
+        for (idx, piece) in pieces.iter().enumerate() {
+            let after = if idx + 1 == pieces.len() {
+                fence_after.as_deref()
+            } else {
+                fence.as_deref()
+            };
+            let piece_len = comment_chars(piece);
+            let reserve = after.map_or(0, |open| fence_run(open).len() + 2);
+            if current_len > 0 && current_len + piece_len + reserve > limit {
+                if let Some(open) = &fence {
+                    close_fence(&mut current, open);
+                }
+                parts.push(std::mem::take(&mut current));
+                if let Some(open) = &fence {
+                    current.push_str(open);
+                    current.push('\n');
+                }
+                current_len = comment_chars(&current);
+            }
+            current.push_str(piece);
+            current_len += piece_len;
+        }
+        fence = fence_after;
+    }
+
+    if !current.trim().is_empty() || parts.is_empty() {
+        if let Some(open) = &fence {
+            close_fence(&mut current, open);
+        }
+        parts.push(current);
+    }
+    parts
+}
+
+/// 본문을 `limit`자 이하로 자르고 잘렸다는 안내를 붙인다.
+pub fn truncate_comment(body: &str, limit: usize) -> String {
+    let limit = limit.max(MIN_COMMENT_CHARS);
+    let total = comment_chars(body);
+    if total <= limit {
+        return body.to_string();
+    }
+    let notice = format!(
+        "\n\n---\n\n_Truncated: this comment is {total} characters, over the {limit}-character limit of the host. Use `--output` to keep the full text._\n"
+    );
+    let mut parts = split_comment(body, limit - comment_chars(&notice));
+    let mut out = parts.swap_remove(0);
+    out.truncate(out.trim_end().len());
+    out.push_str(&notice);
+    out
+}
+
+/// 줄 하나를 `size`자 단위로 자른다.
+fn split_chars(line: &str, size: usize) -> Vec<String> {
+    let chars: Vec<char> = line.chars().collect();
+    chars
+        .chunks(size.max(1))
+        .map(|chunk| chunk.iter().collect())
+        .collect()
+}
+
+fn close_fence(out: &mut String, open: &str) {
+    if !out.ends_with('\n') {
+        out.push('\n');
+    }
+    out.push_str(fence_run(open));
+    out.push('\n');
+}
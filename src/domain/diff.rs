@@ -0,0 +1,598 @@
+//! unified diff 분할/청크 정책.
+//!
+//! 파일 단위(`diff --git` 헤더 기준)로 diff를 나누고, 경로 glob 필터와 바이트 한도에 맞춰
+//! 파일 구간을 거르거나 청크로 구성한다. 이름 변경/복사는 새 경로, 삭제는 이전 경로로 구분하며,
+//! 한도를 넘는 파일은 hunk 경계에서 나눈다. 파일 구간과 청크는 가능한 한 원본 diff를 빌려 써서
+//! 큰 diff를 여러 벌 복사하지 않는다.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::domain::review::LineRange;
+
+/// diff 내 한 파일 구간.
+#[derive(Debug, Clone)]
+pub struct DiffFile<'a> {
+    /// 변경 후 경로(삭제 파일이면 변경 전 경로)
+    pub path: String,
+    /// 이름이 바뀌었거나 복사된 파일의 변경 전 경로
+    pub old_path: Option<String>,
+    /// 심볼릭 링크(mode `120000`) 여부. hunk 내용은 링크 대상 경로라 코드 변경으로 보지 않는다.
+    pub symlink: bool,
+    /// 헤더를 포함한 파일 구간 원문(원본 diff의 일부, 비밀값을 가리면 새 문자열)
+    pub text: Cow<'a, str>,
+}
+
+impl DiffFile<'_> {
+    /// hunk(`@@`)가 있어 리뷰할 텍스트 변경이 있는지 여부(심볼릭 링크는 제외).
+    pub fn has_text_changes(&self) -> bool {
+        !self.symlink && self.text.lines().any(|line| line.starts_with("@@"))
+    }
+
+    /// git이 바이너리로 표시한 파일인지 여부(`Binary files ... differ`, `GIT binary patch`).
+    pub fn is_binary(&self) -> bool {
+        self.text
+            .lines()
+            .any(|line| line.starts_with("Binary files ") || line == "GIT binary patch")
+    }
+}
+
+/// 리뷰할 텍스트 변경이 없는 diff의 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnreviewableDiff {
+    /// 변경 파일이 없음(빈 PR/MR 또는 diff 필터로 모두 제외)
+    Empty,
+    /// 바이너리 파일만 바뀜
+    BinaryOnly,
+    /// 내용 변경 없이 이름/권한/심볼릭 링크만 바뀜(바이너리 파일이 섞여 있을 수 있음)
+    MetadataOnly,
+}
+
+impl UnreviewableDiff {
+    /// 필터 적용 후 남은 파일 구간에 텍스트 변경이 하나도 없으면 그 종류를 돌려준다.
+    pub fn detect(files: &[DiffFile<'_>]) -> Option<Self> {
+        if files.is_empty() {
+            return Some(Self::Empty);
+        }
+        if files.iter().any(DiffFile::has_text_changes) {
+            return None;
+        }
+        if files.iter().all(DiffFile::is_binary) {
+            Some(Self::BinaryOnly)
+        } else {
+            Some(Self::MetadataOnly)
+        }
+    }
+
+    /// 요약 코멘트/진행 로그에 쓰는 설명.
+    pub fn describe(self) -> &'static str {
+        match self {
+            Self::Empty => "no changed files are left to review (empty diff or all files excluded)",
+            Self::BinaryOnly => "only binary files changed",
+            Self::MetadataOnly => "only file names, modes or symlinks changed",
+        }
+    }
+}
+
+/// 청크 하나에 포함된 diff 파일 묶음.
+#[derive(Debug, Clone)]
+pub struct DiffChunk<'a> {
+    pub files: Vec<String>,
+    /// 파일 하나짜리 청크는 파일 구간을 그대로 빌려 쓴다.
+    pub text: Cow<'a, str>,
+}
+
+/// unified diff를 파일 구간으로 나눈다.
+/// `diff --git` 헤더가 없으면 `--- `/`+++ ` 헤더 쌍을 파일 경계로 사용한다.
+/// hunk 본문은 hunk 헤더(`@@ -a,b +c,d @@`)의 줄 수만큼 건너뛰므로 `--`/`++`로 시작하는 변경 줄을
+/// 파일 경계나 경로로 오인하지 않는다. 경로는 `rename to`/`copy to`, `+++`, `diff --git` 순으로 정한다.
+pub fn split_diff_by_file(diff: &str) -> Vec<DiffFile<'_>> {
+    let has_git_headers = diff.lines().any(|l| l.starts_with("diff --git "));
+    let mut files: Vec<DiffFile> = Vec::new();
+    // 현재 파일 구간의 시작 위치와 헤더
+    let mut current: Option<(usize, FileHeader)> = None;
+    // 현재 hunk에서 남은 (변경 전, 변경 후) 줄 수
+    let mut remaining = (0u32, 0u32);
+    let mut offset = 0;
+    let mut lines = diff.split_inclusive('\n').peekable();
+
+    while let Some(line) = lines.next() {
+        // 줄 수가 맞지 않는 hunk라도 다음 파일 헤더에서는 본문을 끝낸다.
+        if remaining != (0, 0) && !line.starts_with("diff --git ") {
+            consume_hunk_line(&mut remaining, line);
+            offset += line.len();
+            continue;
+        }
+        remaining = (0, 0);
+
+        let starts_file = if has_git_headers {
+            line.starts_with("diff --git ")
+        } else {
+            line.starts_with("--- ")
+                && lines.peek().is_some_and(|next| next.starts_with("+++ "))
+        };
+        if starts_file && let Some((start, header)) = current.take() {
+            files.push(header.into_file(&diff[start..offset]));
+        }
+
+        let (_, header) = current.get_or_insert_with(|| (offset, FileHeader::default()));
+        match parse_hunk_counts(line) {
+            Some(counts) => {
+                remaining = counts;
+                header.in_hunks = true;
+            }
+            None => header.update(line),
+        }
+        offset += line.len();
+    }
+
+    if let Some((start, header)) = current.take() {
+        files.push(header.into_file(&diff[start..]));
+    }
+    files.retain(|f| !f.text.trim().is_empty());
+    files
+}
+
+/// 파일 구간 헤더(첫 hunk 전까지)에서 읽은 정보.
+#[derive(Debug, Default)]
+struct FileHeader {
+    /// 첫 hunk가 나온 뒤에는 헤더를 더 읽지 않는다.
+    in_hunks: bool,
+    /// `diff --git a/<old> b/<new>`의 새 경로
+    git_path: Option<String>,
+    /// `--- a/<path>`(새 파일이면 None)
+    old_path: Option<String>,
+    /// `+++ b/<path>`, `rename to`, `copy to`(삭제 파일이면 None)
+    new_path: Option<String>,
+    /// `rename from`, `copy from`
+    source_path: Option<String>,
+    symlink: bool,
+}
+
+impl FileHeader {
+    fn update(&mut self, line: &str) {
+        if self.in_hunks {
+            return;
+        }
+        let line = line.trim_end_matches(['\n', '\r']);
+        if let Some(rest) = line.strip_prefix("diff --git ") {
+            self.git_path = git_header_new_path(rest);
+        } else if let Some(rest) = line.strip_prefix("+++ ") {
+            self.new_path = header_path(rest, "b/");
+        } else if let Some(rest) = line.strip_prefix("--- ") {
+            self.old_path = header_path(rest, "a/");
+        } else if let Some(rest) = line
+            .strip_prefix("rename to ")
+            .or_else(|| line.strip_prefix("copy to "))
+        {
+            self.new_path = Some(unquote_path(rest));
+        } else if let Some(rest) = line
+            .strip_prefix("rename from ")
+            .or_else(|| line.strip_prefix("copy from "))
+        {
+            self.source_path = Some(unquote_path(rest));
+        } else if line.ends_with(" 120000") && !line.starts_with("old mode ") {
+            // `new file mode`/`deleted file mode`/`new mode`/`index`의 모드가 심볼릭 링크
+            self.symlink = true;
+        }
+    }
+
+    fn into_file(self, text: &str) -> DiffFile<'_> {
+        let path = self
+            .new_path
+            .or(self.git_path)
+            .or(self.old_path)
+            .unwrap_or_default();
+        DiffFile {
+            old_path: self.source_path.filter(|source| *source != path),
+            path,
+            symlink: self.symlink,
+            text: Cow::Borrowed(text),
+        }
+    }
+}
+
+/// `---`/`+++` 헤더의 경로. 뒤에 붙는 탭(이후 타임스탬프)과 `a/`/`b/` 접두사를 뗀다.
+fn header_path(rest: &str, prefix: &str) -> Option<String> {
+    let raw = rest.split('\t').next().unwrap_or(rest).trim_end();
+    if raw == "/dev/null" {
+        return None;
+    }
+    let path = unquote_path(raw);
+    Some(
+        path.strip_prefix(prefix)
+            .map(ToString::to_string)
+            .unwrap_or(path),
+    )
+}
+
+/// `diff --git` 헤더의 새 경로. 경로에 공백이 있어도 `a/`와 `b/` 경로가 같으면 정확히 나눈다.
+fn git_header_new_path(rest: &str) -> Option<String> {
+    if rest.starts_with('"') || rest.ends_with('"') {
+        let split = quoted_token_end(rest).unwrap_or(rest.len());
+        let new = rest.get(split..)?.trim_start();
+        let new = unquote_path(new);
+        return Some(
+            new.strip_prefix("b/")
+                .map(ToString::to_string)
+                .unwrap_or(new),
+        );
+    }
+    // "a/<path> b/<path>"에서 두 경로가 같은 경우(이름이 그대로인 파일)
+    if rest.len() >= 5 && (rest.len() - 5).is_multiple_of(2) {
+        let n = (rest.len() - 5) / 2;
+        if let (Some(old), Some(sep), Some(new)) =
+            (rest.get(..2 + n), rest.get(2 + n..5 + n), rest.get(5 + n..))
+            && old.strip_prefix("a/") == Some(new)
+            && sep == " b/"
+        {
+            return Some(new.to_string());
+        }
+    }
+    rest.find(" b/").map(|idx| rest[idx + 3..].to_string())
+}
+
+/// 따옴표로 시작하는 첫 토큰의 끝 위치(닫는 따옴표 다음). 따옴표로 시작하지 않으면 첫 공백 위치.
+fn quoted_token_end(text: &str) -> Option<usize> {
+    if !text.starts_with('"') {
+        return text.find(' ');
+    }
+    let bytes = text.as_bytes();
+    let mut i = 1;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\\' => i += 2,
+            b'"' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// git이 특수 문자가 있는 경로에 쓰는 C 문자열 따옴표(`"a/t\303\251st.txt"`)를 푼다.
+fn unquote_path(raw: &str) -> String {
+    let Some(inner) = raw
+        .strip_prefix('"')
+        .and_then(|rest| rest.strip_suffix('"'))
+    else {
+        return raw.to_string();
+    };
+    let bytes = inner.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' || i + 1 == bytes.len() {
+            out.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        let octal = bytes
+            .get(i + 1..i + 4)
+            .filter(|digits| digits.iter().all(|d| (b'0'..=b'7').contains(d)));
+        if let Some(digits) = octal {
+            out.push(
+                digits
+                    .iter()
+                    .fold(0u8, |acc, d| acc.wrapping_mul(8).wrapping_add(d - b'0')),
+            );
+            i += 4;
+            continue;
+        }
+        out.push(match bytes[i + 1] {
+            b'n' => b'\n',
+            b't' => b'\t',
+            other => other,
+        });
+        i += 2;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// `@@ -a,b +c,d @@` 헤더에서 (변경 전 줄 수, 변경 후 줄 수)를 읽는다(생략되면 1).
+fn parse_hunk_counts(line: &str) -> Option<(u32, u32)> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let count = |range: &str| match range.split_once(',') {
+        Some((_, count)) => count.parse::<u32>().ok(),
+        None => Some(1),
+    };
+    Some((count(old)?, count(new)?))
+}
+
+/// hunk 본문 한 줄만큼 남은 줄 수를 줄인다. 빈 줄은 공백이 지워진 문맥 줄로 본다.
+fn consume_hunk_line(remaining: &mut (u32, u32), line: &str) {
+    let (old, new) = remaining;
+    match line.as_bytes().first() {
+        Some(b'+') => *new = new.saturating_sub(1),
+        Some(b'-') => *old = old.saturating_sub(1),
+        Some(b'\\') => {}
+        _ => {
+            *old = old.saturating_sub(1);
+            *new = new.saturating_sub(1);
+        }
+    }
+}
+
+/// 리뷰 범위를 가늠하기 위한 diff 통계.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffStats {
+    pub files_changed: usize,
+    pub insertions: usize,
+    pub deletions: usize,
+    /// 변경된 최상위 디렉터리(처음 나온 순서, 루트 파일은 `.`)
+    pub top_level_dirs: Vec<String>,
+}
+
+impl DiffStats {
+    /// 파일 구간의 hunk 본문에서 추가/삭제 줄 수를 센다(`---`/`+++` 헤더는 제외).
+    pub fn from_files(files: &[DiffFile<'_>]) -> Self {
+        let mut stats = Self {
+            files_changed: files.len(),
+            ..Self::default()
+        };
+        for file in files {
+            let dir = match file.path.split_once('/') {
+                Some((dir, _)) => dir,
+                None => ".",
+            };
+            if !stats.top_level_dirs.iter().any(|d| d == dir) {
+                stats.top_level_dirs.push(dir.to_string());
+            }
+            let mut in_hunk = false;
+            for line in file.text.lines() {
+                if line.starts_with("@@") {
+                    in_hunk = true;
+                } else if line.starts_with("diff --git ") {
+                    in_hunk = false;
+                } else if in_hunk && line.starts_with('+') {
+                    stats.insertions += 1;
+                } else if in_hunk && line.starts_with('-') {
+                    stats.deletions += 1;
+                }
+            }
+        }
+        stats
+    }
+
+    /// 한 줄 요약(예: `3 files changed, +10 -2 (src, docs)`).
+    pub fn summary(&self) -> String {
+        let files = if self.files_changed == 1 {
+            "1 file changed".to_string()
+        } else {
+            format!("{} files changed", self.files_changed)
+        };
+        let mut text = format!("{files}, +{} -{}", self.insertions, self.deletions);
+        if !self.top_level_dirs.is_empty() {
+            text.push_str(&format!(" ({})", self.top_level_dirs.join(", ")));
+        }
+        text
+    }
+}
+
+/// 변경 후 파일의 한 줄이 diff에서 차지하는 위치.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DiffLine {
+    /// 파일 구간 안에서 몇 번째 hunk인지(0부터)
+    hunk: usize,
+    /// 문맥 줄이면 변경 전 줄 번호, 추가된 줄이면 None
+    old_line: Option<u32>,
+}
+
+/// 인라인 코멘트를 달 diff 위치. 줄 번호는 변경 후 파일 기준이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LinePosition {
+    pub start: u32,
+    pub end: u32,
+    /// 마지막 줄이 문맥 줄이면 변경 전 줄 번호(GitLab position에 필요)
+    pub old_end: Option<u32>,
+}
+
+/// 파일 구간에서 변경 후 파일 기준으로 코멘트를 달 수 있는 줄(hunk 안의 추가/문맥 줄) 표.
+#[derive(Debug, Clone, Default)]
+pub struct DiffLineMap {
+    lines: BTreeMap<u32, DiffLine>,
+}
+
+impl DiffLineMap {
+    /// 파일 구간의 hunk 헤더(`@@ -a,b +c,d @@`)를 따라 줄 번호를 매긴다.
+    pub fn from_file(file: &DiffFile<'_>) -> Self {
+        let mut lines = BTreeMap::new();
+        let mut hunk: Option<usize> = None;
+        let (mut old_line, mut new_line) = (0u32, 0u32);
+        for line in file.text.lines() {
+            if let Some((old_start, new_start)) = parse_hunk_header(line) {
+                hunk = Some(hunk.map_or(0, |h| h + 1));
+                old_line = old_start;
+                new_line = new_start;
+                continue;
+            }
+            let Some(hunk) = hunk else {
+                continue;
+            };
+            match line.as_bytes().first() {
+                Some(b'+') => {
+                    lines.insert(
+                        new_line,
+                        DiffLine {
+                            hunk,
+                            old_line: None,
+                        },
+                    );
+                    new_line += 1;
+                }
+                Some(b'-') => old_line += 1,
+                Some(b'\\') => {}
+                _ => {
+                    lines.insert(
+                        new_line,
+                        DiffLine {
+                            hunk,
+                            old_line: Some(old_line),
+                        },
+                    );
+                    old_line += 1;
+                    new_line += 1;
+                }
+            }
+        }
+        Self { lines }
+    }
+
+    /// 구간의 모든 줄이 같은 hunk 안에 있으면 인라인 코멘트 위치를 반환한다.
+    pub fn position(&self, range: LineRange) -> Option<LinePosition> {
+        let first = self.lines.get(&range.start)?;
+        let all_in_hunk = (range.start..=range.end)
+            .all(|line| self.lines.get(&line).is_some_and(|l| l.hunk == first.hunk));
+        if !all_in_hunk {
+            return None;
+        }
+        Some(LinePosition {
+            start: range.start,
+            end: range.end,
+            old_end: self.lines.get(&range.end)?.old_line,
+        })
+    }
+}
+
+/// `@@ -a,b +c,d @@` 헤더에서 (변경 전 시작 줄, 변경 후 시작 줄)을 읽는다.
+pub(crate) fn parse_hunk_header(line: &str) -> Option<(u32, u32)> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, _) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let start = |range: &str| range.split(',').next()?.parse::<u32>().ok();
+    Some((start(old)?, start(new)?))
+}
+
+/// 파일 구간을 순서대로 묶어 `max_bytes` 이하 청크로 구성한다.
+/// 단일 파일이 한도를 넘으면 hunk 경계에서 나누고 조각마다 파일 헤더를 붙인다
+/// (hunk 하나가 한도를 넘으면 그 hunk만으로 청크를 만든다). 파일 중간을 바이트 단위로 자르지 않는다.
+pub fn chunk_diff_files<'a>(files: &[DiffFile<'a>], max_bytes: usize) -> Vec<DiffChunk<'a>> {
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut size = 0;
+    for (idx, file) in files.iter().enumerate() {
+        if size > 0 && size + file.text.len() > max_bytes {
+            chunks.push(group_chunk(&files[start..idx]));
+            start = idx;
+            size = 0;
+        }
+        if file.text.len() > max_bytes {
+            chunks.extend(split_file_by_hunks(file, max_bytes));
+            start = idx + 1;
+            continue;
+        }
+        size += file.text.len();
+    }
+    if size > 0 {
+        chunks.push(group_chunk(&files[start..]));
+    }
+    chunks
+}
+
+fn group_chunk<'a>(group: &[DiffFile<'a>]) -> DiffChunk<'a> {
+    DiffChunk {
+        files: group.iter().map(|f| f.path.clone()).collect(),
+        text: match group {
+            [single] => single.text.clone(),
+            _ => Cow::Owned(group.iter().map(|f| f.text.as_ref()).collect()),
+        },
+    }
+}
+
+/// 한도를 넘는 파일 구간을 hunk 묶음 청크로 나눈다. 조각마다 첫 hunk 앞의 파일 헤더를 반복한다.
+fn split_file_by_hunks<'a>(file: &DiffFile<'a>, max_bytes: usize) -> Vec<DiffChunk<'a>> {
+    let text = file.text.as_ref();
+    // hunk 본문 줄은 ` `/`+`/`-`/`\`로 시작하므로 `@@`로 시작하는 줄은 모두 hunk 헤더다.
+    let mut hunk_starts = Vec::new();
+    let mut offset = 0;
+    for line in text.split_inclusive('\n') {
+        if line.starts_with("@@") {
+            hunk_starts.push(offset);
+        }
+        offset += line.len();
+    }
+    if hunk_starts.len() <= 1 {
+        return vec![group_chunk(std::slice::from_ref(file))];
+    }
+
+    let header = &text[..hunk_starts[0]];
+    let mut pieces: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for (idx, &start) in hunk_starts.iter().enumerate() {
+        let end = hunk_starts.get(idx + 1).copied().unwrap_or(text.len());
+        let hunk = &text[start..end];
+        if !current.is_empty() && header.len() + current.len() + hunk.len() > max_bytes {
+            pieces.push(std::mem::take(&mut current));
+        }
+        current.push_str(hunk);
+    }
+    pieces.push(current);
+
+    pieces
+        .into_iter()
+        .map(|body| DiffChunk {
+            files: vec![file.path.clone()],
+            text: Cow::Owned(format!("{header}{body}")),
+        })
+        .collect()
+}
+
+/// include/exclude glob으로 파일 구간을 거른다.
+/// include가 비어 있으면 모든 파일을 포함하고, exclude에 걸린 파일은 항상 제외한다.
+/// 반환값은 (남은 파일, 제외된 파일 경로).
+pub fn filter_diff_files<'a>(
+    files: Vec<DiffFile<'a>>,
+    include: &[String],
+    exclude: &[String],
+) -> (Vec<DiffFile<'a>>, Vec<String>) {
+    let mut kept = Vec::new();
+    let mut dropped = Vec::new();
+    for file in files {
+        let included = include.is_empty() || include.iter().any(|p| path_matches(p, &file.path));
+        let excluded = exclude.iter().any(|p| path_matches(p, &file.path));
+        if included && !excluded {
+            kept.push(file);
+        } else {
+            dropped.push(file.path);
+        }
+    }
+    (kept, dropped)
+}
+
+/// 경로가 glob 패턴과 일치하는지 판단한다.
+/// `/`가 없는 패턴(예: `*.lock`)은 파일 이름에, 그 외는 전체 경로에 적용한다.
+/// 지원 문법: `*`(경로 구분자 제외 임의 문자열), `**`(디렉터리 여러 단계), `?`(문자 하나).
+pub fn path_matches(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim().trim_start_matches("./");
+    if pattern.is_empty() {
+        return false;
+    }
+    let target = if pattern.contains('/') {
+        path
+    } else {
+        path.rsplit('/').next().unwrap_or(path)
+    };
+    let pattern = pattern.trim_start_matches('/');
+    glob_match(pattern.as_bytes(), target.as_bytes())
+}
+
+pub(crate) fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern {
+        [] => text.is_empty(),
+        [b'*', b'*', rest @ ..] => {
+            // `**/`는 0개 이상의 디렉터리와 일치한다.
+            let rest = rest.strip_prefix(b"/").unwrap_or(rest);
+            (0..=text.len()).any(|i| {
+                (i == 0 || text[i - 1] == b'/') && glob_match(rest, &text[i..])
+            }) || rest.is_empty()
+        }
+        [b'*', rest @ ..] => (0..=text.len())
+            .take_while(|&i| i == 0 || text[i - 1] != b'/')
+            .any(|i| glob_match(rest, &text[i..])),
+        [b'?', rest @ ..] => {
+            matches!(text.first(), Some(c) if *c != b'/') && glob_match(rest, &text[1..])
+        }
+        [c, rest @ ..] => text.first() == Some(c) && glob_match(rest, &text[1..]),
+    }
+}
@@ -0,0 +1,143 @@
+//! Markdown 코드 펜스 판별과 provider 출력 정리.
+//!
+//! 코멘트 분할과 provider 출력 정리에서 코드 블록 경계를 같은 규칙으로 판단한다.
+
+use crate::domain::marker::is_marker_like;
+
+/// 줄 앞의 펜스 문자열(``` 또는 ~~~ 3개 이상 반복). 펜스가 아니면 빈 문자열.
+pub fn fence_run(line: &str) -> &str {
+    let line = line.trim_start();
+    let Some(first) = line.chars().next().filter(|c| matches!(c, '`' | '~')) else {
+        return "";
+    };
+    let len = line.chars().take_while(|c| *c == first).count();
+    if len < 3 { "" } else { &line[..len] }
+}
+
+/// 줄을 처리한 뒤의 코드 펜스 상태. `open`은 열린 펜스의 여는 줄(언어 표기 포함)이다.
+pub fn next_fence(open: Option<&str>, line: &str) -> Option<String> {
+    let trimmed = line.trim();
+    match open {
+        None if !fence_run(trimmed).is_empty() => Some(trimmed.to_string()),
+        None => None,
+        Some(open) => {
+            let run = fence_run(open);
+            let closing = fence_run(trimmed);
+            let closes = closing.len() >= run.len()
+                && closing.starts_with(&run[..1])
+                && trimmed[closing.len()..].trim().is_empty();
+            if closes { None } else { Some(open.to_string()) }
+        }
+    }
+}
+
+/// 코드 블록 밖의 미완료 작업 목록 항목(`- [ ] ...`, `* [ ] ...`)을 `- [ ] ...` 형태로 모은다.
+/// 들여쓴 하위 항목도 최상위 항목으로 평탄화한다.
+pub fn task_list_items(body: &str) -> Vec<String> {
+    let mut fence: Option<String> = None;
+    let mut items = Vec::new();
+    for line in body.lines() {
+        let in_fence = fence.is_some();
+        fence = next_fence(fence.as_deref(), line);
+        if in_fence || fence.is_some() {
+            continue;
+        }
+        let trimmed = line.trim();
+        let Some(rest) = trimmed
+            .strip_prefix("- [ ]")
+            .or_else(|| trimmed.strip_prefix("* [ ]"))
+        else {
+            continue;
+        };
+        let rest = rest.trim();
+        if !rest.is_empty() {
+            items.push(format!("- [ ] {rest}"));
+        }
+    }
+    items
+}
+
+/// provider가 쓴 Markdown을 코멘트에 넣기 전에 정리한다.
+/// - 코드 블록 밖의 제목은 가장 얕은 제목이 `min_heading` 단계가 되도록 내린다(최대 6단계).
+///   이미 충분히 깊으면 그대로 둔다.
+/// - 코드 블록 밖의 봇 마커처럼 보이는 HTML 주석은 `&lt;!--`로 이스케이프해 중복 판단을 흐리지 않게 한다.
+/// - 닫히지 않은 코드 펜스와 `<details>` 블록을 끝에서 닫는다.
+pub fn sanitize_provider_markdown(body: &str, min_heading: usize) -> String {
+    let mut fence: Option<String> = None;
+    let mut shallowest: Option<usize> = None;
+    for line in body.lines() {
+        if fence.is_none()
+            && let Some(level) = heading_level(line)
+        {
+            shallowest = Some(shallowest.map_or(level, |s| s.min(level)));
+        }
+        fence = next_fence(fence.as_deref(), line);
+    }
+    let shift = shallowest.map_or(0, |level| min_heading.saturating_sub(level));
+
+    let mut out = String::with_capacity(body.len());
+    let mut open_details = 0usize;
+    fence = None;
+    for line in body.split_inclusive('\n') {
+        let next = next_fence(fence.as_deref(), line);
+        if fence.is_some() || next.is_some() {
+            out.push_str(line);
+        } else {
+            let line = match heading_level(line) {
+                Some(level) if shift > 0 => {
+                    let depth = (level + shift).min(6);
+                    format!("{}{}", "#".repeat(depth), &line.trim_start()[level..])
+                }
+                _ => line.to_string(),
+            };
+            let lower = line.to_ascii_lowercase();
+            open_details += lower.matches("<details").count();
+            open_details = open_details.saturating_sub(lower.matches("</details>").count());
+            out.push_str(&escape_marker_comments(&line));
+        }
+        fence = next;
+    }
+
+    if let Some(open) = &fence {
+        if !out.ends_with('\n') {
+            out.push('\n');
+        }
+        out.push_str(fence_run(open));
+    }
+    for _ in 0..open_details {
+        out.push_str("\n\n</details>");
+    }
+    out
+}
+
+/// ATX 제목(`#`~`######` + 공백)의 단계.
+fn heading_level(line: &str) -> Option<usize> {
+    let trimmed = line.trim_start();
+    // 4칸 이상 들여쓴 줄은 코드 블록이다.
+    if line.len() - trimmed.len() > 3 {
+        return None;
+    }
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    let rest = &trimmed[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with([' ', '\t', '\n', '\r'])))
+        .then_some(level)
+}
+
+/// 봇 마커처럼 보이는 HTML 주석의 여는 `<!--`를 이스케이프한다.
+fn escape_marker_comments(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("<!--") {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 4..];
+        let comment = after.split("-->").next().unwrap_or(after);
+        if is_marker_like(comment) {
+            out.push_str("&lt;!--");
+        } else {
+            out.push_str("<!--");
+        }
+        rest = after;
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,379 @@
+//! 리뷰 코멘트 마커 형식과 버전 호환 매칭.
+//!
+//! 봇이 게시하는 코멘트 첫 줄에는 종류와 HEAD SHA를 담은 HTML 주석 마커가 들어간다.
+//! 새 코멘트는 항상 현재 버전([`MARKER_VERSION`])으로 쓰고, 기존 코멘트를 찾을 때는 과거 형식도
+//! 모두 해석해 이전 버전이 남긴 claim/최종/에이전트 코멘트를 그대로 재사용한다.
+//! - v2: `<!-- repopilot-bot v=2 kind=<claim|final|agent|file|part> sha=<SHA> [at=<unix초> nonce=<실행기>] [agent=<id>] [part=<번호> of=<final|agent:<id>>] [file=<경로>] -->`
+//! - v1: `<!-- repopilot-bot [claim |agent=<id> |file=<경로> ]sha=<SHA>[ at=<unix초>] -->`
+//! - v0: v1과 같은 모양이지만 봇 이름이 `prpilot-bot`
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::domain::review::{LineRange, ReviewComment, RunFooter};
+
+/// 새로 게시하는 마커의 형식 버전.
+pub const MARKER_VERSION: u32 = 2;
+
+/// 현재 봇 이름과, 과거 이름별로 버전 필드가 없을 때 가정하는 형식 버전.
+const BOT_NAMES: &[(&str, u32)] = &[("repopilot-bot", 1), ("prpilot-bot", 0)];
+
+/// 마커가 표시하는 코멘트 종류.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MarkerKind {
+    /// 리뷰 진행 중임을 알리는 claim 코멘트(최종 요약으로 갱신된다)
+    Claim,
+    /// 최종 요약 코멘트
+    Final,
+    /// 에이전트별 개별 코멘트
+    Agent(String),
+    /// 파일 단위 리뷰 스레드
+    File(String),
+    /// 길이 한도 때문에 나뉜 코멘트의 이어지는 조각(`parent`는 `final` 또는 `agent:<id>`, `index`는 2부터)
+    Part { parent: String, index: usize },
+}
+
+/// 코멘트 본문에서 해석한 마커.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReviewMarker {
+    pub version: u32,
+    pub kind: MarkerKind,
+    pub sha: String,
+    /// claim 생성 시각(unix 초). 시각 정보가 없는 구버전 claim이면 None.
+    pub claimed_at: Option<u64>,
+    /// claim을 만든 실행기의 nonce. 동시에 claim한 실행기를 구분한다(구버전 claim이면 None).
+    pub nonce: Option<String>,
+}
+
+pub fn claim_marker(sha: &str, claimed_at: u64, nonce: &str) -> String {
+    format!(
+        "<!-- repopilot-bot v={MARKER_VERSION} kind=claim sha={sha} at={claimed_at} nonce={nonce} -->"
+    )
+}
+
+pub fn final_marker(sha: &str) -> String {
+    format!("<!-- repopilot-bot v={MARKER_VERSION} kind=final sha={sha} -->")
+}
+
+pub fn agent_marker(provider_id: &str, sha: &str) -> String {
+    format!("<!-- repopilot-bot v={MARKER_VERSION} kind=agent sha={sha} agent={provider_id} -->")
+}
+
+/// 최종 요약 코멘트의 조각 부모 키.
+pub const FINAL_PART_PARENT: &str = "final";
+
+/// 이어지는 조각 마커. `parent`는 [`FINAL_PART_PARENT`] 또는 [`agent_part_parent`]다.
+pub fn part_marker(parent: &str, index: usize, sha: &str) -> String {
+    format!(
+        "<!-- repopilot-bot v={MARKER_VERSION} kind=part sha={sha} part={index} of={parent} -->"
+    )
+}
+
+/// 에이전트 개별 코멘트의 조각 부모 키.
+pub fn agent_part_parent(provider_id: &str) -> String {
+    format!("agent:{provider_id}")
+}
+
+/// 경로에 공백이 있을 수 있으므로 `file`은 항상 마지막 필드로 쓴다.
+pub fn file_marker(path: &str, sha: &str) -> String {
+    format!("<!-- repopilot-bot v={MARKER_VERSION} kind=file sha={sha} file={path} -->")
+}
+
+/// 최종 요약에서 지적사항과 후속 이슈의 연결을 기록하는 주석. 봇 마커와 이름이 달라
+/// 코멘트 종류 판단에는 영향을 주지 않는다.
+pub fn issue_link_marker(finding_id: &str, url: &str) -> String {
+    format!("<!-- repopilot-issue finding={finding_id} url={url} -->")
+}
+
+/// 인라인 제안 코멘트의 주석. 지적사항 id(파일 포함)와 줄 구간으로 이미 게시한 제안을 찾는다.
+/// [`issue_link_marker`]처럼 봇 마커와 이름이 달라 코멘트 종류 판단에는 영향을 주지 않는다.
+pub fn suggestion_marker(finding_id: &str, range: LineRange) -> String {
+    format!(
+        "<!-- repopilot-suggestion finding={finding_id} lines={}-{} -->",
+        range.start, range.end
+    )
+}
+
+/// 같은 지적사항/줄 구간의 인라인 제안 코멘트 중 가장 최근 것.
+pub fn find_suggestion_comment<'a>(
+    comments: &'a [ReviewComment],
+    finding_id: &str,
+    range: LineRange,
+) -> Option<&'a ReviewComment> {
+    let marker = suggestion_marker(finding_id, range);
+    comments
+        .iter()
+        .filter(|comment| comment.body.contains(&marker))
+        .max_by_key(|comment| creation_order(comment))
+}
+
+/// 기존 코멘트의 [`issue_link_marker`]에서 지적사항 id → 이슈 URL을 모은다(나중 코멘트가 우선).
+pub fn collect_issue_links(comments: &[ReviewComment]) -> BTreeMap<String, String> {
+    let mut links = BTreeMap::new();
+    for comment in comments {
+        let mut rest = comment.body.as_str();
+        while let Some(start) = rest.find("<!-- repopilot-issue ") {
+            let after = &rest[start + 4..];
+            let Some(end) = after.find("-->") else {
+                break;
+            };
+            let mut finding = None;
+            let mut url = None;
+            for field in after[..end].split_whitespace().skip(1) {
+                if let Some(value) = field.strip_prefix("finding=") {
+                    finding = Some(value);
+                } else if let Some(value) = field.strip_prefix("url=") {
+                    url = Some(value);
+                }
+            }
+            if let (Some(finding), Some(url)) = (finding, url) {
+                links.insert(finding.to_string(), url.to_string());
+            }
+            rest = &after[end + 3..];
+        }
+    }
+    links
+}
+
+/// HTML 주석 내용(`<!--`와 `-->` 사이)이 봇 마커처럼 봇 이름으로 시작하는지 여부.
+/// 형식이 틀려 [`parse_marker`]가 해석하지 못하는 주석도 포함한다.
+pub fn is_marker_like(comment: &str) -> bool {
+    comment
+        .split_whitespace()
+        .next()
+        .is_some_and(|name| BOT_NAMES.iter().any(|(bot, _)| *bot == name))
+}
+
+/// 다시 렌더링한 본문이 기존 코멘트와 같은지 여부. 같으면 코멘트를 수정하지 않아 PR 참여자에게
+/// 불필요한 알림이 가지 않게 한다. 줄 끝 형식(`\r\n`)과 줄 끝 공백, 봇 마커의 시각/실행기 필드
+/// (`at=`, `nonce=`), 실행마다 달라지는 실행 정보 footer 줄은 비교하지 않는다.
+pub fn same_comment_body(existing: &str, rendered: &str) -> bool {
+    existing
+        .trim_end()
+        .lines()
+        .filter(|line| !RunFooter::is_footer_line(line))
+        .map(comparable_line)
+        .eq(rendered
+            .trim_end()
+            .lines()
+            .filter(|line| !RunFooter::is_footer_line(line))
+            .map(comparable_line))
+}
+
+fn comparable_line(line: &str) -> Cow<'_, str> {
+    let line = line.trim_end();
+    match line
+        .strip_prefix("<!--")
+        .and_then(|rest| rest.strip_suffix("-->"))
+        .map(str::trim)
+    {
+        Some(comment) if is_marker_like(comment) => Cow::Owned(
+            comment
+                .split_whitespace()
+                .filter(|field| !field.starts_with("at=") && !field.starts_with("nonce="))
+                .collect::<Vec<_>>()
+                .join(" "),
+        ),
+        _ => Cow::Borrowed(line),
+    }
+}
+
+/// 본문의 첫 번째 봇 마커를 해석한다. 마커가 없거나 알 수 없는 형식이면 None.
+pub fn parse_marker(body: &str) -> Option<ReviewMarker> {
+    let mut rest = body;
+    while let Some(start) = rest.find("<!--") {
+        let after = &rest[start + 4..];
+        let end = after.find("-->")?;
+        if let Some(marker) = parse_marker_body(after[..end].trim()) {
+            return Some(marker);
+        }
+        rest = &after[end + 3..];
+    }
+    None
+}
+
+fn parse_marker_body(text: &str) -> Option<ReviewMarker> {
+    let (name, fields) = text.split_once(char::is_whitespace)?;
+    let implied_version = BOT_NAMES
+        .iter()
+        .find(|(bot, _)| *bot == name)
+        .map(|(_, version)| *version)?;
+
+    // `file=`은 공백을 포함할 수 있어 따로 떼어 낸다. v2는 마지막 필드이고, v0/v1은 `sha=` 앞에 온다.
+    let (fields, file) = match fields.split_once("file=") {
+        Some((head, tail)) => match tail.rsplit_once(" sha=") {
+            Some((path, sha)) => (format!("{head}sha={sha}"), Some(path.to_string())),
+            None => (head.to_string(), Some(tail.trim_end().to_string())),
+        },
+        None => (fields.to_string(), None),
+    };
+
+    let mut version = implied_version;
+    let mut kind = None;
+    let mut sha = None;
+    let mut claimed_at = None;
+    let mut nonce = None;
+    let mut agent = None;
+    let mut part = None;
+    let mut parent = None;
+    let mut claim_flag = false;
+    for token in fields.split_whitespace() {
+        match token.split_once('=') {
+            Some(("v", value)) => version = value.parse().ok()?,
+            Some(("kind", value)) => kind = Some(value),
+            Some(("sha", value)) => sha = Some(value),
+            Some(("at", value)) => claimed_at = value.parse().ok(),
+            Some(("nonce", value)) => nonce = Some(value.to_string()),
+            Some(("agent", value)) => agent = Some(value),
+            Some(("part", value)) => part = value.parse().ok(),
+            Some(("of", value)) => parent = Some(value),
+            // 새 버전이 추가한 필드는 무시한다.
+            Some(_) => {}
+            None if token == "claim" => claim_flag = true,
+            None => {}
+        }
+    }
+
+    let kind = match (kind, agent, file) {
+        (Some("claim"), _, _) => MarkerKind::Claim,
+        (Some("final"), _, _) => MarkerKind::Final,
+        (Some("part"), _, _) => MarkerKind::Part {
+            parent: parent?.to_string(),
+            index: part?,
+        },
+        (Some("agent"), Some(id), _) | (None, Some(id), _) => MarkerKind::Agent(id.to_string()),
+        (Some("file"), _, Some(path)) | (None, _, Some(path)) => MarkerKind::File(path),
+        (None, None, None) if claim_flag => MarkerKind::Claim,
+        (None, None, None) => MarkerKind::Final,
+        _ => return None,
+    };
+    Some(ReviewMarker {
+        version,
+        kind,
+        sha: sha.filter(|sha| !sha.is_empty())?.to_string(),
+        claimed_at,
+        nonce,
+    })
+}
+
+/// 마커가 조건을 만족하는 첫 코멘트를 찾는다.
+pub fn find_marked_comment(
+    comments: &[ReviewComment],
+    matches: impl Fn(&ReviewMarker) -> bool,
+) -> Option<(&ReviewComment, ReviewMarker)> {
+    comments.iter().find_map(|comment| {
+        parse_marker(&comment.body)
+            .filter(|marker| matches(marker))
+            .map(|marker| (comment, marker))
+    })
+}
+
+/// 코멘트 생성 순서 키. GitHub/GitLab 코멘트 id는 생성 순으로 증가한다.
+fn creation_order(comment: &ReviewComment) -> (u64, &str) {
+    (comment.id.parse().unwrap_or(u64::MAX), &comment.id)
+}
+
+/// HEAD SHA의 최종 요약 코멘트.
+pub fn find_final_comment<'a>(
+    comments: &'a [ReviewComment],
+    sha: &str,
+) -> Option<&'a ReviewComment> {
+    find_marked_comment(comments, |m| m.sha == sha && m.kind == MarkerKind::Final).map(|(c, _)| c)
+}
+
+/// HEAD SHA의 claim 코멘트와 마커.
+/// 여러 실행기가 동시에 claim해 여러 개면 먼저 생성된 코멘트가 유효한 claim이다. 실행기 간 시계 차이에
+/// 영향받지 않도록 생성 순서는 코멘트 id로 판단한다(GitHub/GitLab id는 생성 순으로 증가한다).
+pub fn find_claim_comment<'a>(
+    comments: &'a [ReviewComment],
+    sha: &str,
+) -> Option<(&'a ReviewComment, ReviewMarker)> {
+    comments
+        .iter()
+        .filter_map(|comment| {
+            parse_marker(&comment.body)
+                .filter(|m| m.sha == sha && m.kind == MarkerKind::Claim)
+                .map(|marker| (comment, marker))
+        })
+        .min_by_key(|(comment, _)| creation_order(comment))
+}
+
+/// SHA와 무관하게 가장 최근에 만든 최종 요약/claim 코멘트(`dedupe_policy = "single"`).
+pub fn find_latest_summary_comment(comments: &[ReviewComment]) -> Option<&ReviewComment> {
+    comments
+        .iter()
+        .filter(|comment| {
+            parse_marker(&comment.body)
+                .is_some_and(|m| matches!(m.kind, MarkerKind::Claim | MarkerKind::Final))
+        })
+        .max_by_key(|comment| creation_order(comment))
+}
+
+/// SHA와 무관하게 가장 최근에 만든 에이전트 개별 코멘트(`dedupe_policy = "single"`).
+pub fn find_latest_agent_comment<'a>(
+    comments: &'a [ReviewComment],
+    provider_id: &str,
+) -> Option<&'a ReviewComment> {
+    comments
+        .iter()
+        .filter(|comment| {
+            parse_marker(&comment.body)
+                .is_some_and(|m| matches!(&m.kind, MarkerKind::Agent(id) if id == provider_id))
+        })
+        .max_by_key(|comment| creation_order(comment))
+}
+
+/// 파일 스레드 코멘트. `sha`가 None이면 SHA와 무관하게 같은 파일의 가장 최근 스레드.
+pub fn find_file_thread_comment<'a>(
+    comments: &'a [ReviewComment],
+    path: &str,
+    sha: Option<&str>,
+) -> Option<&'a ReviewComment> {
+    comments
+        .iter()
+        .filter(|comment| {
+            parse_marker(&comment.body).is_some_and(|m| {
+                sha.is_none_or(|sha| sha == m.sha)
+                    && matches!(&m.kind, MarkerKind::File(file) if file == path)
+            })
+        })
+        .max_by_key(|comment| creation_order(comment))
+}
+
+/// 부모 코멘트의 이어지는 조각(번호별). `sha`가 None이면 SHA와 무관하게 번호마다 가장 최근 것.
+pub fn find_part_comments<'a>(
+    comments: &'a [ReviewComment],
+    parent: &str,
+    sha: Option<&str>,
+) -> BTreeMap<usize, &'a ReviewComment> {
+    let mut parts: Vec<(&ReviewComment, usize)> = comments
+        .iter()
+        .filter_map(|comment| {
+            let marker = parse_marker(&comment.body)?;
+            if sha.is_some_and(|sha| sha != marker.sha) {
+                return None;
+            }
+            match marker.kind {
+                MarkerKind::Part { parent: p, index } if p == parent => Some((comment, index)),
+                _ => None,
+            }
+        })
+        .collect();
+    parts.sort_by_key(|(comment, _)| creation_order(comment));
+    parts
+        .into_iter()
+        .map(|(comment, index)| (index, comment))
+        .collect()
+}
+
+/// HEAD SHA의 에이전트 개별 코멘트.
+pub fn find_agent_comment<'a>(
+    comments: &'a [ReviewComment],
+    provider_id: &str,
+    sha: &str,
+) -> Option<&'a ReviewComment> {
+    find_marked_comment(comments, |m| {
+        m.sha == sha && matches!(&m.kind, MarkerKind::Agent(id) if id == provider_id)
+    })
+    .map(|(c, _)| c)
+}
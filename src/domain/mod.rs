@@ -1,6 +1,15 @@
 //! Domain layer
 //! 비즈니스 규칙(엔티티/값 객체/도메인 정책)을 외부 의존성 없이 표현한다.
 
+pub mod bot_command;
+pub mod codeowners;
+pub mod comment_size;
+pub mod diff;
+pub mod markdown;
+pub mod marker;
+pub mod patch;
 pub mod policy;
+pub mod release;
 pub mod review;
 pub mod target;
+pub mod template;
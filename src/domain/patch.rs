@@ -0,0 +1,361 @@
+//! provider가 만든 unified diff 수정안(`--autofix`)의 추출/파싱/적용 정책.
+//!
+//! 패치는 HEAD 커밋의 파일 내용에 메모리 안에서 적용해 검증한다. 문맥/삭제 줄은 정확히 일치해야 하고,
+//! hunk 헤더의 줄 번호가 어긋나면 가장 가까운 일치 위치를 쓴다(`git apply`의 offset 허용과 같다).
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::domain::diff::parse_hunk_header;
+
+/// 패치 안의 파일 하나에 대한 변경.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePatch {
+    /// 변경 전 경로(새 파일이면 None)
+    pub old_path: Option<String>,
+    /// 변경 후 경로(삭제 파일이면 None)
+    pub new_path: Option<String>,
+    hunks: Vec<Hunk>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Hunk {
+    /// 헤더의 변경 전 시작 줄(헤더에 줄 번호가 없으면 0)
+    old_start: usize,
+    lines: Vec<HunkLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum HunkLine {
+    Context(String),
+    Removed(String),
+    Added(String),
+}
+
+/// 패치를 적용한 뒤의 파일.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatchedFile {
+    pub path: String,
+    /// 변경 후 내용(삭제된 파일이면 None)
+    pub content: Option<String>,
+    /// HEAD 커밋에 없던 새 파일이면 true
+    pub created: bool,
+}
+
+/// HEAD 커밋에 적용한 결과.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedPatch {
+    pub files: Vec<PatchedFile>,
+    /// 실제로 일치한 위치로 hunk 헤더를 다시 쓴 unified diff(`git apply`로 그대로 적용할 수 있다)
+    pub patch: String,
+}
+
+/// `--autofix` 결과(HEAD 커밋에 적용되는 것을 확인한 패치).
+#[derive(Debug, Clone)]
+pub struct AutofixResult {
+    pub provider_name: String,
+    /// 고치도록 요청한 합의 지적사항 id
+    pub finding_ids: Vec<String>,
+    /// unified diff 원문
+    pub patch: String,
+    /// 패치가 바꾸는 파일 경로
+    pub files: Vec<String>,
+    /// `--autofix-push`로 올린 수정 브랜치 URL
+    pub branch_url: Option<String>,
+}
+
+impl FilePatch {
+    /// 대표 경로(변경 후 경로, 삭제 파일이면 변경 전 경로).
+    pub fn path(&self) -> &str {
+        self.new_path
+            .as_deref()
+            .or(self.old_path.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// 적용 전에 HEAD에서 읽어야 하는 경로(변경 전/후, 중복 없음).
+    pub fn touched_paths(&self) -> Vec<&str> {
+        let mut paths: Vec<&str> = self.old_path.iter().map(String::as_str).collect();
+        if let Some(new) = self.new_path.as_deref()
+            && !paths.contains(&new)
+        {
+            paths.push(new);
+        }
+        paths
+    }
+}
+
+/// provider 응답에서 패치를 꺼낸다. ```` ```diff ````(또는 ```` ```patch ````) 블록을 우선하고,
+/// 블록이 없으면 `--- `/`+++ ` 헤더가 있는 응답 전체를 패치로 본다. 패치가 없으면 None.
+pub fn extract_patch(response: &str) -> Option<String> {
+    let mut lines = response.lines();
+    while let Some(line) = lines.next() {
+        let info = line.trim_start().strip_prefix("```").map(str::trim);
+        if !matches!(info, Some("diff" | "patch")) {
+            continue;
+        }
+        // diff 줄은 항상 ` `/`+`/`-`로 시작하므로 맨 앞의 ``` 줄은 블록 끝이다.
+        let body: Vec<&str> = lines
+            .by_ref()
+            .take_while(|l| !l.starts_with("```"))
+            .collect();
+        let patch = body.join("\n");
+        if has_file_header(&patch) {
+            return Some(format!("{}\n", patch.trim_end()));
+        }
+    }
+
+    let trimmed = response.trim();
+    has_file_header(trimmed).then(|| format!("{trimmed}\n"))
+}
+
+fn has_file_header(text: &str) -> bool {
+    let lines: Vec<&str> = text.lines().collect();
+    (0..lines.len()).any(|idx| is_file_header(&lines, idx))
+}
+
+/// `idx`가 `--- `/`+++ ` 헤더 쌍의 시작인지 여부.
+fn is_file_header(lines: &[&str], idx: usize) -> bool {
+    lines[idx].starts_with("--- ") && lines.get(idx + 1).is_some_and(|l| l.starts_with("+++ "))
+}
+
+/// unified diff를 파일별 변경으로 나눈다. `diff --git`/`index`/mode 줄은 무시하고,
+/// hunk 줄 수가 헤더와 달라도 다음 hunk/파일 헤더 전까지를 hunk로 읽는다.
+pub fn parse_patch(text: &str) -> Result<Vec<FilePatch>> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut patches: Vec<FilePatch> = Vec::new();
+    let mut idx = 0;
+    while idx < lines.len() {
+        let line = lines[idx];
+        if is_file_header(&lines, idx) {
+            patches.push(FilePatch {
+                old_path: patch_path(&line[4..], "a/"),
+                new_path: patch_path(&lines[idx + 1][4..], "b/"),
+                hunks: Vec::new(),
+            });
+            idx += 2;
+            continue;
+        }
+        if !line.starts_with("@@") {
+            idx += 1;
+            continue;
+        }
+
+        let patch = patches
+            .last_mut()
+            .with_context(|| format!("hunk without a file header: {line}"))?;
+        let mut hunk = Hunk {
+            old_start: parse_hunk_header(line).map_or(0, |(old, _)| old as usize),
+            lines: Vec::new(),
+        };
+        // 응답 끝의 빈 줄은 문맥 줄이 아니므로 hunk 끝에서 버린다.
+        let mut trailing_blank = 0;
+        idx += 1;
+        while idx < lines.len() {
+            let line = lines[idx];
+            if line.starts_with("@@")
+                || line.starts_with("diff --git ")
+                || is_file_header(&lines, idx)
+            {
+                break;
+            }
+            let parsed = match line.as_bytes().first() {
+                Some(b'+') => HunkLine::Added(line[1..].to_string()),
+                Some(b'-') => HunkLine::Removed(line[1..].to_string()),
+                Some(b' ') => HunkLine::Context(line[1..].to_string()),
+                // 앞 공백이 빠진 빈 문맥 줄
+                None => HunkLine::Context(String::new()),
+                Some(b'\\') => {
+                    idx += 1;
+                    continue;
+                }
+                Some(_) => break,
+            };
+            trailing_blank = if line.is_empty() {
+                trailing_blank + 1
+            } else {
+                0
+            };
+            hunk.lines.push(parsed);
+            idx += 1;
+        }
+        hunk.lines.truncate(hunk.lines.len() - trailing_blank);
+        if hunk.lines.is_empty() {
+            bail!("empty hunk in patch for {}", patch.path());
+        }
+        patch.hunks.push(hunk);
+    }
+
+    if patches.is_empty() {
+        bail!("no file changes found in patch");
+    }
+    if let Some(empty) = patches.iter().find(|p| p.hunks.is_empty()) {
+        bail!("patch for {} has no hunks", empty.path());
+    }
+    Ok(patches)
+}
+
+/// 헤더 경로에서 `a/`/`b/` 접두사와 타임스탬프를 뗀다. `/dev/null`이면 None.
+fn patch_path(raw: &str, prefix: &str) -> Option<String> {
+    let path = raw.split('\t').next().unwrap_or(raw).trim();
+    if path == "/dev/null" {
+        return None;
+    }
+    Some(path.strip_prefix(prefix).unwrap_or(path).to_string())
+}
+
+/// HEAD 파일 내용(`originals`, 없는 파일은 키가 없다)에 패치를 적용한다.
+/// 한 hunk라도 맞지 않거나 같은 파일을 두 번 바꾸면 실패한다. 이름이 바뀐 파일은 변경 전 경로의 삭제를 함께 돌려준다.
+pub fn apply_patch(
+    patches: &[FilePatch],
+    originals: &BTreeMap<String, String>,
+) -> Result<AppliedPatch> {
+    let mut seen = BTreeSet::new();
+    let mut files = Vec::new();
+    let mut text = String::new();
+    for patch in patches {
+        for path in patch.touched_paths() {
+            if !seen.insert(path) {
+                bail!("patch changes {path} more than once");
+            }
+        }
+        let original = match patch.old_path.as_deref() {
+            Some(old) => originals
+                .get(old)
+                .with_context(|| format!("{old} does not exist at the head commit"))?
+                .as_str(),
+            None => {
+                if let Some(new) = patch.new_path.as_deref()
+                    && originals.contains_key(new)
+                {
+                    bail!("{new} already exists at the head commit");
+                }
+                ""
+            }
+        };
+        let (content, hunks) = apply_hunks(patch, original)?;
+        let old = patch.old_path.as_deref();
+        let new = patch.new_path.as_deref();
+        text.push_str(&format!(
+            "diff --git a/{} b/{}\n",
+            old.or(new).unwrap_or_default(),
+            new.or(old).unwrap_or_default()
+        ));
+        match (old, new) {
+            (None, _) => text.push_str("new file mode 100644\n"),
+            (_, None) => text.push_str("deleted file mode 100644\n"),
+            _ => {}
+        }
+        text.push_str(&format!(
+            "--- {}\n+++ {}\n{hunks}",
+            old.map_or("/dev/null".to_string(), |p| format!("a/{p}")),
+            new.map_or("/dev/null".to_string(), |p| format!("b/{p}"))
+        ));
+
+        match (patch.old_path.as_deref(), patch.new_path.as_deref()) {
+            (old, Some(new)) => {
+                files.push(PatchedFile {
+                    path: new.to_string(),
+                    content: Some(content),
+                    created: patch.old_path.as_deref() != Some(new),
+                });
+                if let Some(old) = old
+                    && old != new
+                {
+                    files.push(PatchedFile {
+                        path: old.to_string(),
+                        content: None,
+                        created: false,
+                    });
+                }
+            }
+            (Some(old), None) => files.push(PatchedFile {
+                path: old.to_string(),
+                content: None,
+                created: false,
+            }),
+            (None, None) => bail!("patch has neither a source nor a target path"),
+        }
+    }
+    Ok(AppliedPatch { files, patch: text })
+}
+
+/// 파일 하나의 hunk를 순서대로 적용하고 (변경 후 내용, 위치를 바로잡은 hunk 본문)을 돌려준다.
+/// 줄바꿈 형식(CRLF)과 마지막 줄바꿈 유무는 원본을 따른다.
+fn apply_hunks(patch: &FilePatch, original: &str) -> Result<(String, String)> {
+    let source: Vec<&str> = original.lines().collect();
+    let mut out: Vec<&str> = Vec::new();
+    let mut hunks = String::new();
+    let mut cursor = 0;
+    for (n, hunk) in patch.hunks.iter().enumerate() {
+        let old: Vec<&str> = hunk
+            .lines
+            .iter()
+            .filter_map(|line| match line {
+                HunkLine::Context(text) | HunkLine::Removed(text) => Some(text.as_str()),
+                HunkLine::Added(_) => None,
+            })
+            .collect();
+        let pos = find_block(&source, &old, cursor, hunk.old_start).ok_or_else(|| {
+            anyhow!(
+                "hunk {} of {} does not apply at the head commit (expected near line {})",
+                n + 1,
+                patch.path(),
+                hunk.old_start
+            )
+        })?;
+        out.extend_from_slice(&source[cursor..pos]);
+        let new_pos = out.len();
+        out.extend(hunk.lines.iter().filter_map(|line| match line {
+            HunkLine::Context(text) | HunkLine::Added(text) => Some(text.as_str()),
+            HunkLine::Removed(_) => None,
+        }));
+        cursor = pos + old.len();
+
+        // 빈 구간의 시작 줄은 그 앞 줄 번호로 쓴다(unified diff 규칙).
+        let range = |start: usize, len: usize| {
+            format!("{},{len}", if len == 0 { start } else { start + 1 })
+        };
+        hunks.push_str(&format!(
+            "@@ -{} +{} @@\n",
+            range(pos, old.len()),
+            range(new_pos, out.len() - new_pos)
+        ));
+        for line in &hunk.lines {
+            let (prefix, text) = match line {
+                HunkLine::Context(text) => (' ', text),
+                HunkLine::Removed(text) => ('-', text),
+                HunkLine::Added(text) => ('+', text),
+            };
+            hunks.push_str(&format!("{prefix}{text}\n"));
+        }
+    }
+    out.extend_from_slice(&source[cursor..]);
+
+    let eol = if original.contains("\r\n") {
+        "\r\n"
+    } else {
+        "\n"
+    };
+    let mut content = out.join(eol);
+    if !content.is_empty() && (original.is_empty() || original.ends_with('\n')) {
+        content.push_str(eol);
+    }
+    Ok((content, hunks))
+}
+
+/// `from` 이후에서 `block`과 일치하는 위치 중 헤더의 시작 줄에 가장 가까운 곳을 찾는다.
+/// 추가만 하는 hunk(`-N,0`)는 N번째 줄 뒤에 넣는다.
+fn find_block(source: &[&str], block: &[&str], from: usize, old_start: usize) -> Option<usize> {
+    if block.is_empty() {
+        return Some(old_start.clamp(from, source.len()));
+    }
+    if source.len() < block.len() {
+        return None;
+    }
+    let expected = old_start.saturating_sub(1);
+    (from..=source.len() - block.len())
+        .filter(|&start| source[start..start + block.len()] == *block)
+        .min_by_key(|&start| start.abs_diff(expected))
+}
@@ -1,25 +1,25 @@
 //! 도메인 정책(중복 방지 규칙, 프롬프트 구성, 집계 규칙).
 
+use std::collections::BTreeSet;
+
+use crate::domain::markdown::fence_run;
 use crate::domain::review::{
-    CommentLanguage, ProviderRun, ReviewComment, ReviewMarkers, TokenUsage, UsageTotals,
+    AgentReaction, CommentLanguage, ConsensusFinding, ConsolidatedReview, FileReview, FileThread,
+    Finding, FindingProgress, PreviousFinding, PreviousReview, ProviderRun, ReviewComment,
+    ReviewRequest, Severity, SeverityTaxonomy, StageReview, TokenUsage, UsageBreakdown,
+    UsageTotals,
 };
+use crate::domain::template::{PROMPT_CACHE_BREAKPOINT, TemplateVars};
 
-pub fn markers_for_sha(sha: &str) -> ReviewMarkers {
-    ReviewMarkers {
-        final_marker: format!("<!-- repopilot-bot sha={} -->", sha),
-        claim_marker: format!("<!-- repopilot-bot claim sha={} -->", sha),
-    }
-}
+/// 제목 유사도로 같은 지적사항을 판단할 때의 최소 토큰 자카드 계수.
+const TITLE_SIMILARITY_THRESHOLD: f32 = 0.5;
 
-pub fn agent_marker(provider_id: &str, sha: &str) -> String {
-    format!("<!-- repopilot-bot agent={} sha={} -->", provider_id, sha)
-}
-
-pub fn find_comment_with_marker<'a>(
-    comments: &'a [ReviewComment],
-    marker: &str,
-) -> Option<&'a ReviewComment> {
-    comments.iter().find(|c| c.body.contains(marker))
+/// claim이 TTL을 넘겨 회수 가능한지 판단한다. TTL 0이나 시각 정보가 없는 claim은 회수하지 않는다.
+pub fn is_claim_stale(claimed_at: Option<u64>, now: u64, ttl_minutes: u64) -> bool {
+    match claimed_at {
+        Some(at) if ttl_minutes > 0 => now.saturating_sub(at) > ttl_minutes * 60,
+        _ => false,
+    }
 }
 
 pub fn upsert_comment_cache(comments: &mut Vec<ReviewComment>, comment: ReviewComment) {
@@ -42,39 +42,534 @@ pub fn add_usage_total(
     entry.1.add_from(usage);
 }
 
-pub fn build_cross_agent_prompt(
-    target_url: &str,
-    head_sha: &str,
+/// 1차 리뷰, 교차 반응, 통합 리뷰, 보조 리뷰 단계의 사용량을 provider별로 합친다(provider id 순).
+pub fn usage_breakdown(
+    primary_results: &[ProviderRun],
+    reactions: &[AgentReaction],
+    consolidated: Option<&ConsolidatedReview>,
+    stage_reviews: &[StageReview],
+) -> Vec<UsageBreakdown> {
+    let mut primary = UsageTotals::new();
+    let mut reaction = UsageTotals::new();
+    let mut total = UsageTotals::new();
+    for run in primary_results {
+        add_usage_total(&mut primary, &run.id, &run.name, &run.usage);
+        add_usage_total(&mut total, &run.id, &run.name, &run.usage);
+    }
+    for r in reactions {
+        add_usage_total(&mut reaction, &r.provider_id, &r.provider_name, &r.usage);
+        add_usage_total(&mut total, &r.provider_id, &r.provider_name, &r.usage);
+    }
+    if let Some(c) = consolidated {
+        add_usage_total(&mut total, &c.provider_id, &c.provider_name, &c.usage);
+    }
+    for s in stage_reviews {
+        add_usage_total(&mut total, &s.provider_id, &s.provider_name, &s.usage);
+    }
+
+    total
+        .into_iter()
+        .map(|(id, (name, usage))| UsageBreakdown {
+            primary: primary.remove(&id).map(|(_, u)| u).unwrap_or_default(),
+            reaction: reaction.remove(&id).map(|(_, u)| u).unwrap_or_default(),
+            provider_id: id,
+            provider_name: name,
+            total: usage,
+        })
+        .collect()
+}
+
+/// 교차 에이전트 반응 프롬프트(`request.prompt_templates.cross_agent`)의 변수를 구성한다.
+/// 2라운드 이상에서는 직전 라운드의 다른 에이전트 반응을 함께 제시한다.
+pub fn cross_agent_prompt_vars<'a>(
+    request: &'a ReviewRequest,
     self_id: &str,
-    self_name: &str,
-    comment_language: CommentLanguage,
+    self_name: &'a str,
     primary_results: &[ProviderRun],
-) -> String {
-    let mut out = String::new();
-    out.push_str("You are participating in a multi-agent code review.\n");
-    out.push_str("Analyze other agents' findings and provide your perspective.\n");
-    out.push_str("Output language requirement:\n");
-    out.push_str(comment_language.prompt_instruction());
-    out.push_str("\n\n");
-    out.push_str(&format!("Target URL: {}\n", target_url));
-    out.push_str(&format!("Head SHA: {}\n\n", head_sha));
-    out.push_str("Other agents' findings:\n\n");
+    previous_reactions: &[AgentReaction],
+) -> TemplateVars<'a> {
+    let mut other_findings = String::new();
+    for result in primary_results.iter().filter(|r| r.id != self_id) {
+        push_agent_section(&mut other_findings, &result.name, &result.body);
+    }
 
+    let others_previous: Vec<&AgentReaction> = previous_reactions
+        .iter()
+        .filter(|r| r.provider_id != self_id)
+        .collect();
+    let mut previous = String::new();
+    for reaction in &others_previous {
+        push_agent_section(&mut previous, &reaction.provider_name, &reaction.body);
+    }
+
+    let mut vars = request_template_vars(request);
+    vars.insert("agent_name", self_name.into());
+    vars.insert("other_findings", other_findings.into());
+    vars.insert("previous_reactions", previous.into());
+    vars.insert(
+        "previous_round",
+        others_previous
+            .first()
+            .map(|r| r.round.to_string())
+            .unwrap_or_default()
+            .into(),
+    );
+    vars
+}
+
+/// moderator provider가 1차 리뷰와 반응을 하나의 통합 리뷰로 정리하도록
+/// `request.prompt_templates.moderator` 프롬프트의 변수를 구성한다.
+pub fn moderator_prompt_vars<'a>(
+    request: &'a ReviewRequest,
+    primary_results: &[ProviderRun],
+    reactions: &[AgentReaction],
+) -> TemplateVars<'a> {
+    let mut primary_reviews = String::new();
     for result in primary_results {
-        if result.id == self_id {
-            continue;
+        push_agent_section(&mut primary_reviews, &result.name, &result.body);
+    }
+    let mut reaction_text = String::new();
+    for reaction in reactions {
+        push_agent_section(
+            &mut reaction_text,
+            &format!("{} (round {})", reaction.provider_name, reaction.round),
+            &reaction.body,
+        );
+    }
+
+    let mut vars = request_template_vars(request);
+    vars.insert("primary_reviews", primary_reviews.into());
+    vars.insert("reactions", reaction_text.into());
+    vars
+}
+
+/// PR 제목/설명/커밋 메시지의 품질을 점검하도록 `request.prompt_templates.meta_review`
+/// 프롬프트의 변수를 구성한다. 비어 있는 설명/커밋 목록은 그렇다는 표시로 바꿔 넣는다.
+pub fn meta_review_prompt_vars<'a>(
+    request: &'a ReviewRequest,
+    description: &'a str,
+    commit_messages: &[String],
+    description_template: Option<&'a str>,
+) -> TemplateVars<'a> {
+    let description = description.trim();
+    let mut commits = String::new();
+    for message in commit_messages {
+        let mut lines = message.trim().lines();
+        commits.push_str(&format!("- {}\n", lines.next().unwrap_or_default()));
+        // 본문은 들여써서 같은 항목 아래에 둔다.
+        for line in lines {
+            if line.trim().is_empty() {
+                commits.push('\n');
+            } else {
+                commits.push_str(&format!("  {line}\n"));
+            }
+        }
+    }
+
+    let mut vars = request_template_vars(request);
+    vars.insert(
+        "description",
+        if description.is_empty() {
+            "(empty)".into()
+        } else {
+            description.into()
+        },
+    );
+    vars.insert(
+        "commits",
+        if commits.is_empty() {
+            "(not available)".into()
+        } else {
+            commits.trim_end().to_string().into()
+        },
+    );
+    vars.insert(
+        "description_template",
+        description_template.unwrap_or_default().trim().into(),
+    );
+    vars
+}
+
+/// 테스트 누락 분석 프롬프트(`request.prompt_templates.test_gaps`)의 변수를 구성한다.
+/// 파일 목록은 분할 리뷰여도 PR 전체 기준이고, diff는 이 요청(청크)의 것이다.
+pub fn test_gap_prompt_vars<'a>(
+    request: &'a ReviewRequest,
+    source_files: &[String],
+    test_files: &[String],
+) -> TemplateVars<'a> {
+    let list = |files: &[String]| {
+        if files.is_empty() {
+            "(none)".to_string()
+        } else {
+            files
+                .iter()
+                .map(|f| format!("- `{f}`"))
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+    };
+
+    let mut vars = request_template_vars(request);
+    vars.insert("source_files", list(source_files).into());
+    vars.insert("test_files", list(test_files).into());
+    vars.insert("files", request.chunk_files.join(", ").into());
+    vars.insert("chunk_index", request.chunk_index.to_string().into());
+    vars.insert("chunk_total", request.chunk_total.to_string().into());
+    vars.insert("diff", (*request.diff).into());
+    vars
+}
+
+/// 수정 패치 생성 프롬프트(`PromptTemplates.autofix`)의 변수를 구성한다.
+/// 리뷰 요청 없이 게시 단계에서 호출하므로 공통 변수를 직접 받는다.
+/// `files`는 (경로, HEAD 커밋 기준 내용) 목록이다.
+pub fn autofix_prompt_vars<'a>(
+    target_url: &'a str,
+    head_sha: &'a str,
+    title: &'a str,
+    language: &'a CommentLanguage,
+    findings: &[ConsensusFinding],
+    files: &[(String, String)],
+) -> TemplateVars<'a> {
+    let mut finding_text = String::new();
+    for item in findings {
+        let finding = &item.finding;
+        finding_text.push_str(&format!(
+            "- `{}` [{}] {}: {}\n",
+            finding_id(finding),
+            finding.severity.code(),
+            finding.location().unwrap_or_default(),
+            finding.title
+        ));
+        for line in finding.body.trim().lines() {
+            finding_text.push_str(&format!("  {line}\n"));
         }
-        out.push_str(&format!("## {}\n", result.name));
-        out.push_str(result.body.trim());
-        out.push_str("\n\n");
+        if let Some(suggestion) = &finding.suggestion {
+            finding_text.push_str("  Suggested replacement:\n");
+            for line in suggestion.lines() {
+                finding_text.push_str(&format!("  > {line}\n"));
+            }
+        }
+    }
+
+    let mut file_text = String::new();
+    for (path, content) in files {
+        // 파일 안의 코드 펜스보다 긴 펜스로 감싼다.
+        let longest = content
+            .lines()
+            .map(|line| fence_run(line).len())
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest.max(2) + 1);
+        file_text.push_str(&format!(
+            "### `{path}`\n{fence}\n{}\n{fence}\n\n",
+            content.trim_end_matches('\n')
+        ));
     }
 
-    out.push_str(&format!(
-        "Now write {}'s reaction to other agents.\n",
-        self_name
-    ));
-    out.push_str(
-        "Use Markdown sections in this order: Agreements, Disagreements, Missed Risks, Suggested Resolution.\n",
+    let mut vars = target_template_vars(target_url, head_sha, title, language);
+    vars.insert("findings", finding_text.trim_end().to_string().into());
+    vars.insert("files", file_text.trim_end().to_string().into());
+    vars
+}
+
+/// 모든 단계 템플릿에서 공통으로 쓰는 대상/언어 변수.
+pub fn request_template_vars(request: &ReviewRequest) -> TemplateVars<'_> {
+    let mut vars = target_template_vars(
+        &request.target_url,
+        &request.head_sha,
+        &request.title,
+        &request.comment_language,
+    );
+    vars.insert(
+        "severity_sections",
+        request.severity_taxonomy.section_names().into(),
     );
-    out
+    vars
+}
+
+/// 대상/언어 변수와 캐시 경계 표시.
+fn target_template_vars<'a>(
+    target_url: &'a str,
+    head_sha: &'a str,
+    title: &'a str,
+    language: &'a CommentLanguage,
+) -> TemplateVars<'a> {
+    let mut vars = TemplateVars::new();
+    vars.insert("target_url", target_url.into());
+    vars.insert("head_sha", head_sha.into());
+    vars.insert("pr_title", title.into());
+    vars.insert("language", language.code().into());
+    vars.insert("language_instruction", language.prompt_instruction().into());
+    vars.insert("cache_breakpoint", PROMPT_CACHE_BREAKPOINT.into());
+    vars
+}
+
+fn push_agent_section(out: &mut String, heading: &str, body: &str) {
+    out.push_str(&format!("## {heading}\n"));
+    out.push_str(body.trim());
+    out.push_str("\n\n");
+}
+
+/// 에이전트별 지적사항을 유사도로 묶고, 합의 수가 많은 순서로 정렬한다.
+/// 같은 에이전트의 지적은 한 묶음에서 한 번만 집계한다.
+pub fn cluster_findings(primary_results: &[ProviderRun]) -> Vec<ConsensusFinding> {
+    let mut clusters: Vec<ConsensusFinding> = Vec::new();
+
+    for run in primary_results {
+        for finding in &run.findings {
+            let existing = clusters
+                .iter_mut()
+                .find(|c| is_same_finding(&c.finding, finding));
+            match existing {
+                Some(cluster) => {
+                    if !cluster.agents.contains(&run.name) {
+                        cluster.agents.push(run.name.clone());
+                    }
+                    merge_into_representative(&mut cluster.finding, finding);
+                }
+                None => clusters.push(ConsensusFinding {
+                    finding: finding.clone(),
+                    agents: vec![run.name.clone()],
+                }),
+            }
+        }
+    }
+
+    clusters.sort_by(|a, b| {
+        b.agreement()
+            .cmp(&a.agreement())
+            .then(a.finding.severity.cmp(&b.finding.severity))
+            .then(a.finding.file.cmp(&b.finding.file))
+            .then(
+                a.finding
+                    .line_range
+                    .map(|r| r.start)
+                    .cmp(&b.finding.line_range.map(|r| r.start)),
+            )
+    });
+    clusters
+}
+
+/// 실행 기록에 남길 합의 지적사항 요약.
+pub fn snapshot_findings(consensus: &[ConsensusFinding]) -> Vec<PreviousFinding> {
+    consensus
+        .iter()
+        .map(|item| PreviousFinding {
+            id: finding_id(&item.finding),
+            severity: item.finding.severity,
+            file: item.finding.file.clone(),
+            title: item.finding.title.clone(),
+        })
+        .collect()
+}
+
+/// 이전 SHA 리뷰의 합의 지적사항과 이번 합의 지적사항을 짝지어 해결/미해결/신규로 나눈다.
+/// 코드가 바뀌면 줄 위치가 달라지므로 id가 같거나, 같은 파일에서 제목이 충분히 비슷하면 같은 지적사항으로 본다.
+pub fn compare_with_previous(
+    previous: &PreviousReview,
+    current: &[ConsensusFinding],
+) -> FindingProgress {
+    let mut unmatched: Vec<&PreviousFinding> = previous.findings.iter().collect();
+    let mut still_open = Vec::new();
+    let mut new = Vec::new();
+    for item in current {
+        let finding = &item.finding;
+        let id = finding_id(finding);
+        let matched = unmatched.iter().position(|p| p.id == id).or_else(|| {
+            unmatched.iter().position(|p| {
+                p.file.as_deref().map(normalize_path) == finding.file.as_deref().map(normalize_path)
+                    && title_similarity(&p.title, &finding.title) >= TITLE_SIMILARITY_THRESHOLD
+            })
+        });
+        match matched {
+            Some(index) => {
+                unmatched.remove(index);
+                still_open.push(finding.clone());
+            }
+            None => new.push(finding.clone()),
+        }
+    }
+    FindingProgress {
+        previous_sha: previous.head_sha.clone(),
+        resolved: unmatched.into_iter().cloned().collect(),
+        still_open,
+        new,
+    }
+}
+
+/// 파일/라인 겹침과 제목 유사도로 동일 지적사항 여부를 판단한다.
+fn is_same_finding(a: &Finding, b: &Finding) -> bool {
+    let same_file = match (&a.file, &b.file) {
+        (Some(x), Some(y)) => normalize_path(x) == normalize_path(y),
+        (None, None) => true,
+        _ => false,
+    };
+    if !same_file {
+        return false;
+    }
+
+    match (a.line_range, b.line_range) {
+        (Some(x), Some(y)) => x.overlaps(&y),
+        _ => title_similarity(&a.title, &b.title) >= TITLE_SIMILARITY_THRESHOLD,
+    }
+}
+
+fn merge_into_representative(representative: &mut Finding, incoming: &Finding) {
+    if incoming.severity < representative.severity {
+        representative.severity = incoming.severity;
+    }
+    representative.confidence = match (representative.confidence, incoming.confidence) {
+        (Some(a), Some(b)) => Some(a.max(b)),
+        (a, b) => a.or(b),
+    };
+    if let (Some(range), Some(other)) = (representative.line_range.as_mut(), incoming.line_range) {
+        range.start = range.start.min(other.start);
+        range.end = range.end.max(other.end);
+    }
+}
+
+fn normalize_path(path: &str) -> String {
+    path.trim()
+        .trim_start_matches("./")
+        .trim_start_matches("a/")
+        .trim_start_matches("b/")
+        .to_ascii_lowercase()
+}
+
+fn title_similarity(a: &str, b: &str) -> f32 {
+    let left = title_tokens(a);
+    let right = title_tokens(b);
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+    let common = left.intersection(&right).count() as f32;
+    let union = left.union(&right).count() as f32;
+    common / union
+}
+
+fn title_tokens(title: &str) -> BTreeSet<String> {
+    title
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| t.chars().count() > 1)
+        .map(|t| t.to_lowercase())
+        .collect()
+}
+
+/// 임계 심각도 이상인 합의 지적사항 수를 센다(심각도 게이트 판정용).
+pub fn count_findings_at_or_above(findings: &[ConsensusFinding], threshold: Severity) -> usize {
+    findings
+        .iter()
+        .filter(|c| c.finding.severity.is_at_least(threshold))
+        .count()
+}
+
+/// 지적사항의 짧은 식별자(파일 + 제목 토큰 기반이라 라인 이동에는 영향받지 않는다).
+/// 최종 요약에 표시되며 `/repopilot ignore <id>` 명령에 쓰인다.
+pub fn finding_id(finding: &Finding) -> String {
+    let path = finding.file.as_deref().map(normalize_path).unwrap_or_default();
+    let title = title_tokens(&finding.title)
+        .into_iter()
+        .collect::<Vec<_>>()
+        .join(" ");
+    let hash = stable_hash(&format!("{path}|{title}"));
+    format!("{:08x}", (hash >> 32) as u32 ^ hash as u32)
+}
+
+/// 실행/플랫폼이 달라도 값이 바뀌지 않는 FNV-1a 64bit 해시.
+pub fn stable_hash(text: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in text.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash
+}
+
+/// 파일 단위 리뷰 결과를 파일별 스레드로 묶는다(파일 순서는 처음 등장한 순서).
+pub fn group_file_threads(primary_results: &[ProviderRun]) -> Vec<FileThread> {
+    let mut threads: Vec<FileThread> = Vec::new();
+    for run in primary_results {
+        for review in &run.file_reviews {
+            let section = (run.name.clone(), review.body.clone());
+            if let Some(thread) = threads.iter_mut().find(|t| t.path == review.path) {
+                thread.sections.push(section);
+            } else {
+                threads.push(FileThread {
+                    path: review.path.clone(),
+                    sections: vec![section],
+                });
+            }
+        }
+    }
+    threads
+}
+
+/// 구조화 지적사항 목록을 심각도 섹션별 Markdown 리뷰 본문으로 만든다.
+/// triage로 확정된 지적사항만 게시할 때 provider 원문 대신 사용한다.
+pub fn findings_to_markdown(findings: &[Finding], taxonomy: &SeverityTaxonomy) -> String {
+    let mut sorted: Vec<&Finding> = findings.iter().collect();
+    sorted.sort_by_key(|f| f.severity);
+
+    let mut out = String::new();
+    let mut current: Option<Severity> = None;
+    for finding in sorted {
+        if current != Some(finding.severity) {
+            current = Some(finding.severity);
+            out.push_str(&format!("## {}\n\n", taxonomy.label(finding.severity)));
+        }
+        let location = finding
+            .location()
+            .map(|loc| format!(" `{loc}`"))
+            .unwrap_or_default();
+        out.push_str(&format!("- **{}**{}\n", finding.title, location));
+        let body = finding.body.trim();
+        if !body.is_empty() {
+            for line in body.lines() {
+                out.push_str(&format!("  {}\n", line));
+            }
+        }
+    }
+    if out.is_empty() {
+        out.push_str("_No findings accepted._\n");
+    }
+    out.trim_end().to_string()
+}
+
+/// 지적사항을 파일별 리뷰 구간으로 묶는다(파일 정보가 없는 항목은 제외).
+pub fn findings_to_file_reviews(
+    findings: &[Finding],
+    taxonomy: &SeverityTaxonomy,
+) -> Vec<FileReview> {
+    let mut paths: Vec<&str> = Vec::new();
+    for finding in findings {
+        if let Some(file) = finding.file.as_deref()
+            && !paths.contains(&file)
+        {
+            paths.push(file);
+        }
+    }
+    paths
+        .into_iter()
+        .map(|path| {
+            let scoped: Vec<Finding> = findings
+                .iter()
+                .filter(|f| f.file.as_deref() == Some(path))
+                .cloned()
+                .collect();
+            FileReview {
+                path: path.to_string(),
+                body: findings_to_markdown(&scoped, taxonomy),
+            }
+        })
+        .collect()
+}
+
+/// provider 이름/별칭을 provider id로 정규화한다(codex→openai, claude→anthropic).
+pub fn canonical_provider_id(name: &str) -> Option<&'static str> {
+    match name.trim().to_ascii_lowercase().as_str() {
+        "openai" | "codex" => Some("openai"),
+        "anthropic" | "claude" => Some("anthropic"),
+        "gemini" | "google" => Some("gemini"),
+        _ => None,
+    }
 }
@@ -0,0 +1,153 @@
+//! 릴리스 버전 비교와 플랫폼별 배포 파일 선택 규칙.
+
+/// 릴리스에 첨부된 배포 파일.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReleaseAsset {
+    pub name: String,
+    pub url: String,
+}
+
+// 실행 파일로 바로 쓸 수 없는 부가 파일/압축 파일 확장자
+const NON_BINARY_SUFFIXES: [&str; 12] = [
+    ".sha256", ".sig", ".asc", ".txt", ".json", ".md", ".tar.gz", ".tgz", ".zip", ".xz", ".deb",
+    ".rpm",
+];
+
+/// `latest`가 `current`보다 새 버전인지(`v` 접두사/pre-release 접미사는 무시).
+pub fn is_newer_version(current: &str, latest: &str) -> bool {
+    let Some(current_parts) = parse_version_parts(current) else {
+        return false;
+    };
+    let Some(latest_parts) = parse_version_parts(latest) else {
+        return false;
+    };
+
+    let len = current_parts.len().max(latest_parts.len());
+    for idx in 0..len {
+        let left = *current_parts.get(idx).unwrap_or(&0);
+        let right = *latest_parts.get(idx).unwrap_or(&0);
+        if right > left {
+            return true;
+        }
+        if right < left {
+            return false;
+        }
+    }
+
+    false
+}
+
+fn parse_version_parts(raw: &str) -> Option<Vec<u64>> {
+    let s = raw.trim().trim_start_matches('v');
+    let start = s.find(|c: char| c.is_ascii_digit())?;
+    let mut normalized = String::new();
+    for ch in s[start..].chars() {
+        if ch.is_ascii_digit() || ch == '.' {
+            normalized.push(ch);
+        } else {
+            break;
+        }
+    }
+
+    if normalized.is_empty() {
+        return None;
+    }
+
+    let mut out = Vec::new();
+    for part in normalized.split('.') {
+        if part.is_empty() {
+            continue;
+        }
+        let Ok(v) = part.parse::<u64>() else {
+            return None;
+        };
+        out.push(v);
+    }
+
+    if out.is_empty() { None } else { Some(out) }
+}
+
+/// 현재 플랫폼(`std::env::consts::OS`/`ARCH` 값)에 맞는 실행 파일 asset을 고른다.
+/// - 이름에 OS와 아키텍처 토큰(`linux`/`darwin`/`macos`, `x86_64`/`amd64`/`arm64` 등)이 모두 있어야 한다.
+/// - 체크섬/서명/압축 파일은 제외한다.
+pub fn platform_asset<'a>(
+    assets: &'a [ReleaseAsset],
+    os: &str,
+    arch: &str,
+) -> Option<&'a ReleaseAsset> {
+    let os_tokens = os_tokens(os);
+    let arch_tokens = arch_tokens(arch);
+    assets.iter().find(|asset| {
+        let name = asset.name.to_ascii_lowercase();
+        !NON_BINARY_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+            && os_tokens.iter().any(|token| name.contains(token))
+            && arch_tokens.iter().any(|token| name.contains(token))
+    })
+}
+
+/// 실행 파일 asset의 SHA-256 체크섬 asset(`<이름>.sha256` 또는 `SHA256SUMS` 계열).
+pub fn checksum_asset<'a>(assets: &'a [ReleaseAsset], binary: &str) -> Option<&'a ReleaseAsset> {
+    let own = format!("{binary}.sha256").to_ascii_lowercase();
+    assets
+        .iter()
+        .find(|asset| asset.name.to_ascii_lowercase() == own)
+        .or_else(|| {
+            assets.iter().find(|asset| {
+                matches!(
+                    asset.name.to_ascii_lowercase().as_str(),
+                    "sha256sums" | "sha256sums.txt" | "checksums.txt"
+                )
+            })
+        })
+}
+
+/// 실행 파일 asset의 서명 asset(`<이름>.sig`).
+pub fn signature_asset<'a>(assets: &'a [ReleaseAsset], binary: &str) -> Option<&'a ReleaseAsset> {
+    let own = format!("{binary}.sig").to_ascii_lowercase();
+    assets
+        .iter()
+        .find(|asset| asset.name.to_ascii_lowercase() == own)
+}
+
+/// 체크섬 파일에서 `binary`의 SHA-256 hex 값을 찾는다.
+/// `sha256sum` 출력 형식(`<hex>  <이름>`, `*<이름>`)과 hex 한 줄짜리 파일을 모두 받는다.
+pub fn find_checksum(text: &str, binary: &str) -> Option<String> {
+    let mut single = None;
+    for line in text.lines() {
+        let mut parts = line.split_whitespace();
+        let Some(hex) = parts.next().filter(|hex| is_sha256_hex(hex)) else {
+            continue;
+        };
+        match parts.next() {
+            Some(name) if name.trim_start_matches('*') == binary => {
+                return Some(hex.to_ascii_lowercase());
+            }
+            Some(_) => {}
+            None => single = Some(hex.to_ascii_lowercase()),
+        }
+    }
+    single
+}
+
+fn is_sha256_hex(value: &str) -> bool {
+    value.len() == 64 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn os_tokens(os: &str) -> &'static [&'static str] {
+    match os {
+        "linux" => &["linux"],
+        "macos" => &["darwin", "macos", "apple"],
+        "windows" => &["windows", "win64"],
+        _ => &[],
+    }
+}
+
+fn arch_tokens(arch: &str) -> &'static [&'static str] {
+    match arch {
+        "x86_64" => &["x86_64", "amd64", "x64"],
+        "aarch64" => &["aarch64", "arm64"],
+        _ => &[],
+    }
+}
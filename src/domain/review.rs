@@ -1,70 +1,532 @@
 //! 리뷰 도메인 엔티티/값 객체.
 
 use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::domain::diff::DiffStats;
+use crate::domain::template::PromptTemplates;
 
 #[derive(Debug, Clone)]
 pub struct RunOptions {
     pub url: String,
     pub dry_run: bool,
     pub force: bool,
+    /// 이 심각도 이상 지적사항이 있으면 실행을 실패로 처리한다(CI 게이트)
+    pub fail_on: Option<Severity>,
+    /// 이 심각도 이상 합의 지적사항마다 후속 이슈를 만든다(이미 연결된 이슈가 있으면 재사용)
+    pub create_issues: Option<Severity>,
+    /// 합의 지적사항의 수정 패치를 provider에 요청하고 HEAD 커밋에 적용되는지 검증한다
+    pub autofix: bool,
+    /// 검증한 수정 패치를 `repopilot/fixes-<sha>` 브랜치로 올린다(`autofix`일 때만)
+    pub autofix_push: bool,
+    /// 추천한 사람 리뷰어에게 PR/MR 리뷰를 요청한다(`defaults.suggest_reviewers`와 관계없이 추천 단계를 실행)
+    pub request_reviewers: bool,
+    /// 설정의 `diff_exclude`에 더해 제외할 diff 경로 glob
+    pub exclude: Vec<String>,
+    /// 게시 전에 지적사항을 하나씩 검토(accept/drop/edit)한다
+    pub interactive_publish: bool,
+    /// 이번 실행에서 사용할 provider id 목록(비어 있으면 활성 provider 전체)
+    pub providers: Vec<String>,
+    /// 이번 실행에서만 적용할 provider별 모델 (provider id/별칭, 모델)
+    pub model_overrides: Vec<(String, String)>,
+    /// 이번 실행에서만 적용할 코멘트 언어(`defaults.comment_language` 대체)
+    pub comment_language: Option<CommentLanguage>,
+    /// 적용할 설정 프로필 이름(`profiles.<name>`)
+    pub profile: Option<String>,
 }
 
 #[derive(Debug, Clone)]
 pub struct ReviewComment {
     pub id: String,
     pub body: String,
+    /// 작성자(repopilot이 방금 쓴 코멘트처럼 알 수 없으면 None)
+    pub author: Option<CommentAuthor>,
+}
+
+/// 코멘트 작성자와 저장소 권한.
+#[derive(Debug, Clone)]
+pub struct CommentAuthor {
+    /// GitHub login / GitLab username
+    pub username: String,
+    /// 저장소 관리 권한(GitHub `OWNER`/`MEMBER`/`COLLABORATOR`, GitLab Developer 이상)이 있는지
+    pub maintainer: bool,
+}
+
+/// VCS에 만든 이슈(GitHub issue/GitLab issue).
+#[derive(Debug, Clone)]
+pub struct IssueRef {
+    /// 저장소 안 이슈 번호(GitHub `number`, GitLab `iid`)
+    pub number: String,
+    pub url: String,
+}
+
+/// 합의 지적사항과 연결된 후속 이슈.
+#[derive(Debug, Clone)]
+pub struct FollowUpIssue {
+    pub finding_id: String,
+    pub title: String,
+    pub url: String,
+    /// 이번 실행에서 새로 만들었으면 true, 이전 요약에 연결된 이슈를 재사용했으면 false
+    pub created: bool,
+}
+
+/// 최종 요약에 추천하는 사람 리뷰어.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SuggestedReviewer {
+    /// CODEOWNERS 표기 그대로의 소유자(`@user`, `@org/team`, 이메일) 또는 최근 작성자 `@user`
+    pub handle: String,
+    /// CODEOWNERS상 이 리뷰어가 소유한 변경 파일
+    pub owned_paths: Vec<String>,
+    /// 기본 브랜치에서 최근에 이 리뷰어가 커밋한 변경 파일
+    pub recent_paths: Vec<String>,
+    /// `--request-reviewers`로 리뷰를 요청했는지 여부
+    pub requested: bool,
+}
+
+impl SuggestedReviewer {
+    /// 리뷰 요청 API에 넘길 사용자/팀 이름(`@`로 시작하지 않는 이메일 소유자는 None).
+    pub fn username(&self) -> Option<&str> {
+        self.handle
+            .strip_prefix('@')
+            .filter(|name| !name.is_empty())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ReviewRequest {
     pub target_url: String,
     pub head_sha: String,
-    pub diff: String,
+    /// PR/MR 제목(조회 실패 시 빈 문자열)
+    pub title: String,
+    /// 리뷰할 diff. 요청을 복제해도 본문은 provider 사이에서 공유한다.
+    pub diff: Arc<str>,
     pub system_prompt: String,
+    /// 로컬/대상 저장소 리뷰 가이드를 합친 본문(없으면 빈 문자열)
+    pub review_guide: String,
+    /// 단계별 프롬프트 템플릿
+    pub prompt_templates: PromptTemplates,
+    /// 심각도 체계(출력 계약/지적사항 파싱 기준)
+    pub severity_taxonomy: SeverityTaxonomy,
     pub comment_language: CommentLanguage,
+    /// 리뷰 단위(PR 전체 또는 파일별)
+    pub granularity: ReviewGranularity,
+    /// 분할 리뷰 시 청크 번호(1부터 시작)와 전체 청크 수. 분할하지 않으면 1/1.
+    pub chunk_index: usize,
+    pub chunk_total: usize,
+    /// 이 요청 diff에 포함된 파일 경로
+    pub chunk_files: Vec<String>,
 }
 
-/// 리뷰 결과 출력 언어 정책.
+/// 리뷰 단위 정책.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum CommentLanguage {
-    Korean,
-    English,
+pub enum ReviewGranularity {
+    /// PR/MR 전체 diff를 한 번에 리뷰한다(크면 청크 분할).
+    Pr,
+    /// 변경 파일마다 별도 provider 호출과 파일 스레드를 사용한다.
+    File,
+}
+
+impl ReviewGranularity {
+    /// 설정 문자열을 리뷰 단위로 변환한다. 지원값: pr, file (기본 pr)
+    pub fn from_config(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("file") => Self::File,
+            _ => Self::Pr,
+        }
+    }
+
+    /// inspection 출력용 코드값.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Pr => "pr",
+            Self::File => "file",
+        }
+    }
+}
+
+/// 같은 PR/MR을 다시 리뷰할 때 기존 봇 코멘트를 다루는 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DedupePolicy {
+    /// HEAD SHA마다 요약/에이전트 코멘트를 따로 둔다. 이미 리뷰한 SHA는 건너뛴다.
+    PerSha,
+    /// PR/MR마다 요약/에이전트 코멘트 하나를 새 SHA를 리뷰할 때마다 갱신한다. 이미 리뷰한 SHA는 건너뛴다.
+    Single,
+    /// 실행마다 새 코멘트를 만든다. 이미 리뷰한 SHA도 다시 리뷰한다.
+    AlwaysNew,
+}
+
+impl DedupePolicy {
+    /// 설정 문자열을 정책으로 변환한다. 지원값: per_sha, single, always_new (기본 per_sha)
+    pub fn from_config(value: Option<&str>) -> Self {
+        match value
+            .map(|v| v.trim().to_ascii_lowercase().replace('-', "_"))
+            .as_deref()
+        {
+            Some("single") => Self::Single,
+            Some("always_new") => Self::AlwaysNew,
+            _ => Self::PerSha,
+        }
+    }
+
+    /// inspection 출력용 코드값.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::PerSha => "per_sha",
+            Self::Single => "single",
+            Self::AlwaysNew => "always_new",
+        }
+    }
+
+    /// 같은 HEAD SHA의 claim/최종 코멘트가 있으면 리뷰를 건너뛰는지 여부.
+    pub fn skips_reviewed_sha(self) -> bool {
+        !matches!(self, Self::AlwaysNew)
+    }
+}
+
+/// 파일 단위 리뷰 결과 구간.
+#[derive(Debug, Clone)]
+pub struct FileReview {
+    pub path: String,
+    pub body: String,
+}
+
+/// 파일 단위 리뷰 스레드(한 파일에 대한 에이전트별 리뷰 묶음).
+#[derive(Debug, Clone)]
+pub struct FileThread {
+    pub path: String,
+    /// (에이전트 이름, 본문)
+    pub sections: Vec<(String, String)>,
 }
 
+/// 리뷰 결과 출력 언어 정책(BCP-47 언어 태그).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentLanguage {
+    code: String,
+}
+
+/// 프롬프트 지시문에 쓰는 언어 태그별 영문 이름(태그 전체 → 주 언어 순으로 찾는다).
+const LANGUAGE_NAMES: &[(&str, &str)] = &[
+    ("ko", "Korean"),
+    ("en", "English"),
+    ("ja", "Japanese"),
+    ("zh-CN", "Simplified Chinese"),
+    ("zh-Hans", "Simplified Chinese"),
+    ("zh-TW", "Traditional Chinese"),
+    ("zh-Hant", "Traditional Chinese"),
+    ("zh", "Chinese"),
+    ("de", "German"),
+    ("fr", "French"),
+    ("es", "Spanish"),
+    ("pt-BR", "Brazilian Portuguese"),
+    ("pt", "Portuguese"),
+    ("it", "Italian"),
+    ("nl", "Dutch"),
+    ("pl", "Polish"),
+    ("ru", "Russian"),
+    ("uk", "Ukrainian"),
+    ("tr", "Turkish"),
+    ("vi", "Vietnamese"),
+    ("id", "Indonesian"),
+    ("th", "Thai"),
+    ("hi", "Hindi"),
+    ("sv", "Swedish"),
+];
+
 impl CommentLanguage {
+    pub fn korean() -> Self {
+        Self {
+            code: "ko".to_string(),
+        }
+    }
+
+    pub fn english() -> Self {
+        Self {
+            code: "en".to_string(),
+        }
+    }
+
     /// 설정 문자열을 언어 정책으로 변환한다.
-    /// 지원값: ko/korean, en/english (미지정/알수없음은 ko 기본값)
+    /// BCP-47 태그(ja, zh-CN, pt_BR 등)와 korean/english 같은 이름을 받으며,
+    /// 미지정/형식 오류는 ko 기본값으로 처리한다.
     pub fn from_config(value: Option<&str>) -> Self {
-        let Some(raw) = value else {
-            return Self::Korean;
+        value.and_then(Self::parse).unwrap_or_else(Self::korean)
+    }
+
+    /// 언어 태그를 정규화한다(주 언어 소문자, 지역 대문자, 스크립트 첫 글자 대문자). 형식이 틀리면 None.
+    pub fn parse(value: &str) -> Option<Self> {
+        let raw = value.trim().replace('_', "-");
+        let alias = match raw.to_ascii_lowercase().as_str() {
+            "korean" | "kr" => Some("ko"),
+            "english" => Some("en"),
+            "japanese" | "jp" => Some("ja"),
+            "chinese" | "cn" => Some("zh-CN"),
+            _ => None,
         };
+        if let Some(code) = alias {
+            return Some(Self {
+                code: code.to_string(),
+            });
+        }
 
-        match raw.trim().to_ascii_lowercase().as_str() {
-            "en" | "english" => Self::English,
-            "ko" | "kr" | "korean" => Self::Korean,
-            _ => Self::Korean,
+        let mut parts = raw.split('-');
+        let primary = parts.next()?;
+        if !(2..=3).contains(&primary.len()) || !primary.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
         }
+        let mut code = primary.to_ascii_lowercase();
+        for part in parts {
+            if !(2..=8).contains(&part.len()) || !part.chars().all(|c| c.is_ascii_alphanumeric()) {
+                return None;
+            }
+            code.push('-');
+            match part.len() {
+                2 => code.push_str(&part.to_ascii_uppercase()),
+                4 => {
+                    let (head, tail) = part.split_at(1);
+                    code.push_str(&head.to_ascii_uppercase());
+                    code.push_str(&tail.to_ascii_lowercase());
+                }
+                _ => code.push_str(&part.to_ascii_lowercase()),
+            }
+        }
+        Some(Self { code })
+    }
+
+    /// 사람이 읽는 영문 언어 이름(알 수 없는 태그는 태그 그대로).
+    pub fn name(&self) -> &str {
+        let primary = self.code.split('-').next().unwrap_or_default();
+        LANGUAGE_NAMES
+            .iter()
+            .find(|(code, _)| *code == self.code)
+            .or_else(|| LANGUAGE_NAMES.iter().find(|(code, _)| *code == primary))
+            .map(|(_, name)| *name)
+            .unwrap_or(&self.code)
     }
 
     /// 프롬프트에 넣을 출력 언어 지시문(영문).
-    pub fn prompt_instruction(self) -> &'static str {
-        match self {
-            Self::Korean => {
+    pub fn prompt_instruction(&self) -> String {
+        match self.code.as_str() {
+            "ko" => {
                 "Write the final answer in Korean only. Do not use English headings or body text."
+                    .to_string()
             }
-            Self::English => {
+            "en" => {
                 "Write the final answer in English only. Do not use Korean headings or body text."
+                    .to_string()
             }
+            _ => format!(
+                "Write the final answer in {} (language tag `{}`) only. Keep code identifiers, file paths and the findings JSON keys unchanged.",
+                self.name(),
+                self.code
+            ),
         }
     }
 
-    /// inspection 출력용 코드값.
+    /// 정규화된 언어 태그.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+}
+
+/// 리뷰 지적사항 심각도(높은 순서대로 선언).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Critical,
+    Major,
+    Minor,
+    Suggestion,
+}
+
+impl Severity {
+    /// 문자열 라벨을 심각도로 변환한다(대소문자 무시, 알 수 없으면 None).
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "critical" | "blocker" => Some(Self::Critical),
+            "major" | "high" => Some(Self::Major),
+            "minor" | "medium" | "low" => Some(Self::Minor),
+            "suggestion" | "suggestions" | "info" | "nit" => Some(Self::Suggestion),
+            _ => None,
+        }
+    }
+
+    /// 출력/직렬화용 코드값.
     pub fn code(self) -> &'static str {
         match self {
-            Self::Korean => "ko",
-            Self::English => "en",
+            Self::Critical => "critical",
+            Self::Major => "major",
+            Self::Minor => "minor",
+            Self::Suggestion => "suggestion",
         }
     }
+
+    /// 사람이 읽는 섹션 제목.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Critical => "Critical",
+            Self::Major => "Major",
+            Self::Minor => "Minor",
+            Self::Suggestion => "Suggestions",
+        }
+    }
+
+    /// `self`가 `threshold`와 같거나 더 심각한지 판단한다.
+    pub fn is_at_least(self, threshold: Severity) -> bool {
+        self <= threshold
+    }
+}
+
+/// 조직별 심각도 명칭 하나(내부 4단계 척도 중 하나에 매핑).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeverityLevel {
+    pub name: String,
+    pub emoji: Option<String>,
+    pub severity: Severity,
+}
+
+/// 프롬프트 계약, 지적사항 파싱, 렌더링에 쓰는 심각도 체계(심각한 순서).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeverityTaxonomy {
+    pub levels: Vec<SeverityLevel>,
+}
+
+impl Default for SeverityTaxonomy {
+    fn default() -> Self {
+        Self {
+            levels: [
+                Severity::Critical,
+                Severity::Major,
+                Severity::Minor,
+                Severity::Suggestion,
+            ]
+            .into_iter()
+            .map(|severity| SeverityLevel {
+                name: severity.label().to_string(),
+                emoji: None,
+                severity,
+            })
+            .collect(),
+        }
+    }
+}
+
+impl SeverityTaxonomy {
+    /// provider가 출력한 심각도 라벨을 해석한다. 사용자 정의 이름을 먼저 보고, 없으면 기본 라벨로 해석한다.
+    pub fn parse(&self, value: &str) -> Option<Severity> {
+        let value = value.trim();
+        self.levels
+            .iter()
+            .find(|level| level.name.eq_ignore_ascii_case(value))
+            .map(|level| level.severity)
+            .or_else(|| Severity::parse(value))
+    }
+
+    /// 렌더링용 라벨(이모지 포함). 매핑된 이름이 없으면 기본 라벨을 쓴다.
+    pub fn label(&self, severity: Severity) -> String {
+        let Some(level) = self.levels.iter().find(|level| level.severity == severity) else {
+            return severity.label().to_string();
+        };
+        match &level.emoji {
+            Some(emoji) => format!("{emoji} {}", level.name),
+            None => level.name.clone(),
+        }
+    }
+
+    /// 심각도별 개수 요약(예: `Critical: 1, Major: 2`). 심각한 순서이며 0개인 심각도는 빠진다.
+    /// 지적사항이 없으면 None.
+    pub fn count_summary(&self, severities: &[Severity]) -> Option<String> {
+        let parts: Vec<String> = [
+            Severity::Critical,
+            Severity::Major,
+            Severity::Minor,
+            Severity::Suggestion,
+        ]
+        .into_iter()
+        .filter_map(|severity| {
+            let count = severities.iter().filter(|s| **s == severity).count();
+            (count > 0).then(|| format!("{}: {count}", self.label(severity)))
+        })
+        .collect();
+        (!parts.is_empty()).then(|| parts.join(", "))
+    }
+
+    /// 프롬프트의 섹션 순서 안내용 이름 목록(예: `Critical, Major, Minor, Suggestions`).
+    pub fn section_names(&self) -> String {
+        self.levels
+            .iter()
+            .map(|level| level.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// 출력 계약의 severity 허용값(예: `critical|major|minor|suggestions`).
+    pub fn contract_values(&self) -> String {
+        self.levels
+            .iter()
+            .map(|level| level.name.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("|")
+    }
+}
+
+/// 변경 파일 내 라인 구간(1부터 시작, 양끝 포함).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LineRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl LineRange {
+    /// 두 구간이 겹치는지 판단한다.
+    pub fn overlaps(&self, other: &LineRange) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+/// provider 출력에서 파싱한 구조화 지적사항.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub file: Option<String>,
+    pub line_range: Option<LineRange>,
+    pub severity: Severity,
+    pub title: String,
+    pub body: String,
+    /// provider가 스스로 보고한 확신도(0.0~1.0)
+    pub confidence: Option<f32>,
+    /// `line_range` 구간(변경 후 파일 기준)을 그대로 바꿀 코드. provider가 구체적인 수정안을 낸 경우에만 있다.
+    pub suggestion: Option<String>,
+}
+
+impl Finding {
+    /// `file:start-end` 형식의 위치 문자열(파일 정보가 없으면 None).
+    pub fn location(&self) -> Option<String> {
+        let file = self.file.as_ref()?;
+        Some(match self.line_range {
+            Some(range) if range.start == range.end => format!("{file}:{}", range.start),
+            Some(range) => format!("{file}:{}-{}", range.start, range.end),
+            None => file.clone(),
+        })
+    }
+
+    /// 보고된 확신도가 `threshold`보다 낮은지 여부. 확신도를 보고하지 않았으면 false.
+    pub fn is_below_confidence(&self, threshold: f32) -> bool {
+        self.confidence.is_some_and(|c| c < threshold)
+    }
+}
+
+/// 여러 에이전트가 보고한 유사 지적사항 묶음(합의 정보 포함).
+#[derive(Debug, Clone)]
+pub struct ConsensusFinding {
+    /// 대표 지적사항(묶음 내 최고 심각도 기준)
+    pub finding: Finding,
+    /// 동의한 에이전트 표시 이름(중복 없음, 보고 순서)
+    pub agents: Vec<String>,
+}
+
+impl ConsensusFinding {
+    /// 동의한 에이전트 수.
+    pub fn agreement(&self) -> usize {
+        self.agents.len()
+    }
 }
 
 #[derive(Debug, Clone, Default)]
@@ -80,12 +542,34 @@ impl TokenUsage {
         self.completion_tokens = sum_optional(self.completion_tokens, other.completion_tokens);
         self.total_tokens = sum_optional(self.total_tokens, other.total_tokens);
     }
+
+    /// 전체 토큰 수. 합계가 없으면 입력/출력 토큰을 더한다.
+    pub fn total(&self) -> Option<u64> {
+        self.total_tokens
+            .or_else(|| sum_optional(self.prompt_tokens, self.completion_tokens))
+    }
+
+    /// 단가로 추정한 비용(USD). 입력/출력 토큰 수를 모르면 None.
+    pub fn estimated_cost(&self, pricing: &TokenPricing) -> Option<f64> {
+        let prompt = self.prompt_tokens? as f64;
+        let completion = self.completion_tokens? as f64;
+        Some((prompt * pricing.input_per_mtok + completion * pricing.output_per_mtok) / 1_000_000.0)
+    }
+}
+
+/// provider 토큰 단가(USD, 100만 토큰당).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenPricing {
+    pub input_per_mtok: f64,
+    pub output_per_mtok: f64,
 }
 
 #[derive(Debug, Clone)]
 pub struct ProviderResponse {
     pub content: String,
     pub usage: TokenUsage,
+    /// 출력 계약(JSON 블록)에서 파싱한 지적사항. 계약을 따르지 않았으면 비어 있다.
+    pub findings: Vec<Finding>,
 }
 
 #[derive(Debug, Clone)]
@@ -94,6 +578,11 @@ pub struct ProviderRun {
     pub name: String,
     pub body: String,
     pub usage: TokenUsage,
+    pub findings: Vec<Finding>,
+    /// 파일 단위 리뷰 모드에서의 파일별 본문(그 외 모드는 비어 있음)
+    pub file_reviews: Vec<FileReview>,
+    /// 1차 리뷰(모든 청크 포함) 소요 시간. 이전 버전 실행 저널에서 읽으면 None.
+    pub elapsed_secs: Option<f32>,
 }
 
 #[derive(Debug, Clone)]
@@ -102,22 +591,222 @@ pub struct AgentComment {
     pub provider_name: String,
     pub body: String,
     pub usage: TokenUsage,
+    pub findings: Vec<Finding>,
+    /// 본문 끝에 붙일 실행 정보(`defaults.comment_footer`가 꺼져 있으면 None)
+    pub footer: Option<RunFooter>,
+}
+
+impl AgentComment {
+    /// 1차 리뷰 결과로 개별 코멘트 데이터를 만든다.
+    pub fn from_run(run: &ProviderRun) -> Self {
+        Self {
+            provider_id: run.id.clone(),
+            provider_name: run.name.clone(),
+            body: run.body.clone(),
+            usage: run.usage.clone(),
+            findings: run.findings.clone(),
+            footer: None,
+        }
+    }
+}
+
+/// 에이전트/최종 요약 코멘트 끝에 붙이는 실행 정보. 어느 버전/모델이 언제 쓴 리뷰인지 추적할 때 쓴다.
+#[derive(Debug, Clone, Default)]
+pub struct RunFooter {
+    /// repopilot 버전
+    pub version: String,
+    /// (provider 이름, 설정된 모델). 모델을 지정하지 않아 provider 기본 모델을 쓰면 None
+    pub models: Vec<(String, Option<String>)>,
+    /// 소요 시간(초). 알 수 없으면 None
+    pub elapsed_secs: Option<f32>,
+    /// 게시 시각(unix 초)
+    pub posted_at: u64,
+}
+
+impl RunFooter {
+    /// 렌더링된 footer 줄의 시작 부분.
+    pub const LINE_PREFIX: &str = "<sub>repopilot v";
+
+    /// 코멘트 본문의 줄이 실행 정보 footer인지 여부.
+    pub fn is_footer_line(line: &str) -> bool {
+        line.trim_start().starts_with(Self::LINE_PREFIX)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct AgentReaction {
+    pub provider_id: String,
     pub provider_name: String,
+    /// 1부터 시작하는 반응 라운드 번호
+    pub round: usize,
     pub body: String,
+    pub usage: TokenUsage,
 }
 
+/// moderator provider가 모든 의견을 통합해 작성한 최종 리뷰.
 #[derive(Debug, Clone)]
-pub struct ReviewMarkers {
-    pub final_marker: String,
-    pub claim_marker: String,
+pub struct ConsolidatedReview {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub body: String,
+    pub usage: TokenUsage,
+}
+
+/// 코드 리뷰와 별도로 provider 하나가 맡는 보조 리뷰 단계.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewStage {
+    /// PR 제목/설명/커밋 메시지 품질 점검
+    Meta,
+    /// 보안 전용 프롬프트로 diff를 다시 점검
+    Security,
+    /// 테스트 변경 없이 바뀐 함수를 찾는 테스트 누락 분석
+    TestGaps,
+}
+
+impl ReviewStage {
+    /// 저널/JSON 출력용 코드값.
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::Meta => "meta",
+            Self::Security => "security",
+            Self::TestGaps => "test_gaps",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "meta" => Some(Self::Meta),
+            "security" => Some(Self::Security),
+            "test_gaps" => Some(Self::TestGaps),
+            _ => None,
+        }
+    }
+}
+
+/// 보조 리뷰 단계 하나의 결과.
+#[derive(Debug, Clone)]
+pub struct StageReview {
+    pub stage: ReviewStage,
+    pub provider_id: String,
+    pub provider_name: String,
+    pub body: String,
+    /// 출력 계약 블록에서 파싱한 구조화 지적사항(계약을 쓰지 않는 단계는 비어 있음)
+    pub findings: Vec<Finding>,
+    pub usage: TokenUsage,
+}
+
+/// 실행 기록에 남기는 합의 지적사항 요약(다음 SHA 리뷰에서 진행 상황 비교용).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreviousFinding {
+    /// [`crate::domain::policy::finding_id`]
+    pub id: String,
+    pub severity: Severity,
+    pub file: Option<String>,
+    pub title: String,
+}
+
+/// 같은 PR/MR의 이전 SHA 리뷰에서 기록한 합의 지적사항.
+#[derive(Debug, Clone)]
+pub struct PreviousReview {
+    pub head_sha: String,
+    pub findings: Vec<PreviousFinding>,
+}
+
+/// 이전 리뷰 대비 지적사항 변화.
+#[derive(Debug, Clone)]
+pub struct FindingProgress {
+    /// 비교한 이전 리뷰의 HEAD SHA
+    pub previous_sha: String,
+    /// 이전 리뷰에 있었지만 이번 리뷰에서 더 보고되지 않은 지적사항
+    pub resolved: Vec<PreviousFinding>,
+    /// 이전 리뷰에도 있던 이번 합의 지적사항
+    pub still_open: Vec<Finding>,
+    /// 이번 리뷰에서 처음 보고된 합의 지적사항
+    pub new: Vec<Finding>,
+}
+
+/// 최종 요약 코멘트를 구성하는 리뷰 결과 묶음.
+#[derive(Debug, Clone, Default)]
+pub struct ReviewSummary {
+    pub consolidated: Option<ConsolidatedReview>,
+    /// 보조 리뷰 단계(PR 메타 리뷰 등) 결과
+    pub stage_reviews: Vec<StageReview>,
+    pub consensus: Vec<ConsensusFinding>,
+    pub reactions: Vec<AgentReaction>,
+    /// (에이전트 이름, 게시된 코멘트 id)
+    pub agent_comment_refs: Vec<(String, String)>,
+    /// 렌더링에 쓰는 심각도 체계
+    pub severity_taxonomy: SeverityTaxonomy,
+    /// 에이전트별 1차 리뷰 지적사항 심각도(보고 순서)
+    pub agent_severities: Vec<AgentSeverities>,
+    /// 긴 섹션을 `<details>`로 접는 기준
+    pub collapse: SummaryCollapse,
+    /// 리뷰 대상 diff 통계
+    pub diff_stats: Option<DiffStats>,
+    /// 확신도가 이 값보다 낮은 합의 지적사항은 "Needs Human Verification" 섹션에 따로 표시(0이면 나누지 않음)
+    pub low_confidence_below: f32,
+    /// `--create-issues`로 연결한 후속 이슈
+    pub follow_up_issues: Vec<FollowUpIssue>,
+    /// CODEOWNERS/최근 커밋 기준 사람 리뷰어 추천(`defaults.suggest_reviewers`/`--request-reviewers`)
+    pub suggested_reviewers: Vec<SuggestedReviewer>,
+    /// 같은 PR/MR의 이전 SHA 리뷰 대비 변화(실행 기록에 이전 리뷰가 없으면 None)
+    pub progress: Option<FindingProgress>,
+    /// 본문 끝에 붙일 실행 정보(`defaults.comment_footer`가 꺼져 있으면 None)
+    pub footer: Option<RunFooter>,
+}
+
+/// 한 에이전트가 1차 리뷰에서 보고한 구조화 지적사항의 심각도 목록.
+#[derive(Debug, Clone)]
+pub struct AgentSeverities {
+    pub provider_id: String,
+    pub provider_name: String,
+    pub severities: Vec<Severity>,
+}
+
+impl AgentSeverities {
+    pub fn from_run(run: &ProviderRun) -> Self {
+        Self {
+            provider_id: run.id.clone(),
+            provider_name: run.name.clone(),
+            severities: run.findings.iter().map(|f| f.severity).collect(),
+        }
+    }
+}
+
+/// 최종 요약에서 긴 섹션을 `<details>`로 접는 기준. 0이면 해당 섹션은 접지 않는다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SummaryCollapse {
+    /// 합의 지적사항이 이 개수보다 많으면 목록을 접는다.
+    pub findings_over: usize,
+    /// 교차 반응 본문이 이 줄 수보다 길면 반응을 접는다.
+    pub reaction_lines: usize,
+}
+
+impl SummaryCollapse {
+    pub fn collapses_findings(&self, count: usize) -> bool {
+        self.findings_over > 0 && count > self.findings_over
+    }
+
+    pub fn collapses_reaction(&self, body: &str) -> bool {
+        self.reaction_lines > 0 && body.lines().count() > self.reaction_lines
+    }
 }
 
 pub type UsageTotals = BTreeMap<String, (String, TokenUsage)>;
 
+/// provider 하나의 단계별 토큰 사용량.
+#[derive(Debug, Clone, Default)]
+pub struct UsageBreakdown {
+    pub provider_id: String,
+    pub provider_name: String,
+    /// 1차 리뷰(청크 포함)
+    pub primary: TokenUsage,
+    /// 교차 반응(모든 라운드)
+    pub reaction: TokenUsage,
+    /// 1차 리뷰 + 교차 반응 + 통합 리뷰(moderator)
+    pub total: TokenUsage,
+}
+
 fn sum_optional(a: Option<u64>, b: Option<u64>) -> Option<u64> {
     match (a, b) {
         (Some(x), Some(y)) => Some(x + y),
@@ -1,8 +1,15 @@
-//! 입력 URL을 GitHub PR / GitLab MR 대상으로 해석하는 모듈.
+//! 입력 URL을 GitHub PR / GitLab MR(또는 오프라인 확인용 mock) 대상으로 해석하는 모듈.
 
 use anyhow::{Result, bail};
 use url::Url;
 
+use crate::domain::review::LineRange;
+
+/// fixture 파일을 PR/MR처럼 다루는 대상 URL 접두사(`mock://<fixture 경로>`).
+pub const MOCK_SCHEME: &str = "mock://";
+/// mock 대상의 호스트 이름.
+pub const MOCK_HOST: &str = "mock";
+
 #[derive(Debug, Clone)]
 pub enum ReviewTarget {
     GitHub {
@@ -18,11 +25,23 @@ pub enum ReviewTarget {
         iid: u64,
         url: String,
     },
+    /// 토큰 없이 fixture 파일로 PR/MR을 흉내 내는 대상.
+    Mock { fixture: String, url: String },
 }
 
 impl ReviewTarget {
     /// URL 패턴을 보고 GitHub/GitLab 대상을 자동 감지한다.
     pub fn parse(input: &str) -> Result<Self> {
+        if let Some(fixture) = input.strip_prefix(MOCK_SCHEME) {
+            if fixture.trim().is_empty() {
+                bail!("mock target needs a fixture path: {MOCK_SCHEME}<fixture.json>");
+            }
+            return Ok(ReviewTarget::Mock {
+                fixture: fixture.to_string(),
+                url: input.to_string(),
+            });
+        }
+
         let url = Url::parse(input)?;
         let host = url
             .host_str()
@@ -49,6 +68,7 @@ impl ReviewTarget {
         match self {
             ReviewTarget::GitHub { host, .. } => host,
             ReviewTarget::GitLab { host, .. } => host,
+            ReviewTarget::Mock { .. } => MOCK_HOST,
         }
     }
 
@@ -56,6 +76,91 @@ impl ReviewTarget {
         match self {
             ReviewTarget::GitHub { url, .. } => url,
             ReviewTarget::GitLab { url, .. } => url,
+            ReviewTarget::Mock { url, .. } => url,
+        }
+    }
+
+    /// 저장소 식별 키(`host/owner/repo`, GitLab은 `host/group/.../project`).
+    pub fn repository_key(&self) -> String {
+        match self {
+            ReviewTarget::GitHub {
+                host, owner, repo, ..
+            } => format!("{host}/{owner}/{repo}"),
+            ReviewTarget::GitLab {
+                host, project_path, ..
+            } => format!("{host}/{project_path}"),
+            ReviewTarget::Mock { fixture, .. } => format!("{MOCK_HOST}/{fixture}"),
+        }
+    }
+
+    /// 커밋 `sha` 기준 파일(과 라인 구간)의 웹 링크. mock 대상은 보여 줄 웹 페이지가 없어 None.
+    /// - GitHub: `/<owner>/<repo>/blob/<sha>/<path>#L<start>-L<end>`
+    /// - GitLab: `/<project>/-/blob/<sha>/<path>#L<start>-<end>`
+    pub fn blob_url(&self, sha: &str, path: &str, lines: Option<LineRange>) -> Option<String> {
+        let (blob_path, anchor) = match self {
+            ReviewTarget::GitHub { owner, repo, .. } => (
+                format!("/{owner}/{repo}/blob/{sha}/{path}"),
+                lines.map(|range| {
+                    if range.start == range.end {
+                        format!("L{}", range.start)
+                    } else {
+                        format!("L{}-L{}", range.start, range.end)
+                    }
+                }),
+            ),
+            ReviewTarget::GitLab { project_path, .. } => (
+                format!("/{project_path}/-/blob/{sha}/{path}"),
+                lines.map(|range| {
+                    if range.start == range.end {
+                        format!("L{}", range.start)
+                    } else {
+                        format!("L{}-{}", range.start, range.end)
+                    }
+                }),
+            ),
+            ReviewTarget::Mock { .. } => return None,
+        };
+        // 포트/스킴은 PR/MR URL을 그대로 따른다.
+        let mut url = Url::parse(self.url()).ok()?;
+        url.set_path(&blob_path);
+        url.set_query(None);
+        url.set_fragment(anchor.as_deref());
+        Some(url.to_string())
+    }
+
+    /// PR/MR 코멘트의 웹 링크. mock 대상은 None.
+    pub fn comment_url(&self, comment_id: &str) -> Option<String> {
+        let (path, anchor) = match self {
+            ReviewTarget::GitHub {
+                owner,
+                repo,
+                number,
+                ..
+            } => (
+                format!("/{owner}/{repo}/pull/{number}"),
+                format!("issuecomment-{comment_id}"),
+            ),
+            ReviewTarget::GitLab {
+                project_path, iid, ..
+            } => (
+                format!("/{project_path}/-/merge_requests/{iid}"),
+                format!("note_{comment_id}"),
+            ),
+            ReviewTarget::Mock { .. } => return None,
+        };
+        let mut url = Url::parse(self.url()).ok()?;
+        url.set_path(&path);
+        url.set_query(None);
+        url.set_fragment(Some(&anchor));
+        Some(url.to_string())
+    }
+
+    /// 호스트가 허용하는 코멘트 본문 최대 글자 수(GitHub 65536자, GitLab 1MB). mock 대상은 제한 없음.
+    pub fn max_comment_chars(&self) -> Option<usize> {
+        match self {
+            ReviewTarget::GitHub { .. } => Some(65_536),
+            ReviewTarget::GitLab { .. } => Some(1_000_000),
+            ReviewTarget::Mock { .. } => None,
         }
     }
 }
@@ -104,3 +209,69 @@ fn parse_gitlab(host: &str, segments: &[String], input: &str) -> Option<ReviewTa
         url: input.to_string(),
     })
 }
+
+/// 열린 PR/MR 목록을 조회할 저장소 식별자.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RepositoryRef {
+    GitHub {
+        host: String,
+        owner: String,
+        repo: String,
+    },
+    GitLab {
+        host: String,
+        project_path: String,
+    },
+}
+
+impl RepositoryRef {
+    /// `git remote` URL(`https://host/owner/repo.git`, `git@host:owner/repo.git`,
+    /// `ssh://git@host/owner/repo.git`)을 저장소 식별자로 해석한다.
+    /// 호스트 이름에 `gitlab`이 있거나 경로가 세 단계 이상(하위 그룹)이면 GitLab으로 본다.
+    pub fn from_remote_url(remote: &str) -> Option<Self> {
+        let remote = remote.trim();
+        let (host, path) = if remote.contains("://") {
+            let url = Url::parse(remote).ok()?;
+            (url.host_str()?.to_string(), url.path().to_string())
+        } else {
+            // scp 형식: [user@]host:path
+            let (authority, path) = remote.split_once(':')?;
+            let host = authority.rsplit('@').next()?;
+            (host.to_string(), path.to_string())
+        };
+
+        let path = path.trim_matches('/');
+        let path = path.strip_suffix(".git").unwrap_or(path);
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        if host.is_empty() || segments.len() < 2 {
+            return None;
+        }
+
+        if host.contains("gitlab") || segments.len() > 2 {
+            return Some(RepositoryRef::GitLab {
+                host,
+                project_path: segments.join("/"),
+            });
+        }
+        Some(RepositoryRef::GitHub {
+            host,
+            owner: segments[0].to_string(),
+            repo: segments[1].to_string(),
+        })
+    }
+
+    pub fn host(&self) -> &str {
+        match self {
+            RepositoryRef::GitHub { host, .. } => host,
+            RepositoryRef::GitLab { host, .. } => host,
+        }
+    }
+}
+
+/// 저장소의 열린 PR/MR 요약.
+#[derive(Debug, Clone)]
+pub struct OpenReview {
+    pub url: String,
+    pub number: u64,
+    pub title: String,
+}
@@ -0,0 +1,551 @@
+//! 프롬프트/코멘트 템플릿 값 객체와 변수 목록/검사 규칙.
+//!
+//! 렌더링은 `TemplateEngine` 포트 구현(Tera 문법)이 맡는다. 여기에는 기본 템플릿,
+//! 템플릿 종류별로 받는 변수 목록([`TemplateSchema`])과 모르는 변수를 거부하는 규칙만 둔다.
+//!
+//! `{{cache_breakpoint}}`는 그 앞부분이 같은 provider의 다음 호출(다음 청크/라운드)에서도 그대로
+//! 반복된다는 표시다. API 백엔드는 이 위치까지를 provider 측 프롬프트 캐시 대상으로 보내고,
+//! 그 밖의 백엔드는 표시를 지운 프롬프트를 보낸다.
+
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+
+use anyhow::{Result, bail};
+
+/// 1차 리뷰 기본 템플릿.
+pub const DEFAULT_PRIMARY_TEMPLATE: &str = "System instructions:
+{{system_prompt}}{% if guide %}
+
+Review guide (must follow):
+{{guide}}{% endif %}
+
+Output language requirement:
+{{language_instruction}}
+
+Output contract:
+{{findings_contract}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+{{cache_breakpoint}}{{scope}}
+Review the diff and report key issues in concise Markdown.
+Use sections in this order: {{severity_sections}}.
+
+```diff
+{{diff}}
+```";
+
+/// 교차 에이전트 반응 기본 템플릿.
+pub const DEFAULT_CROSS_AGENT_TEMPLATE: &str = "You are participating in a multi-agent code review.
+Analyze other agents' findings and provide your perspective.
+Output language requirement:
+{{language_instruction}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+
+Other agents' findings:
+
+{{other_findings}}{{cache_breakpoint}}{% if previous_reactions %}Other agents' reactions from round {{previous_round}}:
+
+{{previous_reactions}}Respond to these reactions as well. Revise your position where they convinced you, and defend it where they did not.
+{% endif %}Now write {{agent_name}}'s reaction to other agents.
+Use Markdown sections in this order: Agreements, Disagreements, Missed Risks, Suggested Resolution.
+";
+
+/// 통합 리뷰(moderator) 기본 템플릿.
+pub const DEFAULT_MODERATOR_TEMPLATE: &str = "You are the moderator of a multi-agent code review.
+Merge all agents' findings into a single consolidated review. Deduplicate overlapping issues, resolve disagreements using the reactions, and drop findings that were convincingly refuted.
+Output language requirement:
+{{language_instruction}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+
+Primary reviews:
+
+{{primary_reviews}}{% if reactions %}Cross-agent reactions:
+
+{{reactions}}{% endif %}Write the consolidated review in Markdown, sorted by severity, using sections in this order: {{severity_sections}}.
+";
+
+/// PR 메타 리뷰(제목/설명/커밋 메시지) 기본 템플릿.
+pub const DEFAULT_META_REVIEW_TEMPLATE: &str = "You review the process quality of a pull request, not its code.
+Evaluate the title, description and commit messages below.
+Output language requirement:
+{{language_instruction}}
+
+Target URL: {{target_url}}
+Head SHA: {{head_sha}}
+
+Title:
+{{pr_title}}
+
+Description:
+{{description}}
+
+{% if description_template %}The repository's description template (the description should fill in its sections):
+
+{{description_template}}
+
+{% endif %}Commit messages (oldest first):
+
+{{commits}}
+
+Check:
+- The title is a short imperative summary; if the commits use Conventional Commits (`type(scope): subject`), the title should too.
+- The description explains what changes and why, how it was tested, and follows the template when one exists.
+- Each commit message follows Conventional Commits, has a subject of at most 72 characters, and does not contain fixup/WIP noise that should be squashed.
+
+Write concise Markdown with sections in this order: Title, Description, Commits. Under each, list concrete problems with a suggested rewrite, or write that it looks good.
+";
+
+/// 보안 점검(security pass) 기본 템플릿. 변수는 1차 리뷰 템플릿과 같다.
+pub const DEFAULT_SECURITY_TEMPLATE: &str = "You are an application security reviewer. Review only the security impact of this change; ignore style, naming and bugs without a security consequence.
+Output language requirement:
+{{language_instruction}}
+
+Check the diff against this checklist:
+- Injection: SQL/NoSQL/OS command/template injection, unsafe deserialization, evaluation of untrusted input.
+- Broken access control: missing authorization checks, insecure direct object references, path traversal, privilege escalation.
+- Authentication and sessions: hard-coded credentials, weak token handling, missing expiry or rotation.
+- Cryptography: weak or custom algorithms, predictable randomness, secrets or keys in code or logs.
+- Input validation and output encoding: XSS, open redirects, SSRF, unbounded input sizes.
+- Security misconfiguration: permissive CORS, disabled TLS verification, debug endpoints, unsafe defaults.
+- Vulnerable or unpinned dependencies introduced by the change.
+- Sensitive data exposure: personal data or secrets in logs, errors or responses.
+- Resource exhaustion an attacker can trigger.
+
+Every issue must be reported in the findings block with a severity that reflects exploitability and impact. Do not report issues the diff does not make reachable; use a low confidence when exploitability depends on code outside the diff.
+
+Output contract:
+{{findings_contract}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+{{cache_breakpoint}}{{scope}}
+Summarize the security impact in a few sentences of Markdown, then describe each issue with the attack scenario and the fix.
+
+```diff
+{{diff}}
+```";
+
+/// 테스트 누락 분석 기본 템플릿.
+pub const DEFAULT_TEST_GAPS_TEMPLATE: &str = "You check whether a change is covered by tests. Do not review code quality.
+Output language requirement:
+{{language_instruction}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+
+Changed source files:
+{{source_files}}
+
+Changed test files (by path heuristics):
+{{test_files}}
+{{cache_breakpoint}}{% if files %}This is part {{chunk_index}} of {{chunk_total}} of a large diff (files: {{files}}). Check only this part.
+{% endif %}
+List the functions and methods whose behavior the diff adds or changes but that no test change in this pull request exercises.
+Count tests added in the same file (for example Rust `#[cfg(test)]` modules) as coverage. Skip pure refactors, renames, documentation, configuration and generated code.
+
+Output only a Markdown task list, one item per untested function:
+- [ ] `path/to/file` `function_name`: the behavior or edge case a test should cover
+Write `No missing tests.` when every changed function is covered.
+
+```diff
+{{diff}}
+```";
+
+/// 수정 패치 생성(`--autofix`) 기본 템플릿.
+pub const DEFAULT_AUTOFIX_TEMPLATE: &str = "You fix code review findings by writing a patch. Change only what the findings require; do not refactor, reformat or fix anything else.
+Output language requirement:
+{{language_instruction}}
+
+Target URL: {{target_url}}
+{% if pr_title %}Title: {{pr_title}}
+{% endif %}Head SHA: {{head_sha}}
+
+Findings to fix:
+{{findings}}
+
+Current contents of the affected files at the head commit:
+
+{{files}}
+
+Output one unified diff against the files above in a single ```diff block, with `--- a/<path>` and `+++ b/<path>` headers, `@@` hunk headers and three lines of unchanged context around each change. Copy context and removed lines exactly, including indentation.
+Skip findings you cannot fix safely from these files and list them after the block with one sentence each.
+Write `No fixes.` when none of the findings can be fixed.";
+
+/// 리뷰 단계별 프롬프트 템플릿 묶음.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PromptTemplates {
+    pub primary: String,
+    pub cross_agent: String,
+    pub moderator: String,
+    pub meta_review: String,
+    pub security: String,
+    pub test_gaps: String,
+    pub autofix: String,
+}
+
+impl Default for PromptTemplates {
+    fn default() -> Self {
+        Self {
+            primary: DEFAULT_PRIMARY_TEMPLATE.to_string(),
+            cross_agent: DEFAULT_CROSS_AGENT_TEMPLATE.to_string(),
+            moderator: DEFAULT_MODERATOR_TEMPLATE.to_string(),
+            meta_review: DEFAULT_META_REVIEW_TEMPLATE.to_string(),
+            security: DEFAULT_SECURITY_TEMPLATE.to_string(),
+            test_gaps: DEFAULT_TEST_GAPS_TEMPLATE.to_string(),
+            autofix: DEFAULT_AUTOFIX_TEMPLATE.to_string(),
+        }
+    }
+}
+
+/// claim 코멘트 기본 템플릿(마커 아래 본문).
+pub const DEFAULT_CLAIM_COMMENT_TEMPLATE: &str = "# Multi-Agent Code Review
+
+- Target: {{target_url}}
+- Head SHA: `{{head_sha}}`
+
+Review in progress...";
+
+/// 에이전트별 코멘트 기본 템플릿(마커 아래 본문).
+pub const DEFAULT_AGENT_COMMENT_TEMPLATE: &str = "# Agent Review: {{agent_name}}
+
+- Target: {{target_url}}
+- Head SHA: `{{head_sha}}`
+
+{{body}}
+";
+
+/// 최종 요약 코멘트 기본 템플릿(마커 아래 본문).
+pub const DEFAULT_FINAL_COMMENT_TEMPLATE: &str = "# Multi-Agent Review Summary
+
+- Target: {{target_url}}
+- Head SHA: `{{head_sha}}`
+{% if diff_stats %}- Diff: {{diff_stats}}
+{% endif %}
+{% if stats %}{{stats}}
+
+{% endif %}{% if consolidated %}## Consolidated Review (moderator: {{moderator}})
+
+{{consolidated}}
+
+{% endif %}{% if security %}## Security (reviewer: {{security_reviewer}})
+
+{{security}}
+
+{% endif %}{% if consensus %}## Consensus Findings
+
+{{consensus}}
+
+{% endif %}{% if needs_verification %}## Needs Human Verification
+
+{{needs_verification}}
+
+{% endif %}{% if progress %}## Progress Since Previous Review
+
+{{progress}}
+
+{% endif %}{% if follow_up_issues %}## Follow-up Issues
+
+{{follow_up_issues}}
+
+{% endif %}{% if suggested_reviewers %}## Suggested Reviewers
+
+{{suggested_reviewers}}
+
+{% endif %}{% if missing_tests %}## Missing Tests (reviewer: {{test_gap_reviewer}})
+
+{{missing_tests}}
+
+{% endif %}{% if meta_review %}## PR Hygiene (reviewer: {{meta_reviewer}})
+
+{{meta_review}}
+
+{% endif %}## Individual Agent Comments
+
+{{agent_comments}}
+
+## Agent-to-Agent Reactions
+
+{{reactions}}
+
+";
+
+/// VCS 코멘트 종류별 본문 템플릿 묶음. 중복 판단용 마커는 템플릿과 관계없이 항상 맨 앞에 붙는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommentTemplates {
+    pub claim: String,
+    pub agent: String,
+    pub final_summary: String,
+}
+
+impl Default for CommentTemplates {
+    fn default() -> Self {
+        Self {
+            claim: DEFAULT_CLAIM_COMMENT_TEMPLATE.to_string(),
+            agent: DEFAULT_AGENT_COMMENT_TEMPLATE.to_string(),
+            final_summary: DEFAULT_FINAL_COMMENT_TEMPLATE.to_string(),
+        }
+    }
+}
+
+/// `{{cache_breakpoint}}`가 렌더링되는 표시 문자열.
+pub const PROMPT_CACHE_BREAKPOINT: &str = "<!-- repopilot:cache-breakpoint -->";
+
+/// 템플릿 변수 이름 → 값. diff처럼 큰 값은 빌려 쓴다.
+pub type TemplateVars<'a> = BTreeMap<&'a str, Cow<'a, str>>;
+
+/// 목록 변수의 항목 하나(필드 이름 → 값). 템플릿에서 `{{item.field}}`로 읽는다.
+pub type TemplateItem = BTreeMap<&'static str, String>;
+
+/// 목록 변수 이름 → 항목 목록. `{% for item in name %}...{% endfor %}`로 순회한다.
+pub type TemplateLists<'a> = BTreeMap<&'a str, Vec<TemplateItem>>;
+
+/// 템플릿 한 종류가 받는 변수 이름 목록. 사용자 템플릿을 불러올 때 이 목록으로 검사한다.
+#[derive(Debug, Clone, Copy)]
+pub struct TemplateSchema {
+    pub vars: &'static [&'static str],
+    /// 목록 변수 이름과 그 항목의 필드 이름.
+    pub lists: &'static [(&'static str, &'static [&'static str])],
+}
+
+/// 1차 리뷰/보안 점검 템플릿 변수.
+pub const PRIMARY_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "severity_sections",
+        "system_prompt",
+        "guide",
+        "findings_contract",
+        "scope",
+        "files",
+        "chunk_index",
+        "chunk_total",
+        "diff",
+    ],
+    lists: &[],
+};
+
+/// 교차 에이전트 반응 템플릿 변수.
+pub const CROSS_AGENT_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "severity_sections",
+        "agent_name",
+        "other_findings",
+        "previous_reactions",
+        "previous_round",
+    ],
+    lists: &[],
+};
+
+/// 통합 리뷰 템플릿 변수.
+pub const MODERATOR_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "severity_sections",
+        "primary_reviews",
+        "reactions",
+    ],
+    lists: &[],
+};
+
+/// PR 메타 리뷰 템플릿 변수.
+pub const META_REVIEW_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "severity_sections",
+        "description",
+        "commits",
+        "description_template",
+    ],
+    lists: &[],
+};
+
+/// 테스트 누락 분석 템플릿 변수.
+pub const TEST_GAPS_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "severity_sections",
+        "source_files",
+        "test_files",
+        "files",
+        "chunk_index",
+        "chunk_total",
+        "diff",
+    ],
+    lists: &[],
+};
+
+/// 수정 패치 생성 템플릿 변수.
+pub const AUTOFIX_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "pr_title",
+        "language",
+        "language_instruction",
+        "cache_breakpoint",
+        "findings",
+        "files",
+    ],
+    lists: &[],
+};
+
+/// claim 코멘트 템플릿 변수.
+pub const CLAIM_COMMENT_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &["target_url", "head_sha", "claimed_at"],
+    lists: &[],
+};
+
+/// 에이전트별 코멘트 템플릿 변수.
+pub const AGENT_COMMENT_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &["target_url", "head_sha", "agent_id", "agent_name", "body"],
+    lists: &[],
+};
+
+/// 최종 요약 코멘트 템플릿 변수.
+pub const FINAL_COMMENT_TEMPLATE_SCHEMA: TemplateSchema = TemplateSchema {
+    vars: &[
+        "target_url",
+        "head_sha",
+        "diff_stats",
+        "severity_counts",
+        "stats",
+        "moderator",
+        "consolidated",
+        "consensus",
+        "needs_verification",
+        "consensus_count",
+        "progress",
+        "follow_up_issues",
+        "suggested_reviewers",
+        "security_reviewer",
+        "security",
+        "test_gap_reviewer",
+        "missing_tests",
+        "meta_reviewer",
+        "meta_review",
+        "agent_comments",
+        "reactions",
+    ],
+    lists: &[
+        ("findings", FINDING_ITEM_FIELDS),
+        ("agents", &["name", "comment_id"]),
+    ],
+};
+
+/// 최종 요약의 합의 지적사항 목록(`findings`) 항목 필드. 값이 없는 필드는 빈 문자열이다.
+/// `agents`는 동의한 에이전트 이름(쉼표 구분), `needs_verification`은 확신도가 낮으면 `true`다.
+pub const FINDING_ITEM_FIELDS: &[&str] = &[
+    "id",
+    "severity",
+    "title",
+    "body",
+    "location",
+    "url",
+    "confidence",
+    "agents",
+    "needs_verification",
+];
+
+/// 템플릿이 참조하는 최상위 변수 이름이 `schema`에 있는지 검사한다.
+pub fn check_template_variables(
+    referenced: &BTreeSet<String>,
+    schema: &TemplateSchema,
+) -> Result<()> {
+    let available: Vec<&str> = schema
+        .vars
+        .iter()
+        .copied()
+        .chain(schema.lists.iter().map(|(name, _)| *name))
+        .collect();
+    let unknown: Vec<&str> = referenced
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !available.contains(name))
+        .collect();
+    if !unknown.is_empty() {
+        bail!(
+            "unknown template variable(s): {} (available: {})",
+            unknown.join(", "),
+            available.join(", ")
+        );
+    }
+    Ok(())
+}
+
+impl TemplateSchema {
+    /// 모든 변수에 예시 값을 넣은 변수 표. 검사 렌더링에 쓴다.
+    pub fn sample_vars(&self) -> TemplateVars<'static> {
+        self.vars
+            .iter()
+            .map(|name| (*name, Cow::Borrowed("sample")))
+            .collect()
+    }
+
+    /// 목록 변수마다 예시 항목 하나를 넣은 목록 표. 검사 렌더링에 쓴다.
+    pub fn sample_lists(&self) -> TemplateLists<'static> {
+        self.lists
+            .iter()
+            .map(|(name, fields)| {
+                let item = fields
+                    .iter()
+                    .map(|field| (*field, "sample".to_string()))
+                    .collect();
+                (*name, vec![item])
+            })
+            .collect()
+    }
+}
+
+/// 렌더링된 프롬프트를 (캐시할 앞부분, 나머지)로 나눈다. 표시가 없으면 앞부분은 빈 문자열이다.
+/// 나머지에 남은 표시는 지운다.
+pub fn split_cache_breakpoint(prompt: &str) -> (&str, Cow<'_, str>) {
+    match prompt.split_once(PROMPT_CACHE_BREAKPOINT) {
+        Some((prefix, rest)) => (prefix, strip_cache_breakpoint(rest)),
+        None => ("", Cow::Borrowed(prompt)),
+    }
+}
+
+/// 프롬프트 캐시를 쓰지 않는 백엔드로 보내기 전에 캐시 표시를 지운다.
+pub fn strip_cache_breakpoint(prompt: &str) -> Cow<'_, str> {
+    if prompt.contains(PROMPT_CACHE_BREAKPOINT) {
+        Cow::Owned(prompt.replace(PROMPT_CACHE_BREAKPOINT, ""))
+    } else {
+        Cow::Borrowed(prompt)
+    }
+}
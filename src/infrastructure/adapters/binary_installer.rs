@@ -0,0 +1,89 @@
+//! 실행 파일 검증/교체 포트 구현 어댑터(`repopilot self-update`).
+//!
+//! 새 바이너리는 현재 실행 파일과 같은 디렉터리의 임시 파일에 먼저 쓰고 rename으로 바꿔 끼운다.
+//! 같은 파일 시스템 안의 rename이라 중간에 실패해도 기존 바이너리가 깨지지 않는다.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use ring::digest::{SHA256, digest};
+use ring::signature::{ED25519, UnparsedPublicKey};
+
+use crate::application::ports::BinaryInstaller;
+
+/// 현재 프로세스 실행 파일을 교체하는 어댑터.
+pub struct ExeBinaryInstaller;
+
+impl BinaryInstaller for ExeBinaryInstaller {
+    fn sha256_hex(&self, bytes: &[u8]) -> String {
+        digest(&SHA256, bytes)
+            .as_ref()
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect()
+    }
+
+    fn verify_signature(&self, bytes: &[u8], signature: &[u8], public_key: &str) -> Result<()> {
+        let key = STANDARD
+            .decode(public_key.trim())
+            .context("defaults.update_public_key is not valid base64")?;
+        // 원본 서명(64바이트)과 base64 텍스트로 배포된 서명을 모두 받는다.
+        let signature = if signature.len() == 64 {
+            signature.to_vec()
+        } else {
+            let text = String::from_utf8_lossy(signature);
+            STANDARD
+                .decode(text.trim())
+                .context("release signature is neither raw Ed25519 nor base64")?
+        };
+        UnparsedPublicKey::new(&ED25519, key)
+            .verify(bytes, &signature)
+            .map_err(|_| anyhow::anyhow!("release signature verification failed"))
+    }
+
+    fn replace_current_exe(&self, bytes: &[u8]) -> Result<PathBuf> {
+        let exe = env::current_exe().context("failed to locate current executable")?;
+        // 심볼릭 링크(예: /usr/local/bin/repopilot)면 실제 파일을 교체한다.
+        let exe = fs::canonicalize(&exe).unwrap_or(exe);
+        let Some(dir) = exe.parent() else {
+            bail!("invalid executable path: {}", exe.display());
+        };
+        let file_name = exe
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "repopilot".to_string());
+        let staged = dir.join(format!(".{file_name}.update-{}", std::process::id()));
+
+        if let Err(err) = stage_and_swap(&staged, &exe, bytes) {
+            let _ = fs::remove_file(&staged);
+            return Err(err);
+        }
+        Ok(exe)
+    }
+}
+
+fn stage_and_swap(staged: &Path, exe: &Path, bytes: &[u8]) -> Result<()> {
+    fs::write(staged, bytes)
+        .with_context(|| format!("failed to write {} (permission denied?)", staged.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(staged, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("failed to chmod {}", staged.display()))?;
+    }
+
+    // Windows는 실행 중인 파일을 덮어쓸 수 없지만 이름은 바꿀 수 있으므로 먼저 비켜 둔다.
+    #[cfg(windows)]
+    {
+        let old = exe.with_extension("old.exe");
+        let _ = fs::remove_file(&old);
+        fs::rename(exe, &old).with_context(|| format!("failed to move {} aside", exe.display()))?;
+    }
+
+    fs::rename(staged, exe).with_context(|| format!("failed to replace {}", exe.display()))
+}
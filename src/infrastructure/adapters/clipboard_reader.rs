@@ -0,0 +1,47 @@
+//! 시스템 클립보드 조회 포트 구현.
+//!
+//! - macOS: `pbpaste`
+//! - Linux: `wl-paste`(Wayland), `xclip`/`xsel`(X11)
+//! - Windows: PowerShell `Get-Clipboard`
+
+use std::env;
+use std::process::{Command, Stdio};
+
+use crate::application::ports::ClipboardReader;
+use crate::infrastructure::config::command_exists;
+
+/// 플랫폼 클립보드 명령으로 텍스트를 읽는다.
+pub struct SystemClipboardReader;
+
+impl ClipboardReader for SystemClipboardReader {
+    fn read_text(&self) -> Option<String> {
+        let candidates: &[&[&str]] = if cfg!(target_os = "macos") {
+            &[&["pbpaste"]]
+        } else if cfg!(windows) {
+            &[&["powershell", "-NoProfile", "-Command", "Get-Clipboard"]]
+        } else if env::var_os("WAYLAND_DISPLAY").is_some() {
+            &[&["wl-paste", "--no-newline"]]
+        } else if env::var_os("DISPLAY").is_some() {
+            &[
+                &["xclip", "-selection", "clipboard", "-o"],
+                &["xsel", "--clipboard", "--output"],
+            ]
+        } else {
+            return None;
+        };
+
+        candidates
+            .iter()
+            .filter(|argv| command_exists(argv[0]))
+            .find_map(|argv| {
+                let output = Command::new(argv[0])
+                    .args(&argv[1..])
+                    .stdin(Stdio::null())
+                    .stderr(Stdio::null())
+                    .output()
+                    .ok()?;
+                let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                (output.status.success() && !text.is_empty()).then_some(text)
+            })
+    }
+}
@@ -0,0 +1,17 @@
+//! 시계/임의 id 포트 구현 어댑터.
+
+use crate::application::ports::Clock;
+use crate::infrastructure::clock;
+
+/// 시스템 시계와 표준 라이브러리 해시 시드를 쓰는 어댑터.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn unix_now(&self) -> u64 {
+        clock::unix_now()
+    }
+
+    fn random_id(&self) -> String {
+        clock::random_id()
+    }
+}
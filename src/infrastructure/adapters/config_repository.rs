@@ -4,8 +4,10 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 
-use crate::application::ports::ConfigRepository;
-use crate::infrastructure::config;
+use crate::application::ports::{
+    ConfigMigration, ConfigRepository, ConfigValidation, InitializedProject, ProjectInit,
+};
+use crate::infrastructure::{config, state};
 
 /// JSON 기반 설정 저장소 어댑터.
 pub struct JsonConfigRepository;
@@ -15,11 +17,35 @@ impl ConfigRepository for JsonConfigRepository {
         config::load()
     }
 
-    fn inspect_pretty_json(&self) -> Result<String> {
-        config::inspect_pretty_json()
+    fn inspect_pretty_json(&self, show_secrets: bool) -> Result<String> {
+        config::inspect_pretty_json(show_secrets)
     }
 
     fn editable_config_path(&self) -> Result<PathBuf> {
         config::editable_path()
     }
+
+    fn validate(&self) -> Result<ConfigValidation> {
+        Ok(config::validate())
+    }
+
+    fn get_value(&self, key: &str, show_secrets: bool) -> Result<Option<serde_json::Value>> {
+        config::get_value(key, show_secrets)
+    }
+
+    fn set_value(&self, key: &str, value: &str, force: bool) -> Result<PathBuf> {
+        config::set_value(key, value, force)
+    }
+
+    fn migrate(&self, force: bool) -> Result<Vec<ConfigMigration>> {
+        config::migrate(force)
+    }
+
+    fn init_project(&self, init: &ProjectInit) -> Result<InitializedProject> {
+        config::init_project(init)
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        state::state_dir()
+    }
 }
@@ -0,0 +1,13 @@
+//! 콘솔 로그 on/off 포트 구현 어댑터.
+
+use crate::application::ports::ConsoleLogSwitch;
+use crate::infrastructure::logging;
+
+/// 전역 tracing 구독자의 콘솔(stderr) 출력을 켜고 끄는 어댑터.
+pub struct TracingConsoleLogSwitch;
+
+impl ConsoleLogSwitch for TracingConsoleLogSwitch {
+    fn set_muted(&self, muted: bool) {
+        logging::set_console_muted(muted);
+    }
+}
@@ -0,0 +1,29 @@
+//! 설정 파일 편집기 실행 포트 구현 어댑터.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+
+use crate::application::ports::EditorLauncher;
+use crate::infrastructure::config::command_program;
+
+/// `$EDITOR`(없으면 `vi`, Windows는 `notepad`)로 파일을 연다.
+/// Windows에서는 PATHEXT로 `.cmd`/`.bat` shim까지 찾아 실행한다.
+pub struct EnvEditorLauncher;
+
+impl EditorLauncher for EnvEditorLauncher {
+    fn open(&self, path: &Path) -> Result<()> {
+        let default_editor = if cfg!(windows) { "notepad" } else { "vi" };
+        let editor = env::var("EDITOR").unwrap_or_else(|_| default_editor.to_string());
+        let status = Command::new(command_program(&editor))
+            .arg(path)
+            .status()
+            .with_context(|| format!("failed to launch editor: {editor}"))?;
+        if !status.success() {
+            bail!("editor exited with: {status}");
+        }
+        Ok(())
+    }
+}
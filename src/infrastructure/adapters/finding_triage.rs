@@ -0,0 +1,74 @@
+//! 지적사항 triage 포트 구현 어댑터.
+
+use std::io::{self, Write};
+
+use anyhow::Result;
+
+use crate::application::ports::{FindingTriage, TriageDecision};
+use crate::domain::review::Finding;
+
+/// stdin으로 accept/drop/edit 입력을 받는 어댑터.
+pub struct StdinFindingTriage;
+
+impl FindingTriage for StdinFindingTriage {
+    fn triage(
+        &self,
+        agent_name: &str,
+        finding: &Finding,
+        index: usize,
+        total: usize,
+    ) -> Result<TriageDecision> {
+        eprintln!();
+        eprintln!(
+            "[{index}/{total}] {agent_name} · {} · {}",
+            finding.severity.label(),
+            finding.location().unwrap_or_else(|| "(no location)".to_string())
+        );
+        eprintln!("  {}", finding.title);
+        for line in finding.body.trim().lines() {
+            eprintln!("  {line}");
+        }
+
+        loop {
+            eprint!("[a]ccept / [d]rop / [e]dit: ");
+            io::stderr().flush()?;
+
+            let mut input = String::new();
+            if io::stdin().read_line(&mut input)? == 0 {
+                // 입력이 닫히면 게시하지 않는 쪽으로 처리한다.
+                return Ok(TriageDecision::Drop);
+            }
+            match input.trim().to_ascii_lowercase().as_str() {
+                "a" | "accept" | "y" | "yes" => return Ok(TriageDecision::Accept),
+                "d" | "drop" | "n" | "no" => return Ok(TriageDecision::Drop),
+                "e" | "edit" => {
+                    eprint!("new body (single line, empty keeps current): ");
+                    io::stderr().flush()?;
+                    let mut body = String::new();
+                    io::stdin().read_line(&mut body)?;
+                    let body = body.trim();
+                    if body.is_empty() {
+                        return Ok(TriageDecision::Accept);
+                    }
+                    return Ok(TriageDecision::Edit(body.to_string()));
+                }
+                _ => continue,
+            }
+        }
+    }
+}
+
+/// 모든 지적사항을 승인하는 어댑터(라이브러리 직접 호출용).
+pub struct AutoFindingTriage;
+
+impl FindingTriage for AutoFindingTriage {
+    fn triage(
+        &self,
+        _agent_name: &str,
+        _finding: &Finding,
+        _index: usize,
+        _total: usize,
+    ) -> Result<TriageDecision> {
+        Ok(TriageDecision::Accept)
+    }
+}
@@ -0,0 +1,37 @@
+//! git 원격 저장소 조회 포트 구현.
+
+use std::process::{Command, Stdio};
+
+use crate::application::ports::GitRemoteReader;
+
+/// 현재 작업 디렉터리에서 `git remote -v`를 실행해 원격 URL을 읽는다.
+pub struct GitCommandRemoteReader;
+
+impl GitRemoteReader for GitCommandRemoteReader {
+    fn remote_urls(&self) -> Vec<String> {
+        let Ok(output) = Command::new("git")
+            .args(["remote", "-v"])
+            .stderr(Stdio::null())
+            .output()
+        else {
+            return Vec::new();
+        };
+        if !output.status.success() {
+            return Vec::new();
+        }
+
+        // `<name>\t<url> (fetch|push)` 줄에서 원격별 URL을 한 번씩 모은다.
+        let mut remotes: Vec<(String, String)> = Vec::new();
+        for line in String::from_utf8_lossy(&output.stdout).lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(name), Some(url)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            if !remotes.iter().any(|(_, known)| known == url) {
+                remotes.push((name.to_string(), url.to_string()));
+            }
+        }
+        remotes.sort_by_key(|(name, _)| name != "origin");
+        remotes.into_iter().map(|(_, url)| url).collect()
+    }
+}
@@ -1,17 +1,60 @@
 //! 호스트(VCS) 토큰 해석 포트 구현.
 
+use std::sync::Arc;
+
 use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use crate::application::config::HostConfig;
 use crate::application::ports::{HostTokenResolution, HostTokenResolver};
 use crate::infrastructure::config::resolve_host_token;
+use crate::infrastructure::traffic::{CaptureMode, TrafficCapture};
+
+// 재생 중에는 실제 호출이 없으므로 토큰 값은 의미가 없다.
+const REPLAY_TOKEN: &str = "replay";
 
 /// 설정(token/env/cmd)에 기반해 런타임 토큰을 해석한다.
 pub struct HostTokenResolverAdapter;
 
 impl HostTokenResolver for HostTokenResolverAdapter {
-    fn resolve(&self, _host: &str, host_cfg: Option<&HostConfig>) -> Result<HostTokenResolution> {
-        resolve_host_token(host_cfg)
+    fn resolve(&self, host: &str, host_cfg: Option<&HostConfig>) -> Result<HostTokenResolution> {
+        resolve_host_token(host, host_cfg)
     }
 }
 
+/// 토큰 해석 결과를 기록/재생하는 해석기(`--record` / `--replay`).
+/// 토큰 값은 남기지 않고 토큰 유무와 출처만 기록한다. 재생 시에는 자리표시 토큰을 돌려준다.
+pub struct TrafficHostTokenResolver {
+    pub inner: Box<dyn HostTokenResolver>,
+    pub capture: Arc<TrafficCapture>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct RecordedToken {
+    resolved: bool,
+    source: Option<String>,
+}
+
+impl HostTokenResolver for TrafficHostTokenResolver {
+    fn resolve(&self, host: &str, host_cfg: Option<&HostConfig>) -> Result<HostTokenResolution> {
+        let mut token = None;
+        let recorded: RecordedToken =
+            self.capture
+                .exchange_blocking(("host", host, "resolve_token", ""), || {
+                    let resolution = self.inner.resolve(host, host_cfg)?;
+                    token = resolution.token;
+                    Ok(RecordedToken {
+                        resolved: token.is_some(),
+                        source: resolution.source,
+                    })
+                })?;
+        let token = match self.capture.mode() {
+            CaptureMode::Record => token,
+            CaptureMode::Replay => recorded.resolved.then(|| REPLAY_TOKEN.to_string()),
+        };
+        Ok(HostTokenResolution {
+            token,
+            source: recorded.source,
+        })
+    }
+}
@@ -0,0 +1,17 @@
+//! HTML 보고서 내보내기 포트 구현 어댑터.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::application::ports::{HtmlExporter, OutputWriter};
+use crate::infrastructure::adapters::FsOutputWriter;
+
+/// 렌더링된 HTML 문서를 그대로 파일로 쓰는 내보내기 어댑터.
+pub struct FileHtmlExporter;
+
+impl HtmlExporter for FileHtmlExporter {
+    fn write_html(&self, html: &str, path: &Path) -> Result<()> {
+        FsOutputWriter.write(path, html)
+    }
+}
@@ -0,0 +1,15 @@
+//! 비밀 저장소 포트 구현(OS 키체인).
+
+use anyhow::Result;
+
+use crate::application::ports::SecretStore;
+use crate::infrastructure::config::store_keyring_secret;
+
+/// macOS Keychain / Secret Service에 비밀값을 저장한다.
+pub struct KeyringSecretStore;
+
+impl SecretStore for KeyringSecretStore {
+    fn store(&self, entry: &str, secret: &str) -> Result<()> {
+        store_keyring_secret(entry, secret)
+    }
+}
@@ -0,0 +1,59 @@
+//! 진행 출력을 tracing 이벤트로도 남기는 리포터 데코레이터.
+//!
+//! 콘솔/NDJSON 출력은 내부 리포터에 그대로 넘기고, 같은 내용을 JSON 로그 파일에
+//! 기록해 CI 등에서 끝난 실행을 나중에 진단할 수 있게 한다.
+
+use crate::application::ports::Reporter;
+use crate::domain::review::TokenUsage;
+
+/// 내부 리포터를 감싸 모든 진행 출력을 tracing 이벤트로 기록한다.
+pub struct LoggingReporter {
+    inner: Box<dyn Reporter>,
+}
+
+impl LoggingReporter {
+    pub fn new(inner: Box<dyn Reporter>) -> Self {
+        Self { inner }
+    }
+}
+
+impl Reporter for LoggingReporter {
+    fn section(&self, name: &str) {
+        tracing::debug!(section = name, "section");
+        self.inner.section(name);
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        tracing::info!(key, value, "kv");
+        self.inner.kv(key, value);
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        tracing::info!(scope, "{message}");
+        self.inner.status(scope, message);
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        tracing::info!(provider, status, elapsed_secs, "provider status");
+        self.inner.provider_status(provider, status, elapsed_secs);
+    }
+
+    fn provider_output(&self, provider: &str, body: &str, usage: &TokenUsage) {
+        self.inner.provider_output(provider, body, usage);
+    }
+
+    fn raw(&self, line: &str) {
+        tracing::debug!("{line}");
+        self.inner.raw(line);
+    }
+
+    fn outcome(&self, line: &str) {
+        tracing::info!(outcome = true, "{line}");
+        self.inner.outcome(line);
+    }
+
+    fn detail(&self, scope: &str, message: &str) {
+        tracing::debug!(scope, "{message}");
+        self.inner.detail(scope, message);
+    }
+}
@@ -1,28 +1,64 @@
 //! 마크다운 렌더링 포트 구현 어댑터.
 
+use anyhow::Result;
+
 use crate::application::ports::MarkdownRenderer;
-use crate::domain::review::{AgentComment, AgentReaction};
+use crate::domain::review::{AgentComment, FileThread, Finding, ReviewSummary, SeverityTaxonomy};
 use crate::infrastructure::render;
 
 /// 마크다운 렌더링 어댑터.
 pub struct MarkdownRendererAdapter;
 
 impl MarkdownRenderer for MarkdownRendererAdapter {
-    fn render_claim(&self, sha: &str, target_url: &str) -> String {
-        render::render_claim_markdown(sha, target_url)
+    fn render_claim(
+        &self,
+        sha: &str,
+        target_url: &str,
+        claimed_at: u64,
+        nonce: &str,
+        template: &str,
+    ) -> Result<String> {
+        render::render_claim_markdown(sha, target_url, claimed_at, nonce, template)
     }
 
-    fn render_agent(&self, sha: &str, target_url: &str, agent: &AgentComment) -> String {
-        render::render_agent_markdown(sha, target_url, agent)
+    fn render_claim_withdrawn(&self, sha: &str, target_url: &str) -> String {
+        render::render_claim_withdrawn_markdown(sha, target_url)
     }
 
-    fn render_final(
+    fn render_unreviewable(&self, sha: &str, target_url: &str, reason: &str) -> String {
+        render::render_unreviewable_summary_markdown(sha, target_url, reason)
+    }
+
+    fn render_agent(
         &self,
         sha: &str,
         target_url: &str,
-        reactions: &[AgentReaction],
-        agent_comment_refs: &[(String, String)],
+        agent: &AgentComment,
+        template: &str,
+    ) -> Result<String> {
+        render::render_agent_markdown(sha, target_url, agent, template)
+    }
+
+    fn render_file_thread(&self, sha: &str, thread: &FileThread) -> String {
+        render::render_file_thread_markdown(sha, thread)
+    }
+
+    fn render_suggestion(
+        &self,
+        target_url: &str,
+        finding: &Finding,
+        taxonomy: &SeverityTaxonomy,
     ) -> String {
-        render::render_final_summary_markdown(sha, target_url, reactions, agent_comment_refs)
+        render::render_suggestion_markdown(target_url, finding, taxonomy)
+    }
+
+    fn render_final(
+        &self,
+        sha: &str,
+        target_url: &str,
+        summary: &ReviewSummary,
+        template: &str,
+    ) -> Result<String> {
+        render::render_final_summary_markdown(sha, target_url, summary, template)
     }
 }
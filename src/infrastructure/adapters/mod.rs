@@ -1,27 +1,71 @@
 //! 애플리케이션 포트를 실제 인프라 구현체로 연결하는 어댑터 계층.
 
+mod binary_installer;
+mod clipboard_reader;
+mod clock;
 mod config_repository;
+mod console_log_switch;
+mod editor_launcher;
+mod finding_triage;
+mod git_remote_reader;
 mod host_token_resolver;
+mod html_exporter;
+mod keyring_secret_store;
+mod logging_reporter;
 mod markdown_renderer;
+mod ndjson_reporter;
+mod notifier;
+mod output_writer;
+mod pdf_exporter;
 mod provider_authenticator;
 mod provider_factory;
+mod report_renderer;
 mod reporter;
+mod response_cache;
+mod review_hooks;
+mod run_history;
+mod run_journal;
 mod system_prompt_resolver;
 mod target_resolver;
+mod template_engine;
+mod traffic_reporter;
+mod update_check_cache;
 mod update_checker;
 mod user_confirmer;
 mod vcs_authenticator;
 mod vcs_factory;
 
+pub use binary_installer::ExeBinaryInstaller;
+pub use clipboard_reader::SystemClipboardReader;
+pub use clock::SystemClock;
 pub use config_repository::JsonConfigRepository;
-pub use host_token_resolver::HostTokenResolverAdapter;
+pub use console_log_switch::TracingConsoleLogSwitch;
+pub use editor_launcher::EnvEditorLauncher;
+pub use finding_triage::{AutoFindingTriage, StdinFindingTriage};
+pub use git_remote_reader::GitCommandRemoteReader;
+pub use host_token_resolver::{HostTokenResolverAdapter, TrafficHostTokenResolver};
+pub use html_exporter::FileHtmlExporter;
+pub use keyring_secret_store::KeyringSecretStore;
+pub use logging_reporter::LoggingReporter;
 pub use markdown_renderer::MarkdownRendererAdapter;
+pub use ndjson_reporter::NdjsonReporter;
+pub use notifier::ChannelNotifier;
+pub use output_writer::FsOutputWriter;
+pub use pdf_exporter::BrowserPdfExporter;
 pub use provider_authenticator::ProviderAuthenticatorAdapter;
-pub use provider_factory::ProviderFactoryAdapter;
+pub use provider_factory::{ProviderFactoryAdapter, TrafficProviderFactory};
+pub use report_renderer::ReportRendererAdapter;
 pub use reporter::ConsoleReporter;
+pub use response_cache::FileResponseCache;
+pub use review_hooks::WasmReviewHookFactory;
+pub use run_history::SqliteRunHistory;
+pub use run_journal::FileRunJournal;
 pub use system_prompt_resolver::FileSystemPromptResolver;
 pub use target_resolver::UrlTargetResolver;
+pub use template_engine::TeraTemplateEngine;
+pub use traffic_reporter::TrafficReporter;
+pub use update_check_cache::FileUpdateCheckCache;
 pub use update_checker::HttpUpdateChecker;
 pub use user_confirmer::{AutoConfirmer, StdinConfirmer};
 pub use vcs_authenticator::VcsAuthenticatorAdapter;
-pub use vcs_factory::VcsFactoryAdapter;
+pub use vcs_factory::{TrafficVcsFactory, VcsFactoryAdapter};
@@ -0,0 +1,102 @@
+//! NDJSON 진행 이벤트 리포터 포트 구현 어댑터(`--progress-format ndjson`).
+//!
+//! 사람이 읽는 콘솔 출력 대신 stdout에 한 줄에 JSON 이벤트 하나를 쓴다.
+//! 예: `{"event":"provider_done","provider":"Claude","secs":41.2}`
+
+use std::io::{self, Write};
+
+use serde_json::{Value, json};
+
+use crate::application::ports::{Reporter, Verbosity};
+
+/// NDJSON 이벤트 리포터 어댑터.
+pub struct NdjsonReporter {
+    verbosity: Verbosity,
+    stderr: bool,
+}
+
+impl Default for NdjsonReporter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NdjsonReporter {
+    pub fn new() -> Self {
+        Self {
+            verbosity: Verbosity::Normal,
+            stderr: false,
+        }
+    }
+
+    /// 결과 문서가 stdout을 쓸 때 이벤트를 stderr로 보낸다.
+    pub fn to_stderr(mut self) -> Self {
+        self.stderr = true;
+        self
+    }
+
+    /// `--verbose`이면 `detail` 이벤트도 내보낸다.
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    fn emit(&self, event: Value) {
+        // 래퍼가 줄 단위로 바로 읽을 수 있도록 이벤트마다 flush한다.
+        if self.stderr {
+            let mut out = io::stderr().lock();
+            let _ = writeln!(out, "{event}");
+            let _ = out.flush();
+        } else {
+            let mut out = io::stdout().lock();
+            let _ = writeln!(out, "{event}");
+            let _ = out.flush();
+        }
+    }
+}
+
+impl Reporter for NdjsonReporter {
+    fn section(&self, name: &str) {
+        self.emit(json!({ "event": "section", "name": name }));
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        self.emit(json!({ "event": "info", "key": key, "value": value }));
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        self.emit(json!({ "event": "status", "scope": scope, "message": message }));
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        let event = match status {
+            "running" => "provider_started",
+            "done" => "provider_done",
+            "error" => "provider_error",
+            _ => "provider_status",
+        };
+        let mut value = json!({ "event": event, "provider": provider });
+        if event == "provider_status" {
+            value["status"] = json!(status);
+        }
+        if let Some(secs) = elapsed_secs {
+            // f32를 그대로 직렬화하면 41.20000076 같은 값이 되므로 0.1초 단위로 맞춘다.
+            value["secs"] = json!((f64::from(secs) * 10.0).round() / 10.0);
+        }
+        self.emit(value);
+    }
+
+    fn raw(&self, line: &str) {
+        self.emit(json!({ "event": "log", "text": line }));
+    }
+
+    fn outcome(&self, line: &str) {
+        self.emit(json!({ "event": "outcome", "text": line }));
+    }
+
+    fn detail(&self, scope: &str, message: &str) {
+        if self.verbosity == Verbosity::Verbose {
+            self.emit(json!({ "event": "detail", "scope": scope, "message": message }));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! 리뷰 결과 알림 포트 구현 어댑터(Slack/Teams/Discord incoming webhook, 이메일).
+//!
+//! 채널마다 payload 형식만 다르고 내용(대상 링크, 심각도별 개수, 추정 비용)은 같다.
+//! - Slack: `{"text": ...}` (mrkdwn)
+//! - Teams: `MessageCard`
+//! - Discord: `{"content": ...}` (Markdown)
+//! - 이메일: 위 요약 + 렌더링된 최종 리뷰 요약 Markdown(text/plain)
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde_json::{Value, json};
+
+use crate::application::config::{EmailConfig, NotificationsConfig};
+use crate::application::ports::{Notifier, ReviewNotification};
+use crate::infrastructure::clock::random_id;
+use crate::infrastructure::config::{resolve_smtp_password, resolve_webhook_url};
+use crate::infrastructure::http::attributed;
+use crate::infrastructure::logging::civil_from_days;
+use crate::infrastructure::smtp::{SmtpSecurity, SmtpServer, is_email_address, send_mail};
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+// 채팅 메시지가 길어지지 않도록 오류 내용(VCS 응답 본문 등)은 앞부분만 보낸다.
+const MAX_ERROR_CHARS: usize = 300;
+
+/// webhook/이메일로 알림을 보내는 어댑터.
+pub struct ChannelNotifier;
+
+#[async_trait]
+impl Notifier for ChannelNotifier {
+    async fn notify(
+        &self,
+        config: &NotificationsConfig,
+        notification: &ReviewNotification,
+    ) -> Result<Vec<String>> {
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()?;
+
+        let mut sent = Vec::new();
+        let mut failures = Vec::new();
+        for (channel, cfg) in config.channels() {
+            if !cfg.wants(notification.succeeded) {
+                continue;
+            }
+            let resolution = resolve_webhook_url(cfg);
+            let Some(url) = resolution.credential else {
+                let source = resolution
+                    .source
+                    .unwrap_or_else(|| "webhook_url is not set".to_string());
+                failures.push(format!("{channel}: {source}"));
+                continue;
+            };
+            match post(&client, &url, &payload(channel, notification)).await {
+                Ok(()) => sent.push(channel.to_string()),
+                Err(err) => failures.push(format!("{channel}: {err:#}")),
+            }
+        }
+        if let Some(email) = config.email_channel()
+            && email.wants(notification.succeeded)
+        {
+            match send_email(email, notification).await {
+                Ok(()) => sent.push("email".to_string()),
+                Err(err) => failures.push(format!("email: {err:#}")),
+            }
+        }
+
+        if !failures.is_empty() {
+            return Err(anyhow!(
+                "failed to send notification ({})",
+                failures.join("; ")
+            ));
+        }
+        Ok(sent)
+    }
+}
+
+async fn post(client: &reqwest::Client, url: &str, payload: &Value) -> Result<()> {
+    let response = attributed(client.post(url))
+        .json(payload)
+        .send()
+        .await
+        // 오류 메시지에 webhook URL(비밀값)이 섞이지 않게 한다.
+        .map_err(|err| anyhow!("request failed: {}", err.without_url()))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        bail!("webhook returned {status}: {}", body.trim());
+    }
+    Ok(())
+}
+
+async fn send_email(cfg: &EmailConfig, notification: &ReviewNotification) -> Result<()> {
+    let Some(host) = cfg
+        .smtp_host
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    else {
+        bail!("smtp_host is not set");
+    };
+    let security = cfg.security();
+    let Some(security) = SmtpSecurity::parse(&security) else {
+        bail!("unsupported smtp_security '{security}'");
+    };
+    let from = cfg.from.as_deref().map(str::trim).unwrap_or_default();
+    if !is_email_address(from) {
+        bail!("from is not a valid email address");
+    }
+    let recipients = cfg.recipients();
+    if recipients.is_empty() {
+        bail!("no recipients (set `to` globally or under repos.<key>.notifications.email)");
+    }
+    if let Some(invalid) = recipients.iter().find(|addr| !is_email_address(addr)) {
+        bail!("invalid recipient address '{invalid}'");
+    }
+
+    let username = cfg
+        .username
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty());
+    let password = match username {
+        Some(_) => {
+            let resolution = resolve_smtp_password(cfg);
+            let Some(password) = resolution.credential else {
+                let source = resolution
+                    .source
+                    .unwrap_or_else(|| "password is not set".to_string());
+                bail!("{source}");
+            };
+            Some(password)
+        }
+        None => None,
+    };
+
+    let server = SmtpServer {
+        host,
+        port: cfg.port(),
+        security,
+        credentials: username.zip(password.as_deref()),
+        ca_file: cfg
+            .smtp_ca_file
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty()),
+    };
+    let message = email_message(cfg, from, &recipients, notification);
+    send_mail(&server, from, &recipients, &message).await
+}
+
+/// text/plain(UTF-8, base64) 메일 한 통. Date/Message-ID가 없으면 거부하거나 스팸으로 보는
+/// MTA가 많아 직접 채운다.
+fn email_message(
+    cfg: &EmailConfig,
+    from: &str,
+    recipients: &[String],
+    notification: &ReviewNotification,
+) -> String {
+    let subject = format!(
+        "{} {}: {}",
+        cfg.subject_prefix().trim(),
+        outcome_label(notification),
+        notification.repository
+    );
+    let mut body = format!(
+        "{}: {}\n{}\n{}\n",
+        headline(notification),
+        notification.repository,
+        notification.target_url,
+        details(notification)
+    );
+    if let Some(summary) = &notification.summary_markdown {
+        body.push('\n');
+        body.push_str(summary.trim_end());
+        body.push('\n');
+    }
+
+    let encoded = STANDARD.encode(body.replace('\n', "\r\n"));
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let domain = from
+        .rsplit_once('@')
+        .map_or("localhost", |(_, domain)| domain);
+    let mut message = format!(
+        "Date: {}\nMessage-ID: <{}.{now}@{domain}>\nFrom: {from}\nTo: {}\nSubject: {}\n\
+         MIME-Version: 1.0\nContent-Type: text/plain; charset=utf-8\n\
+         Content-Transfer-Encoding: base64\n\n",
+        rfc5322_date(now),
+        random_id(),
+        recipients.join(", "),
+        encode_header(subject.trim())
+    );
+    // base64 본문은 76자마다 줄을 나눈다(RFC 2045).
+    for chunk in encoded.as_bytes().chunks(76) {
+        message.push_str(&String::from_utf8_lossy(chunk));
+        message.push('\n');
+    }
+    message
+}
+
+/// unix 초를 `Date` 헤더 형식(`Thu, 15 Oct 2026 14:31:08 +0000`)으로 만든다.
+fn rfc5322_date(secs: u64) -> String {
+    const WEEKDAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    const MONTHS: [&str; 12] = [
+        "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+    ];
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{}, {day:02} {} {year} {:02}:{:02}:{:02} +0000",
+        // 1970-01-01은 목요일이다.
+        WEEKDAYS[(days % 7) as usize],
+        MONTHS[month as usize - 1],
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+// ASCII가 아니면 RFC 2047 encoded-word로 감싼다.
+fn encode_header(value: &str) -> String {
+    if value.is_ascii() {
+        value.to_string()
+    } else {
+        format!("=?UTF-8?B?{}?=", STANDARD.encode(value))
+    }
+}
+
+fn payload(channel: &str, notification: &ReviewNotification) -> Value {
+    let headline = headline(notification);
+    let details = details(notification);
+    match channel {
+        "slack" => json!({
+            "text": format!(
+                "{headline}: <{}|{}>\n{details}",
+                notification.target_url, notification.repository
+            ),
+        }),
+        "teams" => json!({
+            "@type": "MessageCard",
+            "@context": "https://schema.org/extensions",
+            "summary": headline,
+            "themeColor": if notification.succeeded { "2EB67D" } else { "E01E5A" },
+            "title": headline,
+            "text": format!(
+                "[{}]({})<br>{}",
+                notification.repository,
+                notification.target_url,
+                details.replace('\n', "<br>")
+            ),
+        }),
+        _ => json!({
+            "content": format!(
+                "**{headline}**: [{}](<{}>)\n{details}",
+                notification.repository, notification.target_url
+            ),
+        }),
+    }
+}
+
+fn headline(notification: &ReviewNotification) -> String {
+    format!("RepoPilot {}", outcome_label(notification))
+}
+
+fn outcome_label(notification: &ReviewNotification) -> &'static str {
+    match notification.outcome.as_str() {
+        "posted" => "review posted",
+        "gate-failed" => "review posted (severity gate failed)",
+        "cancelled" => "review cancelled",
+        _ => "review failed",
+    }
+}
+
+fn details(notification: &ReviewNotification) -> String {
+    let mut parts = Vec::new();
+    if notification.succeeded {
+        if notification.severity_counts.is_empty() {
+            parts.push("no findings".to_string());
+        } else {
+            parts.push(
+                notification
+                    .severity_counts
+                    .iter()
+                    .map(|(label, count)| format!("{count} {label}"))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            );
+        }
+    }
+    if let Some(cost) = notification.cost_usd {
+        parts.push(format!("est. ${cost:.4}"));
+    }
+    parts.push(format!("{:.1}s", notification.duration_secs));
+    if let Some(sha) = &notification.head_sha {
+        parts.push(format!("`{}`", sha.chars().take(12).collect::<String>()));
+    }
+
+    let mut text = parts.join(" · ");
+    if let Some(error) = &notification.error {
+        let clipped: String = error.chars().take(MAX_ERROR_CHARS).collect();
+        let ellipsis = if clipped.len() < error.len() {
+            "…"
+        } else {
+            ""
+        };
+        text.push_str(&format!("\nerror: {clipped}{ellipsis}"));
+    }
+    text
+}
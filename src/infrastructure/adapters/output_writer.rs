@@ -0,0 +1,23 @@
+//! 리뷰 결과 파일 기록 포트 구현 어댑터.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::application::ports::OutputWriter;
+
+/// 로컬 파일 시스템에 기록하는 어댑터.
+pub struct FsOutputWriter;
+
+impl OutputWriter for FsOutputWriter {
+    fn write(&self, path: &Path, content: &str) -> Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        fs::write(path, content).with_context(|| format!("failed to write {}", path.display()))
+    }
+}
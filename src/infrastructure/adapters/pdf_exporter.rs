@@ -0,0 +1,24 @@
+//! PDF 보고서 내보내기 포트 구현 어댑터.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+use crate::application::ports::PdfExporter;
+use crate::infrastructure::html;
+
+/// 헤드리스 Chromium 계열 브라우저로 인쇄하는 PDF 내보내기 어댑터.
+pub struct BrowserPdfExporter;
+
+impl PdfExporter for BrowserPdfExporter {
+    fn write_pdf(&self, html: &str, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent()
+            && !parent.as_os_str().is_empty()
+        {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        html::write_pdf(html, path)
+    }
+}
@@ -6,7 +6,7 @@ use anyhow::{Context, Result, bail};
 
 use crate::application::config::ProviderConfig;
 use crate::application::ports::{ProviderAuthKind, ProviderAuthenticator};
-use crate::infrastructure::config::command_exists;
+use crate::infrastructure::config::{command_exists, command_program};
 
 /// provider CLI를 통해 OAuth 로그인을 수행한다.
 pub struct ProviderAuthenticatorAdapter;
@@ -79,7 +79,7 @@ Install the CLI or use API mode instead.\n\
         _ => {}
     }
 
-    let status = Command::new(program)
+    let status = Command::new(command_program(program))
         .args(&args)
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
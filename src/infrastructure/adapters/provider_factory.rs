@@ -1,44 +1,123 @@
 //! Provider 포트 구현 어댑터.
 
+use std::sync::Arc;
+
 use anyhow::Result;
 use async_trait::async_trait;
 
 use crate::application::ports::{ProviderAgent, ProviderFactory};
 use crate::domain::review::{ProviderResponse, ReviewRequest};
+use crate::infrastructure::http::HttpClients;
+use crate::infrastructure::throttle::{GlobalThrottle, ThrottlePermit};
+use crate::infrastructure::traffic::TrafficCapture;
 use crate::infrastructure::{config, providers};
 
-/// Provider 팩토리 어댑터.
-pub struct ProviderFactoryAdapter;
+/// Provider 팩토리 어댑터. API 모드 provider는 `http`의 연결 풀을 공유한다.
+/// `defaults.global_concurrency`가 있으면 요청마다 전역 슬롯을 잡는다.
+#[derive(Default)]
+pub struct ProviderFactoryAdapter {
+    pub http: HttpClients,
+}
 
 impl ProviderFactory for ProviderFactoryAdapter {
     fn build(&self, config: &config::Config) -> Vec<Box<dyn ProviderAgent>> {
-        providers::build_providers(config)
+        adapt_providers(providers::build_providers(config, &self.http), config)
+    }
+}
+
+/// provider 호출을 기록/재생하는 팩토리 어댑터(`--record` / `--replay`).
+pub struct TrafficProviderFactory {
+    pub capture: Arc<TrafficCapture>,
+    pub http: HttpClients,
+}
+
+impl ProviderFactory for TrafficProviderFactory {
+    fn build(&self, config: &config::Config) -> Vec<Box<dyn ProviderAgent>> {
+        let providers = &config.providers;
+        for cfg in [&providers.openai, &providers.anthropic, &providers.gemini]
             .into_iter()
-            .map(|inner| Box::new(ProviderAgentAdapter { inner }) as Box<dyn ProviderAgent>)
-            .collect()
+            .flatten()
+            .chain(providers.plugins.values())
+        {
+            if let Some(key) = config::resolve_provider_api_key(cfg).credential {
+                self.capture.add_secret(&key);
+            }
+        }
+        match self
+            .capture
+            .wrap_providers(|| providers::build_providers(config, &self.http))
+        {
+            Ok(providers) => adapt_providers(providers, config),
+            Err(err) => {
+                // provider가 없으면 리뷰 유스케이스가 원인 안내와 함께 실패 처리한다.
+                tracing::warn!("{err:#}");
+                Vec::new()
+            }
+        }
     }
 }
 
+/// 인프라 provider를 포트로 감싼다. 전역 동시 요청 슬롯 설정이 잘못되었으면 경고만 남기고 제한 없이 쓴다.
+fn adapt_providers(
+    providers: Vec<Box<dyn providers::ReviewProvider>>,
+    config: &config::Config,
+) -> Vec<Box<dyn ProviderAgent>> {
+    let throttle = match GlobalThrottle::from_config(config) {
+        Ok(throttle) => throttle.map(Arc::new),
+        Err(err) => {
+            tracing::warn!("global concurrency disabled: {err:#}");
+            None
+        }
+    };
+    providers
+        .into_iter()
+        .map(|inner| {
+            Box::new(ProviderAgentAdapter {
+                inner,
+                throttle: throttle.clone(),
+            }) as Box<dyn ProviderAgent>
+        })
+        .collect()
+}
+
 /// 인프라 Provider를 애플리케이션 포트로 감싸는 래퍼.
 struct ProviderAgentAdapter {
     inner: Box<dyn providers::ReviewProvider>,
+    throttle: Option<Arc<GlobalThrottle>>,
+}
+
+impl ProviderAgentAdapter {
+    /// 요청 동안 쥐고 있을 전역 슬롯(설정하지 않았으면 None).
+    async fn acquire_slot(&self) -> Option<ThrottlePermit> {
+        match &self.throttle {
+            Some(throttle) => throttle.acquire(self.inner.id()).await,
+            None => None,
+        }
+    }
 }
 
 #[async_trait]
 impl ProviderAgent for ProviderAgentAdapter {
-    fn id(&self) -> &'static str {
+    fn id(&self) -> &str {
         self.inner.id()
     }
 
-    fn name(&self) -> &'static str {
+    fn name(&self) -> &str {
         self.inner.name()
     }
 
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
-        self.inner.review(request).await
+        let _slot = self.acquire_slot().await;
+        // 1차 리뷰 응답에서 출력 계약 블록을 분리해 구조화 지적사항으로 변환한다.
+        let response = self.inner.review(request).await?;
+        Ok(providers::attach_findings(
+            response,
+            &request.severity_taxonomy,
+        ))
     }
 
     async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse> {
+        let _slot = self.acquire_slot().await;
         self.inner.review_prompt(prompt).await
     }
 }
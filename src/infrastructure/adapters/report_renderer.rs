@@ -0,0 +1,286 @@
+//! 리뷰 결과 보고서 렌더링 포트 구현 어댑터.
+//!
+//! 리뷰 결과 전체를 Markdown/HTML 보고서, JSON 문서, GitLab Code Quality 리포트로 직렬화한다.
+
+use anyhow::{Context, Result};
+use serde_json::{Value, json};
+
+use crate::application::ports::ReportRenderer;
+use crate::application::usecases::review_pr::ReviewOutcome;
+use crate::domain::policy::{finding_id, stable_hash};
+use crate::domain::review::{AgentComment, Finding, Severity, TokenUsage};
+use crate::domain::template::DEFAULT_AGENT_COMMENT_TEMPLATE;
+use crate::infrastructure::html::{html_document, markdown_to_html};
+use crate::infrastructure::render::render_agent_markdown;
+
+/// 리뷰 결과 보고서 렌더링 어댑터.
+pub struct ReportRendererAdapter;
+
+impl ReportRenderer for ReportRendererAdapter {
+    fn render_markdown(&self, outcome: &ReviewOutcome) -> Result<String> {
+        render_outcome_markdown(outcome)
+    }
+
+    fn render_html(&self, outcome: &ReviewOutcome) -> Result<String> {
+        render_outcome_html(outcome)
+    }
+
+    fn render_json(&self, outcome: &ReviewOutcome) -> Result<String> {
+        render_outcome_json(outcome)
+    }
+
+    fn render_code_quality(&self, outcome: &ReviewOutcome) -> Result<String> {
+        render_outcome_code_quality(outcome)
+    }
+}
+
+/// 리뷰 결과 전체(개별 에이전트 코멘트 + 최종 요약)를 하나의 Markdown 문서로 만든다.
+fn render_outcome_markdown(outcome: &ReviewOutcome) -> Result<String> {
+    let mut parts: Vec<String> = outcome
+        .primary_results
+        .iter()
+        .map(|run| {
+            render_agent_markdown(
+                &outcome.head_sha,
+                &outcome.target_url,
+                &AgentComment::from_run(run),
+                DEFAULT_AGENT_COMMENT_TEMPLATE,
+            )
+        })
+        .collect::<Result<_>>()?;
+    parts.push(outcome.summary_markdown.trim_end().to_string() + "\n");
+    Ok(parts.join("\n---\n\n"))
+}
+
+/// 리뷰 결과 전체를 VCS 밖에서 공유할 단독 HTML 보고서로 만든다(최종 요약 다음에 에이전트별 리뷰).
+fn render_outcome_html(outcome: &ReviewOutcome) -> Result<String> {
+    let mut body = markdown_to_html(&outcome.summary_markdown);
+    for run in &outcome.primary_results {
+        let agent = render_agent_markdown(
+            &outcome.head_sha,
+            &outcome.target_url,
+            &AgentComment::from_run(run),
+            DEFAULT_AGENT_COMMENT_TEMPLATE,
+        )?;
+        body.push_str("<hr>\n<section>\n");
+        body.push_str(&markdown_to_html(&agent));
+        body.push_str("</section>\n");
+    }
+    Ok(html_document(
+        &format!("Review report: {}", outcome.target_url),
+        &body,
+    ))
+}
+
+/// 리뷰 결과를 다른 도구가 소비할 수 있는 JSON 문서로 직렬화한다.
+fn render_outcome_json(outcome: &ReviewOutcome) -> Result<String> {
+    let agents: Vec<Value> = outcome
+        .primary_results
+        .iter()
+        .map(|run| {
+            json!({
+                "id": run.id,
+                "name": run.name,
+                "body": run.body,
+                "usage": usage_json(&run.usage),
+                "findings": run.findings.iter().map(finding_json).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    let consensus: Vec<Value> = outcome
+        .summary
+        .consensus
+        .iter()
+        .map(|item| {
+            let mut value = finding_json(&item.finding);
+            value["agents"] = json!(item.agents);
+            value["id"] = json!(finding_id(&item.finding));
+            value
+        })
+        .collect();
+
+    let reactions: Vec<Value> = outcome
+        .summary
+        .reactions
+        .iter()
+        .map(|r| {
+            json!({
+                "provider_id": r.provider_id,
+                "provider_name": r.provider_name,
+                "round": r.round,
+                "body": r.body,
+            })
+        })
+        .collect();
+
+    let usage_totals: Vec<Value> = outcome
+        .usage_breakdown()
+        .iter()
+        .map(|row| {
+            json!({
+                "id": row.provider_id,
+                "name": row.provider_name,
+                "usage": usage_json(&row.total),
+                "primary": usage_json(&row.primary),
+                "reaction": usage_json(&row.reaction),
+            })
+        })
+        .collect();
+
+    let document = json!({
+        "target_url": outcome.target_url,
+        "head_sha": outcome.head_sha,
+        "skipped": outcome.skipped,
+        "agents": agents,
+        "consensus": consensus,
+        "consolidated": outcome.summary.consolidated.as_ref().map(|c| json!({
+            "provider_name": c.provider_name,
+            "body": c.body,
+        })),
+        "stage_reviews": outcome
+            .summary
+            .stage_reviews
+            .iter()
+            .map(|s| json!({
+                "stage": s.stage.code(),
+                "provider_name": s.provider_name,
+                "body": s.body,
+                "findings": s.findings.iter().map(finding_json).collect::<Vec<_>>(),
+            }))
+            .collect::<Vec<_>>(),
+        "follow_up_issues": outcome
+            .summary
+            .follow_up_issues
+            .iter()
+            .map(|i| json!({
+                "finding_id": i.finding_id,
+                "title": i.title,
+                "url": i.url,
+                "created": i.created,
+            }))
+            .collect::<Vec<_>>(),
+        "suggested_reviewers": outcome
+            .summary
+            .suggested_reviewers
+            .iter()
+            .map(|r| json!({
+                "handle": r.handle,
+                "owned_paths": r.owned_paths,
+                "recent_paths": r.recent_paths,
+                "requested": r.requested,
+            }))
+            .collect::<Vec<_>>(),
+        "progress": outcome.summary.progress.as_ref().map(|p| json!({
+            "previous_sha": p.previous_sha,
+            "resolved": p
+                .resolved
+                .iter()
+                .map(|f| json!({
+                    "id": f.id,
+                    "severity": f.severity.code(),
+                    "file": f.file,
+                    "title": f.title,
+                }))
+                .collect::<Vec<_>>(),
+            "still_open": p.still_open.iter().map(finding_json).collect::<Vec<_>>(),
+            "new": p.new.iter().map(finding_json).collect::<Vec<_>>(),
+        })),
+        "autofix": outcome.autofix.as_ref().map(|a| json!({
+            "provider_name": a.provider_name,
+            "finding_ids": a.finding_ids,
+            "files": a.files,
+            "branch_url": a.branch_url,
+            "patch": a.patch,
+        })),
+        "reactions": reactions,
+        "usage": usage_totals,
+        "comments": {
+            "summary": outcome.summary_comment_id,
+            "agents": outcome
+                .summary
+                .agent_comment_refs
+                .iter()
+                .map(|(name, id)| json!({ "name": name, "id": id }))
+                .collect::<Vec<_>>(),
+        },
+        "gate": outcome.gate.as_ref().map(|g| json!({
+            "threshold": g.threshold.code(),
+            "count": g.count,
+        })),
+        "summary_markdown": outcome.summary_markdown,
+    });
+
+    serde_json::to_string_pretty(&document).context("failed to serialize review outcome")
+}
+
+/// 합의 지적사항을 GitLab Code Quality 리포트(JSON 배열)로 렌더링한다.
+/// 위치(파일)가 없는 지적사항은 MR 위젯에 표시할 수 없으므로 제외한다.
+fn render_outcome_code_quality(outcome: &ReviewOutcome) -> Result<String> {
+    let issues: Vec<Value> = outcome
+        .summary
+        .consensus
+        .iter()
+        .filter_map(|item| {
+            let finding = &item.finding;
+            let path = finding.file.as_deref()?;
+            let begin = finding.line_range.map(|r| r.start).unwrap_or(1);
+            let description = if item.agents.is_empty() {
+                finding.title.clone()
+            } else {
+                format!("{} ({})", finding.title, item.agents.join(", "))
+            };
+            Some(json!({
+                "type": "issue",
+                "check_name": format!("repopilot/{}", finding.severity.code()),
+                "description": description,
+                "content": { "body": finding.body },
+                "categories": ["Bug Risk"],
+                "severity": code_quality_severity(finding.severity),
+                "fingerprint": code_quality_fingerprint(path, begin, &finding.title),
+                "location": {
+                    "path": path,
+                    "lines": { "begin": begin },
+                },
+            }))
+        })
+        .collect();
+
+    serde_json::to_string_pretty(&issues).context("failed to serialize code quality report")
+}
+
+fn code_quality_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "critical",
+        Severity::Major => "major",
+        Severity::Minor => "minor",
+        Severity::Suggestion => "info",
+    }
+}
+
+/// 실행 간 안정적인 지문. GitLab은 이 값으로 신규/해결 이슈를 비교한다.
+fn code_quality_fingerprint(path: &str, line: u32, title: &str) -> String {
+    let hash = stable_hash(&format!("{path}:{line}:{}", title.trim().to_lowercase()));
+    format!("{hash:016x}")
+}
+
+fn finding_json(finding: &Finding) -> Value {
+    json!({
+        "file": finding.file,
+        "line_start": finding.line_range.map(|r| r.start),
+        "line_end": finding.line_range.map(|r| r.end),
+        "severity": finding.severity.code(),
+        "title": finding.title,
+        "body": finding.body,
+        "confidence": finding.confidence,
+        "suggestion": finding.suggestion,
+    })
+}
+
+fn usage_json(usage: &TokenUsage) -> Value {
+    json!({
+        "prompt_tokens": usage.prompt_tokens,
+        "completion_tokens": usage.completion_tokens,
+        "total_tokens": usage.total_tokens,
+    })
+}
@@ -1,10 +1,10 @@
 //! 콘솔 리포터 포트 구현 어댑터.
 
 use std::collections::BTreeMap;
-use std::io::{self, IsTerminal, Write};
+use std::io::{self, Write};
 use std::sync::Mutex;
 
-use crate::application::ports::Reporter;
+use crate::application::ports::{Reporter, Verbosity};
 
 #[derive(Default)]
 struct ProviderPanelState {
@@ -16,7 +16,11 @@ struct ProviderPanelState {
 /// 콘솔 전용 리포터 어댑터.
 pub struct ConsoleReporter {
     interactive: bool,
+    color: bool,
     provider_panel_enabled: bool,
+    verbosity: Verbosity,
+    // stdout을 결과 출력(`--format json` 등)에 양보해야 하면 진행 출력을 stderr로 보낸다.
+    stderr: bool,
     state: Mutex<ProviderPanelState>,
 }
 
@@ -27,7 +31,7 @@ impl Default for ConsoleReporter {
 }
 
 impl ConsoleReporter {
-    /// stdout이 TTY일 때 실시간 상태판 모드를 활성화한다.
+    /// 일반 텍스트로 출력하는 리포터를 만든다. 터미널 기능은 [`Self::with_terminal`]로 지정한다.
     pub fn new() -> Self {
         Self::with_provider_panel(true)
     }
@@ -35,12 +39,48 @@ impl ConsoleReporter {
     /// REPL UI와 충돌을 피해야 할 때 provider 상태판을 비활성화할 수 있다.
     pub fn with_provider_panel(enabled: bool) -> Self {
         Self {
-            interactive: io::stdout().is_terminal(),
+            interactive: false,
+            color: false,
             provider_panel_enabled: enabled,
+            verbosity: Verbosity::Normal,
+            stderr: false,
             state: Mutex::new(ProviderPanelState::default()),
         }
     }
 
+    /// 진행 출력을 stderr로 보낸다. 상태판과 색상은 stdout 기준으로 판단한 것이므로 함께 끈다.
+    pub fn to_stderr(mut self) -> Self {
+        self.stderr = true;
+        self.interactive = false;
+        self.color = false;
+        self
+    }
+
+    /// 터미널 기능을 지정한다. `ansi`이면 실시간 상태판을, `color`이면 상태 색상을 쓴다.
+    pub fn with_terminal(mut self, ansi: bool, color: bool) -> Self {
+        self.interactive = ansi;
+        self.color = color;
+        self
+    }
+
+    /// 출력 상세도를 지정한다(`--quiet`/`--verbose`).
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    fn quiet(&self) -> bool {
+        self.verbosity == Verbosity::Quiet
+    }
+
+    fn line(&self, line: std::fmt::Arguments<'_>) {
+        if self.stderr {
+            eprintln!("{line}");
+        } else {
+            println!("{line}");
+        }
+    }
+
     fn set_section(&self, name: &str) {
         if !self.interactive {
             return;
@@ -53,6 +93,20 @@ impl ConsoleReporter {
         }
     }
 
+    fn colorize_status(&self, status: &str) -> String {
+        let sgr = match status {
+            "running" => "33",
+            "done" => "32",
+            "error" => "31",
+            _ => return status.to_string(),
+        };
+        if self.color {
+            format!("\x1b[{sgr}m{status}\x1b[0m")
+        } else {
+            status.to_string()
+        }
+    }
+
     fn render_provider_panel(&self, state: &mut ProviderPanelState) {
         let mut out = io::stdout();
         if state.rendered_lines > 0 {
@@ -62,7 +116,7 @@ impl ConsoleReporter {
         let mut lines = Vec::new();
         lines.push("┌──────────────── Provider Status ────────────────┐".to_string());
         for (provider, (status, extra)) in &state.rows {
-            let status_colored = colorize_status(status);
+            let status_colored = self.colorize_status(status);
             let extra_text = extra.as_deref().unwrap_or("-");
             lines.push(format!(
                 "│ {:<14} {:<16} {:<18} │",
@@ -81,20 +135,33 @@ impl ConsoleReporter {
 
 impl Reporter for ConsoleReporter {
     fn section(&self, name: &str) {
+        if self.quiet() {
+            return;
+        }
         self.set_section(name);
-        println!();
-        println!("==================== {} ====================", name);
+        self.line(format_args!(""));
+        self.line(format_args!(
+            "==================== {} ====================",
+            name
+        ));
     }
 
     fn kv(&self, key: &str, value: &str) {
-        println!("{:<12}: {}", key, value);
+        if !self.quiet() {
+            self.line(format_args!("{:<12}: {}", key, value));
+        }
     }
 
     fn status(&self, scope: &str, message: &str) {
-        println!("[{:<12}] {}", scope, message);
+        if !self.quiet() {
+            self.line(format_args!("[{:<12}] {}", scope, message));
+        }
     }
 
-    fn provider_status(&self, provider: &str, status: &str, extra: Option<&str>) {
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        if self.quiet() {
+            return;
+        }
         if self.interactive
             && self.provider_panel_enabled
             && let Ok(mut state) = self.state.lock()
@@ -102,28 +169,41 @@ impl Reporter for ConsoleReporter {
         {
             state.rows.insert(
                 provider.to_string(),
-                (status.to_string(), extra.map(|s| s.to_string())),
+                (status.to_string(), elapsed_secs.map(format_elapsed)),
             );
             self.render_provider_panel(&mut state);
             return;
         }
 
-        match extra {
-            Some(extra) => println!("[provider:{:<12}] {:<7} {}", provider, status, extra),
-            None => println!("[provider:{:<12}] {}", provider, status),
+        match elapsed_secs {
+            Some(secs) => self.line(format_args!(
+                "[provider:{:<12}] {:<7} {}",
+                provider,
+                status,
+                format_elapsed(secs)
+            )),
+            None => self.line(format_args!("[provider:{:<12}] {}", provider, status)),
         }
     }
 
     fn raw(&self, line: &str) {
-        println!("{}", line);
+        if !self.quiet() {
+            self.line(format_args!("{}", line));
+        }
+    }
+
+    fn outcome(&self, line: &str) {
+        self.line(format_args!("{}", line));
     }
-}
 
-fn colorize_status(status: &str) -> String {
-    match status {
-        "running" => format!("\x1b[33m{status}\x1b[0m"),
-        "done" => format!("\x1b[32m{status}\x1b[0m"),
-        "error" => format!("\x1b[31m{status}\x1b[0m"),
-        _ => status.to_string(),
+    fn detail(&self, scope: &str, message: &str) {
+        if self.verbosity == Verbosity::Verbose {
+            self.line(format_args!("[detail:{:<10}] {}", scope, message));
+        }
     }
 }
+
+/// 콘솔 상태 표시용 소요 시간(예: `41.2s`).
+fn format_elapsed(secs: f32) -> String {
+    format!("{secs:.1}s")
+}
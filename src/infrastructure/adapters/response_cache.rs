@@ -0,0 +1,64 @@
+//! provider 응답 캐시 포트 구현 어댑터.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{CachedResponse, ResponseCache};
+use crate::infrastructure::state;
+
+/// 캐시 디렉터리(`~/.cache/repopilot/responses/<key>.json`) 기반 응답 캐시 어댑터.
+pub struct FileResponseCache;
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    saved_at: u64,
+    content: String,
+}
+
+impl ResponseCache for FileResponseCache {
+    fn load(&self, key: &str) -> Result<Option<CachedResponse>> {
+        let Some(path) = cache_path(key) else {
+            return Ok(None);
+        };
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        // 손상된 캐시는 없는 것으로 보고 provider를 다시 호출한다.
+        let Ok(file) = serde_json::from_str::<CacheFile>(&raw) else {
+            return Ok(None);
+        };
+        Ok(Some(CachedResponse {
+            saved_at: file.saved_at,
+            content: file.content,
+        }))
+    }
+
+    fn save(&self, key: &str, response: &CachedResponse) -> Result<()> {
+        let Some(path) = cache_path(key) else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let file = CacheFile {
+            saved_at: response.saved_at,
+            content: response.content.clone(),
+        };
+        fs::write(&path, serde_json::to_string(&file)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+fn cache_path(key: &str) -> Option<PathBuf> {
+    Some(
+        state::cache_dir()?
+            .join("responses")
+            .join(format!("{key}.json")),
+    )
+}
@@ -0,0 +1,210 @@
+//! 정책 훅 포트 구현 어댑터(`defaults.pre_prompt_hook` / `defaults.post_findings_hook`).
+//!
+//! 훅은 `wasm-hooks` 기능으로 빌드했을 때 WASM 모듈로 실행한다(ABI는 `infrastructure::wasm_hook`).
+//! - `pre_prompt` 입력: `{"hook_version": 1, "target_url", "head_sha", "title", "chunk_index",
+//!   "chunk_total", "files", "diff", "system_prompt", "review_guide"}`
+//! - `pre_prompt` 출력: 바꿀 필드(`title`/`diff`/`system_prompt`/`review_guide`)만 담은 객체,
+//!   또는 리뷰를 막을 때 `{"reject": 사유}`
+//! - `post_findings` 입력: `{"hook_version": 1, "target_url", "head_sha", "provider", "findings": [...]}`
+//! - `post_findings` 출력: `{"findings": [...]}`(남길 지적사항 전체)
+
+use anyhow::Result;
+
+use crate::application::config::Config;
+use crate::application::ports::{ReviewHookFactory, ReviewHooks};
+
+/// 설정된 WASM 모듈을 불러와 훅을 만드는 팩토리 어댑터.
+pub struct WasmReviewHookFactory;
+
+impl ReviewHookFactory for WasmReviewHookFactory {
+    fn build(&self, config: &Config) -> Result<Option<Box<dyn ReviewHooks>>> {
+        let pre_prompt = hook_path(config.defaults.pre_prompt_hook.as_deref());
+        let post_findings = hook_path(config.defaults.post_findings_hook.as_deref());
+        if pre_prompt.is_none() && post_findings.is_none() {
+            return Ok(None);
+        }
+        build_hooks(pre_prompt, post_findings)
+    }
+}
+
+fn hook_path(raw: Option<&str>) -> Option<&str> {
+    raw.map(str::trim).filter(|path| !path.is_empty())
+}
+
+#[cfg(not(feature = "wasm-hooks"))]
+fn build_hooks(
+    pre_prompt: Option<&str>,
+    _post_findings: Option<&str>,
+) -> Result<Option<Box<dyn ReviewHooks>>> {
+    let key = if pre_prompt.is_some() {
+        "pre_prompt_hook"
+    } else {
+        "post_findings_hook"
+    };
+    anyhow::bail!(
+        "defaults.{key} needs a repopilot build with the `wasm-hooks` feature (cargo build --release --features wasm-hooks)"
+    )
+}
+
+#[cfg(feature = "wasm-hooks")]
+fn build_hooks(
+    pre_prompt: Option<&str>,
+    post_findings: Option<&str>,
+) -> Result<Option<Box<dyn ReviewHooks>>> {
+    use crate::infrastructure::wasm_hook::WasmHookModule;
+
+    Ok(Some(Box::new(wasm::WasmReviewHooks {
+        pre_prompt: pre_prompt.map(WasmHookModule::load).transpose()?,
+        post_findings: post_findings.map(WasmHookModule::load).transpose()?,
+    })))
+}
+
+#[cfg(feature = "wasm-hooks")]
+mod wasm {
+    use std::sync::Arc;
+
+    use anyhow::{Context, Result, bail};
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::application::ports::ReviewHooks;
+    use crate::domain::review::{Finding, LineRange, ReviewRequest, Severity};
+    use crate::infrastructure::wasm_hook::WasmHookModule;
+
+    const HOOK_VERSION: u64 = 1;
+
+    pub(super) struct WasmReviewHooks {
+        pub(super) pre_prompt: Option<WasmHookModule>,
+        pub(super) post_findings: Option<WasmHookModule>,
+    }
+
+    #[derive(Deserialize)]
+    struct PrePromptOutput {
+        reject: Option<String>,
+        title: Option<String>,
+        diff: Option<String>,
+        system_prompt: Option<String>,
+        review_guide: Option<String>,
+    }
+
+    #[derive(Deserialize)]
+    struct PostFindingsOutput {
+        findings: Vec<HookFinding>,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct HookFinding {
+        #[serde(default)]
+        file: Option<String>,
+        #[serde(default)]
+        line_start: Option<u32>,
+        #[serde(default)]
+        line_end: Option<u32>,
+        severity: String,
+        title: String,
+        #[serde(default)]
+        body: String,
+        #[serde(default)]
+        confidence: Option<f32>,
+        #[serde(default)]
+        suggestion: Option<String>,
+    }
+
+    impl HookFinding {
+        fn from_finding(f: &Finding) -> Self {
+            Self {
+                file: f.file.clone(),
+                line_start: f.line_range.map(|r| r.start),
+                line_end: f.line_range.map(|r| r.end),
+                severity: f.severity.code().to_string(),
+                title: f.title.clone(),
+                body: f.body.clone(),
+                confidence: f.confidence,
+                suggestion: f.suggestion.clone(),
+            }
+        }
+
+        fn into_finding(self) -> Finding {
+            Finding {
+                file: self.file,
+                line_range: match (self.line_start, self.line_end) {
+                    (Some(start), Some(end)) => Some(LineRange { start, end }),
+                    (Some(start), None) => Some(LineRange { start, end: start }),
+                    _ => None,
+                },
+                severity: Severity::parse(&self.severity).unwrap_or(Severity::Minor),
+                title: self.title,
+                body: self.body,
+                confidence: self.confidence,
+                suggestion: self.suggestion,
+            }
+        }
+    }
+
+    impl ReviewHooks for WasmReviewHooks {
+        fn pre_prompt(&self, request: &mut ReviewRequest) -> Result<()> {
+            let Some(module) = &self.pre_prompt else {
+                return Ok(());
+            };
+            let input = json!({
+                "hook_version": HOOK_VERSION,
+                "target_url": request.target_url,
+                "head_sha": request.head_sha,
+                "title": request.title,
+                "chunk_index": request.chunk_index,
+                "chunk_total": request.chunk_total,
+                "files": request.chunk_files,
+                "diff": &*request.diff,
+                "system_prompt": request.system_prompt,
+                "review_guide": request.review_guide,
+            });
+            let raw = module.call("pre_prompt", &input.to_string())?;
+            let output: PrePromptOutput = serde_json::from_str(&raw).with_context(|| {
+                format!("pre_prompt hook {} returned invalid JSON", module.path())
+            })?;
+            if let Some(reason) = output.reject {
+                bail!("pre_prompt hook rejected the review: {reason}");
+            }
+            if let Some(title) = output.title {
+                request.title = title;
+            }
+            if let Some(diff) = output.diff {
+                request.diff = Arc::from(diff);
+            }
+            if let Some(system_prompt) = output.system_prompt {
+                request.system_prompt = system_prompt;
+            }
+            if let Some(review_guide) = output.review_guide {
+                request.review_guide = review_guide;
+            }
+            Ok(())
+        }
+
+        fn post_findings(
+            &self,
+            request: &ReviewRequest,
+            provider_id: &str,
+            findings: Vec<Finding>,
+        ) -> Result<Vec<Finding>> {
+            let Some(module) = &self.post_findings else {
+                return Ok(findings);
+            };
+            let input = json!({
+                "hook_version": HOOK_VERSION,
+                "target_url": request.target_url,
+                "head_sha": request.head_sha,
+                "provider": provider_id,
+                "findings": findings.iter().map(HookFinding::from_finding).collect::<Vec<_>>(),
+            });
+            let raw = module.call("post_findings", &input.to_string())?;
+            let output: PostFindingsOutput = serde_json::from_str(&raw).with_context(|| {
+                format!("post_findings hook {} returned invalid JSON", module.path())
+            })?;
+            Ok(output
+                .findings
+                .into_iter()
+                .map(HookFinding::into_finding)
+                .collect())
+        }
+    }
+}
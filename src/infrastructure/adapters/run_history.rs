@@ -0,0 +1,28 @@
+//! 실행 기록 포트 구현 어댑터.
+
+use anyhow::Result;
+
+use crate::application::ports::{RecentRun, RunHistory, RunRecord, RunStats};
+use crate::domain::review::PreviousReview;
+use crate::infrastructure::storage;
+
+/// 상태 디렉터리 SQLite 파일 기반 실행 기록 어댑터.
+pub struct SqliteRunHistory;
+
+impl RunHistory for SqliteRunHistory {
+    fn record(&self, run: &RunRecord) -> Result<()> {
+        storage::record(run)
+    }
+
+    fn stats(&self, since: Option<u64>) -> Result<RunStats> {
+        storage::stats(since)
+    }
+
+    fn recent(&self, limit: usize) -> Result<Vec<RecentRun>> {
+        storage::recent(limit)
+    }
+
+    fn previous_review(&self, target_url: &str, head_sha: &str) -> Result<Option<PreviousReview>> {
+        storage::previous_review(target_url, head_sha)
+    }
+}
@@ -0,0 +1,23 @@
+//! 실행 저널 포트 구현 어댑터.
+
+use anyhow::Result;
+
+use crate::application::ports::{RunJournal, RunJournalEntry};
+use crate::infrastructure::journal;
+
+/// `$XDG_STATE_HOME/repopilot/runs/` JSON 파일 기반 실행 저널 어댑터.
+pub struct FileRunJournal;
+
+impl RunJournal for FileRunJournal {
+    fn save(&self, entry: &RunJournalEntry) -> Result<String> {
+        journal::save(entry).map(|path| path.display().to_string())
+    }
+
+    fn load(&self, target_url: &str) -> Result<Option<RunJournalEntry>> {
+        journal::load(target_url)
+    }
+
+    fn remove(&self, target_url: &str) -> Result<()> {
+        journal::remove(target_url)
+    }
+}
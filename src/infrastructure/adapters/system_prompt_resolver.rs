@@ -1,33 +1,151 @@
-//! 시스템 프롬프트 해석 포트 구현(리뷰 가이드 파일 포함).
+//! 시스템 프롬프트 해석 포트 구현(리뷰 가이드/경로별 가이드/프롬프트 템플릿 파일 포함).
 
 use std::fs;
+use std::path::Path;
 
 use anyhow::{Context, Result};
 
 use crate::application::config::Config;
 use crate::application::ports::SystemPromptResolver;
+use crate::domain::template::{
+    AGENT_COMMENT_TEMPLATE_SCHEMA, AUTOFIX_TEMPLATE_SCHEMA, CLAIM_COMMENT_TEMPLATE_SCHEMA,
+    CROSS_AGENT_TEMPLATE_SCHEMA, CommentTemplates, FINAL_COMMENT_TEMPLATE_SCHEMA,
+    META_REVIEW_TEMPLATE_SCHEMA, MODERATOR_TEMPLATE_SCHEMA, PRIMARY_TEMPLATE_SCHEMA,
+    PromptTemplates, TEST_GAPS_TEMPLATE_SCHEMA,
+};
+use crate::infrastructure::template::validate_template;
 
-/// 설정의 `review_guide_path`를 읽어 시스템 프롬프트에 합성한다.
+/// 설정의 `review_guide_path`, `prompt_template_dir`, `comment_template_dir` 파일을 읽는다.
 pub struct FileSystemPromptResolver;
 
 impl SystemPromptResolver for FileSystemPromptResolver {
     fn resolve(&self, config: &Config) -> Result<String> {
-        let mut prompt = config.system_prompt();
+        Ok(config.system_prompt())
+    }
 
+    fn review_guide(&self, config: &Config) -> Result<Option<String>> {
         let Some(path) = config.defaults.review_guide_path.as_deref() else {
-            return Ok(prompt);
+            return Ok(None);
         };
 
         let guide_raw = fs::read_to_string(path)
             .with_context(|| format!("failed to read review guide file at {}", path))?;
         let guide = guide_raw.trim();
         if guide.is_empty() {
-            return Ok(prompt);
+            return Ok(None);
         }
+        Ok(Some(guide.to_string()))
+    }
 
-        prompt.push_str("\n\nReview guide (must follow):\n");
-        prompt.push_str(guide);
-        Ok(prompt)
+    fn path_guide(&self, path: &str) -> Result<Option<String>> {
+        let guide_raw = fs::read_to_string(path)
+            .with_context(|| format!("failed to read path guide file at {}", path))?;
+        let guide = guide_raw.trim();
+        if guide.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(guide.to_string()))
     }
-}
 
+    fn prompt_templates(&self, config: &Config) -> Result<PromptTemplates> {
+        let mut templates = PromptTemplates::default();
+        let Some(dir) = config.defaults.prompt_template_dir.as_deref() else {
+            return Ok(templates);
+        };
+
+        // 디렉터리에 있는 파일만 기본 템플릿을 대체한다.
+        let dir = Path::new(dir);
+        if !dir.is_dir() {
+            anyhow::bail!("prompt_template_dir {} is not a directory", dir.display());
+        }
+        // 리뷰 도중이 아니라 불러올 때 문법 오류와 모르는 변수를 드러낸다.
+        for (file, slot, schema) in [
+            (
+                "primary.md",
+                &mut templates.primary,
+                PRIMARY_TEMPLATE_SCHEMA,
+            ),
+            (
+                "cross_agent.md",
+                &mut templates.cross_agent,
+                CROSS_AGENT_TEMPLATE_SCHEMA,
+            ),
+            (
+                "moderator.md",
+                &mut templates.moderator,
+                MODERATOR_TEMPLATE_SCHEMA,
+            ),
+            (
+                "meta_review.md",
+                &mut templates.meta_review,
+                META_REVIEW_TEMPLATE_SCHEMA,
+            ),
+            (
+                "security.md",
+                &mut templates.security,
+                PRIMARY_TEMPLATE_SCHEMA,
+            ),
+            (
+                "test_gaps.md",
+                &mut templates.test_gaps,
+                TEST_GAPS_TEMPLATE_SCHEMA,
+            ),
+            (
+                "autofix.md",
+                &mut templates.autofix,
+                AUTOFIX_TEMPLATE_SCHEMA,
+            ),
+        ] {
+            let path = dir.join(file);
+            if path.is_file() {
+                let template = fs::read_to_string(&path).with_context(|| {
+                    format!("failed to read prompt template {}", path.display())
+                })?;
+                validate_template(&template, &schema)
+                    .with_context(|| format!("invalid prompt template {}", path.display()))?;
+                *slot = template;
+            }
+        }
+        Ok(templates)
+    }
+
+    fn comment_templates(&self, config: &Config) -> Result<CommentTemplates> {
+        let mut templates = CommentTemplates::default();
+        let Some(dir) = config.defaults.comment_template_dir.as_deref() else {
+            return Ok(templates);
+        };
+
+        let dir = Path::new(dir);
+        if !dir.is_dir() {
+            anyhow::bail!("comment_template_dir {} is not a directory", dir.display());
+        }
+        for (file, slot, schema) in [
+            (
+                "claim.md",
+                &mut templates.claim,
+                CLAIM_COMMENT_TEMPLATE_SCHEMA,
+            ),
+            (
+                "agent.md",
+                &mut templates.agent,
+                AGENT_COMMENT_TEMPLATE_SCHEMA,
+            ),
+            (
+                "final.md",
+                &mut templates.final_summary,
+                FINAL_COMMENT_TEMPLATE_SCHEMA,
+            ),
+        ] {
+            let path = dir.join(file);
+            if path.is_file() {
+                let template = fs::read_to_string(&path).with_context(|| {
+                    format!("failed to read comment template {}", path.display())
+                })?;
+                validate_template(&template, &schema)
+                    .with_context(|| format!("invalid comment template {}", path.display()))?;
+                *slot = template;
+            }
+        }
+        Ok(templates)
+    }
+}
@@ -0,0 +1,20 @@
+//! 템플릿 엔진 포트 구현 어댑터.
+
+use anyhow::Result;
+
+use crate::application::ports::TemplateEngine;
+use crate::domain::template::{TemplateSchema, TemplateVars};
+use crate::infrastructure::template;
+
+/// Tera 문법으로 렌더링/검사하는 템플릿 엔진 어댑터.
+pub struct TeraTemplateEngine;
+
+impl TemplateEngine for TeraTemplateEngine {
+    fn render(&self, template: &str, vars: &TemplateVars<'_>) -> Result<String> {
+        template::render_template(template, vars)
+    }
+
+    fn validate(&self, template: &str, schema: &TemplateSchema) -> Result<()> {
+        template::validate_template(template, schema)
+    }
+}
@@ -0,0 +1,63 @@
+//! 진행 출력을 트래픽 기록 디렉터리에도 남기는 리포터 데코레이터(`--record` / `--replay`).
+//!
+//! 기록 실행과 재생 실행의 출력을 같은 형식으로 남겨 diff로 비교할 수 있게 한다.
+//! 실행마다 달라지는 소요 시간과 `--verbose` 진단 정보는 남기지 않는다.
+
+use std::sync::Arc;
+
+use crate::application::ports::Reporter;
+use crate::domain::review::TokenUsage;
+use crate::infrastructure::traffic::TrafficCapture;
+
+/// 내부 리포터를 감싸 진행 출력을 트래픽 기록 디렉터리에 남긴다.
+pub struct TrafficReporter {
+    inner: Box<dyn Reporter>,
+    capture: Arc<TrafficCapture>,
+}
+
+impl TrafficReporter {
+    pub fn new(inner: Box<dyn Reporter>, capture: Arc<TrafficCapture>) -> Self {
+        Self { inner, capture }
+    }
+}
+
+impl Reporter for TrafficReporter {
+    fn section(&self, name: &str) {
+        self.capture.record_output(&format!("== {name}"));
+        self.inner.section(name);
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        self.capture.record_output(&format!("{key}: {value}"));
+        self.inner.kv(key, value);
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        self.capture.record_output(&format!("[{scope}] {message}"));
+        self.inner.status(scope, message);
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        self.capture
+            .record_output(&format!("[provider] {provider} {status}"));
+        self.inner.provider_status(provider, status, elapsed_secs);
+    }
+
+    fn provider_output(&self, provider: &str, body: &str, usage: &TokenUsage) {
+        self.inner.provider_output(provider, body, usage);
+    }
+
+    fn raw(&self, line: &str) {
+        self.capture.record_output(line);
+        self.inner.raw(line);
+    }
+
+    fn outcome(&self, line: &str) {
+        self.capture.record_output(line);
+        self.inner.outcome(line);
+    }
+
+    fn detail(&self, scope: &str, message: &str) {
+        self.inner.detail(scope, message);
+    }
+}
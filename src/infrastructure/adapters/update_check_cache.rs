@@ -0,0 +1,59 @@
+//! 업데이트 확인 캐시 포트 구현 어댑터.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::{UpdateCheckCache, UpdateCheckRecord};
+use crate::infrastructure::state;
+
+const CACHE_FILE: &str = "update-check.json";
+
+/// 상태 디렉터리(`~/.local/state/repopilot/update-check.json`) 기반 캐시 어댑터.
+pub struct FileUpdateCheckCache;
+
+#[derive(Serialize, Deserialize)]
+struct CacheFile {
+    checked_at: u64,
+    source: String,
+    latest_version: Option<String>,
+    download_url: Option<String>,
+}
+
+impl UpdateCheckCache for FileUpdateCheckCache {
+    fn load(&self) -> Option<UpdateCheckRecord> {
+        let raw = fs::read_to_string(cache_path()?).ok()?;
+        // 손상된 캐시는 없는 것으로 보고 다시 조회한다.
+        let file: CacheFile = serde_json::from_str(&raw).ok()?;
+        Some(UpdateCheckRecord {
+            checked_at: file.checked_at,
+            source: file.source,
+            latest_version: file.latest_version,
+            download_url: file.download_url,
+        })
+    }
+
+    fn save(&self, record: &UpdateCheckRecord) -> Result<()> {
+        let Some(path) = cache_path() else {
+            return Ok(());
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let file = CacheFile {
+            checked_at: record.checked_at,
+            source: record.source.clone(),
+            latest_version: record.latest_version.clone(),
+            download_url: record.download_url.clone(),
+        };
+        fs::write(&path, serde_json::to_string_pretty(&file)?)
+            .with_context(|| format!("failed to write {}", path.display()))
+    }
+}
+
+fn cache_path() -> Option<PathBuf> {
+    Some(state::state_dir()?.join(CACHE_FILE))
+}
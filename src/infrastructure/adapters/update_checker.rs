@@ -1,15 +1,25 @@
 //! 최신 버전 조회 포트 구현 어댑터.
+//!
+//! 사용자 지정 endpoint 외에 GitHub/GitLab 최신 릴리스 API를 직접 이해한다.
+//! - GitHub: `GET /repos/{owner}/{repo}/releases/latest`
+//! - GitLab: `GET /projects/{id}/releases/permalink/latest`
 
 use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{Context, Result, bail};
 use async_trait::async_trait;
+use reqwest::StatusCode;
 use reqwest::header::CONTENT_TYPE;
 use serde_json::Value;
 
+use crate::application::config::HostConfig;
 use crate::application::ports::{LatestVersionInfo, UpdateChecker};
+use crate::domain::release::ReleaseAsset;
+use crate::domain::target::RepositoryRef;
+use crate::infrastructure::http::attributed;
+use crate::infrastructure::vcs::{github, gitlab};
 
-/// HTTP endpoint에서 최신 버전을 조회하는 어댑터.
+/// HTTP endpoint/릴리스 API에서 최신 버전을 조회하는 어댑터.
 pub struct HttpUpdateChecker;
 
 #[async_trait]
@@ -24,7 +34,7 @@ impl UpdateChecker for HttpUpdateChecker {
             .timeout(Duration::from_millis(timeout_ms))
             .build()?;
 
-        let mut req = client.get(url);
+        let mut req = attributed(client.get(url));
         if let Some(token) = token {
             req = req
                 .header("PRIVATE-TOKEN", token)
@@ -57,6 +67,89 @@ impl UpdateChecker for HttpUpdateChecker {
 
         Ok(parse_plain_payload(&body))
     }
+
+    async fn fetch_latest_release(
+        &self,
+        repository: &RepositoryRef,
+        host_cfg: Option<&HostConfig>,
+        token: Option<&str>,
+        timeout_ms: u64,
+    ) -> Result<Option<LatestVersionInfo>> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(timeout_ms))
+            .build()?;
+        let api_base = host_cfg.and_then(|h| h.api_base.as_deref());
+
+        let mut req = match repository {
+            RepositoryRef::GitHub { host, owner, repo } => {
+                let url = format!(
+                    "{}/repos/{owner}/{repo}/releases/latest",
+                    github::api_base(host, api_base)
+                );
+                let req = client
+                    .get(url)
+                    .header("Accept", "application/vnd.github+json");
+                match token {
+                    Some(token) => req.bearer_auth(token),
+                    None => req,
+                }
+            }
+            RepositoryRef::GitLab { host, project_path } => {
+                let url = format!(
+                    "{}/projects/{}/releases/permalink/latest",
+                    gitlab::api_base(host, api_base),
+                    gitlab::encode_project_path(project_path)
+                );
+                let req = client.get(url);
+                match token {
+                    Some(token) => req.header("PRIVATE-TOKEN", token),
+                    None => req,
+                }
+            }
+        };
+        req = attributed(req);
+
+        let resp = req.send().await.context("failed to query latest release")?;
+        let status = resp.status();
+        // 릴리스가 하나도 없으면 404를 돌려준다.
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = resp
+            .text()
+            .await
+            .context("failed to read latest release body")?;
+        if !status.is_success() {
+            bail!("failed to query latest release ({status}): {body}");
+        }
+        Ok(parse_json_payload(&body))
+    }
+
+    async fn download(&self, url: &str, token: Option<&str>, timeout_ms: u64) -> Result<Vec<u8>> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(timeout_ms))
+            .build()?;
+        let mut req = attributed(client.get(url)).header("Accept", "application/octet-stream");
+        if let Some(token) = token {
+            req = req
+                .header("PRIVATE-TOKEN", token)
+                .header("Authorization", format!("Bearer {token}"));
+        }
+
+        let resp = req
+            .send()
+            .await
+            .with_context(|| format!("failed to download {url}"))?;
+        let status = resp.status();
+        if !status.is_success() {
+            bail!("failed to download {url} ({status})");
+        }
+        let bytes = resp
+            .bytes()
+            .await
+            .with_context(|| format!("failed to read {url}"))?;
+        Ok(bytes.to_vec())
+    }
 }
 
 fn parse_plain_payload(raw: &str) -> Option<LatestVersionInfo> {
@@ -68,6 +161,7 @@ fn parse_plain_payload(raw: &str) -> Option<LatestVersionInfo> {
     Some(LatestVersionInfo {
         version: version.to_string(),
         download_url: None,
+        assets: Vec::new(),
     })
 }
 
@@ -75,15 +169,17 @@ fn parse_json_payload(raw: &str) -> Option<LatestVersionInfo> {
     let json: Value = serde_json::from_str(raw).ok()?;
     let version = find_version(&json)?;
     let download_url = find_download_url(&json);
+    let assets = find_assets(&json);
 
     Some(LatestVersionInfo {
         version,
         download_url,
+        assets,
     })
 }
 
 fn find_version(json: &Value) -> Option<String> {
-    // GitLab latest release API: tag_name
+    // GitHub/GitLab latest release API: tag_name
     str_at(json, &["tag_name"])
         .or_else(|| str_at(json, &["latest_version"]))
         .or_else(|| str_at(json, &["version"]))
@@ -92,12 +188,36 @@ fn find_version(json: &Value) -> Option<String> {
 }
 
 fn find_download_url(json: &Value) -> Option<String> {
+    // 릴리스 API의 `url`은 API 주소이므로 릴리스 페이지(html_url/_links.self)를 먼저 본다.
     str_at(json, &["download_url"])
+        .or_else(|| str_at(json, &["html_url"]))
+        .or_else(|| str_at(json, &["_links", "self"]))
         .or_else(|| str_at(json, &["url"]))
         .or_else(|| str_at(json, &["assets", "links", "0", "url"]))
         .or_else(|| str_at(json, &["assets", "sources", "0", "url"]))
 }
 
+fn find_assets(json: &Value) -> Vec<ReleaseAsset> {
+    // GitHub: assets[].browser_download_url, GitLab: assets.links[].direct_asset_url
+    let (entries, url_keys): (_, &[&str]) = match json.get("assets") {
+        Some(Value::Array(entries)) => (entries, &["browser_download_url"]),
+        Some(assets) => match assets.get("links") {
+            Some(Value::Array(entries)) => (entries, &["direct_asset_url", "url"]),
+            _ => return Vec::new(),
+        },
+        None => return Vec::new(),
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| {
+            let name = str_at(entry, &["name"])?;
+            let url = url_keys.iter().find_map(|key| str_at(entry, &[key]))?;
+            Some(ReleaseAsset { name, url })
+        })
+        .collect()
+}
+
 fn str_at(value: &Value, path: &[&str]) -> Option<String> {
     let mut cur = value;
     for key in path {
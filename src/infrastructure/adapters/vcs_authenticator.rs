@@ -1,20 +1,32 @@
-//! VCS OAuth 인증 포트 구현(gh/glab).
+//! VCS OAuth 인증 포트 구현(gh/glab, GitLab device flow).
 
 use std::process::{Command, Stdio};
 
 use anyhow::{Context, Result, bail};
 
+use crate::application::config::HostConfig;
 use crate::application::ports::{VcsAuthKind, VcsAuthenticator};
-use crate::infrastructure::config::command_exists;
+use crate::infrastructure::config::{command_exists, command_program, gitlab_device_login};
 
 /// 외부 CLI를 이용해 OAuth 로그인을 수행한다.
+/// GitLab 호스트에 `oauth_client_id`가 있으면 `glab` 없이 device flow로 직접 로그인한다.
 pub struct VcsAuthenticatorAdapter;
 
 impl VcsAuthenticator for VcsAuthenticatorAdapter {
-    fn authenticate(&self, kind: VcsAuthKind, host: &str) -> Result<()> {
+    fn authenticate(
+        &self,
+        kind: VcsAuthKind,
+        host: &str,
+        host_cfg: Option<&HostConfig>,
+    ) -> Result<()> {
         match kind {
             VcsAuthKind::GitHub => gh_auth_login(host),
-            VcsAuthKind::GitLab => glab_auth_login(host),
+            VcsAuthKind::GitLab => match host_cfg
+                .and_then(|cfg| cfg.oauth_client_id().map(|client_id| (cfg, client_id)))
+            {
+                Some((cfg, client_id)) => gitlab_device_login(host, cfg, client_id),
+                None => glab_auth_login(host),
+            },
         }
     }
 }
@@ -30,7 +42,7 @@ Install it first, then re-run `repopilot auth github`.\n\
         );
     }
 
-    let mut cmd = Command::new("gh");
+    let mut cmd = Command::new(command_program("gh"));
     cmd.args(["auth", "login"]);
     if host != "github.com" {
         cmd.args(["--hostname", host]);
@@ -52,14 +64,15 @@ fn glab_auth_login(host: &str) -> Result<()> {
     if !command_exists("glab") {
         bail!(
             "GitLab CLI (`glab`) not found in PATH.\n\
-Install it first, then re-run `repopilot auth gitlab`.\n\
+Install it first, then re-run `repopilot auth gitlab`,\n\
+or set `hosts.{host}.oauth_client_id` to a GitLab OAuth application ID to log in without `glab`.\n\
 - macOS: brew install glab\n\
 - Windows: winget install --id glab.glab\n\
 - Linux: install `glab` via your package manager"
         );
     }
 
-    let mut cmd = Command::new("glab");
+    let mut cmd = Command::new(command_program("glab"));
     cmd.args(["auth", "login"]);
     if host != "gitlab.com" {
         cmd.args(["--hostname", host]);
@@ -1,15 +1,24 @@
 //! VCS 게이트웨이 포트 구현 어댑터.
 
+use std::sync::Arc;
+
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::application::ports::{VcsFactory, VcsGateway};
-use crate::domain::review::ReviewComment;
-use crate::domain::target::ReviewTarget;
+use crate::application::ports::{RepositoryGateway, VcsFactory, VcsGateway};
+use crate::domain::diff::LinePosition;
+use crate::domain::patch::PatchedFile;
+use crate::domain::review::{IssueRef, ReviewComment};
+use crate::domain::target::{OpenReview, RepositoryRef, ReviewTarget};
+use crate::infrastructure::http::HttpClients;
+use crate::infrastructure::traffic::{CaptureMode, TrafficCapture};
 use crate::infrastructure::{config, vcs};
 
-/// VCS 게이트웨이 팩토리 어댑터.
-pub struct VcsFactoryAdapter;
+/// VCS 게이트웨이 팩토리 어댑터. 만드는 클라이언트는 `http`의 연결 풀을 공유한다.
+#[derive(Default)]
+pub struct VcsFactoryAdapter {
+    pub http: HttpClients,
+}
 
 impl VcsFactory for VcsFactoryAdapter {
     fn build(
@@ -19,9 +28,70 @@ impl VcsFactory for VcsFactoryAdapter {
         token: Option<String>,
     ) -> Box<dyn VcsGateway> {
         Box::new(VcsGatewayAdapter {
-            inner: vcs::build_vcs_client(target, host_cfg, token),
+            inner: vcs::build_vcs_client(target, host_cfg, token, &self.http),
         })
     }
+
+    fn build_repository(
+        &self,
+        repository: &RepositoryRef,
+        host_cfg: Option<&config::HostConfig>,
+        token: Option<String>,
+    ) -> Box<dyn RepositoryGateway> {
+        Box::new(RepositoryGatewayAdapter {
+            inner: vcs::build_repository_client(repository, host_cfg, token, &self.http),
+        })
+    }
+}
+
+/// VCS 호출을 기록/재생하는 팩토리 어댑터(`--record` / `--replay`).
+/// 재생 모드에서는 실제 클라이언트를 만들지 않는다.
+pub struct TrafficVcsFactory {
+    pub capture: Arc<TrafficCapture>,
+    pub http: HttpClients,
+}
+
+impl VcsFactory for TrafficVcsFactory {
+    fn build(
+        &self,
+        target: &ReviewTarget,
+        host_cfg: Option<&config::HostConfig>,
+        token: Option<String>,
+    ) -> Box<dyn VcsGateway> {
+        if let Some(token) = token.as_deref() {
+            self.capture.add_secret(token);
+        }
+        let inner = match self.capture.mode() {
+            CaptureMode::Record => Some(vcs::build_vcs_client(target, host_cfg, token, &self.http)),
+            CaptureMode::Replay => None,
+        };
+        Box::new(VcsGatewayAdapter {
+            inner: self.capture.wrap_vcs(target.url(), inner),
+        })
+    }
+
+    fn build_repository(
+        &self,
+        repository: &RepositoryRef,
+        host_cfg: Option<&config::HostConfig>,
+        token: Option<String>,
+    ) -> Box<dyn RepositoryGateway> {
+        Box::new(RepositoryGatewayAdapter {
+            inner: vcs::build_repository_client(repository, host_cfg, token, &self.http),
+        })
+    }
+}
+
+/// 인프라 저장소 조회 클라이언트를 애플리케이션 포트로 감싸는 래퍼.
+struct RepositoryGatewayAdapter {
+    inner: Box<dyn vcs::RepositoryProvider>,
+}
+
+#[async_trait]
+impl RepositoryGateway for RepositoryGatewayAdapter {
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>> {
+        self.inner.list_open_reviews(limit).await
+    }
 }
 
 /// 인프라 VCS Provider를 애플리케이션 포트로 감싸는 래퍼.
@@ -35,6 +105,26 @@ impl VcsGateway for VcsGatewayAdapter {
         self.inner.fetch_head_sha().await
     }
 
+    async fn fetch_title(&self) -> Result<String> {
+        self.inner.fetch_title().await
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        self.inner.fetch_labels().await
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        self.inner.fetch_description().await
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        self.inner.fetch_commit_messages().await
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        self.inner.fetch_author().await
+    }
+
     async fn fetch_diff(&self) -> Result<String> {
         self.inner.fetch_diff().await
     }
@@ -50,4 +140,73 @@ impl VcsGateway for VcsGatewayAdapter {
     async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
         self.inner.update_comment(comment_id, body).await
     }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        self.inner.create_file_comment(head_sha, path, body).await
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        self.inner
+            .create_line_comment(head_sha, path, position, body)
+            .await
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        self.inner.list_review_comments().await
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        self.inner.update_review_comment(comment_id, body).await
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        self.inner.acknowledge_comment(comment_id).await
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        self.inner.request_reviewers(usernames).await
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        self.inner.create_issue(title, body).await
+    }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        self.inner.fetch_repository_file(path).await
+    }
+
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>> {
+        self.inner.fetch_file_at(path, sha).await
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        self.inner.fetch_recent_authors(path, limit).await
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        self.inner
+            .push_branch(branch, base_sha, message, files)
+            .await
+    }
+
+    async fn check_token_access(&self, write: bool) -> Result<()> {
+        self.inner.check_token_access(write).await
+    }
 }
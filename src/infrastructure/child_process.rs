@@ -0,0 +1,124 @@
+//! provider/플러그인 자식 프로세스 정리.
+//!
+//! CLI provider는 자식 프로세스(node 워커 등)를 다시 띄우는 경우가 많아 직접 자식만 종료하면 손자
+//! 프로세스가 남는다. Unix에서는 자식을 새 프로세스 그룹으로 실행하고 그룹 전체를 종료한다.
+//! - 실행이 끝나거나 시간 초과/취소로 future가 버려지면 [`ChildGroup`]이 그룹을 종료한다.
+//! - repopilot이 SIGTERM/SIGHUP(/SIGINT)을 받으면 [`install_shutdown_handler`]가 남은 그룹을 모두 종료하고 끝낸다.
+
+#[cfg(unix)]
+use std::collections::BTreeSet;
+#[cfg(unix)]
+use std::sync::Mutex;
+
+use tokio::process::{Child, Command};
+
+/// 실행 중인 자식 프로세스 그룹 ID(= 그룹 리더 PID).
+#[cfg(unix)]
+static CHILD_GROUPS: Mutex<BTreeSet<u32>> = Mutex::new(BTreeSet::new());
+
+/// 자식을 새 프로세스 그룹으로 실행하도록 설정한다. 터미널의 Ctrl-C가 자식에게 직접 가지 않으므로
+/// 종료는 [`ChildGroup`]과 종료 처리기가 맡는다.
+/// 백그라운드 그룹이 터미널을 읽으면 멈추므로 호출자는 stdin을 파이프나 null로 지정해야 한다.
+pub fn isolate(cmd: &mut Command) {
+    #[cfg(unix)]
+    cmd.process_group(0);
+    #[cfg(not(unix))]
+    let _ = cmd;
+}
+
+/// 자식 프로세스 그룹이 살아 있는 동안 들고 있는 가드. drop되면 그룹에 남은 프로세스를 모두 종료한다.
+pub struct ChildGroup {
+    #[cfg(unix)]
+    pgid: Option<u32>,
+}
+
+impl ChildGroup {
+    /// [`isolate`]로 실행한 자식의 그룹을 등록한다.
+    pub fn track(child: &Child) -> Self {
+        #[cfg(unix)]
+        {
+            let pgid = child.id();
+            if let Some(pgid) = pgid {
+                lock_groups().insert(pgid);
+            }
+            Self { pgid }
+        }
+        #[cfg(not(unix))]
+        {
+            let _ = child;
+            Self {}
+        }
+    }
+}
+
+impl Drop for ChildGroup {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        if let Some(pgid) = self.pgid.take() {
+            lock_groups().remove(&pgid);
+            kill_group(pgid);
+        }
+    }
+}
+
+/// 종료 신호를 받으면 남은 자식 프로세스 그룹을 종료하고 `128 + 신호 번호`로 끝낸다.
+/// `interrupt`가 false면 SIGINT는 건드리지 않는다(REPL은 Ctrl-C로 진행 중인 리뷰만 취소한다).
+pub fn install_shutdown_handler(interrupt: bool) {
+    #[cfg(unix)]
+    tokio::spawn(async move {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        let (Ok(mut terminate), Ok(mut hangup)) = (
+            signal(SignalKind::terminate()),
+            signal(SignalKind::hangup()),
+        ) else {
+            return;
+        };
+        let mut interrupted = if interrupt {
+            signal(SignalKind::interrupt()).ok()
+        } else {
+            None
+        };
+        let code = tokio::select! {
+            _ = terminate.recv() => 128 + libc::SIGTERM,
+            _ = hangup.recv() => 128 + libc::SIGHUP,
+            _ = async {
+                match interrupted.as_mut() {
+                    Some(signal) => signal.recv().await,
+                    None => std::future::pending().await,
+                }
+            } => 128 + libc::SIGINT,
+        };
+        terminate_all();
+        std::process::exit(code);
+    });
+    #[cfg(not(unix))]
+    let _ = interrupt;
+}
+
+/// 등록된 자식 프로세스 그룹을 모두 종료한다.
+#[cfg(unix)]
+fn terminate_all() {
+    let groups = std::mem::take(&mut *lock_groups());
+    for pgid in groups {
+        kill_group(pgid);
+    }
+}
+
+#[cfg(unix)]
+fn kill_group(pgid: u32) {
+    let Ok(pgid) = i32::try_from(pgid) else {
+        return;
+    };
+    // 이미 모두 끝난 그룹이면 ESRCH로 실패하며 무시한다.
+    unsafe {
+        libc::kill(-pgid, libc::SIGKILL);
+    }
+}
+
+#[cfg(unix)]
+fn lock_groups() -> std::sync::MutexGuard<'static, BTreeSet<u32>> {
+    CHILD_GROUPS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,33 @@
+//! 현재 시각과 임의 값 helper.
+//!
+//! 암호학적 난수가 필요 없는 곳(실행 id, claim nonce, 재시도 지터)에서만 쓴다.
+//! 표준 라이브러리 해시 시드에 현재 시각(ns)과 프로세스 id를 섞어 프로세스/호출마다 다른 값을 만든다.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 현재 unix 시각(초).
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// 호출마다 다른 임의 64비트 값.
+pub fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    hasher.write_u128(nanos);
+    hasher.write_u32(std::process::id());
+    hasher.finish()
+}
+
+/// 임의 id(16진수 16자리).
+pub fn random_id() -> String {
+    format!("{:016x}", random_u64())
+}
@@ -0,0 +1,212 @@
+//! `config get`/`config set`용 키 경로 조회/수정.
+//! 키는 점 표기(`providers.openai.model`)이며, 점이 들어간 호스트 이름은
+//! `hosts.github.com.token_env` 또는 `hosts."github.com".token_env`로 쓸 수 있다.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Map, Value};
+
+use super::format::ConfigFormat;
+use super::loader::{editable_config_path, load_merged_config};
+use super::utils::mask_secret_values;
+use super::validate::{check_config_value, escape_pointer};
+use crate::application::config::HostConfig;
+
+/// 병합된 설정에서 키 값을 읽는다(미설정이면 None).
+/// `show_secrets`가 false면 인라인 `token`/`api_key`/`webhook_url`/`password` 값은 가린다.
+pub(crate) fn get_value(key: &str, show_secrets: bool) -> Result<Option<Value>> {
+    let path = parse_key_path(key)?;
+    let mut merged = serde_json::to_value(load_merged_config()?.config)?;
+    if !show_secrets {
+        mask_secret_values(&mut merged);
+    }
+    let found = path
+        .iter()
+        .try_fold(&merged, |value, segment| value.get(segment.as_str()));
+    Ok(found.filter(|value| !value.is_null()).cloned())
+}
+
+/// 편집 대상 설정 파일의 키 값을 바꾸고 파일 경로를 반환한다.
+/// 값은 JSON(숫자/불리언/배열 등)으로 먼저 해석하고, 스키마에 맞지 않으면 문자열로 다시 시도한다.
+/// 주석이 있는 YAML 파일은 다시 쓰면 주석이 사라지므로 `force`일 때만 쓴다.
+pub(crate) fn set_value(key: &str, raw: &str, force: bool) -> Result<PathBuf> {
+    let path = parse_key_path(key)?;
+    let file = editable_config_path()?;
+    let format = ConfigFormat::from_path(&file);
+    let original = fs::read_to_string(&file)
+        .with_context(|| format!("failed to read config at {}", file.display()))?;
+    let document = if original.trim().is_empty() {
+        Value::Object(Map::new())
+    } else {
+        format
+            .parse(&original)
+            .with_context(|| format!("failed to parse {} in {}", format.label(), file.display()))?
+    };
+
+    let mut candidates = Vec::new();
+    if let Ok(parsed) = serde_json::from_str::<Value>(raw)
+        && !parsed.is_string()
+    {
+        candidates.push(parsed);
+    }
+    candidates.push(Value::String(raw.to_string()));
+
+    let pointer: String = path
+        .iter()
+        .map(|segment| format!("/{}", escape_pointer(segment)))
+        .collect();
+    let mut last_issues = Vec::new();
+    for candidate in candidates {
+        let mut updated = document.clone();
+        set_at(&mut updated, &path, candidate)?;
+
+        // 다른 키에 이미 있던 문제는 이번 수정과 무관하므로 대상 경로 문제만 본다.
+        let issues: Vec<_> = check_config_value(&file, &updated)
+            .into_iter()
+            .filter(|issue| {
+                pointer.starts_with(&issue.pointer) || issue.pointer.starts_with(&pointer)
+            })
+            .collect();
+        if issues.is_empty() {
+            let rendered = render_preserving_style(format, &file, &original, &updated, force)?;
+            fs::write(&file, rendered)
+                .with_context(|| format!("failed to write config at {}", file.display()))?;
+            return Ok(file);
+        }
+        last_issues = issues;
+    }
+
+    let lines = last_issues
+        .iter()
+        .map(|issue| format!("  - {issue}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    bail!("cannot set {key}:\n{lines}")
+}
+
+/// 점 표기 키를 경로 조각으로 나눈다. 큰따옴표로 감싼 조각은 점을 포함할 수 있다.
+fn parse_key_path(key: &str) -> Result<Vec<String>> {
+    let mut segments = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut was_quoted = false;
+    for ch in key.trim().chars() {
+        match ch {
+            '"' => {
+                quoted = !quoted;
+                was_quoted = true;
+            }
+            '.' if !quoted => {
+                segments.push((std::mem::take(&mut current), was_quoted));
+                was_quoted = false;
+            }
+            _ => current.push(ch),
+        }
+    }
+    if quoted {
+        bail!("unterminated quote in key '{key}'");
+    }
+    segments.push((current, was_quoted));
+    if segments.iter().any(|(segment, _)| segment.is_empty()) {
+        bail!("invalid key '{key}' (expected e.g. providers.openai.model)");
+    }
+
+    // 따옴표 없이 쓴 `hosts.<host>[.<field>]`의 호스트 조각은 하나의 호스트 이름으로 합친다.
+    if segments.len() > 2 && segments[0].0 == "hosts" && !segments[1].1 {
+        let host_fields = serde_json::to_value(HostConfig::default())?;
+        let last = &segments[segments.len() - 1].0;
+        let field = host_fields
+            .get(last.as_str())
+            .is_some()
+            .then(|| segments.pop().expect("more than two segments"));
+        let host = segments[1..]
+            .iter()
+            .map(|(segment, _)| segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        segments.truncate(1);
+        segments.push((host, true));
+        segments.extend(field);
+    }
+
+    // 따옴표 없이 쓴 `repos.<host/owner/repo>.defaults...`도 `defaults` 앞까지를 저장소 키로 합친다.
+    if segments.len() > 2
+        && segments[0].0 == "repos"
+        && !segments[1].1
+        && let Some(idx) = segments.iter().skip(2).position(|(segment, _)| segment == "defaults")
+    {
+        let rest = segments.split_off(idx + 2);
+        let repo = segments[1..]
+            .iter()
+            .map(|(segment, _)| segment.as_str())
+            .collect::<Vec<_>>()
+            .join(".");
+        segments.truncate(1);
+        segments.push((repo, true));
+        segments.extend(rest);
+    }
+    Ok(segments.into_iter().map(|(segment, _)| segment).collect())
+}
+
+fn set_at(document: &mut Value, path: &[String], value: Value) -> Result<()> {
+    let (last, parents) = path.split_last().expect("key path is never empty");
+    let mut current = document;
+    for segment in parents {
+        let Value::Object(map) = current else {
+            bail!("'{segment}' is not inside a table/object");
+        };
+        let entry = map
+            .entry(segment.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if entry.is_null() {
+            *entry = Value::Object(Map::new());
+        }
+        current = entry;
+    }
+    let Value::Object(map) = current else {
+        bail!("'{last}' is not inside a table/object");
+    };
+    map.insert(last.clone(), value);
+    Ok(())
+}
+
+/// JSON은 원본 들여쓰기(탭/공백 폭)를 유지해 다시 쓰고, TOML은 원문을 제자리에서 고쳐 주석을 보존한다.
+/// YAML은 표준 형태로 다시 렌더링하므로 주석이 있으면 `force` 없이는 쓰지 않는다.
+pub(super) fn render_preserving_style(
+    format: ConfigFormat,
+    file: &Path,
+    original: &str,
+    value: &Value,
+    force: bool,
+) -> Result<String> {
+    if !force && format.update_drops_comments(original) {
+        bail!(
+            "{} contains comments that rewriting the {} file would drop; edit it by hand or pass --force",
+            file.display(),
+            format.label()
+        );
+    }
+    if format != ConfigFormat::Json {
+        return format.update(original, value);
+    }
+    let indent = detect_indent(original).unwrap_or_else(|| "  ".to_string());
+    let mut out = Vec::new();
+    let formatter = serde_json::ser::PrettyFormatter::with_indent(indent.as_bytes());
+    let mut serializer = serde_json::Serializer::with_formatter(&mut out, formatter);
+    serde::Serialize::serialize(value, &mut serializer)?;
+    out.push(b'\n');
+    Ok(String::from_utf8(out)?)
+}
+
+/// 처음으로 들여쓴 줄의 선행 공백을 들여쓰기 단위로 본다.
+fn detect_indent(text: &str) -> Option<String> {
+    text.lines().find_map(|line| {
+        let indent: String = line
+            .chars()
+            .take_while(|ch| *ch == ' ' || *ch == '\t')
+            .collect();
+        (!indent.is_empty() && indent.len() < line.len()).then_some(indent)
+    })
+}
@@ -0,0 +1,77 @@
+//! 설정 파일 형식(JSON/TOML/YAML) 판별과 변환.
+//! 모든 형식은 `serde_json::Value`로 변환한 뒤 동일한 `Config` 역직렬화/병합 규칙을 따른다.
+
+mod toml;
+mod yaml;
+
+use std::path::Path;
+
+use anyhow::Result;
+use serde_json::Value;
+
+/// 확장자로 결정되는 설정 파일 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfigFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+/// 같은 디렉터리에서 탐색하는 설정 파일 이름(낮은 우선순위 -> 높은 우선순위).
+pub(crate) const CONFIG_FILE_NAMES: &[&str] =
+    &["config.json", "config.toml", "config.yaml", "config.yml"];
+
+impl ConfigFormat {
+    /// `.toml`/`.yaml`/`.yml`이 아니면 JSON으로 취급한다.
+    pub fn from_path(path: &Path) -> Self {
+        let ext = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match ext.as_deref() {
+            Some("toml") => Self::Toml,
+            Some("yaml" | "yml") => Self::Yaml,
+            _ => Self::Json,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Toml => "TOML",
+            Self::Yaml => "YAML",
+        }
+    }
+
+    /// 원문을 JSON 값 트리로 변환한다.
+    pub fn parse(self, raw: &str) -> Result<Value> {
+        match self {
+            Self::Json => Ok(serde_json::from_str(raw)?),
+            Self::Toml => toml::parse(raw),
+            Self::Yaml => yaml::parse(raw),
+        }
+    }
+
+    /// 원문을 JSON 값 트리에 맞게 고친다. TOML은 원문을 제자리에서 고쳐 주석과 서식을 보존하고,
+    /// 나머지 형식은 [`Self::render`]로 다시 렌더링한다.
+    pub fn update(self, original: &str, value: &Value) -> Result<String> {
+        match self {
+            Self::Toml => toml::update(original, value),
+            Self::Json | Self::Yaml => self.render(value),
+        }
+    }
+
+    /// [`Self::update`]가 원문의 주석을 잃는지(주석이 있는 YAML). JSON에는 주석이 없다.
+    pub fn update_drops_comments(self, original: &str) -> bool {
+        self == Self::Yaml && yaml::has_comments(original)
+    }
+
+    /// JSON 값 트리를 해당 형식의 문서로 렌더링한다(끝에 개행 포함).
+    pub fn render(self, value: &Value) -> Result<String> {
+        match self {
+            Self::Json => Ok(format!("{}\n", serde_json::to_string_pretty(value)?)),
+            Self::Toml => toml::render(value),
+            Self::Yaml => Ok(yaml::render(value)),
+        }
+    }
+}
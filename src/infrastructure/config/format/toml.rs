@@ -0,0 +1,265 @@
+//! 설정 파일용 TOML 파서/렌더러.
+//! 해석과 제자리 수정은 `toml_edit`에 맡기고 JSON 값 트리로 변환한다. 날짜/시간 값은 문자열로 보존한다.
+
+use anyhow::{Result, bail};
+use serde_json::{Map, Number, Value};
+use toml_edit::{ArrayOfTables, DocumentMut, InlineTable, Item, Table, TableLike};
+
+/// TOML 문서를 JSON 값 트리로 변환한다. 같은 테이블/키의 중복 정의 같은 스펙 위반은 오류로 낸다.
+pub(super) fn parse(raw: &str) -> Result<Value> {
+    let document: DocumentMut = raw.parse()?;
+    Ok(item_value(document.as_item()).unwrap_or_else(|| Value::Object(Map::new())))
+}
+
+/// JSON 값 트리를 TOML 문서로 렌더링한다. TOML에 없는 null은 생략한다.
+pub(super) fn render(value: &Value) -> Result<String> {
+    let Value::Object(root) = value else {
+        bail!("TOML document root must be a table");
+    };
+    let mut out = String::new();
+    render_table(&mut out, &[], root, false);
+    Ok(out)
+}
+
+fn render_table(out: &mut String, path: &[String], table: &Map<String, Value>, array_item: bool) {
+    let (nested, inline): (Vec<_>, Vec<_>) = table
+        .iter()
+        .filter(|(_, value)| !value.is_null())
+        .partition(|(_, value)| is_table(value) || is_table_array(value));
+
+    // 값 없이 하위 테이블만 있는 중간 테이블은 헤더를 생략한다.
+    if !path.is_empty() && (array_item || !inline.is_empty() || nested.is_empty()) {
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        let header = path.iter().map(|key| render_key(key)).collect::<Vec<_>>().join(".");
+        if array_item {
+            out.push_str(&format!("[[{header}]]\n"));
+        } else {
+            out.push_str(&format!("[{header}]\n"));
+        }
+    }
+    for (key, value) in inline {
+        out.push_str(&format!("{} = {}\n", render_key(key), render_inline(value)));
+    }
+
+    for (key, value) in nested {
+        let mut child = path.to_vec();
+        child.push(key.clone());
+        match value {
+            Value::Object(map) => render_table(out, &child, map, false),
+            Value::Array(items) => {
+                for item in items {
+                    if let Value::Object(map) = item {
+                        render_table(out, &child, map, true);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_table(value: &Value) -> bool {
+    matches!(value, Value::Object(_))
+}
+
+fn is_table_array(value: &Value) -> bool {
+    matches!(value, Value::Array(items) if !items.is_empty() && items.iter().all(is_table))
+}
+
+fn render_key(key: &str) -> String {
+    let bare = !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+    if bare {
+        key.to_string()
+    } else {
+        render_string(key)
+    }
+}
+
+/// JSON 문자열 이스케이프는 TOML 기본 문자열과 호환된다.
+fn render_string(text: &str) -> String {
+    Value::String(text.to_string()).to_string()
+}
+
+fn render_inline(value: &Value) -> String {
+    match value {
+        Value::Null => "\"\"".to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Number(number) => number.to_string(),
+        Value::String(text) => render_string(text),
+        Value::Array(items) => {
+            let items = items
+                .iter()
+                .filter(|item| !item.is_null())
+                .map(render_inline)
+                .collect::<Vec<_>>();
+            format!("[{}]", items.join(", "))
+        }
+        Value::Object(map) => {
+            let entries = map
+                .iter()
+                .filter(|(_, value)| !value.is_null())
+                .map(|(key, value)| format!("{} = {}", render_key(key), render_inline(value)))
+                .collect::<Vec<_>>();
+            if entries.is_empty() {
+                "{}".to_string()
+            } else {
+                format!("{{ {} }}", entries.join(", "))
+            }
+        }
+    }
+}
+
+/// 원문 TOML 문서를 JSON 값 트리와 같아지도록 고친다.
+/// 바뀌지 않은 키는 원문(주석, 공백, 키 순서, 문자열 표기)을 그대로 두고,
+/// 바뀐 값은 앞뒤 주석을 유지한 채 값만 바꾸며, 사라진 키는 지우고 새 키는 끝에 붙인다.
+pub(super) fn update(original: &str, value: &Value) -> Result<String> {
+    let Value::Object(root) = value else {
+        bail!("TOML document root must be a table");
+    };
+    let mut document: DocumentMut = original.parse()?;
+    sync_table(document.as_table_mut(), root, true);
+    Ok(document.to_string())
+}
+
+/// `standard`는 하위 테이블을 `[header]` 테이블로 만들 수 있는 위치인지(인라인 테이블 안이 아닌지).
+/// 키 이름만 바뀐 항목(마이그레이션의 별칭 키 변경)은 지운 항목을 새 키로 옮겨 주석과 위치를 유지한다.
+fn sync_table(table: &mut dyn TableLike, value: &Map<String, Value>, standard: bool) {
+    let stale: Vec<String> = table
+        .iter()
+        .map(|(key, _)| key.to_string())
+        .filter(|key| value.get(key).is_none_or(Value::is_null))
+        .collect();
+    let mut removed: Vec<Item> = stale.iter().filter_map(|key| table.remove(key)).collect();
+    for (key, new) in value.iter().filter(|(_, new)| !new.is_null()) {
+        match table.get_mut(key) {
+            Some(item) => sync_item(item, new, standard),
+            None => {
+                let moved = removed
+                    .iter()
+                    .position(|item| item_value(item).as_ref() == Some(new))
+                    .map(|idx| removed.remove(idx));
+                table.insert(key, moved.unwrap_or_else(|| new_item(new, standard)));
+            }
+        }
+    }
+}
+
+fn sync_item(item: &mut Item, new: &Value, standard: bool) {
+    if item_value(item).as_ref() == Some(new) {
+        return;
+    }
+    match (item, new) {
+        (Item::Table(table), Value::Object(map)) => sync_table(table, map, true),
+        (Item::Value(toml_edit::Value::InlineTable(table)), Value::Object(map)) => {
+            sync_table(table, map, false)
+        }
+        (Item::ArrayOfTables(array), Value::Array(items))
+            if array.len() == items.len() && items.iter().all(is_table) =>
+        {
+            for (table, item) in array.iter_mut().zip(items) {
+                if let Value::Object(map) = item {
+                    sync_table(table, map, true);
+                }
+            }
+        }
+        (Item::Value(old), _) if !is_table(new) && !is_table_array(new) => {
+            let decor = old.decor().clone();
+            let mut replaced = inline_value(new);
+            *replaced.decor_mut() = decor;
+            *old = replaced;
+        }
+        (item, _) => *item = new_item(new, standard),
+    }
+}
+
+fn new_item(value: &Value, standard: bool) -> Item {
+    match value {
+        Value::Object(map) if standard => {
+            let mut table = Table::new();
+            sync_table(&mut table, map, true);
+            // 값 없이 하위 테이블만 있는 중간 테이블은 헤더를 생략한다.
+            table.set_implicit(true);
+            Item::Table(table)
+        }
+        Value::Array(items) if standard && is_table_array(value) => {
+            let mut array = ArrayOfTables::new();
+            for item in items {
+                if let Value::Object(map) = item {
+                    let mut table = Table::new();
+                    sync_table(&mut table, map, true);
+                    array.push(table);
+                }
+            }
+            Item::ArrayOfTables(array)
+        }
+        _ => Item::Value(inline_value(value)),
+    }
+}
+
+fn inline_value(value: &Value) -> toml_edit::Value {
+    match value {
+        Value::Null => toml_edit::Value::from(""),
+        Value::Bool(flag) => toml_edit::Value::from(*flag),
+        Value::Number(number) => match number.as_i64() {
+            Some(int) => toml_edit::Value::from(int),
+            None => toml_edit::Value::from(number.as_f64().unwrap_or_default()),
+        },
+        Value::String(text) => toml_edit::Value::from(text.as_str()),
+        Value::Array(items) => toml_edit::Value::Array(
+            items
+                .iter()
+                .filter(|item| !item.is_null())
+                .map(inline_value)
+                .collect(),
+        ),
+        Value::Object(map) => {
+            let mut table = InlineTable::new();
+            sync_table(&mut table, map, false);
+            toml_edit::Value::InlineTable(table)
+        }
+    }
+}
+
+/// 원문 항목을 [`parse`]와 같은 규칙의 JSON 값으로 바꾼다(날짜/시간은 문자열).
+fn item_value(item: &Item) -> Option<Value> {
+    match item {
+        Item::None => None,
+        Item::Value(value) => Some(toml_value(value)),
+        Item::Table(table) => Some(Value::Object(
+            table
+                .iter()
+                .filter_map(|(key, item)| Some((key.to_string(), item_value(item)?)))
+                .collect(),
+        )),
+        Item::ArrayOfTables(array) => Some(Value::Array(
+            array
+                .iter()
+                .map(|table| item_value(&Item::Table(table.clone())).unwrap_or_default())
+                .collect(),
+        )),
+    }
+}
+
+fn toml_value(value: &toml_edit::Value) -> Value {
+    match value {
+        toml_edit::Value::String(text) => Value::String(text.value().clone()),
+        toml_edit::Value::Integer(int) => Value::from(*int.value()),
+        toml_edit::Value::Float(float) => {
+            Number::from_f64(*float.value()).map_or(Value::Null, Value::Number)
+        }
+        toml_edit::Value::Boolean(flag) => Value::Bool(*flag.value()),
+        toml_edit::Value::Datetime(datetime) => Value::String(datetime.value().to_string()),
+        toml_edit::Value::Array(items) => Value::Array(items.iter().map(toml_value).collect()),
+        toml_edit::Value::InlineTable(table) => Value::Object(
+            table
+                .iter()
+                .map(|(key, value)| (key.to_string(), toml_value(value)))
+                .collect(),
+        ),
+    }
+}
@@ -0,0 +1,200 @@
+//! 설정 파일용 YAML 파서/렌더러.
+//! 해석은 `serde_yaml`에 맡기고 JSON 값 트리로 변환한다. 렌더링은 블록 스타일로 직접 한다.
+
+use anyhow::{Result, anyhow, bail};
+use serde_json::{Map, Number, Value};
+use serde_yaml::Value as YamlValue;
+
+/// YAML 문서를 JSON 값 트리로 변환한다.
+/// 앵커/별칭과 병합 키(`<<`)는 풀어서 반영하고, JSON으로 옮길 수 없는 태그 값과
+/// 여러 문서 스트림, 중복 키는 오류로 낸다.
+pub(super) fn parse(raw: &str) -> Result<Value> {
+    let mut document: YamlValue = serde_yaml::from_str(raw)?;
+    document.apply_merge()?;
+    match yaml_value(document)? {
+        Value::Null => Ok(Value::Object(Map::new())),
+        value => Ok(value),
+    }
+}
+
+fn yaml_value(value: YamlValue) -> Result<Value> {
+    Ok(match value {
+        YamlValue::Null => Value::Null,
+        YamlValue::Bool(flag) => Value::Bool(flag),
+        YamlValue::Number(number) => yaml_number(&number)?,
+        YamlValue::String(text) => Value::String(text),
+        YamlValue::Sequence(items) => Value::Array(
+            items
+                .into_iter()
+                .map(yaml_value)
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        YamlValue::Mapping(mapping) => {
+            let mut map = Map::new();
+            for (key, value) in mapping {
+                map.insert(yaml_key(key)?, yaml_value(value)?);
+            }
+            Value::Object(map)
+        }
+        YamlValue::Tagged(tagged) => bail!("YAML tag {} is not supported", tagged.tag),
+    })
+}
+
+fn yaml_number(number: &serde_yaml::Number) -> Result<Value> {
+    if let Some(value) = number.as_i64() {
+        return Ok(Value::Number(Number::from(value)));
+    }
+    if let Some(value) = number.as_u64() {
+        return Ok(Value::Number(Number::from(value)));
+    }
+    number
+        .as_f64()
+        .and_then(Number::from_f64)
+        .map(Value::Number)
+        .ok_or_else(|| anyhow!("YAML number {number} cannot be represented in config"))
+}
+
+/// 매핑 키는 문자열로 맞춘다(`1:`/`true:` 같은 스칼라 키는 원문 표기 그대로).
+fn yaml_key(key: YamlValue) -> Result<String> {
+    match key {
+        YamlValue::String(text) => Ok(text),
+        YamlValue::Null => Ok("null".to_string()),
+        YamlValue::Bool(flag) => Ok(flag.to_string()),
+        YamlValue::Number(number) => Ok(number.to_string()),
+        _ => bail!("YAML mapping keys must be scalars"),
+    }
+}
+
+/// 주석 줄이나 줄 끝 주석이 있는지. [`render`]로 다시 쓰면 주석은 사라진다.
+pub(super) fn has_comments(raw: &str) -> bool {
+    raw.lines().any(|line| {
+        let text = line.trim_start();
+        strip_comment(text).len() != text.len()
+    })
+}
+
+/// 따옴표 밖에서 공백 뒤(또는 줄 시작)에 오는 `#`부터 주석으로 본다.
+fn strip_comment(text: &str) -> &str {
+    let mut quote = None;
+    let mut prev_space = true;
+    for (idx, ch) in text.char_indices() {
+        match quote {
+            Some(q) if ch == q => quote = None,
+            Some(_) => {}
+            None if ch == '"' || ch == '\'' => quote = Some(ch),
+            None if ch == '#' && prev_space => return &text[..idx],
+            None => {}
+        }
+        prev_space = ch == ' ' || ch == '\t';
+    }
+    text
+}
+
+/// plain 스칼라로 쓰면 null/불리언/숫자로 읽히는지 판별할 때 쓴다(YAML 1.2 core schema).
+fn plain_scalar(text: &str) -> Value {
+    match text {
+        "" | "~" | "null" | "Null" | "NULL" => return Value::Null,
+        "true" | "True" | "TRUE" => return Value::Bool(true),
+        "false" | "False" | "FALSE" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(value) = text.parse::<i64>() {
+        return Value::Number(Number::from(value));
+    }
+    if let Some(hex) = text.strip_prefix("0x")
+        && let Ok(value) = i64::from_str_radix(hex, 16)
+    {
+        return Value::Number(Number::from(value));
+    }
+    let numeric = text
+        .trim_start_matches(['-', '+'])
+        .starts_with(|ch: char| ch.is_ascii_digit() || ch == '.');
+    if numeric
+        && let Ok(value) = text.parse::<f64>()
+        && let Some(number) = Number::from_f64(value)
+    {
+        return Value::Number(number);
+    }
+    Value::String(text.to_string())
+}
+
+/// JSON 값 트리를 블록 스타일 YAML 문서로 렌더링한다.
+pub(super) fn render(value: &Value) -> String {
+    let mut out = String::new();
+    match value {
+        Value::Object(map) if !map.is_empty() => render_mapping(&mut out, map, 0),
+        Value::Array(items) if !items.is_empty() => render_sequence(&mut out, items, 0),
+        other => {
+            out.push_str(&render_scalar(other));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn render_mapping(out: &mut String, map: &Map<String, Value>, indent: usize) {
+    for (key, value) in map {
+        out.push_str(&" ".repeat(indent));
+        out.push_str(&render_key(key));
+        out.push(':');
+        render_nested(out, value, indent);
+    }
+}
+
+fn render_sequence(out: &mut String, items: &[Value], indent: usize) {
+    for item in items {
+        out.push_str(&" ".repeat(indent));
+        out.push('-');
+        match item {
+            // 매핑 항목은 첫 키를 `- ` 뒤에 붙이고 나머지 키를 같은 깊이로 맞춘다.
+            Value::Object(map) if !map.is_empty() => {
+                let mut entry = String::new();
+                render_mapping(&mut entry, map, indent + 2);
+                out.push(' ');
+                out.push_str(&entry[indent + 2..]);
+            }
+            _ => render_nested(out, item, indent),
+        }
+    }
+}
+
+/// `key:`/`-` 뒤에 값을 붙인다(스칼라는 같은 줄, 컬렉션은 다음 줄부터).
+fn render_nested(out: &mut String, value: &Value, indent: usize) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            out.push('\n');
+            render_mapping(out, map, indent + 2);
+        }
+        Value::Array(items) if !items.is_empty() => {
+            out.push('\n');
+            render_sequence(out, items, indent + 2);
+        }
+        other => {
+            out.push(' ');
+            out.push_str(&render_scalar(other));
+            out.push('\n');
+        }
+    }
+}
+
+fn render_key(key: &str) -> String {
+    let plain = !key.is_empty()
+        && key
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | '-' | '.' | '/'))
+        && matches!(plain_scalar(key), Value::String(_));
+    if plain {
+        key.to_string()
+    } else {
+        Value::String(key.to_string()).to_string()
+    }
+}
+
+/// 문자열은 항상 큰따옴표로 감싼다(JSON 이스케이프는 YAML 큰따옴표 문자열과 호환).
+fn render_scalar(value: &Value) -> String {
+    match value {
+        Value::Object(_) => "{}".to_string(),
+        Value::Array(_) => "[]".to_string(),
+        other => other.to_string(),
+    }
+}
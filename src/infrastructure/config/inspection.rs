@@ -9,18 +9,31 @@ use serde::Serialize;
 use serde_json::Value;
 
 use super::loader::LoadedConfig;
-use super::resolve::{resolve_host_token, resolve_provider_api_key};
-use super::utils::command_exists;
-use crate::application::config::{DefaultsConfig, HostConfig, ProviderConfig};
+use super::remote::RemoteConfigStatus;
+use super::resolve::{
+    resolve_host_token, resolve_provider_api_key, resolve_smtp_password, resolve_webhook_url,
+};
+use super::utils::{MASKED_SECRET, command_exists, command_program, mask_url_credentials};
+use crate::application::config::{
+    DefaultsConfig, EmailConfig, HostConfig, NotificationsConfig, ProviderConfig, WebhookConfig,
+};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct ConfigInspection {
     pub searched_paths: Vec<String>,
     pub loaded_paths: Vec<String>,
+    /// 원격 팀 설정 URL/적용 상태(`defaults.remote_config_url` 미설정이면 null)
+    pub remote_config: Option<RemoteConfigStatus>,
     pub defaults: DefaultsConfig,
     pub effective_defaults: EffectiveDefaults,
     pub hosts: BTreeMap<String, HostInspection>,
     pub providers: ProvidersInspection,
+    /// 저장소별 재정의 키와 재정의하는 defaults 키 목록(알림 채널은 `notifications.<채널>`)
+    pub repos: BTreeMap<String, Vec<String>>,
+    /// 정의된 프로필 이름 목록
+    pub profiles: Vec<String>,
+    /// 사용 중인 알림 채널(사용하지 않는 채널은 null)
+    pub notifications: NotificationsInspection,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -32,10 +45,64 @@ pub struct EffectiveDefaults {
     pub update_check_url: Option<String>,
     pub update_download_url: Option<String>,
     pub update_timeout_ms: u64,
+    pub update_check: bool,
+    pub update_check_interval_hours: u64,
+    pub update_repository: String,
+    pub update_public_key: Option<String>,
+    pub reaction_rounds: usize,
+    pub moderator_provider: Option<String>,
+    pub meta_review_provider: Option<String>,
+    pub description_templates: Vec<String>,
+    pub security_pass: bool,
+    pub security_provider: Option<String>,
+    pub test_gap_analysis: bool,
+    pub test_gap_provider: Option<String>,
+    pub test_file_globs: Vec<String>,
+    pub inline_suggestions: bool,
+    pub autofix_provider: Option<String>,
+    pub provider_order: Vec<String>,
+    pub suggest_reviewers: bool,
+    pub chunk_concurrency: usize,
+    pub global_concurrency: usize,
+    pub global_concurrency_store: Option<String>,
+    pub command_allowlist: Vec<String>,
+    pub review_granularity: String,
+    pub claim_ttl_minutes: u64,
+    pub collapse_findings_over: usize,
+    pub collapse_reaction_lines: usize,
+    pub min_finding_confidence: f32,
+    pub low_confidence_below: f32,
+    pub dedupe_policy: String,
+    /// 설정값(미지정이면 None, 호스트 한도 사용)
+    pub max_comment_chars: Option<usize>,
+    pub comment_overflow: String,
+    pub diff_include: Vec<String>,
+    pub diff_exclude: Vec<String>,
+    pub remote_review_guides: Vec<String>,
+    pub prompt_template_dir: Option<String>,
+    pub comment_template_dir: Option<String>,
+    pub comment_footer: bool,
+    /// `이름=내부 심각도` 목록(심각한 순서)
+    pub severity_levels: Vec<String>,
+    pub redact_secrets: bool,
+    pub redact_patterns: Vec<String>,
+    pub remote_config_url: Option<String>,
+    pub remote_config_ttl_minutes: u64,
+    /// `glob -> 가이드 파일` 목록
+    pub path_guides: Vec<String>,
+    /// `이름 <- 라벨/제목 정규식` 목록(판별 순서)
+    pub pr_types: Vec<String>,
+    pub run_history: bool,
+    pub response_cache_ttl_hours: u64,
+    pub user_agent: String,
+    pub pre_prompt_hook: Option<String>,
+    pub post_findings_hook: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize)]
 pub struct HostInspection {
+    /// 해석된 토큰(`--show-secrets`가 아니면 `****`)
+    pub token: Option<String>,
     pub token_source: Option<String>,
     pub token_resolved: bool,
     pub api_base: Option<String>,
@@ -46,6 +113,8 @@ pub struct ProvidersInspection {
     pub openai: Option<ProviderInspection>,
     pub anthropic: Option<ProviderInspection>,
     pub gemini: Option<ProviderInspection>,
+    /// 플러그인/mock provider(`kind: "plugin"` | `"mock"`) 이름별 상태
+    pub plugins: BTreeMap<String, ProviderInspection>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -59,17 +128,84 @@ pub struct ProviderInspection {
     pub command_available: bool,
     pub auth_status: String,
     pub auth_hint: Option<String>,
+    /// 해석된 API 키(`--show-secrets`가 아니면 `****`)
+    pub api_key: Option<String>,
     pub api_key_source: Option<String>,
     pub api_key_resolved: bool,
 }
 
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationsInspection {
+    pub slack: Option<NotificationInspection>,
+    pub teams: Option<NotificationInspection>,
+    pub discord: Option<NotificationInspection>,
+    pub email: Option<EmailNotificationInspection>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationInspection {
+    /// `always` | `failure`
+    pub on: String,
+    /// 해석된 webhook URL(`--show-secrets`가 아니면 `****`)
+    pub webhook_url: Option<String>,
+    pub webhook_url_source: Option<String>,
+    pub webhook_url_resolved: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct EmailNotificationInspection {
+    /// `always` | `failure`
+    pub on: String,
+    pub smtp_host: Option<String>,
+    pub smtp_port: u16,
+    /// `starttls` | `tls` | `none`
+    pub smtp_security: String,
+    pub smtp_ca_file: Option<String>,
+    pub username: Option<String>,
+    /// 해석된 비밀번호(`--show-secrets`가 아니면 `****`)
+    pub password: Option<String>,
+    pub password_source: Option<String>,
+    pub password_resolved: bool,
+    pub from: Option<String>,
+    /// 전역 수신자(저장소별 수신자는 `repos.<key>.notifications.email.to`)
+    pub to: Vec<String>,
+}
+
 impl ConfigInspection {
-    pub(crate) fn from_loaded(loaded: LoadedConfig) -> Self {
+    pub(crate) fn from_loaded(loaded: LoadedConfig, show_secrets: bool) -> Self {
         let mut hosts = BTreeMap::new();
         for (host, cfg) in &loaded.config.hosts {
-            hosts.insert(host.clone(), host_inspection(cfg));
+            hosts.insert(host.clone(), host_inspection(host, cfg, show_secrets));
         }
 
+        let mut repos = BTreeMap::new();
+        for (repo, cfg) in &loaded.config.repos {
+            let mut keys = overridden_keys(&cfg.defaults);
+            keys.extend(
+                notification_channel_names(&cfg.notifications)
+                    .into_iter()
+                    .map(|name| format!("notifications.{name}")),
+            );
+            repos.insert(repo.clone(), keys);
+        }
+
+        // URL 값 설정(`redis://:비밀번호@호스트` 등)은 접속 정보를 가려 출력한다.
+        let mut defaults = loaded.config.defaults.clone();
+        for url in [
+            &mut defaults.global_concurrency_store,
+            &mut defaults.remote_config_url,
+            &mut defaults.update_check_url,
+            &mut defaults.update_download_url,
+        ] {
+            *url = reveal_url(url.as_deref(), show_secrets);
+        }
+        let remote_config = loaded.remote.clone().map(|mut status| {
+            if !show_secrets {
+                status.url = mask_url_credentials(&status.url);
+            }
+            status
+        });
+
         Self {
             searched_paths: loaded
                 .searched_paths
@@ -81,15 +217,102 @@ impl ConfigInspection {
                 .iter()
                 .map(|p| p.display().to_string())
                 .collect(),
-            defaults: loaded.config.defaults.clone(),
+            remote_config,
+            defaults,
             effective_defaults: EffectiveDefaults {
                 max_diff_bytes: loaded.config.max_diff_bytes(),
                 system_prompt: loaded.config.system_prompt(),
                 review_guide_path: loaded.config.defaults.review_guide_path.clone(),
                 comment_language: loaded.config.comment_language().code().to_string(),
-                update_check_url: loaded.config.defaults.update_check_url.clone(),
-                update_download_url: loaded.config.defaults.update_download_url.clone(),
+                update_check_url: reveal_url(
+                    loaded.config.defaults.update_check_url.as_deref(),
+                    show_secrets,
+                ),
+                update_download_url: reveal_url(
+                    loaded.config.defaults.update_download_url.as_deref(),
+                    show_secrets,
+                ),
                 update_timeout_ms: loaded.config.defaults.update_timeout_ms.unwrap_or(1200),
+                update_check: loaded.config.update_check_enabled(),
+                update_check_interval_hours: loaded.config.update_check_interval_hours(),
+                update_repository: loaded.config.update_repository().to_string(),
+                update_public_key: loaded.config.update_public_key().map(ToString::to_string),
+                reaction_rounds: loaded.config.reaction_rounds(),
+                moderator_provider: loaded.config.moderator_provider().map(ToString::to_string),
+                meta_review_provider: loaded
+                    .config
+                    .meta_review_provider()
+                    .map(ToString::to_string),
+                description_templates: loaded.config.description_templates(),
+                security_pass: loaded.config.security_pass(),
+                security_provider: loaded.config.security_provider().map(ToString::to_string),
+                test_gap_analysis: loaded.config.test_gap_analysis(),
+                test_gap_provider: loaded.config.test_gap_provider().map(ToString::to_string),
+                test_file_globs: loaded.config.test_file_globs(),
+                inline_suggestions: loaded.config.inline_suggestions(),
+                autofix_provider: loaded.config.autofix_provider().map(ToString::to_string),
+                provider_order: loaded.config.provider_order(),
+                suggest_reviewers: loaded.config.suggest_reviewers(),
+                chunk_concurrency: loaded.config.chunk_concurrency(),
+                global_concurrency: loaded.config.global_concurrency(),
+                global_concurrency_store: reveal_url(
+                    loaded.config.global_concurrency_store(),
+                    show_secrets,
+                ),
+                command_allowlist: loaded.config.command_allowlist(),
+                review_granularity: loaded.config.review_granularity().code().to_string(),
+                claim_ttl_minutes: loaded.config.claim_ttl_minutes(),
+                collapse_findings_over: loaded.config.summary_collapse().findings_over,
+                collapse_reaction_lines: loaded.config.summary_collapse().reaction_lines,
+                min_finding_confidence: loaded.config.min_finding_confidence(),
+                low_confidence_below: loaded.config.low_confidence_below(),
+                dedupe_policy: loaded.config.dedupe_policy().code().to_string(),
+                max_comment_chars: loaded.config.defaults.max_comment_chars,
+                comment_overflow: loaded.config.comment_overflow().code().to_string(),
+                diff_include: loaded.config.diff_include().to_vec(),
+                diff_exclude: loaded.config.diff_exclude().to_vec(),
+                remote_review_guides: loaded.config.remote_review_guides(),
+                prompt_template_dir: loaded.config.defaults.prompt_template_dir.clone(),
+                comment_template_dir: loaded.config.defaults.comment_template_dir.clone(),
+                comment_footer: loaded.config.comment_footer(),
+                severity_levels: loaded
+                    .config
+                    .severity_taxonomy()
+                    .levels
+                    .iter()
+                    .map(|level| format!("{}={}", level.name, level.severity.code()))
+                    .collect(),
+                redact_secrets: loaded.config.redact_secrets(),
+                redact_patterns: loaded.config.redact_patterns().to_vec(),
+                remote_config_url: reveal_url(loaded.config.remote_config_url(), show_secrets),
+                remote_config_ttl_minutes: loaded.config.remote_config_ttl_minutes(),
+                path_guides: loaded
+                    .config
+                    .path_guides()
+                    .iter()
+                    .map(|entry| format!("{} -> {}", entry.glob, entry.guide))
+                    .collect(),
+                pr_types: loaded
+                    .config
+                    .pr_types()
+                    .iter()
+                    .map(|pr_type| {
+                        let mut rules: Vec<String> = pr_type
+                            .labels
+                            .iter()
+                            .map(|label| format!("label {label}"))
+                            .collect();
+                        if let Some(pattern) = &pr_type.title_pattern {
+                            rules.push(format!("title {pattern}"));
+                        }
+                        format!("{} <- {}", pr_type.name, rules.join(", "))
+                    })
+                    .collect(),
+                run_history: loaded.config.run_history_enabled(),
+                response_cache_ttl_hours: loaded.config.response_cache_ttl_hours(),
+                user_agent: loaded.config.user_agent(),
+                pre_prompt_hook: loaded.config.defaults.pre_prompt_hook.clone(),
+                post_findings_hook: loaded.config.defaults.post_findings_hook.clone(),
             },
             hosts,
             providers: ProvidersInspection {
@@ -98,26 +321,40 @@ impl ConfigInspection {
                     .providers
                     .openai
                     .as_ref()
-                    .map(|cfg| ProviderInspection::from_config(cfg, "codex")),
+                    .map(|cfg| ProviderInspection::from_config(cfg, "codex", show_secrets)),
                 anthropic: loaded
                     .config
                     .providers
                     .anthropic
                     .as_ref()
-                    .map(|cfg| ProviderInspection::from_config(cfg, "claude")),
+                    .map(|cfg| ProviderInspection::from_config(cfg, "claude", show_secrets)),
                 gemini: loaded
                     .config
                     .providers
                     .gemini
                     .as_ref()
-                    .map(|cfg| ProviderInspection::from_config(cfg, "gemini")),
+                    .map(|cfg| ProviderInspection::from_config(cfg, "gemini", show_secrets)),
+                plugins: loaded
+                    .config
+                    .providers
+                    .plugin_providers()
+                    .map(|(name, cfg)| {
+                        (
+                            name.to_string(),
+                            ProviderInspection::from_plugin_config(cfg),
+                        )
+                    })
+                    .collect(),
             },
+            repos,
+            profiles: loaded.config.profile_names(),
+            notifications: notifications_inspection(&loaded.config.notifications, show_secrets),
         }
     }
 }
 
 impl ProviderInspection {
-    fn from_config(cfg: &ProviderConfig, default_command: &str) -> Self {
+    fn from_config(cfg: &ProviderConfig, default_command: &str, show_secrets: bool) -> Self {
         let enabled = cfg.is_enabled();
         let api_resolution = resolve_provider_api_key(cfg);
         let api_ready = api_resolution.credential.is_some();
@@ -171,12 +408,73 @@ impl ProviderInspection {
             command_available,
             auth_status,
             auth_hint,
+            api_key: reveal_secret(api_resolution.credential, show_secrets),
             api_key_source: api_resolution.source,
             api_key_resolved: api_ready,
         }
     }
 }
 
+impl ProviderInspection {
+    /// 플러그인 provider는 인증 개념이 없으므로 명령 존재 여부만 본다.
+    fn from_plugin_config(cfg: &ProviderConfig) -> Self {
+        let enabled = cfg.is_enabled();
+        if cfg.is_mock() {
+            return Self {
+                enabled,
+                resolved_mode: if enabled { "mock" } else { "disabled" }.to_string(),
+                runnable: enabled,
+                command: None,
+                args: Vec::new(),
+                use_stdin: false,
+                command_available: false,
+                auth_status: if enabled { "ok" } else { "disabled" }.to_string(),
+                auth_hint: None,
+                api_key: None,
+                api_key_source: None,
+                api_key_resolved: false,
+            };
+        }
+        let command = cfg
+            .command
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(ToString::to_string);
+        let command_available = command.as_deref().is_some_and(command_exists);
+        let (auth_status, auth_hint) = if !enabled {
+            ("disabled".to_string(), None)
+        } else if command.is_none() {
+            (
+                "missing_cli".to_string(),
+                Some("hint: set providers.<name>.command to the plugin executable".to_string()),
+            )
+        } else if !command_available {
+            (
+                "missing_cli".to_string(),
+                Some("hint: plugin command not found in PATH".to_string()),
+            )
+        } else {
+            ("ok".to_string(), None)
+        };
+
+        Self {
+            enabled,
+            resolved_mode: if enabled { "plugin" } else { "disabled" }.to_string(),
+            runnable: enabled && command_available,
+            command,
+            args: cfg.args.clone().unwrap_or_default(),
+            use_stdin: true,
+            command_available,
+            auth_status,
+            auth_hint,
+            api_key: None,
+            api_key_source: None,
+            api_key_resolved: false,
+        }
+    }
+}
+
 fn probe_provider_cli_auth(default_command: &str, program: &str) -> (String, Option<String>) {
     match default_command {
         "codex" => probe_codex_cli_auth(program),
@@ -190,7 +488,9 @@ fn probe_provider_cli_auth(default_command: &str, program: &str) -> (String, Opt
 }
 
 fn probe_codex_cli_auth(program: &str) -> (String, Option<String>) {
-    let output = Command::new(program).args(["login", "status"]).output();
+    let output = Command::new(command_program(program))
+        .args(["login", "status"])
+        .output();
     match output {
         Ok(out) if out.status.success() => ("ok".to_string(), None),
         Ok(_) => (
@@ -205,7 +505,9 @@ fn probe_codex_cli_auth(program: &str) -> (String, Option<String>) {
 }
 
 fn probe_claude_cli_auth(program: &str) -> (String, Option<String>) {
-    let output = Command::new(program).args(["auth", "status"]).output();
+    let output = Command::new(command_program(program))
+        .args(["auth", "status"])
+        .output();
     match output {
         Ok(out) => {
             let stdout = String::from_utf8_lossy(&out.stdout);
@@ -288,9 +590,13 @@ fn has_non_empty_env(key: &str) -> bool {
         .is_some_and(|v| !v.trim().is_empty())
 }
 
-fn host_inspection(cfg: &HostConfig) -> HostInspection {
-    let token_resolution = resolve_host_token(Some(cfg)).ok();
+fn host_inspection(host: &str, cfg: &HostConfig, show_secrets: bool) -> HostInspection {
+    let token_resolution = resolve_host_token(host, Some(cfg)).ok();
     HostInspection {
+        token: reveal_secret(
+            token_resolution.as_ref().and_then(|r| r.token.clone()),
+            show_secrets,
+        ),
         token_source: token_resolution.as_ref().and_then(|r| r.source.clone()),
         token_resolved: token_resolution
             .as_ref()
@@ -299,3 +605,100 @@ fn host_inspection(cfg: &HostConfig) -> HostInspection {
         api_base: cfg.api_base.clone(),
     }
 }
+
+fn notifications_inspection(
+    cfg: &NotificationsConfig,
+    show_secrets: bool,
+) -> NotificationsInspection {
+    let webhook = |channel: &Option<WebhookConfig>| {
+        channel
+            .as_ref()
+            .filter(|cfg| cfg.is_enabled())
+            .map(|cfg| notification_inspection(cfg, show_secrets))
+    };
+    NotificationsInspection {
+        slack: webhook(&cfg.slack),
+        teams: webhook(&cfg.teams),
+        discord: webhook(&cfg.discord),
+        email: cfg
+            .email_channel()
+            .map(|email| email_notification_inspection(email, show_secrets)),
+    }
+}
+
+/// 설정이 있는 알림 채널 이름 목록.
+fn notification_channel_names(cfg: &NotificationsConfig) -> Vec<&'static str> {
+    [
+        ("slack", cfg.slack.is_some()),
+        ("teams", cfg.teams.is_some()),
+        ("discord", cfg.discord.is_some()),
+        ("email", cfg.email.is_some()),
+    ]
+    .into_iter()
+    .filter_map(|(name, set)| set.then_some(name))
+    .collect()
+}
+
+fn email_notification_inspection(
+    cfg: &EmailConfig,
+    show_secrets: bool,
+) -> EmailNotificationInspection {
+    let resolution = resolve_smtp_password(cfg);
+    EmailNotificationInspection {
+        on: if cfg.wants(true) { "always" } else { "failure" }.to_string(),
+        smtp_host: cfg.smtp_host.clone(),
+        smtp_port: cfg.port(),
+        smtp_security: cfg.security(),
+        smtp_ca_file: cfg.smtp_ca_file.clone(),
+        username: cfg.username.clone(),
+        password_resolved: resolution.credential.is_some(),
+        password: reveal_secret(resolution.credential, show_secrets),
+        password_source: resolution.source,
+        from: cfg.from.clone(),
+        to: cfg.recipients(),
+    }
+}
+
+fn notification_inspection(cfg: &WebhookConfig, show_secrets: bool) -> NotificationInspection {
+    let resolution = resolve_webhook_url(cfg);
+    NotificationInspection {
+        on: if cfg.wants(true) { "always" } else { "failure" }.to_string(),
+        webhook_url_resolved: resolution.credential.is_some(),
+        webhook_url: reveal_secret(resolution.credential, show_secrets),
+        webhook_url_source: resolution.source,
+    }
+}
+
+/// 해석된 비밀값을 출력용으로 변환한다. `show_secrets`가 아니면 값 대신 `****`만 남긴다.
+fn reveal_secret(secret: Option<String>, show_secrets: bool) -> Option<String> {
+    secret.map(|value| {
+        if show_secrets {
+            value
+        } else {
+            MASKED_SECRET.to_string()
+        }
+    })
+}
+
+/// URL 값 설정을 출력용으로 변환한다. `show_secrets`가 아니면 접속 정보(비밀번호)를 가린다.
+fn reveal_url(url: Option<&str>, show_secrets: bool) -> Option<String> {
+    url.map(|url| {
+        if show_secrets {
+            url.to_string()
+        } else {
+            mask_url_credentials(url)
+        }
+    })
+}
+
+/// 값이 지정된(null이 아닌) defaults 키 목록.
+fn overridden_keys(defaults: &DefaultsConfig) -> Vec<String> {
+    match serde_json::to_value(defaults) {
+        Ok(Value::Object(map)) => map
+            .into_iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(key, _)| key)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
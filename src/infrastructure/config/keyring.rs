@@ -0,0 +1,52 @@
+//! OS 키체인(keyring) 비밀값 조회/저장.
+//!
+//! - macOS: Keychain (login keychain generic password)
+//! - Windows: Credential Manager
+//! - Linux 등: freedesktop Secret Service (GNOME Keyring/KWallet)
+//! - 항목 이름은 `<service>/<account>` 형식이며 `/`가 없으면 service는 `repopilot`이다.
+//!
+//! 외부 명령을 거치지 않고 OS API를 직접 호출하므로 비밀값이 명령줄 인자(프로세스 목록)에 드러나지 않는다.
+
+use anyhow::{Context, Result, bail};
+use keyring::{Entry, Error as KeyringError};
+
+const DEFAULT_SERVICE: &str = "repopilot";
+
+/// 키체인 항목을 읽는다. 항목이 없으면 None이고, 키체인에 접근하지 못한 경우는 오류다.
+pub(crate) fn read_secret(entry: &str) -> Result<Option<String>> {
+    let keyring_entry = open_entry(entry)?;
+    match keyring_entry.get_password() {
+        Ok(secret) => {
+            let secret = secret.trim().to_string();
+            Ok((!secret.is_empty()).then_some(secret))
+        }
+        Err(KeyringError::NoEntry) => Ok(None),
+        Err(err) => Err(err).with_context(|| format!("failed to read keyring entry '{entry}'")),
+    }
+}
+
+/// 키체인 항목에 비밀값을 저장한다(기존 값은 덮어쓴다).
+pub(crate) fn store_secret(entry: &str, secret: &str) -> Result<()> {
+    let keyring_entry = open_entry(entry)?;
+    if secret.trim().is_empty() {
+        bail!("refusing to store an empty secret");
+    }
+    keyring_entry
+        .set_password(secret.trim())
+        .with_context(|| format!("failed to store keyring entry '{entry}'"))
+}
+
+fn open_entry(entry: &str) -> Result<Entry> {
+    let (service, account) = split_entry(entry)?;
+    Entry::new(service, account)
+        .with_context(|| format!("OS keyring is not available for entry '{entry}'"))
+}
+
+fn split_entry(entry: &str) -> Result<(&str, &str)> {
+    let entry = entry.trim();
+    let (service, account) = entry.split_once('/').unwrap_or((DEFAULT_SERVICE, entry));
+    if service.trim().is_empty() || account.trim().is_empty() {
+        bail!("invalid keyring entry '{entry}' (expected <service>/<account>, e.g. repopilot/github.com)");
+    }
+    Ok((service.trim(), account.trim()))
+}
@@ -4,83 +4,197 @@ use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use anyhow::{Context, Result};
-use serde_json::json;
+use anyhow::{Context, Result, bail};
+use serde_json::{Value, json};
 
+use super::format::{CONFIG_FILE_NAMES, ConfigFormat};
+use super::migrate::{CURRENT_CONFIG_VERSION, migrate_value, notify_migrated};
+use super::remote::{RemoteConfigStatus, load_remote_config};
+use super::validate::check_config_value;
 use crate::application::config::Config;
 use crate::application::config::DEFAULT_SYSTEM_PROMPT;
+use crate::application::ports::{InitializedProject, ProjectInit, VcsAuthKind};
+use crate::infrastructure::state;
 
 #[derive(Debug, Clone)]
 pub(crate) struct LoadedConfig {
     pub config: Config,
     pub searched_paths: Vec<PathBuf>,
     pub loaded_paths: Vec<PathBuf>,
+    /// `defaults.remote_config_url` 팀 설정 상태(미설정이면 None)
+    pub remote: Option<RemoteConfigStatus>,
 }
 
-/// 우선순위 경로를 순회해 JSON 설정을 병합한다.
+/// 우선순위 경로를 순회해 JSON/TOML/YAML 설정을 병합한다.
 pub(crate) fn load_merged_config() -> Result<LoadedConfig> {
     // 낮은 우선순위에서 높은 우선순위 순서로 병합한다.
     let mut merged = Config::default();
     let mut loaded_paths = Vec::new();
     let paths = config_paths();
 
-    if let Ok(path) = env::var("REPOPILOT_CONFIG")
-        && !Path::new(&path).exists()
-    {
-        bootstrap_template_bundle(Path::new(&path))?;
-    }
-
+    // 설정 파일이 하나도 없으면 기본값으로 진행한다. 파일 생성은 `repopilot init`이 맡는다.
     for path in &paths {
         if !path.exists() {
             continue;
         }
 
-        let raw = fs::read_to_string(path)
-            .with_context(|| format!("failed to read config at {}", path.display()))?;
-        let parsed: Config = serde_json::from_str(&raw)
-            .with_context(|| format!("failed to parse JSON in {}", path.display()))?;
-        merged.merge_from(parsed);
+        merged.merge_from(read_config_file(path)?);
         loaded_paths.push(path.to_path_buf());
     }
 
-    if loaded_paths.is_empty() {
-        // 최초 실행 경험을 위해 로컬 기본 설정 템플릿을 자동 생성한다.
-        let bootstrap_target = default_bootstrap_config_path();
-        bootstrap_template_bundle(&bootstrap_target)?;
-
-        let raw = fs::read_to_string(&bootstrap_target).with_context(|| {
-            format!(
-                "failed to read bootstrapped config at {}",
-                bootstrap_target.display()
-            )
-        })?;
-        let parsed: Config = serde_json::from_str(&raw).with_context(|| {
-            format!(
-                "failed to parse bootstrapped JSON in {}",
-                bootstrap_target.display()
-            )
-        })?;
-        merged.merge_from(parsed);
-        loaded_paths.push(bootstrap_target);
-    }
+    // 원격 팀 설정은 로컬 파일보다 낮은 우선순위로 깔고 그 위에 로컬 값을 덮어쓴다.
+    let remote = load_remote_config(&merged).map(|(team, status)| {
+        if let Some(team) = team {
+            let mut layered = team;
+            layered.merge_from(merged.clone());
+            merged = layered;
+        }
+        status
+    });
 
     Ok(LoadedConfig {
         config: merged,
         searched_paths: paths,
         loaded_paths,
+        remote,
     })
 }
 
+/// 설정 파일 하나를 확장자에 맞는 형식으로 읽어 역직렬화한다.
+fn read_config_file(path: &Path) -> Result<Config> {
+    let value = read_config_value(path)?;
+    config_from_value(path, value)
+}
+
+/// 값 트리를 스키마 기준으로 검증한 뒤 `Config`로 역직렬화한다.
+/// 스키마 문제가 있으면 위치/제안을 담은 검증 결과로 오류를 보고한다.
+pub(super) fn config_from_value(path: &Path, value: Value) -> Result<Config> {
+    let issues = check_config_value(path, &value);
+    if !issues.is_empty() {
+        let lines = issues
+            .iter()
+            .map(|issue| format!("  - {issue}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        bail!(
+            "invalid config in {}:\n{lines}\nhint: run `repopilot config validate` after fixing",
+            path.display()
+        );
+    }
+    serde_json::from_value(value)
+        .with_context(|| format!("invalid config in {}", path.display()))
+}
+
+/// 설정 파일을 읽어 파싱/스키마 마이그레이션/환경변수 치환까지 마친 값 트리를 반환한다(역직렬화 전 단계).
+pub(super) fn read_config_value(path: &Path) -> Result<Value> {
+    let format = ConfigFormat::from_path(path);
+    let raw = fs::read_to_string(path)
+        .with_context(|| format!("failed to read config at {}", path.display()))?;
+    parse_config_text(format, &raw, path)
+}
+
+/// 설정 원문을 파싱하고 스키마 마이그레이션/환경변수 치환을 적용한다.
+/// `origin`은 오류/안내 메시지에 쓰는 파일 경로 또는 URL이다.
+pub(super) fn parse_config_text(format: ConfigFormat, raw: &str, origin: &Path) -> Result<Value> {
+    let mut value = format
+        .parse(raw)
+        .with_context(|| format!("failed to parse {} in {}", format.label(), origin.display()))?;
+    if let Some(steps) = migrate_value(&mut value)
+        .with_context(|| format!("failed to migrate {}", origin.display()))?
+    {
+        notify_migrated(origin, &steps);
+    }
+    expand_env_vars(&mut value, &mut Vec::new())
+        .with_context(|| format!("failed to expand environment variables in {}", origin.display()))?;
+    Ok(value)
+}
+
+/// 문자열 값의 `${VAR}`/`${VAR:-default}`를 환경변수로 치환한다(`$${`는 `${` 리터럴).
+/// 기본값 없이 설정되지 않은 변수는 어느 키에서 났는지와 함께 오류로 보고한다.
+fn expand_env_vars(value: &mut Value, path: &mut Vec<String>) -> Result<()> {
+    match value {
+        Value::String(text) if text.contains('$') => {
+            *text = expand_env_in_str(text).with_context(|| format!("at '{}'", path.join(".")))?;
+        }
+        Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                path.push(idx.to_string());
+                expand_env_vars(item, path)?;
+                path.pop();
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map.iter_mut() {
+                path.push(key.clone());
+                expand_env_vars(item, path)?;
+                path.pop();
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+fn expand_env_in_str(text: &str) -> Result<String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(idx) = rest.find('$') {
+        out.push_str(&rest[..idx]);
+        let after = &rest[idx + 1..];
+        if let Some(escaped) = after.strip_prefix("${") {
+            out.push_str("${");
+            rest = escaped;
+            continue;
+        }
+        let Some(body) = after.strip_prefix('{') else {
+            out.push('$');
+            rest = after;
+            continue;
+        };
+        let Some(end) = body.find('}') else {
+            bail!("unterminated '${{' in '{text}'");
+        };
+        let expr = &body[..end];
+        let (name, default) = match expr.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (expr, None),
+        };
+        let valid_name = !name.is_empty()
+            && name
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '_');
+        if !valid_name {
+            bail!("invalid variable reference '${{{expr}}}'");
+        }
+        // `:-`는 셸과 같이 비어 있는 값도 미설정으로 본다.
+        match (env::var(name), default) {
+            (Ok(value), Some(default)) if value.is_empty() => out.push_str(default),
+            (Ok(value), _) => out.push_str(&value),
+            (Err(_), Some(default)) => out.push_str(default),
+            (Err(_), None) => bail!("environment variable {name} is not set"),
+        }
+        rest = &body[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
 /// 기본 + 사용자 + 프로젝트 + 명시 경로 순으로 병합 경로를 구성한다.
+/// 같은 디렉터리에서는 config.json -> config.toml -> config.yaml/yml 순으로 병합된다.
 pub fn config_paths() -> Vec<PathBuf> {
     // 낮은 우선순위 -> 높은 우선순위 순서로 병합됨.
-    let mut paths = vec![PathBuf::from("/etc/repopilot/config.json")];
+    let mut dirs = vec![PathBuf::from("/etc/repopilot")];
 
-    if let Some(base) = dirs::config_dir() {
-        paths.push(base.join("repopilot").join("config.json"));
+    if let Some(dir) = state::config_dir() {
+        dirs.push(dir);
     }
 
-    paths.push(PathBuf::from(".repopilot/config.json"));
+    dirs.push(PathBuf::from(".repopilot"));
+
+    let mut paths: Vec<PathBuf> = dirs
+        .iter()
+        .flat_map(|dir| CONFIG_FILE_NAMES.iter().map(move |name| dir.join(name)))
+        .collect();
 
     if let Ok(path) = env::var("REPOPILOT_CONFIG") {
         paths.push(Path::new(&path).to_path_buf());
@@ -91,7 +205,8 @@ pub fn config_paths() -> Vec<PathBuf> {
 
 /// 편집 대상 설정 파일 경로를 결정한다.
 /// 로딩된 파일 중 최고 우선순위 경로를 반환하고,
-/// 로딩된 파일이 없으면 `.repopilot/config.json`을 생성한다.
+/// 로딩된 파일이 없으면 사용자 설정 파일(`~/.config/repopilot/config.json`)을 생성한다.
+/// 작업 디렉터리에는 쓰지 않는다(프로젝트 설정은 `repopilot init`으로만 만든다).
 pub(crate) fn editable_config_path() -> Result<PathBuf> {
     let loaded = load_merged_config();
 
@@ -102,27 +217,33 @@ pub(crate) fn editable_config_path() -> Result<PathBuf> {
         return Ok(last.clone());
     }
 
-    // 설정 파일이 없으면 프로젝트 로컬 기본 템플릿을 생성한다.
-    let fallback = PathBuf::from(".repopilot/config.json");
-    if let Some(parent) = fallback.parent() {
-        fs::create_dir_all(parent)
-            .with_context(|| format!("failed to create directory {}", parent.display()))?;
-    }
+    // 설정 파일이 없으면 사용자 설정 디렉터리에 빈 설정을 생성한다.
+    let dir = state::config_dir().context("cannot determine user config directory")?;
+    fs::create_dir_all(&dir)
+        .with_context(|| format!("failed to create directory {}", dir.display()))?;
+    let fallback = dir.join("config.json");
     fs::write(&fallback, "{}\n")
         .with_context(|| format!("failed to create default config at {}", fallback.display()))?;
     Ok(fallback)
 }
 
-fn default_bootstrap_config_path() -> PathBuf {
+/// `repopilot init` 대상 경로. `REPOPILOT_CONFIG`가 있으면 그 경로를 쓴다.
+fn project_config_path() -> PathBuf {
     if let Ok(path) = env::var("REPOPILOT_CONFIG") {
         return PathBuf::from(path);
     }
     PathBuf::from(".repopilot/config.json")
 }
 
-fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
-    if config_path.exists() {
-        return Ok(());
+/// 선택한 호스트/provider로 프로젝트 설정과 리뷰 가이드 템플릿을 생성한다.
+/// 설정 파일이 이미 있으면 `force` 없이는 덮어쓰지 않고, 리뷰 가이드는 있으면 그대로 둔다.
+pub(crate) fn init_project_bundle(init: &ProjectInit) -> Result<InitializedProject> {
+    let config_path = project_config_path();
+    if config_path.exists() && !init.force {
+        bail!(
+            "{} already exists (use --force to overwrite)",
+            config_path.display()
+        );
     }
 
     if let Some(parent) = config_path.parent()
@@ -132,8 +253,9 @@ fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
             .with_context(|| format!("failed to create directory {}", parent.display()))?;
     }
 
-    let guide_path = default_review_guide_path(config_path);
-    if !guide_path.exists() {
+    let guide_path = default_review_guide_path(&config_path);
+    let guide_created = !guide_path.exists();
+    if guide_created {
         if let Some(parent) = guide_path.parent()
             && !parent.as_os_str().is_empty()
         {
@@ -154,27 +276,27 @@ fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
         guide_path.display().to_string()
     };
 
+    let hosts: serde_json::Map<String, Value> = init
+        .hosts
+        .iter()
+        .map(|(host, kind)| (host.clone(), host_template(host, *kind)))
+        .collect();
+    let enabled = |id: &str| init.providers.iter().any(|p| p == id);
+
     let template = json!({
+        "config_version": CURRENT_CONFIG_VERSION,
         "defaults": {
             "max_diff_bytes": 120000,
             "system_prompt": DEFAULT_SYSTEM_PROMPT,
             "review_guide_path": review_guide_path,
-            "comment_language": "ko",
-            "update_timeout_ms": 1200
-        },
-        "hosts": {
-            "github.com": {
-                "token_env": "GITHUB_TOKEN",
-                "token_command": ["gh", "auth", "token"]
-            },
-            "gitlab.com": {
-                "token_env": "GITLAB_TOKEN",
-                "token_command": ["glab", "auth", "token"]
-            }
+            "comment_language": init.comment_language,
+            "update_timeout_ms": 1200,
+            "reaction_rounds": 1
         },
+        "hosts": hosts,
         "providers": {
             "openai": {
-                "enabled": true,
+                "enabled": enabled("openai"),
                 "api_key_env": "OPENAI_API_KEY",
                 "model": "gpt-4.1-mini",
                 "command": "codex",
@@ -183,7 +305,7 @@ fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
                 "args": ["exec"]
             },
             "anthropic": {
-                "enabled": true,
+                "enabled": enabled("anthropic"),
                 "api_key_env": "ANTHROPIC_API_KEY",
                 "model": "claude-3-7-sonnet-latest",
                 "command": "claude",
@@ -193,7 +315,7 @@ fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
                 "args": ["-p", "{prompt}"]
             },
             "gemini": {
-                "enabled": true,
+                "enabled": enabled("gemini"),
                 "api_key_env": "GEMINI_API_KEY",
                 "model": "gemini-2.0-flash",
                 "command": "gemini",
@@ -205,9 +327,31 @@ fn bootstrap_template_bundle(config_path: &Path) -> Result<()> {
         }
     });
 
-    let rendered = serde_json::to_string_pretty(&template)?;
-    fs::write(config_path, format!("{rendered}\n"))
-        .with_context(|| format!("failed to create config template at {}", config_path.display()))
+    let rendered = ConfigFormat::from_path(&config_path).render(&template)?;
+    fs::write(&config_path, rendered)
+        .with_context(|| format!("failed to create config at {}", config_path.display()))?;
+
+    Ok(InitializedProject {
+        config_path,
+        guide_path,
+        guide_created,
+    })
+}
+
+/// 호스트 토큰 설정 템플릿. 기본 호스트가 아니면 CLI 토큰 명령에 `--hostname`을 붙인다.
+fn host_template(host: &str, kind: VcsAuthKind) -> Value {
+    let (token_env, cli) = match kind {
+        VcsAuthKind::GitHub => ("GITHUB_TOKEN", "gh"),
+        VcsAuthKind::GitLab => ("GITLAB_TOKEN", "glab"),
+    };
+    let mut token_command = vec![cli, "auth", "token"];
+    if host != "github.com" && host != "gitlab.com" {
+        token_command.extend(["--hostname", host]);
+    }
+    json!({
+        "token_env": token_env,
+        "token_command": token_command
+    })
 }
 
 fn default_review_guide_path(config_path: &Path) -> PathBuf {
@@ -0,0 +1,223 @@
+//! 설정 스키마 버전(`config_version`) 마이그레이션.
+//!
+//! 로더는 파일을 역직렬화하기 전에 오래된 레이아웃을 메모리에서 현재 버전으로 올리고,
+//! `repopilot config migrate`는 같은 변환 결과를 파일에 다시 쓴다.
+//! 스키마를 바꿀 때는 `CURRENT_CONFIG_VERSION`을 올리고 `MIGRATIONS`에 단계를 추가한다.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+
+use anyhow::{Context, Result, bail};
+use serde_json::{Map, Value};
+
+use super::edit::render_preserving_style;
+use super::format::ConfigFormat;
+use super::loader::config_paths;
+use crate::application::ports::{ConfigMigration, MigrationOutput};
+use crate::domain::policy::canonical_provider_id;
+
+/// 현재 설정 스키마 버전. `config_version`이 없는 파일은 0으로 본다.
+pub(crate) const CURRENT_CONFIG_VERSION: u64 = 1;
+
+/// `from` 버전을 `from + 1`로 올리는 변환 단계.
+struct Migration {
+    from: u64,
+    description: &'static str,
+    /// 문서를 고치고 실제로 바뀐 내용을 설명하는 줄을 반환한다.
+    apply: fn(&mut Map<String, Value>) -> Vec<String>,
+}
+
+const MIGRATIONS: &[Migration] = &[Migration {
+    from: 0,
+    description: "rename provider alias keys (codex/claude/google) to provider ids",
+    apply: canonical_provider_keys,
+}];
+
+/// 값 트리를 현재 스키마 버전으로 올린다. 이미 최신이면 None.
+pub(crate) fn migrate_value(value: &mut Value) -> Result<Option<ConfigMigrationSteps>> {
+    let Value::Object(map) = value else {
+        return Ok(None);
+    };
+    let from_version = match map.get("config_version") {
+        None | Some(Value::Null) => 0,
+        Some(version) => match version.as_u64() {
+            Some(version) => version,
+            None => bail!("config_version must be a non-negative integer, found {version}"),
+        },
+    };
+    if from_version > CURRENT_CONFIG_VERSION {
+        bail!(
+            "config_version {from_version} is newer than this repopilot supports ({CURRENT_CONFIG_VERSION}); upgrade repopilot"
+        );
+    }
+    if from_version == CURRENT_CONFIG_VERSION {
+        return Ok(None);
+    }
+
+    let mut changes = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.from >= from_version) {
+        let applied = (migration.apply)(map);
+        if applied.is_empty() {
+            continue;
+        }
+        changes.push(format!("v{}: {}", migration.from, migration.description));
+        changes.extend(applied.into_iter().map(|line| format!("  {line}")));
+    }
+
+    // 버전 키는 사람이 바로 보도록 문서 맨 앞에 둔다.
+    let rest = std::mem::take(map);
+    map.insert(
+        "config_version".to_string(),
+        Value::from(CURRENT_CONFIG_VERSION),
+    );
+    map.extend(rest.into_iter().filter(|(key, _)| key != "config_version"));
+
+    Ok(Some(ConfigMigrationSteps {
+        from_version,
+        changes,
+    }))
+}
+
+/// 메모리 마이그레이션 결과.
+pub(crate) struct ConfigMigrationSteps {
+    pub from_version: u64,
+    pub changes: Vec<String>,
+}
+
+/// 로더가 오래된 파일을 만났을 때 파일마다 한 번만 안내한다.
+pub(crate) fn notify_migrated(path: &Path, steps: &ConfigMigrationSteps) {
+    static NOTIFIED: OnceLock<Mutex<HashSet<PathBuf>>> = OnceLock::new();
+    let mut notified = NOTIFIED
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if notified.insert(path.to_path_buf()) {
+        eprintln!(
+            "note: {} uses config schema v{} (current v{CURRENT_CONFIG_VERSION}); upgraded in memory. Run `repopilot config migrate` to rewrite the file.",
+            path.display(),
+            steps.from_version
+        );
+    }
+}
+
+/// 탐색 경로의 오래된 설정 파일을 현재 버전으로 다시 쓴다. 원본은 `<파일>.bak`으로 남긴다.
+/// TOML은 제자리에서 고쳐 주석을 보존한다. 주석이 있는 YAML은 다시 쓰면 주석이 사라지므로
+/// `force`가 아니면 원본을 두고 변환 결과를 `<파일>.migrated`에 쓴다.
+pub(crate) fn migrate_config_files(force: bool) -> Result<Vec<ConfigMigration>> {
+    let mut migrated = Vec::new();
+    for path in config_paths() {
+        if !path.exists() {
+            continue;
+        }
+        let format = ConfigFormat::from_path(&path);
+        let original = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read config at {}", path.display()))?;
+        if original.trim().is_empty() {
+            continue;
+        }
+        // 환경변수 치환 전 원문을 바꿔야 `${VAR}`가 파일에 그대로 남는다.
+        let mut value = format
+            .parse(&original)
+            .with_context(|| format!("failed to parse {} in {}", format.label(), path.display()))?;
+        let Some(steps) = migrate_value(&mut value)
+            .with_context(|| format!("failed to migrate {}", path.display()))?
+        else {
+            continue;
+        };
+
+        let output = if !force && format.update_drops_comments(&original) {
+            let beside = sibling_path(&path, "migrated");
+            let rendered = render_preserving_style(format, &path, &original, &value, true)?;
+            fs::write(&beside, rendered)
+                .with_context(|| format!("failed to write {}", beside.display()))?;
+            MigrationOutput::WrittenBeside { path: beside }
+        } else {
+            let backup = sibling_path(&path, "bak");
+            fs::write(&backup, &original)
+                .with_context(|| format!("failed to write backup at {}", backup.display()))?;
+            let rendered = render_preserving_style(format, &path, &original, &value, true)?;
+            fs::write(&path, rendered)
+                .with_context(|| format!("failed to write config at {}", path.display()))?;
+            MigrationOutput::Rewritten { backup }
+        };
+
+        migrated.push(ConfigMigration {
+            file: path,
+            output,
+            from_version: steps.from_version,
+            to_version: CURRENT_CONFIG_VERSION,
+            changes: steps.changes,
+        });
+    }
+    Ok(migrated)
+}
+
+/// `<파일>.<suffix>` 경로(예: `config.yaml.bak`).
+fn sibling_path(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(".");
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// v0 → v1: `providers.codex` 같은 별칭 키를 provider id 키로 바꾼다.
+/// 같은 provider의 id 키가 이미 있으면 id 키 값을 우선하고 빠진 필드만 채운다.
+fn canonical_provider_keys(root: &mut Map<String, Value>) -> Vec<String> {
+    let Some(Value::Object(providers)) = root.get_mut("providers") else {
+        return Vec::new();
+    };
+
+    let mut changes = Vec::new();
+    let entries = std::mem::take(providers);
+    let original_keys: HashSet<String> = entries.keys().cloned().collect();
+    let aliases: Vec<(String, &'static str)> = entries
+        .keys()
+        .filter_map(|key| {
+            canonical_provider_id(key)
+                .filter(|id| id != key)
+                .map(|id| (key.clone(), id))
+        })
+        .collect();
+
+    for (key, value) in entries {
+        let Some(id) = aliases
+            .iter()
+            .find(|(alias, _)| *alias == key)
+            .map(|(_, id)| *id)
+        else {
+            match providers.get_mut(&key) {
+                // 별칭이 먼저 나와 이미 자리를 잡았으면 id 키 값으로 덮어쓴다.
+                Some(Value::Object(existing)) => {
+                    if let Value::Object(fields) = value {
+                        existing.extend(fields);
+                    }
+                }
+                _ => {
+                    providers.insert(key, value);
+                }
+            }
+            continue;
+        };
+
+        match providers.get_mut(id) {
+            Some(Value::Object(existing)) => {
+                if let Value::Object(fields) = value {
+                    for (field, field_value) in fields {
+                        existing.entry(field).or_insert(field_value);
+                    }
+                }
+            }
+            _ => {
+                providers.insert(id.to_string(), value);
+            }
+        }
+        if original_keys.contains(id) {
+            changes.push(format!("providers.{key} merged into providers.{id}"));
+        } else {
+            changes.push(format!("providers.{key} renamed to providers.{id}"));
+        }
+    }
+    changes
+}
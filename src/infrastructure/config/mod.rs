@@ -1,28 +1,43 @@
-//! 사용자 설정(JSON) 로딩/병합 모듈.
+//! 사용자 설정(JSON/TOML/YAML) 로딩/병합 모듈.
 //! 여러 경로의 설정을 우선순위대로 병합하고, 실행 진단용 정보를 함께 제공한다.
 
+mod edit;
+mod format;
 mod inspection;
+mod keyring;
 mod loader;
+mod migrate;
+mod oauth;
+mod remote;
 mod resolve;
 mod utils;
+mod validate;
 
 use std::path::PathBuf;
 
 use anyhow::Result;
 
+use crate::application::ports::{
+    ConfigMigration, ConfigValidation, InitializedProject, ProjectInit,
+};
+
 pub use crate::application::config::{
-    Config, DefaultsConfig, HostConfig, ProviderCommandSpec, ProviderConfig, ProvidersConfig,
+    Config, DefaultsConfig, EmailConfig, HostConfig, NotificationsConfig, ProviderCommandSpec,
+    ProviderConfig, ProvidersConfig, ProfileConfig, RepoConfig, WebhookConfig,
     DEFAULT_SYSTEM_PROMPT,
 };
 pub use inspection::{
-    ConfigInspection, EffectiveDefaults, HostInspection, ProviderInspection, ProvidersInspection,
+    ConfigInspection, EffectiveDefaults, EmailNotificationInspection, HostInspection,
+    NotificationInspection, NotificationsInspection, ProviderInspection, ProvidersInspection,
 };
 pub use loader::config_paths;
+pub use oauth::{gitlab_device_login, oauth_keyring_entry};
+pub use remote::RemoteConfigStatus;
 pub use resolve::{
     ProviderCredentialResolution, resolve_host_token, resolve_provider_api_key,
-    provider_api_key_source_label,
+    provider_api_key_source_label, resolve_smtp_password, resolve_webhook_url,
 };
-pub use utils::command_exists;
+pub use utils::{command_exists, command_program, find_command, is_batch_script};
 
 /// 병합된 최종 설정을 로딩한다.
 pub fn load() -> Result<Config> {
@@ -30,14 +45,47 @@ pub fn load() -> Result<Config> {
 }
 
 /// 디버깅/진단용 설정 정보를 구성한다.
-pub fn inspect() -> Result<ConfigInspection> {
+/// `show_secrets`가 false면 해석된 토큰/API 키 값은 `****`로만 표시한다.
+pub fn inspect(show_secrets: bool) -> Result<ConfigInspection> {
     let loaded = loader::load_merged_config()?;
-    Ok(ConfigInspection::from_loaded(loaded))
+    Ok(ConfigInspection::from_loaded(loaded, show_secrets))
 }
 
 /// 설정 진단 결과를 사람이 읽기 쉬운 JSON으로 반환한다.
-pub fn inspect_pretty_json() -> Result<String> {
-    Ok(serde_json::to_string_pretty(&inspect()?)?)
+pub fn inspect_pretty_json(show_secrets: bool) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&inspect(show_secrets)?)?)
+}
+
+/// 탐색 경로의 설정 파일을 스키마 기준으로 검증한다.
+pub fn validate() -> ConfigValidation {
+    validate::validate_config_files()
+}
+
+/// 오래된 스키마의 설정 파일을 현재 버전으로 다시 쓴다.
+/// `force`가 아니면 주석이 사라지는 파일(YAML)은 변환 결과를 옆 파일에 쓴다.
+pub fn migrate(force: bool) -> Result<Vec<ConfigMigration>> {
+    migrate::migrate_config_files(force)
+}
+
+/// 병합된 설정에서 점 표기 키의 값을 읽는다(미설정이면 None).
+pub fn get_value(key: &str, show_secrets: bool) -> Result<Option<serde_json::Value>> {
+    edit::get_value(key, show_secrets)
+}
+
+/// 편집 대상 설정 파일에 점 표기 키의 값을 기록하고 파일 경로를 반환한다.
+/// `force`이면 다시 쓰면서 사라지는 주석이 있어도(YAML) 기록한다.
+pub fn set_value(key: &str, value: &str, force: bool) -> Result<PathBuf> {
+    edit::set_value(key, value, force)
+}
+
+/// OS 키체인 항목(`<service>/<account>`)에 비밀값을 저장한다.
+pub fn store_keyring_secret(entry: &str, secret: &str) -> Result<()> {
+    keyring::store_secret(entry, secret)
+}
+
+/// 프로젝트 설정 파일과 리뷰 가이드 템플릿을 생성한다(`repopilot init`).
+pub fn init_project(init: &ProjectInit) -> Result<InitializedProject> {
+    loader::init_project_bundle(init)
 }
 
 /// 편집 대상 설정 파일 경로를 반환한다.
@@ -0,0 +1,233 @@
+//! GitLab OAuth device flow 로그인과 저장된 토큰 갱신.
+//!
+//! `hosts.<host>.oauth_client_id`(GitLab에 등록한 non-confidential OAuth 애플리케이션 ID)가 있으면
+//! `glab` 없이 device authorization grant로 로그인하고, access/refresh 토큰을 키체인
+//! `repopilot/<host>/oauth` 항목에 JSON으로 저장한다. 토큰 해석 시 만료가 가까우면 refresh 토큰으로
+//! 새 토큰을 받아 다시 저장한다(GitLab은 refresh 토큰을 한 번 쓰면 교체한다).
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::keyring::{read_secret, store_secret};
+use crate::application::attribution;
+use crate::application::config::HostConfig;
+use crate::infrastructure::clock::unix_now;
+use crate::infrastructure::http::attributed;
+use crate::infrastructure::vcs::gitlab;
+
+const DEVICE_GRANT_TYPE: &str = "urn:ietf:params:oauth:grant-type:device_code";
+// 리뷰 코멘트/노트 작성에 필요한 범위.
+const OAUTH_SCOPE: &str = "api";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+// 만료 직전 토큰으로 리뷰를 시작하지 않도록 이 시간 안에 만료되면 미리 갱신한다.
+const REFRESH_MARGIN_SECS: u64 = 300;
+// slow_down 응답마다 폴링 간격에 더하는 시간(RFC 8628).
+const SLOW_DOWN_SECS: u64 = 5;
+
+/// 키체인에 저장하는 OAuth 토큰 묶음. 로그/오류 메시지에 토큰이 섞이지 않도록 `Debug`를 구현하지 않는다.
+#[derive(Serialize, Deserialize)]
+struct StoredToken {
+    access_token: String,
+    refresh_token: Option<String>,
+    /// access token 만료 시각(unix 초). 응답에 만료 정보가 없으면 None.
+    expires_at: Option<u64>,
+}
+
+/// 호스트의 OAuth 토큰 키체인 항목 이름.
+pub fn oauth_keyring_entry(host: &str) -> String {
+    format!("repopilot/{host}/oauth")
+}
+
+/// device flow로 로그인하고 토큰을 키체인에 저장한다.
+/// 로더/인증 코드는 동기 코드이고 tokio 런타임 안에서도 불리므로 별도 스레드의 런타임에서 요청한다.
+pub fn gitlab_device_login(host: &str, host_cfg: &HostConfig, client_id: &str) -> Result<()> {
+    let base = web_base(host, host_cfg);
+    let client_id = client_id.to_string();
+    let token = run_blocking(async move { device_flow(&base, &client_id).await })?;
+    store_token(host, &token)?;
+    eprintln!(
+        "stored GitLab OAuth token in keyring: {}",
+        oauth_keyring_entry(host)
+    );
+    Ok(())
+}
+
+/// 저장된 OAuth 토큰을 읽고, 만료가 가까우면 갱신한다. 로그인한 적이 없으면 None.
+pub fn resolve_gitlab_oauth_token(
+    host: &str,
+    host_cfg: &HostConfig,
+    client_id: &str,
+) -> Result<Option<String>> {
+    let Some(raw) = read_secret(&oauth_keyring_entry(host))? else {
+        return Ok(None);
+    };
+    let stored: StoredToken =
+        serde_json::from_str(&raw).context("stored GitLab OAuth token is not valid JSON")?;
+    let fresh = stored
+        .expires_at
+        .is_none_or(|expires_at| unix_now() + REFRESH_MARGIN_SECS < expires_at);
+    if fresh {
+        return Ok(Some(stored.access_token));
+    }
+
+    let refresh_token = stored.refresh_token.ok_or_else(|| {
+        anyhow!("GitLab OAuth token expired; run `repopilot auth gitlab --host {host}` again")
+    })?;
+    let base = web_base(host, host_cfg);
+    let client_id = client_id.to_string();
+    let login_hint = format!("run `repopilot auth gitlab --host {host}` again");
+    let token = run_blocking(async move {
+        let client = http_client()?;
+        let response = attributed(client.post(format!("{base}/oauth/token")))
+            .form(&[
+                ("grant_type", "refresh_token"),
+                ("refresh_token", refresh_token.as_str()),
+                ("client_id", client_id.as_str()),
+            ])
+            .send()
+            .await
+            .context("GitLab OAuth token refresh request failed")?;
+        let status = response.status();
+        let body: Value = response.json().await.unwrap_or_default();
+        if !status.is_success() {
+            bail!(
+                "GitLab OAuth token refresh failed ({status}: {}); {login_hint}",
+                error_text(&body)
+            );
+        }
+        token_from_response(&body)
+    })?;
+    // 이전 refresh 토큰은 이미 폐기됐으므로 저장에 실패해도 이번 실행은 새 access 토큰으로 진행한다.
+    if let Err(err) = store_token(host, &token) {
+        tracing::warn!(
+            "refreshed GitLab OAuth token could not be saved; run `repopilot auth gitlab --host {host}` \
+             again after this run: {err:#}"
+        );
+    }
+    Ok(Some(token.access_token))
+}
+
+async fn device_flow(base: &str, client_id: &str) -> Result<StoredToken> {
+    let client = http_client()?;
+    let response = attributed(client.post(format!("{base}/oauth/authorize_device")))
+        .form(&[("client_id", client_id), ("scope", OAUTH_SCOPE)])
+        .send()
+        .await
+        .context("GitLab device authorization request failed")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or_default();
+    if !status.is_success() {
+        bail!(
+            "GitLab device authorization failed ({status}: {}). Check that the OAuth application \
+             is not confidential and has the `{OAUTH_SCOPE}` scope",
+            error_text(&body)
+        );
+    }
+
+    let device_code = body["device_code"]
+        .as_str()
+        .context("device authorization response has no device_code")?;
+    let user_code = body["user_code"].as_str().unwrap_or_default();
+    let verification_uri = body["verification_uri_complete"]
+        .as_str()
+        .or_else(|| body["verification_uri"].as_str())
+        .context("device authorization response has no verification_uri")?;
+    let mut interval = body["interval"].as_u64().unwrap_or(5).max(1);
+    let expires_in = body["expires_in"].as_u64().unwrap_or(900);
+
+    eprintln!("open {verification_uri} in a browser and enter code: {user_code}");
+    eprintln!("waiting for authorization...");
+
+    let deadline = unix_now() + expires_in;
+    loop {
+        tokio::time::sleep(Duration::from_secs(interval)).await;
+        if unix_now() > deadline {
+            bail!("GitLab device code expired before authorization completed");
+        }
+        let response = attributed(client.post(format!("{base}/oauth/token")))
+            .form(&[
+                ("grant_type", DEVICE_GRANT_TYPE),
+                ("device_code", device_code),
+                ("client_id", client_id),
+            ])
+            .send()
+            .await
+            .context("GitLab OAuth token request failed")?;
+        let status = response.status();
+        let body: Value = response.json().await.unwrap_or_default();
+        if status.is_success() {
+            return token_from_response(&body);
+        }
+        match body["error"].as_str().unwrap_or_default() {
+            "authorization_pending" => {}
+            "slow_down" => interval += SLOW_DOWN_SECS,
+            "access_denied" => bail!("GitLab authorization was denied"),
+            "expired_token" => bail!("GitLab device code expired before authorization completed"),
+            _ => bail!(
+                "GitLab OAuth token request failed ({status}: {})",
+                error_text(&body)
+            ),
+        }
+    }
+}
+
+fn token_from_response(body: &Value) -> Result<StoredToken> {
+    let access_token = body["access_token"]
+        .as_str()
+        .filter(|v| !v.is_empty())
+        .context("GitLab OAuth response has no access_token")?;
+    Ok(StoredToken {
+        access_token: access_token.to_string(),
+        refresh_token: body["refresh_token"].as_str().map(ToString::to_string),
+        expires_at: body["expires_in"].as_u64().map(|secs| unix_now() + secs),
+    })
+}
+
+fn store_token(host: &str, token: &StoredToken) -> Result<()> {
+    let json = serde_json::to_string(token)?;
+    store_secret(&oauth_keyring_entry(host), &json)
+        .context("failed to save the GitLab OAuth token in the OS keyring")
+}
+
+/// OAuth 엔드포인트 기준 URL. `api_base`가 `/api/v4`로 끝나면 그 앞부분을 쓴다.
+fn web_base(host: &str, host_cfg: &HostConfig) -> String {
+    let api_base = gitlab::api_base(host, host_cfg.api_base.as_deref());
+    api_base
+        .strip_suffix("/api/v4")
+        .map(ToString::to_string)
+        .unwrap_or_else(|| format!("https://{host}"))
+}
+
+fn error_text(body: &Value) -> String {
+    body["error_description"]
+        .as_str()
+        .or_else(|| body["error"].as_str())
+        .map(ToString::to_string)
+        .unwrap_or_else(|| "no error details".to_string())
+}
+
+fn http_client() -> Result<reqwest::Client> {
+    Ok(reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?)
+}
+
+fn run_blocking<T, F>(future: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+{
+    // 새 스레드에는 호출한 쪽의 귀속 정보(task-local)가 없으므로 옮겨 간다.
+    let request_attribution = attribution::current();
+    std::thread::spawn(move || -> Result<T> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(attribution::scope(request_attribution, future))
+    })
+    .join()
+    .map_err(|_| anyhow!("GitLab OAuth request thread panicked"))?
+}
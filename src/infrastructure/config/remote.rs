@@ -0,0 +1,183 @@
+//! 원격 팀 설정(`defaults.remote_config_url`) 조회/캐시.
+//!
+//! 플랫폼 팀이 관리하는 설정 파일을 HTTP로 받아 로컬 캐시에 TTL 동안 보관하고,
+//! 로더는 이를 로컬 설정보다 낮은 우선순위로 병합한다.
+//! 조회에 실패하면 만료된 캐시라도 사용하고, 캐시도 없으면 경고 후 원격 설정 없이 진행한다.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Once;
+use std::time::{Duration, SystemTime};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde::Serialize;
+use url::Url;
+
+use super::format::ConfigFormat;
+use super::loader::{config_from_value, parse_config_text};
+use super::resolve::resolve_host_token;
+use crate::application::attribution;
+use crate::application::config::{Config, HostConfig};
+use crate::infrastructure::http::attribute;
+use crate::infrastructure::state;
+
+const FETCH_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 원격 팀 설정 적용 상태(inspection용).
+#[derive(Debug, Clone, Serialize)]
+pub struct RemoteConfigStatus {
+    pub url: String,
+    /// `fetched` | `cache` | `stale cache (...)` | `unavailable (...)`
+    pub source: String,
+    pub cache_path: Option<String>,
+}
+
+/// 로컬 설정의 `remote_config_url`로 팀 설정을 읽는다. URL이 없으면 None.
+/// 반환한 설정이 None이면 조회/파싱에 실패해 적용하지 않은 상태다.
+pub(super) fn load_remote_config(local: &Config) -> Option<(Option<Config>, RemoteConfigStatus)> {
+    let url = local.remote_config_url()?;
+    let cache = cache_path(url);
+    let status = |source: String| RemoteConfigStatus {
+        url: url.to_string(),
+        source,
+        cache_path: cache.as_ref().map(|path| path.display().to_string()),
+    };
+
+    let ttl = Duration::from_secs(local.remote_config_ttl_minutes().saturating_mul(60));
+    if let Some(path) = &cache
+        && is_fresh(path, ttl)
+        && let Ok(config) = read_cached(url, path)
+    {
+        return Some((Some(config), status("cache".to_string())));
+    }
+
+    let fetched = fetch_remote(url, local).and_then(|raw| {
+        let config = parse_remote(url, &raw)?;
+        Ok((config, raw))
+    });
+    match fetched {
+        Ok((config, raw)) => {
+            if let Some(path) = &cache {
+                // 캐시 저장 실패는 다음 실행에서 다시 받으면 되므로 무시한다.
+                let _ = path.parent().map(fs::create_dir_all);
+                let _ = fs::write(path, raw);
+            }
+            Some((Some(config), status("fetched".to_string())))
+        }
+        Err(err) => {
+            if let Some(path) = &cache
+                && let Ok(config) = read_cached(url, path)
+            {
+                warn_once(&format!(
+                    "warning: failed to fetch remote config {url}; using stale cache: {err:#}"
+                ));
+                return Some((Some(config), status(format!("stale cache ({err:#})"))));
+            }
+            warn_once(&format!(
+                "warning: failed to fetch remote config {url}; continuing with local config only: {err:#}"
+            ));
+            Some((None, status(format!("unavailable ({err:#})"))))
+        }
+    }
+}
+
+fn parse_remote(url: &str, raw: &str) -> Result<Config> {
+    let origin = Path::new(url);
+    let format = ConfigFormat::from_path(Path::new(
+        Url::parse(url).map(|u| u.path().to_string()).unwrap_or_default().as_str(),
+    ));
+    let value = parse_config_text(format, raw, origin)?;
+    let mut config = config_from_value(origin, value)?;
+    // 원격 설정이 다른 원격 설정을 가리키는 연쇄는 허용하지 않는다.
+    config.defaults.remote_config_url = None;
+    config.defaults.remote_config_ttl_minutes = None;
+    Ok(config)
+}
+
+fn read_cached(url: &str, path: &Path) -> Result<Config> {
+    let raw = fs::read_to_string(path)?;
+    parse_remote(url, &raw)
+}
+
+fn is_fresh(path: &Path, ttl: Duration) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age < ttl)
+}
+
+/// URL마다 사용자 캐시 디렉터리 아래 파일 하나를 쓴다.
+fn cache_path(url: &str) -> Option<PathBuf> {
+    // URL을 파일 이름으로 쓸 수 있게 영숫자 외 문자를 `_`로 바꾼다.
+    let name: String = url
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '.' { c } else { '_' })
+        .collect();
+    state::cache_dir().map(|dir| dir.join("remote-config").join(name))
+}
+
+/// 원격 설정을 받는다. URL 호스트의 `hosts.<host>` 토큰이 있으면 호스트 종류에 맞는 헤더 하나로 함께
+/// 보내고, 토큰이 있는데 `https`가 아니면 평문으로 흘리지 않도록 받지 않는다.
+/// 로더는 동기 코드이고 tokio 런타임 안에서도 불리므로 별도 스레드의 런타임에서 요청한다.
+/// 그 스레드에는 호출한 실행의 귀속 정보가 없으므로 미리 잡아 넘긴다.
+fn fetch_remote(url: &str, local: &Config) -> Result<String> {
+    let parsed = Url::parse(url).with_context(|| format!("invalid remote_config_url '{url}'"))?;
+    let credential = parsed.host_str().and_then(|host| {
+        let host_config = local.host_config(host);
+        let token = resolve_host_token(host, host_config).ok()?.token?;
+        Some(auth_header(host, host_config, &token))
+    });
+    if credential.is_some() && parsed.scheme() != "https" {
+        bail!(
+            "refusing to send the hosts.{} token over {}; use an https remote_config_url",
+            parsed.host_str().unwrap_or_default(),
+            parsed.scheme()
+        );
+    }
+
+    let mut request_attribution = attribution::current();
+    request_attribution.user_agent = local.user_agent();
+
+    let url = url.to_string();
+    std::thread::spawn(move || -> Result<String> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        runtime.block_on(async move {
+            let client = reqwest::Client::builder().timeout(FETCH_TIMEOUT).build()?;
+            let mut req = attribute(client.get(&url), &request_attribution);
+            if let Some((name, value)) = credential {
+                req = req.header(name, value);
+            }
+            let resp = req.send().await?.error_for_status()?;
+            Ok(resp.text().await?)
+        })
+    })
+    .join()
+    .map_err(|_| anyhow!("remote config fetch thread panicked"))?
+}
+
+/// 호스트 종류에 맞는 인증 헤더. GitLab 개인 토큰은 `PRIVATE-TOKEN`, GitLab OAuth 토큰과 그 밖의
+/// 호스트(GitHub 등)는 `Authorization: Bearer`로 보낸다. 호스트 종류는 원격 URL 해석과 같이 이름으로
+/// 판단한다.
+fn auth_header(
+    host: &str,
+    host_config: Option<&HostConfig>,
+    token: &str,
+) -> (&'static str, String) {
+    let oauth = host_config.is_some_and(|config| config.oauth_client_id.is_some());
+    if host.contains("gitlab") && !oauth {
+        ("PRIVATE-TOKEN", token.to_string())
+    } else {
+        ("Authorization", format!("Bearer {token}"))
+    }
+}
+
+fn warn_once(message: &str) {
+    static WARNED: Once = Once::new();
+    WARNED.call_once(|| eprintln!("{message}"));
+}
@@ -7,7 +7,10 @@ use std::process::Command;
 
 use anyhow::{Context, Result};
 
-use crate::application::config::{HostConfig, ProviderConfig};
+use crate::application::config::{EmailConfig, HostConfig, ProviderConfig, WebhookConfig};
+use super::keyring::read_secret;
+use super::utils::command_program;
+use super::oauth::{oauth_keyring_entry, resolve_gitlab_oauth_token};
 use crate::application::ports::HostTokenResolution;
 
 /// Provider(API key) 해석 결과.
@@ -18,7 +21,10 @@ pub struct ProviderCredentialResolution {
 }
 
 /// Host(VCS) 토큰을 해석한다.
-pub fn resolve_host_token(host_cfg: Option<&HostConfig>) -> Result<HostTokenResolution> {
+pub fn resolve_host_token(
+    host: &str,
+    host_cfg: Option<&HostConfig>,
+) -> Result<HostTokenResolution> {
     let Some(cfg) = host_cfg else {
         return Ok(HostTokenResolution {
             token: None,
@@ -34,7 +40,9 @@ pub fn resolve_host_token(host_cfg: Option<&HostConfig>) -> Result<HostTokenReso
     }
 
     let mut env_hint: Option<String> = None;
+    let mut keyring_hint: Option<String> = None;
     let mut cmd_hint: Option<String> = None;
+    let mut oauth_hint: Option<String> = None;
 
     if let Some(env_name) = cfg.token_env.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
         match env::var(env_name).ok().map(|v| v.trim().to_string()) {
@@ -50,6 +58,27 @@ pub fn resolve_host_token(host_cfg: Option<&HostConfig>) -> Result<HostTokenReso
         }
     }
 
+    if let Some(entry) = cfg
+        .token_keyring
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+    {
+        match read_secret(entry) {
+            Ok(Some(token)) => {
+                return Ok(HostTokenResolution {
+                    token: Some(token),
+                    source: Some(format!("keyring:{entry}")),
+                });
+            }
+            Ok(None) => keyring_hint = Some(format!("keyring:{entry} (missing)")),
+            Err(err) => {
+                tracing::warn!("failed to read keyring entry '{entry}': {err:#}");
+                keyring_hint = Some(format!("keyring:{entry} (unavailable: {err:#})"));
+            }
+        }
+    }
+
     if let Some(cmd) = cfg
         .token_command
         .as_ref()
@@ -74,9 +103,23 @@ pub fn resolve_host_token(host_cfg: Option<&HostConfig>) -> Result<HostTokenReso
         }
     }
 
+    if let Some(client_id) = cfg.oauth_client_id() {
+        let label = format!("oauth:{}", oauth_keyring_entry(host));
+        match resolve_gitlab_oauth_token(host, cfg, client_id) {
+            Ok(Some(token)) => {
+                return Ok(HostTokenResolution {
+                    token: Some(token),
+                    source: Some(label),
+                });
+            }
+            Ok(None) => oauth_hint = Some(format!("{label} (not logged in)")),
+            Err(err) => oauth_hint = Some(format!("{label} ({err:#})")),
+        }
+    }
+
     Ok(HostTokenResolution {
         token: None,
-        source: cmd_hint.or(env_hint),
+        source: oauth_hint.or(cmd_hint).or(keyring_hint).or(env_hint),
     })
 }
 
@@ -89,18 +132,82 @@ pub fn resolve_provider_api_key(cfg: &ProviderConfig) -> ProviderCredentialResol
         };
     }
 
-    let Some(env_name) = cfg
+    let mut env_hint: Option<String> = None;
+    if let Some(env_name) = cfg
         .api_key_env
         .as_deref()
         .map(str::trim)
         .filter(|v| !v.is_empty())
+    {
+        match env::var(env_name).ok().map(|v| v.trim().to_string()) {
+            Some(v) if !v.is_empty() => {
+                return ProviderCredentialResolution {
+                    credential: Some(v),
+                    source: Some(format!("env:{env_name}")),
+                };
+            }
+            _ => env_hint = Some(format!("env:{env_name} (missing)")),
+        }
+    }
+
+    let Some(entry) = cfg
+        .api_key_keyring
+        .as_deref()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
     else {
         return ProviderCredentialResolution {
             credential: None,
-            source: None,
+            source: env_hint,
         };
     };
 
+    match read_secret(entry) {
+        Ok(Some(key)) => ProviderCredentialResolution {
+            credential: Some(key),
+            source: Some(format!("keyring:{entry}")),
+        },
+        Ok(None) => ProviderCredentialResolution {
+            credential: None,
+            source: Some(format!("keyring:{entry} (missing)")),
+        },
+        Err(err) => {
+            tracing::warn!("failed to read keyring entry '{entry}': {err:#}");
+            ProviderCredentialResolution {
+                credential: None,
+                source: Some(format!("keyring:{entry} (unavailable: {err:#})")),
+            }
+        }
+    }
+}
+
+/// 알림 webhook URL을 해석한다(인라인 값 우선, 없으면 환경변수).
+pub fn resolve_webhook_url(cfg: &WebhookConfig) -> ProviderCredentialResolution {
+    resolve_inline_or_env(cfg.webhook_url.as_deref(), cfg.webhook_url_env.as_deref())
+}
+
+/// 이메일 알림 SMTP 비밀번호를 해석한다(인라인 값 우선, 없으면 환경변수).
+pub fn resolve_smtp_password(cfg: &EmailConfig) -> ProviderCredentialResolution {
+    resolve_inline_or_env(cfg.password.as_deref(), cfg.password_env.as_deref())
+}
+
+fn resolve_inline_or_env(
+    inline: Option<&str>,
+    env_name: Option<&str>,
+) -> ProviderCredentialResolution {
+    if let Some(value) = inline.map(str::trim).filter(|v| !v.is_empty()) {
+        return ProviderCredentialResolution {
+            credential: Some(value.to_string()),
+            source: Some("inline".to_string()),
+        };
+    }
+
+    let Some(env_name) = env_name.map(str::trim).filter(|v| !v.is_empty()) else {
+        return ProviderCredentialResolution {
+            credential: None,
+            source: None,
+        };
+    };
     match env::var(env_name).ok().map(|v| v.trim().to_string()) {
         Some(v) if !v.is_empty() => ProviderCredentialResolution {
             credential: Some(v),
@@ -117,7 +224,8 @@ pub fn provider_api_key_source_label(cfg: &ProviderConfig) -> Option<String> {
     if cfg.api_key.as_deref().map(str::trim).filter(|v| !v.is_empty()).is_some() {
         return Some("inline".to_string());
     }
-    cfg.api_key_env
+    let env_label = cfg
+        .api_key_env
         .as_deref()
         .map(str::trim)
         .filter(|v| !v.is_empty())
@@ -131,7 +239,18 @@ pub fn provider_api_key_source_label(cfg: &ProviderConfig) -> Option<String> {
             } else {
                 format!("env:{env_name} (missing)")
             }
-        })
+        });
+    // 키체인 조회는 외부 프로세스를 띄우므로 라벨에는 항목 이름만 표시한다.
+    match env_label {
+        Some(label) if !label.ends_with("(missing)") => Some(label),
+        env_label => cfg
+            .api_key_keyring
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(|entry| format!("keyring:{entry}"))
+            .or(env_label),
+    }
 }
 
 fn run_token_command(cmd: &[String]) -> Result<String> {
@@ -146,7 +265,7 @@ fn run_token_command(cmd: &[String]) -> Result<String> {
         .map(|s| s.to_string())
         .collect();
 
-    let output = Command::new(&program)
+    let output = Command::new(command_program(&program))
         .args(&args)
         .output()
         .with_context(|| format!("failed to run token command: {program}"))?;
@@ -1,60 +1,169 @@
 //! 설정 모듈 공용 유틸리티.
 
 use std::env;
-use std::path::Path;
+use std::ffi::{OsStr, OsString};
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+use url::Url;
+
+/// 출력에서 비밀값 대신 표시하는 문자열.
+pub(crate) const MASKED_SECRET: &str = "****";
+
+/// 비밀값을 담는 설정 키.
+const SECRET_KEYS: &[&str] = &["token", "api_key", "webhook_url", "password"];
+
+/// 설정 JSON에서 `token`/`api_key`/`webhook_url`/`password` 문자열 값을 재귀적으로 가린다.
+/// 그 밖의 URL 값(`global_concurrency_store`의 `redis://:비밀번호@호스트` 등)은 접속 정보만 가린다.
+pub(crate) fn mask_secret_values(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                if SECRET_KEYS.contains(&key.as_str()) && child.is_string() {
+                    *child = Value::String(MASKED_SECRET.to_string());
+                } else {
+                    mask_secret_values(child);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(mask_secret_values),
+        Value::String(text) => *text = mask_url_credentials(text),
+        _ => {}
+    }
+}
+
+/// URL의 접속 정보(`사용자:비밀번호@`)에서 비밀번호를 가린다. 사용자 이름만 있으면 토큰일 수 있어
+/// 사용자 이름을 가린다. URL이 아니거나 접속 정보가 없으면 그대로 돌려준다.
+pub(crate) fn mask_url_credentials(value: &str) -> String {
+    let Ok(mut url) = Url::parse(value.trim()) else {
+        return value.to_string();
+    };
+    let masked = if url.password().is_some() {
+        url.set_password(Some(MASKED_SECRET))
+    } else if !url.username().is_empty() {
+        url.set_username(MASKED_SECRET)
+    } else {
+        return value.to_string();
+    };
+    match masked {
+        Ok(()) => url.into(),
+        // 접속 정보를 바꿀 수 없는 형식이면 값 전체를 가린다.
+        Err(()) => MASKED_SECRET.to_string(),
+    }
+}
+
+/// Windows에서 `PATHEXT`가 없을 때 쓰는 실행 파일 확장자.
+#[cfg(windows)]
+const DEFAULT_PATHEXT: &str = ".COM;.EXE;.BAT;.CMD";
 
 /// 로컬 명령이 실행 가능한지 탐지한다.
 pub fn command_exists(command: &str) -> bool {
-    // 절대/상대 경로가 주어지면 파일 존재만 검사한다.
+    find_command(command).is_some()
+}
+
+/// 로컬 명령의 실행 파일 경로를 찾는다.
+/// 절대/상대 경로가 주어지면 해당 파일만, 일반 명령은 PATH를 순회해 찾는다.
+pub fn find_command(command: &str) -> Option<PathBuf> {
     if command.trim().is_empty() {
-        return false;
+        return None;
     }
 
     let command_path = Path::new(command);
     if command_path.components().count() > 1 {
-        return command_path.is_file();
+        return executable_candidates(command_path).find(|p| p.is_file());
     }
 
-    let Some(path_var) = env::var_os("PATH") else {
-        return false;
-    };
+    let path_var = env::var_os("PATH")?;
+    env::split_paths(&path_var)
+        .find_map(|dir| executable_candidates(&dir.join(command)).find(|p| p.is_file()))
+}
 
-    // 일반 명령은 PATH를 순회해 탐지한다.
+/// 프로세스 실행에 넘길 프로그램.
+/// Windows에서는 PATHEXT로 찾은 전체 경로를 넘겨야 npm이 설치한 `.cmd` shim(`codex`, `claude`,
+/// `gemini`)도 실행된다. 표준 라이브러리는 `.cmd`/`.bat` 경로를 `cmd.exe`로 실행하며 인자를
+/// 그에 맞게 quoting한다. 다른 플랫폼은 명령을 그대로 넘긴다.
+pub fn command_program(command: &str) -> OsString {
     #[cfg(windows)]
-    {
-        // Windows는 확장자를 생략할 수 있으므로 PATHEXT를 고려한다.
-        let has_ext = command_path.extension().is_some();
-        let pathext = env::var_os("PATHEXT").unwrap_or_else(|| ".EXE;.CMD;.BAT;.COM".into());
-        let exts: Vec<String> = pathext
-            .to_string_lossy()
-            .split(';')
-            .map(|s| s.trim())
-            .filter(|s| !s.is_empty())
-            .map(|s| s.to_string())
-            .collect();
-
-        for dir in env::split_paths(&path_var) {
-            if dir.join(command).is_file() {
-                return true;
-            }
-            if !has_ext {
-                for ext in &exts {
-                    if dir.join(format!("{command}{ext}")).is_file() {
-                        return true;
-                    }
-                }
-            }
-        }
-        return false;
+    if let Some(path) = find_command(command) {
+        return path.into_os_string();
+    }
+    OsString::from(command)
+}
+
+/// `cmd.exe`를 거쳐 실행되는 Windows 배치 파일(`.cmd`/`.bat`)인지 확인한다.
+/// 배치 파일에는 줄바꿈이 든 인자를 넘길 수 없고 명령줄 길이도 8191자로 제한된다.
+pub fn is_batch_script(program: &OsStr) -> bool {
+    cfg!(windows)
+        && Path::new(program)
+            .extension()
+            .and_then(OsStr::to_str)
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("cmd") || ext.eq_ignore_ascii_case("bat"))
+}
+
+#[cfg(not(windows))]
+fn executable_candidates(path: &Path) -> impl Iterator<Item = PathBuf> {
+    std::iter::once(path.to_path_buf())
+}
+
+#[cfg(windows)]
+fn executable_candidates(path: &Path) -> impl Iterator<Item = PathBuf> {
+    let pathext = env::var_os("PATHEXT").unwrap_or_else(|| DEFAULT_PATHEXT.into());
+    pathext_candidates(path, &pathext).into_iter()
+}
+
+/// 확장자가 있으면 그 파일을, 이어서 `pathext`(PATHEXT 형식) 확장자를 차례로 붙인 파일을 후보로 돌려준다.
+/// npm은 `codex`(sh 스크립트)와 `codex.cmd`를 함께 설치하므로 확장자 없는 파일은 후보로 보지 않는다.
+#[cfg(any(windows, test))]
+fn pathext_candidates(path: &Path, pathext: &OsStr) -> Vec<PathBuf> {
+    let as_is = path.extension().is_some().then(|| path.to_path_buf());
+    let with_ext = pathext
+        .to_string_lossy()
+        .split(';')
+        .map(str::trim)
+        .filter(|ext| !ext.is_empty())
+        .map(|ext| {
+            let mut name = path.as_os_str().to_os_string();
+            name.push(ext);
+            PathBuf::from(name)
+        })
+        .collect::<Vec<_>>();
+    as_is.into_iter().chain(with_ext).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pathext_expands_extensionless_name() {
+        let candidates = pathext_candidates(Path::new("bin/codex"), OsStr::new(".COM;.EXE; ;.CMD"));
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("bin/codex.COM"),
+                PathBuf::from("bin/codex.EXE"),
+                PathBuf::from("bin/codex.CMD"),
+            ]
+        );
+    }
+
+    #[test]
+    fn pathext_keeps_existing_extension_first() {
+        let candidates = pathext_candidates(Path::new("codex.cmd"), OsStr::new(".EXE;.CMD"));
+        assert_eq!(
+            candidates,
+            vec![
+                PathBuf::from("codex.cmd"),
+                PathBuf::from("codex.cmd.EXE"),
+                PathBuf::from("codex.cmd.CMD"),
+            ]
+        );
     }
 
     #[cfg(not(windows))]
-    {
-        for dir in env::split_paths(&path_var) {
-            if dir.join(command).is_file() {
-                return true;
-            }
-        }
-        false
+    #[test]
+    fn non_windows_uses_path_as_is() {
+        let candidates: Vec<PathBuf> = executable_candidates(Path::new("bin/codex")).collect();
+        assert_eq!(candidates, vec![PathBuf::from("bin/codex")]);
     }
 }
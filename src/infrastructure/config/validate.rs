@@ -0,0 +1,796 @@
+//! 설정 스키마 검증(알 수 없는 키, 타입 오류, 허용되지 않는 값).
+//! 알려진 키 목록은 스키마 타입의 직렬화 결과에서 얻으므로 필드 추가 시 별도 갱신이 필요 없다.
+
+use std::path::Path;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::loader::{config_paths, read_config_value};
+use crate::application::config::{
+    Config, DefaultsConfig, EmailConfig, HostConfig, NotificationsConfig, PathGuideConfig,
+    PrTypeConfig, ProfileConfig, ProviderConfig, ProvidersConfig, RepoConfig, SeverityLevelConfig,
+    WebhookConfig,
+};
+use crate::application::ports::{ConfigIssue, ConfigValidation};
+use crate::domain::comment_size::MIN_COMMENT_CHARS;
+use crate::domain::policy::canonical_provider_id;
+use crate::domain::review::{CommentLanguage, Severity};
+use crate::infrastructure::smtp::is_email_address;
+
+const BUILTIN_PROVIDER_IDS: [&str; 3] = ["openai", "anthropic", "gemini"];
+
+/// 탐색 경로에 존재하는 설정 파일을 모두 검증한다.
+pub(crate) fn validate_config_files() -> ConfigValidation {
+    let mut checked_paths = Vec::new();
+    let mut issues = Vec::new();
+
+    for path in config_paths() {
+        if !path.exists() {
+            continue;
+        }
+        match read_config_value(&path) {
+            Ok(value) => issues.extend(check_config_value(&path, &value)),
+            Err(err) => issues.push(ConfigIssue {
+                file: path.clone(),
+                pointer: String::new(),
+                message: format!("{err:#}"),
+                suggestion: None,
+            }),
+        }
+        checked_paths.push(path);
+    }
+
+    ConfigValidation {
+        checked_paths,
+        issues,
+    }
+}
+
+/// 파싱된 설정 값 트리의 스키마 문제를 모은다.
+pub(crate) fn check_config_value(file: &Path, value: &Value) -> Vec<ConfigIssue> {
+    let mut checker = Checker {
+        file,
+        issues: Vec::new(),
+        plugin_ids: plugin_ids(value),
+    };
+
+    let Some(root) = checker.section::<Config>(
+        value,
+        "",
+        &Config::default(),
+        &[
+            "defaults",
+            "hosts",
+            "providers",
+            "repos",
+            "profiles",
+            "notifications",
+        ],
+    ) else {
+        return checker.issues;
+    };
+
+    if let Some(defaults) = root.get("defaults") {
+        checker.defaults(defaults, "/defaults");
+    }
+
+    if let Some(repos) = root.get("repos")
+        && let Some(map) = checker.object(repos, "/repos")
+    {
+        for (repo, repo_cfg) in map {
+            let pointer = format!("/repos/{}", escape_pointer(repo));
+            if let Some(repo_map) = checker.section::<RepoConfig>(
+                repo_cfg,
+                &pointer,
+                &RepoConfig::default(),
+                &["defaults", "notifications"],
+            ) {
+                if let Some(defaults) = repo_map.get("defaults") {
+                    checker.defaults(defaults, &format!("{pointer}/defaults"));
+                }
+                if let Some(notifications) = repo_map.get("notifications") {
+                    checker.notifications(notifications, &format!("{pointer}/notifications"));
+                }
+            }
+        }
+    }
+
+    if let Some(profiles) = root.get("profiles")
+        && let Some(map) = checker.object(profiles, "/profiles")
+    {
+        for (name, profile_cfg) in map {
+            let pointer = format!("/profiles/{}", escape_pointer(name));
+            checker.profile(profile_cfg, &pointer);
+        }
+    }
+
+    if let Some(hosts) = root.get("hosts")
+        && let Some(map) = checker.object(hosts, "/hosts")
+    {
+        for (host, host_cfg) in map {
+            let pointer = format!("/hosts/{}", escape_pointer(host));
+            checker.section::<HostConfig>(host_cfg, &pointer, &HostConfig::default(), &[]);
+        }
+    }
+
+    if let Some(providers) = root.get("providers") {
+        checker.providers(providers);
+    }
+
+    if let Some(notifications) = root.get("notifications") {
+        checker.notifications(notifications, "/notifications");
+    }
+
+    checker.issues
+}
+
+struct Checker<'a> {
+    file: &'a Path,
+    issues: Vec<ConfigIssue>,
+    /// 이 파일에 정의된 플러그인 provider 이름
+    plugin_ids: Vec<String>,
+}
+
+/// `providers` 아래에서 `kind`가 지정된 키 중 기본 제공 provider id/별칭이 아닌 것(플러그인 이름) 목록.
+fn plugin_ids(root: &Value) -> Vec<String> {
+    let Some(Value::Object(providers)) = root.get("providers") else {
+        return Vec::new();
+    };
+    providers
+        .iter()
+        .filter(|(id, cfg)| canonical_provider_id(id).is_none() && cfg.get("kind").is_some())
+        .map(|(id, _)| id.clone())
+        .collect()
+}
+
+impl Checker<'_> {
+    fn push(&mut self, pointer: &str, message: String, suggestion: Option<String>) {
+        self.issues.push(ConfigIssue {
+            file: self.file.to_path_buf(),
+            pointer: pointer.to_string(),
+            message,
+            suggestion,
+        });
+    }
+
+    fn object<'v>(&mut self, value: &'v Value, pointer: &str) -> Option<&'v Map<String, Value>> {
+        match value {
+            Value::Object(map) => Some(map),
+            other => {
+                self.push(
+                    pointer,
+                    format!("expected a table/object, found {}", type_name(other)),
+                    None,
+                );
+                None
+            }
+        }
+    }
+
+    /// 구조체 하나에 대응하는 객체를 검증한다.
+    /// - 알 수 없는 키는 did-you-mean 제안과 함께 보고한다.
+    /// - 각 키는 유효한 샘플 객체에 끼워 넣어 역직렬화해 보고 타입 오류 위치를 찾는다.
+    /// - `nested` 키는 호출자가 따로 검증하므로 타입 검사를 건너뛴다.
+    fn section<'v, T>(
+        &mut self,
+        value: &'v Value,
+        pointer: &str,
+        sample: &T,
+        nested: &[&str],
+    ) -> Option<&'v Map<String, Value>>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let map = self.object(value, pointer)?;
+        let Ok(Value::Object(template)) = serde_json::to_value(sample) else {
+            return Some(map);
+        };
+        let known: Vec<&str> = template.keys().map(String::as_str).collect();
+
+        let mut field_errors = false;
+        for (key, field) in map {
+            let field_pointer = format!("{pointer}/{}", escape_pointer(key));
+            if !template.contains_key(key) {
+                let suggestion = unknown_key_suggestion(pointer, key, &known);
+                self.push(&field_pointer, format!("unknown key '{key}'"), suggestion);
+                field_errors = true;
+                continue;
+            }
+            if nested.contains(&key.as_str()) {
+                continue;
+            }
+            let mut probe = template.clone();
+            probe.insert(key.clone(), field.clone());
+            if let Err(err) = serde_json::from_value::<T>(Value::Object(probe)) {
+                self.push(&field_pointer, err.to_string(), None);
+                field_errors = true;
+            }
+        }
+
+        // 키 단위로 문제가 없는데도 실패하면 필수 키 누락 같은 객체 단위 문제다.
+        if !field_errors
+            && nested.is_empty()
+            && let Err(err) = serde_json::from_value::<T>(value.clone())
+        {
+            self.push(pointer, err.to_string(), None);
+        }
+        Some(map)
+    }
+
+    /// 프로필 하나를 검증한다(provider id는 별칭도 허용).
+    fn profile(&mut self, value: &Value, pointer: &str) {
+        let Some(map) = self.section::<ProfileConfig>(
+            value,
+            pointer,
+            &ProfileConfig::default(),
+            &["defaults"],
+        ) else {
+            return;
+        };
+        if let Some(defaults) = map.get("defaults") {
+            self.defaults(defaults, &format!("{pointer}/defaults"));
+        }
+
+        if let Some(Value::Array(ids)) = map.get("providers") {
+            for (idx, id) in ids.iter().enumerate() {
+                if let Value::String(id) = id {
+                    self.provider_reference(id, &format!("{pointer}/providers/{idx}"));
+                }
+            }
+        }
+        if let Some(Value::Object(models)) = map.get("models") {
+            for id in models.keys() {
+                self.provider_reference(id, &format!("{pointer}/models/{}", escape_pointer(id)));
+            }
+        }
+    }
+
+    /// `providers` 섹션을 검증한다(기본 제공 provider + `kind: "plugin"` | `"mock"` provider).
+    fn providers(&mut self, value: &Value) {
+        let sample = ProvidersConfig {
+            plugins: self
+                .plugin_ids
+                .iter()
+                .map(|id| (id.clone(), ProviderConfig::default()))
+                .collect(),
+            ..ProvidersConfig::default()
+        };
+        let mut nested: Vec<&str> = BUILTIN_PROVIDER_IDS.to_vec();
+        let plugin_ids = self.plugin_ids.clone();
+        nested.extend(plugin_ids.iter().map(String::as_str));
+        let Some(map) = self.section::<ProvidersConfig>(value, "/providers", &sample, &nested)
+        else {
+            return;
+        };
+
+        for (id, provider_cfg) in map {
+            if !nested.contains(&id.as_str()) || provider_cfg.is_null() {
+                continue;
+            }
+            let pointer = format!("/providers/{}", escape_pointer(id));
+            let Some(cfg) = self.section::<ProviderConfig>(
+                provider_cfg,
+                &pointer,
+                &ProviderConfig::default(),
+                &[],
+            ) else {
+                continue;
+            };
+            if let Some(rate) = cfg.get("mock_failure_rate").and_then(Value::as_f64)
+                && !(0.0..=1.0).contains(&rate)
+            {
+                self.push(
+                    &format!("{pointer}/mock_failure_rate"),
+                    format!("mock_failure_rate must be between 0.0 and 1.0 (got {rate})"),
+                    None,
+                );
+            }
+            let Some(Value::String(kind)) = cfg.get("kind") else {
+                continue;
+            };
+            if BUILTIN_PROVIDER_IDS.contains(&id.as_str()) {
+                self.push(
+                    &format!("{pointer}/kind"),
+                    "kind is only used for plugin and mock providers".to_string(),
+                    Some("give the plugin its own name, e.g. providers.my-llm".to_string()),
+                );
+            } else if !["plugin", "mock"].contains(&kind.trim().to_ascii_lowercase().as_str()) {
+                self.push(
+                    &format!("{pointer}/kind"),
+                    format!("unsupported provider kind '{kind}'"),
+                    Some(choice_suggestion(kind, &["plugin", "mock"])),
+                );
+            }
+        }
+    }
+
+    /// 프로필/moderator의 provider 참조를 검증한다. 기본 제공 id와 비슷한 이름(오타)만 오류로 보고,
+    /// 그 밖의 이름은 다른 설정 파일에 정의된 플러그인일 수 있어 실행 시점에 확인한다.
+    fn provider_reference(&mut self, name: &str, pointer: &str) {
+        if canonical_provider_id(name).is_some()
+            || self
+                .plugin_ids
+                .iter()
+                .any(|id| id.eq_ignore_ascii_case(name.trim()))
+        {
+            return;
+        }
+        if let Some(candidate) = closest_match(name, &BUILTIN_PROVIDER_IDS) {
+            self.push(
+                pointer,
+                format!("unknown provider '{name}'"),
+                Some(format!("did you mean '{candidate}'?")),
+            );
+        }
+    }
+
+    /// `defaults` 객체(전역 또는 저장소별)를 검증한다.
+    fn defaults(&mut self, value: &Value, pointer: &str) {
+        let Some(map) = self.section::<DefaultsConfig>(
+            value,
+            pointer,
+            &DefaultsConfig::default(),
+            &["severity_levels", "path_guides", "pr_types"],
+        ) else {
+            return;
+        };
+        self.defaults_values(map, pointer);
+        if let Some(levels) = map.get("severity_levels").filter(|levels| !levels.is_null()) {
+            self.severity_levels(levels, &format!("{pointer}/severity_levels"));
+        }
+        if let Some(guides) = map.get("path_guides").filter(|guides| !guides.is_null()) {
+            self.path_guides(guides, &format!("{pointer}/path_guides"));
+        }
+        if let Some(types) = map.get("pr_types").filter(|types| !types.is_null()) {
+            self.pr_types(types, &format!("{pointer}/pr_types"));
+        }
+    }
+
+    /// 문자열 값이 정해진 집합에 속하는지 검증한다(타입 오류는 `section`에서 보고됨).
+    fn defaults_values(&mut self, map: &Map<String, Value>, pointer: &str) {
+        if let Some(Value::String(language)) = map.get("comment_language")
+            && CommentLanguage::parse(language).is_none()
+        {
+            self.push(
+                &format!("{pointer}/comment_language"),
+                format!("invalid language tag '{language}'"),
+                Some("use a BCP-47 tag such as ko, en, ja, zh-CN".to_string()),
+            );
+        }
+
+        if let Some(Value::String(granularity)) = map.get("review_granularity") {
+            let normalized = granularity.trim().to_ascii_lowercase();
+            if !["pr", "file"].contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/review_granularity"),
+                    format!("unsupported review granularity '{granularity}'"),
+                    Some(choice_suggestion(&normalized, &["pr", "file"])),
+                );
+            }
+        }
+
+        if let Some(Value::String(policy)) = map.get("dedupe_policy") {
+            let normalized = policy.trim().to_ascii_lowercase().replace('-', "_");
+            let choices = ["per_sha", "single", "always_new"];
+            if !choices.contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/dedupe_policy"),
+                    format!("unsupported dedupe policy '{policy}'"),
+                    Some(choice_suggestion(&normalized, &choices)),
+                );
+            }
+        }
+
+        for key in ["min_finding_confidence", "low_confidence_below"] {
+            if let Some(value) = map.get(key).and_then(Value::as_f64)
+                && !(0.0..=1.0).contains(&value)
+            {
+                self.push(
+                    &format!("{pointer}/{key}"),
+                    format!("{key} must be between 0.0 and 1.0 (got {value})"),
+                    None,
+                );
+            }
+        }
+
+        if let Some(Value::String(policy)) = map.get("comment_overflow") {
+            let normalized = policy.trim().to_ascii_lowercase();
+            let choices = ["split", "truncate"];
+            if !choices.contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/comment_overflow"),
+                    format!("unsupported comment overflow policy '{policy}'"),
+                    Some(choice_suggestion(&normalized, &choices)),
+                );
+            }
+        }
+
+        if let Some(limit) = map.get("max_comment_chars").and_then(Value::as_u64)
+            && limit > 0
+            && limit < MIN_COMMENT_CHARS as u64
+        {
+            self.push(
+                &format!("{pointer}/max_comment_chars"),
+                format!(
+                    "max_comment_chars must be 0 or at least {MIN_COMMENT_CHARS} (got {limit})"
+                ),
+                None,
+            );
+        }
+
+        if let Some(Value::String(mode)) = map.get("update_check") {
+            let normalized = mode.trim().to_ascii_lowercase();
+            if !["on", "off"].contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/update_check"),
+                    format!("unsupported update_check value '{mode}'"),
+                    Some(choice_suggestion(&normalized, &["on", "off"])),
+                );
+            }
+        }
+
+        if let Some(Value::Array(patterns)) = map.get("redact_patterns") {
+            for (idx, pattern) in patterns.iter().enumerate() {
+                if let Value::String(pattern) = pattern
+                    && let Err(err) = regex_automata::meta::Regex::new(pattern)
+                {
+                    self.push(
+                        &format!("{pointer}/redact_patterns/{idx}"),
+                        match err.syntax_error() {
+                            Some(detail) => format!("invalid regular expression:\n{detail}"),
+                            None => format!("invalid regular expression: {err}"),
+                        },
+                        None,
+                    );
+                }
+            }
+        }
+
+        // WASM 훅은 `wasm-hooks` 기능으로 빌드했을 때만 실행할 수 있다.
+        if !cfg!(feature = "wasm-hooks") {
+            for key in ["pre_prompt_hook", "post_findings_hook"] {
+                if let Some(Value::String(path)) = map.get(key)
+                    && !path.trim().is_empty()
+                {
+                    self.push(
+                        &format!("{pointer}/{key}"),
+                        format!("{key} needs a repopilot build with the `wasm-hooks` feature"),
+                        Some(
+                            "rebuild with `cargo build --release --features wasm-hooks`"
+                                .to_string(),
+                        ),
+                    );
+                }
+            }
+        }
+
+        if let Some(Value::String(provider)) = map.get("moderator_provider")
+            && !provider.trim().is_empty()
+        {
+            self.provider_reference(provider, &format!("{pointer}/moderator_provider"));
+        }
+
+        if let Some(Value::String(provider)) = map.get("meta_review_provider")
+            && !provider.trim().is_empty()
+        {
+            self.provider_reference(provider, &format!("{pointer}/meta_review_provider"));
+        }
+
+        if let Some(Value::String(provider)) = map.get("security_provider")
+            && !provider.trim().is_empty()
+        {
+            self.provider_reference(provider, &format!("{pointer}/security_provider"));
+        }
+
+        if let Some(Value::String(provider)) = map.get("test_gap_provider")
+            && !provider.trim().is_empty()
+        {
+            self.provider_reference(provider, &format!("{pointer}/test_gap_provider"));
+        }
+
+        if let Some(Value::String(provider)) = map.get("autofix_provider")
+            && !provider.trim().is_empty()
+        {
+            self.provider_reference(provider, &format!("{pointer}/autofix_provider"));
+        }
+
+        if let Some(Value::String(store)) = map.get("global_concurrency_store")
+            && let Some((scheme, _)) = store.trim().split_once("://")
+            && scheme != "redis"
+        {
+            self.push(
+                &format!("{pointer}/global_concurrency_store"),
+                format!("unsupported store scheme '{scheme}'"),
+                Some("use a directory path or a redis://host:port/db URL".to_string()),
+            );
+        }
+
+        if let Some(Value::Array(ids)) = map.get("provider_order") {
+            for (idx, id) in ids.iter().enumerate() {
+                if let Value::String(id) = id {
+                    self.provider_reference(id, &format!("{pointer}/provider_order/{idx}"));
+                }
+            }
+        }
+    }
+
+    /// `notifications` 섹션(전역 또는 저장소별)을 검증한다.
+    fn notifications(&mut self, value: &Value, pointer: &str) {
+        let channel_ids = ["slack", "teams", "discord", "email"];
+        let Some(map) = self.section::<NotificationsConfig>(
+            value,
+            pointer,
+            &NotificationsConfig::default(),
+            &channel_ids,
+        ) else {
+            return;
+        };
+        for (id, channel_cfg) in map {
+            if !channel_ids.contains(&id.as_str()) || channel_cfg.is_null() {
+                continue;
+            }
+            let channel_pointer = format!("{pointer}/{id}");
+            if id == "email" {
+                self.email(channel_cfg, &channel_pointer);
+            } else {
+                self.webhook(channel_cfg, &channel_pointer);
+            }
+        }
+    }
+
+    /// 알림 채널 하나를 검증한다.
+    fn webhook(&mut self, value: &Value, pointer: &str) {
+        let Some(map) =
+            self.section::<WebhookConfig>(value, pointer, &WebhookConfig::default(), &[])
+        else {
+            return;
+        };
+        self.notify_on(map, pointer);
+    }
+
+    /// 이메일 알림 채널을 검증한다.
+    fn email(&mut self, value: &Value, pointer: &str) {
+        let Some(map) = self.section::<EmailConfig>(value, pointer, &EmailConfig::default(), &[])
+        else {
+            return;
+        };
+        self.notify_on(map, pointer);
+        if let Some(Value::String(security)) = map.get("smtp_security") {
+            let normalized = security.trim().to_ascii_lowercase();
+            let choices = ["starttls", "tls", "none"];
+            if !choices.contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/smtp_security"),
+                    format!("unsupported SMTP security '{security}'"),
+                    Some(choice_suggestion(&normalized, &choices)),
+                );
+            }
+            // 평문 연결에서는 인증 정보를 보내지 않으므로 전송이 실패한다.
+            if normalized == "none"
+                && let Some(Value::String(username)) = map.get("username")
+                && !username.trim().is_empty()
+            {
+                self.push(
+                    &format!("{pointer}/smtp_security"),
+                    "SMTP credentials are never sent over an unencrypted connection".to_string(),
+                    Some("use starttls or tls, or remove username".to_string()),
+                );
+            }
+        }
+        if let Some(Value::String(from)) = map.get("from")
+            && !is_email_address(from)
+        {
+            self.push(
+                &format!("{pointer}/from"),
+                format!("invalid email address '{from}'"),
+                None,
+            );
+        }
+        if let Some(Value::Array(to)) = map.get("to") {
+            for (index, address) in to.iter().enumerate() {
+                if let Value::String(address) = address
+                    && !is_email_address(address)
+                {
+                    self.push(
+                        &format!("{pointer}/to/{index}"),
+                        format!("invalid email address '{address}'"),
+                        None,
+                    );
+                }
+            }
+        }
+    }
+
+    fn notify_on(&mut self, map: &Map<String, Value>, pointer: &str) {
+        if let Some(Value::String(on)) = map.get("on") {
+            let normalized = on.trim().to_ascii_lowercase();
+            if !["always", "failure"].contains(&normalized.as_str()) {
+                self.push(
+                    &format!("{pointer}/on"),
+                    format!("unsupported notification condition '{on}'"),
+                    Some(choice_suggestion(&normalized, &["always", "failure"])),
+                );
+            }
+        }
+    }
+
+    fn path_guides(&mut self, value: &Value, pointer: &str) {
+        let Value::Array(guides) = value else {
+            self.push(
+                pointer,
+                format!("expected an array, found {}", type_name(value)),
+                None,
+            );
+            return;
+        };
+
+        let sample = PathGuideConfig {
+            glob: String::new(),
+            guide: String::new(),
+        };
+        for (idx, guide) in guides.iter().enumerate() {
+            let pointer = format!("{pointer}/{idx}");
+            let Some(map) = self.section::<PathGuideConfig>(guide, &pointer, &sample, &[]) else {
+                continue;
+            };
+            if let Some(Value::String(glob)) = map.get("glob")
+                && glob.trim().is_empty()
+            {
+                self.push(
+                    &format!("{pointer}/glob"),
+                    "glob must not be empty".to_string(),
+                    None,
+                );
+            }
+        }
+    }
+
+    fn pr_types(&mut self, value: &Value, pointer: &str) {
+        let Value::Array(types) = value else {
+            self.push(
+                pointer,
+                format!("expected an array, found {}", type_name(value)),
+                None,
+            );
+            return;
+        };
+
+        let sample = PrTypeConfig {
+            name: String::new(),
+            labels: Vec::new(),
+            title_pattern: None,
+            prompt: String::new(),
+        };
+        for (idx, pr_type) in types.iter().enumerate() {
+            let pointer = format!("{pointer}/{idx}");
+            let Some(map) = self.section::<PrTypeConfig>(pr_type, &pointer, &sample, &[]) else {
+                continue;
+            };
+            if let Some(Value::String(name)) = map.get("name")
+                && name.trim().is_empty()
+            {
+                self.push(
+                    &format!("{pointer}/name"),
+                    "name must not be empty".to_string(),
+                    None,
+                );
+            }
+            if let Some(Value::String(pattern)) = map.get("title_pattern")
+                && let Err(err) = regex_automata::meta::Regex::new(pattern)
+            {
+                self.push(
+                    &format!("{pointer}/title_pattern"),
+                    match err.syntax_error() {
+                        Some(detail) => format!("invalid regular expression:\n{detail}"),
+                        None => format!("invalid regular expression: {err}"),
+                    },
+                    None,
+                );
+            }
+        }
+    }
+
+    fn severity_levels(&mut self, value: &Value, pointer: &str) {
+        let Value::Array(levels) = value else {
+            self.push(
+                pointer,
+                format!("expected an array, found {}", type_name(value)),
+                None,
+            );
+            return;
+        };
+
+        let sample = SeverityLevelConfig {
+            name: String::new(),
+            emoji: None,
+            maps_to: None,
+        };
+        for (idx, level) in levels.iter().enumerate() {
+            let pointer = format!("{pointer}/{idx}");
+            let Some(map) = self.section::<SeverityLevelConfig>(level, &pointer, &sample, &[])
+            else {
+                continue;
+            };
+            if let Some(Value::String(maps_to)) = map.get("maps_to")
+                && Severity::parse(maps_to).is_none()
+            {
+                self.push(
+                    &format!("{pointer}/maps_to"),
+                    format!("unknown severity '{maps_to}'"),
+                    Some(choice_suggestion(
+                        maps_to,
+                        &["critical", "major", "minor", "suggestion"],
+                    )),
+                );
+            }
+        }
+    }
+}
+
+/// 오타 키에는 가장 가까운 키를, provider 별칭에는 정식 id를 제안한다.
+fn unknown_key_suggestion(pointer: &str, key: &str, known: &[&str]) -> Option<String> {
+    if pointer == "/providers"
+        && let Some(id) = canonical_provider_id(key)
+    {
+        return Some(format!("did you mean '{id}'?"));
+    }
+    match closest_match(key, known) {
+        Some(candidate) => Some(format!("did you mean '{candidate}'?")),
+        None if known.is_empty() => None,
+        None if pointer == "/providers" => Some(format!(
+            "expected one of: {}, or set kind: \"plugin\" for a plugin provider",
+            known.join(", ")
+        )),
+        None => Some(format!("expected one of: {}", known.join(", "))),
+    }
+}
+
+fn choice_suggestion(value: &str, choices: &[&str]) -> String {
+    match closest_match(value, choices) {
+        Some(candidate) => format!("did you mean '{candidate}'?"),
+        None => format!("use one of: {}", choices.join(", ")),
+    }
+}
+
+/// 편집 거리가 충분히 가까운 후보를 고른다(대소문자 무시).
+fn closest_match<'a>(input: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let input = input.trim().to_ascii_lowercase();
+    candidates
+        .iter()
+        .map(|candidate| (levenshtein(&input, &candidate.to_ascii_lowercase()), *candidate))
+        .filter(|(distance, _)| *distance <= 2 || *distance * 3 <= input.chars().count())
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// RFC 6901 JSON pointer 토큰 이스케이프.
+pub(super) fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "an array",
+        Value::Object(_) => "an object",
+    }
+}
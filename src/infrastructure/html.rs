@@ -0,0 +1,549 @@
+//! 리뷰 보고서 내보내기(`--export html|pdf`)용 HTML 변환.
+//!
+//! 외부 엔진 없이 리뷰 코멘트에 쓰이는 Markdown 부분 문법만 변환한다.
+//! - 블록: 제목, 코드 펜스, 목록(들여쓰기 중첩), 인용, 표, 구분선, 문단
+//! - 인라인: 코드, 굵게/기울임, http(s) 링크
+//!
+//! provider 출력은 신뢰할 수 없으므로 HTML은 모두 이스케이프하고, GitHub/GitLab 코멘트에서 쓰는
+//! 일부 태그([`ALLOWED_TAGS`])만 속성 없이 되살린다. HTML 주석 줄(봇 마커)은 지운다.
+
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::{Context, Result, anyhow, bail};
+use url::Url;
+
+use crate::infrastructure::config::find_command;
+
+/// 이스케이프하지 않고 되살리는 태그(속성 없는 형태만).
+const ALLOWED_TAGS: &[&str] = &[
+    "details", "summary", "sub", "sup", "b", "i", "em", "strong", "br", "kbd",
+];
+
+/// PDF 인쇄에 쓰는 헤드리스 브라우저 후보(앞에서부터 PATH에서 찾는다).
+const PDF_BROWSERS: &[&str] = &[
+    "chromium",
+    "chromium-browser",
+    "google-chrome",
+    "google-chrome-stable",
+    "msedge",
+];
+
+/// 보고서 스타일. 화면과 인쇄(PDF) 모두에서 읽기 좋은 최소한의 규칙만 둔다.
+const REPORT_STYLE: &str = "
+body { margin: 0; background: #f6f8fa; color: #1f2328; font: 15px/1.6 -apple-system, 'Segoe UI', Helvetica, Arial, sans-serif; }
+main { max-width: 960px; margin: 0 auto; padding: 32px 40px; background: #fff; }
+h1, h2, h3, h4 { line-height: 1.3; margin: 1.4em 0 0.6em; }
+h1 { font-size: 1.8em; border-bottom: 1px solid #d1d9e0; padding-bottom: 0.3em; }
+h2 { font-size: 1.4em; border-bottom: 1px solid #d1d9e0; padding-bottom: 0.3em; }
+a { color: #0969da; }
+code { font: 0.9em ui-monospace, SFMono-Regular, Menlo, Consolas, monospace; background: #eff1f3; padding: 0.1em 0.35em; border-radius: 4px; }
+pre { background: #f6f8fa; border: 1px solid #d1d9e0; border-radius: 6px; padding: 12px 16px; overflow-x: auto; }
+pre code { background: none; padding: 0; white-space: pre; }
+blockquote { margin: 0; padding: 0 1em; color: #59636e; border-left: 4px solid #d1d9e0; }
+table { border-collapse: collapse; margin: 1em 0; }
+th, td { border: 1px solid #d1d9e0; padding: 4px 12px; }
+th { background: #f6f8fa; }
+hr { border: 0; border-top: 1px solid #d1d9e0; margin: 2em 0; }
+details { margin: 0.8em 0; }
+summary { cursor: pointer; font-weight: 600; }
+@media print {
+  body { background: #fff; }
+  main { max-width: none; padding: 0; }
+  details > summary { list-style: none; }
+  pre { white-space: pre-wrap; }
+}
+";
+
+/// 제목과 본문 HTML로 스타일이 포함된 단독 HTML 문서를 만든다.
+pub fn html_document(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n<title>{}</title>\n<style>{REPORT_STYLE}</style>\n</head>\n<body>\n<main>\n{body}</main>\n</body>\n</html>\n",
+        escape(title)
+    )
+}
+
+/// Markdown을 HTML 조각으로 변환한다.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out = String::new();
+    let mut i = 0;
+    while i < lines.len() {
+        let trimmed = lines[i].trim_start();
+        if trimmed.is_empty() {
+            i += 1;
+        } else if trimmed.starts_with("<!--") {
+            i = skip_html_comment(&lines, i);
+        } else if let Some(fence) = fence_marker(trimmed) {
+            i = code_block(&lines, i, fence, &mut out);
+        } else if let Some((level, text)) = heading(trimmed) {
+            out.push_str(&format!("<h{level}>{}</h{level}>\n", inline(text)));
+            i += 1;
+        } else if is_rule(trimmed) {
+            out.push_str("<hr>\n");
+            i += 1;
+        } else if is_table_start(&lines, i) {
+            i = table(&lines, i, &mut out);
+        } else if list_item(trimmed).is_some() {
+            i = list(&lines, i, &mut out);
+        } else if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() && lines[i].trim_start().starts_with('>') {
+                let line = &lines[i].trim_start()[1..];
+                quoted.push(line.strip_prefix(' ').unwrap_or(line));
+                i += 1;
+            }
+            out.push_str(&format!(
+                "<blockquote>\n{}</blockquote>\n",
+                markdown_to_html(&quoted.join("\n"))
+            ));
+        } else if trimmed.starts_with('<') {
+            // `<details>`/`<summary>` 같은 HTML 블록 줄은 허용 태그만 살려 그대로 둔다.
+            out.push_str(&inline(trimmed.trim_end()));
+            out.push('\n');
+            i += 1;
+        } else {
+            let mut paragraph = vec![trimmed.trim_end()];
+            i += 1;
+            while i < lines.len() && !starts_block(&lines, i) {
+                paragraph.push(lines[i].trim());
+                i += 1;
+            }
+            out.push_str(&format!("<p>{}</p>\n", inline(&paragraph.join("\n"))));
+        }
+    }
+    out
+}
+
+/// 헤드리스 Chromium 계열 브라우저로 HTML 문서를 PDF로 인쇄한다.
+pub fn write_pdf(html: &str, path: &Path) -> Result<()> {
+    let browser = PDF_BROWSERS
+        .iter()
+        .find_map(|name| find_command(name))
+        .ok_or_else(|| {
+            anyhow!(
+                "PDF export needs a Chromium-based browser on PATH ({}); use --export html instead",
+                PDF_BROWSERS.join(", ")
+            )
+        })?;
+
+    let source = std::env::temp_dir().join(format!("repopilot-report-{}.html", std::process::id()));
+    fs::write(&source, html).with_context(|| format!("failed to write {}", source.display()))?;
+    let target = std::path::absolute(path)
+        .with_context(|| format!("failed to resolve {}", path.display()))?;
+    let source_url = Url::from_file_path(&source)
+        .map_err(|_| anyhow!("failed to build file URL for {}", source.display()))?;
+
+    let status = Command::new(&browser)
+        .arg("--headless")
+        .arg("--disable-gpu")
+        .arg("--no-pdf-header-footer")
+        .arg(format!("--print-to-pdf={}", target.display()))
+        .arg(source_url.as_str())
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status();
+    let _ = fs::remove_file(&source);
+
+    let status = status.with_context(|| format!("failed to run {}", browser.display()))?;
+    if !status.success() || !target.is_file() {
+        bail!(
+            "{} failed to print the report to PDF ({status})",
+            browser.display()
+        );
+    }
+    Ok(())
+}
+
+/// 빈 줄이 아닌 줄이 새 블록을 시작하는지(문단을 끝내는지) 판단한다.
+fn starts_block(lines: &[&str], i: usize) -> bool {
+    let trimmed = lines[i].trim_start();
+    trimmed.is_empty()
+        || trimmed.starts_with("<!--")
+        || trimmed.starts_with('<')
+        || trimmed.starts_with('>')
+        || fence_marker(trimmed).is_some()
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || is_table_start(lines, i)
+        || list_item(trimmed).is_some()
+}
+
+fn skip_html_comment(lines: &[&str], mut i: usize) -> usize {
+    while i < lines.len() {
+        let done = lines[i].contains("-->");
+        i += 1;
+        if done {
+            break;
+        }
+    }
+    i
+}
+
+fn fence_marker(trimmed: &str) -> Option<&'static str> {
+    if trimmed.starts_with("```") {
+        Some("```")
+    } else if trimmed.starts_with("~~~") {
+        Some("~~~")
+    } else {
+        None
+    }
+}
+
+/// 코드 펜스 블록을 `<pre><code>`로 쓰고 다음 줄 위치를 돌려준다. 닫히지 않은 펜스는 끝까지 코드로 본다.
+fn code_block(lines: &[&str], start: usize, fence: &str, out: &mut String) -> usize {
+    let opening = lines[start].trim_start();
+    let indent = lines[start].len() - opening.len();
+    let class = opening[fence.len()..]
+        .split_whitespace()
+        .next()
+        .map(|lang| format!(" class=\"language-{}\"", escape(lang)))
+        .unwrap_or_default();
+
+    let mut code = Vec::new();
+    let mut i = start + 1;
+    while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+        // 목록 안의 펜스는 여는 줄의 들여쓰기만큼 걷어 낸다.
+        let line = lines[i];
+        let strip = line.len() - line.trim_start().len();
+        code.push(&line[strip.min(indent)..]);
+        i += 1;
+    }
+    out.push_str(&format!(
+        "<pre><code{class}>{}</code></pre>\n",
+        escape(&code.join("\n"))
+    ));
+    i + 1
+}
+
+fn heading(trimmed: &str) -> Option<(usize, &str)> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+fn is_rule(trimmed: &str) -> bool {
+    let marks: Vec<char> = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && ['-', '*', '_'].contains(&marks[0]) && marks.iter().all(|c| *c == marks[0])
+}
+
+/// `- `/`* `/`+ `(순서 없음) 또는 `1. `/`1) `(순서 있음) 목록 항목이면 (순서 있음 여부, 본문).
+fn list_item(trimmed: &str) -> Option<(bool, &str)> {
+    for bullet in ["- ", "* ", "+ "] {
+        if let Some(rest) = trimmed.strip_prefix(bullet) {
+            return Some((false, rest));
+        }
+    }
+    let digits = trimmed.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 || digits > 9 {
+        return None;
+    }
+    let rest = &trimmed[digits..];
+    rest.strip_prefix(". ")
+        .or_else(|| rest.strip_prefix(") "))
+        .map(|text| (true, text))
+}
+
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// 들여쓰기로 중첩된 목록을 쓰고 다음 줄 위치를 돌려준다.
+fn list(lines: &[&str], mut i: usize, out: &mut String) -> usize {
+    // 열린 목록 단계별 (들여쓰기, 순서 있음 여부)
+    let mut stack: Vec<(usize, bool)> = Vec::new();
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            // 빈 줄 뒤에 항목이나 들여쓴 이어지는 줄이 오면 같은 목록으로 본다.
+            let next = lines[i + 1..].iter().find(|l| !l.trim().is_empty());
+            match next {
+                Some(next) if list_item(next.trim_start()).is_some() || indent_of(next) > 0 => {
+                    i += 1;
+                    continue;
+                }
+                _ => break,
+            }
+        }
+
+        let indent = indent_of(line);
+        if let Some((ordered, text)) = list_item(trimmed) {
+            match stack.last() {
+                Some(&(top, _)) if indent <= top => {
+                    while stack.len() > 1 && indent < stack[stack.len() - 1].0 {
+                        let (_, nested_ordered) = stack.pop().unwrap_or_default();
+                        out.push_str(&format!("</li>\n{}\n", list_close(nested_ordered)));
+                    }
+                    // 같은 단계에서 목록 종류가 바뀌면 새 목록을 연다.
+                    if let Some(level) = stack.last_mut()
+                        && level.1 != ordered
+                    {
+                        out.push_str(&format!(
+                            "</li>\n{}\n{}\n<li>",
+                            list_close(level.1),
+                            list_open(ordered)
+                        ));
+                        level.1 = ordered;
+                    } else {
+                        out.push_str("</li>\n<li>");
+                    }
+                }
+                _ => {
+                    out.push_str(&format!("\n{}\n<li>", list_open(ordered)));
+                    stack.push((indent, ordered));
+                }
+            }
+            out.push_str(&inline(text.trim_end()));
+            i += 1;
+        } else if indent > 0 {
+            if let Some(fence) = fence_marker(trimmed) {
+                out.push('\n');
+                i = code_block(lines, i, fence, out);
+            } else {
+                out.push_str(&format!("<br>\n{}", inline(trimmed.trim_end())));
+                i += 1;
+            }
+        } else {
+            break;
+        }
+    }
+    while let Some((_, ordered)) = stack.pop() {
+        out.push_str(&format!("</li>\n{}\n", list_close(ordered)));
+    }
+    i
+}
+
+fn list_open(ordered: bool) -> &'static str {
+    if ordered { "<ol>" } else { "<ul>" }
+}
+
+fn list_close(ordered: bool) -> &'static str {
+    if ordered { "</ol>" } else { "</ul>" }
+}
+
+fn is_table_start(lines: &[&str], i: usize) -> bool {
+    lines[i].trim_start().starts_with('|')
+        && lines.get(i + 1).is_some_and(|next| {
+            next.trim_start().starts_with('|') && table_alignments(next).is_some()
+        })
+}
+
+/// 표 구분 줄(`| --- | :---: | ---: |`)의 열별 정렬.
+fn table_alignments(line: &str) -> Option<Vec<&'static str>> {
+    table_cells(line)
+        .iter()
+        .map(|cell| {
+            let dashes = cell.trim_matches(':');
+            if dashes.is_empty() || !dashes.chars().all(|c| c == '-') {
+                return None;
+            }
+            Some(match (cell.starts_with(':'), cell.ends_with(':')) {
+                (true, true) => "center",
+                (false, true) => "right",
+                _ => "left",
+            })
+        })
+        .collect()
+}
+
+fn table_cells(line: &str) -> Vec<String> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    // `\|`는 셀 구분자가 아닌 글자다.
+    line.replace("\\|", "\u{0}")
+        .split('|')
+        .map(|cell| cell.trim().replace('\u{0}', "|"))
+        .collect()
+}
+
+fn table(lines: &[&str], start: usize, out: &mut String) -> usize {
+    let alignments = table_alignments(lines[start + 1]).unwrap_or_default();
+    let row = |line: &str, tag: &str| {
+        let cells: String = table_cells(line)
+            .iter()
+            .enumerate()
+            .map(|(col, cell)| {
+                let align = alignments.get(col).copied().unwrap_or("left");
+                format!(
+                    "<{tag} style=\"text-align: {align}\">{}</{tag}>",
+                    inline(cell)
+                )
+            })
+            .collect();
+        format!("<tr>{cells}</tr>\n")
+    };
+
+    out.push_str("<table>\n<thead>\n");
+    out.push_str(&row(lines[start], "th"));
+    out.push_str("</thead>\n<tbody>\n");
+    let mut i = start + 2;
+    while i < lines.len() && lines[i].trim_start().starts_with('|') {
+        out.push_str(&row(lines[i], "td"));
+        i += 1;
+    }
+    out.push_str("</tbody>\n</table>\n");
+    i
+}
+
+/// 인라인 코드/링크/강조를 변환한다.
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut plain = String::new();
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        if c == '`'
+            && let Some(end) = rest[1..].find('`')
+        {
+            out.push_str(&emphasis(&plain));
+            plain.clear();
+            out.push_str(&format!("<code>{}</code>", escape(&rest[1..1 + end])));
+            rest = &rest[end + 2..];
+            continue;
+        }
+        if c == '['
+            && let Some((label, url, len)) = link(rest)
+        {
+            out.push_str(&emphasis(&plain));
+            plain.clear();
+            match url {
+                Some(url) => out.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(url),
+                    inline(label)
+                )),
+                None => out.push_str(&inline(label)),
+            }
+            rest = &rest[len..];
+            continue;
+        }
+        plain.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    out.push_str(&emphasis(&plain));
+    out
+}
+
+/// `[label](url)` 링크면 (label, http(s) URL, 전체 길이). http(s)가 아닌 URL은 링크 없이 label만 쓴다.
+fn link(text: &str) -> Option<(&str, Option<&str>, usize)> {
+    let mut depth = 0usize;
+    let mut in_code = false;
+    let mut close = None;
+    for (pos, c) in text.char_indices() {
+        match c {
+            '`' => in_code = !in_code,
+            '[' if !in_code => depth += 1,
+            ']' if !in_code => {
+                depth -= 1;
+                if depth == 0 {
+                    close = Some(pos);
+                    break;
+                }
+            }
+            _ => {}
+        }
+    }
+    let close = close?;
+    let after = text[close + 1..].strip_prefix('(')?;
+    // URL 안의 괄호 쌍(`wiki/Foo_(bar)`)은 URL의 일부로 본다.
+    let mut parens = 0usize;
+    let end = after.char_indices().find_map(|(pos, c)| match c {
+        '(' => {
+            parens += 1;
+            None
+        }
+        ')' if parens == 0 => Some(pos),
+        ')' => {
+            parens -= 1;
+            None
+        }
+        _ => None,
+    })?;
+    let url = after[..end].trim();
+    let len = close + 2 + end + 1;
+    let safe = (url.starts_with("https://") || url.starts_with("http://")) && !url.contains(' ');
+    Some((&text[1..close], safe.then_some(url), len))
+}
+
+/// 일반 글자를 이스케이프하고 허용 태그를 되살린 뒤 `**굵게**`/`__굵게__`/`*기울임*`/`_기울임_`을 바꾼다.
+fn emphasis(text: &str) -> String {
+    if text.is_empty() {
+        return String::new();
+    }
+    let mut out = allow_tags(&escape(text));
+    for (delimiter, tag) in [("**", "strong"), ("__", "strong"), ("*", "em"), ("_", "em")] {
+        out = pair_delimiters(&out, delimiter, tag);
+    }
+    out
+}
+
+/// 단어 경계에 있는 구분자 쌍을 태그로 바꾼다(`snake_case`처럼 단어 안의 구분자는 그대로 둔다).
+fn pair_delimiters(text: &str, delimiter: &str, tag: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(open) = find_delimiter(rest, delimiter, true) {
+        let body_start = open + delimiter.len();
+        let Some(close) = find_delimiter(&rest[body_start..], delimiter, false) else {
+            break;
+        };
+        let close = body_start + close;
+        out.push_str(&rest[..open]);
+        out.push_str(&format!("<{tag}>{}</{tag}>", &rest[body_start..close]));
+        rest = &rest[close + delimiter.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+fn find_delimiter(text: &str, delimiter: &str, opening: bool) -> Option<usize> {
+    let mut offset = 0;
+    while let Some(pos) = text[offset..].find(delimiter) {
+        let at = offset + pos;
+        let before = text[..at].chars().next_back();
+        let after = text[at + delimiter.len()..].chars().next();
+        let boundary = if opening {
+            before.is_none_or(|c| !c.is_alphanumeric())
+                && after.is_some_and(|c| !c.is_whitespace() && !delimiter.starts_with(c))
+        } else {
+            at > 0
+                && before.is_some_and(|c| !c.is_whitespace())
+                && after.is_none_or(|c| !c.is_alphanumeric() && !delimiter.starts_with(c))
+        };
+        if boundary {
+            return Some(at);
+        }
+        offset = at + delimiter.len();
+    }
+    None
+}
+
+fn allow_tags(escaped: &str) -> String {
+    let mut out = escaped.to_string();
+    for tag in ALLOWED_TAGS {
+        for (from, to) in [
+            (format!("&lt;{tag}&gt;"), format!("<{tag}>")),
+            (format!("&lt;/{tag}&gt;"), format!("</{tag}>")),
+            (format!("&lt;{tag}/&gt;"), format!("<{tag}>")),
+            (format!("&lt;{tag} /&gt;"), format!("<{tag}>")),
+        ] {
+            out = out.replace(&from, &to);
+        }
+    }
+    out.replace("&lt;details open&gt;", "<details open>")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,248 @@
+//! 공유 HTTP 클라이언트 레지스트리.
+//!
+//! `reqwest::Client`는 연결 풀을 안에 두고, 복제해도 같은 풀을 쓴다. 클라이언트 설정(프로필)마다
+//! 하나만 만들어 두고 VCS/provider 클라이언트에 복제해 넘기면, 한 실행 안의 여러 단계와
+//! 대상(배치/감시 모드)이 같은 호스트 연결을 다시 쓴다.
+//!
+//! 실행마다 달라지는 `User-Agent`/`X-Repopilot-Run-Id` 헤더는 클라이언트가 아니라 요청마다
+//! `attributed`로 붙인다.
+//!
+//! 같은 레지스트리의 `ConditionalCache`는 코멘트 목록 같은 GET 응답을 ETag와 함께 기억해, watch 모드나
+//! MCP 서버처럼 같은 목록을 반복 조회할 때 `If-None-Match`로 변경 여부만 확인한다.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use reqwest::header::{ETAG, HeaderMap, HeaderValue, IF_NONE_MATCH, LINK, USER_AGENT};
+use reqwest::{Client, RequestBuilder, StatusCode};
+use url::Url;
+
+use crate::application::attribution::{self, RequestAttribution};
+use crate::application::config::DEFAULT_USER_AGENT;
+
+/// 요청을 보낸 리뷰 실행을 프록시/서버 로그에서 찾을 수 있게 붙이는 헤더.
+pub const RUN_ID_HEADER: &str = "X-Repopilot-Run-Id";
+
+/// 클라이언트를 나누는 설정 묶음. 같은 프로필이면 같은 클라이언트(연결 풀)를 쓴다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HttpProfile {
+    /// 요청 전체 제한 시간(None이면 제한 없음)
+    pub timeout: Option<Duration>,
+}
+
+impl HttpProfile {
+    /// VCS(GitHub/GitLab) REST API 호출.
+    pub const VCS: Self = Self { timeout: None };
+    /// Provider HTTP API 호출. 응답 생성이 길어도 끝없이 기다리지 않게 제한한다.
+    pub const PROVIDER_API: Self = Self {
+        timeout: Some(Duration::from_secs(120)),
+    };
+
+    fn build(self) -> Client {
+        let mut builder = Client::builder();
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+        // TLS 설정 실패 등 예외 상황에서는 기본 클라이언트로 폴백한다.
+        builder.build().unwrap_or_else(|_| Client::new())
+    }
+}
+
+/// 프로필별 HTTP 클라이언트 레지스트리. 복제본은 같은 레지스트리를 공유한다.
+#[derive(Clone, Default)]
+pub struct HttpClients {
+    clients: Arc<Mutex<HashMap<HttpProfile, Client>>>,
+    conditional: ConditionalCache,
+}
+
+impl HttpClients {
+    /// 프로필의 클라이언트(처음 요청할 때 만든다).
+    pub fn client(&self, profile: HttpProfile) -> Client {
+        let mut clients = self.clients.lock().unwrap_or_else(PoisonError::into_inner);
+        clients
+            .entry(profile)
+            .or_insert_with(|| profile.build())
+            .clone()
+    }
+
+    /// 레지스트리가 공유하는 조건부 요청 캐시.
+    pub fn conditional_cache(&self) -> ConditionalCache {
+        self.conditional.clone()
+    }
+}
+
+/// URL별로 마지막 성공 응답 본문과 ETag를 기억하는 조건부 GET 캐시. 복제본은 같은 캐시를 공유한다.
+///
+/// 서버가 304(변경 없음)로 답하면 기억한 본문을 쓴다. GitHub는 304 응답을 rate limit에 세지 않고,
+/// GitLab도 본문을 다시 만들지 않는다. ETag는 서버가 인증 정보별로 만들므로 키는 URL만 쓴다.
+/// 페이지마다 URL(`page=N`)이 다르므로 ETag도 페이지별로 따로 기억하고, 304 응답에는 페이지 링크
+/// 헤더가 빠질 수 있어 다음 페이지 URL도 본문과 함께 기억한다. 그래서 첫 페이지가 304여도 뒤 페이지의
+/// 새 코멘트를 계속 확인한다.
+#[derive(Clone, Default)]
+pub struct ConditionalCache {
+    entries: Arc<Mutex<HashMap<String, CachedBody>>>,
+}
+
+#[derive(Clone)]
+struct CachedBody {
+    etag: String,
+    body: String,
+    next_page: Option<String>,
+}
+
+/// 조건부 GET 응답.
+pub struct ConditionalResponse {
+    pub status: StatusCode,
+    pub body: String,
+    /// 목록 API의 다음 페이지 URL(마지막 페이지면 None)
+    pub next_page: Option<String>,
+    /// 304(변경 없음)라 기억한 본문을 돌려줬는지 여부
+    pub not_modified: bool,
+}
+
+impl ConditionalCache {
+    /// `request`(`url`로 보내는 GET)를 조건부로 보내 상태와 본문을 돌려준다.
+    /// 304면 기억한 본문을 200으로 돌려주고, ETag 없는 성공 응답이면 기억한 항목을 지운다.
+    pub async fn send(
+        &self,
+        url: &str,
+        request: RequestBuilder,
+    ) -> reqwest::Result<ConditionalResponse> {
+        let cached = self.lock().get(url).cloned();
+        let request = match &cached {
+            Some(cached) => request.header(IF_NONE_MATCH, &cached.etag),
+            None => request,
+        };
+        let response = request.send().await?;
+        let status = response.status();
+        let next_page = next_page_url(url, response.headers());
+        if status == StatusCode::NOT_MODIFIED
+            && let Some(cached) = cached
+        {
+            tracing::debug!(url, "not modified; using cached response");
+            return Ok(ConditionalResponse {
+                status: StatusCode::OK,
+                body: cached.body,
+                next_page: next_page.or(cached.next_page),
+                not_modified: true,
+            });
+        }
+
+        let etag = response
+            .headers()
+            .get(ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+        let body = response.text().await?;
+        if status.is_success() {
+            let mut entries = self.lock();
+            match etag {
+                Some(etag) => {
+                    entries.insert(
+                        url.to_string(),
+                        CachedBody {
+                            etag,
+                            body: body.clone(),
+                            next_page: next_page.clone(),
+                        },
+                    );
+                }
+                None => {
+                    entries.remove(url);
+                }
+            }
+        }
+        Ok(ConditionalResponse {
+            status,
+            body,
+            next_page,
+            not_modified: false,
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, CachedBody>> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// 목록 응답 헤더에서 다음 페이지 URL을 찾는다.
+/// GitHub/GitLab 모두 `Link: <...>; rel="next"`를 보내고, GitLab은 Link가 빠질 때도
+/// `x-next-page`(다음 페이지 번호, 마지막이면 빈 값)를 보내므로 `url`의 `page`만 바꿔 쓴다.
+pub fn next_page_url(url: &str, headers: &HeaderMap) -> Option<String> {
+    let link = headers.get(LINK).and_then(|value| value.to_str().ok());
+    if let Some(next) = link.and_then(link_next) {
+        return Some(next);
+    }
+
+    let page = headers
+        .get("x-next-page")
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|page| !page.is_empty())?;
+    with_page(url, page)
+}
+
+/// `url`(`page`가 없으면 1페이지) 바로 뒤 페이지의 URL.
+/// 304로 받은 마지막 페이지가 꽉 차 있으면 그 뒤에 새 페이지가 생겼는지 확인할 때 쓴다.
+pub fn following_page_url(url: &str) -> Option<String> {
+    let current = Url::parse(url)
+        .ok()?
+        .query_pairs()
+        .find(|(key, _)| key == "page")
+        .and_then(|(_, value)| value.parse::<u64>().ok())
+        .unwrap_or(1);
+    with_page(url, &(current + 1).to_string())
+}
+
+fn with_page(url: &str, page: &str) -> Option<String> {
+    let mut next = Url::parse(url).ok()?;
+    let pairs: Vec<(String, String)> = next
+        .query_pairs()
+        .filter(|(key, _)| key != "page")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect();
+    next.query_pairs_mut()
+        .clear()
+        .extend_pairs(pairs)
+        .append_pair("page", page);
+    Some(next.into())
+}
+
+/// `Link` 헤더 값에서 `rel="next"` 대상 URL.
+fn link_next(link: &str) -> Option<String> {
+    link.split(',').find_map(|entry| {
+        let (target, params) = entry.split_once(';')?;
+        let is_next = params.split(';').any(|param| {
+            param.trim().strip_prefix("rel=").is_some_and(|rel| {
+                rel.trim_matches('"')
+                    .split_whitespace()
+                    .any(|r| r == "next")
+            })
+        });
+        is_next.then(|| {
+            target
+                .trim()
+                .trim_start_matches('<')
+                .trim_end_matches('>')
+                .to_string()
+        })
+    })
+}
+
+/// 현재 실행의 귀속 헤더(`User-Agent`, 실행 중이면 `X-Repopilot-Run-Id`)를 붙인다.
+pub fn attributed(request: RequestBuilder) -> RequestBuilder {
+    attribute(request, &attribution::current())
+}
+
+/// 별도 스레드의 런타임에서 보내는 요청처럼 task-local을 읽을 수 없을 때 미리 잡아 둔 정보로 붙인다.
+/// 헤더 값으로 쓸 수 없는 `User-Agent`는 기본값으로 바꾼다.
+pub fn attribute(request: RequestBuilder, attribution: &RequestAttribution) -> RequestBuilder {
+    let user_agent = HeaderValue::from_str(&attribution.user_agent)
+        .unwrap_or_else(|_| HeaderValue::from_static(DEFAULT_USER_AGENT));
+    let request = request.header(USER_AGENT, user_agent);
+    match attribution.run_id.as_deref() {
+        Some(run_id) => request.header(RUN_ID_HEADER, run_id),
+        None => request,
+    }
+}
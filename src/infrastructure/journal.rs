@@ -0,0 +1,371 @@
+//! 실행 저널(run journal) 파일 저장소.
+//!
+//! provider 실행이 끝난 결과를 `$XDG_STATE_HOME/repopilot/runs/` 아래 JSON으로 보관해
+//! 게시 단계만 다시 시도할 수 있게 한다.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::application::ports::RunJournalEntry;
+use crate::domain::diff::DiffStats;
+use crate::domain::review::{
+    AgentReaction, ConsolidatedReview, FileReview, Finding, LineRange, ProviderRun, ReviewStage,
+    Severity, StageReview, TokenUsage,
+};
+use crate::infrastructure::state;
+
+// 이전 버전이 작업 디렉터리에 남긴 저널 위치. resume/정리할 때만 읽는다.
+const LEGACY_JOURNAL_DIR: &str = ".repopilot/runs";
+
+#[derive(Serialize, Deserialize)]
+struct JournalFile {
+    target_url: String,
+    head_sha: String,
+    primary_results: Vec<JournalRun>,
+    reactions: Vec<JournalReaction>,
+    consolidated: Option<JournalConsolidated>,
+    /// 이전 버전 저널에는 없다.
+    #[serde(default)]
+    stage_reviews: Vec<JournalStageReview>,
+    diff_stats: Option<JournalDiffStats>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalRun {
+    id: String,
+    name: String,
+    body: String,
+    usage: JournalUsage,
+    findings: Vec<JournalFinding>,
+    file_reviews: Vec<JournalFileReview>,
+    elapsed_secs: Option<f32>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalUsage {
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+    total_tokens: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalFinding {
+    file: Option<String>,
+    line_start: Option<u32>,
+    line_end: Option<u32>,
+    severity: String,
+    title: String,
+    body: String,
+    confidence: Option<f32>,
+    #[serde(default)]
+    suggestion: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalFileReview {
+    path: String,
+    body: String,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalReaction {
+    provider_id: String,
+    provider_name: String,
+    round: usize,
+    body: String,
+    /// 이전 버전 저널에는 없다.
+    usage: Option<JournalUsage>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalConsolidated {
+    /// 이전 버전 저널에는 없다.
+    provider_id: Option<String>,
+    provider_name: String,
+    body: String,
+    /// 이전 버전 저널에는 없다.
+    usage: Option<JournalUsage>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalStageReview {
+    stage: String,
+    provider_id: String,
+    provider_name: String,
+    body: String,
+    /// 이전 버전 저널에는 없다.
+    #[serde(default)]
+    findings: Vec<JournalFinding>,
+    usage: JournalUsage,
+}
+
+#[derive(Serialize, Deserialize)]
+struct JournalDiffStats {
+    files_changed: usize,
+    insertions: usize,
+    deletions: usize,
+    top_level_dirs: Vec<String>,
+}
+
+/// 대상 URL에 해당하는 저널을 저장한다.
+pub fn save(entry: &RunJournalEntry) -> Result<PathBuf> {
+    let path = journal_dir()?.join(journal_file_name(&entry.target_url));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create {}", parent.display()))?;
+    }
+    let file = JournalFile::from_entry(entry);
+    let json = serde_json::to_string_pretty(&file).context("failed to serialize run journal")?;
+    fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+/// 대상 URL의 저널을 읽는다. 없으면 None.
+pub fn load(target_url: &str) -> Result<Option<RunJournalEntry>> {
+    let Some(path) = journal_paths(target_url)?
+        .into_iter()
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    let raw =
+        fs::read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+    let file: JournalFile = serde_json::from_str(&raw)
+        .with_context(|| format!("invalid run journal: {}", path.display()))?;
+    Ok(Some(file.into_entry()))
+}
+
+/// 대상 URL의 저널을 삭제한다(없으면 무시).
+pub fn remove(target_url: &str) -> Result<()> {
+    for path in journal_paths(target_url)? {
+        if path.is_file() {
+            fs::remove_file(&path)
+                .with_context(|| format!("failed to remove {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// 저널 디렉터리(`$XDG_STATE_HOME/repopilot/runs`).
+fn journal_dir() -> Result<PathBuf> {
+    let dir = state::state_dir().context("cannot determine state directory for run journal")?;
+    Ok(dir.join("runs"))
+}
+
+/// 대상 URL의 저널 후보 경로(현재 위치, 이전 버전 위치 순).
+fn journal_paths(target_url: &str) -> Result<[PathBuf; 2]> {
+    let name = journal_file_name(target_url);
+    Ok([
+        journal_dir()?.join(&name),
+        PathBuf::from(LEGACY_JOURNAL_DIR).join(name),
+    ])
+}
+
+fn journal_file_name(target_url: &str) -> String {
+    // URL을 파일 이름으로 쓸 수 있게 영숫자 외 문자를 `_`로 바꾼다.
+    let name: String = target_url
+        .trim()
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    format!("{name}.json")
+}
+
+impl JournalUsage {
+    fn from_usage(usage: &TokenUsage) -> Self {
+        Self {
+            prompt_tokens: usage.prompt_tokens,
+            completion_tokens: usage.completion_tokens,
+            total_tokens: usage.total_tokens,
+        }
+    }
+
+    fn into_usage(self) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: self.prompt_tokens,
+            completion_tokens: self.completion_tokens,
+            total_tokens: self.total_tokens,
+        }
+    }
+}
+
+impl JournalFinding {
+    fn from_finding(f: &Finding) -> Self {
+        Self {
+            file: f.file.clone(),
+            line_start: f.line_range.map(|r| r.start),
+            line_end: f.line_range.map(|r| r.end),
+            severity: f.severity.code().to_string(),
+            title: f.title.clone(),
+            body: f.body.clone(),
+            confidence: f.confidence,
+            suggestion: f.suggestion.clone(),
+        }
+    }
+
+    fn into_finding(self) -> Finding {
+        Finding {
+            file: self.file,
+            line_range: match (self.line_start, self.line_end) {
+                (Some(start), Some(end)) => Some(LineRange { start, end }),
+                (Some(start), None) => Some(LineRange { start, end: start }),
+                _ => None,
+            },
+            severity: Severity::parse(&self.severity).unwrap_or(Severity::Minor),
+            title: self.title,
+            body: self.body,
+            confidence: self.confidence,
+            suggestion: self.suggestion,
+        }
+    }
+}
+
+impl JournalFile {
+    fn from_entry(entry: &RunJournalEntry) -> Self {
+        Self {
+            target_url: entry.target_url.clone(),
+            head_sha: entry.head_sha.clone(),
+            primary_results: entry
+                .primary_results
+                .iter()
+                .map(|run| JournalRun {
+                    id: run.id.clone(),
+                    name: run.name.clone(),
+                    body: run.body.clone(),
+                    usage: JournalUsage::from_usage(&run.usage),
+                    findings: run
+                        .findings
+                        .iter()
+                        .map(JournalFinding::from_finding)
+                        .collect(),
+                    file_reviews: run
+                        .file_reviews
+                        .iter()
+                        .map(|r| JournalFileReview {
+                            path: r.path.clone(),
+                            body: r.body.clone(),
+                        })
+                        .collect(),
+                    elapsed_secs: run.elapsed_secs,
+                })
+                .collect(),
+            reactions: entry
+                .reactions
+                .iter()
+                .map(|r| JournalReaction {
+                    provider_id: r.provider_id.clone(),
+                    provider_name: r.provider_name.clone(),
+                    round: r.round,
+                    body: r.body.clone(),
+                    usage: Some(JournalUsage::from_usage(&r.usage)),
+                })
+                .collect(),
+            consolidated: entry.consolidated.as_ref().map(|c| JournalConsolidated {
+                provider_id: Some(c.provider_id.clone()),
+                provider_name: c.provider_name.clone(),
+                body: c.body.clone(),
+                usage: Some(JournalUsage::from_usage(&c.usage)),
+            }),
+            stage_reviews: entry
+                .stage_reviews
+                .iter()
+                .map(|s| JournalStageReview {
+                    stage: s.stage.code().to_string(),
+                    provider_id: s.provider_id.clone(),
+                    provider_name: s.provider_name.clone(),
+                    body: s.body.clone(),
+                    findings: s
+                        .findings
+                        .iter()
+                        .map(JournalFinding::from_finding)
+                        .collect(),
+                    usage: JournalUsage::from_usage(&s.usage),
+                })
+                .collect(),
+            diff_stats: entry.diff_stats.as_ref().map(|d| JournalDiffStats {
+                files_changed: d.files_changed,
+                insertions: d.insertions,
+                deletions: d.deletions,
+                top_level_dirs: d.top_level_dirs.clone(),
+            }),
+        }
+    }
+
+    fn into_entry(self) -> RunJournalEntry {
+        RunJournalEntry {
+            target_url: self.target_url,
+            head_sha: self.head_sha,
+            primary_results: self
+                .primary_results
+                .into_iter()
+                .map(|run| ProviderRun {
+                    id: run.id,
+                    name: run.name,
+                    body: run.body,
+                    usage: run.usage.into_usage(),
+                    findings: run
+                        .findings
+                        .into_iter()
+                        .map(JournalFinding::into_finding)
+                        .collect(),
+                    file_reviews: run
+                        .file_reviews
+                        .into_iter()
+                        .map(|r| FileReview {
+                            path: r.path,
+                            body: r.body,
+                        })
+                        .collect(),
+                    elapsed_secs: run.elapsed_secs,
+                })
+                .collect(),
+            reactions: self
+                .reactions
+                .into_iter()
+                .map(|r| AgentReaction {
+                    provider_id: r.provider_id,
+                    provider_name: r.provider_name,
+                    round: r.round,
+                    body: r.body,
+                    usage: r.usage.map(JournalUsage::into_usage).unwrap_or_default(),
+                })
+                .collect(),
+            consolidated: self.consolidated.map(|c| ConsolidatedReview {
+                provider_id: c.provider_id.unwrap_or_default(),
+                provider_name: c.provider_name,
+                body: c.body,
+                usage: c.usage.map(JournalUsage::into_usage).unwrap_or_default(),
+            }),
+            // 이 버전이 모르는 단계는 버린다.
+            stage_reviews: self
+                .stage_reviews
+                .into_iter()
+                .filter_map(|s| {
+                    Some(StageReview {
+                        stage: ReviewStage::parse(&s.stage)?,
+                        provider_id: s.provider_id,
+                        provider_name: s.provider_name,
+                        body: s.body,
+                        findings: s
+                            .findings
+                            .into_iter()
+                            .map(JournalFinding::into_finding)
+                            .collect(),
+                        usage: s.usage.into_usage(),
+                    })
+                })
+                .collect(),
+            diff_stats: self.diff_stats.map(|d| DiffStats {
+                files_changed: d.files_changed,
+                insertions: d.insertions,
+                deletions: d.deletions,
+                top_level_dirs: d.top_level_dirs,
+            }),
+        }
+    }
+}
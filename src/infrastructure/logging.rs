@@ -0,0 +1,245 @@
+//! tracing 구독자 구성(콘솔 + 상태 디렉터리 JSON 로그 파일).
+//!
+//! 콘솔은 기존처럼 `RUST_LOG`(기본 `warn`)를 따르고, 파일 로그는 `REPOPILOT_LOG`
+//! (기본 `info,repopilot=debug`, `off`면 끔)를 따른다. 파일의 모든 이벤트에는 `run_id`가 붙는다:
+//! 리뷰 실행 안의 이벤트는 그 실행의 ID, 나머지는 프로세스 단위 ID.
+
+use std::env;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+use crate::infrastructure::clock::random_id;
+use crate::infrastructure::state;
+
+const LOG_FILE: &str = "repopilot.log";
+const DEFAULT_FILE_FILTER: &str = "info,repopilot=debug";
+// 이 크기를 넘으면 시작 시 `repopilot.log.1`로 한 번 밀어낸다.
+const MAX_LOG_BYTES: u64 = 10 * 1024 * 1024;
+
+static CONSOLE_MUTED: AtomicBool = AtomicBool::new(false);
+
+/// 전역 tracing 구독자를 설치한다. 로그 파일을 열 수 없으면 콘솔 출력만 설정한다.
+/// 콘솔 로그는 stderr로 보내 stdout 결과(NDJSON, MCP 메시지)와 섞이지 않게 한다.
+pub fn init() {
+    let console = tracing_subscriber::fmt::layer()
+        .with_writer(|| -> Box<dyn Write> {
+            if CONSOLE_MUTED.load(Ordering::Relaxed) {
+                Box::new(io::sink())
+            } else {
+                Box::new(io::stderr())
+            }
+        })
+        .with_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")));
+    let file = file_filter().and_then(|filter| {
+        let path = log_path()?;
+        let file = open_log_file(&path).ok()?;
+        Some(JsonFileLayer::new(file).with_filter(filter))
+    });
+    tracing_subscriber::registry()
+        .with(console)
+        .with(file)
+        .init();
+}
+
+/// 콘솔 로그 출력을 멈추거나 다시 켠다. 파일 로그는 그대로 남긴다.
+/// 전체 화면 UI(`--tui`)가 떠 있는 동안 stderr 로그가 화면을 깨뜨리지 않게 한다.
+pub fn set_console_muted(muted: bool) {
+    CONSOLE_MUTED.store(muted, Ordering::Relaxed);
+}
+
+/// JSON 로그 파일 경로(`$XDG_STATE_HOME/repopilot/logs/repopilot.log`).
+pub fn log_path() -> Option<PathBuf> {
+    Some(state::state_dir()?.join("logs").join(LOG_FILE))
+}
+
+fn file_filter() -> Option<EnvFilter> {
+    let raw = env::var("REPOPILOT_LOG").unwrap_or_default();
+    let raw = raw.trim();
+    if raw.eq_ignore_ascii_case("off") {
+        return None;
+    }
+    let directives = if raw.is_empty() {
+        DEFAULT_FILE_FILTER
+    } else {
+        raw
+    };
+    Some(EnvFilter::try_new(directives).unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILE_FILTER)))
+}
+
+fn open_log_file(path: &Path) -> std::io::Result<File> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    if fs::metadata(path).is_ok_and(|meta| meta.len() > MAX_LOG_BYTES) {
+        let _ = fs::rename(path, path.with_extension("log.1"));
+    }
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// 이벤트를 한 줄짜리 JSON 객체로 기록하는 레이어.
+struct JsonFileLayer {
+    file: Mutex<File>,
+    process_run_id: String,
+}
+
+/// span 생성/기록 시 모아 둔 필드(확장 데이터로 보관).
+struct SpanFields(Map<String, Value>);
+
+impl JsonFileLayer {
+    fn new(file: File) -> Self {
+        Self {
+            file: Mutex::new(file),
+            process_run_id: random_id(),
+        }
+    }
+}
+
+impl<S> Layer<S> for JsonFileLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut fields = Map::new();
+        attrs.record(&mut JsonVisitor(&mut fields));
+        span.extensions_mut().insert(SpanFields(fields));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        if let Some(SpanFields(fields)) = extensions.get_mut::<SpanFields>() {
+            values.record(&mut JsonVisitor(fields));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let mut fields = Map::new();
+        event.record(&mut JsonVisitor(&mut fields));
+        let message = fields.remove("message").unwrap_or(Value::Null);
+
+        // 바깥 span부터 필드를 덮어써 가장 안쪽 값(특히 run_id)이 남게 한다.
+        let mut span_fields = Map::new();
+        let mut span_names = Vec::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                span_names.push(Value::String(span.name().to_string()));
+                if let Some(SpanFields(values)) = span.extensions().get::<SpanFields>() {
+                    for (key, value) in values {
+                        span_fields.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        let run_id = span_fields
+            .remove("run_id")
+            .unwrap_or_else(|| Value::String(self.process_run_id.clone()));
+
+        let mut line = Map::new();
+        line.insert("ts".to_string(), Value::String(utc_timestamp()));
+        line.insert("level".to_string(), Value::String(meta.level().to_string()));
+        line.insert("run_id".to_string(), run_id);
+        line.insert(
+            "target".to_string(),
+            Value::String(meta.target().to_string()),
+        );
+        if !span_names.is_empty() {
+            line.insert("spans".to_string(), Value::Array(span_names));
+        }
+        line.insert("message".to_string(), message);
+        for (key, value) in span_fields.into_iter().chain(fields) {
+            line.entry(key).or_insert(value);
+        }
+
+        let Ok(mut text) = serde_json::to_string(&Value::Object(line)) else {
+            return;
+        };
+        text.push('\n');
+        if let Ok(mut file) = self.file.lock() {
+            // 여러 프로세스가 같은 파일에 append하므로 한 줄을 한 번에 쓴다.
+            let _ = file.write_all(text.as_bytes());
+        }
+    }
+}
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.0.insert(
+            field.name().to_string(),
+            Value::String(format!("{value:?}")),
+        );
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0
+            .insert(field.name().to_string(), Value::String(value.to_string()));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.0.insert(field.name().to_string(), Value::from(value));
+    }
+}
+
+/// 현재 시각을 RFC 3339 UTC 문자열(`2024-01-02T03:04:05.678Z`)로 만든다.
+fn utc_timestamp() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let secs = now.as_secs();
+    let (days, rem) = (secs / 86_400, secs % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60,
+        now.subsec_millis()
+    )
+}
+
+// 1970-01-01 기준 일수를 그레고리력 날짜로 바꾼다(Howard Hinnant의 civil_from_days).
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
@@ -2,7 +2,21 @@
 //! 외부 시스템(API/CLI/파일시스템)과 직접 통신하는 구현체 집합.
 
 pub mod adapters;
+pub mod child_process;
+pub mod clock;
 pub mod config;
+pub mod html;
+pub mod http;
+pub mod journal;
+pub mod logging;
 pub mod providers;
 pub mod render;
+pub mod smtp;
+pub mod state;
+pub mod storage;
+pub mod template;
+pub mod throttle;
+pub mod traffic;
 pub mod vcs;
+#[cfg(feature = "wasm-hooks")]
+pub mod wasm_hook;
@@ -6,13 +6,22 @@ use reqwest::Client;
 use serde_json::{Value, json};
 
 use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::domain::template::{split_cache_breakpoint, strip_cache_breakpoint};
 use crate::infrastructure::config::{Config, ProviderCommandSpec, resolve_provider_api_key};
+use crate::infrastructure::http::{HttpClients, HttpProfile};
 
 use super::{
     ReviewProvider, build_primary_prompt, command_available, run_provider_command,
-    api_runner::{build_api_client, collect_text, send_json},
+    api_runner::{collect_text, send_json},
 };
 
+// 입력 토큰으로 집계하는 usage 항목(캐시 미사용분, 캐시 쓰기, 캐시 읽기).
+const INPUT_USAGE_KEYS: [&str; 3] = [
+    "input_tokens",
+    "cache_creation_input_tokens",
+    "cache_read_input_tokens",
+];
+
 struct CliBackend {
     spec: ProviderCommandSpec,
     auth_command: Option<Vec<String>>,
@@ -29,6 +38,7 @@ struct AnthropicApiBackend {
     base_url: String,
     model: String,
     credential: String,
+    prompt_cache: bool,
 }
 
 pub struct AnthropicProvider {
@@ -37,7 +47,7 @@ pub struct AnthropicProvider {
 
 impl AnthropicProvider {
     /// API key가 있으면 API 모드, 없으면 CLI 모드로 provider를 활성화한다.
-    pub fn from_config(config: &Config) -> Option<Self> {
+    pub fn from_config(config: &Config, http: &HttpClients) -> Option<Self> {
         let provider = config.providers.anthropic.as_ref()?;
         if !provider.is_enabled() {
             return None;
@@ -45,7 +55,7 @@ impl AnthropicProvider {
 
         if let Some(credential) = resolve_provider_api_key(provider).credential {
             let api = AnthropicApiBackend {
-                client: build_api_client(),
+                client: http.client(HttpProfile::PROVIDER_API),
                 base_url: provider
                     .api_base
                     .clone()
@@ -55,6 +65,7 @@ impl AnthropicProvider {
                     .clone()
                     .unwrap_or_else(|| "claude-3-7-sonnet-latest".to_string()),
                 credential,
+                prompt_cache: provider.prompt_cache(),
             };
             return Some(Self {
                 backend: AnthropicBackend::Api(api),
@@ -97,7 +108,7 @@ impl AnthropicProvider {
             "model": api.model,
             "max_tokens": 4096,
             "messages": [
-                { "role": "user", "content": prompt }
+                { "role": "user", "content": message_content(prompt, api.prompt_cache) }
             ]
         });
 
@@ -122,29 +133,43 @@ impl AnthropicProvider {
             bail!("Claude: empty response content");
         }
 
+        // input_tokens에는 캐시에서 읽거나 캐시에 쓴 토큰이 빠져 있으므로 더해서 전체 입력으로 집계한다.
+        let usage = |key: &str| response.get("usage")?.get(key)?.as_u64();
+        let prompt_tokens = INPUT_USAGE_KEYS
+            .into_iter()
+            .filter_map(usage)
+            .reduce(|a, b| a + b);
+        let completion_tokens = usage("output_tokens");
         Ok(ProviderResponse {
             content,
             usage: TokenUsage {
-                prompt_tokens: response
-                    .pointer("/usage/input_tokens")
-                    .and_then(Value::as_u64),
-                completion_tokens: response
-                    .pointer("/usage/output_tokens")
-                    .and_then(Value::as_u64),
-                total_tokens: match (
-                    response.pointer("/usage/input_tokens").and_then(Value::as_u64),
-                    response.pointer("/usage/output_tokens").and_then(Value::as_u64),
-                ) {
+                prompt_tokens,
+                completion_tokens,
+                total_tokens: match (prompt_tokens, completion_tokens) {
                     (Some(input), Some(output)) => Some(input + output),
                     (Some(input), None) => Some(input),
                     (None, Some(output)) => Some(output),
                     (None, None) => None,
                 },
             },
+            findings: Vec::new(),
         })
     }
 }
 
+/// 사용자 메시지 본문. 캐시 표시가 있으면 그 앞부분을 `cache_control` 블록으로 나눠
+/// 같은 앞부분을 반복하는 다음 청크/라운드 호출이 캐시를 읽게 한다.
+fn message_content(prompt: &str, prompt_cache: bool) -> Value {
+    let (prefix, rest) = split_cache_breakpoint(prompt);
+    if !prompt_cache || prefix.is_empty() || rest.is_empty() {
+        return json!(strip_cache_breakpoint(prompt));
+    }
+    json!([
+        { "type": "text", "text": prefix, "cache_control": { "type": "ephemeral" } },
+        { "type": "text", "text": rest },
+    ])
+}
+
 fn extract_anthropic_content(response: &Value) -> String {
     if let Some(content) = response.get("content") {
         return collect_text(content);
@@ -163,7 +188,7 @@ impl ReviewProvider for AnthropicProvider {
     }
 
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
-        let prompt = build_primary_prompt(request);
+        let prompt = build_primary_prompt(request)?;
         match &self.backend {
             AnthropicBackend::Api(_) => self.review_via_api(&prompt).await,
             AnthropicBackend::Cli(cli) => {
@@ -3,28 +3,57 @@
 use std::time::Duration;
 
 use anyhow::{Context, Result, bail};
-use reqwest::{Client, RequestBuilder};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{RequestBuilder, Response, StatusCode};
 use serde_json::Value;
 
-/// Provider API 호출용 기본 HTTP 클라이언트를 생성한다.
-pub fn build_api_client() -> Client {
-    // TLS 설정 실패 등 예외 상황에서는 기본 클라이언트로 폴백한다.
-    Client::builder()
-        .timeout(Duration::from_secs(120))
-        .build()
-        .unwrap_or_else(|_| Client::new())
-}
+use crate::infrastructure::clock::random_u64;
+use crate::infrastructure::http::attributed;
+
+/// 429(rate limit) 응답에 대한 최대 재시도 횟수.
+const MAX_RATE_LIMIT_RETRIES: u32 = 4;
+/// 재시도 대기 시간 합계 상한. `Retry-After`가 남은 예산보다 길면 바로 실패한다.
+const RATE_LIMIT_WAIT_BUDGET: Duration = Duration::from_secs(90);
+/// `Retry-After`가 없을 때 첫 재시도 기본 대기 시간(재시도마다 두 배).
+const RATE_LIMIT_BASE_DELAY: Duration = Duration::from_secs(2);
 
 /// JSON 응답을 기대하는 요청을 전송하고 실패/파싱 오류를 표준화한다.
+/// 429 응답은 `Retry-After`(없으면 지수 백오프)에 jitter를 더해 기다린 뒤 예산 안에서 재시도한다.
+/// 귀속 헤더(`User-Agent`, `X-Repopilot-Run-Id`)는 여기서 붙인다.
 pub async fn send_json(
     provider_name: &str,
     action: &str,
     request: RequestBuilder,
 ) -> Result<Value> {
-    let response = request
-        .send()
-        .await
-        .with_context(|| format!("{provider_name}: failed to {action}"))?;
+    let mut request = attributed(request);
+    let mut waited = Duration::ZERO;
+    let mut attempt = 0;
+    let response = loop {
+        // 본문을 복제할 수 없는 요청은 재시도하지 않는다.
+        let retry = request.try_clone();
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("{provider_name}: failed to {action}"))?;
+        let Some(next) = retry else {
+            break response;
+        };
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            break response;
+        }
+        let delay = rate_limit_delay(&response, attempt);
+        if attempt >= MAX_RATE_LIMIT_RETRIES || waited + delay > RATE_LIMIT_WAIT_BUDGET {
+            break response;
+        }
+        attempt += 1;
+        waited += delay;
+        tracing::warn!(
+            "{provider_name}: rate limited; retrying in {:.1}s ({attempt}/{MAX_RATE_LIMIT_RETRIES})",
+            delay.as_secs_f32()
+        );
+        tokio::time::sleep(delay).await;
+        request = next;
+    };
 
     let status = response.status();
     let body = response
@@ -32,6 +61,11 @@ pub async fn send_json(
         .await
         .with_context(|| format!("{provider_name}: failed to read {action} response body"))?;
 
+    if status == StatusCode::TOO_MANY_REQUESTS && attempt > 0 {
+        bail!(
+            "{provider_name}: {action} still rate limited after {attempt} retries ({status}): {body}"
+        );
+    }
     if !status.is_success() {
         bail!("{provider_name}: {action} failed ({status}): {body}");
     }
@@ -40,6 +74,20 @@ pub async fn send_json(
         .with_context(|| format!("{provider_name}: invalid JSON response while {action}"))
 }
 
+/// 다음 재시도까지 기다릴 시간. `Retry-After`(초)가 있으면 따르고, 없으면 지수 백오프를 쓴다.
+/// 여러 provider/청크가 같은 순간에 재시도하지 않도록 0~50% jitter를 더한다.
+fn rate_limit_delay(response: &Response, attempt: u32) -> Duration {
+    let base = response
+        .headers()
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(RATE_LIMIT_BASE_DELAY * 2u32.pow(attempt));
+    let jitter = random_u64() % 500;
+    base + base * jitter as u32 / 1000
+}
+
 /// API 응답 구조에서 텍스트를 재귀적으로 추출한다.
 pub fn collect_text(value: &Value) -> String {
     match value {
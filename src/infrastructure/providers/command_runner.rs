@@ -3,13 +3,15 @@
 use std::io::IsTerminal;
 use std::process::Stdio;
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 use tokio::io::AsyncWriteExt;
 use tokio::process::Command;
 
 use crate::domain::review::ProviderResponse;
-use crate::infrastructure::config::command_exists;
+use crate::domain::template::strip_cache_breakpoint;
+use crate::infrastructure::child_process::{self, ChildGroup};
 use crate::infrastructure::config::ProviderCommandSpec;
+use crate::infrastructure::config::{command_exists, command_program, is_batch_script};
 
 use super::usage_parser::parse_usage;
 
@@ -21,12 +23,13 @@ pub async fn run_provider_command(
     auth_command: Option<&[String]>,
     auto_auth: bool,
 ) -> Result<ProviderResponse> {
+    let prompt = strip_cache_breakpoint(prompt);
     let mut current = spec.clone();
     let mut tried_stdin_fallback = false;
     let mut tried_auth = false;
 
     loop {
-        match run_provider_command_once(provider_name, &current, prompt).await {
+        match run_provider_command_once(provider_name, &current, &prompt).await {
             Ok(text) => return Ok(text),
             Err(err) => {
                 let msg = format!("{err:#}");
@@ -94,7 +97,7 @@ async fn run_auth_command(provider_name: &str, cmd: &[String]) -> Result<()> {
         eprintln!("{provider_name}: Gemini login is interactive. Choose Login with Google, finish auth, then exit.");
     }
 
-    let status = Command::new(program)
+    let status = Command::new(command_program(program))
         .args(&args)
         .stdin(Stdio::inherit())
         .stdout(Stdio::inherit())
@@ -114,51 +117,76 @@ async fn run_provider_command_once(
     spec: &ProviderCommandSpec,
     prompt: &str,
 ) -> Result<ProviderResponse> {
+    // Windows의 `.cmd` shim은 cmd.exe를 거치므로 여러 줄 프롬프트를 인자로 넘길 수 없다.
+    // 이때는 `{prompt}` 자리를 비우고 프롬프트를 stdin으로 보낸다(각 CLI는 stdin 입력을 프롬프트에 합친다).
+    let program = command_program(&spec.command);
+    let batch = is_batch_script(&program);
+    let use_stdin = spec.use_stdin || batch;
+
     // {prompt} 치환 또는 stdin 전달 규칙에 따라 최종 실행 인자를 구성한다.
     let mut args = Vec::new();
     let mut prompt_in_args = false;
     for arg in &spec.args {
         if arg.contains("{prompt}") {
             prompt_in_args = true;
-            args.push(arg.replace("{prompt}", prompt));
+            args.push(arg.replace("{prompt}", if batch { "" } else { prompt }));
         } else {
             args.push(arg.clone());
         }
     }
 
-    if !spec.use_stdin && !prompt_in_args {
+    if !use_stdin && !prompt_in_args {
         args.push(prompt.to_string());
     }
 
-    let mut cmd = Command::new(&spec.command);
+    let mut cmd = Command::new(&program);
+    // 리뷰가 취소되거나 시간 초과로 future가 버려지면 자식 프로세스(그룹)도 함께 종료한다.
     cmd.args(&args)
         .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+    child_process::isolate(&mut cmd);
 
-    if spec.use_stdin {
+    // 새 프로세스 그룹은 터미널의 백그라운드 그룹이라 터미널을 읽거나 검사하면 SIGTTIN/SIGTTOU로
+    // 멈춘다. 프롬프트를 stdin으로 보내지 않을 때도 터미널을 물려주지 않는다.
+    if use_stdin {
         cmd.stdin(Stdio::piped());
+    } else {
+        cmd.stdin(Stdio::null());
     }
 
     let mut child = cmd
         .spawn()
         .with_context(|| format!("failed to spawn {} command '{}'", provider_name, spec.command))?;
+    let _group = ChildGroup::track(&child);
+
+    let run = async move {
+        if use_stdin {
+            let mut stdin = child
+                .stdin
+                .take()
+                .context("failed to open provider command stdin")?;
+            stdin
+                .write_all(prompt.as_bytes())
+                .await
+                .context("failed to write prompt to provider command stdin")?;
+            drop(stdin);
+        }
 
-    if spec.use_stdin {
-        let mut stdin = child
-            .stdin
-            .take()
-            .context("failed to open provider command stdin")?;
-        stdin
-            .write_all(prompt.as_bytes())
+        child
+            .wait_with_output()
             .await
-            .context("failed to write prompt to provider command stdin")?;
-        drop(stdin);
-    }
-
-    let output = child
-        .wait_with_output()
+            .context("provider command execution failed")
+    };
+    let output = tokio::time::timeout(spec.timeout, run)
         .await
-        .context("provider command execution failed")?;
+        .map_err(|_| {
+            anyhow!(
+                "{} command timed out after {}s (providers.<name>.timeout_secs)",
+                provider_name,
+                spec.timeout.as_secs()
+            )
+        })??;
 
     let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
     let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
@@ -185,11 +213,13 @@ async fn run_provider_command_once(
         return Ok(ProviderResponse {
             content: stderr,
             usage,
+            findings: Vec::new(),
         });
     }
 
     Ok(ProviderResponse {
         content: stdout,
         usage,
+        findings: Vec::new(),
     })
 }
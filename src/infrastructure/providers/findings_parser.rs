@@ -0,0 +1,94 @@
+//! Provider 출력의 구조화 지적사항(JSON 블록) 파서.
+//!
+//! 출력 계약은 `prompt.rs`의 `FINDINGS_CONTRACT`에 정의되어 있다.
+
+use serde::Deserialize;
+
+use crate::domain::review::{Finding, LineRange, ProviderResponse, Severity, SeverityTaxonomy};
+
+/// 지적사항 JSON 블록을 여는 펜스 문자열.
+pub const FINDINGS_FENCE: &str = "```json repopilot-findings";
+
+#[derive(Debug, Deserialize)]
+struct RawFinding {
+    file: Option<String>,
+    line_start: Option<u32>,
+    line_end: Option<u32>,
+    severity: Option<String>,
+    title: Option<String>,
+    body: Option<String>,
+    confidence: Option<f32>,
+    suggestion: Option<String>,
+}
+
+/// 응답 본문에서 지적사항 블록을 분리해 `findings`에 채운다.
+/// - 블록이 없거나 파싱에 실패하면 본문을 그대로 두고 findings는 비워 둔다.
+pub fn attach_findings(
+    mut response: ProviderResponse,
+    taxonomy: &SeverityTaxonomy,
+) -> ProviderResponse {
+    if let Some((prose, findings)) = split_findings(&response.content, taxonomy) {
+        response.content = prose;
+        response.findings = findings;
+    }
+    response
+}
+
+/// 본문을 (산문, 지적사항)으로 나눈다. 계약 블록이 없으면 None.
+pub fn split_findings(content: &str, taxonomy: &SeverityTaxonomy) -> Option<(String, Vec<Finding>)> {
+    let start = content.rfind(FINDINGS_FENCE)?;
+    let json_start = start + FINDINGS_FENCE.len();
+    let json_len = content[json_start..].find("```")?;
+    let json = content[json_start..json_start + json_len].trim();
+
+    let raw: Vec<RawFinding> = serde_json::from_str(json).ok()?;
+    let findings = raw
+        .into_iter()
+        .filter_map(|finding| to_finding(finding, taxonomy))
+        .collect();
+
+    let mut prose = content[..start].trim_end().to_string();
+    let rest = content[json_start + json_len + 3..].trim();
+    if !rest.is_empty() {
+        prose.push_str("\n\n");
+        prose.push_str(rest);
+    }
+
+    Some((prose, findings))
+}
+
+fn to_finding(raw: RawFinding, taxonomy: &SeverityTaxonomy) -> Option<Finding> {
+    let title = raw
+        .title
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())?;
+    // 알 수 없는 심각도는 과장하지 않도록 Minor로 취급한다.
+    let severity = raw
+        .severity
+        .as_deref()
+        .and_then(|label| taxonomy.parse(label))
+        .unwrap_or(Severity::Minor);
+    let line_range = match (raw.line_start, raw.line_end) {
+        (Some(start), Some(end)) if end >= start => Some(LineRange { start, end }),
+        (Some(start), _) => Some(LineRange { start, end: start }),
+        (None, Some(end)) => Some(LineRange { start: end, end }),
+        (None, None) => None,
+    };
+
+    Some(Finding {
+        file: raw
+            .file
+            .map(|f| f.trim().to_string())
+            .filter(|f| !f.is_empty()),
+        line_range,
+        severity,
+        title,
+        body: raw.body.unwrap_or_default().trim().to_string(),
+        confidence: raw.confidence.map(|c| c.clamp(0.0, 1.0)),
+        // 수정안은 들여쓰기를 보존하고 끝 줄바꿈만 정리한다.
+        suggestion: raw
+            .suggestion
+            .map(|s| s.trim_end_matches(['\n', '\r']).to_string())
+            .filter(|s| !s.trim().is_empty()),
+    })
+}
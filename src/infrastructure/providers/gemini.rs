@@ -7,11 +7,13 @@ use serde_json::{Value, json};
 use url::Url;
 
 use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::domain::template::strip_cache_breakpoint;
 use crate::infrastructure::config::{Config, ProviderCommandSpec, resolve_provider_api_key};
+use crate::infrastructure::http::{HttpClients, HttpProfile};
 
 use super::{
     ReviewProvider, build_primary_prompt, command_available, run_provider_command,
-    api_runner::{build_api_client, collect_text, send_json},
+    api_runner::{collect_text, send_json},
 };
 
 struct CliBackend {
@@ -38,7 +40,7 @@ pub struct GeminiProvider {
 
 impl GeminiProvider {
     /// API key가 있으면 API 모드, 없으면 CLI 모드로 provider를 활성화한다.
-    pub fn from_config(config: &Config) -> Option<Self> {
+    pub fn from_config(config: &Config, http: &HttpClients) -> Option<Self> {
         let provider = config.providers.gemini.as_ref()?;
         if !provider.is_enabled() {
             return None;
@@ -46,7 +48,7 @@ impl GeminiProvider {
 
         if let Some(credential) = resolve_provider_api_key(provider).credential {
             let api = GeminiApiBackend {
-                client: build_api_client(),
+                client: http.client(HttpProfile::PROVIDER_API),
                 base_url: provider
                     .api_base
                     .clone()
@@ -92,11 +94,12 @@ impl GeminiProvider {
             api.base_url.trim_end_matches('/'),
             api.model
         );
+        // Gemini 2.5 모델은 반복되는 앞부분을 암묵적으로 캐시하므로 표시만 지운다.
         let payload = json!({
             "contents": [
                 {
                     "parts": [
-                        { "text": prompt }
+                        { "text": strip_cache_breakpoint(prompt) }
                     ]
                 }
             ]
@@ -142,6 +145,7 @@ impl GeminiProvider {
                     .pointer("/usageMetadata/totalTokenCount")
                     .and_then(Value::as_u64),
             },
+            findings: Vec::new(),
         })
     }
 }
@@ -164,7 +168,7 @@ impl ReviewProvider for GeminiProvider {
     }
 
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
-        let prompt = build_primary_prompt(request);
+        let prompt = build_primary_prompt(request)?;
         match &self.backend {
             GeminiBackend::Api(_) => self.review_via_api(&prompt).await,
             GeminiBackend::Cli(cli) => {
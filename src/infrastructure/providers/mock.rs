@@ -0,0 +1,144 @@
+//! 고정 응답 mock provider 어댑터(`providers.<이름>.kind = "mock"`).
+//!
+//! 외부 API/CLI를 호출하지 않고 `mock_response`(없으면 변경 파일을 짚는 기본 응답)를 돌려준다.
+//! 자격 증명이나 비용 없이 렌더링, 중복 방지, 게시까지 전체 흐름을 확인하는 용도이며
+//! `mock_delay_ms`와 `mock_failure_rate`로 느린 provider와 실패를 흉내 낸다.
+
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+use crate::domain::diff::split_diff_by_file;
+use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::infrastructure::clock::random_u64;
+use crate::infrastructure::config::ProviderConfig;
+
+use super::findings_parser::FINDINGS_FENCE;
+use super::{ReviewProvider, build_primary_prompt};
+
+// 토큰 사용량을 흉내 낼 때 쓰는 토큰당 글자 수.
+const CHARS_PER_TOKEN: usize = 4;
+
+pub struct MockProvider {
+    id: String,
+    response: Option<String>,
+    delay: Duration,
+    failure_rate: f64,
+}
+
+impl MockProvider {
+    /// 활성화된 mock provider를 만든다.
+    pub fn from_config(name: &str, provider: &ProviderConfig) -> Option<Self> {
+        if !provider.is_mock() || !provider.is_enabled() {
+            return None;
+        }
+        Some(Self {
+            id: name.to_string(),
+            response: provider
+                .mock_response
+                .clone()
+                .filter(|v| !v.trim().is_empty()),
+            delay: Duration::from_millis(provider.mock_delay_ms.unwrap_or(0)),
+            failure_rate: provider.mock_failure_rate.unwrap_or(0.0).clamp(0.0, 1.0),
+        })
+    }
+
+    async fn respond(&self, prompt: &str, default_content: String) -> Result<ProviderResponse> {
+        if !self.delay.is_zero() {
+            tokio::time::sleep(self.delay).await;
+        }
+        if self.failure_rate > 0.0 && random_unit() < self.failure_rate {
+            bail!(
+                "{} mock provider simulated a failure (providers.{}.mock_failure_rate)",
+                self.id,
+                self.id
+            );
+        }
+
+        let content = self.response.clone().unwrap_or(default_content);
+        let prompt_tokens = estimate_tokens(prompt);
+        let completion_tokens = estimate_tokens(&content);
+        Ok(ProviderResponse {
+            content,
+            usage: TokenUsage {
+                prompt_tokens: Some(prompt_tokens),
+                completion_tokens: Some(completion_tokens),
+                total_tokens: Some(prompt_tokens + completion_tokens),
+            },
+            findings: Vec::new(),
+        })
+    }
+}
+
+/// 요청 파일마다 지적사항 하나를 담은 기본 리뷰 응답.
+fn default_review(id: &str, request: &ReviewRequest) -> String {
+    let mut content = format!(
+        "## Mock review ({id})\n\nThis is a canned response from a `mock` provider for `{}`.\n",
+        request.head_sha
+    );
+    let files: Vec<String> = split_diff_by_file(&request.diff)
+        .into_iter()
+        .map(|file| file.path)
+        .collect();
+    if files.is_empty() {
+        content.push_str("\nNo changed files were sent.\n");
+        return content;
+    }
+    for file in &files {
+        content.push_str(&format!("\n- `{file}`: looks fine to the mock reviewer."));
+    }
+    let findings: Vec<serde_json::Value> = files
+        .iter()
+        .map(|file| {
+            serde_json::json!({
+                "file": file,
+                "line_start": 1,
+                "line_end": 1,
+                "severity": "suggestion",
+                "title": "Mock finding",
+                "body": format!("Canned finding from the `{id}` mock provider."),
+                "confidence": 0.5,
+            })
+        })
+        .collect();
+    content.push_str(&format!(
+        "\n\n{FINDINGS_FENCE}\n{}\n```\n",
+        serde_json::Value::Array(findings)
+    ));
+    content
+}
+
+fn estimate_tokens(text: &str) -> u64 {
+    text.chars().count().div_ceil(CHARS_PER_TOKEN) as u64
+}
+
+/// [0, 1) 범위의 난수. 실패 흉내에만 쓰므로 표준 라이브러리 해시 시드로 충분하다.
+fn random_unit() -> f64 {
+    (random_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[async_trait]
+impl ReviewProvider for MockProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
+        let prompt = build_primary_prompt(request)?;
+        self.respond(&prompt, default_review(&self.id, request))
+            .await
+    }
+
+    async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse> {
+        let content = format!(
+            "## Mock response ({})\n\nThis is a canned follow-up from a `mock` provider.\n",
+            self.id
+        );
+        self.respond(prompt, content).await
+    }
+}
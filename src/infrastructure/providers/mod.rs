@@ -1,11 +1,14 @@
 //! Provider 실행 공통 모듈.
-//! API 호출 또는 로컬 CLI(codex/claude/gemini)를 사용해 결과/사용량을 표준화한다.
+//! API 호출, 로컬 CLI(codex/claude/gemini), 외부 플러그인 프로세스 또는 mock 응답으로 결과/사용량을 표준화한다.
 
 pub mod anthropic;
 pub mod gemini;
+pub mod mock;
 pub mod openai;
+pub mod plugin;
 mod api_runner;
 mod command_runner;
+mod findings_parser;
 mod prompt;
 mod usage_parser;
 
@@ -14,35 +17,45 @@ use async_trait::async_trait;
 
 use crate::domain::review::{ProviderResponse, ReviewRequest};
 use crate::infrastructure::config::{Config, command_exists};
+use crate::infrastructure::http::HttpClients;
 
 pub use command_runner::run_provider_command;
+pub use findings_parser::attach_findings;
 pub use prompt::build_primary_prompt;
 
 #[async_trait]
 pub trait ReviewProvider: Send + Sync {
     /// 내부 식별자(마커/집계 키)
-    fn id(&self) -> &'static str;
+    fn id(&self) -> &str;
     /// 사용자 표시 이름
-    fn name(&self) -> &'static str;
+    fn name(&self) -> &str;
     /// 1차 리뷰 실행
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse>;
     /// 임의 프롬프트 실행(2차 상호 코멘트)
     async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse>;
 }
 
-pub fn build_providers(config: &Config) -> Vec<Box<dyn ReviewProvider>> {
+/// API 모드 provider는 `http`의 공유 클라이언트를 쓴다.
+pub fn build_providers(config: &Config, http: &HttpClients) -> Vec<Box<dyn ReviewProvider>> {
     // 각 provider가 API 또는 CLI 중 실행 가능한 백엔드를 선택해 활성화한다.
     let mut providers: Vec<Box<dyn ReviewProvider>> = Vec::new();
 
-    if let Some(provider) = openai::OpenAiProvider::from_config(config) {
+    if let Some(provider) = openai::OpenAiProvider::from_config(config, http) {
         providers.push(Box::new(provider));
     }
-    if let Some(provider) = anthropic::AnthropicProvider::from_config(config) {
+    if let Some(provider) = anthropic::AnthropicProvider::from_config(config, http) {
         providers.push(Box::new(provider));
     }
-    if let Some(provider) = gemini::GeminiProvider::from_config(config) {
+    if let Some(provider) = gemini::GeminiProvider::from_config(config, http) {
         providers.push(Box::new(provider));
     }
+    for (name, cfg) in config.providers.plugin_providers() {
+        if let Some(provider) = mock::MockProvider::from_config(name, cfg) {
+            providers.push(Box::new(provider));
+        } else if let Some(provider) = plugin::PluginProvider::from_config(name, cfg) {
+            providers.push(Box::new(provider));
+        }
+    }
 
     providers
 }
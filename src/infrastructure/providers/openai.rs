@@ -1,16 +1,20 @@
 //! OpenAI/Codex provider 어댑터.
 
+use std::hash::{DefaultHasher, Hash, Hasher};
+
 use anyhow::{Result, bail};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde_json::{Value, json};
 
 use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::domain::template::split_cache_breakpoint;
 use crate::infrastructure::config::{Config, ProviderCommandSpec, resolve_provider_api_key};
+use crate::infrastructure::http::{HttpClients, HttpProfile};
 
 use super::{
     ReviewProvider, build_primary_prompt, command_available, run_provider_command,
-    api_runner::{build_api_client, collect_text, send_json},
+    api_runner::{collect_text, send_json},
 };
 
 struct CliBackend {
@@ -29,6 +33,7 @@ struct OpenAiApiBackend {
     base_url: String,
     model: String,
     credential: String,
+    prompt_cache: bool,
 }
 
 pub struct OpenAiProvider {
@@ -37,7 +42,7 @@ pub struct OpenAiProvider {
 
 impl OpenAiProvider {
     /// API key가 있으면 API 모드, 없으면 CLI 모드로 provider를 활성화한다.
-    pub fn from_config(config: &Config) -> Option<Self> {
+    pub fn from_config(config: &Config, http: &HttpClients) -> Option<Self> {
         let provider = config.providers.openai.as_ref()?;
         if !provider.is_enabled() {
             return None;
@@ -45,7 +50,7 @@ impl OpenAiProvider {
 
         if let Some(credential) = resolve_provider_api_key(provider).credential {
             let api = OpenAiApiBackend {
-                client: build_api_client(),
+                client: http.client(HttpProfile::PROVIDER_API),
                 base_url: provider
                     .api_base
                     .clone()
@@ -55,6 +60,7 @@ impl OpenAiProvider {
                     .clone()
                     .unwrap_or_else(|| "gpt-4.1-mini".to_string()),
                 credential,
+                prompt_cache: provider.prompt_cache(),
             };
             return Some(Self {
                 backend: OpenAiBackend::Api(api),
@@ -91,12 +97,20 @@ impl OpenAiProvider {
             api.base_url.trim_end_matches('/'),
             "chat/completions"
         );
-        let payload = json!({
+        // OpenAI는 같은 앞부분(1024토큰 이상)을 자동으로 캐시한다. 캐시 표시 앞부분으로
+        // `prompt_cache_key`를 정하면 같은 앞부분을 반복하는 청크/라운드 호출이 같은 캐시로 모인다.
+        let (prefix, rest) = split_cache_breakpoint(prompt);
+        let mut payload = json!({
             "model": api.model,
             "messages": [
-                { "role": "user", "content": prompt }
+                { "role": "user", "content": format!("{prefix}{rest}") }
             ]
         });
+        if api.prompt_cache && !prefix.is_empty() {
+            let mut hasher = DefaultHasher::new();
+            prefix.hash(&mut hasher);
+            payload["prompt_cache_key"] = json!(format!("repopilot-{:016x}", hasher.finish()));
+        }
 
         let response = send_json(
             self.name(),
@@ -127,6 +141,7 @@ impl OpenAiProvider {
                     .pointer("/usage/total_tokens")
                     .and_then(Value::as_u64),
             },
+            findings: Vec::new(),
         })
     }
 }
@@ -159,7 +174,7 @@ impl ReviewProvider for OpenAiProvider {
     }
 
     async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
-        let prompt = build_primary_prompt(request);
+        let prompt = build_primary_prompt(request)?;
         match &self.backend {
             OpenAiBackend::Api(_) => self.review_via_api(&prompt).await,
             OpenAiBackend::Cli(cli) => {
@@ -0,0 +1,208 @@
+//! 외부 프로세스 플러그인 provider 어댑터(`providers.<이름>.kind = "plugin"`).
+//!
+//! 요청마다 `command`를 실행해 stdin으로 JSON 요청 하나를 보내고 stdout의 JSON 응답 하나를 읽는다.
+//! - 요청: `{"protocol_version": 1, "request_type": "review" | "prompt", "prompt": ..., "metadata": {...}}`
+//! - 응답: `{"content": ..., "usage": {"prompt_tokens", "completion_tokens", "total_tokens"}}`
+//!   또는 `{"error": ...}`
+
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use serde_json::{Value, json};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+
+use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::domain::template::strip_cache_breakpoint;
+use crate::infrastructure::child_process::{self, ChildGroup};
+use crate::infrastructure::config::{ProviderConfig, command_program};
+
+use super::{ReviewProvider, build_primary_prompt, command_available};
+
+const PROTOCOL_VERSION: u64 = 1;
+// 응답이 JSON이 아닐 때 오류 메시지에 넣을 stdout 앞부분 길이.
+const MAX_OUTPUT_PREVIEW_CHARS: usize = 200;
+
+pub struct PluginProvider {
+    id: String,
+    command: String,
+    args: Vec<String>,
+    model: Option<String>,
+    timeout: Duration,
+}
+
+impl PluginProvider {
+    /// 활성화되어 있고 `command`를 찾을 수 있으면 플러그인 provider를 만든다.
+    pub fn from_config(name: &str, provider: &ProviderConfig) -> Option<Self> {
+        if !provider.is_plugin() || !provider.is_enabled() {
+            return None;
+        }
+        let command = provider
+            .command
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())?;
+        if !command_available(command) {
+            return None;
+        }
+        Some(Self {
+            id: name.to_string(),
+            command: command.to_string(),
+            args: provider.args.clone().unwrap_or_default(),
+            model: provider.model.clone(),
+            timeout: provider.timeout(),
+        })
+    }
+
+    async fn call(
+        &self,
+        request_type: &str,
+        prompt: &str,
+        metadata: Value,
+    ) -> Result<ProviderResponse> {
+        let payload = json!({
+            "protocol_version": PROTOCOL_VERSION,
+            "request_type": request_type,
+            "prompt": strip_cache_breakpoint(prompt),
+            "metadata": metadata,
+        });
+
+        // 리뷰가 취소되거나 시간 초과로 future가 버려지면 자식 프로세스(그룹)도 함께 종료한다.
+        let mut command = Command::new(command_program(&self.command));
+        command
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        child_process::isolate(&mut command);
+        let mut child = command
+            .spawn()
+            .with_context(|| format!("failed to spawn {} plugin '{}'", self.id, self.command))?;
+        let _group = ChildGroup::track(&child);
+
+        let run = async move {
+            let mut stdin = child.stdin.take().context("failed to open plugin stdin")?;
+            stdin
+                .write_all(payload.to_string().as_bytes())
+                .await
+                .context("failed to write request to plugin stdin")?;
+            drop(stdin);
+
+            child
+                .wait_with_output()
+                .await
+                .context("plugin execution failed")
+        };
+        let output = tokio::time::timeout(self.timeout, run)
+            .await
+            .map_err(|_| {
+                anyhow!(
+                    "{} plugin timed out after {}s (providers.{}.timeout_secs)",
+                    self.id,
+                    self.timeout.as_secs(),
+                    self.id
+                )
+            })??;
+        let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+
+        if !output.status.success() {
+            bail!(
+                "{} plugin failed ({}): {}",
+                self.id,
+                output.status,
+                if stderr.is_empty() {
+                    "no stderr output"
+                } else {
+                    stderr.as_str()
+                }
+            );
+        }
+        parse_response(&self.id, &stdout)
+    }
+
+    fn base_metadata(&self) -> Value {
+        json!({
+            "provider": self.id,
+            "model": self.model,
+        })
+    }
+}
+
+fn parse_response(id: &str, stdout: &str) -> Result<ProviderResponse> {
+    let response: Value = serde_json::from_str(stdout).with_context(|| {
+        let preview: String = stdout.chars().take(MAX_OUTPUT_PREVIEW_CHARS).collect();
+        format!("{id} plugin returned invalid JSON: {preview}")
+    })?;
+    if let Some(error) = response.get("error").filter(|v| !v.is_null()) {
+        let message = error
+            .as_str()
+            .map(ToString::to_string)
+            .unwrap_or_else(|| error.to_string());
+        bail!("{id} plugin reported an error: {message}");
+    }
+
+    let content = response
+        .get("content")
+        .and_then(Value::as_str)
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    if content.is_empty() {
+        bail!("{id} plugin returned empty content");
+    }
+
+    let usage_value = |key: &str| {
+        response
+            .pointer(&format!("/usage/{key}"))
+            .and_then(Value::as_u64)
+    };
+    let prompt_tokens = usage_value("prompt_tokens");
+    let completion_tokens = usage_value("completion_tokens");
+    let total_tokens = usage_value("total_tokens").or(match (prompt_tokens, completion_tokens) {
+        (Some(p), Some(c)) => Some(p + c),
+        _ => None,
+    });
+
+    Ok(ProviderResponse {
+        content,
+        usage: TokenUsage {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens,
+        },
+        findings: Vec::new(),
+    })
+}
+
+#[async_trait]
+impl ReviewProvider for PluginProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.id
+    }
+
+    async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
+        let prompt = build_primary_prompt(request)?;
+        let mut metadata = self.base_metadata();
+        metadata["target_url"] = json!(request.target_url);
+        metadata["head_sha"] = json!(request.head_sha);
+        metadata["title"] = json!(request.title);
+        metadata["comment_language"] = json!(request.comment_language.code());
+        metadata["granularity"] = json!(request.granularity.code());
+        metadata["chunk_index"] = json!(request.chunk_index);
+        metadata["chunk_total"] = json!(request.chunk_total);
+        metadata["files"] = json!(request.chunk_files);
+        self.call("review", &prompt, metadata).await
+    }
+
+    async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse> {
+        self.call("prompt", prompt, self.base_metadata()).await
+    }
+}
@@ -1,21 +1,58 @@
 //! Provider 공통 프롬프트 구성.
 
-use crate::domain::review::ReviewRequest;
+use anyhow::Result;
 
-/// 1차 리뷰용 시스템+사용자 통합 프롬프트를 생성한다.
-pub fn build_primary_prompt(request: &ReviewRequest) -> String {
-    format!(
-        "System instructions:\n{}\n\nOutput language requirement:\n{}\n\n{}",
-        request.system_prompt,
-        request.comment_language.prompt_instruction(),
-        build_user_prompt(request)
-    )
+use crate::domain::policy::request_template_vars;
+use crate::domain::review::{ReviewGranularity, ReviewRequest, SeverityTaxonomy};
+use crate::infrastructure::template::render_template;
+
+use super::findings_parser::FINDINGS_FENCE;
+
+/// 기계 판독용 지적사항 JSON 출력 계약.
+const FINDINGS_CONTRACT: &str = "After the Markdown review, append exactly one machine-readable findings block in this format (use [] when there are no findings):\n";
+
+/// 1차 리뷰용 시스템+사용자 통합 프롬프트를 `request.prompt_templates.primary`로 생성한다.
+pub fn build_primary_prompt(request: &ReviewRequest) -> Result<String> {
+    let mut vars = request_template_vars(request);
+    vars.insert("system_prompt", request.system_prompt.as_str().into());
+    vars.insert("guide", request.review_guide.as_str().into());
+    vars.insert(
+        "findings_contract",
+        findings_contract(&request.severity_taxonomy).into(),
+    );
+    vars.insert("scope", review_scope(request).into());
+    vars.insert("files", request.chunk_files.join(", ").into());
+    vars.insert("chunk_index", request.chunk_index.to_string().into());
+    vars.insert("chunk_total", request.chunk_total.to_string().into());
+    vars.insert("diff", (*request.diff).into());
+    render_template(&request.prompt_templates.primary, &vars)
 }
 
-/// 1차 리뷰용 사용자 프롬프트를 생성한다.
-pub fn build_user_prompt(request: &ReviewRequest) -> String {
+/// 지적사항 JSON 블록 형식 지시문을 생성한다.
+pub fn findings_contract(taxonomy: &SeverityTaxonomy) -> String {
     format!(
-        "Target URL: {}\nHead SHA: {}\n\nReview the diff and report key issues in concise Markdown.\nUse sections in this order: Critical, Major, Minor, Suggestions.\n\n```diff\n{}\n```",
-        request.target_url, request.head_sha, request.diff
+        "{FINDINGS_CONTRACT}{FINDINGS_FENCE}\n[{{\"file\": \"path/to/file\", \"line_start\": 10, \"line_end\": 12, \"severity\": \"{}\", \"title\": \"short title\", \"body\": \"explanation and fix\", \"confidence\": 0.8, \"suggestion\": \"replacement code\"}}]\n```\nLine numbers refer to the new version of the file. confidence is your certainty between 0.0 and 1.0; use a low value when the issue depends on code outside the diff or you could not verify it. Add suggestion only when you can give the exact code that replaces lines line_start..line_end (keep the original indentation, no fences); omit it otherwise.",
+        taxonomy.contract_values()
     )
 }
+
+/// 분할/파일 단위 리뷰일 때 이번 요청의 리뷰 범위 안내문(전체 리뷰면 빈 문자열).
+fn review_scope(request: &ReviewRequest) -> String {
+    if request.granularity == ReviewGranularity::File {
+        format!(
+            "Review only the changes to `{}` (file {} of {} in this change set).\n",
+            request.chunk_files.join(", "),
+            request.chunk_index,
+            request.chunk_total
+        )
+    } else if request.chunk_total > 1 {
+        format!(
+            "This is part {} of {} of a large diff (files: {}). Review only this part.\n",
+            request.chunk_index,
+            request.chunk_total,
+            request.chunk_files.join(", ")
+        )
+    } else {
+        String::new()
+    }
+}
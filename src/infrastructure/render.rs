@@ -1,69 +1,733 @@
 //! VCS 코멘트용 Markdown 렌더링 모듈.
 
-use crate::domain::review::{AgentComment, AgentReaction};
+use std::borrow::Cow;
+
+use anyhow::Result;
+
+use crate::domain::diff::DiffStats;
+use crate::domain::markdown::sanitize_provider_markdown;
+use crate::domain::marker::{
+    agent_marker, claim_marker, file_marker, final_marker, issue_link_marker, suggestion_marker,
+};
+use crate::domain::policy::finding_id;
+use crate::domain::review::{
+    AgentComment, ConsensusFinding, FileThread, Finding, FindingProgress, PreviousFinding,
+    ReviewStage, ReviewSummary, RunFooter, Severity, SeverityTaxonomy, StageReview,
+    SuggestedReviewer,
+};
+use crate::domain::target::ReviewTarget;
+use crate::domain::template::{TemplateItem, TemplateLists, TemplateVars};
+use crate::infrastructure::logging::civil_from_days;
+use crate::infrastructure::template::render_template_with_lists;
 
 /// 리뷰 시작 상태를 나타내는 claim 코멘트 본문을 생성한다.
-pub fn render_claim_markdown(sha: &str, target_url: &str) -> String {
+/// 변수: `target_url`, `head_sha`, `claimed_at`
+pub fn render_claim_markdown(
+    sha: &str,
+    target_url: &str,
+    claimed_at: u64,
+    nonce: &str,
+    template: &str,
+) -> Result<String> {
+    let vars = TemplateVars::from([
+        ("target_url", Cow::Borrowed(target_url)),
+        ("head_sha", Cow::Borrowed(sha)),
+        ("claimed_at", Cow::Owned(claimed_at.to_string())),
+    ]);
+    with_marker(
+        &claim_marker(sha, claimed_at, nonce),
+        template,
+        &vars,
+        &TemplateLists::new(),
+    )
+}
+
+/// 동시에 claim한 다른 실행기에 밀려 철회한 claim 코멘트 본문. 마커가 없으므로 중복 판단에서 빠진다.
+pub fn render_claim_withdrawn_markdown(sha: &str, target_url: &str) -> String {
     format!(
-        "<!-- repopilot-bot claim sha={sha} -->\n\n# Multi-Agent Code Review\n\n- Target: {target_url}\n- Head SHA: `{sha}`\n\nReview in progress..."
+        "# Multi-Agent Code Review\n\n- Target: {target_url}\n- Head SHA: `{sha}`\n\nAnother runner is already reviewing this head SHA; this duplicate claim was withdrawn."
+    )
+}
+
+/// 리뷰할 텍스트 변경이 없어 provider를 실행하지 않은 실행의 최종 요약 본문.
+/// 최종 요약 마커를 붙여 같은 SHA를 다시 리뷰하지 않게 한다.
+pub fn render_unreviewable_summary_markdown(sha: &str, target_url: &str, reason: &str) -> String {
+    format!(
+        "{}\n\n# Multi-Agent Review Summary\n\n- Target: {target_url}\n- Head SHA: `{sha}`\n\nNo reviewable text changes: {reason}. Providers were not run.\n",
+        final_marker(sha)
     )
 }
 
 /// 에이전트별 개별 코멘트 본문을 생성한다.
-pub fn render_agent_markdown(sha: &str, target_url: &str, agent: &AgentComment) -> String {
+/// 변수: `target_url`, `head_sha`, `agent_id`, `agent_name`, `body`(정리된 provider 출력, 제목은 2단계부터)
+pub fn render_agent_markdown(
+    sha: &str,
+    target_url: &str,
+    agent: &AgentComment,
+    template: &str,
+) -> Result<String> {
+    let body = sanitize_provider_markdown(agent.body.trim(), 2);
+    let vars = TemplateVars::from([
+        ("target_url", Cow::Borrowed(target_url)),
+        ("head_sha", Cow::Borrowed(sha)),
+        ("agent_id", Cow::Borrowed(agent.provider_id.as_str())),
+        ("agent_name", Cow::Borrowed(agent.provider_name.as_str())),
+        ("body", Cow::Borrowed(body.as_str())),
+    ]);
+    Ok(with_footer(
+        with_marker(
+            &agent_marker(&agent.provider_id, sha),
+            template,
+            &vars,
+            &TemplateLists::new(),
+        )?,
+        agent.footer.as_ref(),
+    ))
+}
+
+/// 파일 단위 리뷰 스레드 본문을 생성한다.
+pub fn render_file_thread_markdown(sha: &str, thread: &FileThread) -> String {
+    let mut out = String::new();
+    out.push_str(&file_marker(&thread.path, sha));
+    out.push_str("\n\n");
+    out.push_str(&format!("# File Review: `{}`\n\n", thread.path));
+    for (agent_name, body) in &thread.sections {
+        out.push_str(&format!("## {}\n\n", agent_name));
+        out.push_str(&sanitize_provider_markdown(body.trim(), 3));
+        out.push_str("\n\n");
+    }
+    out.trim_end().to_string() + "\n"
+}
+
+/// 수정안이 있는 지적사항의 인라인 코멘트 본문. 한 번에 적용할 수 있도록 플랫폼 문법의 제안 블록을 붙인다.
+/// - GitHub: 코멘트가 지적 구간 전체에 걸리므로 ` ```suggestion `
+/// - GitLab: 코멘트가 구간 마지막 줄에 걸리므로 위쪽 줄 수를 지정한 ` ```suggestion:-N+0 `
+pub fn render_suggestion_markdown(
+    target_url: &str,
+    finding: &Finding,
+    taxonomy: &SeverityTaxonomy,
+) -> String {
+    let id = finding_id(finding);
     let mut out = String::new();
+    if let Some(range) = finding.line_range {
+        out.push_str(&suggestion_marker(&id, range));
+        out.push('\n');
+    }
     out.push_str(&format!(
-        "<!-- repopilot-bot agent={} sha={} -->\n\n",
-        agent.provider_id, sha
+        "**[{}]** {} <sub>id `{}`</sub>\n\n",
+        taxonomy.label(finding.severity),
+        finding.title,
+        id
     ));
-    out.push_str(&format!("# Agent Review: {}\n\n", agent.provider_name));
-    out.push_str(&format!("- Target: {}\n", target_url));
-    out.push_str(&format!("- Head SHA: `{}`\n", sha));
-    out.push('\n');
-    out.push_str(agent.body.trim());
-    out.push('\n');
+    let body = finding.body.trim();
+    if !body.is_empty() {
+        out.push_str(&sanitize_provider_markdown(body, 3));
+        out.push_str("\n\n");
+    }
+    let Some(suggestion) = &finding.suggestion else {
+        return out.trim_end().to_string() + "\n";
+    };
+    let lines_above = finding
+        .line_range
+        .map(|range| range.end - range.start)
+        .unwrap_or(0);
+    let info = match ReviewTarget::parse(target_url) {
+        Ok(ReviewTarget::GitLab { .. }) => format!("suggestion:-{lines_above}+0"),
+        _ => "suggestion".to_string(),
+    };
+    // 수정안 안의 백틱 펜스보다 길게 열어야 블록이 중간에 닫히지 않는다.
+    let longest = suggestion
+        .lines()
+        .map(|line| line.trim_start().chars().take_while(|c| *c == '`').count())
+        .max()
+        .unwrap_or(0);
+    let fence = "`".repeat(longest.max(2) + 1);
+    out.push_str(&format!("{fence}{info}\n{suggestion}\n{fence}\n"));
     out
 }
 
-/// 최종 요약 코멘트(상호 코멘트)를 생성한다.
+/// 최종 요약 코멘트(통합 리뷰 + 합의 지적사항 + 상호 코멘트)를 생성한다.
+/// provider가 쓴 통합 리뷰/교차 반응 본문은 들어갈 섹션 제목 아래 단계로 정리해서 넣는다.
+/// 확신도가 `low_confidence_below`보다 낮은 합의 지적사항은 `consensus` 대신 `needs_verification`에 들어간다.
+/// 변수: `target_url`, `head_sha`, `diff_stats`, `severity_counts`, `stats`, `moderator`, `consolidated`, `consensus`,
+/// `needs_verification`, `progress`, `follow_up_issues`, `suggested_reviewers`, `security_reviewer`, `security`, `test_gap_reviewer`, `missing_tests`,
+/// `meta_reviewer`, `meta_review`, `consensus_count`,
+/// `agent_comments`, `reactions`(목록/섹션/표는 Markdown으로 렌더링된 값)
 pub fn render_final_summary_markdown(
     sha: &str,
     target_url: &str,
-    reactions: &[AgentReaction],
-    agent_comment_refs: &[(String, String)],
-) -> String {
-    let mut out = String::new();
-    out.push_str(&format!("<!-- repopilot-bot sha={sha} -->\n\n"));
-    out.push_str("# Multi-Agent Review Summary\n\n");
-    out.push_str(&format!("- Target: {target_url}\n"));
-    out.push_str(&format!("- Head SHA: `{sha}`\n\n"));
-
-    out.push_str("## Individual Agent Comments\n\n");
-    if agent_comment_refs.is_empty() {
-        out.push_str("- No individual agent comments were posted.\n\n");
-    } else {
-        for (name, id) in agent_comment_refs {
-            out.push_str(&format!("- {}: comment id `{}`\n", name, id));
+    summary: &ReviewSummary,
+    template: &str,
+) -> Result<String> {
+    let reactions = &summary.reactions;
+    let agent_comment_refs = &summary.agent_comment_refs;
+
+    let taxonomy = &summary.severity_taxonomy;
+    let target = ReviewTarget::parse(target_url).ok();
+    let consensus_severities: Vec<Severity> = summary
+        .consensus
+        .iter()
+        .map(|item| item.finding.severity)
+        .collect();
+    let severity_counts = taxonomy
+        .count_summary(&consensus_severities)
+        .unwrap_or_default();
+    let (uncertain, confident): (Vec<&ConsensusFinding>, Vec<&ConsensusFinding>) =
+        summary.consensus.iter().partition(|item| {
+            item.finding
+                .is_below_confidence(summary.low_confidence_below)
+        });
+    let mut consensus: String = confident
+        .iter()
+        .map(|item| render_consensus_line(item, taxonomy, sha, target.as_ref(), false))
+        .collect();
+    // 목록이 길면 심각도별 개수만 보이게 접는다.
+    if summary.collapse.collapses_findings(confident.len()) {
+        let severities: Vec<Severity> =
+            confident.iter().map(|item| item.finding.severity).collect();
+        let mut label = format!("{} findings", confident.len());
+        if let Some(counts) = taxonomy.count_summary(&severities) {
+            label.push_str(&format!(" ({counts})"));
         }
-        out.push('\n');
+        consensus = render_details(&label, consensus.trim_end());
     }
+    let needs_verification: String = uncertain
+        .iter()
+        .map(|item| render_consensus_line(item, taxonomy, sha, target.as_ref(), true))
+        .collect();
+
+    let agent_comments = if agent_comment_refs.is_empty() {
+        "- No individual agent comments were posted.".to_string()
+    } else {
+        agent_comment_refs
+            .iter()
+            .map(|(name, id)| format!("- {}: comment id `{}`", name, id))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
 
-    out.push_str("## Agent-to-Agent Reactions\n\n");
+    let mut reaction_sections = String::new();
     if reactions.is_empty() {
-        out.push_str("- Not enough agents to run cross-agent reactions.\n\n");
+        reaction_sections
+            .push_str("- Cross-agent reactions were not run (single agent or reaction_rounds=0).");
     } else {
-        for reaction in reactions {
-            out.push_str("---\n\n");
-            out.push_str(&format!("### {} on Other Agents\n\n", reaction.provider_name));
-            out.push_str(reaction.body.trim());
-            out.push_str("\n\n");
+        // 라운드가 여러 개면 라운드별 섹션으로 구분한다.
+        let last_round = reactions.iter().map(|r| r.round).max().unwrap_or(1);
+        for round in 1..=last_round {
+            if last_round > 1 {
+                reaction_sections.push_str(&format!("### Round {round}\n\n"));
+            }
+            // 반응 제목(`###`, 라운드가 여러 개면 `####`) 아래에 들어가도록 본문 제목을 내린다.
+            let min_heading = if last_round > 1 { 5 } else { 4 };
+            for reaction in reactions.iter().filter(|r| r.round == round) {
+                let body = sanitize_provider_markdown(reaction.body.trim(), min_heading);
+                reaction_sections.push_str("---\n\n");
+                // 긴 반응은 접고, 제목 줄에 그 에이전트의 1차 리뷰 심각도별 개수를 붙인다.
+                if summary.collapse.collapses_reaction(reaction.body.trim()) {
+                    let mut label = format!("{} on Other Agents", reaction.provider_name);
+                    if let Some(counts) = summary
+                        .agent_severities
+                        .iter()
+                        .find(|agent| agent.provider_id == reaction.provider_id)
+                        .and_then(|agent| taxonomy.count_summary(&agent.severities))
+                    {
+                        label.push_str(&format!(" ({counts})"));
+                    }
+                    reaction_sections.push_str(&render_details(&label, &body));
+                    reaction_sections.push_str("\n\n");
+                    continue;
+                }
+                if last_round > 1 {
+                    reaction_sections.push_str(&format!(
+                        "#### {} on Other Agents\n\n",
+                        reaction.provider_name
+                    ));
+                } else {
+                    reaction_sections.push_str(&format!(
+                        "### {} on Other Agents\n\n",
+                        reaction.provider_name
+                    ));
+                }
+                reaction_sections.push_str(&body);
+                reaction_sections.push_str("\n\n");
+            }
+        }
+    }
+
+    // moderator 통합 리뷰가 있으면 기본 템플릿은 요약 본문의 최상단에 둔다.
+    let (moderator, consolidated) = match &summary.consolidated {
+        Some(review) => (
+            review.provider_name.as_str(),
+            sanitize_provider_markdown(review.body.trim(), 3),
+        ),
+        None => ("", String::new()),
+    };
+    let (security_reviewer, security) = match summary
+        .stage_reviews
+        .iter()
+        .find(|review| review.stage == ReviewStage::Security)
+    {
+        Some(review) => (
+            review.provider_name.as_str(),
+            render_security_section(review, summary, sha, target.as_ref()),
+        ),
+        None => ("", String::new()),
+    };
+    let (test_gap_reviewer, missing_tests) = match summary
+        .stage_reviews
+        .iter()
+        .find(|review| review.stage == ReviewStage::TestGaps)
+    {
+        Some(review) if review.body.trim().is_empty() => (
+            review.provider_name.as_str(),
+            "_No missing tests reported._".to_string(),
+        ),
+        Some(review) => (
+            review.provider_name.as_str(),
+            sanitize_provider_markdown(review.body.trim(), 3),
+        ),
+        None => ("", String::new()),
+    };
+    let (meta_reviewer, meta_review) = match summary
+        .stage_reviews
+        .iter()
+        .find(|review| review.stage == ReviewStage::Meta)
+    {
+        Some(review) => (
+            review.provider_name.as_str(),
+            sanitize_provider_markdown(review.body.trim(), 3),
+        ),
+        None => ("", String::new()),
+    };
+    // 연결 주석은 다음 실행이 같은 지적사항의 이슈를 다시 만들지 않게 한다.
+    let follow_up_issues: String = summary
+        .follow_up_issues
+        .iter()
+        .map(|issue| {
+            format!(
+                "- `{}` [{}]({}) {}\n",
+                issue.finding_id,
+                issue.title,
+                issue.url,
+                issue_link_marker(&issue.finding_id, &issue.url)
+            )
+        })
+        .collect();
+    let vars = TemplateVars::from([
+        ("target_url", Cow::Borrowed(target_url)),
+        ("head_sha", Cow::Borrowed(sha)),
+        (
+            "diff_stats",
+            Cow::Owned(
+                summary
+                    .diff_stats
+                    .as_ref()
+                    .map(DiffStats::summary)
+                    .unwrap_or_default(),
+            ),
+        ),
+        ("severity_counts", Cow::Owned(severity_counts)),
+        ("stats", Cow::Owned(render_stats_header(summary))),
+        ("moderator", Cow::Borrowed(moderator)),
+        ("consolidated", Cow::Owned(consolidated)),
+        ("consensus", Cow::Borrowed(consensus.trim_end())),
+        (
+            "needs_verification",
+            Cow::Borrowed(needs_verification.trim_end()),
+        ),
+        (
+            "consensus_count",
+            Cow::Owned(summary.consensus.len().to_string()),
+        ),
+        (
+            "progress",
+            Cow::Owned(
+                summary
+                    .progress
+                    .as_ref()
+                    .map(|progress| render_progress(progress, taxonomy, sha, target.as_ref()))
+                    .unwrap_or_default(),
+            ),
+        ),
+        (
+            "follow_up_issues",
+            Cow::Borrowed(follow_up_issues.trim_end()),
+        ),
+        (
+            "suggested_reviewers",
+            Cow::Owned(render_suggested_reviewers(&summary.suggested_reviewers)),
+        ),
+        ("security_reviewer", Cow::Borrowed(security_reviewer)),
+        ("security", Cow::Owned(security)),
+        ("test_gap_reviewer", Cow::Borrowed(test_gap_reviewer)),
+        ("missing_tests", Cow::Owned(missing_tests)),
+        ("meta_reviewer", Cow::Borrowed(meta_reviewer)),
+        ("meta_review", Cow::Owned(meta_review)),
+        ("agent_comments", Cow::Owned(agent_comments)),
+        ("reactions", Cow::Borrowed(reaction_sections.trim_end())),
+    ]);
+    let lists = TemplateLists::from([
+        (
+            "findings",
+            summary
+                .consensus
+                .iter()
+                .map(|item| finding_item(item, summary, sha, target.as_ref()))
+                .collect(),
+        ),
+        (
+            "agents",
+            agent_comment_refs
+                .iter()
+                .map(|(name, id)| {
+                    TemplateItem::from([("name", name.clone()), ("comment_id", id.clone())])
+                })
+                .collect(),
+        ),
+    ]);
+    Ok(with_footer(
+        with_marker(&final_marker(sha), template, &vars, &lists)?,
+        summary.footer.as_ref(),
+    ))
+}
+
+/// 템플릿 `findings` 목록의 항목 하나. 필드는 [`crate::domain::template::FINDING_ITEM_FIELDS`]와 같다.
+fn finding_item(
+    item: &ConsensusFinding,
+    summary: &ReviewSummary,
+    sha: &str,
+    target: Option<&ReviewTarget>,
+) -> TemplateItem {
+    let finding = &item.finding;
+    let url = target
+        .zip(finding.file.as_deref())
+        .and_then(|(target, path)| target.blob_url(sha, path, finding.line_range));
+    let needs_verification = finding.is_below_confidence(summary.low_confidence_below);
+    TemplateItem::from([
+        ("id", finding_id(finding)),
+        (
+            "severity",
+            summary.severity_taxonomy.label(finding.severity),
+        ),
+        ("title", finding.title.clone()),
+        ("body", sanitize_provider_markdown(finding.body.trim(), 3)),
+        ("location", finding.location().unwrap_or_default()),
+        ("url", url.unwrap_or_default()),
+        (
+            "confidence",
+            finding
+                .confidence
+                .map(|confidence| format!("{confidence:.2}"))
+                .unwrap_or_default(),
+        ),
+        ("agents", item.agents.join(", ")),
+        (
+            "needs_verification",
+            if needs_verification { "true" } else { "" }.to_string(),
+        ),
+    ])
+}
+
+/// 이전 리뷰 대비 진행 상황. 비어 있는 하위 섹션은 뺀다.
+fn render_progress(
+    progress: &FindingProgress,
+    taxonomy: &SeverityTaxonomy,
+    sha: &str,
+    target: Option<&ReviewTarget>,
+) -> String {
+    let mut out = format!(
+        "Compared with the review of `{}`: {} resolved, {} still open, {} new.\n",
+        progress.previous_sha,
+        progress.resolved.len(),
+        progress.still_open.len(),
+        progress.new.len()
+    );
+    if !progress.resolved.is_empty() {
+        out.push_str("\n### Resolved\n\n");
+        for finding in &progress.resolved {
+            out.push_str(&render_resolved_line(finding, taxonomy));
+        }
+    }
+    for (heading, findings) in [("Still Open", &progress.still_open), ("New", &progress.new)] {
+        if findings.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("\n### {heading}\n\n"));
+        for finding in findings {
+            out.push_str(&render_progress_line(finding, taxonomy, sha, target));
         }
     }
+    out.trim_end().to_string()
+}
+
+fn render_resolved_line(finding: &PreviousFinding, taxonomy: &SeverityTaxonomy) -> String {
+    let location = finding
+        .file
+        .as_deref()
+        .map(|file| format!(" `{file}`"))
+        .unwrap_or_default();
+    format!(
+        "- ~~**[{}]**{} {}~~ <sub>id `{}`</sub>\n",
+        taxonomy.label(finding.severity),
+        location,
+        finding.title,
+        finding.id
+    )
+}
 
+fn render_progress_line(
+    finding: &Finding,
+    taxonomy: &SeverityTaxonomy,
+    sha: &str,
+    target: Option<&ReviewTarget>,
+) -> String {
+    let link = target
+        .zip(finding.file.as_deref())
+        .and_then(|(target, path)| target.blob_url(sha, path, finding.line_range));
+    let location = finding
+        .location()
+        .map(|loc| match &link {
+            Some(url) => format!(" [`{loc}`]({url})"),
+            None => format!(" `{loc}`"),
+        })
+        .unwrap_or_default();
+    format!(
+        "- **[{}]**{} {} <sub>id `{}`</sub>\n",
+        taxonomy.label(finding.severity),
+        location,
+        finding.title,
+        finding_id(finding)
+    )
+}
+
+/// 추천 리뷰어 목록. 요약을 다시 게시할 때마다 알림이 가지 않도록 이름은 코드 표기로 쓴다.
+fn render_suggested_reviewers(reviewers: &[SuggestedReviewer]) -> String {
+    fn paths(paths: &[String]) -> String {
+        const SHOWN: usize = 3;
+        let mut out = paths
+            .iter()
+            .take(SHOWN)
+            .map(|path| format!("`{path}`"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        if paths.len() > SHOWN {
+            out.push_str(&format!(" and {} more", paths.len() - SHOWN));
+        }
+        out
+    }
+
+    let mut out = String::new();
+    for reviewer in reviewers {
+        let mut reasons = Vec::new();
+        if !reviewer.owned_paths.is_empty() {
+            reasons.push(format!("code owner of {}", paths(&reviewer.owned_paths)));
+        }
+        if !reviewer.recent_paths.is_empty() {
+            reasons.push(format!(
+                "recently changed {}",
+                paths(&reviewer.recent_paths)
+            ));
+        }
+        let requested = if reviewer.requested {
+            " (review requested)"
+        } else {
+            ""
+        };
+        out.push_str(&format!(
+            "- `{}`{requested}: {}\n",
+            reviewer.handle,
+            reasons.join("; ")
+        ));
+    }
+    out.trim_end().to_string()
+}
+
+/// 보안 점검 섹션 본문. 구조화 지적사항을 심각한 순서로 심각도와 함께 나열하고,
+/// 출력 계약 블록이 없어 지적사항을 파싱하지 못했으면 provider 본문을 그대로 넣는다.
+fn render_security_section(
+    review: &StageReview,
+    summary: &ReviewSummary,
+    sha: &str,
+    target: Option<&ReviewTarget>,
+) -> String {
+    let taxonomy = &summary.severity_taxonomy;
+    if review.findings.is_empty() {
+        let body = review.body.trim();
+        return if body.is_empty() {
+            "_No security issues reported._".to_string()
+        } else {
+            sanitize_provider_markdown(body, 3)
+        };
+    }
+
+    let mut findings: Vec<&Finding> = review.findings.iter().collect();
+    findings.sort_by_key(|f| f.severity);
+    let mut out = String::new();
+    for finding in &findings {
+        let link = target
+            .zip(finding.file.as_deref())
+            .and_then(|(target, path)| target.blob_url(sha, path, finding.line_range));
+        let location = finding
+            .location()
+            .map(|loc| match &link {
+                Some(url) => format!(" [`{loc}`]({url})"),
+                None => format!(" `{loc}`"),
+            })
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "- **[{}]**{} {}\n",
+            taxonomy.label(finding.severity),
+            location,
+            finding.title
+        ));
+        for line in sanitize_provider_markdown(finding.body.trim(), 4).lines() {
+            if line.trim().is_empty() {
+                out.push('\n');
+            } else {
+                out.push_str(&format!("  {line}\n"));
+            }
+        }
+    }
+    let out = out.trim_end().to_string();
+    if !summary.collapse.collapses_findings(findings.len()) {
+        return out;
+    }
+    let severities: Vec<Severity> = findings.iter().map(|f| f.severity).collect();
+    let mut label = format!("{} security findings", findings.len());
+    if let Some(counts) = taxonomy.count_summary(&severities) {
+        label.push_str(&format!(" ({counts})"));
+    }
+    render_details(&label, &out)
+}
+
+/// 요약 맨 위의 심각도 배지 줄과 심각도별 개수 표(합의 지적사항 + 에이전트별 1차 리뷰).
+/// 구조화 지적사항이 하나도 없으면 빈 문자열.
+fn render_stats_header(summary: &ReviewSummary) -> String {
+    let taxonomy = &summary.severity_taxonomy;
+    let agents = &summary.agent_severities;
+    if summary.consensus.is_empty() && agents.iter().all(|agent| agent.severities.is_empty()) {
+        return String::new();
+    }
+
+    let consensus: Vec<Severity> = summary
+        .consensus
+        .iter()
+        .map(|item| item.finding.severity)
+        .collect();
+    let badge = taxonomy
+        .count_summary(&consensus)
+        .unwrap_or_else(|| "no consensus findings".to_string());
+
+    let mut out = format!("**Findings:** {badge}\n\n| Severity | Consensus |");
+    for agent in agents {
+        out.push_str(&format!(" {} |", agent.provider_name.replace('|', "\\|")));
+    }
+    out.push_str("\n| --- | ---: |");
+    out.push_str(&" ---: |".repeat(agents.len()));
+    for severity in [
+        Severity::Critical,
+        Severity::Major,
+        Severity::Minor,
+        Severity::Suggestion,
+    ] {
+        let count = |severities: &[Severity]| severities.iter().filter(|s| **s == severity).count();
+        out.push_str(&format!(
+            "\n| {} | {} |",
+            taxonomy.label(severity),
+            count(&consensus)
+        ));
+        for agent in agents {
+            out.push_str(&format!(" {} |", count(&agent.severities)));
+        }
+    }
     out
 }
 
-/// 동일 SHA/에이전트 코멘트를 식별하기 위한 마커 문자열을 만든다.
-pub fn agent_marker(provider_id: &str, sha: &str) -> String {
-    format!("<!-- repopilot-bot agent={} sha={} -->", provider_id, sha)
+/// GitHub/GitLab에서 접어서 보이는 `<details>` 블록. 본문 Markdown이 렌더링되도록 빈 줄로 감싼다.
+fn render_details(summary: &str, body: &str) -> String {
+    format!("<details>\n<summary>{summary}</summary>\n\n{body}\n\n</details>")
+}
+
+/// 중복 판단용 마커를 맨 앞에 두고 그 아래에 템플릿 본문을 붙인다.
+fn with_marker(
+    marker: &str,
+    template: &str,
+    vars: &TemplateVars<'_>,
+    lists: &TemplateLists<'_>,
+) -> Result<String> {
+    Ok(format!(
+        "{marker}\n\n{}",
+        render_template_with_lists(template, vars, lists)?
+    ))
+}
+
+/// 템플릿과 관계없이 본문 맨 끝에 실행 정보 footer 한 줄을 붙인다.
+fn with_footer(markdown: String, footer: Option<&RunFooter>) -> String {
+    match footer {
+        Some(footer) => format!("{}\n\n{}\n", markdown.trim_end(), render_run_footer(footer)),
+        None => markdown,
+    }
+}
+
+/// 실행 정보 footer(예: `<sub>repopilot v1.2.0 · OpenAI `gpt-4o` · 42.3s · 2024-01-02 03:04 UTC</sub>`).
+fn render_run_footer(footer: &RunFooter) -> String {
+    let mut fields = vec![format!("{}{}", RunFooter::LINE_PREFIX, footer.version)];
+    if !footer.models.is_empty() {
+        let models: Vec<String> = footer
+            .models
+            .iter()
+            .map(|(name, model)| match model {
+                Some(model) => format!("{name} `{model}`"),
+                None => format!("{name} (default model)"),
+            })
+            .collect();
+        fields.push(models.join(", "));
+    }
+    if let Some(secs) = footer.elapsed_secs {
+        fields.push(format!("{secs:.1}s"));
+    }
+    let (days, rem) = (footer.posted_at / 86_400, footer.posted_at % 86_400);
+    let (year, month, day) = civil_from_days(days as i64);
+    fields.push(format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02} UTC",
+        rem / 3600,
+        rem % 3600 / 60
+    ));
+    format!("{}</sub>", fields.join(" · "))
+}
+
+/// 합의 지적사항 한 줄. 대상이 GitHub/GitLab이면 위치를 해당 커밋의 파일/라인 링크로 만든다.
+/// `show_confidence`면 보고된 확신도를 함께 표시한다.
+fn render_consensus_line(
+    item: &ConsensusFinding,
+    taxonomy: &SeverityTaxonomy,
+    sha: &str,
+    target: Option<&ReviewTarget>,
+    show_confidence: bool,
+) -> String {
+    let finding = &item.finding;
+    let link = target
+        .zip(finding.file.as_deref())
+        .and_then(|(target, path)| target.blob_url(sha, path, finding.line_range));
+    let location = finding
+        .location()
+        .map(|loc| match &link {
+            Some(url) => format!(" [`{loc}`]({url})"),
+            None => format!(" `{loc}`"),
+        })
+        .unwrap_or_default();
+    let confidence = match finding.confidence {
+        Some(confidence) if show_confidence => format!(" <sub>confidence {confidence:.2}</sub>"),
+        _ => String::new(),
+    };
+    format!(
+        "- **[{}]**{} {} _(agreed by {}: {})_ <sub>id `{}`</sub>{}\n",
+        taxonomy.label(finding.severity),
+        location,
+        finding.title,
+        item.agreement(),
+        item.agents.join(", "),
+        finding_id(finding),
+        confidence
+    )
 }
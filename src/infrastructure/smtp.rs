@@ -0,0 +1,292 @@
+//! 최소 SMTP 클라이언트(이메일 알림 전송용).
+//!
+//! 메일 한 통을 보내는 데 필요한 만큼만 구현한다: EHLO, STARTTLS/암묵적 TLS,
+//! AUTH PLAIN/LOGIN, MAIL FROM, RCPT TO, DATA. 서버 인증서는 webpki 루트(와 설정한 CA 번들)로
+//! 검증하고, 인증 정보는 TLS로 보호된 연결에서만 보낸다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow, bail};
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio_rustls::TlsConnector;
+use tokio_rustls::rustls::pki_types::pem::PemObject;
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName};
+use tokio_rustls::rustls::{ClientConfig, RootCertStore};
+
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+const EHLO_NAME: &str = "localhost";
+
+/// SMTP 연결 보안 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SmtpSecurity {
+    /// 평문으로 연결한 뒤 STARTTLS로 전환(서버가 지원하지 않으면 실패)
+    StartTls,
+    /// 처음부터 TLS로 연결(보통 465 포트)
+    Tls,
+    /// 암호화하지 않음(사내 릴레이 등)
+    None,
+}
+
+impl SmtpSecurity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "starttls" => Some(Self::StartTls),
+            "tls" => Some(Self::Tls),
+            "none" => Some(Self::None),
+            _ => None,
+        }
+    }
+}
+
+/// 접속할 SMTP 서버.
+pub struct SmtpServer<'a> {
+    pub host: &'a str,
+    pub port: u16,
+    pub security: SmtpSecurity,
+    /// (사용자, 비밀번호). None이면 인증하지 않는다.
+    pub credentials: Option<(&'a str, &'a str)>,
+    /// webpki 루트와 함께 신뢰할 PEM CA 번들 경로
+    pub ca_file: Option<&'a str>,
+}
+
+/// 헤더에 그대로 넣어도 되는 단순한 메일 주소(`local@domain`)인지.
+pub fn is_email_address(address: &str) -> bool {
+    let address = address.trim();
+    let Some((local, domain)) = address.rsplit_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.is_empty()
+        && address
+            .chars()
+            .all(|c| c.is_ascii_graphic() && !matches!(c, '<' | '>' | ',' | ';' | '"'))
+}
+
+/// RFC 5322 메시지(`\n` 줄바꿈 허용)를 `to` 수신자들에게 보낸다.
+pub async fn send_mail(
+    server: &SmtpServer<'_>,
+    from: &str,
+    to: &[String],
+    message: &str,
+) -> Result<()> {
+    tokio::time::timeout(SMTP_TIMEOUT, deliver(server, from, to, message))
+        .await
+        .map_err(|_| anyhow!("SMTP timed out after {}s", SMTP_TIMEOUT.as_secs()))?
+}
+
+async fn deliver(server: &SmtpServer<'_>, from: &str, to: &[String], message: &str) -> Result<()> {
+    // 평문 연결의 AUTH PLAIN/LOGIN은 비밀번호를 그대로 흘린다.
+    if server.credentials.is_some() && server.security == SmtpSecurity::None {
+        bail!(
+            "refusing to send SMTP credentials over an unencrypted connection (set smtp_security to starttls or tls, or remove username)"
+        );
+    }
+    let tls = tls_config(server.ca_file)?;
+    let tcp = TcpStream::connect((server.host, server.port))
+        .await
+        .with_context(|| format!("failed to connect to {}:{}", server.host, server.port))?;
+    let mut session = match server.security {
+        SmtpSecurity::Tls => Session::new(Box::new(tls_handshake(&tls, server.host, tcp).await?)),
+        _ => Session::new(Box::new(tcp)),
+    };
+
+    session.expect_reply("greeting", &[220]).await?;
+    let mut extensions = session
+        .command(&format!("EHLO {EHLO_NAME}"), &[250])
+        .await?;
+    if server.security == SmtpSecurity::StartTls {
+        if !has_extension(&extensions, "STARTTLS") {
+            bail!(
+                "{} does not support STARTTLS (set smtp_security to tls or none)",
+                server.host
+            );
+        }
+        session.command("STARTTLS", &[220]).await?;
+        session = session.upgrade(&tls, server.host).await?;
+        extensions = session
+            .command(&format!("EHLO {EHLO_NAME}"), &[250])
+            .await?;
+    }
+
+    if let Some((username, password)) = server.credentials {
+        session
+            .authenticate(&extensions, username, password)
+            .await?;
+    }
+
+    session
+        .command(&format!("MAIL FROM:<{from}>"), &[250])
+        .await?;
+    for recipient in to {
+        session
+            .command(&format!("RCPT TO:<{recipient}>"), &[250, 251])
+            .await?;
+    }
+    session.command("DATA", &[354]).await?;
+    session.send_raw(&dot_stuffed(message)).await?;
+    session.expect_reply("DATA", &[250]).await?;
+    // 메일은 이미 접수됐으므로 QUIT 응답 실패는 무시한다.
+    let _ = session.command("QUIT", &[221]).await;
+    Ok(())
+}
+
+trait SmtpStream: AsyncRead + AsyncWrite + Unpin + Send {}
+
+impl<T: AsyncRead + AsyncWrite + Unpin + Send> SmtpStream for T {}
+
+struct Session {
+    io: BufReader<Box<dyn SmtpStream>>,
+}
+
+impl Session {
+    fn new(stream: Box<dyn SmtpStream>) -> Self {
+        Self {
+            io: BufReader::new(stream),
+        }
+    }
+
+    /// STARTTLS 응답 뒤 같은 연결을 TLS로 감싼다.
+    async fn upgrade(self, tls: &Arc<ClientConfig>, host: &str) -> Result<Self> {
+        let stream = self.io.into_inner();
+        Ok(Self::new(Box::new(tls_handshake(tls, host, stream).await?)))
+    }
+
+    async fn authenticate(
+        &mut self,
+        extensions: &[String],
+        username: &str,
+        password: &str,
+    ) -> Result<()> {
+        let mechanisms = extensions
+            .iter()
+            .find_map(|line| {
+                let upper = line.to_ascii_uppercase();
+                upper.strip_prefix("AUTH ").map(ToString::to_string)
+            })
+            .unwrap_or_default();
+        let mechanisms: Vec<&str> = mechanisms.split_whitespace().collect();
+
+        // 응답 오류에 비밀번호가 섞이지 않도록 명령 대신 단계 이름만 남긴다.
+        if mechanisms.contains(&"LOGIN") && !mechanisms.contains(&"PLAIN") {
+            self.send_line("AUTH LOGIN").await?;
+            self.expect_reply("AUTH LOGIN", &[334]).await?;
+            self.send_line(&STANDARD.encode(username)).await?;
+            self.expect_reply("AUTH LOGIN", &[334]).await?;
+            self.send_line(&STANDARD.encode(password)).await?;
+        } else {
+            let token = STANDARD.encode(format!("\0{username}\0{password}"));
+            self.send_line(&format!("AUTH PLAIN {token}")).await?;
+        }
+        self.expect_reply("authentication", &[235]).await?;
+        Ok(())
+    }
+
+    /// 명령을 보내고 응답 줄(코드 제외)을 반환한다.
+    async fn command(&mut self, line: &str, expected: &[u16]) -> Result<Vec<String>> {
+        self.send_line(line).await?;
+        let verb = line.split([' ', ':']).next().unwrap_or(line);
+        self.expect_reply(verb, expected).await
+    }
+
+    async fn send_line(&mut self, line: &str) -> Result<()> {
+        self.send_raw(&format!("{line}\r\n")).await
+    }
+
+    async fn send_raw(&mut self, data: &str) -> Result<()> {
+        let stream = self.io.get_mut();
+        stream.write_all(data.as_bytes()).await?;
+        stream.flush().await?;
+        Ok(())
+    }
+
+    /// 여러 줄 응답(`250-...`, 마지막 `250 ...`)을 읽어 코드가 기대값인지 확인한다.
+    async fn expect_reply(&mut self, step: &str, expected: &[u16]) -> Result<Vec<String>> {
+        let mut lines = Vec::new();
+        loop {
+            let mut line = String::new();
+            if self.io.read_line(&mut line).await? == 0 {
+                bail!("SMTP server closed the connection during {step}");
+            }
+            let line = line.trim_end();
+            let code = line.get(..3).and_then(|code| code.parse::<u16>().ok());
+            let Some(code) = code else {
+                bail!("malformed SMTP reply during {step}: {line}");
+            };
+            lines.push(line.get(4..).unwrap_or_default().to_string());
+            if line.as_bytes().get(3) == Some(&b'-') {
+                continue;
+            }
+            if !expected.contains(&code) {
+                bail!("SMTP {step} failed: {code} {}", lines.join(" "));
+            }
+            return Ok(lines);
+        }
+    }
+}
+
+fn has_extension(extensions: &[String], name: &str) -> bool {
+    extensions.iter().any(|line| {
+        line.split_whitespace()
+            .next()
+            .is_some_and(|keyword| keyword.eq_ignore_ascii_case(name))
+    })
+}
+
+/// webpki 루트에 `ca_file`(PEM)의 인증서를 더한 TLS 설정.
+fn tls_config(ca_file: Option<&str>) -> Result<Arc<ClientConfig>> {
+    let mut roots = RootCertStore::from_iter(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+    if let Some(path) = ca_file {
+        let certs = CertificateDer::pem_file_iter(path)
+            .and_then(|certs| certs.collect::<Result<Vec<_>, _>>())
+            .map_err(|err| anyhow!("failed to read smtp_ca_file {path}: {err}"))?;
+        if certs.is_empty() {
+            bail!("smtp_ca_file {path} contains no PEM certificates");
+        }
+        for cert in certs {
+            roots
+                .add(cert)
+                .with_context(|| format!("invalid certificate in smtp_ca_file {path}"))?;
+        }
+    }
+    let config = ClientConfig::builder_with_provider(Arc::new(
+        tokio_rustls::rustls::crypto::ring::default_provider(),
+    ))
+    .with_safe_default_protocol_versions()?
+    .with_root_certificates(roots)
+    .with_no_client_auth();
+    Ok(Arc::new(config))
+}
+
+async fn tls_handshake<S>(
+    tls: &Arc<ClientConfig>,
+    host: &str,
+    stream: S,
+) -> Result<tokio_rustls::client::TlsStream<S>>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let name = ServerName::try_from(host.to_string())
+        .map_err(|_| anyhow!("invalid SMTP host name '{host}'"))?;
+    TlsConnector::from(Arc::clone(tls))
+        .connect(name, stream)
+        .await
+        .with_context(|| format!("TLS handshake with {host} failed"))
+}
+
+/// 줄바꿈을 CRLF로 맞추고 `.`으로 시작하는 줄을 이스케이프한 뒤 DATA 종료 표시를 붙인다.
+fn dot_stuffed(message: &str) -> String {
+    let mut data = String::with_capacity(message.len() + 16);
+    for line in message.lines() {
+        if line.starts_with('.') {
+            data.push('.');
+        }
+        data.push_str(line);
+        data.push_str("\r\n");
+    }
+    data.push_str(".\r\n");
+    data
+}
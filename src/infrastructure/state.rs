@@ -0,0 +1,33 @@
+//! 사용자별 설정/캐시/상태 파일 디렉터리.
+//!
+//! 작업 디렉터리(저장소)에는 `repopilot init`처럼 명시적으로 요청한 프로젝트 파일만 쓰고,
+//! 그 밖의 파일은 모두 아래 사용자 디렉터리에 둔다.
+//! - 설정: 사용자 설정 파일(`config set`이 새로 만드는 파일)
+//! - 캐시: 원격 팀 설정처럼 지워져도 다시 받을 수 있는 데이터
+//! - 상태: REPL 입력 기록, 업데이트 확인 결과, 실행 기록/저널, 로그처럼 실행 중에 쌓이는 데이터
+
+use std::env;
+use std::path::PathBuf;
+
+/// OS 표준 사용자 설정 디렉터리의 `repopilot` (Linux 기본 `~/.config/repopilot`).
+pub fn config_dir() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join("repopilot"))
+}
+
+/// `$XDG_CACHE_HOME/repopilot` (기본은 OS 표준 캐시 디렉터리, Linux `~/.cache/repopilot`).
+pub fn cache_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(dirs::cache_dir)?;
+    Some(base.join("repopilot"))
+}
+
+/// `$XDG_STATE_HOME/repopilot` (기본 `~/.local/state/repopilot`).
+pub fn state_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_STATE_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".local").join("state")))?;
+    Some(base.join("repopilot"))
+}
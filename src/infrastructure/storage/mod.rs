@@ -0,0 +1,223 @@
+//! 실행 기록(run history) SQLite 저장소.
+//!
+//! 리뷰 실행마다 대상/SHA/결과/소요 시간과 provider별 토큰 사용량·추정 비용을
+//! 상태 디렉터리의 `history.sqlite3`에 남기고, `repopilot stats`가 이를 집계한다.
+//! 리뷰를 마친 실행은 합의 지적사항 요약(JSON)도 남겨 다음 SHA 리뷰의 진행 상황 비교에 쓴다.
+
+pub mod sqlite;
+
+use std::fs;
+
+use anyhow::{Context, Result};
+use rusqlite::params;
+use serde_json::{Value, json};
+
+use crate::application::ports::{RecentRun, RunRecord, RunStats, UsageStatsRow};
+use crate::domain::review::{PreviousFinding, PreviousReview, Severity};
+use crate::infrastructure::state;
+use sqlite::{Database, finite};
+
+const DATABASE_FILE: &str = "history.sqlite3";
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    started_at INTEGER NOT NULL,
+    repository TEXT NOT NULL,
+    target_url TEXT NOT NULL,
+    head_sha TEXT,
+    outcome TEXT NOT NULL,
+    duration_secs REAL NOT NULL
+);
+CREATE INDEX IF NOT EXISTS runs_started_at ON runs (started_at);
+CREATE TABLE IF NOT EXISTS provider_runs (
+    run_id INTEGER NOT NULL REFERENCES runs (id),
+    provider_id TEXT NOT NULL,
+    provider_name TEXT NOT NULL,
+    duration_secs REAL,
+    prompt_tokens INTEGER,
+    completion_tokens INTEGER,
+    total_tokens INTEGER,
+    cost_usd REAL
+);
+CREATE TABLE IF NOT EXISTS run_findings (
+    run_id INTEGER PRIMARY KEY REFERENCES runs (id),
+    findings TEXT NOT NULL
+);
+";
+
+/// 실행 한 건을 기록한다.
+pub fn record(run: &RunRecord) -> Result<()> {
+    let mut db = open()?;
+    let tx = db.transaction()?;
+    tx.execute(
+        "INSERT INTO runs (started_at, repository, target_url, head_sha, outcome, duration_secs) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            run.started_at,
+            run.repository,
+            run.target_url,
+            run.head_sha,
+            run.outcome,
+            finite(Some(run.duration_secs)).unwrap_or_default(),
+        ],
+    )?;
+    let run_id = tx.last_insert_rowid();
+    for provider in &run.providers {
+        tx.execute(
+            "INSERT INTO provider_runs (run_id, provider_id, provider_name, duration_secs, \
+             prompt_tokens, completion_tokens, total_tokens, cost_usd) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+            params![
+                run_id,
+                provider.provider_id,
+                provider.provider_name,
+                finite(provider.duration_secs),
+                provider.prompt_tokens,
+                provider.completion_tokens,
+                provider.total_tokens,
+                finite(provider.cost_usd),
+            ],
+        )?;
+    }
+    if let Some(findings) = &run.findings {
+        let findings: Vec<Value> = findings
+            .iter()
+            .map(|f| {
+                json!({
+                    "id": f.id,
+                    "severity": f.severity.code(),
+                    "file": f.file,
+                    "title": f.title,
+                })
+            })
+            .collect();
+        tx.execute(
+            "INSERT INTO run_findings (run_id, findings) VALUES (?1, ?2)",
+            params![run_id, Value::Array(findings).to_string()],
+        )?;
+    }
+    tx.commit().context("failed to commit run history")
+}
+
+/// `since`(unix 초) 이후 실행을 저장소/provider별로 집계한다.
+pub fn stats(since: Option<u64>) -> Result<RunStats> {
+    let db = open()?;
+    let since = since.unwrap_or(0);
+
+    let repositories = db.query(
+        "SELECT r.repository AS name, COUNT(*) AS runs,
+                SUM(r.outcome IN ('failed', 'cancelled')) AS failed,
+                AVG(r.duration_secs) AS avg_secs,
+                SUM(u.tokens) AS total_tokens, SUM(u.cost) AS cost_usd
+         FROM runs r
+         LEFT JOIN (
+             SELECT run_id, SUM(total_tokens) AS tokens, SUM(cost_usd) AS cost
+             FROM provider_runs GROUP BY run_id
+         ) u ON u.run_id = r.id
+         WHERE r.started_at >= ?1
+         GROUP BY r.repository
+         ORDER BY runs DESC, name",
+        params![since],
+    )?;
+    let providers = db.query(
+        "SELECT MAX(p.provider_name) AS name, COUNT(*) AS runs,
+                AVG(p.duration_secs) AS avg_secs,
+                SUM(p.total_tokens) AS total_tokens, SUM(p.cost_usd) AS cost_usd
+         FROM provider_runs p
+         JOIN runs r ON r.id = p.run_id
+         WHERE r.started_at >= ?1
+         GROUP BY p.provider_id
+         ORDER BY runs DESC, name",
+        params![since],
+    )?;
+
+    Ok(RunStats {
+        location: db.path().display().to_string(),
+        repositories: repositories.iter().map(stats_row).collect(),
+        providers: providers.iter().map(stats_row).collect(),
+    })
+}
+
+/// 최근 실행을 새것부터 `limit`건 조회한다.
+pub fn recent(limit: usize) -> Result<Vec<RecentRun>> {
+    let db = open()?;
+    let rows = db.query(
+        "SELECT r.started_at, r.repository, r.target_url, r.head_sha, r.outcome, r.duration_secs,
+                u.tokens AS total_tokens, u.cost AS cost_usd
+         FROM runs r
+         LEFT JOIN (
+             SELECT run_id, SUM(total_tokens) AS tokens, SUM(cost_usd) AS cost
+             FROM provider_runs GROUP BY run_id
+         ) u ON u.run_id = r.id
+         ORDER BY r.started_at DESC, r.id DESC
+         LIMIT ?1",
+        params![limit],
+    )?;
+    Ok(rows.iter().map(recent_row).collect())
+}
+
+/// 같은 대상에서 `head_sha`가 아닌 SHA로 게시까지 마친 가장 최근 리뷰의 합의 지적사항을 조회한다.
+pub fn previous_review(target_url: &str, head_sha: &str) -> Result<Option<PreviousReview>> {
+    let db = open()?;
+    let rows = db.query(
+        "SELECT r.head_sha, f.findings
+         FROM runs r
+         JOIN run_findings f ON f.run_id = r.id
+         WHERE r.target_url = ?1 AND r.head_sha IS NOT NULL AND r.head_sha != ?2
+               AND r.outcome IN ('posted', 'gate-failed')
+         ORDER BY r.started_at DESC, r.id DESC
+         LIMIT 1",
+        params![target_url, head_sha],
+    )?;
+    let Some(row) = rows.first() else {
+        return Ok(None);
+    };
+    let findings: Vec<Value> = serde_json::from_str(row["findings"].as_str().unwrap_or("[]"))
+        .context("invalid recorded findings JSON")?;
+    Ok(Some(PreviousReview {
+        head_sha: row["head_sha"].as_str().unwrap_or_default().to_string(),
+        findings: findings.iter().filter_map(previous_finding).collect(),
+    }))
+}
+
+fn previous_finding(value: &Value) -> Option<PreviousFinding> {
+    Some(PreviousFinding {
+        id: value["id"].as_str()?.to_string(),
+        severity: Severity::parse(value["severity"].as_str()?)?,
+        file: value["file"].as_str().map(ToString::to_string),
+        title: value["title"].as_str().unwrap_or_default().to_string(),
+    })
+}
+
+fn open() -> Result<Database> {
+    let dir = state::state_dir().context("cannot determine state directory for run history")?;
+    fs::create_dir_all(&dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    let db = Database::open(dir.join(DATABASE_FILE))?;
+    db.execute_batch(SCHEMA)?;
+    Ok(db)
+}
+
+fn stats_row(row: &Value) -> UsageStatsRow {
+    UsageStatsRow {
+        name: row["name"].as_str().unwrap_or_default().to_string(),
+        runs: row["runs"].as_u64().unwrap_or_default(),
+        failed: row["failed"].as_u64().unwrap_or_default(),
+        avg_secs: row["avg_secs"].as_f64(),
+        total_tokens: row["total_tokens"].as_u64(),
+        cost_usd: row["cost_usd"].as_f64(),
+    }
+}
+
+fn recent_row(row: &Value) -> RecentRun {
+    RecentRun {
+        started_at: row["started_at"].as_u64().unwrap_or_default(),
+        repository: row["repository"].as_str().unwrap_or_default().to_string(),
+        target_url: row["target_url"].as_str().unwrap_or_default().to_string(),
+        head_sha: row["head_sha"].as_str().map(ToString::to_string),
+        outcome: row["outcome"].as_str().unwrap_or_default().to_string(),
+        duration_secs: row["duration_secs"].as_f64().unwrap_or_default(),
+        total_tokens: row["total_tokens"].as_u64(),
+        cost_usd: row["cost_usd"].as_f64(),
+    }
+}
@@ -0,0 +1,87 @@
+//! 내장(bundled) SQLite 접근.
+//!
+//! `rusqlite`로 데이터베이스 파일을 직접 열며, 값은 모두 바인딩 파라미터(`?N`)로 넘긴다.
+//! 조회 결과는 열 이름을 키로 하는 JSON 객체로 반환한다.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use rusqlite::types::ValueRef;
+use rusqlite::{Connection, Params, Transaction};
+use serde_json::{Map, Value};
+
+// 배치 리뷰처럼 여러 실행이 동시에 기록할 때 잠금을 기다리는 시간
+const BUSY_TIMEOUT: Duration = Duration::from_millis(5000);
+
+/// SQLite 데이터베이스 파일 하나.
+pub struct Database {
+    path: PathBuf,
+    connection: Connection,
+}
+
+impl Database {
+    pub fn open(path: PathBuf) -> Result<Self> {
+        let connection = Connection::open(&path)
+            .with_context(|| format!("failed to open {}", path.display()))?;
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .context("failed to set SQLite busy timeout")?;
+        Ok(Self { path, connection })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 파라미터 없는 SQL 스크립트(스키마 등)를 실행한다.
+    pub fn execute_batch(&self, sql: &str) -> Result<()> {
+        self.connection
+            .execute_batch(sql)
+            .with_context(|| format!("SQLite failed on {}", self.path.display()))
+    }
+
+    /// 쓰기 잠금을 바로 잡는(`BEGIN IMMEDIATE`) 트랜잭션을 시작한다.
+    pub fn transaction(&mut self) -> Result<Transaction<'_>> {
+        self.connection
+            .transaction_with_behavior(rusqlite::TransactionBehavior::Immediate)
+            .with_context(|| format!("failed to begin transaction on {}", self.path.display()))
+    }
+
+    /// 조회 SQL을 실행하고 행을 JSON 객체로 반환한다.
+    pub fn query(&self, sql: &str, params: impl Params) -> Result<Vec<Value>> {
+        let mut statement = self
+            .connection
+            .prepare(sql)
+            .with_context(|| format!("SQLite failed on {}", self.path.display()))?;
+        let columns: Vec<String> = statement
+            .column_names()
+            .into_iter()
+            .map(ToString::to_string)
+            .collect();
+        let mut rows = statement.query(params)?;
+        let mut values = Vec::new();
+        while let Some(row) = rows.next()? {
+            let mut object = Map::new();
+            for (index, column) in columns.iter().enumerate() {
+                object.insert(column.clone(), json_value(row.get_ref(index)?));
+            }
+            values.push(Value::Object(object));
+        }
+        Ok(values)
+    }
+}
+
+fn json_value(value: ValueRef<'_>) -> Value {
+    match value {
+        ValueRef::Null | ValueRef::Blob(_) => Value::Null,
+        ValueRef::Integer(v) => Value::from(v),
+        ValueRef::Real(v) => serde_json::Number::from_f64(v).map_or(Value::Null, Value::Number),
+        ValueRef::Text(v) => Value::String(String::from_utf8_lossy(v).to_string()),
+    }
+}
+
+/// 유한한 값만 남긴다(NaN/무한대는 NULL로 저장).
+pub fn finite(value: Option<f64>) -> Option<f64> {
+    value.filter(|v| v.is_finite())
+}
@@ -0,0 +1,179 @@
+//! Tera 기반 프롬프트/코멘트 템플릿 엔진.
+//!
+//! - `{{name}}`: 변수 값으로 치환(정의되지 않은 변수는 오류)
+//! - `{% if name %}...{% endif %}`: 변수 값이 비어 있지 않을 때만 출력(중첩 가능)
+//! - 그 밖의 Tera 문법(필터, `{% else %}`, `{% set %}` 등)도 쓸 수 있다.
+//!
+//! 사용자 템플릿은 불러올 때 [`validate_template`]으로 검사해 문법 오류와 모르는 변수를 거부한다.
+//! 템플릿 종류별 변수 목록과 검사 규칙은 `domain::template`에 있다.
+
+use std::collections::BTreeSet;
+
+use anyhow::{Context as _, Result, anyhow};
+use tera::ast::{Expr, ExprVal, Node};
+use tera::{Context, Template, Tera};
+
+use crate::domain::template::{
+    TemplateLists, TemplateSchema, TemplateVars, check_template_variables,
+};
+
+/// 렌더링/검사 오류 메시지에 쓰는 템플릿 이름.
+const TEMPLATE_NAME: &str = "template";
+
+/// 템플릿을 렌더링한다. 정의되지 않은 변수를 출력하면 오류다.
+pub fn render_template(template: &str, vars: &TemplateVars<'_>) -> Result<String> {
+    render_template_with_lists(template, vars, &TemplateLists::new())
+}
+
+/// 목록 변수까지 넣어 템플릿을 렌더링한다.
+pub fn render_template_with_lists(
+    template: &str,
+    vars: &TemplateVars<'_>,
+    lists: &TemplateLists<'_>,
+) -> Result<String> {
+    let mut context = Context::new();
+    for (name, value) in vars {
+        // 공백뿐인 값은 `{% if %}`에서 거짓이 되도록 빈 문자열로 넘긴다.
+        let value: &str = if value.trim().is_empty() { "" } else { value };
+        context.insert(*name, value);
+    }
+    for (name, items) in lists {
+        context.insert(*name, items);
+    }
+    let mut tera = Tera::default();
+    tera.add_raw_template(TEMPLATE_NAME, template)
+        .map_err(template_error)?;
+    tera.render(TEMPLATE_NAME, &context).map_err(template_error)
+}
+
+/// 불러온 템플릿을 검사한다. 문법 오류(닫히지 않은 블록 등)와 `schema`에 없는 변수를 거부하고,
+/// 모든 변수와 목록 항목 하나에 예시 값을 넣어 한 번 렌더링해 없는 필터나 항목 필드 같은
+/// 렌더링 오류도 미리 드러낸다.
+pub fn validate_template(template: &str, schema: &TemplateSchema) -> Result<()> {
+    let parsed = Template::new(TEMPLATE_NAME, None, template)
+        .map_err(template_error)
+        .context("failed to parse template")?;
+    let mut names = BTreeSet::new();
+    collect_nodes(&parsed.ast, &mut Vec::new(), &mut names);
+    check_template_variables(&names, schema)?;
+    render_template_with_lists(template, &schema.sample_vars(), &schema.sample_lists()).map(drop)
+}
+
+/// Tera 오류는 원인(줄 위치, 없는 변수 이름)이 source 체인에 있으므로 한 메시지로 합친다.
+fn template_error(err: tera::Error) -> anyhow::Error {
+    let mut message = err.to_string();
+    let mut source = std::error::Error::source(&err);
+    while let Some(cause) = source {
+        message.push_str(&format!(": {cause}"));
+        source = cause.source();
+    }
+    anyhow!(message)
+}
+
+/// 템플릿이 참조하는 변수 이름(최상위 이름)을 모은다. `for`/`set`/매크로 인자로 만든 이름은 뺀다.
+fn collect_nodes(nodes: &[Node], bound: &mut Vec<String>, names: &mut BTreeSet<String>) {
+    for node in nodes {
+        match node {
+            Node::VariableBlock(_, expr) => collect_expr(expr, bound, names),
+            Node::Set(_, set) => {
+                collect_expr(&set.value, bound, names);
+                bound.push(set.key.clone());
+            }
+            Node::FilterSection(_, section, _) => {
+                for arg in section.filter.args.values() {
+                    collect_expr(arg, bound, names);
+                }
+                collect_nodes(&section.body, bound, names);
+            }
+            Node::Block(_, block, _) => collect_nodes(&block.body, bound, names),
+            Node::Forloop(_, forloop, _) => {
+                collect_expr(&forloop.container, bound, names);
+                let depth = bound.len();
+                bound.extend(forloop.key.iter().cloned());
+                bound.push(forloop.value.clone());
+                bound.push("loop".to_string());
+                collect_nodes(&forloop.body, bound, names);
+                bound.truncate(depth);
+                if let Some(body) = &forloop.empty_body {
+                    collect_nodes(body, bound, names);
+                }
+            }
+            Node::If(branches, _) => {
+                for (_, condition, body) in &branches.conditions {
+                    collect_expr(condition, bound, names);
+                    collect_nodes(body, bound, names);
+                }
+                if let Some((_, body)) = &branches.otherwise {
+                    collect_nodes(body, bound, names);
+                }
+            }
+            Node::MacroDefinition(_, definition, _) => {
+                let depth = bound.len();
+                bound.extend(definition.args.keys().cloned());
+                collect_nodes(&definition.body, bound, names);
+                bound.truncate(depth);
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_expr(expr: &Expr, bound: &[String], names: &mut BTreeSet<String>) {
+    collect_value(&expr.val, bound, names);
+    for filter in &expr.filters {
+        for arg in filter.args.values() {
+            collect_expr(arg, bound, names);
+        }
+    }
+}
+
+fn collect_value(value: &ExprVal, bound: &[String], names: &mut BTreeSet<String>) {
+    let mut ident = |ident: &str| {
+        let root = ident.split(['.', '[']).next().unwrap_or_default();
+        if !bound.iter().any(|name| name == root) {
+            names.insert(root.to_string());
+        }
+    };
+    match value {
+        ExprVal::Ident(name) => ident(name),
+        ExprVal::Test(test) => {
+            ident(&test.ident);
+            for arg in &test.args {
+                collect_expr(arg, bound, names);
+            }
+        }
+        ExprVal::Math(expr) => {
+            collect_expr(&expr.lhs, bound, names);
+            collect_expr(&expr.rhs, bound, names);
+        }
+        ExprVal::Logic(expr) => {
+            collect_expr(&expr.lhs, bound, names);
+            collect_expr(&expr.rhs, bound, names);
+        }
+        ExprVal::In(expr) => {
+            collect_expr(&expr.lhs, bound, names);
+            collect_expr(&expr.rhs, bound, names);
+        }
+        ExprVal::FunctionCall(call) => {
+            for arg in call.args.values() {
+                collect_expr(arg, bound, names);
+            }
+        }
+        ExprVal::MacroCall(call) => {
+            for arg in call.args.values() {
+                collect_expr(arg, bound, names);
+            }
+        }
+        ExprVal::Array(items) => {
+            for item in items {
+                collect_expr(item, bound, names);
+            }
+        }
+        ExprVal::StringConcat(concat) => {
+            for value in &concat.values {
+                collect_value(value, bound, names);
+            }
+        }
+        ExprVal::String(_) | ExprVal::Int(_) | ExprVal::Float(_) | ExprVal::Bool(_) => {}
+    }
+}
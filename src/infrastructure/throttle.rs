@@ -0,0 +1,473 @@
+//! 병렬 CI 작업처럼 동시에 도는 여러 repopilot 프로세스가 provider 동시 요청 수를 나눠 쓰는
+//! 분산 세마포어(`defaults.global_concurrency`).
+//!
+//! provider마다 `global_concurrency`개의 슬롯을 두고, 요청 전에 슬롯 하나를 잡았다가 응답을 받으면 놓는다.
+//! 슬롯 저장소(`defaults.global_concurrency_store`)는 두 가지다.
+//! - 디렉터리(기본 `~/.local/state/repopilot/concurrency`): `<provider>/slot-<번호>` 파일을 새로 만들면
+//!   그 슬롯을 잡은 것이다. 같은 머신의 작업끼리, 또는 같은 공유 볼륨을 쓰는 러너끼리 조정한다.
+//! - `redis://[:비밀번호@]호스트[:포트][/DB]`: provider별 sorted set에 실행기 토큰을 넣고, 넣은 뒤 크기가
+//!   한도 이하일 때만 잡은 것으로 본다(넘으면 토큰을 빼고 다시 기다린다).
+//!
+//! 잡은 슬롯은 [`HEARTBEAT_INTERVAL`]마다 갱신하고, 갱신이 [`STALE_AFTER`] 넘게 끊긴 슬롯(비정상 종료한
+//! 실행)은 다른 실행이 회수한다. 저장소에 접근할 수 없으면 경고만 남기고 슬롯 없이 요청한다.
+
+use std::fs::{self, OpenOptions};
+use std::future::Future;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result, bail};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::task::JoinHandle;
+use url::Url;
+
+use crate::infrastructure::clock::{random_id, random_u64};
+use crate::infrastructure::config::Config;
+use crate::infrastructure::state;
+
+/// 잡은 슬롯을 갱신하는 주기.
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+/// 이 시간 넘게 갱신되지 않은 슬롯은 주인이 사라진 것으로 보고 회수한다.
+const STALE_AFTER: Duration = Duration::from_secs(60);
+/// 빈 슬롯을 다시 확인하는 기본 간격(실행마다 최대 절반만큼 흩뜨린다).
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Redis 연결/명령 제한 시간.
+const REDIS_TIMEOUT: Duration = Duration::from_secs(5);
+const REDIS_DEFAULT_PORT: u16 = 6379;
+const REDIS_KEY_PREFIX: &str = "repopilot:concurrency:";
+
+/// provider별 전역 동시 요청 세마포어.
+pub struct GlobalThrottle {
+    limit: usize,
+    store: SlotStore,
+}
+
+#[derive(Clone)]
+enum SlotStore {
+    Directory(PathBuf),
+    Redis(RedisTarget),
+}
+
+/// 잡은 슬롯. 파일 슬롯은 파일 경로, Redis 슬롯은 sorted set 키를 담는다.
+#[derive(Clone)]
+enum Slot {
+    File(PathBuf),
+    Redis(String),
+}
+
+impl GlobalThrottle {
+    /// 설정의 한도/저장소로 만든다. 한도가 0(기본)이면 None.
+    pub fn from_config(config: &Config) -> Result<Option<Self>> {
+        let limit = config.global_concurrency();
+        if limit == 0 {
+            return Ok(None);
+        }
+        let store = match config.global_concurrency_store() {
+            Some(url) if url.contains("://") => SlotStore::Redis(RedisTarget::parse(url)?),
+            Some(dir) => SlotStore::Directory(PathBuf::from(dir)),
+            None => SlotStore::Directory(
+                state::state_dir()
+                    .context("cannot determine the state directory for global_concurrency")?
+                    .join("concurrency"),
+            ),
+        };
+        Ok(Some(Self { limit, store }))
+    }
+
+    /// `provider_id`의 슬롯을 잡을 때까지 기다린다. 저장소 오류면 경고만 남기고 None(제한 없이 진행).
+    pub async fn acquire(&self, provider_id: &str) -> Option<ThrottlePermit> {
+        let token = random_token();
+        let mut waiting = false;
+        loop {
+            match self.try_acquire(provider_id, &token).await {
+                Ok(Some(slot)) => {
+                    if waiting {
+                        tracing::info!(provider = provider_id, "global concurrency slot acquired");
+                    }
+                    return Some(ThrottlePermit::hold(self.store.clone(), slot, token));
+                }
+                Ok(None) => {
+                    if !waiting {
+                        tracing::info!(
+                            provider = provider_id,
+                            limit = self.limit,
+                            "waiting for a global concurrency slot"
+                        );
+                        waiting = true;
+                    }
+                    tokio::time::sleep(poll_interval()).await;
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        provider = provider_id,
+                        "global concurrency store unavailable; continuing without a slot: {err:#}"
+                    );
+                    return None;
+                }
+            }
+        }
+    }
+
+    async fn try_acquire(&self, provider_id: &str, token: &str) -> Result<Option<Slot>> {
+        match &self.store {
+            SlotStore::Directory(dir) => {
+                let dir = dir.join(sanitize_key(provider_id));
+                Ok(try_acquire_file(&dir, self.limit, token)?.map(Slot::File))
+            }
+            SlotStore::Redis(target) => {
+                let key = format!("{REDIS_KEY_PREFIX}{provider_id}");
+                let acquired = try_acquire_redis(target, &key, self.limit, token).await?;
+                Ok(acquired.then_some(Slot::Redis(key)))
+            }
+        }
+    }
+}
+
+/// 잡은 슬롯. 살아 있는 동안 슬롯을 갱신하고, drop하면 놓는다.
+pub struct ThrottlePermit {
+    store: SlotStore,
+    slot: Slot,
+    token: String,
+    heartbeat: JoinHandle<()>,
+}
+
+impl ThrottlePermit {
+    fn hold(store: SlotStore, slot: Slot, token: String) -> Self {
+        let heartbeat = {
+            let store = store.clone();
+            let slot = slot.clone();
+            let token = token.clone();
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+                    let result = match (&store, &slot) {
+                        (_, Slot::File(path)) => touch_file_slot(path, &token),
+                        (SlotStore::Redis(target), Slot::Redis(key)) => {
+                            refresh_redis_slot(target, key, &token).await
+                        }
+                        _ => Ok(()),
+                    };
+                    if let Err(err) = result {
+                        tracing::warn!("failed to refresh global concurrency slot: {err:#}");
+                    }
+                }
+            })
+        };
+        Self {
+            store,
+            slot,
+            token,
+            heartbeat,
+        }
+    }
+}
+
+impl Drop for ThrottlePermit {
+    fn drop(&mut self) {
+        self.heartbeat.abort();
+        match (&self.store, &self.slot) {
+            (_, Slot::File(path)) => release_file_slot(path, &self.token),
+            (SlotStore::Redis(target), Slot::Redis(key)) => {
+                // drop에서는 기다릴 수 없으므로 런타임에 맡긴다. 놓치면 STALE_AFTER 뒤에 회수된다.
+                if let Ok(handle) = tokio::runtime::Handle::try_current() {
+                    let (target, key, token) = (target.clone(), key.clone(), self.token.clone());
+                    handle.spawn(async move {
+                        if let Err(err) = release_redis_slot(&target, &key, &token).await {
+                            tracing::warn!("failed to release global concurrency slot: {err:#}");
+                        }
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// 비어 있는 `slot-<번호>` 파일을 새로 만들어 잡는다. 오래 갱신되지 않은 슬롯은 지워 다음 확인 때 쓴다.
+fn try_acquire_file(dir: &Path, limit: usize, token: &str) -> Result<Option<PathBuf>> {
+    fs::create_dir_all(dir).with_context(|| format!("failed to create {}", dir.display()))?;
+    for index in 0..limit {
+        let path = dir.join(format!("slot-{index}"));
+        match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(mut file) => {
+                file.write_all(token.as_bytes())
+                    .with_context(|| format!("failed to write {}", path.display()))?;
+                return Ok(Some(path));
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                reclaim_stale_slot(&path, token);
+            }
+            Err(err) => {
+                return Err(err).with_context(|| format!("failed to create {}", path.display()));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// 오래된 슬롯을 회수한다. 확인과 삭제 사이에 다른 실행이 같은 슬롯을 회수해 새로 잡을 수 있으므로,
+/// 먼저 자기만 아는 이름(tombstone)으로 원자적으로 옮긴 뒤 옮긴 파일의 소유 토큰과 수정 시각을 다시
+/// 확인하고 지운다. 그 사이 갱신된 슬롯이면 덮어쓰지 않고 원래 이름으로 되돌린다.
+fn reclaim_stale_slot(path: &Path, token: &str) {
+    let Ok(owner) = fs::read_to_string(path) else {
+        return;
+    };
+    if !is_stale(path) {
+        return;
+    }
+    let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+        return;
+    };
+    let tombstone = path.with_file_name(format!("{name}.reclaim-{token}"));
+    if fs::rename(path, &tombstone).is_err() {
+        // 다른 실행이 먼저 회수했다.
+        return;
+    }
+    let unchanged = fs::read_to_string(&tombstone).is_ok_and(|current| current == owner);
+    if unchanged && is_stale(&tombstone) {
+        tracing::debug!(slot = %path.display(), "reclaiming stale concurrency slot");
+    } else {
+        // 새 소유자의 슬롯을 옮겨 버렸다. 그 사이 같은 이름이 다시 생겼으면 덮어쓰지 않는다.
+        let _ = fs::hard_link(&tombstone, path);
+    }
+    let _ = fs::remove_file(&tombstone);
+}
+
+fn is_stale(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age > STALE_AFTER)
+}
+
+/// 슬롯 파일의 수정 시각을 갱신한다. 이미 회수된 슬롯이면 다시 만들지 않고, 회수되어 다른 실행이
+/// 잡은 슬롯이면 건드리지 않는다.
+fn touch_file_slot(path: &Path, token: &str) -> Result<()> {
+    if !fs::read_to_string(path).is_ok_and(|owner| owner == token) {
+        bail!("slot {} is no longer held", path.display());
+    }
+    let file = OpenOptions::new()
+        .write(true)
+        .open(path)
+        .with_context(|| format!("failed to open {}", path.display()))?;
+    file.set_modified(SystemTime::now())
+        .with_context(|| format!("failed to touch {}", path.display()))
+}
+
+/// 자기 토큰이 든 슬롯 파일만 지운다(회수되어 다른 실행이 잡은 슬롯은 그대로 둔다).
+fn release_file_slot(path: &Path, token: &str) {
+    if fs::read_to_string(path).is_ok_and(|owner| owner == token) {
+        let _ = fs::remove_file(path);
+    }
+}
+
+/// 오래된 토큰을 지우고 자기 토큰을 넣은 뒤, 집합 크기가 한도 이하면 잡은 것으로 본다.
+async fn try_acquire_redis(
+    target: &RedisTarget,
+    key: &str,
+    limit: usize,
+    token: &str,
+) -> Result<bool> {
+    let now = unix_millis();
+    let cutoff = now.saturating_sub(STALE_AFTER.as_millis() as u64);
+    let mut conn = RedisConnection::connect(target).await?;
+    conn.command(&["MULTI"]).await?;
+    conn.command(&["ZREMRANGEBYSCORE", key, "-inf", &cutoff.to_string()])
+        .await?;
+    conn.command(&["ZADD", key, &now.to_string(), token])
+        .await?;
+    conn.command(&["ZCARD", key]).await?;
+    let RedisReply::Array(replies) = conn.command(&["EXEC"]).await? else {
+        bail!("unexpected reply to EXEC");
+    };
+    let Some(RedisReply::Integer(count)) = replies.last() else {
+        bail!("unexpected reply to ZCARD");
+    };
+    if usize::try_from(*count).is_ok_and(|count| count <= limit) {
+        return Ok(true);
+    }
+    conn.command(&["ZREM", key, token]).await?;
+    Ok(false)
+}
+
+/// 자기 토큰의 점수(마지막 갱신 시각)를 갱신한다. 이미 회수된 토큰은 다시 넣지 않는다(`XX`).
+async fn refresh_redis_slot(target: &RedisTarget, key: &str, token: &str) -> Result<()> {
+    let mut conn = RedisConnection::connect(target).await?;
+    conn.command(&["ZADD", key, "XX", &unix_millis().to_string(), token])
+        .await?;
+    Ok(())
+}
+
+async fn release_redis_slot(target: &RedisTarget, key: &str, token: &str) -> Result<()> {
+    let mut conn = RedisConnection::connect(target).await?;
+    conn.command(&["ZREM", key, token]).await?;
+    Ok(())
+}
+
+/// `redis://[사용자[:비밀번호]@]호스트[:포트][/DB]` 연결 정보.
+#[derive(Clone)]
+struct RedisTarget {
+    addr: String,
+    username: Option<String>,
+    password: Option<String>,
+    db: Option<u32>,
+}
+
+impl RedisTarget {
+    fn parse(raw: &str) -> Result<Self> {
+        let url = Url::parse(raw).context("invalid global_concurrency_store URL")?;
+        if url.scheme() != "redis" {
+            bail!(
+                "unsupported global_concurrency_store scheme '{}' (use a directory path or redis://)",
+                url.scheme()
+            );
+        }
+        let host = url
+            .host_str()
+            .filter(|host| !host.is_empty())
+            .context("global_concurrency_store URL has no host")?;
+        let db = match url.path().trim_matches('/') {
+            "" => None,
+            db => Some(
+                db.parse()
+                    .with_context(|| format!("invalid Redis database '{db}'"))?,
+            ),
+        };
+        let decode = |value: &str| {
+            percent_encoding::percent_decode_str(value)
+                .decode_utf8_lossy()
+                .into_owned()
+        };
+        Ok(Self {
+            addr: format!("{host}:{}", url.port().unwrap_or(REDIS_DEFAULT_PORT)),
+            username: Some(url.username())
+                .filter(|name| !name.is_empty())
+                .map(decode),
+            password: url.password().map(decode),
+            db,
+        })
+    }
+}
+
+/// 슬롯 조정에 필요한 만큼만 구현한 RESP2 클라이언트.
+struct RedisConnection {
+    stream: BufReader<TcpStream>,
+}
+
+enum RedisReply {
+    Status,
+    Integer(i64),
+    Bulk,
+    Array(Vec<RedisReply>),
+}
+
+impl RedisConnection {
+    async fn connect(target: &RedisTarget) -> Result<Self> {
+        let stream = tokio::time::timeout(REDIS_TIMEOUT, TcpStream::connect(&target.addr))
+            .await
+            .with_context(|| format!("timed out connecting to Redis at {}", target.addr))?
+            .with_context(|| format!("failed to connect to Redis at {}", target.addr))?;
+        let mut conn = Self {
+            stream: BufReader::new(stream),
+        };
+        match (&target.username, &target.password) {
+            (Some(username), Some(password)) => {
+                conn.command(&["AUTH", username, password]).await?;
+            }
+            (None, Some(password)) => {
+                conn.command(&["AUTH", password]).await?;
+            }
+            _ => {}
+        }
+        if let Some(db) = target.db {
+            conn.command(&["SELECT", &db.to_string()]).await?;
+        }
+        Ok(conn)
+    }
+
+    async fn command(&mut self, args: &[&str]) -> Result<RedisReply> {
+        let mut request = format!("*{}\r\n", args.len()).into_bytes();
+        for arg in args {
+            request.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+            request.extend_from_slice(arg.as_bytes());
+            request.extend_from_slice(b"\r\n");
+        }
+        let exchange = async {
+            self.stream.get_mut().write_all(&request).await?;
+            self.read_reply().await
+        };
+        tokio::time::timeout(REDIS_TIMEOUT, exchange)
+            .await
+            .with_context(|| format!("Redis {} timed out", args[0]))?
+            .with_context(|| format!("Redis {} failed", args[0]))
+    }
+
+    fn read_reply(&mut self) -> Pin<Box<dyn Future<Output = Result<RedisReply>> + Send + '_>> {
+        Box::pin(async move {
+            let mut line = String::new();
+            if self.stream.read_line(&mut line).await? == 0 {
+                bail!("connection closed");
+            }
+            let line = line.trim_end_matches(['\r', '\n']);
+            let (kind, rest) = line.split_at(line.len().min(1));
+            match kind {
+                "+" => Ok(RedisReply::Status),
+                "-" => bail!("{rest}"),
+                ":" => Ok(RedisReply::Integer(
+                    rest.parse().context("invalid integer reply")?,
+                )),
+                "$" => {
+                    let len: i64 = rest.parse().context("invalid bulk reply")?;
+                    if let Ok(len) = usize::try_from(len) {
+                        // 본문과 끝의 CRLF는 쓰지 않으므로 읽고 버린다.
+                        let mut body = vec![0; len + 2];
+                        self.stream.read_exact(&mut body).await?;
+                    }
+                    Ok(RedisReply::Bulk)
+                }
+                "*" => {
+                    let len: i64 = rest.parse().context("invalid array reply")?;
+                    let mut items = Vec::new();
+                    for _ in 0..len.max(0) {
+                        items.push(self.read_reply().await?);
+                    }
+                    Ok(RedisReply::Array(items))
+                }
+                _ => bail!("unexpected reply '{line}'"),
+            }
+        })
+    }
+}
+
+/// provider id를 슬롯 디렉터리 이름으로 쓸 수 있게 바꾼다.
+fn sanitize_key(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+fn random_token() -> String {
+    format!("{}-{}", std::process::id(), random_id())
+}
+
+fn poll_interval() -> Duration {
+    let jitter = random_u64() % 500;
+    POLL_INTERVAL + POLL_INTERVAL * jitter as u32 / 1000
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as u64)
+        .unwrap_or_default()
+}
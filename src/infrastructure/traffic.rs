@@ -0,0 +1,794 @@
+//! 리뷰 실행의 VCS/provider 트래픽 기록과 재생(`--record <dir>` / `--replay <dir>`).
+//!
+//! VCS 클라이언트와 provider 경계에서 요청과 응답을 `<dir>/traffic.jsonl`에 한 줄씩 남기고,
+//! 진행 출력은 `<dir>/output.txt`(재생 시 `output.replay.txt`)에 남겨 두 실행을 diff로 비교할 수 있게 한다.
+//! HTTP 헤더나 인증 정보는 이 경계를 지나지 않으며, 호스트 토큰과 provider API 키 값은 기록 전에
+//! `[REDACTED]`로 가린다. 재생 모드에서는 네트워크와 provider CLI를 호출하지 않고 기록된 응답을 돌려준다.
+//! - 같은 호출(채널, 대상, 호출 이름, 키)이 여러 번이면 기록된 순서대로 소비한다.
+//! - 재생 중 코멘트 생성/수정은 이번 실행이 보낸 본문을 돌려주고 이후 코멘트 목록에도 반영한다.
+//!   claim nonce처럼 실행마다 달라지는 값이 있어도 중복 방지 흐름이 기록과 같게 진행된다.
+
+use std::collections::{HashMap, VecDeque};
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{Context, Result, anyhow, bail};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::domain::diff::LinePosition;
+use crate::domain::marker::{MarkerKind, parse_marker};
+use crate::domain::patch::PatchedFile;
+use crate::domain::review::{
+    CommentAuthor, IssueRef, ProviderResponse, ReviewComment, ReviewRequest, TokenUsage,
+};
+use crate::infrastructure::providers::{ReviewProvider, build_primary_prompt};
+use crate::infrastructure::vcs::VcsProvider;
+
+/// 기록 파일 이름.
+pub const TRAFFIC_FILE: &str = "traffic.jsonl";
+/// 기록 실행의 진행 출력 파일 이름.
+pub const OUTPUT_FILE: &str = "output.txt";
+/// 재생 실행의 진행 출력 파일 이름.
+pub const REPLAY_OUTPUT_FILE: &str = "output.replay.txt";
+const REDACTED: &str = "[REDACTED]";
+// 이보다 짧은 값은 본문의 흔한 단어까지 가릴 수 있어 비밀값으로 취급하지 않는다.
+const MIN_SECRET_LEN: usize = 8;
+
+/// 트래픽 기록/재생 모드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureMode {
+    Record,
+    Replay,
+}
+
+/// 기록 파일의 한 줄(요청 하나와 그 응답).
+#[derive(Debug, Serialize, Deserialize)]
+struct Exchange {
+    /// `vcs` | `provider` | `providers` | `host`
+    channel: String,
+    /// 대상 URL 또는 provider id
+    target: String,
+    call: String,
+    /// 같은 호출을 구분하는 값(코멘트 id, 파일 경로, 청크 번호 등)
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    key: String,
+    /// 요청 본문(코멘트 본문, 프롬프트). 재생 시 비교하지 않고 버그 리포트 참고용으로만 남긴다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    request: Option<String>,
+    response: Outcome,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Outcome {
+    Ok(Value),
+    Err(String),
+}
+
+type ExchangeKey = (String, String, String, String);
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedComment {
+    id: String,
+    body: String,
+    #[serde(default)]
+    author: Option<String>,
+    #[serde(default)]
+    maintainer: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedIssue {
+    number: String,
+    url: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedResponse {
+    content: String,
+    prompt_tokens: Option<u64>,
+    completion_tokens: Option<u64>,
+    total_tokens: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedProvider {
+    id: String,
+    name: String,
+}
+
+/// 한 실행(배치 포함)의 트래픽 기록기/재생기.
+pub struct TrafficCapture {
+    mode: CaptureMode,
+    dir: PathBuf,
+    writer: Mutex<Option<File>>,
+    output: Mutex<Option<File>>,
+    /// 기록 전에 가릴 비밀값(호스트 토큰, provider API 키)
+    secrets: Mutex<Vec<String>>,
+    /// 재생할 응답(호출별 기록 순서)
+    recorded: Mutex<HashMap<ExchangeKey, VecDeque<Outcome>>>,
+    /// 재생 중 이번 실행이 쓴 코멘트 본문((대상 URL, 코멘트 id) → 본문)
+    written_comments: Mutex<HashMap<(String, String), String>>,
+}
+
+impl TrafficCapture {
+    /// 기록이면 `<dir>/traffic.jsonl`을 새로 만들고, 재생이면 기존 기록을 읽는다.
+    pub fn open(mode: CaptureMode, dir: &Path) -> Result<Self> {
+        let path = dir.join(TRAFFIC_FILE);
+        let mut capture = Self {
+            mode,
+            dir: dir.to_path_buf(),
+            writer: Mutex::new(None),
+            output: Mutex::new(None),
+            secrets: Mutex::new(Vec::new()),
+            recorded: Mutex::new(HashMap::new()),
+            written_comments: Mutex::new(HashMap::new()),
+        };
+        match mode {
+            CaptureMode::Record => {
+                fs::create_dir_all(dir)
+                    .with_context(|| format!("failed to create {}", dir.display()))?;
+                let file = File::create(&path)
+                    .with_context(|| format!("failed to create {}", path.display()))?;
+                capture.writer = Mutex::new(Some(file));
+            }
+            CaptureMode::Replay => {
+                let raw = fs::read_to_string(&path).with_context(|| {
+                    format!(
+                        "failed to read {} (record one with --record)",
+                        path.display()
+                    )
+                })?;
+                let mut recorded: HashMap<ExchangeKey, VecDeque<Outcome>> = HashMap::new();
+                for (idx, line) in raw.lines().enumerate() {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+                    let exchange: Exchange = serde_json::from_str(line).with_context(|| {
+                        format!("invalid traffic record at {}:{}", path.display(), idx + 1)
+                    })?;
+                    recorded
+                        .entry((
+                            exchange.channel,
+                            exchange.target,
+                            exchange.call,
+                            exchange.key,
+                        ))
+                        .or_default()
+                        .push_back(exchange.response);
+                }
+                capture.recorded = Mutex::new(recorded);
+            }
+        }
+        let output_path = dir.join(match mode {
+            CaptureMode::Record => OUTPUT_FILE,
+            CaptureMode::Replay => REPLAY_OUTPUT_FILE,
+        });
+        let output = File::create(&output_path)
+            .with_context(|| format!("failed to create {}", output_path.display()))?;
+        capture.output = Mutex::new(Some(output));
+        Ok(capture)
+    }
+
+    pub fn mode(&self) -> CaptureMode {
+        self.mode
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 기록 전에 가릴 비밀값을 등록한다.
+    pub fn add_secret(&self, secret: &str) {
+        let secret = secret.trim();
+        if secret.len() < MIN_SECRET_LEN {
+            return;
+        }
+        let mut secrets = lock(&self.secrets);
+        if !secrets.iter().any(|s| s == secret) {
+            secrets.push(secret.to_string());
+        }
+    }
+
+    /// 진행 출력 한 줄을 남긴다. 출력 기록 실패는 리뷰를 막지 않는다.
+    pub fn record_output(&self, line: &str) {
+        let line = self.redact(line);
+        if let Some(file) = lock(&self.output).as_mut() {
+            let _ = writeln!(file, "{line}");
+        }
+    }
+
+    fn redact(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for secret in lock(&self.secrets).iter() {
+            text = text.replace(secret.as_str(), REDACTED);
+        }
+        text
+    }
+
+    /// VCS 클라이언트를 감싼다. 재생 모드에서는 `inner` 없이 기록된 응답만 쓴다.
+    pub fn wrap_vcs(
+        self: &Arc<Self>,
+        target_url: &str,
+        inner: Option<Box<dyn VcsProvider>>,
+    ) -> Box<dyn VcsProvider> {
+        Box::new(TrafficVcs {
+            capture: Arc::clone(self),
+            target: target_url.to_string(),
+            inner,
+        })
+    }
+
+    /// provider 목록을 감싼다. 재생 모드에서는 설정과 무관하게 기록된 provider를 그대로 만든다.
+    pub fn wrap_providers(
+        self: &Arc<Self>,
+        build: impl FnOnce() -> Vec<Box<dyn ReviewProvider>>,
+    ) -> Result<Vec<Box<dyn ReviewProvider>>> {
+        let providers: Vec<(RecordedProvider, Option<Box<dyn ReviewProvider>>)> = match self.mode {
+            CaptureMode::Record => {
+                let providers = build();
+                let list: Vec<RecordedProvider> = providers
+                    .iter()
+                    .map(|p| RecordedProvider {
+                        id: p.id().to_string(),
+                        name: p.name().to_string(),
+                    })
+                    .collect();
+                self.write(Exchange {
+                    channel: "providers".to_string(),
+                    target: String::new(),
+                    call: "build".to_string(),
+                    key: String::new(),
+                    request: None,
+                    response: Outcome::Ok(serde_json::to_value(list.as_slice())?),
+                })?;
+                list.into_iter()
+                    .zip(providers.into_iter().map(Some))
+                    .collect()
+            }
+            CaptureMode::Replay => {
+                let list: Vec<RecordedProvider> = self.take("providers", "", "build", "")?;
+                list.into_iter().map(|p| (p, None)).collect()
+            }
+        };
+        Ok(providers
+            .into_iter()
+            .map(|(recorded, inner)| {
+                Box::new(TrafficProvider {
+                    capture: Arc::clone(self),
+                    id: recorded.id,
+                    name: recorded.name,
+                    inner,
+                }) as Box<dyn ReviewProvider>
+            })
+            .collect())
+    }
+
+    /// 기록 모드에서는 실제 호출 결과를 남기고, 재생 모드에서는 `live`를 실행하지 않고 기록을 돌려준다.
+    async fn exchange<T, F>(
+        &self,
+        call: (&str, &str, &str, &str),
+        request: Option<&str>,
+        live: F,
+    ) -> Result<T>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Future<Output = Result<T>>,
+    {
+        if self.mode == CaptureMode::Replay {
+            let (channel, target, name, key) = call;
+            return self.take(channel, target, name, key);
+        }
+        self.record(call, request, live.await)
+    }
+
+    /// 동기 호출용 [`Self::exchange`]. 토큰 해석처럼 네트워크 밖의 입력도 같은 방식으로 기록/재생한다.
+    pub fn exchange_blocking<T: Serialize + DeserializeOwned>(
+        &self,
+        call: (&str, &str, &str, &str),
+        live: impl FnOnce() -> Result<T>,
+    ) -> Result<T> {
+        if self.mode == CaptureMode::Replay {
+            let (channel, target, name, key) = call;
+            return self.take(channel, target, name, key);
+        }
+        self.record(call, None, live())
+    }
+
+    fn record<T: Serialize>(
+        &self,
+        call: (&str, &str, &str, &str),
+        request: Option<&str>,
+        result: Result<T>,
+    ) -> Result<T> {
+        let (channel, target, name, key) = call;
+        let response = match &result {
+            Ok(value) => Outcome::Ok(serde_json::to_value(value)?),
+            Err(err) => Outcome::Err(format!("{err:#}")),
+        };
+        self.write(Exchange {
+            channel: channel.to_string(),
+            target: target.to_string(),
+            call: name.to_string(),
+            key: key.to_string(),
+            request: request.map(ToString::to_string),
+            response,
+        })?;
+        result
+    }
+
+    fn take<T: DeserializeOwned>(
+        &self,
+        channel: &str,
+        target: &str,
+        call: &str,
+        key: &str,
+    ) -> Result<T> {
+        let outcome = lock(&self.recorded)
+            .get_mut(&(
+                channel.to_string(),
+                target.to_string(),
+                call.to_string(),
+                key.to_string(),
+            ))
+            .and_then(VecDeque::pop_front);
+        match outcome {
+            Some(Outcome::Ok(value)) => serde_json::from_value(value)
+                .with_context(|| format!("replay: invalid recorded response for {channel} {call}")),
+            Some(Outcome::Err(message)) => Err(anyhow!(message)),
+            None => bail!(
+                "replay: no recorded response for {channel} {call}{} ({target}) in {}",
+                if key.is_empty() {
+                    String::new()
+                } else {
+                    format!(" [{key}]")
+                },
+                self.dir.join(TRAFFIC_FILE).display()
+            ),
+        }
+    }
+
+    fn write(&self, exchange: Exchange) -> Result<()> {
+        let line = self.redact(
+            &serde_json::to_string(&exchange).context("failed to serialize traffic record")?,
+        );
+        let mut writer = lock(&self.writer);
+        if let Some(file) = writer.as_mut() {
+            writeln!(file, "{line}").with_context(|| {
+                format!("failed to write {}", self.dir.join(TRAFFIC_FILE).display())
+            })?;
+        }
+        Ok(())
+    }
+
+    /// 재생 중 쓴 코멘트 본문을 기억하고, 기록된 코멘트 대신 보낸 본문으로 돌려준다.
+    fn echo_written(&self, target: &str, recorded: RecordedComment, body: &str) -> ReviewComment {
+        if self.mode == CaptureMode::Record {
+            return ReviewComment {
+                id: recorded.id,
+                body: recorded.body,
+                author: None,
+            };
+        }
+        lock(&self.written_comments)
+            .insert((target.to_string(), recorded.id.clone()), body.to_string());
+        ReviewComment {
+            id: recorded.id,
+            body: body.to_string(),
+            author: None,
+        }
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 코멘트 생성 호출의 키. 동시에 게시되는 에이전트 코멘트를 구분하도록 마커 종류를 쓴다.
+fn comment_key(body: &str) -> String {
+    match parse_marker(body).map(|marker| marker.kind) {
+        Some(MarkerKind::Claim) => "claim".to_string(),
+        Some(MarkerKind::Final) => "final".to_string(),
+        Some(MarkerKind::Agent(id)) => format!("agent={id}"),
+        Some(MarkerKind::File(path)) => format!("file={path}"),
+        Some(MarkerKind::Part { parent, index }) => format!("part={index} of={parent}"),
+        None => String::new(),
+    }
+}
+
+impl From<IssueRef> for RecordedIssue {
+    fn from(issue: IssueRef) -> Self {
+        Self {
+            number: issue.number,
+            url: issue.url,
+        }
+    }
+}
+
+impl From<RecordedIssue> for IssueRef {
+    fn from(issue: RecordedIssue) -> Self {
+        Self {
+            number: issue.number,
+            url: issue.url,
+        }
+    }
+}
+
+impl From<ReviewComment> for RecordedComment {
+    fn from(comment: ReviewComment) -> Self {
+        let maintainer = comment
+            .author
+            .as_ref()
+            .is_some_and(|author| author.maintainer);
+        Self {
+            id: comment.id,
+            body: comment.body,
+            author: comment.author.map(|author| author.username),
+            maintainer,
+        }
+    }
+}
+
+impl From<ProviderResponse> for RecordedResponse {
+    fn from(response: ProviderResponse) -> Self {
+        Self {
+            content: response.content,
+            prompt_tokens: response.usage.prompt_tokens,
+            completion_tokens: response.usage.completion_tokens,
+            total_tokens: response.usage.total_tokens,
+        }
+    }
+}
+
+impl From<RecordedResponse> for ProviderResponse {
+    fn from(response: RecordedResponse) -> Self {
+        Self {
+            content: response.content,
+            usage: TokenUsage {
+                prompt_tokens: response.prompt_tokens,
+                completion_tokens: response.completion_tokens,
+                total_tokens: response.total_tokens,
+            },
+            findings: Vec::new(),
+        }
+    }
+}
+
+struct TrafficVcs {
+    capture: Arc<TrafficCapture>,
+    target: String,
+    inner: Option<Box<dyn VcsProvider>>,
+}
+
+impl TrafficVcs {
+    fn live(&self) -> Result<&dyn VcsProvider> {
+        self.inner
+            .as_deref()
+            .context("replay: live VCS calls are disabled")
+    }
+
+    fn call<'a>(&'a self, name: &'a str, key: &'a str) -> (&'a str, &'a str, &'a str, &'a str) {
+        ("vcs", &self.target, name, key)
+    }
+
+    /// 기록된 코멘트 목록. 재생 중 이 대상에 쓴 코멘트는 보낸 본문으로 돌려준다.
+    fn with_written(&self, recorded: Vec<RecordedComment>) -> Vec<ReviewComment> {
+        let written = lock(&self.capture.written_comments);
+        recorded
+            .into_iter()
+            .map(|comment| ReviewComment {
+                body: written
+                    .get(&(self.target.clone(), comment.id.clone()))
+                    .cloned()
+                    .unwrap_or(comment.body),
+                id: comment.id,
+                author: comment.author.map(|username| CommentAuthor {
+                    username,
+                    maintainer: comment.maintainer,
+                }),
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl VcsProvider for TrafficVcs {
+    async fn fetch_head_sha(&self) -> Result<String> {
+        self.capture
+            .exchange(self.call("fetch_head_sha", ""), None, async {
+                self.live()?.fetch_head_sha().await
+            })
+            .await
+    }
+
+    async fn fetch_title(&self) -> Result<String> {
+        self.capture
+            .exchange(self.call("fetch_title", ""), None, async {
+                self.live()?.fetch_title().await
+            })
+            .await
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        self.capture
+            .exchange(self.call("fetch_labels", ""), None, async {
+                self.live()?.fetch_labels().await
+            })
+            .await
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        self.capture
+            .exchange(self.call("fetch_description", ""), None, async {
+                self.live()?.fetch_description().await
+            })
+            .await
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        self.capture
+            .exchange(self.call("fetch_commit_messages", ""), None, async {
+                self.live()?.fetch_commit_messages().await
+            })
+            .await
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        self.capture
+            .exchange(self.call("fetch_author", ""), None, async {
+                self.live()?.fetch_author().await
+            })
+            .await
+    }
+
+    async fn fetch_diff(&self) -> Result<String> {
+        self.capture
+            .exchange(self.call("fetch_diff", ""), None, async {
+                self.live()?.fetch_diff().await
+            })
+            .await
+    }
+
+    async fn list_comments(&self) -> Result<Vec<ReviewComment>> {
+        let recorded: Vec<RecordedComment> = self
+            .capture
+            .exchange(self.call("list_comments", ""), None, async {
+                let comments = self.live()?.list_comments().await?;
+                Ok(comments.into_iter().map(RecordedComment::from).collect())
+            })
+            .await?;
+        Ok(self.with_written(recorded))
+    }
+
+    async fn create_comment(&self, body: &str) -> Result<ReviewComment> {
+        let key = comment_key(body);
+        let recorded: RecordedComment = self
+            .capture
+            .exchange(self.call("create_comment", &key), Some(body), async {
+                Ok(self.live()?.create_comment(body).await?.into())
+            })
+            .await?;
+        Ok(self.capture.echo_written(&self.target, recorded, body))
+    }
+
+    async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        let recorded: RecordedComment = self
+            .capture
+            .exchange(self.call("update_comment", comment_id), Some(body), async {
+                Ok(self.live()?.update_comment(comment_id, body).await?.into())
+            })
+            .await?;
+        Ok(self.capture.echo_written(&self.target, recorded, body))
+    }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let recorded: RecordedComment = self
+            .capture
+            .exchange(self.call("create_file_comment", path), Some(body), async {
+                Ok(self
+                    .live()?
+                    .create_file_comment(head_sha, path, body)
+                    .await?
+                    .into())
+            })
+            .await?;
+        Ok(self.capture.echo_written(&self.target, recorded, body))
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let key = format!("{path}:{}-{}", position.start, position.end);
+        let recorded: RecordedComment = self
+            .capture
+            .exchange(self.call("create_line_comment", &key), Some(body), async {
+                Ok(self
+                    .live()?
+                    .create_line_comment(head_sha, path, position, body)
+                    .await?
+                    .into())
+            })
+            .await?;
+        Ok(self.capture.echo_written(&self.target, recorded, body))
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        let recorded: Vec<RecordedComment> = self
+            .capture
+            .exchange(self.call("list_review_comments", ""), None, async {
+                let comments = self.live()?.list_review_comments().await?;
+                Ok(comments.into_iter().map(RecordedComment::from).collect())
+            })
+            .await?;
+        Ok(self.with_written(recorded))
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        let recorded: RecordedComment = self
+            .capture
+            .exchange(
+                self.call("update_review_comment", comment_id),
+                Some(body),
+                async {
+                    Ok(self
+                        .live()?
+                        .update_review_comment(comment_id, body)
+                        .await?
+                        .into())
+                },
+            )
+            .await?;
+        Ok(self.capture.echo_written(&self.target, recorded, body))
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        self.capture
+            .exchange(self.call("acknowledge_comment", comment_id), None, async {
+                self.live()?.acknowledge_comment(comment_id).await
+            })
+            .await
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        self.capture
+            .exchange(
+                self.call("request_reviewers", &usernames.join(",")),
+                None,
+                async { self.live()?.request_reviewers(usernames).await },
+            )
+            .await
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        let recorded: RecordedIssue = self
+            .capture
+            .exchange(self.call("create_issue", title), Some(body), async {
+                Ok(self.live()?.create_issue(title, body).await?.into())
+            })
+            .await?;
+        Ok(recorded.into())
+    }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        self.capture
+            .exchange(self.call("fetch_repository_file", path), None, async {
+                self.live()?.fetch_repository_file(path).await
+            })
+            .await
+    }
+
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>> {
+        let key = format!("{path}@{sha}");
+        self.capture
+            .exchange(self.call("fetch_file_at", &key), None, async {
+                self.live()?.fetch_file_at(path, sha).await
+            })
+            .await
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        let key = format!("{path}#{limit}");
+        self.capture
+            .exchange(self.call("fetch_recent_authors", &key), None, async {
+                self.live()?.fetch_recent_authors(path, limit).await
+            })
+            .await
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        self.capture
+            .exchange(self.call("push_branch", branch), Some(message), async {
+                self.live()?
+                    .push_branch(branch, base_sha, message, files)
+                    .await
+            })
+            .await
+    }
+
+    async fn check_token_access(&self, write: bool) -> Result<()> {
+        let key = if write { "write" } else { "read" };
+        self.capture
+            .exchange(self.call("check_token_access", key), None, async {
+                self.live()?.check_token_access(write).await
+            })
+            .await
+    }
+}
+
+struct TrafficProvider {
+    capture: Arc<TrafficCapture>,
+    id: String,
+    name: String,
+    inner: Option<Box<dyn ReviewProvider>>,
+}
+
+impl TrafficProvider {
+    fn live(&self) -> Result<&dyn ReviewProvider> {
+        self.inner
+            .as_deref()
+            .context("replay: live provider calls are disabled")
+    }
+}
+
+#[async_trait]
+impl ReviewProvider for TrafficProvider {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
+        // 청크는 동시에 실행되므로 청크 번호로 구분한다.
+        let key = format!("chunk {}/{}", request.chunk_index, request.chunk_total);
+        let prompt = match self.capture.mode() {
+            CaptureMode::Record => Some(build_primary_prompt(request)?),
+            CaptureMode::Replay => None,
+        };
+        let recorded: RecordedResponse = self
+            .capture
+            .exchange(
+                ("provider", &self.id, "review", &key),
+                prompt.as_deref(),
+                async { Ok(self.live()?.review(request).await?.into()) },
+            )
+            .await?;
+        Ok(recorded.into())
+    }
+
+    async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse> {
+        let recorded: RecordedResponse = self
+            .capture
+            .exchange(
+                ("provider", &self.id, "review_prompt", ""),
+                Some(prompt),
+                async { Ok(self.live()?.review_prompt(prompt).await?.into()) },
+            )
+            .await?;
+        Ok(recorded.into())
+    }
+}
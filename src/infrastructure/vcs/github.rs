@@ -1,15 +1,29 @@
 //! GitHub API 연동 구현.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
-use reqwest::{Client, Method, RequestBuilder};
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
 use serde::Deserialize;
-use serde_json::json;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use crate::domain::review::CommentAuthor;
+use crate::infrastructure::http::{
+    ConditionalCache, HttpClients, HttpProfile, attributed, following_page_url,
+};
 
-use super::{ReviewComment, VcsProvider};
+use super::{
+    IssueRef, LIST_PAGE_SIZE, LinePosition, MAX_LIST_PAGES, OpenReview, PatchedFile,
+    RepositoryProvider, ReviewComment, VcsProvider,
+};
 
 pub struct GitHubClient {
     client: Client,
+    /// 코멘트 목록 조건부 조회(ETag) 캐시
+    conditional: ConditionalCache,
     host: String,
     owner: String,
     repo: String,
@@ -21,6 +35,7 @@ pub struct GitHubClient {
 impl GitHubClient {
     /// GitHub 대상 클라이언트를 생성한다.
     pub fn new(
+        http: &HttpClients,
         host: String,
         owner: String,
         repo: String,
@@ -29,7 +44,8 @@ impl GitHubClient {
         api_base: Option<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: http.client(HttpProfile::VCS),
+            conditional: http.conditional_cache(),
             host,
             owner,
             repo,
@@ -40,15 +56,7 @@ impl GitHubClient {
     }
 
     fn api_base(&self) -> String {
-        // github.com은 공개 API, 그 외는 Enterprise 기본 경로를 사용한다.
-        if let Some(base) = &self.api_base {
-            return base.trim_end_matches('/').to_string();
-        }
-        if self.host == "github.com" {
-            "https://api.github.com".to_string()
-        } else {
-            format!("https://{}/api/v3", self.host)
-        }
+        api_base(&self.host, self.api_base.as_deref())
     }
 
     fn pulls_endpoint(&self) -> String {
@@ -61,6 +69,20 @@ impl GitHubClient {
         )
     }
 
+    fn review_comments_endpoint(&self) -> String {
+        format!("{}/comments", self.pulls_endpoint())
+    }
+
+    fn review_comment_endpoint(&self, comment_id: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/pulls/comments/{}",
+            self.api_base(),
+            self.owner,
+            self.repo,
+            comment_id
+        )
+    }
+
     fn issue_comments_endpoint(&self) -> String {
         format!(
             "{}/repos/{}/{}/issues/{}/comments",
@@ -81,13 +103,48 @@ impl GitHubClient {
         )
     }
 
+    fn issues_endpoint(&self) -> String {
+        format!(
+            "{}/repos/{}/{}/issues",
+            self.api_base(),
+            self.owner,
+            self.repo
+        )
+    }
+
+    fn contents_endpoint(&self, path: &str) -> String {
+        let encoded = path
+            .trim_start_matches('/')
+            .split('/')
+            .map(|segment| utf8_percent_encode(segment, NON_ALPHANUMERIC).to_string())
+            .collect::<Vec<_>>()
+            .join("/");
+        format!(
+            "{}/repos/{}/{}/contents/{}",
+            self.api_base(),
+            self.owner,
+            self.repo,
+            encoded
+        )
+    }
+
+    fn git_endpoint(&self, path: &str) -> String {
+        format!(
+            "{}/repos/{}/{}/git/{}",
+            self.api_base(),
+            self.owner,
+            self.repo,
+            path
+        )
+    }
+
     fn request(&self, method: Method, url: String) -> RequestBuilder {
+        self.request_with_accept(method, url, "application/vnd.github+json")
+    }
+
+    fn request_with_accept(&self, method: Method, url: String, accept: &str) -> RequestBuilder {
         // 공통 헤더/인증 적용.
-        let req = self
-            .client
-            .request(method, url)
-            .header("User-Agent", "repopilot")
-            .header("Accept", "application/vnd.github+json");
+        let req = attributed(self.client.request(method, url)).header("Accept", accept);
 
         if let Some(token) = &self.token {
             req.bearer_auth(token)
@@ -95,11 +152,288 @@ impl GitHubClient {
             req
         }
     }
+
+    async fn fetch_pull(&self) -> Result<PullResponse> {
+        let resp = self
+            .request(Method::GET, self.pulls_endpoint())
+            .send()
+            .await
+            .context("github: failed to fetch PR")?;
+
+        let status = resp.status();
+        let body = resp.text().await.context("github: failed to read PR body")?;
+        if !status.is_success() {
+            anyhow::bail!("github: failed to fetch PR metadata ({status}): {body}");
+        }
+
+        serde_json::from_str(&body).context("github: invalid PR JSON")
+    }
+
+    /// contents API로 파일 원문을 읽는다. `git_ref`를 생략하면 저장소 기본 브랜치 기준이다.
+    async fn fetch_contents(&self, path: &str, git_ref: Option<&str>) -> Result<Option<String>> {
+        let mut url = self.contents_endpoint(path);
+        if let Some(git_ref) = git_ref {
+            url = format!(
+                "{url}?ref={}",
+                utf8_percent_encode(git_ref, NON_ALPHANUMERIC)
+            );
+        }
+        let resp = self
+            .request_with_accept(Method::GET, url, "application/vnd.github.raw")
+            .send()
+            .await
+            .with_context(|| format!("github: failed to fetch {path}"))?;
+
+        let status = resp.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = resp
+            .text()
+            .await
+            .with_context(|| format!("github: failed to read {path}"))?;
+
+        if !status.is_success() {
+            anyhow::bail!("github: failed to fetch {path} ({status}): {body}");
+        }
+        Ok(Some(body))
+    }
+
+    /// 목록 API를 `Link: rel="next"`를 따라 마지막 페이지까지 조회한다.
+    /// watch 모드처럼 반복 조회할 때 바뀌지 않은 페이지는 304로 받는다.
+    async fn list_all<T: DeserializeOwned>(&self, endpoint: String, what: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut next = Some(format!("{endpoint}?per_page={LIST_PAGE_SIZE}"));
+        let mut pages = 0;
+        while let Some(url) = next {
+            if pages == MAX_LIST_PAGES {
+                tracing::warn!(
+                    url,
+                    "github: stopped listing {what} after {MAX_LIST_PAGES} pages"
+                );
+                break;
+            }
+            pages += 1;
+            let response = self
+                .conditional
+                .send(&url, self.request(Method::GET, url.clone()))
+                .await
+                .with_context(|| format!("github: failed to list {what}"))?;
+            if !response.status.is_success() {
+                anyhow::bail!(
+                    "github: failed to list {what} ({}): {}",
+                    response.status,
+                    response.body
+                );
+            }
+            let page: Vec<T> = serde_json::from_str(&response.body)
+                .with_context(|| format!("github: invalid {what} JSON"))?;
+            // 304로 받은 꽉 찬 마지막 페이지 뒤에는 그 사이 새 페이지가 생겼을 수 있다.
+            next = response.next_page.or_else(|| {
+                (response.not_modified && page.len() == LIST_PAGE_SIZE)
+                    .then(|| following_page_url(&url))
+                    .flatten()
+            });
+            items.extend(page);
+        }
+        Ok(items)
+    }
+
+    /// git data API를 호출하고 JSON 응답을 읽는다.
+    async fn send_git_data<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        url: String,
+        body: Option<Value>,
+        action: &str,
+    ) -> Result<T> {
+        let mut req = self.request(method, url);
+        if let Some(body) = body {
+            req = req.json(&body);
+        }
+        let resp = req
+            .send()
+            .await
+            .with_context(|| format!("github: failed to {action}"))?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .with_context(|| format!("github: failed to read {action} body"))?;
+        if !status.is_success() {
+            anyhow::bail!("github: failed to {action} ({status}): {response_body}");
+        }
+        serde_json::from_str(&response_body)
+            .with_context(|| format!("github: invalid {action} JSON"))
+    }
+
+    /// base 트리에서 각 경로의 파일 모드(`100755` 실행 파일, `120000` 심볼릭 링크 등)를 찾는다.
+    /// 저장소 전체가 아니라 경로가 지나는 디렉터리 트리만 한 단계씩 읽는다. base에 없는 경로는 빠진다.
+    async fn base_file_modes(
+        &self,
+        root_tree: &str,
+        paths: &[&str],
+    ) -> Result<HashMap<String, String>> {
+        let mut trees: HashMap<String, Vec<GitTreeEntry>> = HashMap::new();
+        let mut modes = HashMap::new();
+        'paths: for path in paths {
+            let mut tree_sha = root_tree.to_string();
+            let mut names = path.trim_start_matches('/').split('/').peekable();
+            while let Some(name) = names.next() {
+                if !trees.contains_key(&tree_sha) {
+                    let tree: GitTreeResponse = self
+                        .send_git_data(
+                            Method::GET,
+                            self.git_endpoint(&format!("trees/{tree_sha}")),
+                            None,
+                            "fetch base tree",
+                        )
+                        .await?;
+                    trees.insert(tree_sha.clone(), tree.tree);
+                }
+                let Some(entry) = trees[&tree_sha].iter().find(|entry| entry.path == name) else {
+                    continue 'paths;
+                };
+                if names.peek().is_none() {
+                    modes.insert(path.to_string(), entry.mode.clone());
+                } else if entry.kind == "tree" {
+                    tree_sha = entry.sha.clone();
+                } else {
+                    continue 'paths;
+                }
+            }
+        }
+        Ok(modes)
+    }
+}
+
+pub(crate) fn api_base(host: &str, api_base: Option<&str>) -> String {
+    // github.com은 공개 API, 그 외는 Enterprise 기본 경로를 사용한다.
+    if let Some(base) = api_base {
+        return base.trim_end_matches('/').to_string();
+    }
+    if host == "github.com" {
+        "https://api.github.com".to_string()
+    } else {
+        format!("https://{host}/api/v3")
+    }
+}
+
+/// 저장소 단위 GitHub 조회 클라이언트.
+pub struct GitHubRepositoryClient {
+    client: Client,
+    host: String,
+    owner: String,
+    repo: String,
+    token: Option<String>,
+    api_base: Option<String>,
+}
+
+impl GitHubRepositoryClient {
+    pub fn new(
+        client: Client,
+        host: String,
+        owner: String,
+        repo: String,
+        token: Option<String>,
+        api_base: Option<String>,
+    ) -> Self {
+        Self {
+            client,
+            host,
+            owner,
+            repo,
+            token,
+            api_base,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenPullResponse {
+    number: u64,
+    html_url: String,
+    #[serde(default)]
+    title: String,
+}
+
+#[async_trait]
+impl RepositoryProvider for GitHubRepositoryClient {
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>> {
+        let url = format!(
+            "{}/repos/{}/{}/pulls?state=open&sort=updated&direction=desc&per_page={}",
+            api_base(&self.host, self.api_base.as_deref()),
+            self.owner,
+            self.repo,
+            limit.clamp(1, 100)
+        );
+        let mut req =
+            attributed(self.client.get(url)).header("Accept", "application/vnd.github+json");
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+
+        let resp = req
+            .send()
+            .await
+            .context("github: failed to list open PRs")?;
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("github: failed to read PR list body")?;
+        if !status.is_success() {
+            anyhow::bail!("github: failed to list open PRs ({status}): {body}");
+        }
+
+        let pulls: Vec<OpenPullResponse> =
+            serde_json::from_str(&body).context("github: invalid PR list JSON")?;
+        Ok(pulls
+            .into_iter()
+            .map(|pull| OpenReview {
+                url: pull.html_url,
+                number: pull.number,
+                title: pull.title,
+            })
+            .collect())
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct PullResponse {
     head: PullHead,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    labels: Vec<LabelResponse>,
+    body: Option<String>,
+    user: Option<UserResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserResponse {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitResponse {
+    author: Option<UserResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LabelResponse {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullCommitResponse {
+    commit: PullCommitDetail,
+}
+
+#[derive(Debug, Deserialize)]
+struct PullCommitDetail {
+    message: String,
 }
 
 #[derive(Debug, Deserialize)]
@@ -107,37 +441,128 @@ struct PullHead {
     sha: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct RepositoryResponse {
+    #[serde(default)]
+    private: bool,
+}
+
 #[derive(Debug, Deserialize)]
 struct IssueCommentResponse {
     id: u64,
     body: String,
+    user: Option<UserResponse>,
+    /// 저장소와 작성자의 관계(`OWNER`, `MEMBER`, `COLLABORATOR`, `CONTRIBUTOR`, `NONE` 등)
+    #[serde(default)]
+    author_association: String,
+}
+
+/// 목록으로 받은 코멘트. `OWNER`/`MEMBER`/`COLLABORATOR` 작성자를 저장소 관리자로 본다.
+fn listed_comment(comment: IssueCommentResponse) -> ReviewComment {
+    let maintainer = matches!(
+        comment.author_association.as_str(),
+        "OWNER" | "MEMBER" | "COLLABORATOR"
+    );
+    ReviewComment {
+        id: comment.id.to_string(),
+        body: comment.body,
+        author: comment.user.map(|user| CommentAuthor {
+            username: user.login,
+            maintainer,
+        }),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueResponse {
+    number: u64,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitObject {
+    sha: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitCommitResponse {
+    tree: GitObject,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitTreeResponse {
+    tree: Vec<GitTreeEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitTreeEntry {
+    path: String,
+    mode: String,
+    #[serde(rename = "type")]
+    kind: String,
+    sha: String,
 }
 
 #[async_trait]
 impl VcsProvider for GitHubClient {
     async fn fetch_head_sha(&self) -> Result<String> {
+        Ok(self.fetch_pull().await?.head.sha)
+    }
+
+    async fn fetch_title(&self) -> Result<String> {
+        Ok(self.fetch_pull().await?.title)
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        Ok(self
+            .fetch_pull()
+            .await?
+            .labels
+            .into_iter()
+            .map(|label| label.name)
+            .collect())
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        Ok(self.fetch_pull().await?.body.unwrap_or_default())
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        // PR 커밋 API는 최대 250개까지 오래된 순으로 돌려준다. 메타 리뷰에는 첫 페이지(100개)면 충분하다.
         let resp = self
-            .request(Method::GET, self.pulls_endpoint())
+            .request(
+                Method::GET,
+                format!("{}/commits?per_page=100", self.pulls_endpoint()),
+            )
             .send()
             .await
-            .context("github: failed to fetch PR")?;
+            .context("github: failed to list PR commits")?;
 
         let status = resp.status();
-        let body = resp.text().await.context("github: failed to read PR body")?;
+        let body = resp
+            .text()
+            .await
+            .context("github: failed to read PR commits body")?;
         if !status.is_success() {
-            anyhow::bail!("github: failed to fetch PR metadata ({status}): {body}");
+            anyhow::bail!("github: failed to list PR commits ({status}): {body}");
         }
 
-        let pr: PullResponse = serde_json::from_str(&body).context("github: invalid PR JSON")?;
-        Ok(pr.head.sha)
+        let commits: Vec<PullCommitResponse> =
+            serde_json::from_str(&body).context("github: invalid PR commits JSON")?;
+        Ok(commits.into_iter().map(|c| c.commit.message).collect())
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        self.fetch_pull()
+            .await?
+            .user
+            .map(|user| user.login)
+            .context("github: PR has no author")
     }
 
     async fn fetch_diff(&self) -> Result<String> {
         // PR endpoint에 diff Accept 헤더를 적용해 unified diff를 가져온다.
-        let mut req = self
-            .client
-            .get(self.pulls_endpoint())
-            .header("User-Agent", "repopilot")
+        let mut req = attributed(self.client.get(self.pulls_endpoint()))
             .header("Accept", "application/vnd.github.v3.diff");
         if let Some(token) = &self.token {
             req = req.bearer_auth(token);
@@ -162,32 +587,11 @@ impl VcsProvider for GitHubClient {
     }
 
     async fn list_comments(&self) -> Result<Vec<ReviewComment>> {
-        let resp = self
-            .request(Method::GET, self.issue_comments_endpoint())
-            .send()
-            .await
-            .context("github: failed to list comments")?;
-
-        let status = resp.status();
-        let body = resp
-            .text()
-            .await
-            .context("github: failed to read comments body")?;
-
-        if !status.is_success() {
-            anyhow::bail!("github: failed to list comments ({status}): {body}");
-        }
-
-        let comments: Vec<IssueCommentResponse> =
-            serde_json::from_str(&body).context("github: invalid comments JSON")?;
-
-        Ok(comments
-            .into_iter()
-            .map(|c| ReviewComment {
-                id: c.id.to_string(),
-                body: c.body,
-            })
-            .collect())
+        // 명령/마커 코멘트가 첫 페이지(기본 30개) 밖에 있어도 찾도록 모든 페이지를 읽는다.
+        let comments: Vec<IssueCommentResponse> = self
+            .list_all(self.issue_comments_endpoint(), "comments")
+            .await?;
+        Ok(comments.into_iter().map(listed_comment).collect())
     }
 
     async fn create_comment(&self, body: &str) -> Result<ReviewComment> {
@@ -213,6 +617,7 @@ impl VcsProvider for GitHubClient {
         Ok(ReviewComment {
             id: comment.id.to_string(),
             body: comment.body,
+            author: None,
         })
     }
 
@@ -240,6 +645,411 @@ impl VcsProvider for GitHubClient {
         Ok(ReviewComment {
             id: comment.id.to_string(),
             body: comment.body,
+            author: None,
         })
     }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        // 라인 없이 파일 자체에 다는 리뷰 코멘트(subject_type=file)로 스레드를 만든다.
+        let resp = self
+            .request(Method::POST, self.review_comments_endpoint())
+            .json(&json!({
+                "body": body,
+                "commit_id": head_sha,
+                "path": path,
+                "subject_type": "file",
+            }))
+            .send()
+            .await
+            .context("github: failed to create file comment")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("github: failed to read create-file-comment body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("github: failed to create file comment ({status}): {response_body}");
+        }
+
+        let comment: IssueCommentResponse = serde_json::from_str(&response_body)
+            .context("github: invalid create-file-comment JSON")?;
+
+        Ok(ReviewComment {
+            id: comment.id.to_string(),
+            body: comment.body,
+            author: None,
+        })
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let mut payload = json!({
+            "body": body,
+            "commit_id": head_sha,
+            "path": path,
+            "line": position.end,
+            "side": "RIGHT",
+        });
+        if position.start < position.end {
+            payload["start_line"] = json!(position.start);
+            payload["start_side"] = json!("RIGHT");
+        }
+        let resp = self
+            .request(Method::POST, self.review_comments_endpoint())
+            .json(&payload)
+            .send()
+            .await
+            .context("github: failed to create line comment")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("github: failed to read create-line-comment body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("github: failed to create line comment ({status}): {response_body}");
+        }
+
+        let comment: IssueCommentResponse = serde_json::from_str(&response_body)
+            .context("github: invalid create-line-comment JSON")?;
+
+        Ok(ReviewComment {
+            id: comment.id.to_string(),
+            body: comment.body,
+            author: None,
+        })
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        let comments: Vec<IssueCommentResponse> = self
+            .list_all(self.review_comments_endpoint(), "review comments")
+            .await?;
+        Ok(comments.into_iter().map(listed_comment).collect())
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        let resp = self
+            .request(Method::PATCH, self.review_comment_endpoint(comment_id))
+            .json(&json!({ "body": body }))
+            .send()
+            .await
+            .context("github: failed to update review comment")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("github: failed to read update-review-comment body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("github: failed to update review comment ({status}): {response_body}");
+        }
+
+        let comment: IssueCommentResponse = serde_json::from_str(&response_body)
+            .context("github: invalid update-review-comment JSON")?;
+
+        Ok(ReviewComment {
+            id: comment.id.to_string(),
+            body: comment.body,
+            author: None,
+        })
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::POST,
+                format!("{}/reactions", self.issue_comment_endpoint(comment_id)),
+            )
+            .json(&json!({ "content": "+1" }))
+            .send()
+            .await
+            .context("github: failed to add reaction")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("github: failed to add reaction ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        // `org/team`은 팀 리뷰어(slug)로, 나머지는 사용자로 요청한다. 기존 요청은 그대로 남는다.
+        let (teams, users): (Vec<&String>, Vec<&String>) =
+            usernames.iter().partition(|name| name.contains('/'));
+        let team_slugs: Vec<&str> = teams
+            .iter()
+            .filter_map(|team| team.rsplit('/').next())
+            .collect();
+        let resp = self
+            .request(
+                Method::POST,
+                format!("{}/requested_reviewers", self.pulls_endpoint()),
+            )
+            .json(&json!({ "reviewers": users, "team_reviewers": team_slugs }))
+            .send()
+            .await
+            .context("github: failed to request reviewers")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("github: failed to request reviewers ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        let resp = self
+            .request(Method::POST, self.issues_endpoint())
+            .json(&json!({ "title": title, "body": body }))
+            .send()
+            .await
+            .context("github: failed to create issue")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("github: failed to read create-issue body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("github: failed to create issue ({status}): {response_body}");
+        }
+
+        let issue: IssueResponse =
+            serde_json::from_str(&response_body).context("github: invalid create-issue JSON")?;
+        Ok(IssueRef {
+            number: issue.number.to_string(),
+            url: issue.html_url,
+        })
+    }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        self.fetch_contents(path, None).await
+    }
+
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>> {
+        self.fetch_contents(path, Some(sha)).await
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        // sha를 생략하면 저장소 기본 브랜치의 커밋 이력을 최신순으로 돌려준다.
+        let url = format!(
+            "{}/repos/{}/{}/commits?path={}&per_page={}",
+            self.api_base(),
+            self.owner,
+            self.repo,
+            utf8_percent_encode(path.trim_start_matches('/'), NON_ALPHANUMERIC),
+            limit.clamp(1, 100)
+        );
+        let resp = self
+            .request(Method::GET, url)
+            .send()
+            .await
+            .with_context(|| format!("github: failed to list commits for {path}"))?;
+
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("github: failed to read commits body")?;
+        if !status.is_success() {
+            anyhow::bail!("github: failed to list commits for {path} ({status}): {body}");
+        }
+
+        let commits: Vec<CommitResponse> =
+            serde_json::from_str(&body).context("github: invalid commits JSON")?;
+        let mut authors: Vec<String> = Vec::new();
+        for login in commits
+            .into_iter()
+            .filter_map(|c| c.author)
+            .map(|a| a.login)
+        {
+            if !login.ends_with("[bot]") && !authors.contains(&login) {
+                authors.push(login);
+            }
+        }
+        Ok(authors)
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        // git data API로 base 커밋의 트리 위에 변경 파일만 얹은 트리/커밋을 만든다.
+        let base: GitCommitResponse = self
+            .send_git_data(
+                Method::GET,
+                self.git_endpoint(&format!("commits/{base_sha}")),
+                None,
+                "fetch base commit",
+            )
+            .await?;
+        // 기존 파일은 base 트리의 모드(실행 권한, 심볼릭 링크)를 그대로 쓰고, 새 파일만 일반 파일로 만든다.
+        let paths: Vec<&str> = files
+            .iter()
+            .filter(|file| !file.created)
+            .map(|file| file.path.as_str())
+            .collect();
+        let modes = self.base_file_modes(&base.tree.sha, &paths).await?;
+        let entries: Vec<Value> = files
+            .iter()
+            .map(|file| {
+                let mode = modes.get(&file.path).map_or("100644", String::as_str);
+                match &file.content {
+                    Some(content) => json!({
+                        "path": file.path,
+                        "mode": mode,
+                        "type": "blob",
+                        "content": content,
+                    }),
+                    // sha를 null로 두면 트리에서 파일을 지운다.
+                    None => json!({
+                        "path": file.path,
+                        "mode": mode,
+                        "type": "blob",
+                        "sha": null,
+                    }),
+                }
+            })
+            .collect();
+        let tree: GitObject = self
+            .send_git_data(
+                Method::POST,
+                self.git_endpoint("trees"),
+                Some(json!({ "base_tree": base.tree.sha, "tree": entries })),
+                "create tree",
+            )
+            .await?;
+        let commit: GitObject = self
+            .send_git_data(
+                Method::POST,
+                self.git_endpoint("commits"),
+                Some(json!({ "message": message, "tree": tree.sha, "parents": [base_sha] })),
+                "create commit",
+            )
+            .await?;
+
+        // 브랜치가 이미 있으면 강제로 옮기고, 없으면(422) 새로 만든다.
+        let resp = self
+            .request(
+                Method::PATCH,
+                self.git_endpoint(&format!("refs/heads/{branch}")),
+            )
+            .json(&json!({ "sha": commit.sha, "force": true }))
+            .send()
+            .await
+            .context("github: failed to update branch")?;
+        let status = resp.status();
+        if status == StatusCode::UNPROCESSABLE_ENTITY || status == StatusCode::NOT_FOUND {
+            let _: Value = self
+                .send_git_data(
+                    Method::POST,
+                    self.git_endpoint("refs"),
+                    Some(json!({ "ref": format!("refs/heads/{branch}"), "sha": commit.sha })),
+                    "create branch",
+                )
+                .await?;
+        } else if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("github: failed to update branch ({status}): {body}");
+        }
+
+        Ok(format!(
+            "https://{}/{}/{}/tree/{}",
+            self.host, self.owner, self.repo, branch
+        ))
+    }
+
+    async fn check_token_access(&self, write: bool) -> Result<()> {
+        if self.token.is_none() {
+            return Ok(());
+        }
+        let repository = format!("{}/{}", self.owner, self.repo);
+
+        let resp = self
+            .request(Method::GET, format!("{}/user", self.api_base()))
+            .send()
+            .await
+            .context("github: failed to check token")?;
+        // GitHub App 설치 토큰은 /user에 403을 받으므로 401만 토큰 오류로 본다.
+        if resp.status() == StatusCode::UNAUTHORIZED {
+            anyhow::bail!(
+                "github: token for {} is invalid or expired (401 on /user)",
+                self.host
+            );
+        }
+        // classic PAT/OAuth 토큰만 scope 헤더가 있다(fine-grained 토큰은 확인할 수 없음).
+        let scopes: Option<Vec<String>> = resp
+            .headers()
+            .get("x-oauth-scopes")
+            .and_then(|value| value.to_str().ok())
+            .map(|value| {
+                value
+                    .split(',')
+                    .map(|scope| scope.trim().to_string())
+                    .filter(|scope| !scope.is_empty())
+                    .collect()
+            });
+
+        let resp = self
+            .request(
+                Method::GET,
+                format!("{}/repos/{repository}", self.api_base()),
+            )
+            .send()
+            .await
+            .context("github: failed to check repository access")?;
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("github: failed to read repository body")?;
+        if status == StatusCode::NOT_FOUND || status == StatusCode::FORBIDDEN {
+            anyhow::bail!("github: token cannot access {repository} ({status})");
+        }
+        if !status.is_success() {
+            anyhow::bail!("github: failed to check repository access ({status}): {body}");
+        }
+        let info: RepositoryResponse =
+            serde_json::from_str(&body).context("github: invalid repository JSON")?;
+
+        if write && let Some(scopes) = scopes {
+            let has_scope = |name: &str| scopes.iter().any(|scope| scope == name);
+            if !has_scope("repo") && (info.private || !has_scope("public_repo")) {
+                let needed = if info.private {
+                    "`repo`"
+                } else {
+                    "`repo` or `public_repo`"
+                };
+                anyhow::bail!(
+                    "github: token lacks write access to {repository} (scopes: {}; needs {needed})",
+                    if scopes.is_empty() {
+                        "none".to_string()
+                    } else {
+                        scopes.join(", ")
+                    }
+                );
+            }
+        }
+        Ok(())
+    }
 }
@@ -1,16 +1,32 @@
 //! GitLab API 연동 구현.
 
+use std::collections::HashMap;
+
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
-use reqwest::{Client, Method, RequestBuilder};
+use reqwest::{Client, Method, RequestBuilder, StatusCode};
 use serde::Deserialize;
 use serde_json::json;
 
-use super::{ReviewComment, VcsProvider};
+use crate::domain::bot_command::BOT_COMMAND_PREFIX;
+use crate::domain::review::CommentAuthor;
+use crate::infrastructure::http::{
+    ConditionalCache, HttpClients, HttpProfile, attributed, following_page_url,
+};
+
+use super::{
+    IssueRef, LIST_PAGE_SIZE, LinePosition, MAX_LIST_PAGES, OpenReview, PatchedFile,
+    RepositoryProvider, ReviewComment, VcsProvider,
+};
+
+/// GitLab Developer 권한(push 가능) 접근 수준.
+const DEVELOPER_ACCESS_LEVEL: u64 = 30;
 
 pub struct GitLabClient {
     client: Client,
+    /// 코멘트 목록 조건부 조회(ETag) 캐시
+    conditional: ConditionalCache,
     host: String,
     project_path: String,
     iid: u64,
@@ -21,6 +37,7 @@ pub struct GitLabClient {
 impl GitLabClient {
     /// GitLab 대상 클라이언트를 생성한다.
     pub fn new(
+        http: &HttpClients,
         host: String,
         project_path: String,
         iid: u64,
@@ -28,7 +45,8 @@ impl GitLabClient {
         api_base: Option<String>,
     ) -> Self {
         Self {
-            client: Client::new(),
+            client: http.client(HttpProfile::VCS),
+            conditional: http.conditional_cache(),
             host,
             project_path,
             iid,
@@ -38,20 +56,11 @@ impl GitLabClient {
     }
 
     fn api_base(&self) -> String {
-        // gitlab.com은 공개 API, 그 외는 self-hosted 기본 경로를 사용한다.
-        if let Some(base) = &self.api_base {
-            return base.trim_end_matches('/').to_string();
-        }
-        if self.host == "gitlab.com" {
-            "https://gitlab.com/api/v4".to_string()
-        } else {
-            format!("https://{}/api/v4", self.host)
-        }
+        api_base(&self.host, self.api_base.as_deref())
     }
 
     fn encoded_project_path(&self) -> String {
-        // /projects/{path} API 규격에 맞춰 경로를 URL 인코딩한다.
-        utf8_percent_encode(&self.project_path, NON_ALPHANUMERIC).to_string()
+        encode_project_path(&self.project_path)
     }
 
     fn merge_request_endpoint(&self) -> String {
@@ -67,33 +76,386 @@ impl GitLabClient {
         format!("{}/changes", self.merge_request_endpoint())
     }
 
+    fn merge_request_commits_endpoint(&self) -> String {
+        format!("{}/commits?per_page=100", self.merge_request_endpoint())
+    }
+
     fn notes_endpoint(&self) -> String {
         format!("{}/notes", self.merge_request_endpoint())
     }
 
+    fn discussions_endpoint(&self) -> String {
+        format!("{}/discussions", self.merge_request_endpoint())
+    }
+
     fn note_endpoint(&self, note_id: &str) -> String {
         format!("{}/{}", self.notes_endpoint(), note_id)
     }
 
+    fn issues_endpoint(&self) -> String {
+        format!(
+            "{}/projects/{}/issues",
+            self.api_base(),
+            self.encoded_project_path()
+        )
+    }
+
+    fn commits_endpoint(&self) -> String {
+        format!(
+            "{}/projects/{}/repository/commits",
+            self.api_base(),
+            self.encoded_project_path()
+        )
+    }
+
+    fn raw_file_endpoint(&self, path: &str) -> String {
+        // ref를 생략하면 프로젝트 기본 브랜치(HEAD) 기준으로 조회된다.
+        format!(
+            "{}/projects/{}/repository/files/{}/raw",
+            self.api_base(),
+            self.encoded_project_path(),
+            utf8_percent_encode(path.trim_start_matches('/'), NON_ALPHANUMERIC)
+        )
+    }
+
+    fn member_endpoint(&self, user_id: u64) -> String {
+        format!(
+            "{}/projects/{}/members/all/{user_id}",
+            self.api_base(),
+            self.encoded_project_path()
+        )
+    }
+
+    /// 사용자가 프로젝트(상속 포함)의 Developer 이상 멤버인지. 멤버가 아니거나 조회에 실패하면 false.
+    async fn is_maintainer(&self, user_id: u64) -> bool {
+        let resp = match self
+            .request(Method::GET, self.member_endpoint(user_id))
+            .send()
+            .await
+        {
+            Ok(resp) if resp.status().is_success() => resp,
+            Ok(_) | Err(_) => return false,
+        };
+        resp.json::<MemberResponse>()
+            .await
+            .is_ok_and(|member| member.access_level >= DEVELOPER_ACCESS_LEVEL)
+    }
+
+    /// MR 노트를 `x-next-page`를 따라 마지막 페이지까지 조회한다.
+    /// watch 모드처럼 반복 조회할 때 바뀌지 않은 페이지는 304로 받는다.
+    async fn list_all_notes(&self) -> Result<Vec<NoteResponse>> {
+        let mut notes = Vec::new();
+        let mut next = Some(format!(
+            "{}?per_page={LIST_PAGE_SIZE}",
+            self.notes_endpoint()
+        ));
+        let mut pages = 0;
+        while let Some(url) = next {
+            if pages == MAX_LIST_PAGES {
+                tracing::warn!(
+                    url,
+                    "gitlab: stopped listing notes after {MAX_LIST_PAGES} pages"
+                );
+                break;
+            }
+            pages += 1;
+            let response = self
+                .conditional
+                .send(&url, self.request(Method::GET, url.clone()))
+                .await
+                .context("gitlab: failed to list notes")?;
+            if !response.status.is_success() {
+                anyhow::bail!(
+                    "gitlab: failed to list notes ({}): {}",
+                    response.status,
+                    response.body
+                );
+            }
+            let page: Vec<NoteResponse> =
+                serde_json::from_str(&response.body).context("gitlab: invalid notes JSON")?;
+            // 304로 받은 꽉 찬 마지막 페이지 뒤에는 그 사이 새 페이지가 생겼을 수 있다.
+            next = response.next_page.or_else(|| {
+                (response.not_modified && page.len() == LIST_PAGE_SIZE)
+                    .then(|| following_page_url(&url))
+                    .flatten()
+            });
+            notes.extend(page);
+        }
+        Ok(notes)
+    }
+
+    /// 목록으로 받은 노트. 노트 목록에는 권한이 없으므로 코멘트 명령을 쓴 작성자만 멤버 권한을 조회한다.
+    async fn listed_notes(&self, notes: Vec<NoteResponse>) -> Vec<ReviewComment> {
+        let mut maintainers: HashMap<u64, bool> = HashMap::new();
+        let mut comments = Vec::with_capacity(notes.len());
+        for note in notes {
+            let author = match note.author {
+                Some(user) => {
+                    let maintainer = if note.body.contains(BOT_COMMAND_PREFIX) {
+                        match maintainers.get(&user.id) {
+                            Some(known) => *known,
+                            None => {
+                                let known = self.is_maintainer(user.id).await;
+                                maintainers.insert(user.id, known);
+                                known
+                            }
+                        }
+                    } else {
+                        false
+                    };
+                    Some(CommentAuthor {
+                        username: user.username,
+                        maintainer,
+                    })
+                }
+                None => None,
+            };
+            comments.push(ReviewComment {
+                id: note.id.to_string(),
+                body: note.body,
+                author,
+            });
+        }
+        comments
+    }
+
     fn request(&self, method: Method, url: String) -> RequestBuilder {
-        // GitLab 토큰 헤더(`PRIVATE-TOKEN`)를 공통 적용한다.
-        let req = self.client.request(method, url);
+        // 토큰을 공통 적용한다. Bearer 헤더는 access token(PAT/프로젝트/그룹)과 OAuth 토큰을 모두 받는다.
+        let req = attributed(self.client.request(method, url));
         if let Some(token) = &self.token {
-            req.header("PRIVATE-TOKEN", token)
+            req.bearer_auth(token)
         } else {
             req
         }
     }
+
+    async fn fetch_merge_request(&self) -> Result<MergeRequestResponse> {
+        let resp = self
+            .request(Method::GET, self.merge_request_endpoint())
+            .send()
+            .await
+            .context("gitlab: failed to fetch MR")?;
+
+        let status = resp.status();
+        let body = resp.text().await.context("gitlab: failed to read MR body")?;
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to fetch MR metadata ({status}): {body}");
+        }
+
+        serde_json::from_str(&body).context("gitlab: invalid MR JSON")
+    }
+
+    /// 사용자 검색(`username=<이름>` 또는 `search=<이메일>`). 실패하면 빈 목록으로 본다.
+    async fn find_users(&self, key: &str, value: &str) -> Vec<UserResponse> {
+        let url = format!(
+            "{}/users?{key}={}",
+            self.api_base(),
+            utf8_percent_encode(value, NON_ALPHANUMERIC)
+        );
+        let Ok(resp) = self.request(Method::GET, url).send().await else {
+            return Vec::new();
+        };
+        if !resp.status().is_success() {
+            return Vec::new();
+        }
+        resp.json().await.unwrap_or_default()
+    }
+
+    /// raw 파일 API로 파일 원문을 읽는다(없으면 None).
+    async fn fetch_raw_file(&self, url: String, path: &str) -> Result<Option<String>> {
+        let resp = self
+            .request(Method::GET, url)
+            .send()
+            .await
+            .with_context(|| format!("gitlab: failed to fetch {path}"))?;
+
+        let status = resp.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let body = resp
+            .text()
+            .await
+            .with_context(|| format!("gitlab: failed to read {path}"))?;
+
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to fetch {path} ({status}): {body}");
+        }
+        Ok(Some(body))
+    }
+
+    /// 위치 지정 discussion을 만든다. 위치에는 MR의 diff_refs(base/start/head SHA)가 필요하다.
+    async fn create_position_discussion(
+        &self,
+        head_sha: &str,
+        mut position: serde_json::Value,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let refs = self
+            .fetch_merge_request()
+            .await?
+            .diff_refs
+            .context("gitlab: MR response missing diff_refs")?;
+        position["base_sha"] = json!(refs.base_sha);
+        position["start_sha"] = json!(refs.start_sha);
+        position["head_sha"] = json!(refs.head_sha.as_deref().unwrap_or(head_sha));
+
+        let resp = self
+            .request(Method::POST, self.discussions_endpoint())
+            .json(&json!({ "body": body, "position": position }))
+            .send()
+            .await
+            .context("gitlab: failed to create discussion")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("gitlab: failed to read create-discussion body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to create discussion ({status}): {response_body}");
+        }
+
+        let discussion: DiscussionResponse = serde_json::from_str(&response_body)
+            .context("gitlab: invalid create-discussion JSON")?;
+        let note = discussion
+            .notes
+            .into_iter()
+            .next()
+            .context("gitlab: discussion response has no notes")?;
+
+        Ok(ReviewComment {
+            id: note.id.to_string(),
+            body: note.body,
+            author: None,
+        })
+    }
+}
+
+pub(crate) fn api_base(host: &str, api_base: Option<&str>) -> String {
+    // gitlab.com은 공개 API, 그 외는 self-hosted 기본 경로를 사용한다.
+    if let Some(base) = api_base {
+        return base.trim_end_matches('/').to_string();
+    }
+    if host == "gitlab.com" {
+        "https://gitlab.com/api/v4".to_string()
+    } else {
+        format!("https://{host}/api/v4")
+    }
+}
+
+pub(crate) fn encode_project_path(project_path: &str) -> String {
+    // /projects/{path} API 규격에 맞춰 경로를 URL 인코딩한다.
+    utf8_percent_encode(project_path, NON_ALPHANUMERIC).to_string()
+}
+
+/// 저장소(프로젝트) 단위 GitLab 조회 클라이언트.
+pub struct GitLabRepositoryClient {
+    client: Client,
+    host: String,
+    project_path: String,
+    token: Option<String>,
+    api_base: Option<String>,
+}
+
+impl GitLabRepositoryClient {
+    pub fn new(
+        client: Client,
+        host: String,
+        project_path: String,
+        token: Option<String>,
+        api_base: Option<String>,
+    ) -> Self {
+        Self {
+            client,
+            host,
+            project_path,
+            token,
+            api_base,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenMergeRequestResponse {
+    iid: u64,
+    web_url: String,
+    #[serde(default)]
+    title: String,
+}
+
+#[async_trait]
+impl RepositoryProvider for GitLabRepositoryClient {
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>> {
+        let url = format!(
+            "{}/projects/{}/merge_requests?state=opened&order_by=updated_at&sort=desc&per_page={}",
+            api_base(&self.host, self.api_base.as_deref()),
+            encode_project_path(&self.project_path),
+            limit.clamp(1, 100)
+        );
+        let mut req = attributed(self.client.get(url));
+        if let Some(token) = &self.token {
+            req = req.bearer_auth(token);
+        }
+
+        let resp = req
+            .send()
+            .await
+            .context("gitlab: failed to list open MRs")?;
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("gitlab: failed to read MR list body")?;
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to list open MRs ({status}): {body}");
+        }
+
+        let merge_requests: Vec<OpenMergeRequestResponse> =
+            serde_json::from_str(&body).context("gitlab: invalid MR list JSON")?;
+        Ok(merge_requests
+            .into_iter()
+            .map(|mr| OpenReview {
+                url: mr.web_url,
+                number: mr.iid,
+                title: mr.title,
+            })
+            .collect())
+    }
 }
 
 #[derive(Debug, Deserialize)]
 struct MergeRequestResponse {
     sha: Option<String>,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    labels: Vec<String>,
+    description: Option<String>,
     diff_refs: Option<DiffRefs>,
+    author: Option<UserResponse>,
+    #[serde(default)]
+    reviewers: Vec<UserResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UserResponse {
+    id: u64,
+    username: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitResponse {
+    message: String,
+    #[serde(default)]
+    author_email: String,
 }
 
 #[derive(Debug, Deserialize)]
 struct DiffRefs {
+    base_sha: Option<String>,
+    start_sha: Option<String>,
     head_sha: Option<String>,
 }
 
@@ -107,30 +469,42 @@ struct MergeRequestChange {
     diff: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct DiscussionResponse {
+    notes: Vec<NoteResponse>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenSelfResponse {
+    #[serde(default)]
+    scopes: Vec<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct NoteResponse {
     id: u64,
     body: String,
+    author: Option<UserResponse>,
+    /// 파일/줄 위치에 단 노트면 `DiffNote`
+    #[serde(rename = "type")]
+    note_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MemberResponse {
+    access_level: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct IssueResponse {
+    iid: u64,
+    web_url: String,
 }
 
 #[async_trait]
 impl VcsProvider for GitLabClient {
     async fn fetch_head_sha(&self) -> Result<String> {
-        let resp = self
-            .request(Method::GET, self.merge_request_endpoint())
-            .send()
-            .await
-            .context("gitlab: failed to fetch MR")?;
-
-        let status = resp.status();
-        let body = resp.text().await.context("gitlab: failed to read MR body")?;
-
-        if !status.is_success() {
-            anyhow::bail!("gitlab: failed to fetch MR metadata ({status}): {body}");
-        }
-
-        let mr: MergeRequestResponse =
-            serde_json::from_str(&body).context("gitlab: invalid MR JSON")?;
+        let mr = self.fetch_merge_request().await?;
 
         if let Some(sha) = mr.sha {
             return Ok(sha);
@@ -142,6 +516,52 @@ impl VcsProvider for GitLabClient {
         anyhow::bail!("gitlab: MR response missing sha and diff_refs.head_sha")
     }
 
+    async fn fetch_title(&self) -> Result<String> {
+        Ok(self.fetch_merge_request().await?.title)
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        Ok(self.fetch_merge_request().await?.labels)
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        Ok(self
+            .fetch_merge_request()
+            .await?
+            .description
+            .unwrap_or_default())
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        let resp = self
+            .request(Method::GET, self.merge_request_commits_endpoint())
+            .send()
+            .await
+            .context("gitlab: failed to list MR commits")?;
+
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("gitlab: failed to read MR commits body")?;
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to list MR commits ({status}): {body}");
+        }
+
+        // GitLab은 최신 커밋부터 돌려주므로 오래된 순으로 뒤집는다.
+        let commits: Vec<CommitResponse> =
+            serde_json::from_str(&body).context("gitlab: invalid MR commits JSON")?;
+        Ok(commits.into_iter().rev().map(|c| c.message).collect())
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        self.fetch_merge_request()
+            .await?
+            .author
+            .map(|user| user.username)
+            .context("gitlab: MR has no author")
+    }
+
     async fn fetch_diff(&self) -> Result<String> {
         // changes API의 개별 diff를 이어붙여 unified diff처럼 사용한다.
         let resp = self
@@ -174,31 +594,9 @@ impl VcsProvider for GitLabClient {
     }
 
     async fn list_comments(&self) -> Result<Vec<ReviewComment>> {
-        let resp = self
-            .request(Method::GET, self.notes_endpoint())
-            .send()
-            .await
-            .context("gitlab: failed to list notes")?;
-
-        let status = resp.status();
-        let body = resp
-            .text()
-            .await
-            .context("gitlab: failed to read notes body")?;
-
-        if !status.is_success() {
-            anyhow::bail!("gitlab: failed to list notes ({status}): {body}");
-        }
-
-        let notes: Vec<NoteResponse> = serde_json::from_str(&body).context("gitlab: invalid notes JSON")?;
-
-        Ok(notes
-            .into_iter()
-            .map(|n| ReviewComment {
-                id: n.id.to_string(),
-                body: n.body,
-            })
-            .collect())
+        // 명령/마커 노트가 첫 페이지(기본 20개) 밖에 있어도 찾도록 모든 페이지를 읽는다.
+        let notes = self.list_all_notes().await?;
+        Ok(self.listed_notes(notes).await)
     }
 
     async fn create_comment(&self, body: &str) -> Result<ReviewComment> {
@@ -225,6 +623,7 @@ impl VcsProvider for GitLabClient {
         Ok(ReviewComment {
             id: note.id.to_string(),
             body: note.body,
+            author: None,
         })
     }
 
@@ -252,6 +651,322 @@ impl VcsProvider for GitLabClient {
         Ok(ReviewComment {
             id: note.id.to_string(),
             body: note.body,
+            author: None,
+        })
+    }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        self.create_position_discussion(
+            head_sha,
+            json!({
+                "position_type": "file",
+                "old_path": path,
+                "new_path": path,
+            }),
+            body,
+        )
+        .await
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        // 문맥 줄은 변경 전/후 줄 번호를 모두, 추가된 줄은 변경 후 줄 번호만 지정한다.
+        let mut line_position = json!({
+            "position_type": "text",
+            "old_path": path,
+            "new_path": path,
+            "new_line": position.end,
+        });
+        if let Some(old_line) = position.old_end {
+            line_position["old_line"] = json!(old_line);
+        }
+        self.create_position_discussion(head_sha, line_position, body)
+            .await
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        let notes = self.list_all_notes().await?;
+        let diff_notes = notes
+            .into_iter()
+            .filter(|note| note.note_type.as_deref() == Some("DiffNote"))
+            .collect();
+        Ok(self.listed_notes(diff_notes).await)
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        // 위치 노트도 일반 노트와 같은 notes API로 수정한다.
+        self.update_comment(comment_id, body).await
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        // reviewer_ids는 목록 전체를 바꾸므로 기존 리뷰어에 더해서 보낸다.
+        // 그룹(`@group/sub`)은 사용자로 조회되지 않아 건너뛴다.
+        let mr = self.fetch_merge_request().await?;
+        let mut ids: Vec<u64> = mr.reviewers.iter().map(|user| user.id).collect();
+        let mut resolved = 0;
+        for name in usernames {
+            let users = self.find_users("username", name).await;
+            if let Some(user) = users
+                .iter()
+                .find(|user| user.username.eq_ignore_ascii_case(name))
+            {
+                resolved += 1;
+                if !ids.contains(&user.id) {
+                    ids.push(user.id);
+                }
+            }
+        }
+        if resolved == 0 {
+            anyhow::bail!("gitlab: no users found for {}", usernames.join(", "));
+        }
+
+        let resp = self
+            .request(Method::PUT, self.merge_request_endpoint())
+            .json(&json!({ "reviewer_ids": ids }))
+            .send()
+            .await
+            .context("gitlab: failed to request reviewers")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("gitlab: failed to request reviewers ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        let resp = self
+            .request(
+                Method::POST,
+                format!("{}/award_emoji", self.note_endpoint(comment_id)),
+            )
+            .json(&json!({ "name": "thumbsup" }))
+            .send()
+            .await
+            .context("gitlab: failed to award emoji")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("gitlab: failed to award emoji ({status}): {body}");
+        }
+        Ok(())
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        let resp = self
+            .request(Method::POST, self.issues_endpoint())
+            .json(&json!({ "title": title, "description": body }))
+            .send()
+            .await
+            .context("gitlab: failed to create issue")?;
+
+        let status = resp.status();
+        let response_body = resp
+            .text()
+            .await
+            .context("gitlab: failed to read create-issue body")?;
+
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to create issue ({status}): {response_body}");
+        }
+
+        let issue: IssueResponse =
+            serde_json::from_str(&response_body).context("gitlab: invalid create-issue JSON")?;
+        Ok(IssueRef {
+            number: issue.iid.to_string(),
+            url: issue.web_url,
         })
     }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        self.fetch_raw_file(self.raw_file_endpoint(path), path)
+            .await
+    }
+
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>> {
+        let url = format!(
+            "{}?ref={}",
+            self.raw_file_endpoint(path),
+            utf8_percent_encode(sha, NON_ALPHANUMERIC)
+        );
+        self.fetch_raw_file(url, path).await
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        // ref_name을 생략하면 기본 브랜치 이력을 최신순으로 돌려준다.
+        let url = format!(
+            "{}?path={}&per_page={}",
+            self.commits_endpoint(),
+            utf8_percent_encode(path.trim_start_matches('/'), NON_ALPHANUMERIC),
+            limit.clamp(1, 100)
+        );
+        let resp = self
+            .request(Method::GET, url)
+            .send()
+            .await
+            .with_context(|| format!("gitlab: failed to list commits for {path}"))?;
+
+        let status = resp.status();
+        let body = resp
+            .text()
+            .await
+            .context("gitlab: failed to read commits body")?;
+        if !status.is_success() {
+            anyhow::bail!("gitlab: failed to list commits for {path} ({status}): {body}");
+        }
+
+        // 커밋에는 사용자 이름이 없으므로 작성자 이메일로 공개 이메일이 같은 사용자를 찾는다.
+        let commits: Vec<CommitResponse> =
+            serde_json::from_str(&body).context("gitlab: invalid commits JSON")?;
+        let mut emails: Vec<String> = Vec::new();
+        for commit in commits {
+            let email = commit.author_email.trim().to_ascii_lowercase();
+            if !email.is_empty() && !emails.contains(&email) {
+                emails.push(email);
+            }
+        }
+        let mut authors: Vec<String> = Vec::new();
+        for email in emails {
+            if let Some(user) = self.find_users("search", &email).await.into_iter().next()
+                && !authors.contains(&user.username)
+            {
+                authors.push(user.username);
+            }
+        }
+        Ok(authors)
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        let actions: Vec<serde_json::Value> = files
+            .iter()
+            .map(|file| match &file.content {
+                Some(content) => json!({
+                    "action": if file.created { "create" } else { "update" },
+                    "file_path": file.path,
+                    "content": content,
+                }),
+                None => json!({ "action": "delete", "file_path": file.path }),
+            })
+            .collect();
+        // force면 브랜치가 이미 있어도 start_sha 위의 새 커밋으로 덮어쓴다.
+        let resp = self
+            .request(Method::POST, self.commits_endpoint())
+            .json(&json!({
+                "branch": branch,
+                "start_sha": base_sha,
+                "commit_message": message,
+                "actions": actions,
+                "force": true,
+            }))
+            .send()
+            .await
+            .context("gitlab: failed to push branch")?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("gitlab: failed to push branch ({status}): {body}");
+        }
+        Ok(format!(
+            "https://{}/{}/-/tree/{}",
+            self.host, self.project_path, branch
+        ))
+    }
+
+    async fn check_token_access(&self, write: bool) -> Result<()> {
+        if self.token.is_none() {
+            return Ok(());
+        }
+
+        // PAT/프로젝트/그룹 토큰은 자기 scope를 조회할 수 있다. OAuth 토큰 등은 조회할 수 없으므로
+        // /user로 유효성만 확인한다.
+        let resp = self
+            .request(
+                Method::GET,
+                format!("{}/personal_access_tokens/self", self.api_base()),
+            )
+            .send()
+            .await
+            .context("gitlab: failed to check token")?;
+        let scopes = if resp.status().is_success() {
+            let body = resp
+                .text()
+                .await
+                .context("gitlab: failed to read token body")?;
+            let token: TokenSelfResponse =
+                serde_json::from_str(&body).context("gitlab: invalid token JSON")?;
+            Some(token.scopes)
+        } else {
+            let resp = self
+                .request(Method::GET, format!("{}/user", self.api_base()))
+                .send()
+                .await
+                .context("gitlab: failed to check token")?;
+            if resp.status() == StatusCode::UNAUTHORIZED {
+                anyhow::bail!(
+                    "gitlab: token for {} is invalid, expired or revoked (401 on /user)",
+                    self.host
+                );
+            }
+            None
+        };
+
+        let resp = self
+            .request(
+                Method::GET,
+                format!(
+                    "{}/projects/{}",
+                    self.api_base(),
+                    self.encoded_project_path()
+                ),
+            )
+            .send()
+            .await
+            .context("gitlab: failed to check project access")?;
+        let status = resp.status();
+        if status == StatusCode::NOT_FOUND || status == StatusCode::FORBIDDEN {
+            anyhow::bail!(
+                "gitlab: token cannot access {} ({status})",
+                self.project_path
+            );
+        }
+        if !status.is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            anyhow::bail!("gitlab: failed to check project access ({status}): {body}");
+        }
+
+        if write
+            && let Some(scopes) = scopes
+            && !scopes.iter().any(|scope| scope == "api")
+        {
+            anyhow::bail!(
+                "gitlab: token lacks write access to {} (scopes: {}; needs `api`)",
+                self.project_path,
+                if scopes.is_empty() {
+                    "none".to_string()
+                } else {
+                    scopes.join(", ")
+                }
+            );
+        }
+        Ok(())
+    }
 }
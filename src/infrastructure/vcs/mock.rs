@@ -0,0 +1,495 @@
+//! fixture 파일 기반 mock VCS 구현(`mock://<fixture 경로>`).
+//!
+//! fixture(JSON)에서 제목, 작성자, 라벨, 설명, 커밋 메시지, HEAD SHA, diff, 기존 코멘트, 기본 브랜치/HEAD 파일,
+//! 경로별 최근 커밋 작성자를 읽는다.
+//! 게시한 코멘트는 fixture를 고치지 않고 상태 파일(`$XDG_STATE_HOME/repopilot/mock/`)에 쌓아
+//! 다음 실행의 중복 방지에 쓴다. 상태 파일을 지우면 fixture의 처음 상태로 돌아간다.
+//!
+//! ```json
+//! {
+//!   "title": "Add parser",
+//!   "author": "alice",
+//!   "labels": ["feature"],
+//!   "description": "Adds a parser for ...",
+//!   "commits": ["feat: add parser"],
+//!   "head_sha": "abc123",
+//!   "diff_file": "pr.diff",
+//!   "comments": [{ "id": "1", "body": "LGTM" }],
+//!   "files": { "REVIEW.md": "..." },
+//!   "head_files": { "src/parser.rs": "..." },
+//!   "recent_authors": { "src/parser.rs": ["bob"] }
+//! }
+//! ```
+//! - `diff`(본문) 또는 `diff_file`(fixture 기준 상대 경로) 중 하나가 필요하다.
+//! - HEAD 커밋 파일(`head_files`)에 없는 경로는 `files`에서 찾는다.
+//! - `head_sha`를 생략하면 diff 내용의 해시를 쓴다(diff를 고치면 새 SHA가 된다).
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use super::{IssueRef, LinePosition, PatchedFile, ReviewComment, VcsProvider};
+use crate::domain::review::CommentAuthor;
+use crate::infrastructure::state;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fixture {
+    title: Option<String>,
+    author: Option<String>,
+    #[serde(default)]
+    labels: Vec<String>,
+    description: Option<String>,
+    #[serde(default)]
+    commits: Vec<String>,
+    head_sha: Option<String>,
+    diff: Option<String>,
+    diff_file: Option<String>,
+    #[serde(default)]
+    comments: Vec<MockComment>,
+    #[serde(default)]
+    files: BTreeMap<String, String>,
+    #[serde(default)]
+    head_files: BTreeMap<String, String>,
+    /// 경로 → 기본 브랜치 최근 커밋 작성자(최신순)
+    #[serde(default)]
+    recent_authors: BTreeMap<String, Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MockComment {
+    id: String,
+    body: String,
+    /// 작성자 이름(repopilot이 쓴 코멘트면 없음)
+    #[serde(default)]
+    author: Option<String>,
+    /// 작성자가 저장소 관리자인지(코멘트 명령 확인용)
+    #[serde(default)]
+    maintainer: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MockFileComment {
+    id: String,
+    head_sha: String,
+    path: String,
+    body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MockLineComment {
+    id: String,
+    head_sha: String,
+    path: String,
+    line_start: u32,
+    line_end: u32,
+    body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MockBranch {
+    name: String,
+    base_sha: String,
+    message: String,
+    /// 경로 → 변경 후 내용(삭제면 null)
+    files: BTreeMap<String, Option<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct MockIssue {
+    number: u64,
+    title: String,
+    body: String,
+}
+
+/// 실행 사이에 유지하는 게시 결과.
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MockState {
+    comments: Vec<MockComment>,
+    #[serde(default)]
+    file_comments: Vec<MockFileComment>,
+    /// 확인 반응을 단 코멘트 id
+    #[serde(default)]
+    acknowledged: Vec<String>,
+    /// 인라인 코멘트(수정 제안)
+    #[serde(default)]
+    line_comments: Vec<MockLineComment>,
+    /// 후속 이슈(`--create-issues`)
+    #[serde(default)]
+    issues: Vec<MockIssue>,
+    /// 수정 브랜치(`--autofix-push`). 같은 이름으로 다시 올리면 덮어쓴다.
+    #[serde(default)]
+    branches: Vec<MockBranch>,
+    /// 리뷰를 요청한 사용자/팀(`--request-reviewers`)
+    #[serde(default)]
+    requested_reviewers: Vec<String>,
+}
+
+pub struct MockVcsClient {
+    fixture: PathBuf,
+    /// 상태 파일 읽기-수정-쓰기를 직렬화한다(에이전트 코멘트는 동시에 게시될 수 있다).
+    lock: Mutex<()>,
+}
+
+impl MockVcsClient {
+    /// fixture 경로로 mock 클라이언트를 생성한다.
+    pub fn new(fixture: PathBuf) -> Self {
+        Self {
+            fixture,
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn load_fixture(&self) -> Result<Fixture> {
+        let raw = fs::read_to_string(&self.fixture)
+            .with_context(|| format!("mock: failed to read fixture {}", self.fixture.display()))?;
+        serde_json::from_str(&raw)
+            .with_context(|| format!("mock: invalid fixture JSON: {}", self.fixture.display()))
+    }
+
+    fn load_diff(&self, fixture: &Fixture) -> Result<String> {
+        if let Some(diff) = &fixture.diff {
+            return Ok(diff.clone());
+        }
+        let Some(diff_file) = fixture.diff_file.as_deref() else {
+            bail!(
+                "mock: fixture {} has neither `diff` nor `diff_file`",
+                self.fixture.display()
+            );
+        };
+        let path = self
+            .fixture
+            .parent()
+            .unwrap_or(Path::new("."))
+            .join(diff_file);
+        fs::read_to_string(&path)
+            .with_context(|| format!("mock: failed to read diff {}", path.display()))
+    }
+
+    /// 상태 파일 경로(`$XDG_STATE_HOME/repopilot/mock/<fixture 경로>.json`).
+    fn state_path(&self) -> Result<PathBuf> {
+        let dir = state::state_dir().context("cannot determine state directory for mock VCS")?;
+        let fixture = fs::canonicalize(&self.fixture).unwrap_or_else(|_| self.fixture.clone());
+        // 경로를 파일 이름으로 쓸 수 있게 영숫자 외 문자를 `_`로 바꾼다.
+        let name: String = fixture
+            .to_string_lossy()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        Ok(dir.join("mock").join(format!("{name}.json")))
+    }
+
+    fn load_state(&self) -> Result<MockState> {
+        let path = self.state_path()?;
+        if !path.is_file() {
+            return Ok(MockState {
+                comments: self.load_fixture()?.comments,
+                ..MockState::default()
+            });
+        }
+        let raw = fs::read_to_string(&path)
+            .with_context(|| format!("mock: failed to read {}", path.display()))?;
+        serde_json::from_str(&raw)
+            .with_context(|| format!("mock: invalid state file: {}", path.display()))
+    }
+
+    fn save_state(&self, state: &MockState) -> Result<()> {
+        let path = self.state_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("failed to create {}", parent.display()))?;
+        }
+        let json =
+            serde_json::to_string_pretty(state).context("mock: failed to serialize state")?;
+        fs::write(&path, json).with_context(|| format!("failed to write {}", path.display()))
+    }
+
+    /// 상태를 읽어 수정한 뒤 저장한다.
+    fn update_state<T>(&self, apply: impl FnOnce(&mut MockState) -> Result<T>) -> Result<T> {
+        let _guard = self
+            .lock
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut state = self.load_state()?;
+        let value = apply(&mut state)?;
+        self.save_state(&state)?;
+        Ok(value)
+    }
+}
+
+/// 다음 코멘트 id. 실제 VCS처럼 생성 순으로 증가하는 숫자를 쓴다.
+fn next_id(state: &MockState) -> String {
+    let max = state
+        .comments
+        .iter()
+        .map(|c| c.id.as_str())
+        .chain(state.file_comments.iter().map(|c| c.id.as_str()))
+        .chain(state.line_comments.iter().map(|c| c.id.as_str()))
+        .filter_map(|id| id.parse::<u64>().ok())
+        .max()
+        .unwrap_or(0);
+    (max + 1).to_string()
+}
+
+#[async_trait]
+impl VcsProvider for MockVcsClient {
+    async fn fetch_head_sha(&self) -> Result<String> {
+        let fixture = self.load_fixture()?;
+        if let Some(sha) = fixture
+            .head_sha
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+        {
+            return Ok(sha.to_string());
+        }
+        let mut hasher = DefaultHasher::new();
+        self.load_diff(&fixture)?.hash(&mut hasher);
+        Ok(format!("{:016x}", hasher.finish()))
+    }
+
+    async fn fetch_title(&self) -> Result<String> {
+        Ok(self.load_fixture()?.title.unwrap_or_default())
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        Ok(self.load_fixture()?.labels)
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        Ok(self.load_fixture()?.description.unwrap_or_default())
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        Ok(self.load_fixture()?.commits)
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        Ok(self.load_fixture()?.author.unwrap_or_default())
+    }
+
+    async fn fetch_diff(&self) -> Result<String> {
+        let fixture = self.load_fixture()?;
+        self.load_diff(&fixture)
+    }
+
+    async fn list_comments(&self) -> Result<Vec<ReviewComment>> {
+        Ok(self
+            .load_state()?
+            .comments
+            .into_iter()
+            .map(|c| ReviewComment {
+                id: c.id,
+                body: c.body,
+                author: c.author.map(|username| CommentAuthor {
+                    username,
+                    maintainer: c.maintainer,
+                }),
+            })
+            .collect())
+    }
+
+    async fn create_comment(&self, body: &str) -> Result<ReviewComment> {
+        self.update_state(|state| {
+            let comment = MockComment {
+                id: next_id(state),
+                body: body.to_string(),
+                author: None,
+                maintainer: false,
+            };
+            state.comments.push(comment.clone());
+            Ok(ReviewComment {
+                id: comment.id,
+                body: comment.body,
+                author: None,
+            })
+        })
+    }
+
+    async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        self.update_state(|state| {
+            let Some(comment) = state.comments.iter_mut().find(|c| c.id == comment_id) else {
+                bail!("mock: comment {comment_id} not found");
+            };
+            comment.body = body.to_string();
+            Ok(ReviewComment {
+                id: comment.id.clone(),
+                body: comment.body.clone(),
+                author: None,
+            })
+        })
+    }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        self.update_state(|state| {
+            let comment = MockFileComment {
+                id: next_id(state),
+                head_sha: head_sha.to_string(),
+                path: path.to_string(),
+                body: body.to_string(),
+            };
+            state.file_comments.push(comment.clone());
+            Ok(ReviewComment {
+                id: comment.id,
+                body: comment.body,
+                author: None,
+            })
+        })
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        self.update_state(|state| {
+            let comment = MockLineComment {
+                id: next_id(state),
+                head_sha: head_sha.to_string(),
+                path: path.to_string(),
+                line_start: position.start,
+                line_end: position.end,
+                body: body.to_string(),
+            };
+            state.line_comments.push(comment.clone());
+            Ok(ReviewComment {
+                id: comment.id,
+                body: comment.body,
+                author: None,
+            })
+        })
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        let state = self.load_state()?;
+        let files = state.file_comments.into_iter().map(|c| (c.id, c.body));
+        let lines = state.line_comments.into_iter().map(|c| (c.id, c.body));
+        Ok(files
+            .chain(lines)
+            .map(|(id, body)| ReviewComment {
+                id,
+                body,
+                author: None,
+            })
+            .collect())
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        self.update_state(|state| {
+            let target = state
+                .file_comments
+                .iter_mut()
+                .find(|c| c.id == comment_id)
+                .map(|c| &mut c.body)
+                .or_else(|| {
+                    state
+                        .line_comments
+                        .iter_mut()
+                        .find(|c| c.id == comment_id)
+                        .map(|c| &mut c.body)
+                });
+            let Some(target) = target else {
+                bail!("mock: review comment {comment_id} not found");
+            };
+            *target = body.to_string();
+            Ok(ReviewComment {
+                id: comment_id.to_string(),
+                body: body.to_string(),
+                author: None,
+            })
+        })
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        self.update_state(|state| {
+            if !state.acknowledged.iter().any(|id| id == comment_id) {
+                state.acknowledged.push(comment_id.to_string());
+            }
+            Ok(())
+        })
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        self.update_state(|state| {
+            for name in usernames {
+                if !state.requested_reviewers.contains(name) {
+                    state.requested_reviewers.push(name.clone());
+                }
+            }
+            Ok(())
+        })
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        self.update_state(|state| {
+            let number = state.issues.len() as u64 + 1;
+            state.issues.push(MockIssue {
+                number,
+                title: title.to_string(),
+                body: body.to_string(),
+            });
+            Ok(IssueRef {
+                number: number.to_string(),
+                url: format!("mock://issues/{number}"),
+            })
+        })
+    }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        Ok(self.load_fixture()?.files.get(path).cloned())
+    }
+
+    async fn fetch_file_at(&self, path: &str, _sha: &str) -> Result<Option<String>> {
+        let fixture = self.load_fixture()?;
+        Ok(fixture
+            .head_files
+            .get(path)
+            .or_else(|| fixture.files.get(path))
+            .cloned())
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        let mut authors = self
+            .load_fixture()?
+            .recent_authors
+            .remove(path)
+            .unwrap_or_default();
+        authors.truncate(limit);
+        Ok(authors)
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        self.update_state(|state| {
+            state.branches.retain(|b| b.name != branch);
+            state.branches.push(MockBranch {
+                name: branch.to_string(),
+                base_sha: base_sha.to_string(),
+                message: message.to_string(),
+                files: files
+                    .iter()
+                    .map(|f| (f.path.clone(), f.content.clone()))
+                    .collect(),
+            });
+            Ok(format!("mock://branches/{branch}"))
+        })
+    }
+}
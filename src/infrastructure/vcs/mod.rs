@@ -1,20 +1,41 @@
 //! VCS 추상화 계층.
-//! GitHub/GitLab별 구현을 공통 인터페이스로 묶는다.
+//! GitHub/GitLab별 구현(과 오프라인 확인용 mock)을 공통 인터페이스로 묶는다.
 
 pub mod github;
 pub mod gitlab;
+pub mod mock;
+
+use std::path::PathBuf;
 
 use anyhow::Result;
 use async_trait::async_trait;
 
-use crate::domain::review::ReviewComment;
-use crate::domain::target::ReviewTarget;
+use crate::domain::diff::LinePosition;
+use crate::domain::patch::PatchedFile;
+use crate::domain::review::{IssueRef, ReviewComment};
+use crate::domain::target::{OpenReview, RepositoryRef, ReviewTarget};
 use crate::infrastructure::config::HostConfig;
+use crate::infrastructure::http::{HttpClients, HttpProfile};
+
+/// 코멘트 목록 API의 페이지 크기(GitHub/GitLab 최댓값).
+pub(crate) const LIST_PAGE_SIZE: usize = 100;
+/// 목록을 끝까지 따라갈 때의 최대 페이지 수. 비정상 Link 헤더로 끝없이 조회하지 않게 한다.
+pub(crate) const MAX_LIST_PAGES: usize = 50;
 
 #[async_trait]
 pub trait VcsProvider: Send + Sync {
     /// PR/MR의 현재 HEAD SHA 조회
     async fn fetch_head_sha(&self) -> Result<String>;
+    /// PR/MR 제목 조회
+    async fn fetch_title(&self) -> Result<String>;
+    /// PR/MR 라벨 이름 조회
+    async fn fetch_labels(&self) -> Result<Vec<String>>;
+    /// PR/MR 설명 본문 조회
+    async fn fetch_description(&self) -> Result<String>;
+    /// PR/MR 커밋 메시지 조회(오래된 순)
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>>;
+    /// PR/MR 작성자 사용자 이름 조회
+    async fn fetch_author(&self) -> Result<String>;
     /// API 기반 diff 전문 조회
     async fn fetch_diff(&self) -> Result<String>;
     /// 기존 코멘트/노트 조회
@@ -23,12 +44,63 @@ pub trait VcsProvider: Send + Sync {
     async fn create_comment(&self, body: &str) -> Result<ReviewComment>;
     /// 코멘트/노트 수정
     async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment>;
+    /// 특정 파일에 대한 파일 단위 리뷰 스레드 생성
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment>;
+    /// 변경 후 파일의 줄 구간에 인라인 리뷰 코멘트 생성
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment>;
+    /// 파일/줄 단위 리뷰 코멘트 목록
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>>;
+    /// 파일/줄 단위 리뷰 코멘트 수정
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment>;
+    /// 명령 코멘트에 확인(👍) 반응 추가
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()>;
+    /// 리뷰어 요청(기존 리뷰어 유지)
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()>;
+    /// 저장소 이슈 생성
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef>;
+    /// 기본 브랜치의 파일 원문 조회(없으면 None)
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>>;
+    /// 특정 커밋의 파일 원문 조회(없으면 None)
+    async fn fetch_file_at(&self, path: &str, sha: &str) -> Result<Option<String>>;
+    /// 기본 브랜치에서 경로를 바꾼 최근 커밋 작성자 사용자 이름 조회(최신순, 중복 제거)
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>>;
+    /// `base_sha` 위에 파일 변경을 커밋한 브랜치 생성/강제 갱신, 브랜치 URL 반환
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String>;
+    /// 토큰 유효성/저장소 접근/쓰기 scope 사전 확인(토큰이 없으면 생략)
+    async fn check_token_access(&self, _write: bool) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// 저장소 단위 조회(PR/MR 번호가 정해지기 전 단계).
+#[async_trait]
+pub trait RepositoryProvider: Send + Sync {
+    /// 최근 갱신 순으로 열린 PR/MR 조회
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>>;
 }
 
 pub fn build_vcs_client(
     target: &ReviewTarget,
     host_cfg: Option<&HostConfig>,
     token: Option<String>,
+    http: &HttpClients,
 ) -> Box<dyn VcsProvider> {
     // URL 해석 결과에 따라 적절한 VCS 구현체를 선택한다.
     let api_base = host_cfg.and_then(|h| h.api_base.clone());
@@ -41,6 +113,7 @@ pub fn build_vcs_client(
             number,
             ..
         } => Box::new(github::GitHubClient::new(
+            http,
             host.clone(),
             owner.clone(),
             repo.clone(),
@@ -54,11 +127,44 @@ pub fn build_vcs_client(
             iid,
             ..
         } => Box::new(gitlab::GitLabClient::new(
+            http,
             host.clone(),
             project_path.clone(),
             *iid,
             token,
             api_base,
         )),
+        ReviewTarget::Mock { fixture, .. } => {
+            Box::new(mock::MockVcsClient::new(PathBuf::from(fixture)))
+        }
+    }
+}
+
+pub fn build_repository_client(
+    repository: &RepositoryRef,
+    host_cfg: Option<&HostConfig>,
+    token: Option<String>,
+    http: &HttpClients,
+) -> Box<dyn RepositoryProvider> {
+    let api_base = host_cfg.and_then(|h| h.api_base.clone());
+
+    match repository {
+        RepositoryRef::GitHub { host, owner, repo } => Box::new(github::GitHubRepositoryClient::new(
+            http.client(HttpProfile::VCS),
+            host.clone(),
+            owner.clone(),
+            repo.clone(),
+            token,
+            api_base,
+        )),
+        RepositoryRef::GitLab { host, project_path } => {
+            Box::new(gitlab::GitLabRepositoryClient::new(
+                http.client(HttpProfile::VCS),
+                host.clone(),
+                project_path.clone(),
+                token,
+                api_base,
+            ))
+        }
     }
 }
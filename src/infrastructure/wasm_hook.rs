@@ -0,0 +1,90 @@
+//! wasmtime 기반 정책 훅 모듈 실행기(`wasm-hooks` 기능).
+//!
+//! 훅 모듈은 JSON 문자열 하나를 받아 JSON 문자열 하나를 돌려주는 함수를 export한다.
+//! - export: `memory`, `repopilot_alloc(len: i32) -> i32`, 훅 함수 `(ptr: i32, len: i32) -> i64`
+//! - 호스트는 `repopilot_alloc`으로 받은 위치에 입력 JSON(UTF-8)을 쓰고 훅 함수를 부른다.
+//! - 반환값은 `(출력 ptr << 32) | 출력 len`이며 그 구간의 UTF-8 JSON을 응답으로 읽는다.
+//!
+//! 모듈은 import를 쓸 수 없고(호스트 함수/WASI 없음) 호출마다 새 인스턴스에서
+//! 연료(명령 수)와 메모리 상한 안에서만 실행된다.
+
+use std::path::Path;
+
+use anyhow::{Context, Result, anyhow, bail};
+use wasmtime::{Config, Engine, Instance, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+// 호출 한 번에 쓸 수 있는 연료. 무한 루프 훅이 리뷰를 붙잡지 않게 한다.
+const FUEL_PER_CALL: u64 = 2_000_000_000;
+// 인스턴스 하나의 선형 메모리 상한.
+const MAX_MEMORY_BYTES: usize = 256 * 1024 * 1024;
+
+/// 불러온(컴파일된) 훅 모듈 하나.
+pub struct WasmHookModule {
+    engine: Engine,
+    module: Module,
+    path: String,
+}
+
+impl WasmHookModule {
+    /// `.wasm` 바이너리나 `.wat` 텍스트 모듈을 불러온다.
+    pub fn load(path: &str) -> Result<Self> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config)?;
+        if !Path::new(path).is_file() {
+            bail!("wasm hook module {path} does not exist");
+        }
+        let module = Module::from_file(&engine, path)
+            .map_err(anyhow::Error::from)
+            .with_context(|| format!("failed to load wasm hook module {path}"))?;
+        if let Some(import) = module.imports().next() {
+            bail!(
+                "wasm hook module {path} imports {}::{}; hook modules cannot import host functions",
+                import.module(),
+                import.name()
+            );
+        }
+        Ok(Self {
+            engine,
+            module,
+            path: path.to_string(),
+        })
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// 새 인스턴스에서 `export` 훅 함수를 입력 JSON으로 호출하고 출력 JSON을 돌려준다.
+    pub fn call(&self, export: &str, input: &str) -> Result<String> {
+        self.call_inner(export, input)
+            .with_context(|| format!("wasm hook {export} in {} failed", self.path))
+    }
+
+    fn call_inner(&self, export: &str, input: &str) -> Result<String> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY_BYTES)
+            .build();
+        let mut store: Store<StoreLimits> = Store::new(&self.engine, limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(FUEL_PER_CALL)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[])?;
+        let memory = instance
+            .get_memory(&mut store, "memory")
+            .ok_or_else(|| anyhow!("module does not export `memory`"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&mut store, "repopilot_alloc")?;
+        let hook = instance.get_typed_func::<(i32, i32), i64>(&mut store, export)?;
+
+        let len = i32::try_from(input.len()).map_err(|_| anyhow!("input is too large"))?;
+        let ptr = alloc.call(&mut store, len)?;
+        memory.write(&mut store, ptr as u32 as usize, input.as_bytes())?;
+        let packed = hook.call(&mut store, (ptr, len))? as u64;
+
+        let out_ptr = (packed >> 32) as usize;
+        let out_len = (packed & 0xffff_ffff) as usize;
+        let mut output = vec![0u8; out_len];
+        memory.read(&store, out_ptr, &mut output)?;
+        String::from_utf8(output).context("output is not valid UTF-8")
+    }
+}
@@ -0,0 +1,138 @@
+//! 라이브러리 사용자를 위한 리뷰 실행 빌더.
+
+use anyhow::Result;
+
+use crate::application::ports::{FindingTriage, UserConfirmer};
+use crate::application::usecases::review_pr::ReviewOutcome;
+use crate::domain::review::{CommentLanguage, RunOptions, Severity};
+use crate::interface::cli::{AppComposition, AppCompositionBuilder};
+
+/// CLI 조립 없이 리뷰를 구성/실행하는 빌더.
+/// 기본값은 확인/triage 입력을 모두 자동 승인하는 비대화형 실행이다.
+pub struct ReviewBuilder {
+    options: RunOptions,
+    composition: AppCompositionBuilder,
+}
+
+impl ReviewBuilder {
+    /// 대상 PR/MR URL로 빌더를 만든다.
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            options: RunOptions {
+                url: url.into(),
+                dry_run: false,
+                force: false,
+                fail_on: None,
+                create_issues: None,
+                autofix: false,
+                autofix_push: false,
+                request_reviewers: false,
+                exclude: Vec::new(),
+                interactive_publish: false,
+                providers: Vec::new(),
+                model_overrides: Vec::new(),
+                comment_language: None,
+                profile: None,
+            },
+            composition: AppComposition::builder()
+                .with_provider_panel(false)
+                .non_interactive(),
+        }
+    }
+
+    /// 코멘트를 게시하지 않고 Markdown만 생성한다.
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.options.dry_run = dry_run;
+        self
+    }
+
+    /// 이미 리뷰된 SHA여도 다시 실행한다.
+    pub fn force(mut self, force: bool) -> Self {
+        self.options.force = force;
+        self
+    }
+
+    /// 이 심각도 이상 지적사항이 있으면 결과의 `gate`를 채운다.
+    pub fn fail_on(mut self, threshold: Severity) -> Self {
+        self.options.fail_on = Some(threshold);
+        self
+    }
+
+    /// 이 심각도 이상 합의 지적사항마다 후속 이슈를 만든다.
+    pub fn create_issues(mut self, threshold: Severity) -> Self {
+        self.options.create_issues = Some(threshold);
+        self
+    }
+
+    /// 추천한 사람 리뷰어(코드 소유자/최근 작성자)에게 리뷰를 요청한다.
+    pub fn request_reviewers(mut self, request: bool) -> Self {
+        self.options.request_reviewers = request;
+        self
+    }
+
+    /// 이번 실행에서 사용할 provider id를 추가한다(지정하지 않으면 활성 provider 전체).
+    pub fn provider(mut self, id: impl Into<String>) -> Self {
+        self.options.providers.push(id.into());
+        self
+    }
+
+    /// 이번 실행에서만 provider 모델을 바꾼다.
+    pub fn model(mut self, provider: impl Into<String>, model: impl Into<String>) -> Self {
+        self.options
+            .model_overrides
+            .push((provider.into(), model.into()));
+        self
+    }
+
+    /// 이번 실행에서만 코멘트 언어를 바꾼다.
+    pub fn language(mut self, language: CommentLanguage) -> Self {
+        self.options.comment_language = Some(language);
+        self
+    }
+
+    /// 설정의 `profiles.<name>` 묶음을 적용한다.
+    pub fn profile(mut self, name: impl Into<String>) -> Self {
+        self.options.profile = Some(name.into());
+        self
+    }
+
+    /// 리뷰에서 제외할 diff 경로 glob을 추가한다.
+    pub fn exclude(mut self, pattern: impl Into<String>) -> Self {
+        self.options.exclude.push(pattern.into());
+        self
+    }
+
+    /// 콘솔 provider 상태판 출력 여부(기본 false).
+    pub fn provider_panel(mut self, enabled: bool) -> Self {
+        self.composition = self.composition.with_provider_panel(enabled);
+        self
+    }
+
+    /// 사용자 확인 어댑터를 주입한다.
+    pub fn confirmer(mut self, confirmer: Box<dyn UserConfirmer>) -> Self {
+        self.composition = self.composition.with_confirmer(confirmer);
+        self
+    }
+
+    /// 지적사항 triage 어댑터를 주입하고 게시 전 triage를 켠다.
+    pub fn finding_triage(mut self, finding_triage: Box<dyn FindingTriage>) -> Self {
+        self.composition = self.composition.with_finding_triage(finding_triage);
+        self.options.interactive_publish = true;
+        self
+    }
+
+    /// provider/VCS/reporter 등 나머지 포트를 교체한다.
+    pub fn configure(
+        mut self,
+        configure: impl FnOnce(AppCompositionBuilder) -> AppCompositionBuilder,
+    ) -> Self {
+        self.composition = configure(self.composition);
+        self
+    }
+
+    /// 리뷰를 실행하고 결과를 반환한다.
+    pub async fn run(self) -> Result<ReviewOutcome> {
+        let composition = self.composition.build();
+        composition.review_usecase().execute(self.options).await
+    }
+}
@@ -1,9 +1,11 @@
 //! CLI 명령 파싱 모듈.
 
-use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
 
-use crate::application::ports::{ProviderAuthKind, VcsAuthKind};
-use crate::domain::review::RunOptions;
+use clap::{Parser, Subcommand, ValueEnum};
+
+use crate::application::ports::{ProgressFormat, ProviderAuthKind, VcsAuthKind, Verbosity};
+use crate::domain::review::{CommentLanguage, RunOptions, Severity};
 
 #[derive(Debug, Parser)]
 #[command(name = "repopilot")]
@@ -12,8 +14,16 @@ pub struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
 
-    /// PR/MR URL
-    url: Option<String>,
+    /// PR/MR URL(s). Multiple URLs are reviewed as a batch
+    urls: Vec<String>,
+
+    /// Read additional PR/MR URLs from a file (one per line, `#` comments allowed)
+    #[arg(long, value_name = "PATH")]
+    from_file: Option<PathBuf>,
+
+    /// Number of targets reviewed concurrently in batch mode
+    #[arg(long, default_value_t = 1)]
+    jobs: usize,
 
     /// Print markdown to stdout, do not post
     #[arg(long)]
@@ -22,12 +32,311 @@ pub struct Cli {
     /// Re-run even if current SHA is already claimed/reviewed
     #[arg(long)]
     force: bool,
+
+    /// Exit with code 3 if any finding at or above this severity exists
+    #[arg(long, value_enum)]
+    fail_on: Option<FailOnLevel>,
+
+    /// Open a follow-up issue for each consensus finding at or above this severity
+    #[arg(long, value_enum, value_name = "SEVERITY")]
+    create_issues: Option<FailOnLevel>,
+
+    /// Ask a provider for a unified-diff fix of the consensus findings, check that it applies to the head commit and write it to this file
+    #[arg(long, value_name = "PATH")]
+    autofix: Option<PathBuf>,
+
+    /// Also push the validated fix as a repopilot/fixes-<sha> branch (requires --autofix)
+    #[arg(long, requires = "autofix")]
+    autofix_push: bool,
+
+    /// Request reviews from the suggested human reviewers (code owners and recent authors of the changed files)
+    #[arg(long)]
+    request_reviewers: bool,
+
+    /// Exclude diff files matching this glob (repeatable, added to defaults.diff_exclude)
+    #[arg(long, value_name = "GLOB")]
+    exclude: Vec<String>,
+
+    /// Review findings one by one (accept/drop/edit) before posting
+    #[arg(long)]
+    interactive_publish: bool,
+
+    /// Run only these providers for this invocation (e.g. openai,gemini)
+    #[arg(long, value_delimiter = ',', value_name = "IDS")]
+    providers: Vec<String>,
+
+    /// Override a provider model for this run (repeatable), e.g. openai=gpt-4.1
+    #[arg(long = "model", value_name = "PROVIDER=MODEL", value_parser = parse_model_override)]
+    models: Vec<(String, String)>,
+
+    /// Override defaults.comment_language for this run (BCP-47 tag, e.g. en, ko, ja, zh-CN)
+    #[arg(long, value_name = "LANG", value_parser = parse_language)]
+    lang: Option<CommentLanguage>,
+
+    /// Apply a named config profile (profiles.<name>) for this run
+    #[arg(long, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Write the review result (agent comments, summary, findings) to this file
+    #[arg(long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    /// Format of the review result written by --output (json is printed to stdout without --output)
+    #[arg(long, value_enum, default_value_t = OutputFormat::Markdown)]
+    format: OutputFormat,
+
+    /// Also export a standalone styled report of the full review (html, or pdf via a headless Chromium)
+    #[arg(long, num_args = 2, value_names = ["FORMAT", "PATH"])]
+    export: Vec<String>,
+
+    /// GitLab CI mode: take the MR from CI_MERGE_REQUEST_* and write gl-code-quality-report.json
+    #[arg(long)]
+    gitlab_ci: bool,
+
+    /// Record all VCS/provider traffic (secrets redacted) and progress output into this directory
+    #[arg(long, value_name = "DIR", conflicts_with = "replay")]
+    record: Option<PathBuf>,
+
+    /// Replay a run recorded with --record from this directory, without network or provider calls
+    #[arg(long, value_name = "DIR")]
+    replay: Option<PathBuf>,
+
+    /// Start the interactive shell as a full-screen cockpit (provider status, agent output, command bar)
+    #[arg(long)]
+    tui: bool,
+
+    /// Print only the final result (gate verdict, summary location, dry-run summary)
+    #[arg(long, short = 'q', global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Also print diagnostics (prompt sizes, VCS call timings)
+    #[arg(long, short = 'v', global = true)]
+    verbose: bool,
+
+    /// Disable colored output (also honored via the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Progress output format; ndjson prints one JSON event per line for CI/wrappers
+    #[arg(
+        long,
+        value_enum,
+        global = true,
+        default_value_t = ProgressFormatArg::Text,
+        conflicts_with = "quiet"
+    )]
+    progress_format: ProgressFormatArg,
+}
+
+/// `--export <FORMAT> <PATH>` 값을 해석한다(지정하지 않았으면 None).
+fn parse_export(values: &[String]) -> Result<Option<ReviewExport>, String> {
+    let [format, path] = values else {
+        return Ok(None);
+    };
+    let format = match format.to_ascii_lowercase().as_str() {
+        "html" => ExportFormat::Html,
+        "pdf" => ExportFormat::Pdf,
+        other => return Err(format!("invalid --export format '{other}' (expected html or pdf)")),
+    };
+    Ok(Some(ReviewExport {
+        format,
+        path: PathBuf::from(path),
+    }))
+}
+
+/// `--gitlab-ci`에서 기본으로 기록하는 Code Quality 리포트 경로.
+const GITLAB_CODE_QUALITY_REPORT: &str = "gl-code-quality-report.json";
+
+/// `--lang` 언어 태그를 파싱한다.
+fn parse_language(value: &str) -> Result<CommentLanguage, String> {
+    CommentLanguage::parse(value)
+        .ok_or_else(|| format!("invalid language tag '{value}' (e.g. en, ko, ja, zh-CN)"))
+}
+
+/// `--model provider=model` 값을 파싱한다.
+fn parse_model_override(value: &str) -> Result<(String, String), String> {
+    let Some((provider, model)) = value.split_once('=') else {
+        return Err("expected PROVIDER=MODEL (e.g. openai=gpt-4.1)".to_string());
+    };
+    let (provider, model) = (provider.trim(), model.trim());
+    if provider.is_empty() || model.is_empty() {
+        return Err("expected PROVIDER=MODEL (e.g. openai=gpt-4.1)".to_string());
+    }
+    Ok((provider.to_string(), model.to_string()))
+}
+
+/// `--since` 기간(`30d`, `12h`, `2w`, `90m`)을 초 단위로 파싱한다.
+fn parse_since(value: &str) -> Result<u64, String> {
+    let value = value.trim();
+    let err = || format!("invalid duration '{value}' (e.g. 30d, 12h, 2w)");
+    let split = value.find(|c: char| !c.is_ascii_digit()).ok_or_else(err)?;
+    let amount = value[..split].parse::<u64>().map_err(|_| err())?;
+    let unit = match &value[split..] {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(err()),
+    };
+    Ok(amount.saturating_mul(unit))
+}
+
+/// 리뷰 결과 출력 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Markdown,
+    Json,
+    /// GitLab Code Quality 리포트
+    CodeQuality,
+}
+
+/// `--export` 보고서 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Html,
+    Pdf,
+}
+
+/// `--export <FORMAT> <PATH>` 설정.
+#[derive(Debug, Clone)]
+pub struct ReviewExport {
+    pub format: ExportFormat,
+    pub path: PathBuf,
+}
+
+/// 리뷰 결과 파일/표준출력 기록 설정.
+#[derive(Debug, Clone)]
+pub struct ReviewOutput {
+    pub path: Option<PathBuf>,
+    pub format: OutputFormat,
+    pub export: Option<ReviewExport>,
+    /// `--autofix`로 검증한 수정 패치를 기록할 경로
+    pub autofix: Option<PathBuf>,
+}
+
+impl ReviewOutput {
+    /// 결과 문서(JSON/Code Quality)를 stdout으로 내보내는지. 이때 진행 출력은 stderr로 보내야 한다.
+    pub fn writes_stdout(&self) -> bool {
+        self.path.is_none() && self.format != OutputFormat::Markdown
+    }
+}
+
+/// 트래픽 기록/재생 모드(`--record` / `--replay`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrafficMode {
+    Record,
+    Replay,
+}
+
+/// `--record` / `--replay` 설정.
+#[derive(Debug, Clone)]
+pub struct TrafficOption {
+    pub mode: TrafficMode,
+    pub dir: PathBuf,
+}
+
+/// 모든 하위 명령에 공통인 콘솔 출력 설정(`--quiet`/`--verbose`/`--no-color`/`--progress-format`).
+#[derive(Debug, Clone, Copy)]
+pub struct OutputSettings {
+    pub verbosity: Verbosity,
+    pub no_color: bool,
+    pub progress_format: ProgressFormat,
+}
+
+/// `--fail-on`/`--create-issues` 임계 심각도.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum FailOnLevel {
+    Critical,
+    Major,
+    Minor,
+}
+
+impl FailOnLevel {
+    fn severity(self) -> Severity {
+        match self {
+            Self::Critical => Severity::Critical,
+            Self::Major => Severity::Major,
+            Self::Minor => Severity::Minor,
+        }
+    }
+}
+
+/// `--progress-format` 값.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ProgressFormatArg {
+    Text,
+    Ndjson,
+}
+
+impl ProgressFormatArg {
+    fn format(self) -> ProgressFormat {
+        match self {
+            Self::Text => ProgressFormat::Text,
+            Self::Ndjson => ProgressFormat::Ndjson,
+        }
+    }
 }
 
 #[derive(Debug, Subcommand)]
 enum Commands {
     /// Show effective merged config and provider command availability
-    Config,
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+        /// Print resolved tokens/API keys instead of masking them as **** (debugging only)
+        #[arg(long, global = true)]
+        show_secrets: bool,
+    },
+    /// Retry only the publish phase of a failed run from its saved run journal
+    Resume {
+        /// PR/MR URL
+        url: String,
+        /// Print markdown to stdout, do not post
+        #[arg(long)]
+        dry_run: bool,
+        /// Exit with code 3 if any finding at or above this severity exists
+        #[arg(long, value_enum)]
+        fail_on: Option<FailOnLevel>,
+        /// Open a follow-up issue for each consensus finding at or above this severity
+        #[arg(long, value_enum, value_name = "SEVERITY")]
+        create_issues: Option<FailOnLevel>,
+    },
+    /// Poll a PR/MR for `/repopilot review|ignore` comment commands and act on them
+    Watch {
+        /// PR/MR URL
+        url: String,
+        /// Seconds between comment polls
+        #[arg(long, default_value_t = 60)]
+        interval: u64,
+    },
+    /// Run REPL slash-commands from a script line by line (`-` reads stdin)
+    Batch {
+        /// Script path; blank lines and `#` comments are skipped
+        script: PathBuf,
+    },
+    /// Create .repopilot/config.json and review-guide.md for this repo, then test credentials
+    Init {
+        /// Overwrite an existing config file
+        #[arg(long)]
+        force: bool,
+        /// Do not ask; use hosts from git remotes and enable all providers
+        #[arg(long, short = 'y')]
+        yes: bool,
+    },
+    /// Replace this binary with the latest release after verifying its checksum/signature
+    SelfUpdate {
+        /// Only report whether a newer release exists for this platform
+        #[arg(long)]
+        check: bool,
+    },
+    /// Show run counts, average duration, token usage and estimated cost per repository/provider
+    Stats {
+        /// Only include runs from this recent period (e.g. 30d, 12h, 2w)
+        #[arg(long, value_parser = parse_since)]
+        since: Option<u64>,
+    },
+    /// Serve review_pr/get_config/list_recent_runs tools over MCP (JSON-RPC on stdin/stdout)
+    McpServe,
     /// OAuth login via VCS/provider CLI
     Auth {
         #[command(subcommand)]
@@ -35,6 +344,34 @@ enum Commands {
     },
 }
 
+#[derive(Debug, Subcommand)]
+enum ConfigAction {
+    /// Check config files for unknown keys, type mistakes and invalid values
+    Validate,
+    /// Rewrite config files with an old config_version to the current schema (keeps a .bak copy)
+    Migrate {
+        /// Rewrite YAML files in place even though their comments would be lost
+        /// (otherwise the migrated YAML is written next to the original)
+        #[arg(long)]
+        force: bool,
+    },
+    /// Print a merged config value (e.g. defaults.comment_language)
+    Get {
+        /// Dotted key path
+        key: String,
+    },
+    /// Write a value into the editable config file (e.g. providers.openai.model gpt-4.1)
+    Set {
+        /// Dotted key path
+        key: String,
+        /// Value (JSON numbers/booleans/arrays are accepted; otherwise stored as a string)
+        value: String,
+        /// Rewrite YAML files even though their comments would be lost
+        #[arg(long)]
+        force: bool,
+    },
+}
+
 #[derive(Debug, Subcommand)]
 enum AuthProvider {
     /// GitHub OAuth login (`gh auth login`). Requires GitHub CLI: `gh`
@@ -49,6 +386,11 @@ enum AuthProvider {
         #[arg(long, default_value = "gitlab.com")]
         host: String,
     },
+    /// Save a token/API key into the OS keyring (read from stdin, hidden on a terminal)
+    Store {
+        /// Keyring entry as <service>/<account> (e.g. repopilot/github.com)
+        entry: String,
+    },
     /// OpenAI/Codex OAuth login (provider CLI). Requires `codex`
     Codex,
     /// Anthropic/Claude OAuth login (provider CLI). Requires `claude`
@@ -59,18 +401,168 @@ enum AuthProvider {
 
 pub enum CliAction {
     Interactive,
-    InspectConfig,
-    Review(RunOptions),
+    /// `--tui`: ratatui 전체 화면 조종석으로 실행하는 대화형 모드
+    Cockpit,
+    InspectConfig { show_secrets: bool },
+    ValidateConfig,
+    MigrateConfig { force: bool },
+    GetConfig { key: String, show_secrets: bool },
+    SetConfig { key: String, value: String, force: bool },
+    Review {
+        options: RunOptions,
+        output: ReviewOutput,
+        traffic: Option<TrafficOption>,
+    },
+    BatchReview {
+        targets: Vec<RunOptions>,
+        jobs: usize,
+        traffic: Option<TrafficOption>,
+    },
+    Resume(RunOptions),
+    Watch { options: RunOptions, interval_secs: u64 },
+    Script { path: PathBuf },
+    Init { force: bool, assume_defaults: bool },
+    SelfUpdate { check_only: bool },
+    Stats { since_secs: Option<u64> },
+    McpServe,
     Auth { kind: VcsAuthKind, host: String },
     AuthProvider { kind: ProviderAuthKind },
+    StoreSecret { entry: String },
 }
 
 impl Cli {
-    pub fn parse_action() -> Result<CliAction, String> {
+    /// 인자를 파싱해 실행할 동작과 콘솔 출력 설정을 반환한다.
+    pub fn parse_action() -> Result<(CliAction, OutputSettings), String> {
         let cli = Cli::parse();
+        let verbosity = if cli.quiet {
+            Verbosity::Quiet
+        } else if cli.verbose {
+            Verbosity::Verbose
+        } else {
+            Verbosity::Normal
+        };
+        let output = OutputSettings {
+            verbosity,
+            no_color: cli.no_color,
+            progress_format: cli.progress_format.format(),
+        };
+        // NDJSON 이벤트와 stdout 결과 출력이 섞이지 않게 한다.
+        if output.progress_format == ProgressFormat::Ndjson
+            && cli.output.is_none()
+            && cli.format != OutputFormat::Markdown
+        {
+            return Err(
+                "--progress-format ndjson writes events to stdout; use --output with --format"
+                    .to_string(),
+            );
+        }
+        cli.into_action().map(|action| (action, output))
+    }
 
+    fn into_action(self) -> Result<CliAction, String> {
+        let cli = self;
+        let traffic = match (&cli.record, &cli.replay) {
+            (Some(dir), _) => Some(TrafficOption {
+                mode: TrafficMode::Record,
+                dir: dir.clone(),
+            }),
+            (None, Some(dir)) => Some(TrafficOption {
+                mode: TrafficMode::Replay,
+                dir: dir.clone(),
+            }),
+            (None, None) => None,
+        };
+        // 기록/재생은 URL을 받는 일회성 리뷰에만 연결되어 있다.
+        if traffic.is_some() && cli.command.is_some() {
+            return Err(
+                "--record/--replay only apply to reviews started with PR/MR URLs".to_string(),
+            );
+        }
+        if cli.tui
+            && (cli.command.is_some()
+                || !cli.urls.is_empty()
+                || cli.from_file.is_some()
+                || cli.gitlab_ci)
+        {
+            return Err(
+                "--tui starts the interactive cockpit; it cannot be combined with PR/MR URLs or subcommands"
+                    .to_string(),
+            );
+        }
         match cli.command {
-            Some(Commands::Config) => Ok(CliAction::InspectConfig),
+            Some(Commands::Config {
+                action: None,
+                show_secrets,
+            }) => Ok(CliAction::InspectConfig { show_secrets }),
+            Some(Commands::Config {
+                action: Some(ConfigAction::Validate),
+                ..
+            }) => Ok(CliAction::ValidateConfig),
+            Some(Commands::Config {
+                action: Some(ConfigAction::Migrate { force }),
+                ..
+            }) => Ok(CliAction::MigrateConfig { force }),
+            Some(Commands::Config {
+                action: Some(ConfigAction::Get { key }),
+                show_secrets,
+            }) => Ok(CliAction::GetConfig { key, show_secrets }),
+            Some(Commands::Config {
+                action: Some(ConfigAction::Set { key, value, force }),
+                ..
+            }) => Ok(CliAction::SetConfig { key, value, force }),
+            Some(Commands::Resume {
+                url,
+                dry_run,
+                fail_on,
+                create_issues,
+            }) => Ok(CliAction::Resume(RunOptions {
+                url,
+                dry_run,
+                force: false,
+                fail_on: fail_on.map(FailOnLevel::severity),
+                create_issues: create_issues.map(FailOnLevel::severity),
+                autofix: false,
+                autofix_push: false,
+                request_reviewers: false,
+                exclude: Vec::new(),
+                interactive_publish: false,
+                providers: Vec::new(),
+                model_overrides: Vec::new(),
+                comment_language: None,
+                profile: None,
+            })),
+            Some(Commands::Watch { url, interval }) => {
+                if interval == 0 {
+                    return Err("--interval must be at least 1".to_string());
+                }
+                Ok(CliAction::Watch {
+                    options: RunOptions {
+                        url,
+                        dry_run: false,
+                        force: false,
+                        fail_on: None,
+                        create_issues: None,
+                        autofix: false,
+                        autofix_push: false,
+                        request_reviewers: false,
+                        exclude: Vec::new(),
+                        interactive_publish: false,
+                        providers: Vec::new(),
+                        model_overrides: Vec::new(),
+                        comment_language: None,
+                        profile: cli.profile.clone(),
+                    },
+                    interval_secs: interval,
+                })
+            }
+            Some(Commands::Batch { script }) => Ok(CliAction::Script { path: script }),
+            Some(Commands::Init { force, yes }) => Ok(CliAction::Init {
+                force,
+                assume_defaults: yes,
+            }),
+            Some(Commands::SelfUpdate { check }) => Ok(CliAction::SelfUpdate { check_only: check }),
+            Some(Commands::Stats { since }) => Ok(CliAction::Stats { since_secs: since }),
+            Some(Commands::McpServe) => Ok(CliAction::McpServe),
             Some(Commands::Auth { provider }) => match provider {
                 AuthProvider::Github { host } => Ok(CliAction::Auth {
                     kind: VcsAuthKind::GitHub,
@@ -80,6 +572,7 @@ impl Cli {
                     kind: VcsAuthKind::GitLab,
                     host,
                 }),
+                AuthProvider::Store { entry } => Ok(CliAction::StoreSecret { entry }),
                 AuthProvider::Codex => Ok(CliAction::AuthProvider {
                     kind: ProviderAuthKind::Codex,
                 }),
@@ -91,16 +584,132 @@ impl Cli {
                 }),
             },
             None => {
-                let Some(url) = cli.url else {
+                let mut urls = cli.urls;
+                if let Some(path) = &cli.from_file {
+                    urls.extend(read_url_file(path)?);
+                }
+                if cli.gitlab_ci && urls.is_empty() {
+                    urls.push(gitlab_ci_merge_request_url()?);
+                }
+                if urls.is_empty() {
+                    if traffic.is_some() {
+                        return Err("--record/--replay require a PR/MR URL".to_string());
+                    }
+                    if cli.tui {
+                        return Ok(CliAction::Cockpit);
+                    }
                     return Ok(CliAction::Interactive);
-                };
+                }
+                if cli.jobs == 0 {
+                    return Err("--jobs must be at least 1".to_string());
+                }
+
+                let batch = urls.len() > 1 || cli.from_file.is_some();
+                if batch
+                    && (cli.output.is_some()
+                        || cli.format != OutputFormat::Markdown
+                        || cli.gitlab_ci
+                        || !cli.export.is_empty()
+                        || cli.autofix.is_some())
+                {
+                    return Err(
+                        "--output/--format/--export/--gitlab-ci/--autofix are not supported in batch mode"
+                            .to_string(),
+                    );
+                }
+                let export = parse_export(&cli.export)?;
+                if batch && cli.interactive_publish && cli.jobs > 1 {
+                    return Err("--interactive-publish cannot be combined with --jobs > 1".to_string());
+                }
 
-                Ok(CliAction::Review(RunOptions {
-                    url,
-                    dry_run: cli.dry_run,
-                    force: cli.force,
-                }))
+                let mut targets: Vec<RunOptions> = urls
+                    .into_iter()
+                    .map(|url| RunOptions {
+                        url,
+                        dry_run: cli.dry_run,
+                        force: cli.force,
+                        fail_on: cli.fail_on.map(FailOnLevel::severity),
+                        create_issues: cli.create_issues.map(FailOnLevel::severity),
+                        autofix: cli.autofix.is_some(),
+                        autofix_push: cli.autofix_push,
+                        request_reviewers: cli.request_reviewers,
+                        exclude: cli.exclude.clone(),
+                        interactive_publish: cli.interactive_publish,
+                        providers: cli.providers.clone(),
+                        model_overrides: cli.models.clone(),
+                        comment_language: cli.lang.clone(),
+                        profile: cli.profile.clone(),
+                    })
+                    .collect();
+
+                if batch {
+                    Ok(CliAction::BatchReview {
+                        targets,
+                        jobs: cli.jobs,
+                        traffic,
+                    })
+                } else if cli.gitlab_ci {
+                    Ok(CliAction::Review {
+                        options: targets.remove(0),
+                        output: ReviewOutput {
+                            path: Some(
+                                cli.output
+                                    .unwrap_or_else(|| PathBuf::from(GITLAB_CODE_QUALITY_REPORT)),
+                            ),
+                            format: OutputFormat::CodeQuality,
+                            export,
+                            autofix: cli.autofix,
+                        },
+                        traffic,
+                    })
+                } else {
+                    Ok(CliAction::Review {
+                        options: targets.remove(0),
+                        output: ReviewOutput {
+                            path: cli.output,
+                            format: cli.format,
+                            export,
+                            autofix: cli.autofix,
+                        },
+                        traffic,
+                    })
+                }
             }
         }
     }
 }
+
+/// GitLab MR 파이프라인 변수(`CI_MERGE_REQUEST_PROJECT_URL`, `CI_MERGE_REQUEST_IID`)로 MR URL을 만든다.
+fn gitlab_ci_merge_request_url() -> Result<String, String> {
+    let read = |name: &str| {
+        std::env::var(name)
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    };
+    match (
+        read("CI_MERGE_REQUEST_PROJECT_URL"),
+        read("CI_MERGE_REQUEST_IID"),
+    ) {
+        (Some(project_url), Some(iid)) => Ok(format!(
+            "{}/-/merge_requests/{iid}",
+            project_url.trim_end_matches('/')
+        )),
+        _ => Err(
+            "--gitlab-ci without a URL requires CI_MERGE_REQUEST_PROJECT_URL and CI_MERGE_REQUEST_IID (run in a merge request pipeline)"
+                .to_string(),
+        ),
+    }
+}
+
+/// URL 목록 파일을 읽는다(빈 줄과 `#` 주석은 무시).
+fn read_url_file(path: &Path) -> Result<Vec<String>, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(ToString::to_string)
+        .collect())
+}
@@ -1,32 +1,86 @@
 //! 애플리케이션 조립(composition root) 모듈.
 
-use crate::application::ports::UserConfirmer;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::Result;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::ports::{
+    BinaryInstaller, ClipboardReader, Clock, ConfigRepository, ConsoleLogSwitch, EditorLauncher,
+    FindingTriage, GitRemoteReader, HostTokenResolver, HtmlExporter, MarkdownRenderer, Notifier,
+    OutputWriter, PdfExporter, ProgressFormat, ProviderAuthenticator, ProviderFactory,
+    ReportRenderer, Reporter, ResponseCache, ReviewHookFactory, RunHistory, RunJournal,
+    SecretStore, SystemPromptResolver, TargetResolver, TemplateEngine, UpdateCheckCache,
+    UpdateChecker, UserConfirmer, VcsAuthenticator, VcsFactory, Verbosity,
+};
 use crate::application::usecases::auth_vcs::AuthVcsUseCase;
+use crate::application::usecases::batch_review::BatchReviewUseCase;
 use crate::application::usecases::check_update::CheckUpdateUseCase;
+use crate::application::usecases::config_value::ConfigValueUseCase;
 use crate::application::usecases::edit_config::EditConfigUseCase;
+use crate::application::usecases::init_project::InitProjectUseCase;
 use crate::application::usecases::inspect_config::InspectConfigUseCase;
+use crate::application::usecases::migrate_config::MigrateConfigUseCase;
 use crate::application::usecases::review_pr::ReviewPrUseCase;
+use crate::application::usecases::run_stats::RunStatsUseCase;
+use crate::application::usecases::self_update::SelfUpdateUseCase;
+use crate::application::usecases::store_secret::StoreSecretUseCase;
+use crate::application::usecases::suggest_targets::SuggestTargetsUseCase;
+use crate::application::usecases::validate_config::ValidateConfigUseCase;
+use crate::application::usecases::watch_commands::WatchCommandsUseCase;
 use crate::application::usecases::auth_provider::AuthProviderUseCase;
 use crate::infrastructure::adapters::{
-    ConsoleReporter, FileSystemPromptResolver, HostTokenResolverAdapter, HttpUpdateChecker,
-    JsonConfigRepository, MarkdownRendererAdapter, ProviderFactoryAdapter, StdinConfirmer,
-    ProviderAuthenticatorAdapter, UrlTargetResolver, VcsAuthenticatorAdapter, VcsFactoryAdapter,
+    AutoConfirmer, AutoFindingTriage, BrowserPdfExporter, ChannelNotifier, ConsoleReporter,
+    EnvEditorLauncher, ExeBinaryInstaller, FileHtmlExporter, FileResponseCache, FileRunJournal,
+    FileSystemPromptResolver, FileUpdateCheckCache, FsOutputWriter, GitCommandRemoteReader,
+    HostTokenResolverAdapter, HttpUpdateChecker, JsonConfigRepository, KeyringSecretStore,
+    LoggingReporter, MarkdownRendererAdapter, NdjsonReporter, ProviderAuthenticatorAdapter,
+    ProviderFactoryAdapter, ReportRendererAdapter, SqliteRunHistory, StdinConfirmer,
+    StdinFindingTriage, SystemClipboardReader, SystemClock, TeraTemplateEngine,
+    TracingConsoleLogSwitch, TrafficHostTokenResolver, TrafficProviderFactory, TrafficReporter,
+    TrafficVcsFactory, UrlTargetResolver, VcsAuthenticatorAdapter, VcsFactoryAdapter,
+    WasmReviewHookFactory,
 };
+use crate::infrastructure::http::HttpClients;
+use crate::infrastructure::traffic::{CaptureMode, TrafficCapture};
+use crate::interface::cli::command::{TrafficMode, TrafficOption};
+use crate::interface::cli::terminal;
 
 /// 실행 시점 의존성을 한 곳에서 조립하는 컨테이너.
+/// 기본 구현 대신 다른 어댑터를 쓰려면 `AppComposition::builder()`를 사용한다.
 pub struct AppComposition {
-    config_repo: JsonConfigRepository,
-    host_token_resolver: HostTokenResolverAdapter,
-    system_prompt_resolver: FileSystemPromptResolver,
-    target_resolver: UrlTargetResolver,
-    vcs_authenticator: VcsAuthenticatorAdapter,
-    provider_authenticator: ProviderAuthenticatorAdapter,
-    vcs_factory: VcsFactoryAdapter,
-    provider_factory: ProviderFactoryAdapter,
-    renderer: MarkdownRendererAdapter,
-    reporter: ConsoleReporter,
-    update_checker: HttpUpdateChecker,
+    config_repo: Box<dyn ConfigRepository>,
+    host_token_resolver: Box<dyn HostTokenResolver>,
+    system_prompt_resolver: Box<dyn SystemPromptResolver>,
+    target_resolver: Box<dyn TargetResolver>,
+    vcs_authenticator: Box<dyn VcsAuthenticator>,
+    provider_authenticator: Box<dyn ProviderAuthenticator>,
+    secret_store: Box<dyn SecretStore>,
+    vcs_factory: Box<dyn VcsFactory>,
+    provider_factory: Box<dyn ProviderFactory>,
+    review_hooks: Box<dyn ReviewHookFactory>,
+    renderer: Box<dyn MarkdownRenderer>,
+    templates: Box<dyn TemplateEngine>,
+    report_renderer: Box<dyn ReportRenderer>,
+    output_writer: Box<dyn OutputWriter>,
+    html_exporter: Box<dyn HtmlExporter>,
+    pdf_exporter: Box<dyn PdfExporter>,
+    reporter: Box<dyn Reporter>,
+    update_checker: Box<dyn UpdateChecker>,
+    update_check_cache: Box<dyn UpdateCheckCache>,
+    binary_installer: Box<dyn BinaryInstaller>,
+    run_journal: Box<dyn RunJournal>,
+    run_history: Box<dyn RunHistory>,
+    response_cache: Box<dyn ResponseCache>,
+    notifier: Box<dyn Notifier>,
     confirmer: Box<dyn UserConfirmer>,
+    finding_triage: Box<dyn FindingTriage>,
+    git_remotes: Box<dyn GitRemoteReader>,
+    clipboard: Box<dyn ClipboardReader>,
+    editor: Box<dyn EditorLauncher>,
+    console_log: Box<dyn ConsoleLogSwitch>,
+    clock: Box<dyn Clock>,
 }
 
 impl Default for AppComposition {
@@ -38,7 +92,9 @@ impl Default for AppComposition {
 impl AppComposition {
     /// provider 상태판 사용 여부를 받아 실행 조합을 생성한다.
     pub fn new(provider_panel_enabled: bool) -> Self {
-        Self::with_confirmer(provider_panel_enabled, Box::new(StdinConfirmer))
+        Self::builder()
+            .with_provider_panel(provider_panel_enabled)
+            .build()
     }
 
     /// 확인 어댑터를 외부에서 주입한다.
@@ -46,72 +102,584 @@ impl AppComposition {
         provider_panel_enabled: bool,
         confirmer: Box<dyn UserConfirmer>,
     ) -> Self {
-        Self {
-            config_repo: JsonConfigRepository,
-            host_token_resolver: HostTokenResolverAdapter,
-            system_prompt_resolver: FileSystemPromptResolver,
-            target_resolver: UrlTargetResolver,
-            vcs_authenticator: VcsAuthenticatorAdapter,
-            provider_authenticator: ProviderAuthenticatorAdapter,
-            vcs_factory: VcsFactoryAdapter,
-            provider_factory: ProviderFactoryAdapter,
-            renderer: MarkdownRendererAdapter,
-            reporter: ConsoleReporter::with_provider_panel(provider_panel_enabled),
-            update_checker: HttpUpdateChecker,
-            confirmer,
-        }
+        Self::builder()
+            .with_provider_panel(provider_panel_enabled)
+            .with_confirmer(confirmer)
+            .build()
+    }
+
+    /// 기본 어댑터에서 시작해 필요한 포트만 교체하는 빌더를 만든다.
+    pub fn builder() -> AppCompositionBuilder {
+        AppCompositionBuilder::default()
+    }
+
+    /// 지적사항 triage 어댑터를 외부에서 주입한다.
+    pub fn with_finding_triage(mut self, finding_triage: Box<dyn FindingTriage>) -> Self {
+        self.finding_triage = finding_triage;
+        self
+    }
+
+    /// 리뷰 결과 보고서(`--format`/`--export`) 렌더러.
+    pub fn report_renderer(&self) -> &dyn ReportRenderer {
+        self.report_renderer.as_ref()
+    }
+
+    /// 리뷰 결과 파일(`--output`, `--autofix`) 기록.
+    pub fn output_writer(&self) -> &dyn OutputWriter {
+        self.output_writer.as_ref()
+    }
+
+    /// 리뷰 결과 HTML 보고서(`--export html`) 내보내기.
+    pub fn html_exporter(&self) -> &dyn HtmlExporter {
+        self.html_exporter.as_ref()
+    }
+
+    /// 리뷰 결과 PDF 보고서(`--export pdf`) 내보내기.
+    pub fn pdf_exporter(&self) -> &dyn PdfExporter {
+        self.pdf_exporter.as_ref()
+    }
+
+    /// 콘솔 로그 on/off(`--tui` 화면이 떠 있는 동안 stderr 로그를 멈춘다).
+    pub fn console_log(&self) -> &dyn ConsoleLogSwitch {
+        self.console_log.as_ref()
+    }
+
+    /// 실행 상태 디렉터리(REPL 입력 기록 등).
+    pub fn state_dir(&self) -> Option<PathBuf> {
+        self.config_repo.state_dir()
     }
 
     /// 최신 버전 알림 유스케이스를 생성한다.
     pub fn check_update_usecase(&self) -> CheckUpdateUseCase<'_> {
         CheckUpdateUseCase {
-            config_repo: &self.config_repo,
-            host_token_resolver: &self.host_token_resolver,
-            update_checker: &self.update_checker,
+            config_repo: self.config_repo.as_ref(),
+            host_token_resolver: self.host_token_resolver.as_ref(),
+            update_checker: self.update_checker.as_ref(),
+            cache: self.update_check_cache.as_ref(),
+            clock: self.clock.as_ref(),
+        }
+    }
+
+    /// 바이너리 자체 업데이트 유스케이스를 생성한다.
+    pub fn self_update_usecase(&self) -> SelfUpdateUseCase<'_> {
+        SelfUpdateUseCase {
+            config_repo: self.config_repo.as_ref(),
+            host_token_resolver: self.host_token_resolver.as_ref(),
+            update_checker: self.update_checker.as_ref(),
+            installer: self.binary_installer.as_ref(),
         }
     }
 
     /// VCS OAuth 인증 유스케이스를 생성한다.
     pub fn auth_vcs_usecase(&self) -> AuthVcsUseCase<'_> {
         AuthVcsUseCase {
-            authenticator: &self.vcs_authenticator,
+            config_repo: self.config_repo.as_ref(),
+            authenticator: self.vcs_authenticator.as_ref(),
         }
     }
 
     /// Provider OAuth 인증 유스케이스를 생성한다.
     pub fn auth_provider_usecase(&self) -> AuthProviderUseCase<'_> {
         AuthProviderUseCase {
-            config_repo: &self.config_repo,
-            authenticator: &self.provider_authenticator,
+            config_repo: self.config_repo.as_ref(),
+            authenticator: self.provider_authenticator.as_ref(),
+        }
+    }
+
+    /// 키체인 비밀값 저장 유스케이스를 생성한다.
+    pub fn store_secret_usecase(&self) -> StoreSecretUseCase<'_> {
+        StoreSecretUseCase {
+            secret_store: self.secret_store.as_ref(),
         }
     }
 
     /// 설정 편집 유스케이스를 생성한다.
     pub fn edit_config_usecase(&self) -> EditConfigUseCase<'_> {
         EditConfigUseCase {
-            config_repo: &self.config_repo,
+            config_repo: self.config_repo.as_ref(),
+            editor: self.editor.as_ref(),
         }
     }
 
     /// 설정 점검 유스케이스를 생성한다.
     pub fn inspect_config_usecase(&self) -> InspectConfigUseCase<'_> {
         InspectConfigUseCase {
-            config_repo: &self.config_repo,
+            config_repo: self.config_repo.as_ref(),
+        }
+    }
+
+    /// 설정 키 조회/수정 유스케이스를 생성한다.
+    pub fn config_value_usecase(&self) -> ConfigValueUseCase<'_> {
+        ConfigValueUseCase {
+            config_repo: self.config_repo.as_ref(),
+        }
+    }
+
+    /// 설정 스키마 마이그레이션 유스케이스를 생성한다.
+    pub fn migrate_config_usecase(&self) -> MigrateConfigUseCase<'_> {
+        MigrateConfigUseCase {
+            config_repo: self.config_repo.as_ref(),
+        }
+    }
+
+    /// 설정 검증 유스케이스를 생성한다.
+    pub fn validate_config_usecase(&self) -> ValidateConfigUseCase<'_> {
+        ValidateConfigUseCase {
+            config_repo: self.config_repo.as_ref(),
         }
     }
 
     /// 리뷰 실행 유스케이스를 생성한다.
     pub fn review_usecase(&self) -> ReviewPrUseCase<'_> {
         ReviewPrUseCase {
-            config_repo: &self.config_repo,
-            host_token_resolver: &self.host_token_resolver,
-            system_prompt_resolver: &self.system_prompt_resolver,
-            target_resolver: &self.target_resolver,
-            vcs_factory: &self.vcs_factory,
-            provider_factory: &self.provider_factory,
-            renderer: &self.renderer,
-            reporter: &self.reporter,
+            config_repo: self.config_repo.as_ref(),
+            host_token_resolver: self.host_token_resolver.as_ref(),
+            system_prompt_resolver: self.system_prompt_resolver.as_ref(),
+            target_resolver: self.target_resolver.as_ref(),
+            vcs_factory: self.vcs_factory.as_ref(),
+            provider_factory: self.provider_factory.as_ref(),
+            review_hooks: self.review_hooks.as_ref(),
+            renderer: self.renderer.as_ref(),
+            templates: self.templates.as_ref(),
+            reporter: self.reporter.as_ref(),
             confirmer: self.confirmer.as_ref(),
+            finding_triage: self.finding_triage.as_ref(),
+            run_journal: self.run_journal.as_ref(),
+            run_history: self.run_history.as_ref(),
+            response_cache: self.response_cache.as_ref(),
+            notifier: self.notifier.as_ref(),
+            clock: self.clock.as_ref(),
+            cancel: CancellationToken::new(),
+        }
+    }
+
+    /// 실행 기록 집계 유스케이스를 생성한다.
+    pub fn run_stats_usecase(&self) -> RunStatsUseCase<'_> {
+        RunStatsUseCase {
+            run_history: self.run_history.as_ref(),
+            clock: self.clock.as_ref(),
+        }
+    }
+
+    /// REPL `/review` URL 자동 완성 후보 유스케이스를 생성한다.
+    pub fn suggest_targets_usecase(&self) -> SuggestTargetsUseCase<'_> {
+        SuggestTargetsUseCase {
+            config_repo: self.config_repo.as_ref(),
+            host_token_resolver: self.host_token_resolver.as_ref(),
+            target_resolver: self.target_resolver.as_ref(),
+            vcs_factory: self.vcs_factory.as_ref(),
+            git_remotes: self.git_remotes.as_ref(),
+            clipboard: self.clipboard.as_ref(),
+        }
+    }
+
+    /// 프로젝트 설정 초기화 유스케이스를 생성한다.
+    pub fn init_project_usecase(&self) -> InitProjectUseCase<'_> {
+        InitProjectUseCase {
+            config_repo: self.config_repo.as_ref(),
+            host_token_resolver: self.host_token_resolver.as_ref(),
+            vcs_factory: self.vcs_factory.as_ref(),
+            git_remotes: self.git_remotes.as_ref(),
+        }
+    }
+
+    /// 여러 URL 배치 리뷰 유스케이스를 생성한다.
+    pub fn batch_review_usecase(&self) -> BatchReviewUseCase<'_> {
+        BatchReviewUseCase {
+            review: self.review_usecase(),
+        }
+    }
+
+    /// PR/MR 코멘트 명령 watch 유스케이스를 생성한다.
+    pub fn watch_commands_usecase(&self) -> WatchCommandsUseCase<'_> {
+        WatchCommandsUseCase {
+            review: self.review_usecase(),
+        }
+    }
+}
+
+/// `AppComposition` 빌더.
+/// 지정하지 않은 포트는 기본 인프라 어댑터(파일 설정, HTTP VCS, 콘솔 출력, stdin 입력)를 사용한다.
+#[derive(Default)]
+pub struct AppCompositionBuilder {
+    provider_panel_enabled: Option<bool>,
+    verbosity: Verbosity,
+    progress_format: ProgressFormat,
+    progress_to_stderr: bool,
+    config_repo: Option<Box<dyn ConfigRepository>>,
+    host_token_resolver: Option<Box<dyn HostTokenResolver>>,
+    system_prompt_resolver: Option<Box<dyn SystemPromptResolver>>,
+    target_resolver: Option<Box<dyn TargetResolver>>,
+    vcs_authenticator: Option<Box<dyn VcsAuthenticator>>,
+    provider_authenticator: Option<Box<dyn ProviderAuthenticator>>,
+    secret_store: Option<Box<dyn SecretStore>>,
+    vcs_factory: Option<Box<dyn VcsFactory>>,
+    provider_factory: Option<Box<dyn ProviderFactory>>,
+    review_hooks: Option<Box<dyn ReviewHookFactory>>,
+    renderer: Option<Box<dyn MarkdownRenderer>>,
+    templates: Option<Box<dyn TemplateEngine>>,
+    report_renderer: Option<Box<dyn ReportRenderer>>,
+    output_writer: Option<Box<dyn OutputWriter>>,
+    html_exporter: Option<Box<dyn HtmlExporter>>,
+    pdf_exporter: Option<Box<dyn PdfExporter>>,
+    reporter: Option<Box<dyn Reporter>>,
+    update_checker: Option<Box<dyn UpdateChecker>>,
+    update_check_cache: Option<Box<dyn UpdateCheckCache>>,
+    binary_installer: Option<Box<dyn BinaryInstaller>>,
+    run_journal: Option<Box<dyn RunJournal>>,
+    run_history: Option<Box<dyn RunHistory>>,
+    response_cache: Option<Box<dyn ResponseCache>>,
+    notifier: Option<Box<dyn Notifier>>,
+    confirmer: Option<Box<dyn UserConfirmer>>,
+    finding_triage: Option<Box<dyn FindingTriage>>,
+    git_remotes: Option<Box<dyn GitRemoteReader>>,
+    clipboard: Option<Box<dyn ClipboardReader>>,
+    editor: Option<Box<dyn EditorLauncher>>,
+    console_log: Option<Box<dyn ConsoleLogSwitch>>,
+    clock: Option<Box<dyn Clock>>,
+    traffic: Option<Arc<TrafficCapture>>,
+}
+
+impl AppCompositionBuilder {
+    /// 기본 콘솔 reporter의 provider 상태판 사용 여부(기본 true).
+    pub fn with_provider_panel(mut self, enabled: bool) -> Self {
+        self.provider_panel_enabled = Some(enabled);
+        self
+    }
+
+    /// 기본 콘솔 reporter의 출력 상세도(기본 `Normal`).
+    pub fn with_verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// 기본 reporter의 진행 출력 형식(기본 `Text`). `Ndjson`이면 NDJSON 이벤트 리포터를 쓴다.
+    pub fn with_progress_format(mut self, format: ProgressFormat) -> Self {
+        self.progress_format = format;
+        self
+    }
+
+    /// 기본 reporter의 진행 출력을 stderr로 보낸다(결과 문서를 stdout으로 내보낼 때).
+    pub fn with_progress_to_stderr(mut self, enabled: bool) -> Self {
+        self.progress_to_stderr = enabled;
+        self
+    }
+
+    pub fn with_config_repository(mut self, config_repo: Box<dyn ConfigRepository>) -> Self {
+        self.config_repo = Some(config_repo);
+        self
+    }
+
+    pub fn with_host_token_resolver(mut self, resolver: Box<dyn HostTokenResolver>) -> Self {
+        self.host_token_resolver = Some(resolver);
+        self
+    }
+
+    pub fn with_system_prompt_resolver(mut self, resolver: Box<dyn SystemPromptResolver>) -> Self {
+        self.system_prompt_resolver = Some(resolver);
+        self
+    }
+
+    pub fn with_target_resolver(mut self, resolver: Box<dyn TargetResolver>) -> Self {
+        self.target_resolver = Some(resolver);
+        self
+    }
+
+    pub fn with_vcs_authenticator(mut self, authenticator: Box<dyn VcsAuthenticator>) -> Self {
+        self.vcs_authenticator = Some(authenticator);
+        self
+    }
+
+    pub fn with_provider_authenticator(
+        mut self,
+        authenticator: Box<dyn ProviderAuthenticator>,
+    ) -> Self {
+        self.provider_authenticator = Some(authenticator);
+        self
+    }
+
+    pub fn with_secret_store(mut self, secret_store: Box<dyn SecretStore>) -> Self {
+        self.secret_store = Some(secret_store);
+        self
+    }
+
+    /// 자체 `VcsGateway` 구현을 쓰려면 이를 생성하는 팩토리를 주입한다.
+    pub fn with_vcs_factory(mut self, factory: Box<dyn VcsFactory>) -> Self {
+        self.vcs_factory = Some(factory);
+        self
+    }
+
+    /// 자체 `ProviderAgent` 구현을 쓰려면 이를 생성하는 팩토리를 주입한다.
+    pub fn with_provider_factory(mut self, factory: Box<dyn ProviderFactory>) -> Self {
+        self.provider_factory = Some(factory);
+        self
+    }
+
+    /// 정책 훅(`pre_prompt`/`post_findings`)을 WASM 모듈 대신 직접 구현할 때 주입한다.
+    pub fn with_review_hooks(mut self, review_hooks: Box<dyn ReviewHookFactory>) -> Self {
+        self.review_hooks = Some(review_hooks);
+        self
+    }
+
+    pub fn with_renderer(mut self, renderer: Box<dyn MarkdownRenderer>) -> Self {
+        self.renderer = Some(renderer);
+        self
+    }
+
+    pub fn with_template_engine(mut self, templates: Box<dyn TemplateEngine>) -> Self {
+        self.templates = Some(templates);
+        self
+    }
+
+    pub fn with_report_renderer(mut self, renderer: Box<dyn ReportRenderer>) -> Self {
+        self.report_renderer = Some(renderer);
+        self
+    }
+
+    pub fn with_output_writer(mut self, writer: Box<dyn OutputWriter>) -> Self {
+        self.output_writer = Some(writer);
+        self
+    }
+
+    pub fn with_html_exporter(mut self, exporter: Box<dyn HtmlExporter>) -> Self {
+        self.html_exporter = Some(exporter);
+        self
+    }
+
+    pub fn with_pdf_exporter(mut self, exporter: Box<dyn PdfExporter>) -> Self {
+        self.pdf_exporter = Some(exporter);
+        self
+    }
+
+    pub fn with_reporter(mut self, reporter: Box<dyn Reporter>) -> Self {
+        self.reporter = Some(reporter);
+        self
+    }
+
+    pub fn with_update_checker(mut self, checker: Box<dyn UpdateChecker>) -> Self {
+        self.update_checker = Some(checker);
+        self
+    }
+
+    pub fn with_update_check_cache(mut self, cache: Box<dyn UpdateCheckCache>) -> Self {
+        self.update_check_cache = Some(cache);
+        self
+    }
+
+    pub fn with_binary_installer(mut self, installer: Box<dyn BinaryInstaller>) -> Self {
+        self.binary_installer = Some(installer);
+        self
+    }
+
+    pub fn with_run_journal(mut self, run_journal: Box<dyn RunJournal>) -> Self {
+        self.run_journal = Some(run_journal);
+        self
+    }
+
+    pub fn with_run_history(mut self, run_history: Box<dyn RunHistory>) -> Self {
+        self.run_history = Some(run_history);
+        self
+    }
+
+    pub fn with_response_cache(mut self, response_cache: Box<dyn ResponseCache>) -> Self {
+        self.response_cache = Some(response_cache);
+        self
+    }
+
+    pub fn with_notifier(mut self, notifier: Box<dyn Notifier>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    pub fn with_confirmer(mut self, confirmer: Box<dyn UserConfirmer>) -> Self {
+        self.confirmer = Some(confirmer);
+        self
+    }
+
+    pub fn with_finding_triage(mut self, finding_triage: Box<dyn FindingTriage>) -> Self {
+        self.finding_triage = Some(finding_triage);
+        self
+    }
+
+    /// stdin으로 확인/triage 입력을 받을 수 없는 실행(MCP 서버, 라이브러리 빌더)에서
+    /// 확인과 지적사항 triage를 모두 자동 승인하는 어댑터로 바꾼다.
+    pub fn non_interactive(self) -> Self {
+        self.with_confirmer(Box::new(AutoConfirmer))
+            .with_finding_triage(Box::new(AutoFindingTriage))
+    }
+
+    pub fn with_git_remote_reader(mut self, git_remotes: Box<dyn GitRemoteReader>) -> Self {
+        self.git_remotes = Some(git_remotes);
+        self
+    }
+
+    pub fn with_clipboard_reader(mut self, clipboard: Box<dyn ClipboardReader>) -> Self {
+        self.clipboard = Some(clipboard);
+        self
+    }
+
+    pub fn with_editor_launcher(mut self, editor: Box<dyn EditorLauncher>) -> Self {
+        self.editor = Some(editor);
+        self
+    }
+
+    pub fn with_console_log_switch(mut self, console_log: Box<dyn ConsoleLogSwitch>) -> Self {
+        self.console_log = Some(console_log);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Box<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// VCS/provider 트래픽과 진행 출력을 기록하거나 기록에서 재생한다(`--record` / `--replay`).
+    /// 토큰 해석기와 진행 출력은 기록/재생 어댑터로 감싸고, 직접 지정하지 않은 VCS/provider 팩토리를 바꾼다.
+    pub fn with_traffic_capture(mut self, capture: TrafficCapture) -> Self {
+        self.traffic = Some(Arc::new(capture));
+        self
+    }
+
+    /// `--record` / `--replay` 설정으로 트래픽 기록 파일을 열어 [`Self::with_traffic_capture`]에 넘긴다.
+    pub fn with_traffic(self, traffic: &TrafficOption) -> Result<Self> {
+        let mode = match traffic.mode {
+            TrafficMode::Record => CaptureMode::Record,
+            TrafficMode::Replay => CaptureMode::Replay,
+        };
+        Ok(self.with_traffic_capture(TrafficCapture::open(mode, &traffic.dir)?))
+    }
+
+    /// 지정하지 않은 포트를 기본 어댑터로 채워 조합을 완성한다.
+    pub fn build(self) -> AppComposition {
+        let provider_panel_enabled = self.provider_panel_enabled.unwrap_or(true);
+        let traffic = self.traffic;
+        // VCS/provider 팩토리가 만드는 클라이언트가 같은 연결 풀을 쓰도록 레지스트리를 함께 넘긴다.
+        let http = HttpClients::default();
+        let reporter: Box<dyn Reporter> =
+            self.reporter.unwrap_or_else(|| match self.progress_format {
+                ProgressFormat::Text => {
+                    let reporter = ConsoleReporter::with_provider_panel(provider_panel_enabled)
+                        .with_terminal(terminal::supports_ansi(), terminal::color_enabled())
+                        .with_verbosity(self.verbosity);
+                    if self.progress_to_stderr {
+                        Box::new(reporter.to_stderr())
+                    } else {
+                        Box::new(reporter)
+                    }
+                }
+                ProgressFormat::Ndjson => {
+                    let reporter = NdjsonReporter::new().with_verbosity(self.verbosity);
+                    if self.progress_to_stderr {
+                        Box::new(reporter.to_stderr())
+                    } else {
+                        Box::new(reporter)
+                    }
+                }
+            });
+        let reporter: Box<dyn Reporter> = match &traffic {
+            Some(capture) => Box::new(TrafficReporter::new(reporter, Arc::clone(capture))),
+            None => reporter,
+        };
+        AppComposition {
+            config_repo: self
+                .config_repo
+                .unwrap_or_else(|| Box::new(JsonConfigRepository)),
+            host_token_resolver: {
+                let resolver = self
+                    .host_token_resolver
+                    .unwrap_or_else(|| Box::new(HostTokenResolverAdapter));
+                match &traffic {
+                    Some(capture) => Box::new(TrafficHostTokenResolver {
+                        inner: resolver,
+                        capture: Arc::clone(capture),
+                    }),
+                    None => resolver,
+                }
+            },
+            system_prompt_resolver: self
+                .system_prompt_resolver
+                .unwrap_or_else(|| Box::new(FileSystemPromptResolver)),
+            target_resolver: self
+                .target_resolver
+                .unwrap_or_else(|| Box::new(UrlTargetResolver)),
+            vcs_authenticator: self
+                .vcs_authenticator
+                .unwrap_or_else(|| Box::new(VcsAuthenticatorAdapter)),
+            provider_authenticator: self
+                .provider_authenticator
+                .unwrap_or_else(|| Box::new(ProviderAuthenticatorAdapter)),
+            secret_store: self
+                .secret_store
+                .unwrap_or_else(|| Box::new(KeyringSecretStore)),
+            vcs_factory: self.vcs_factory.unwrap_or_else(|| match &traffic {
+                Some(capture) => Box::new(TrafficVcsFactory {
+                    capture: Arc::clone(capture),
+                    http: http.clone(),
+                }),
+                None => Box::new(VcsFactoryAdapter { http: http.clone() }),
+            }),
+            provider_factory: self.provider_factory.unwrap_or_else(|| match &traffic {
+                Some(capture) => Box::new(TrafficProviderFactory {
+                    capture: Arc::clone(capture),
+                    http: http.clone(),
+                }),
+                None => Box::new(ProviderFactoryAdapter { http: http.clone() }),
+            }),
+            review_hooks: self
+                .review_hooks
+                .unwrap_or_else(|| Box::new(WasmReviewHookFactory)),
+            renderer: self
+                .renderer
+                .unwrap_or_else(|| Box::new(MarkdownRendererAdapter)),
+            templates: self
+                .templates
+                .unwrap_or_else(|| Box::new(TeraTemplateEngine)),
+            report_renderer: self
+                .report_renderer
+                .unwrap_or_else(|| Box::new(ReportRendererAdapter)),
+            output_writer: self
+                .output_writer
+                .unwrap_or_else(|| Box::new(FsOutputWriter)),
+            html_exporter: self
+                .html_exporter
+                .unwrap_or_else(|| Box::new(FileHtmlExporter)),
+            pdf_exporter: self
+                .pdf_exporter
+                .unwrap_or_else(|| Box::new(BrowserPdfExporter)),
+            // 어떤 리포터든 진행 출력이 JSON 로그 파일에도 남도록 감싼다.
+            reporter: Box::new(LoggingReporter::new(reporter)),
+            update_checker: self
+                .update_checker
+                .unwrap_or_else(|| Box::new(HttpUpdateChecker)),
+            update_check_cache: self
+                .update_check_cache
+                .unwrap_or_else(|| Box::new(FileUpdateCheckCache)),
+            binary_installer: self
+                .binary_installer
+                .unwrap_or_else(|| Box::new(ExeBinaryInstaller)),
+            run_journal: self.run_journal.unwrap_or_else(|| Box::new(FileRunJournal)),
+            run_history: self
+                .run_history
+                .unwrap_or_else(|| Box::new(SqliteRunHistory)),
+            response_cache: self
+                .response_cache
+                .unwrap_or_else(|| Box::new(FileResponseCache)),
+            notifier: self.notifier.unwrap_or_else(|| Box::new(ChannelNotifier)),
+            confirmer: self.confirmer.unwrap_or_else(|| Box::new(StdinConfirmer)),
+            finding_triage: self
+                .finding_triage
+                .unwrap_or_else(|| Box::new(StdinFindingTriage)),
+            git_remotes: self
+                .git_remotes
+                .unwrap_or_else(|| Box::new(GitCommandRemoteReader)),
+            clipboard: self
+                .clipboard
+                .unwrap_or_else(|| Box::new(SystemClipboardReader)),
+            editor: self.editor.unwrap_or_else(|| Box::new(EnvEditorLauncher)),
+            console_log: self
+                .console_log
+                .unwrap_or_else(|| Box::new(TracingConsoleLogSwitch)),
+            clock: self.clock.unwrap_or_else(|| Box::new(SystemClock)),
         }
     }
 }
@@ -0,0 +1,171 @@
+//! `repopilot init` 프로젝트 설정 생성 흐름.
+//!
+//! 호스트/provider/코멘트 언어를 묻고 설정 파일과 리뷰 가이드를 만든 뒤,
+//! 호스트 토큰과 provider 실행 가능 여부를 점검해 출력한다.
+
+use std::io::{self, Write};
+
+use anyhow::{Result, bail};
+use serde_json::Value;
+
+use crate::application::ports::{ProjectInit, VcsAuthKind};
+use crate::application::usecases::init_project::HostCheck;
+use crate::domain::policy::canonical_provider_id;
+use crate::domain::review::CommentLanguage;
+use crate::interface::cli::composition::AppComposition;
+use crate::interface::cli::repl::provider_dashboard_lines;
+
+const ALL_PROVIDERS: [&str; 3] = ["openai", "anthropic", "gemini"];
+
+/// 질문에 답해(또는 `assume_defaults`이면 기본값으로) 프로젝트 설정을 생성한다.
+pub async fn run_init(
+    composition: &AppComposition,
+    force: bool,
+    assume_defaults: bool,
+) -> Result<()> {
+    let use_case = composition.init_project_usecase();
+
+    // git 원격이 없으면 예전 기본 템플릿처럼 github.com/gitlab.com을 모두 넣는다.
+    let mut default_hosts = use_case.detected_hosts();
+    if default_hosts.is_empty() {
+        default_hosts = vec![
+            ("github.com".to_string(), VcsAuthKind::GitHub),
+            ("gitlab.com".to_string(), VcsAuthKind::GitLab),
+        ];
+    }
+    let default_host_names = default_hosts
+        .iter()
+        .map(|(host, _)| host.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let init = if assume_defaults {
+        ProjectInit {
+            hosts: default_hosts,
+            providers: ALL_PROVIDERS.iter().map(ToString::to_string).collect(),
+            comment_language: "ko".to_string(),
+            force,
+        }
+    } else {
+        let hosts = ask("VCS hosts (comma separated)", &default_host_names)?;
+        let hosts = parse_hosts(&hosts, &default_hosts)?;
+        let providers = ask(
+            "Providers to enable (openai, anthropic, gemini)",
+            &ALL_PROVIDERS.join(","),
+        )?;
+        let providers = parse_providers(&providers)?;
+        let language = ask("Review comment language (BCP-47, e.g. ko, en)", "ko")?;
+        let Some(language) = CommentLanguage::parse(&language) else {
+            bail!("invalid language tag '{language}' (e.g. en, ko, ja, zh-CN)");
+        };
+        ProjectInit {
+            hosts,
+            providers,
+            comment_language: language.code().to_string(),
+            force,
+        }
+    };
+
+    let created = use_case.execute(&init)?;
+    println!();
+    println!("created {}", created.config_path.display());
+    if created.guide_created {
+        println!("created {}", created.guide_path.display());
+    } else {
+        println!("kept existing {}", created.guide_path.display());
+    }
+
+    println!();
+    println!("Credentials:");
+    for check in use_case.check_hosts(&init.hosts).await? {
+        println!("  - {}", host_check_line(&check, &init.hosts));
+    }
+    let inspection = composition.inspect_config_usecase().execute()?;
+    let value: Value = serde_json::from_str(&inspection)?;
+    for line in provider_dashboard_lines(&value) {
+        println!("  {line}");
+    }
+    println!();
+    println!(
+        "next: edit {} and run `repopilot <PR/MR URL> --dry-run`",
+        created.guide_path.display()
+    );
+    Ok(())
+}
+
+fn ask(question: &str, default: &str) -> Result<String> {
+    print!("{question} [{default}]: ");
+    io::stdout().flush()?;
+    let mut line = String::new();
+    io::stdin().read_line(&mut line)?;
+    let answer = line.trim();
+    Ok(if answer.is_empty() {
+        default.to_string()
+    } else {
+        answer.to_string()
+    })
+}
+
+/// 호스트 목록을 해석한다. git 원격에서 찾은 호스트는 그 종류를 쓰고,
+/// 그 외에는 원격 URL 해석과 같이 이름에 `gitlab`이 있으면 GitLab으로 본다.
+fn parse_hosts(
+    input: &str,
+    detected: &[(String, VcsAuthKind)],
+) -> Result<Vec<(String, VcsAuthKind)>> {
+    let mut hosts: Vec<(String, VcsAuthKind)> = Vec::new();
+    for host in input.split(',').map(str::trim).filter(|h| !h.is_empty()) {
+        if host.contains('/') || host.contains(' ') {
+            bail!("invalid host '{host}' (expected a hostname such as github.com)");
+        }
+        let kind = detected
+            .iter()
+            .find(|(name, _)| name == host)
+            .map(|(_, kind)| *kind)
+            .unwrap_or(if host.contains("gitlab") {
+                VcsAuthKind::GitLab
+            } else {
+                VcsAuthKind::GitHub
+            });
+        if !hosts.iter().any(|(name, _)| name == host) {
+            hosts.push((host.to_string(), kind));
+        }
+    }
+    Ok(hosts)
+}
+
+fn parse_providers(input: &str) -> Result<Vec<String>> {
+    let mut providers: Vec<String> = Vec::new();
+    for name in input.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+        let Some(id) = canonical_provider_id(name) else {
+            bail!("unknown provider '{name}' (expected openai, anthropic or gemini)");
+        };
+        if !providers.iter().any(|p| p == id) {
+            providers.push(id.to_string());
+        }
+    }
+    Ok(providers)
+}
+
+fn host_check_line(check: &HostCheck, hosts: &[(String, VcsAuthKind)]) -> String {
+    let Some(source) = &check.token_source else {
+        let auth = match hosts.iter().find(|(host, _)| *host == check.host) {
+            Some((_, VcsAuthKind::GitLab)) => "gitlab",
+            _ => "github",
+        };
+        return format!(
+            "{}: token missing (run `repopilot auth {auth} --host {}`)",
+            check.host, check.host
+        );
+    };
+    match &check.api {
+        Some(Ok(())) => format!("{}: token from {source}, API access ok", check.host),
+        Some(Err(err)) => format!(
+            "{}: token from {source}, API check failed: {err}",
+            check.host
+        ),
+        None => format!(
+            "{}: token from {source} (not verified: no git remote on this host)",
+            check.host
+        ),
+    }
+}
@@ -0,0 +1,295 @@
+//! MCP(Model Context Protocol) stdio 서버(`repopilot mcp-serve`).
+//!
+//! stdin/stdout으로 한 줄에 JSON-RPC 2.0 메시지 하나씩 주고받으며 `initialize`, `ping`,
+//! `tools/list`, `tools/call`을 처리한다. stdout은 프로토콜 전용이므로 리뷰 진행 출력은 stderr로 보낸다.
+
+use std::io::{self, Write};
+
+use anyhow::{Context, Result, anyhow, bail};
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, BufReader};
+
+use crate::application::ports::Reporter;
+use crate::domain::review::{RunOptions, Severity};
+use crate::interface::cli::AppComposition;
+
+// 클라이언트가 요청한 버전을 지원하면 그대로, 아니면 첫 번째(최신) 버전으로 응답한다.
+const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+const DEFAULT_RECENT_RUNS: u64 = 20;
+const MAX_RECENT_RUNS: u64 = 200;
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+
+/// stdin이 닫힐 때까지 MCP 요청을 하나씩 처리한다.
+/// 확인/triage 입력은 stdin을 쓸 수 없으므로 모두 자동 승인한다.
+pub async fn run_mcp_server() -> Result<()> {
+    let composition = AppComposition::builder()
+        .with_reporter(Box::new(StderrReporter))
+        .non_interactive()
+        .build();
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .context("failed to read MCP message from stdin")?
+    {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let response = match serde_json::from_str::<Value>(line) {
+            Ok(message) => handle_message(&composition, message).await,
+            Err(err) => Some(error_response(
+                Value::Null,
+                PARSE_ERROR,
+                &format!("parse error: {err}"),
+            )),
+        };
+        if let Some(response) = response {
+            write_message(&response)?;
+        }
+    }
+    Ok(())
+}
+
+fn write_message(message: &Value) -> Result<()> {
+    let mut out = io::stdout().lock();
+    writeln!(out, "{message}").context("failed to write MCP message to stdout")?;
+    out.flush().context("failed to flush stdout")
+}
+
+/// 요청 하나를 처리한다. 알림(id 없음)과 클라이언트 응답에는 답하지 않는다.
+async fn handle_message(composition: &AppComposition, message: Value) -> Option<Value> {
+    let Some(method) = message.get("method").and_then(Value::as_str) else {
+        if message.get("result").is_some() || message.get("error").is_some() {
+            return None;
+        }
+        let id = message.get("id").cloned().unwrap_or(Value::Null);
+        return Some(error_response(id, INVALID_REQUEST, "invalid request"));
+    };
+    let id = message.get("id").cloned()?;
+    let params = message.get("params").cloned().unwrap_or_else(|| json!({}));
+
+    let result = match method {
+        "initialize" => Ok(initialize_result(&params)),
+        "ping" => Ok(json!({})),
+        "tools/list" => Ok(json!({ "tools": tool_definitions() })),
+        "tools/call" => call_tool(composition, &params).await,
+        _ => Err((METHOD_NOT_FOUND, format!("method not found: {method}"))),
+    };
+    Some(match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err((code, message)) => error_response(id, code, &message),
+    })
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
+
+fn initialize_result(params: &Value) -> Value {
+    let requested = params.get("protocolVersion").and_then(Value::as_str);
+    let version = requested
+        .filter(|v| SUPPORTED_PROTOCOL_VERSIONS.contains(v))
+        .unwrap_or(SUPPORTED_PROTOCOL_VERSIONS[0]);
+    json!({
+        "protocolVersion": version,
+        "capabilities": { "tools": {} },
+        "serverInfo": {
+            "name": "repopilot",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+    })
+}
+
+fn tool_definitions() -> Value {
+    json!([
+        {
+            "name": "review_pr",
+            "description": "Run a multi-provider code review on a GitHub PR or GitLab MR URL and return the outcome as JSON (findings, consensus, usage, summary markdown). Runs as a dry run unless dry_run is false.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "url": { "type": "string", "description": "PR/MR URL" },
+                    "dry_run": { "type": "boolean", "description": "Do not post comments (default true)" },
+                    "force": { "type": "boolean", "description": "Review again even if this head SHA was already reviewed" },
+                    "providers": { "type": "array", "items": { "type": "string" }, "description": "Provider ids to run (default: all enabled)" },
+                    "profile": { "type": "string", "description": "Config profile name (profiles.<name>)" },
+                    "fail_on": { "type": "string", "enum": ["critical", "major", "minor", "suggestion"], "description": "Report a severity gate result for findings at or above this level" }
+                },
+                "required": ["url"]
+            }
+        },
+        {
+            "name": "get_config",
+            "description": "Return the effective merged repopilot config with secrets masked, or a single value when key is given.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "key": { "type": "string", "description": "Dotted key path (e.g. defaults.comment_language)" }
+                }
+            }
+        },
+        {
+            "name": "list_recent_runs",
+            "description": "List recent review runs from the local run history, newest first.",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "limit": { "type": "integer", "minimum": 1, "maximum": MAX_RECENT_RUNS, "description": "Maximum number of runs (default 20)" }
+                }
+            }
+        }
+    ])
+}
+
+/// 도구를 실행한다. 도구 실행 실패는 JSON-RPC 오류가 아니라 `isError` 결과로 돌려준다.
+async fn call_tool(composition: &AppComposition, params: &Value) -> Result<Value, (i64, String)> {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or((INVALID_PARAMS, "missing tool name".to_string()))?;
+    let empty = json!({});
+    let args = params.get("arguments").unwrap_or(&empty);
+
+    let result = match name {
+        "review_pr" => review_pr(composition, args).await,
+        "get_config" => get_config(composition, args),
+        "list_recent_runs" => list_recent_runs(composition, args),
+        _ => return Err((INVALID_PARAMS, format!("unknown tool: {name}"))),
+    };
+    let (text, is_error) = match result {
+        Ok(text) => (text, false),
+        Err(err) => (format!("{err:#}"), true),
+    };
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+        "isError": is_error,
+    }))
+}
+
+async fn review_pr(composition: &AppComposition, args: &Value) -> Result<String> {
+    let url = string_arg(args, "url")?.ok_or_else(|| anyhow!("`url` is required"))?;
+    let fail_on = string_arg(args, "fail_on")?
+        .map(|value| Severity::parse(&value).ok_or_else(|| anyhow!("unknown fail_on: {value}")))
+        .transpose()?;
+    let providers = match args.get("providers") {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .map(|item| {
+                item.as_str()
+                    .map(ToString::to_string)
+                    .ok_or_else(|| anyhow!("`providers` must be an array of strings"))
+            })
+            .collect::<Result<_>>()?,
+        Some(_) => bail!("`providers` must be an array of strings"),
+    };
+    let options = RunOptions {
+        url,
+        dry_run: bool_arg(args, "dry_run")?.unwrap_or(true),
+        force: bool_arg(args, "force")?.unwrap_or(false),
+        fail_on,
+        create_issues: None,
+        autofix: false,
+        autofix_push: false,
+        request_reviewers: false,
+        exclude: Vec::new(),
+        interactive_publish: false,
+        providers,
+        model_overrides: Vec::new(),
+        comment_language: None,
+        profile: string_arg(args, "profile")?,
+    };
+    let outcome = composition.review_usecase().execute(options).await?;
+    composition.report_renderer().render_json(&outcome)
+}
+
+fn get_config(composition: &AppComposition, args: &Value) -> Result<String> {
+    match string_arg(args, "key")? {
+        Some(key) => match composition.config_value_usecase().get(&key)? {
+            Some(value) => Ok(serde_json::to_string_pretty(&value)?),
+            None => bail!("config key is not set: {key}"),
+        },
+        None => composition.inspect_config_usecase().execute(),
+    }
+}
+
+fn list_recent_runs(composition: &AppComposition, args: &Value) -> Result<String> {
+    let limit = match args.get("limit") {
+        None | Some(Value::Null) => DEFAULT_RECENT_RUNS,
+        Some(value) => value
+            .as_u64()
+            .filter(|limit| (1..=MAX_RECENT_RUNS).contains(limit))
+            .ok_or_else(|| anyhow!("`limit` must be an integer between 1 and {MAX_RECENT_RUNS}"))?,
+    };
+    let runs = composition.run_stats_usecase().recent(limit as usize)?;
+    let runs: Vec<Value> = runs
+        .iter()
+        .map(|run| {
+            json!({
+                "started_at": run.started_at,
+                "repository": run.repository,
+                "target_url": run.target_url,
+                "head_sha": run.head_sha,
+                "outcome": run.outcome,
+                "duration_secs": run.duration_secs,
+                "total_tokens": run.total_tokens,
+                "cost_usd": run.cost_usd,
+            })
+        })
+        .collect();
+    Ok(serde_json::to_string_pretty(&runs)?)
+}
+
+fn string_arg(args: &Value, key: &str) -> Result<Option<String>> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.trim().to_string()).filter(|v| !v.is_empty())),
+        Some(_) => bail!("`{key}` must be a string"),
+    }
+}
+
+fn bool_arg(args: &Value, key: &str) -> Result<Option<bool>> {
+    match args.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Bool(value)) => Ok(Some(*value)),
+        Some(_) => bail!("`{key}` must be a boolean"),
+    }
+}
+
+/// 진행 출력을 stderr에 줄 단위로 쓰는 리포터(stdout은 MCP 메시지 전용).
+struct StderrReporter;
+
+impl Reporter for StderrReporter {
+    fn section(&self, name: &str) {
+        eprintln!("== {name} ==");
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        eprintln!("{key}: {value}");
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        eprintln!("[{scope}] {message}");
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        match elapsed_secs {
+            Some(secs) => eprintln!("[provider:{provider}] {status} {secs:.1}s"),
+            None => eprintln!("[provider:{provider}] {status}"),
+        }
+    }
+
+    fn raw(&self, line: &str) {
+        eprintln!("{line}");
+    }
+}
@@ -3,9 +3,23 @@
 
 pub mod command;
 pub mod composition;
+mod init;
+mod mcp;
+pub mod output;
 pub mod repl;
 pub mod repl_input;
+mod repl_progress;
+pub mod terminal;
+mod tui;
 
-pub use command::{Cli, CliAction};
-pub use composition::AppComposition;
-pub use repl::run_repl;
+pub use command::{
+    Cli, CliAction, ExportFormat, OutputFormat, OutputSettings, ReviewExport, ReviewOutput,
+    TrafficMode, TrafficOption,
+};
+pub use composition::{AppComposition, AppCompositionBuilder};
+pub use init::run_init;
+pub use mcp::run_mcp_server;
+pub use output::{print_config_validation, write_review_output};
+pub use repl::{run_repl, run_repl_script};
+pub use repl_input::read_secret_input;
+pub use tui::run_tui;
@@ -0,0 +1,92 @@
+//! 리뷰 결과 파일/표준출력 기록.
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::application::ports::ConfigValidation;
+use crate::application::usecases::review_pr::ReviewOutcome;
+use crate::interface::cli::AppComposition;
+use crate::interface::cli::command::{ExportFormat, OutputFormat, ReviewExport, ReviewOutput};
+
+/// `--output`/`--format`/`--export`/`--autofix` 설정에 맞춰 리뷰 결과를 기록한다.
+/// 경로 없이 markdown이면 이미 콘솔에 출력했으므로 `--output` 기록은 하지 않는다.
+pub fn write_review_output(
+    composition: &AppComposition,
+    outcome: &ReviewOutcome,
+    output: &ReviewOutput,
+) -> Result<()> {
+    if let Some(export) = &output.export {
+        write_review_export(composition, outcome, export)?;
+    }
+    if let Some(path) = &output.autofix {
+        write_autofix_patch(composition, outcome, path)?;
+    }
+    if output.path.is_none() && output.format == OutputFormat::Markdown {
+        return Ok(());
+    }
+
+    let renderer = composition.report_renderer();
+    let content = match output.format {
+        OutputFormat::Markdown => renderer.render_markdown(outcome)?,
+        OutputFormat::Json => renderer.render_json(outcome)?,
+        OutputFormat::CodeQuality => renderer.render_code_quality(outcome)?,
+    };
+
+    match &output.path {
+        Some(path) => {
+            composition.output_writer().write(path, &content)?;
+            eprintln!("review output written: {}", path.display());
+        }
+        None => println!("{content}"),
+    }
+    Ok(())
+}
+
+/// 리뷰 결과 전체를 단독 보고서(HTML/PDF)로 내보낸다.
+fn write_review_export(
+    composition: &AppComposition,
+    outcome: &ReviewOutcome,
+    export: &ReviewExport,
+) -> Result<()> {
+    let path = &export.path;
+    let html = composition.report_renderer().render_html(outcome)?;
+    match export.format {
+        ExportFormat::Html => composition.html_exporter().write_html(&html, path)?,
+        ExportFormat::Pdf => composition.pdf_exporter().write_pdf(&html, path)?,
+    }
+    eprintln!("review report exported: {}", path.display());
+    Ok(())
+}
+
+/// 검증한 수정 패치를 기록한다. 패치를 만들지 못했으면 기존 파일을 건드리지 않는다.
+fn write_autofix_patch(
+    composition: &AppComposition,
+    outcome: &ReviewOutcome,
+    path: &Path,
+) -> Result<()> {
+    let Some(autofix) = &outcome.autofix else {
+        if !outcome.skipped {
+            eprintln!("no autofix patch produced; {} not written", path.display());
+        }
+        return Ok(());
+    };
+    composition.output_writer().write(path, &autofix.patch)?;
+    eprintln!("autofix patch written: {}", path.display());
+    Ok(())
+}
+
+/// 설정 검증 결과를 출력한다. 문제가 없으면 true.
+pub fn print_config_validation(validation: &ConfigValidation) -> bool {
+    if validation.checked_paths.is_empty() {
+        println!("no config files found");
+    }
+    for path in &validation.checked_paths {
+        let ok = !validation.issues.iter().any(|issue| &issue.file == path);
+        println!("{} {}", if ok { "ok  " } else { "FAIL" }, path.display());
+    }
+    for issue in &validation.issues {
+        eprintln!("error: {issue}");
+    }
+    validation.issues.is_empty()
+}
@@ -1,24 +1,52 @@
 //! `RepoPilot` 대화형 쉘(REPL) 인터페이스.
 
-use std::io::{self, IsTerminal, Write};
-use std::process::Command;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use serde_json::Value;
+use tokio_util::sync::CancellationToken;
 
+use crate::application::ports::{ProviderAuthKind, VcsAuthKind};
+use crate::application::usecases::review_pr::ReviewCancelled;
+use crate::domain::policy::canonical_provider_id;
 use crate::domain::review::RunOptions;
+use crate::domain::target::OpenReview;
 use crate::interface::cli::composition::AppComposition;
-use crate::interface::cli::repl_input::read_repl_input;
+use crate::interface::cli::output::print_config_validation;
+use crate::interface::cli::repl_input::{
+    AUTH_USAGE, PROVIDERS_USAGE, REVIEW_USAGE, ReplHistory, ReviewUrlCandidate, read_repl_input,
+    read_secret_input, supports_interactive_input,
+};
+use crate::interface::cli::repl_progress::ReplProgressReporter;
+use crate::interface::cli::terminal::{self, paint};
 
 /// 대화형 입력으로 `/command`를 처리한다.
 pub async fn run_repl(composition: &AppComposition) -> Result<()> {
     print_welcome(composition);
     io::stdout().flush()?;
     let mut next_prefill: Option<String> = None;
+    let mut session = ReplSession::default();
+    let mut history = ReplHistory::load(composition.state_dir());
+
+    // `/review` Tab 완성용 열린 PR/MR 목록은 입력을 막지 않도록 백그라운드로 받아 둔다.
+    let open_reviews: Arc<Mutex<Vec<OpenReview>>> = Arc::default();
+    if supports_interactive_input() {
+        let query = composition.suggest_targets_usecase().open_reviews();
+        let slot = Arc::clone(&open_reviews);
+        tokio::spawn(async move {
+            let reviews = query.fetch().await;
+            *slot.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = reviews;
+        });
+    }
+    let review_urls = || review_url_candidates(composition, &open_reviews);
 
     loop {
         let prefill = next_prefill.take();
-        let Some(raw_input) = read_repl_input(prefill.as_deref())? else {
+        let Some(raw_input) = read_repl_input(prefill.as_deref(), &mut history, &review_urls)?
+        else {
             println!();
             break;
         };
@@ -34,7 +62,7 @@ pub async fn run_repl(composition: &AppComposition) -> Result<()> {
                 next_prefill = Some("/review ".to_string());
             }
             Ok(cmd) => {
-                if let Err(err) = execute_command(composition, cmd).await {
+                if let Err(err) = execute_command(composition, &mut session, cmd).await {
                     eprintln!("error: {err:#}");
                 }
             }
@@ -48,16 +76,140 @@ pub async fn run_repl(composition: &AppComposition) -> Result<()> {
     Ok(())
 }
 
-enum ReplCommand {
+/// 스크립트(`-`이면 표준 입력)의 slash-command를 한 줄씩 실행한다.
+/// 빈 줄과 `#` 주석은 건너뛰고, 첫 실패에서 멈춘다. `/exit`를 만나면 남은 줄을 실행하지 않는다.
+pub async fn run_repl_script(composition: &AppComposition, path: &Path) -> Result<()> {
+    let script = if path == Path::new("-") {
+        let mut script = String::new();
+        io::stdin()
+            .read_to_string(&mut script)
+            .context("failed to read script from stdin")?;
+        script
+    } else {
+        fs::read_to_string(path)
+            .with_context(|| format!("failed to read script {}", path.display()))?
+    };
+
+    let mut session = ReplSession {
+        scripted: true,
+        ..ReplSession::default()
+    };
+    for (idx, line) in script.lines().enumerate() {
+        let input = line.trim();
+        if input.is_empty() || input.starts_with('#') {
+            continue;
+        }
+        let line_no = idx + 1;
+        println!("repopilot> {input}");
+        let command = match parse_repl_command(input) {
+            Ok(ReplCommand::Exit) => break,
+            Ok(ReplCommand::ReviewNeedsArgs) => bail!("line {line_no}: usage: {REVIEW_USAGE}"),
+            // 편집기/로그인 CLI는 터미널 입력이 필요하므로 스크립트에서는 쓰지 않는다.
+            Ok(ReplCommand::EditConfig | ReplCommand::Auth(_)) => {
+                bail!("line {line_no}: `{input}` needs an interactive terminal")
+            }
+            Ok(command) => command,
+            Err(msg) => bail!("line {line_no}: {msg}"),
+        };
+        execute_command(composition, &mut session, command)
+            .await
+            .with_context(|| format!("line {line_no}: {input}"))?;
+    }
+    Ok(())
+}
+
+/// `/review` Tab 완성 후보: 클립보드의 PR/MR URL을 먼저, 이어서 git 원격 저장소의 열린 PR/MR.
+fn review_url_candidates(
+    composition: &AppComposition,
+    open_reviews: &Mutex<Vec<OpenReview>>,
+) -> Vec<ReviewUrlCandidate> {
+    let mut candidates: Vec<ReviewUrlCandidate> = composition
+        .suggest_targets_usecase()
+        .clipboard_url()
+        .map(|url| ReviewUrlCandidate {
+            url,
+            label: "clipboard".to_string(),
+        })
+        .into_iter()
+        .collect();
+    let reviews = open_reviews
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    for review in reviews.iter() {
+        if candidates.iter().any(|c| c.url == review.url) {
+            continue;
+        }
+        candidates.push(ReviewUrlCandidate {
+            url: review.url.clone(),
+            label: format!("#{} {}", review.number, review.title),
+        });
+    }
+    candidates
+}
+
+/// REPL 세션 동안 유지되는 상태.
+#[derive(Default)]
+pub(super) struct ReplSession {
+    /// `/profile <name>`으로 고른 프로필. `/review`에 `--profile`이 없으면 이 값을 쓴다.
+    profile: Option<String>,
+    /// `/providers enable|disable`로 고른 provider id. None이면 설정에서 활성화된 provider 전체.
+    /// `/review`에 `--providers`가 없으면 프로필보다 먼저 이 값을 쓴다.
+    providers: Option<Vec<String>>,
+    /// `repopilot batch` 스크립트 실행 여부. 진행 상태판을 띄우지 않고 리뷰 취소를 실패로 본다.
+    scripted: bool,
+}
+
+impl ReplSession {
+    /// `/review`에 빠진 `--profile`/`--providers`를 세션에서 고른 값으로 채운다.
+    pub(super) fn apply_defaults(&self, options: &mut RunOptions) {
+        if options.profile.is_none() {
+            options.profile = self.profile.clone();
+        }
+        if options.providers.is_empty()
+            && let Some(providers) = &self.providers
+        {
+            options.providers = providers.clone();
+        }
+    }
+}
+
+/// REPL에서 다루는 provider id(표시 순서).
+const PROVIDER_IDS: [&str; 3] = ["openai", "anthropic", "gemini"];
+
+pub(super) enum ReplCommand {
     Exit,
     InspectConfig,
     EditConfig,
+    ValidateConfig,
     /// `/review`만 입력된 상태. 다음 입력 라운드에 `/review `를 프리필한다.
     ReviewNeedsArgs,
     Review(RunOptions),
+    /// `/profile [name|off]`. None이면 현재/사용 가능한 프로필을 보여준다.
+    Profile(Option<String>),
+    /// `/providers list|enable <name>|disable <name>`
+    Providers(ProvidersAction),
+    /// `/auth <target>`: CLI `repopilot auth <target>`과 같은 인증 흐름
+    Auth(AuthTarget),
 }
 
-async fn execute_command(composition: &AppComposition, command: ReplCommand) -> Result<()> {
+pub(super) enum AuthTarget {
+    Vcs(VcsAuthKind, String),
+    Provider(ProviderAuthKind),
+    /// OS 키체인 항목(`<service>/<account>`)에 비밀값 저장
+    Store(String),
+}
+
+pub(super) enum ProvidersAction {
+    List,
+    Enable(&'static str),
+    Disable(&'static str),
+}
+
+pub(super) async fn execute_command(
+    composition: &AppComposition,
+    session: &mut ReplSession,
+    command: ReplCommand,
+) -> Result<()> {
     match command {
         ReplCommand::Exit => Ok(()),
         ReplCommand::InspectConfig => {
@@ -66,33 +218,222 @@ async fn execute_command(composition: &AppComposition, command: ReplCommand) ->
             Ok(())
         }
         ReplCommand::EditConfig => {
-            let path = composition.edit_config_usecase().execute()?;
-            let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+            let usecase = composition.edit_config_usecase();
+            let path = usecase.execute()?;
 
             // 에디터가 정상 동작하도록 raw mode를 해제한다.
             let _ = crossterm::terminal::disable_raw_mode();
-            let status = Command::new(&editor)
-                .arg(&path)
-                .status()
-                .with_context(|| format!("failed to launch editor: {editor}"))?;
+            let result = usecase.open_in_editor(&path);
             let _ = crossterm::terminal::enable_raw_mode();
 
-            if status.success() {
-                println!("config saved: {}", path.display());
-            } else {
-                eprintln!("editor exited with: {status}");
+            match result {
+                Ok(()) => println!("config saved: {}", path.display()),
+                Err(err) => eprintln!("{err:#}"),
             }
             Ok(())
         }
+        ReplCommand::ValidateConfig => {
+            let validation = composition.validate_config_usecase().execute()?;
+            print_config_validation(&validation);
+            Ok(())
+        }
         ReplCommand::ReviewNeedsArgs => Ok(()),
-        ReplCommand::Review(options) => {
-            composition.review_usecase().execute(options).await?;
+        ReplCommand::Review(mut options) => {
+            session.apply_defaults(&mut options);
+            // 리뷰 중 Ctrl-C는 REPL을 끝내지 않고 진행 중인 리뷰만 취소한다.
+            let cancel = CancellationToken::new();
+            let watcher = tokio::spawn({
+                let cancel = cancel.clone();
+                async move {
+                    if tokio::signal::ctrl_c().await.is_ok() {
+                        cancel.cancel();
+                    }
+                }
+            });
+            let result = if session.scripted {
+                composition
+                    .review_usecase()
+                    .with_cancellation(cancel)
+                    .execute(options)
+                    .await
+            } else {
+                // 진행 상태판은 이 리뷰 동안만 하단 패널로 표시한다.
+                let progress = ReplProgressReporter::start();
+                let result = composition
+                    .review_usecase()
+                    .with_cancellation(cancel)
+                    .with_reporter(&progress)
+                    .execute(options)
+                    .await;
+                drop(progress);
+                result
+            };
+            watcher.abort();
+            match result {
+                Ok(_) => Ok(()),
+                // 스크립트에서는 취소를 실패로 전달해 남은 명령을 실행하지 않는다.
+                Err(err)
+                    if !session.scripted && err.downcast_ref::<ReviewCancelled>().is_some() =>
+                {
+                    eprintln!("{err}");
+                    Ok(())
+                }
+                Err(err) => Err(err),
+            }
+        }
+        ReplCommand::Profile(None) => {
+            let names = profile_names(composition)?;
+            let active = session.profile.as_deref().unwrap_or("none");
+            println!("active profile: {active}");
+            if names.is_empty() {
+                println!("available: none (add `profiles.<name>` to config)");
+            } else {
+                println!("available: {}", names.join(", "));
+            }
+            Ok(())
+        }
+        ReplCommand::Profile(Some(name)) => {
+            if name.eq_ignore_ascii_case("off") {
+                session.profile = None;
+                println!("profile cleared");
+                return Ok(());
+            }
+            let names = profile_names(composition)?;
+            let Some(found) = names.iter().find(|n| n.eq_ignore_ascii_case(&name)) else {
+                let available = if names.is_empty() {
+                    "none".to_string()
+                } else {
+                    names.join(", ")
+                };
+                anyhow::bail!("unknown profile '{name}' (available: {available})");
+            };
+            session.profile = Some(found.clone());
+            println!("profile set: {found}");
+            Ok(())
+        }
+        ReplCommand::Auth(target) => {
+            // 인증 CLI가 터미널을 직접 다루도록 raw mode를 해제한다.
+            let _ = crossterm::terminal::disable_raw_mode();
+            match target {
+                AuthTarget::Vcs(kind, host) => {
+                    composition.auth_vcs_usecase().execute(kind, &host)?;
+                }
+                AuthTarget::Provider(kind) => {
+                    composition.auth_provider_usecase().execute(kind)?;
+                }
+                AuthTarget::Store(entry) => {
+                    let secret = read_secret_input(&format!("secret for {entry}: "))?;
+                    composition
+                        .store_secret_usecase()
+                        .execute(&entry, &secret)?;
+                    println!("stored secret in keyring: {entry}");
+                }
+            }
+            // 토큰/로그인 상태가 바뀌었으므로 상태판을 다시 보여준다.
+            print_status_dashboard(composition);
+            Ok(())
+        }
+        ReplCommand::Providers(ProvidersAction::List) => {
+            let configured = configured_providers(composition)?;
+            println!("{:<10} {:<16} session", "provider", "config");
+            for (id, config_state) in &configured {
+                let selected = config_state == "enabled"
+                    && session
+                        .providers
+                        .as_ref()
+                        .is_none_or(|selected| selected.iter().any(|s| s == id));
+                let session_state = match (config_state.as_str(), selected) {
+                    ("enabled", true) => "on",
+                    ("enabled", false) => "off",
+                    _ => "-",
+                };
+                println!("{id:<10} {config_state:<16} {session_state}");
+            }
+            match &session.providers {
+                Some(selected) => println!("selection: {}", selected.join(", ")),
+                None => println!("selection: all enabled providers (no session override)"),
+            }
+            Ok(())
+        }
+        ReplCommand::Providers(ProvidersAction::Enable(id)) => {
+            let enabled = enabled_providers(composition)?;
+            if !enabled.contains(&id) {
+                anyhow::bail!(
+                    "provider '{id}' is not enabled in config (set providers.{id}.enabled = true and configure api_key or command)"
+                );
+            }
+            let Some(selected) = &mut session.providers else {
+                println!("{id} is already enabled");
+                return Ok(());
+            };
+            if !selected.iter().any(|s| s == id) {
+                selected.push(id.to_string());
+            }
+            // 설정의 활성 provider를 모두 다시 고르면 세션 재정의를 없앤다.
+            if enabled.iter().all(|e| selected.iter().any(|s| s == e)) {
+                session.providers = None;
+            }
+            println!("provider enabled for this session: {id}");
+            Ok(())
+        }
+        ReplCommand::Providers(ProvidersAction::Disable(id)) => {
+            let enabled = enabled_providers(composition)?;
+            let mut selected = session
+                .providers
+                .clone()
+                .unwrap_or_else(|| enabled.iter().map(ToString::to_string).collect());
+            selected.retain(|s| s != id);
+            if selected.is_empty() {
+                anyhow::bail!("cannot disable '{id}': at least one provider must stay enabled");
+            }
+            session.providers = Some(selected);
+            println!("provider disabled for this session: {id}");
             Ok(())
         }
     }
 }
 
-fn parse_repl_command(input: &str) -> Result<ReplCommand, String> {
+/// provider id별 설정 상태(`enabled`/`disabled`/`not configured`).
+fn configured_providers(composition: &AppComposition) -> Result<Vec<(&'static str, String)>> {
+    let inspection: Value = serde_json::from_str(&composition.inspect_config_usecase().execute()?)
+        .context("failed to parse config inspection")?;
+    Ok(PROVIDER_IDS
+        .iter()
+        .map(|id| {
+            let state = match inspection.pointer(&format!("/providers/{id}")) {
+                None | Some(Value::Null) => "not configured",
+                Some(cfg) if cfg.get("enabled").and_then(Value::as_bool) == Some(true) => {
+                    "enabled"
+                }
+                Some(_) => "disabled",
+            };
+            (*id, state.to_string())
+        })
+        .collect())
+}
+
+/// 설정에서 활성화된 provider id 목록.
+fn enabled_providers(composition: &AppComposition) -> Result<Vec<&'static str>> {
+    Ok(configured_providers(composition)?
+        .into_iter()
+        .filter(|(_, state)| state == "enabled")
+        .map(|(id, _)| id)
+        .collect())
+}
+
+/// 설정에 정의된 프로필 이름을 정렬해 반환한다.
+fn profile_names(composition: &AppComposition) -> Result<Vec<String>> {
+    let profiles = composition.config_value_usecase().get("profiles")?;
+    let mut names: Vec<String> = profiles
+        .as_ref()
+        .and_then(Value::as_object)
+        .map(|map| map.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort();
+    Ok(names)
+}
+
+pub(super) fn parse_repl_command(input: &str) -> Result<ReplCommand, String> {
     if !input.starts_with('/') {
         return Err("slash command only. example: /review <url>".to_string());
     }
@@ -111,7 +452,10 @@ fn parse_repl_command(input: &str) -> Result<ReplCommand, String> {
             if parts.len() == 2 && parts[1] == "edit" {
                 return Ok(ReplCommand::EditConfig);
             }
-            Err("usage: /config [edit]".to_string())
+            if parts.len() == 2 && parts[1] == "validate" {
+                return Ok(ReplCommand::ValidateConfig);
+            }
+            Err("usage: /config [edit|validate]".to_string())
         }
         "/review" => {
             if parts.len() == 1 {
@@ -120,32 +464,99 @@ fn parse_repl_command(input: &str) -> Result<ReplCommand, String> {
                 parse_review_command(&parts[1..]).map(ReplCommand::Review)
             }
         }
+        "/profile" => match parts.len() {
+            1 => Ok(ReplCommand::Profile(None)),
+            2 => Ok(ReplCommand::Profile(Some(parts[1].to_string()))),
+            _ => Err("usage: /profile [name|off]".to_string()),
+        },
+        "/providers" => match parts.get(1..).unwrap_or_default() {
+            [] | ["list"] => Ok(ReplCommand::Providers(ProvidersAction::List)),
+            [action @ ("enable" | "disable"), name] => {
+                let Some(id) = canonical_provider_id(name) else {
+                    return Err(format!(
+                        "unknown provider '{name}' (expected {})",
+                        PROVIDER_IDS.join(", ")
+                    ));
+                };
+                Ok(ReplCommand::Providers(if *action == "enable" {
+                    ProvidersAction::Enable(id)
+                } else {
+                    ProvidersAction::Disable(id)
+                }))
+            }
+            _ => Err(format!("usage: {PROVIDERS_USAGE}")),
+        },
+        "/auth" => parse_auth_command(&parts[1..]).map(ReplCommand::Auth),
         other => Err(format!("unknown command: {other}")),
     }
 }
 
+/// `/auth` 인자를 해석한다. provider는 CLI 이름(codex/claude/gemini)과 provider id를 모두 받는다.
+fn parse_auth_command(args: &[&str]) -> Result<AuthTarget, String> {
+    let usage = || format!("usage: {AUTH_USAGE}");
+    let (target, rest) = args.split_first().ok_or_else(usage)?;
+    let target = target.to_ascii_lowercase();
+    match (target.as_str(), rest) {
+        ("github", [] | [_]) => Ok(AuthTarget::Vcs(
+            VcsAuthKind::GitHub,
+            rest.first().unwrap_or(&"github.com").to_string(),
+        )),
+        ("gitlab", [] | [_]) => Ok(AuthTarget::Vcs(
+            VcsAuthKind::GitLab,
+            rest.first().unwrap_or(&"gitlab.com").to_string(),
+        )),
+        ("store", [entry]) => Ok(AuthTarget::Store((*entry).to_string())),
+        ("store", _) => Err("usage: /auth store <service>/<account>".to_string()),
+        (name, []) => match canonical_provider_id(name) {
+            Some("openai") => Ok(AuthTarget::Provider(ProviderAuthKind::Codex)),
+            Some("anthropic") => Ok(AuthTarget::Provider(ProviderAuthKind::Claude)),
+            Some("gemini") => Ok(AuthTarget::Provider(ProviderAuthKind::Gemini)),
+            _ => Err(format!("unknown auth target '{name}' ({})", usage())),
+        },
+        _ => Err(usage()),
+    }
+}
+
 fn parse_review_command(args: &[&str]) -> Result<RunOptions, String> {
     if args.is_empty() {
-        return Err("usage: /review <url> [--dry-run] [--force]".to_string());
+        return Err(format!("usage: {REVIEW_USAGE}"));
     }
 
     let mut url: Option<String> = None;
     let mut dry_run = false;
     let mut force = false;
+    let mut providers: Vec<String> = Vec::new();
+    let mut profile: Option<String> = None;
 
-    for arg in args {
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
         match *arg {
             "--dry-run" => dry_run = true,
             "--force" => force = true,
+            "--providers" => {
+                let Some(value) = iter.next() else {
+                    return Err("--providers requires a value (e.g. openai,gemini)".to_string());
+                };
+                providers.extend(split_provider_list(value));
+            }
+            _ if arg.starts_with("--providers=") => {
+                providers.extend(split_provider_list(&arg["--providers=".len()..]));
+            }
+            "--profile" => {
+                let Some(value) = iter.next() else {
+                    return Err("--profile requires a name".to_string());
+                };
+                profile = Some((*value).to_string());
+            }
+            _ if arg.starts_with("--profile=") => {
+                profile = Some(arg["--profile=".len()..].to_string());
+            }
             _ if arg.starts_with("--") => {
                 return Err(format!("unknown option: {arg}"));
             }
             _ => {
                 if url.is_some() {
-                    return Err(
-                        "usage: /review <url> [--dry-run] [--force] (url must be single)"
-                            .to_string(),
-                    );
+                    return Err(format!("usage: {REVIEW_USAGE} (url must be single)"));
                 }
                 url = Some((*arg).to_string());
             }
@@ -153,54 +564,76 @@ fn parse_review_command(args: &[&str]) -> Result<RunOptions, String> {
     }
 
     let Some(url) = url else {
-        return Err("usage: /review <url> [--dry-run] [--force]".to_string());
+        return Err(format!("usage: {REVIEW_USAGE}"));
     };
 
     Ok(RunOptions {
         url,
         dry_run,
         force,
+        fail_on: None,
+        create_issues: None,
+        autofix: false,
+        autofix_push: false,
+        request_reviewers: false,
+        exclude: Vec::new(),
+        interactive_publish: false,
+        providers,
+        model_overrides: Vec::new(),
+        comment_language: None,
+        profile,
     })
 }
 
+fn split_provider_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
+
 fn print_welcome(composition: &AppComposition) {
-    let interactive = io::stdout().is_terminal();
-    if interactive {
+    if terminal::supports_ansi() {
         // 대화형 터미널에서는 시작 화면을 지우고 배너를 출력한다.
         print!("\x1b[2J\x1b[H");
     }
 
-    let title = paint("RepoPilot interactive shell", "1;36", interactive);
-    let subtitle = paint("multi-agent review cockpit", "2;37", interactive);
-    let cmd_palette = paint("/", "1;33", interactive);
-    let cmd_config = paint("/config [edit]", "1;32", interactive);
-    let cmd_review = paint("/review <url> [--dry-run] [--force]", "1;35", interactive);
-    let cmd_exit = paint("/exit", "1;31", interactive);
+    let title = paint("RepoPilot interactive shell", "1;36");
+    let subtitle = paint("multi-agent review cockpit", "2;37");
+    let cmd_palette = paint("/", "1;33");
+    let cmd_config = paint("/config [edit|validate]", "1;32");
+    let cmd_review = paint(REVIEW_USAGE, "1;35");
+    let cmd_profile = paint("/profile [name|off]", "1;34");
+    let cmd_providers = paint(PROVIDERS_USAGE, "1;36");
+    let cmd_auth = paint(AUTH_USAGE, "1;33");
+    let cmd_exit = paint("/exit", "1;31");
 
     println!("+------------------------------------------------------------+");
     println!("| {:<58} |", title);
     println!("| {:<58} |", subtitle);
-    println!("+------------------------------------------------------------+");
-    println!("| Status Dashboard                                            |");
-    for line in build_startup_dashboard_lines(composition) {
-        println!("| {:<58} |", fit_box_line(&line, 58));
-    }
-    println!("+------------------------------------------------------------+");
+    print_status_dashboard(composition);
     println!("| Quick start                                                 |");
     println!("|  0) {:<54} |", cmd_palette);
     println!("|  1) {:<54} |", cmd_config);
     println!("|  2) {:<54} |", cmd_review);
-    println!("|  3) {:<54} |", cmd_exit);
+    println!("|  3) {:<54} |", cmd_profile);
+    println!("|  4) {:<54} |", cmd_providers);
+    println!("|  5) {:<54} |", cmd_auth);
+    println!("|  6) {:<54} |", cmd_exit);
     println!("+------------------------------------------------------------+");
     println!();
 }
 
-fn paint(text: &str, ansi: &str, interactive: bool) -> String {
-    if interactive {
-        format!("\x1b[{ansi}m{text}\x1b[0m")
-    } else {
-        text.to_string()
+/// 설정/토큰/provider 상태판을 상자 형태로 출력한다(위아래 구분선 포함).
+fn print_status_dashboard(composition: &AppComposition) {
+    println!("+------------------------------------------------------------+");
+    println!("| Status Dashboard                                            |");
+    for line in build_startup_dashboard_lines(composition) {
+        println!("| {:<58} |", fit_box_line(&line, 58));
     }
+    println!("+------------------------------------------------------------+");
 }
 
 fn build_startup_dashboard_lines(composition: &AppComposition) -> Vec<String> {
@@ -231,6 +664,9 @@ fn build_startup_dashboard_lines(composition: &AppComposition) -> Vec<String> {
         .map(|arr| arr.len())
         .unwrap_or(0);
     lines.push(format!("Config: ok (loaded files: {loaded_count})"));
+    if loaded_count == 0 {
+        lines.push("hint: run `repopilot init` to create a project config".to_string());
+    }
 
     let guide = value
         .pointer("/effective_defaults/review_guide_path")
@@ -260,10 +696,25 @@ fn build_startup_dashboard_lines(composition: &AppComposition) -> Vec<String> {
         lines.push("Hosts: unavailable".to_string());
     }
 
+    lines.extend(provider_dashboard_lines(&value));
+    lines
+}
+
+/// 설정 점검 JSON의 provider별 모드/명령/인증 상태 줄.
+pub(super) fn provider_dashboard_lines(value: &Value) -> Vec<String> {
+    let mut lines = Vec::new();
     if let Some(providers) = value.get("providers").and_then(|v| v.as_object()) {
         lines.push("Providers:".to_string());
-        for key in ["openai", "anthropic", "gemini"] {
-            let Some(cfg) = providers.get(key) else {
+        let plugins = providers
+            .get("plugins")
+            .and_then(|v| v.as_object())
+            .into_iter()
+            .flat_map(|plugins| plugins.keys().map(String::as_str));
+        for key in ["openai", "anthropic", "gemini"].into_iter().chain(plugins) {
+            let Some(cfg) = providers
+                .get(key)
+                .or_else(|| providers.get("plugins").and_then(|p| p.get(key)))
+            else {
                 lines.push(format!("  - {key:<10} not configured"));
                 continue;
             };
@@ -298,10 +749,12 @@ fn build_startup_dashboard_lines(composition: &AppComposition) -> Vec<String> {
                 let run_status = if runnable { "ok" } else { "missing" };
                 lines.push(format!("  - {key:<10} {state:<8} api auth=ok ({run_status})"));
             } else if !available {
-                lines.push(format!("  - {key:<10} {state:<8} cli {command} cmd=missing"));
+                lines.push(format!(
+                    "  - {key:<10} {state:<8} {resolved_mode} {command} cmd=missing"
+                ));
             } else {
                 lines.push(format!(
-                    "  - {key:<10} {state:<8} cli {command} auth={auth_status}"
+                    "  - {key:<10} {state:<8} {resolved_mode} {command} auth={auth_status}"
                 ));
                 if auth_status != "ok"
                     && let Some(hint) = cfg.get("auth_hint").and_then(|v| v.as_str())
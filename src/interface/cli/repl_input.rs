@@ -1,10 +1,14 @@
 //! REPL 입력 처리기.
 //! `/`로 시작하면 입력 중 실시간으로 명령 추천을 표시한다.
+//! 입력 기록은 ↑/↓ 탐색과 Ctrl-R 역방향 검색을 지원하고
+//! `~/.local/state/repopilot/history`에 저장해 다음 실행에서도 쓴다.
+//! `/review ` 뒤에서 Tab을 누르면 git 원격 저장소의 열린 PR/MR과 클립보드 URL을 차례로 채운다.
 
-use std::env;
+use std::fs::{self, OpenOptions};
 use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
 
-use anyhow::Result;
+use anyhow::{Result, bail};
 use crossterm::cursor;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
 use crossterm::execute;
@@ -13,6 +17,7 @@ use crossterm::terminal::{self, ClearType};
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::domain::target::ReviewTarget;
+use crate::interface::cli::terminal::{color_enabled, supports_ansi};
 
 struct Suggestion {
     slash: &'static str,
@@ -20,16 +25,47 @@ struct Suggestion {
     usage: &'static str,
 }
 
-const SUGGESTIONS: [Suggestion; 3] = [
+/// `/review` 명령 사용법 문자열.
+pub(crate) const REVIEW_USAGE: &str =
+    "/review <url> [--dry-run] [--force] [--providers <ids>] [--profile <name>]";
+
+/// `/providers` 명령 사용법 문자열.
+pub(crate) const PROVIDERS_USAGE: &str = "/providers [list|enable <name>|disable <name>]";
+
+/// `/auth` 명령 사용법 문자열.
+pub(crate) const AUTH_USAGE: &str = "/auth <github|gitlab|codex|claude|gemini|store> [arg]";
+
+// `/providers enable|disable` 탭 완성 후보
+const PROVIDER_NAMES: [&str; 3] = ["openai", "anthropic", "gemini"];
+
+// `/auth` 탭 완성 후보
+const AUTH_TARGETS: [&str; 6] = ["github", "gitlab", "codex", "claude", "gemini", "store"];
+
+const SUGGESTIONS: [Suggestion; 6] = [
     Suggestion {
         slash: "/config",
         description: "show effective merged config",
-        usage: "/config [edit]",
+        usage: "/config [edit|validate]",
     },
     Suggestion {
         slash: "/review",
         description: "run review for PR/MR URL",
-        usage: "/review <url> [--dry-run] [--force]",
+        usage: REVIEW_USAGE,
+    },
+    Suggestion {
+        slash: "/profile",
+        description: "show or switch config profile",
+        usage: "/profile [name|off]",
+    },
+    Suggestion {
+        slash: "/providers",
+        description: "enable/disable providers for this session",
+        usage: PROVIDERS_USAGE,
+    },
+    Suggestion {
+        slash: "/auth",
+        description: "log in to a VCS host or provider CLI",
+        usage: AUTH_USAGE,
     },
     Suggestion {
         slash: "/exit",
@@ -41,34 +77,172 @@ const DEFAULT_INPUT_PREFILL: &str = "";
 // 입력 영역 기본 높이: 상단 구분선 + 입력줄 + 하단 구분선
 const PANEL_BASE_HEIGHT: usize = 3;
 const PANEL_BOTTOM_PADDING: usize = 0;
+// 기록 파일에 유지하는 최대 항목 수
+const MAX_HISTORY_ENTRIES: usize = 1000;
+// 상태 디렉터리 안의 기록 파일 이름(기본 `~/.local/state/repopilot/history`)
+const HISTORY_FILE: &str = "history";
+
+/// `/review` URL 자동 완성 후보.
+pub struct ReviewUrlCandidate {
+    pub url: String,
+    /// 상태줄에 보여줄 설명(예: `#42 Fix login`, `clipboard`)
+    pub label: String,
+}
+
+/// Tab으로 순환 중인 `/review` URL 후보.
+struct UrlCycle {
+    candidates: Vec<ReviewUrlCandidate>,
+    idx: usize,
+}
+
+/// REPL 입력 기록.
+/// 표준 입력이 터미널일 때만 파일에 저장한다(파이프 입력은 기록하지 않음).
+pub struct ReplHistory {
+    entries: Vec<String>,
+    path: Option<PathBuf>,
+}
+
+impl ReplHistory {
+    /// 상태 디렉터리(`state_dir`)의 `history` 파일을 읽는다.
+    /// 파일이 없거나 읽을 수 없으면 빈 기록으로 시작한다.
+    pub fn load(state_dir: Option<PathBuf>) -> Self {
+        let path = state_dir
+            .filter(|_| io::stdin().is_terminal())
+            .map(|dir| dir.join(HISTORY_FILE));
+        let mut entries: Vec<String> = path
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .map(|raw| {
+                raw.lines()
+                    .filter(|line| !line.trim().is_empty())
+                    .map(ToString::to_string)
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // 한도를 넘은 파일은 최근 항목만 남겨 다시 쓴다.
+        if entries.len() > MAX_HISTORY_ENTRIES {
+            entries.drain(..entries.len() - MAX_HISTORY_ENTRIES);
+            if let Some(path) = &path {
+                let mut raw = entries.join("\n");
+                raw.push('\n');
+                let _ = fs::write(path, raw);
+            }
+        }
+        Self { entries, path }
+    }
 
-/// REPL 한 줄 입력을 읽는다.
-/// - TTY + 지원 터미널: 실시간 추천 + 방향키 선택
+    /// 실행한 입력을 기록한다. 빈 입력, 종료 명령, 직전과 같은 입력, 여러 줄 입력은 건너뛴다.
+    pub fn push(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty()
+            || line == "/exit"
+            || line.contains(['\n', '\r'])
+            || self.entries.last().is_some_and(|last| last == line)
+        {
+            return;
+        }
+        self.entries.push(line.to_string());
+        if self.entries.len() > MAX_HISTORY_ENTRIES {
+            self.entries.remove(0);
+        }
+
+        // 기록 저장 실패는 REPL 사용을 막지 않는다.
+        if let Some(path) = &self.path {
+            if let Some(parent) = path.parent() {
+                let _ = fs::create_dir_all(parent);
+            }
+            if let Ok(mut file) = OpenOptions::new().create(true).append(true).open(path) {
+                let _ = writeln!(file, "{line}");
+            }
+        }
+    }
+
+    /// 기록된 입력(오래된 것부터).
+    pub(super) fn entries(&self) -> &[String] {
+        &self.entries
+    }
+
+    /// `before` 이전(없으면 끝)부터 거꾸로 `query`를 포함하는 항목을 찾는다.
+    fn search_back(&self, query: &str, before: Option<usize>) -> Option<usize> {
+        let end = before.unwrap_or(self.entries.len()).min(self.entries.len());
+        self.entries[..end]
+            .iter()
+            .rposition(|entry| entry.contains(query))
+    }
+}
+
+/// REPL 한 줄 입력을 읽고 기록에 남긴다.
+/// - TTY + 지원 터미널: 실시간 추천 + 방향키 선택 + 기록 탐색/검색
 /// - non-TTY/미지원 터미널: 일반 라인 입력
-pub fn read_repl_input(prefill: Option<&str>) -> Result<Option<String>> {
+///
+/// `review_urls`는 `/review ` 뒤에서 Tab을 누를 때 불러 URL 후보를 얻는다.
+pub fn read_repl_input(
+    prefill: Option<&str>,
+    history: &mut ReplHistory,
+    review_urls: &dyn Fn() -> Vec<ReviewUrlCandidate>,
+) -> Result<Option<String>> {
     let initial = prefill.unwrap_or(DEFAULT_INPUT_PREFILL);
 
-    if !supports_interactive_input() {
-        return read_line_fallback(initial);
+    let line = if !supports_interactive_input() {
+        read_line_fallback(initial)?
+    } else {
+        match read_line_interactive(initial, history, review_urls) {
+            Ok(v) => v,
+            Err(_) => read_line_fallback(initial)?,
+        }
+    };
+    if let Some(line) = &line {
+        history.push(line);
     }
+    Ok(line)
+}
 
-    match read_line_interactive(initial) {
-        Ok(v) => Ok(v),
-        Err(_) => read_line_fallback(initial),
+/// 토큰 같은 비밀값을 화면에 표시하지 않고 읽는다.
+/// 표준 입력이 터미널이 아니면(파이프) 첫 줄을 그대로 읽는다.
+pub fn read_secret_input(prompt: &str) -> Result<String> {
+    if !io::stdin().is_terminal() {
+        let mut line = String::new();
+        io::stdin().read_line(&mut line)?;
+        return Ok(trim_newline(line));
     }
+
+    eprint!("{prompt}");
+    io::stderr().flush()?;
+    terminal::enable_raw_mode()?;
+    let result = read_hidden_line();
+    let _ = terminal::disable_raw_mode();
+    eprintln!();
+    result
 }
 
-fn supports_interactive_input() -> bool {
-    if !io::stdout().is_terminal() {
-        return false;
+fn read_hidden_line() -> Result<String> {
+    let mut secret = String::new();
+    loop {
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Enter => return Ok(secret),
+            KeyCode::Backspace => {
+                secret.pop();
+            }
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                bail!("cancelled");
+            }
+            KeyCode::Char(ch) => secret.push(ch),
+            KeyCode::Esc => bail!("cancelled"),
+            _ => {}
+        }
     }
+}
 
+pub(super) fn supports_interactive_input() -> bool {
     // dumb 터미널에서는 제어 시퀀스 기반 UI를 비활성화한다.
-    if let Ok(term) = env::var("TERM") && term.eq_ignore_ascii_case("dumb") {
-        return false;
-    }
-
-    true
+    supports_ansi()
 }
 
 fn read_line_fallback(initial: &str) -> Result<Option<String>> {
@@ -90,15 +264,115 @@ fn read_line_fallback(initial: &str) -> Result<Option<String>> {
     Ok(Some(format!("{initial}{typed}")))
 }
 
-fn read_line_interactive(initial: &str) -> Result<Option<String>> {
+/// Ctrl-R 역방향 검색 상태.
+struct HistorySearch {
+    query: String,
+    /// 현재 일치한 기록 위치
+    matched: Option<usize>,
+    /// 검색을 취소하면 되돌릴 입력
+    original: String,
+}
+
+fn read_line_interactive(
+    initial: &str,
+    history: &ReplHistory,
+    review_urls: &dyn Fn() -> Vec<ReviewUrlCandidate>,
+) -> Result<Option<String>> {
     let mut stdout = io::stdout();
     let _guard = InputGuard::enter(&mut stdout)?;
 
     let mut input = initial.to_string();
     let mut cursor_chars = input.chars().count();
     let mut selected_idx = default_suggestion_index(&match_suggestions(&input));
+    // ↑/↓로 불러온 기록 위치와, 탐색을 시작하기 전 입력
+    let mut history_idx: Option<usize> = None;
+    let mut draft = String::new();
+    let mut search: Option<HistorySearch> = None;
+    let mut url_cycle: Option<UrlCycle> = None;
+    // 다음 키 입력 전까지 상태줄에 보여줄 안내
+    let mut notice: Option<(Color, String)> = None;
 
     loop {
+        if let Some(state) = &mut search {
+            let shown = state
+                .matched
+                .map(|idx| history.entries[idx].as_str())
+                .unwrap_or_default();
+            let status = if state.matched.is_some() || state.query.is_empty() {
+                (
+                    Color::Yellow,
+                    format!("(reverse-i-search)`{}`", state.query),
+                )
+            } else {
+                (
+                    Color::Red,
+                    format!("(failing reverse-i-search)`{}`", state.query),
+                )
+            };
+            render_frame(
+                &mut stdout,
+                shown,
+                shown.chars().count(),
+                &[],
+                0,
+                Some(status),
+            )?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+            match key.code {
+                KeyCode::Char('r') if ctrl => {
+                    // 같은 검색어로 더 오래된 항목을 찾는다.
+                    if let Some(idx) = history.search_back(&state.query, state.matched) {
+                        state.matched = Some(idx);
+                    }
+                }
+                KeyCode::Char('c' | 'g') if ctrl => {
+                    input = std::mem::take(&mut state.original);
+                    cursor_chars = input.chars().count();
+                    search = None;
+                }
+                KeyCode::Esc => {
+                    input = std::mem::take(&mut state.original);
+                    cursor_chars = input.chars().count();
+                    search = None;
+                }
+                KeyCode::Char(ch) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+                    state.query.push(ch);
+                    state.matched = history.search_back(&state.query, None);
+                }
+                KeyCode::Backspace => {
+                    state.query.pop();
+                    state.matched = history.search_back(&state.query, None);
+                }
+                KeyCode::Enter => {
+                    // 일치한 항목을 바로 실행한다.
+                    if let Some(idx) = state.matched {
+                        clear_panel_for_output(&mut stdout)?;
+                        return Ok(Some(history.entries[idx].clone()));
+                    }
+                    input = std::mem::take(&mut state.original);
+                    cursor_chars = input.chars().count();
+                    search = None;
+                }
+                _ => {
+                    // 그 밖의 키는 일치한 항목을 편집할 수 있게 입력줄로 가져온다.
+                    input = match state.matched {
+                        Some(idx) => history.entries[idx].clone(),
+                        None => std::mem::take(&mut state.original),
+                    };
+                    cursor_chars = input.chars().count();
+                    search = None;
+                }
+            }
+            continue;
+        }
+
         let suggestions = match_suggestions(&input);
         if suggestions.is_empty() {
             selected_idx = 0;
@@ -106,21 +380,61 @@ fn read_line_interactive(initial: &str) -> Result<Option<String>> {
             selected_idx = suggestions.len() - 1;
         }
 
-        render_frame(&mut stdout, &input, cursor_chars, &suggestions, selected_idx)?;
+        let status = url_cycle
+            .as_ref()
+            .map(|cycle| {
+                let current = &cycle.candidates[cycle.idx];
+                (
+                    Color::Green,
+                    format!(
+                        "{} ({}/{}) · Tab next · Enter run",
+                        current.label,
+                        cycle.idx + 1,
+                        cycle.candidates.len()
+                    ),
+                )
+            })
+            .or_else(|| notice.take());
+        render_frame(
+            &mut stdout,
+            &input,
+            cursor_chars,
+            &suggestions,
+            selected_idx,
+            status,
+        )?;
 
-        match event::read()? {
+        let event = event::read()?;
+        if !matches!(&event, Event::Key(key) if key.code == KeyCode::Tab) {
+            url_cycle = None;
+        }
+        match event {
             Event::Paste(text) => {
                 for ch in text.chars() {
                     insert_char_at(&mut input, cursor_chars, ch);
                     cursor_chars += 1;
                 }
+                history_idx = None;
             }
             Event::Key(key) => {
                 if key.kind != KeyEventKind::Press {
                     continue;
                 }
+                // 입력을 바꾸는 키를 누르면 기록 탐색을 끝낸다.
+                if matches!(
+                    key.code,
+                    KeyCode::Char(_) | KeyCode::Backspace | KeyCode::Delete
+                ) && !key.modifiers.contains(KeyModifiers::CONTROL)
+                {
+                    history_idx = None;
+                }
 
                 match key.code {
+            // 기록에서 불러온 입력은 추천/인자 확장 없이 그대로 실행한다.
+            KeyCode::Enter if history_idx.is_some() => {
+                clear_panel_for_output(&mut stdout)?;
+                return Ok(Some(input));
+            }
             KeyCode::Enter => {
                 // 인자가 필요한 명령은 즉시 실행하지 않고 인자 입력 상태로 확장한다.
                 if let Some(expanded) = expand_input(&input, &suggestions, selected_idx) {
@@ -132,16 +446,12 @@ fn read_line_interactive(initial: &str) -> Result<Option<String>> {
                 clear_panel_for_output(&mut stdout)?;
                 return Ok(Some(final_input));
             }
-            KeyCode::Backspace => {
-                if cursor_chars > 0 {
-                    remove_char_at(&mut input, cursor_chars - 1);
-                    cursor_chars -= 1;
-                }
+            KeyCode::Backspace if cursor_chars > 0 => {
+                remove_char_at(&mut input, cursor_chars - 1);
+                cursor_chars -= 1;
             }
-            KeyCode::Delete => {
-                if cursor_chars < input.chars().count() {
-                    remove_char_at(&mut input, cursor_chars);
-                }
+            KeyCode::Delete if cursor_chars < input.chars().count() => {
+                remove_char_at(&mut input, cursor_chars);
             }
             KeyCode::Left => {
                 cursor_chars = cursor_chars.saturating_sub(1);
@@ -155,16 +465,47 @@ fn read_line_interactive(initial: &str) -> Result<Option<String>> {
             KeyCode::End => {
                 cursor_chars = input.chars().count();
             }
+            // 추천 목록이 떠 있으면 ↑/↓는 추천 선택, 아니면 기록 탐색이다.
+            KeyCode::Up if !suggestions.is_empty() && history_idx.is_none() => {
+                selected_idx = selected_idx.saturating_sub(1);
+            }
+            KeyCode::Down if !suggestions.is_empty() && history_idx.is_none() => {
+                selected_idx = (selected_idx + 1).min(suggestions.len() - 1);
+            }
             KeyCode::Up => {
-                if !suggestions.is_empty() {
-                    selected_idx = selected_idx.saturating_sub(1);
+                let next = match history_idx {
+                    Some(idx) => idx.checked_sub(1),
+                    None => {
+                        draft = input.clone();
+                        history.entries.len().checked_sub(1)
+                    }
+                };
+                if let Some(idx) = next {
+                    history_idx = Some(idx);
+                    input = history.entries[idx].clone();
+                    cursor_chars = input.chars().count();
                 }
             }
             KeyCode::Down => {
-                if !suggestions.is_empty() {
-                    selected_idx = (selected_idx + 1).min(suggestions.len() - 1);
+                if let Some(idx) = history_idx {
+                    if idx + 1 < history.entries.len() {
+                        history_idx = Some(idx + 1);
+                        input = history.entries[idx + 1].clone();
+                    } else {
+                        history_idx = None;
+                        input = std::mem::take(&mut draft);
+                    }
+                    cursor_chars = input.chars().count();
                 }
             }
+            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                history_idx = None;
+                search = Some(HistorySearch {
+                    query: String::new(),
+                    matched: None,
+                    original: input.clone(),
+                });
+            }
             KeyCode::Tab => {
                 if !suggestions.is_empty() && input.starts_with('/') && !input.contains(' ') {
                     input = suggestions[selected_idx].slash.to_string();
@@ -172,6 +513,22 @@ fn read_line_interactive(initial: &str) -> Result<Option<String>> {
                 } else if let Some(completed) = complete_subcommand(&input) {
                     input = completed;
                     cursor_chars = input.chars().count();
+                } else if let Some(cycle) = &mut url_cycle {
+                    cycle.idx = (cycle.idx + 1) % cycle.candidates.len();
+                    input = format!("/review {}", cycle.candidates[cycle.idx].url);
+                    cursor_chars = input.chars().count();
+                } else if let Some(partial) = review_url_partial(&input) {
+                    let candidates = match_review_urls(review_urls(), partial);
+                    if let Some(first) = candidates.first() {
+                        input = format!("/review {}", first.url);
+                        cursor_chars = input.chars().count();
+                        url_cycle = Some(UrlCycle { candidates, idx: 0 });
+                    } else {
+                        notice = Some((
+                            Color::Yellow,
+                            "no PR/MR URL candidates (git remotes / clipboard)".to_string(),
+                        ));
+                    }
                 }
             }
             KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -182,13 +539,12 @@ fn read_line_interactive(initial: &str) -> Result<Option<String>> {
                 clear_panel_for_output(&mut stdout)?;
                 return Ok(Some("/exit".to_string()));
             }
-            KeyCode::Char(ch) => {
+            KeyCode::Char(ch)
                 if !key.modifiers.contains(KeyModifiers::CONTROL)
-                    && !key.modifiers.contains(KeyModifiers::ALT)
-                {
-                    insert_char_at(&mut input, cursor_chars, ch);
-                    cursor_chars += 1;
-                }
+                    && !key.modifiers.contains(KeyModifiers::ALT) =>
+            {
+                insert_char_at(&mut input, cursor_chars, ch);
+                cursor_chars += 1;
             }
             _ => {}
                 }
@@ -234,7 +590,7 @@ fn expand_input(input: &str, suggestions: &[&Suggestion], selected_idx: usize) -
         return None;
     }
 
-    const EXPANDABLE: &[&str] = &["/review", "/config"];
+    const EXPANDABLE: &[&str] = &["/review", "/config", "/profile", "/providers", "/auth"];
 
     if EXPANDABLE.contains(&input) {
         return Some(format!("{input} "));
@@ -252,7 +608,89 @@ fn expand_input(input: &str, suggestions: &[&Suggestion], selected_idx: usize) -
 
 /// 명령별 실시간 힌트를 통합 반환한다.
 fn realtime_hint(input: &str) -> Option<(Color, String)> {
-    review_realtime_hint(input).or_else(|| config_realtime_hint(input))
+    review_realtime_hint(input)
+        .or_else(|| config_realtime_hint(input))
+        .or_else(|| providers_realtime_hint(input))
+        .or_else(|| auth_realtime_hint(input))
+}
+
+/// `/auth` 대상 실시간 힌트를 반환한다.
+fn auth_realtime_hint(input: &str) -> Option<(Color, String)> {
+    let after = input.trim_start().strip_prefix("/auth")?;
+    if !after.is_empty() && !after.starts_with(' ') {
+        return None;
+    }
+
+    let args: Vec<&str> = after.split_whitespace().collect();
+    match args.as_slice() {
+        [] => Some((Color::Yellow, format!("hint: {AUTH_USAGE}"))),
+        ["store"] => Some((
+            Color::Yellow,
+            "hint: /auth store <service>/<account>".to_string(),
+        )),
+        ["store", entry] => Some((
+            Color::Green,
+            format!("ready: press Enter to store a secret in keyring entry {entry}"),
+        )),
+        [host @ ("github" | "gitlab")] => Some((
+            Color::Green,
+            format!("ready: press Enter to log in to {host}.com (optional: host)"),
+        )),
+        [host @ ("github" | "gitlab"), custom] => Some((
+            Color::Green,
+            format!("ready: press Enter to log in to {host} host {custom}"),
+        )),
+        [target] if AUTH_TARGETS.contains(target) => Some((
+            Color::Green,
+            format!("ready: press Enter to log in to {target} CLI"),
+        )),
+        [partial] if AUTH_TARGETS.iter().any(|t| t.starts_with(partial)) => {
+            Some((Color::Yellow, "hint: press Tab to complete".to_string()))
+        }
+        [other] => Some((Color::Red, format!("error: unknown auth target `{other}`"))),
+        _ => Some((Color::Red, format!("error: usage: {AUTH_USAGE}"))),
+    }
+}
+
+/// `/providers` 서브커맨드 실시간 힌트를 반환한다.
+fn providers_realtime_hint(input: &str) -> Option<(Color, String)> {
+    let after = input.trim_start().strip_prefix("/providers")?;
+    if !after.is_empty() && !after.starts_with(' ') {
+        return None;
+    }
+
+    let args: Vec<&str> = after.split_whitespace().collect();
+    let usage = || (Color::Yellow, format!("hint: {PROVIDERS_USAGE}"));
+    match args.as_slice() {
+        [] => Some(usage()),
+        ["list"] => Some((
+            Color::Green,
+            "ready: press Enter to list providers".to_string(),
+        )),
+        [action @ ("enable" | "disable")] => Some((
+            Color::Yellow,
+            format!("hint: /providers {action} <{}>", PROVIDER_NAMES.join("|")),
+        )),
+        [action @ ("enable" | "disable"), name] => {
+            if PROVIDER_NAMES.contains(name) || ["codex", "claude", "google"].contains(name) {
+                Some((
+                    Color::Green,
+                    format!("ready: press Enter to {action} {name} for this session"),
+                ))
+            } else if PROVIDER_NAMES.iter().any(|p| p.starts_with(name)) {
+                Some((Color::Yellow, "hint: press Tab to complete".to_string()))
+            } else {
+                Some((Color::Red, format!("error: unknown provider `{name}`")))
+            }
+        }
+        [partial] if ["list", "enable", "disable"].iter().any(|s| s.starts_with(partial)) => {
+            Some(usage())
+        }
+        [other, ..] if !["list", "enable", "disable"].contains(other) => {
+            Some((Color::Red, format!("error: unknown subcommand `{other}`")))
+        }
+        _ => Some((Color::Red, format!("error: usage: {PROVIDERS_USAGE}"))),
+    }
 }
 
 /// `/config` 서브커맨드 실시간 힌트를 반환한다.
@@ -268,7 +706,7 @@ fn config_realtime_hint(input: &str) -> Option<(Color, String)> {
     if rest.is_empty() {
         return Some((
             Color::Yellow,
-            "hint: /config [edit]".to_string(),
+            "hint: /config [edit|validate]".to_string(),
         ));
     }
 
@@ -279,18 +717,98 @@ fn config_realtime_hint(input: &str) -> Option<(Color, String)> {
         ));
     }
 
-    if "edit".starts_with(rest) {
+    if rest == "validate" {
+        return Some((
+            Color::Green,
+            "ready: press Enter to validate config files".to_string(),
+        ));
+    }
+
+    if "edit".starts_with(rest) || "validate".starts_with(rest) {
         return Some((
             Color::Yellow,
-            "hint: /config [edit]".to_string(),
+            "hint: /config [edit|validate]".to_string(),
         ));
     }
 
     Some((Color::Red, format!("error: unknown subcommand `{rest}`")))
 }
 
+/// `/review <부분 입력>`에서 URL 자리의 부분 입력을 반환한다.
+fn review_url_partial(input: &str) -> Option<&str> {
+    let partial = input.trim_start().strip_prefix("/review ")?.trim_start();
+    (!partial.contains(' ') && !partial.starts_with('-')).then_some(partial)
+}
+
+/// URL 접두사 또는 설명(번호/제목) 일부가 맞는 후보만 남긴다.
+fn match_review_urls(candidates: Vec<ReviewUrlCandidate>, partial: &str) -> Vec<ReviewUrlCandidate> {
+    let query = partial.to_lowercase();
+    candidates
+        .into_iter()
+        .filter(|c| {
+            c.url.starts_with(partial)
+                || (!partial.contains("://") && c.label.to_lowercase().contains(&query))
+        })
+        .collect()
+}
+
 /// 서브커맨드 탭 완성을 시도한다.
 fn complete_subcommand(input: &str) -> Option<String> {
+    complete_config_subcommand(input)
+        .or_else(|| complete_providers_argument(input))
+        .or_else(|| complete_auth_target(input))
+}
+
+/// `/auth` 대상을 완성한다.
+fn complete_auth_target(input: &str) -> Option<String> {
+    let partial = input.trim_start().strip_prefix("/auth ")?;
+    if partial.is_empty() || partial.contains(' ') {
+        return None;
+    }
+    let matches: Vec<&str> = AUTH_TARGETS
+        .iter()
+        .copied()
+        .filter(|t| t.starts_with(partial))
+        .collect();
+    if matches.len() == 1 && partial != matches[0] {
+        return Some(format!("/auth {}", matches[0]));
+    }
+    None
+}
+
+/// `/providers` 서브커맨드와 provider 이름을 완성한다.
+fn complete_providers_argument(input: &str) -> Option<String> {
+    let after = input.trim_start().strip_prefix("/providers ")?;
+    let args: Vec<&str> = after.split_whitespace().collect();
+    let ends_with_space = after.ends_with(' ');
+
+    let (prefix, candidates, partial): (String, &[&str], &str) = match args.as_slice() {
+        [] => return None,
+        [partial] if !ends_with_space => (
+            "/providers".to_string(),
+            &["list", "enable", "disable"],
+            partial,
+        ),
+        [action @ ("enable" | "disable")] => (format!("/providers {action}"), &PROVIDER_NAMES, ""),
+        [action @ ("enable" | "disable"), partial] if !ends_with_space => {
+            (format!("/providers {action}"), &PROVIDER_NAMES, partial)
+        }
+        _ => return None,
+    };
+
+    let matches: Vec<&str> = candidates
+        .iter()
+        .copied()
+        .filter(|c| c.starts_with(partial))
+        .collect();
+    if matches.len() == 1 && partial != matches[0] {
+        return Some(format!("{prefix} {}", matches[0]));
+    }
+    None
+}
+
+/// `/config` 서브커맨드를 완성한다.
+fn complete_config_subcommand(input: &str) -> Option<String> {
     let trimmed = input.trim_start();
     let after = trimmed.strip_prefix("/config")?;
 
@@ -299,7 +817,7 @@ fn complete_subcommand(input: &str) -> Option<String> {
     }
 
     let rest = after.trim();
-    let subs = ["edit"];
+    let subs = ["edit", "validate"];
     let matches: Vec<&str> = subs.iter().copied().filter(|s| s.starts_with(rest)).collect();
     if matches.len() == 1 && rest != matches[0] {
         return Some(format!("/config {}", matches[0]));
@@ -311,7 +829,7 @@ fn complete_subcommand(input: &str) -> Option<String> {
 fn review_usage_hint(input: &str) -> Option<&'static str> {
     let trimmed = input.trim_start();
     if trimmed.starts_with("/review") {
-        Some("/review <url> [--dry-run] [--force]")
+        Some(REVIEW_USAGE)
     } else {
         None
     }
@@ -327,14 +845,24 @@ fn review_realtime_hint(input: &str) -> Option<(Color, String)> {
     if rest.is_empty() {
         return Some((
             Color::Yellow,
-            "hint: /review <url> [--dry-run] [--force]".to_string(),
+            format!("hint: {REVIEW_USAGE}"),
         ));
     }
 
     let mut url: Option<&str> = None;
-    for arg in rest.split_whitespace() {
+    let mut args = rest.split_whitespace();
+    while let Some(arg) = args.next() {
         match arg {
             "--dry-run" | "--force" => {}
+            "--providers" => {
+                if args.next().is_none() {
+                    return Some((
+                        Color::Yellow,
+                        "hint: --providers <ids> (e.g. openai,gemini)".to_string(),
+                    ));
+                }
+            }
+            _ if arg.starts_with("--providers=") => {}
             _ if arg.starts_with("--") => {
                 return Some((Color::Red, format!("error: unknown option `{arg}`")));
             }
@@ -353,7 +881,7 @@ fn review_realtime_hint(input: &str) -> Option<(Color, String)> {
     let Some(url) = url else {
         return Some((
             Color::Yellow,
-            "hint: /review <url> [--dry-run] [--force]".to_string(),
+            format!("hint: {REVIEW_USAGE}"),
         ));
     };
 
@@ -364,7 +892,7 @@ fn review_realtime_hint(input: &str) -> Option<(Color, String)> {
         )),
         Err(_) => Some((
             Color::Red,
-            "error: invalid URL (GitHub /pull/<n> or GitLab /-/merge_requests/<iid>)"
+            "error: invalid URL (GitHub /pull/<n>, GitLab /-/merge_requests/<iid> or mock://<fixture>)"
                 .to_string(),
         )),
     }
@@ -376,6 +904,7 @@ fn render_frame(
     cursor_chars: usize,
     suggestions: &[&Suggestion],
     selected_idx: usize,
+    status: Option<(Color, String)>,
 ) -> Result<()> {
     let (w, h) = terminal::size().unwrap_or((120, 40));
     // 패널 배경의 우측 끊김을 막기 위해 터미널 전체 폭을 사용한다.
@@ -383,7 +912,9 @@ fn render_frame(
     let total_rows = h as usize;
 
     // 힌트/추천 유무에 따라 패널 높이를 동적으로 결정한다.
-    let has_hint = realtime_hint(input).is_some() || review_usage_hint(input).is_some();
+    let has_hint = status.is_some()
+        || realtime_hint(input).is_some()
+        || review_usage_hint(input).is_some();
     let hint_rows = usize::from(has_hint);
     let suggestion_rows = suggestions.len();
     let extra_rows = hint_rows + suggestion_rows;
@@ -407,9 +938,11 @@ fn render_frame(
     let divider = "─".repeat(width);
     draw_panel_line_at(stdout, input_header_row as u16, &divider, width)?;
 
-    if input.is_empty() {
-        let placeholder =
-            render_prompt_line("/ Enter run · ↑↓ select · Tab autocomplete", width);
+    if input.is_empty() && status.is_none() {
+        let placeholder = render_prompt_line(
+            "/ Enter run · ↑↓ select/history · Tab autocomplete · Ctrl-R search",
+            width,
+        );
         draw_panel_line_at_with_fg(
             stdout,
             input_row as u16,
@@ -431,7 +964,7 @@ fn render_frame(
     // 하단 구분선 아래: 배경 없이 힌트와 추천을 표시한다.
     let mut next_row = extra_start;
 
-    if let Some((color, line)) = realtime_hint(input) {
+    if let Some((color, line)) = status.or_else(|| realtime_hint(input)) {
         draw_line_at_with_fg(
             stdout,
             next_row as u16,
@@ -579,7 +1112,7 @@ fn trim_newline(mut s: String) -> String {
 }
 
 // 라인을 기본 배경으로 클리어만 한다.
-fn clear_line_at(stdout: &mut io::Stdout, row: u16) -> Result<()> {
+pub(super) fn clear_line_at(stdout: &mut io::Stdout, row: u16) -> Result<()> {
     execute!(
         stdout,
         cursor::MoveTo(0, row),
@@ -593,20 +1126,22 @@ fn draw_panel_line_at(stdout: &mut io::Stdout, row: u16, text: &str, width: usiz
     draw_panel_line_at_with_fg(stdout, row, text, width, Color::White)
 }
 
-fn draw_panel_line_at_with_fg(
+pub(super) fn draw_panel_line_at_with_fg(
     stdout: &mut io::Stdout,
     row: u16,
     text: &str,
     width: usize,
     fg: Color,
 ) -> Result<()> {
-    execute!(
-        stdout,
-        cursor::MoveTo(0, row),
-        SetBackgroundColor(Color::DarkGrey),
-        SetForegroundColor(fg),
-        terminal::Clear(ClearType::CurrentLine)
-    )?;
+    execute!(stdout, cursor::MoveTo(0, row))?;
+    if color_enabled() {
+        execute!(
+            stdout,
+            SetBackgroundColor(Color::DarkGrey),
+            SetForegroundColor(fg)
+        )?;
+    }
+    execute!(stdout, terminal::Clear(ClearType::CurrentLine))?;
     write!(
         stdout,
         "{}",
@@ -617,7 +1152,7 @@ fn draw_panel_line_at_with_fg(
 }
 
 // 배경색 없는 일반 라인 (힌트/추천 영역용).
-fn draw_line_at_with_fg(
+pub(super) fn draw_line_at_with_fg(
     stdout: &mut io::Stdout,
     row: u16,
     text: &str,
@@ -627,9 +1162,11 @@ fn draw_line_at_with_fg(
     execute!(
         stdout,
         cursor::MoveTo(0, row),
-        terminal::Clear(ClearType::CurrentLine),
-        SetForegroundColor(fg)
+        terminal::Clear(ClearType::CurrentLine)
     )?;
+    if color_enabled() {
+        execute!(stdout, SetForegroundColor(fg))?;
+    }
     write!(
         stdout,
         "{}",
@@ -0,0 +1,288 @@
+//! REPL 리뷰 진행 상태판.
+//!
+//! REPL 입력 패널과 같은 하단 패널 렌더러로 스피너, 경과 시간, provider별 상태를 표시한다.
+//! 터미널 스크롤 영역을 패널 위쪽으로 제한해 리뷰 로그가 패널을 덮지 않게 한다.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crossterm::style::Color;
+use crossterm::{cursor, execute, terminal};
+
+use crate::application::ports::Reporter;
+use crate::interface::cli::repl_input::{
+    clear_line_at, draw_line_at_with_fg, draw_panel_line_at_with_fg, supports_interactive_input,
+};
+
+pub(super) const SPINNER: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+const TICK: Duration = Duration::from_millis(120);
+// 패널 높이: 구분선 + 진행 줄 + provider 줄(openai/anthropic/gemini)
+const PANEL_HEIGHT: usize = 5;
+const PROVIDER_ROWS: usize = PANEL_HEIGHT - 2;
+
+struct ProviderRow {
+    status: String,
+    extra: Option<String>,
+    since: Instant,
+}
+
+#[derive(Default)]
+struct PanelState {
+    section: String,
+    rows: BTreeMap<String, ProviderRow>,
+    frame: usize,
+    /// 스크롤 영역을 맞춘 터미널 높이(0이면 패널을 표시하지 않는 상태)
+    height: u16,
+}
+
+struct Shared {
+    started: Instant,
+    state: Mutex<PanelState>,
+    stop: AtomicBool,
+}
+
+/// REPL `/review` 실행 동안만 쓰는 리포터.
+/// drop되면 패널을 지우고 스크롤 영역을 되돌린다.
+pub(super) struct ReplProgressReporter {
+    shared: Arc<Shared>,
+    ticker: Option<JoinHandle<()>>,
+}
+
+impl ReplProgressReporter {
+    /// 패널을 띄우고 스피너 갱신을 시작한다. 대화형 터미널이 아니면 일반 로그만 출력한다.
+    pub(super) fn start() -> Self {
+        let shared = Arc::new(Shared {
+            started: Instant::now(),
+            state: Mutex::new(PanelState::default()),
+            stop: AtomicBool::new(false),
+        });
+        if !supports_interactive_input() {
+            return Self {
+                shared,
+                ticker: None,
+            };
+        }
+
+        if let Ok(mut state) = shared.state.lock() {
+            open_panel(&mut state);
+            draw_panel(shared.started, &mut state);
+        }
+        let ticker = {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                while !shared.stop.load(Ordering::Relaxed) {
+                    thread::sleep(TICK);
+                    if let Ok(mut state) = shared.state.lock()
+                        && state.height > 0
+                    {
+                        state.frame += 1;
+                        draw_panel(shared.started, &mut state);
+                    }
+                }
+            })
+        };
+        Self {
+            shared,
+            ticker: Some(ticker),
+        }
+    }
+
+    /// 로그 한 줄을 출력한다. 패널 갱신과 섞이지 않도록 상태 잠금을 잡은 채 쓴다.
+    fn log(&self, line: &str) {
+        let _state = self.shared.state.lock();
+        println!("{line}");
+    }
+}
+
+impl Reporter for ReplProgressReporter {
+    fn section(&self, name: &str) {
+        if let Ok(mut state) = self.shared.state.lock() {
+            state.section = name.to_string();
+            if name.starts_with("Providers (") {
+                state.rows.clear();
+            }
+            println!();
+            println!("==================== {} ====================", name);
+            if state.height > 0 {
+                draw_panel(self.shared.started, &mut state);
+            }
+        }
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        self.log(&format!("{:<12}: {}", key, value));
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        self.log(&format!("[{:<12}] {}", scope, message));
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        let Ok(mut state) = self.shared.state.lock() else {
+            return;
+        };
+        state.rows.insert(
+            provider.to_string(),
+            ProviderRow {
+                status: status.to_string(),
+                extra: elapsed_secs.map(|secs| format!("{secs:.1}s")),
+                since: Instant::now(),
+            },
+        );
+        // 패널은 리뷰가 끝나면 사라지므로 완료/실패는 로그에도 남긴다.
+        if status != "running" || state.height == 0 {
+            match elapsed_secs {
+                Some(secs) => println!("[provider:{:<12}] {:<7} {secs:.1}s", provider, status),
+                None => println!("[provider:{:<12}] {}", provider, status),
+            }
+        }
+        if state.height > 0 {
+            draw_panel(self.shared.started, &mut state);
+        }
+    }
+
+    fn raw(&self, line: &str) {
+        self.log(line);
+    }
+}
+
+impl Drop for ReplProgressReporter {
+    fn drop(&mut self) {
+        self.shared.stop.store(true, Ordering::Relaxed);
+        if let Some(ticker) = self.ticker.take() {
+            let _ = ticker.join();
+        }
+        if let Ok(mut state) = self.shared.state.lock() {
+            close_panel(&mut state);
+        }
+    }
+}
+
+/// 패널 자리를 비우고 스크롤 영역을 패널 위쪽으로 제한한다.
+fn open_panel(state: &mut PanelState) {
+    let (_, h) = terminal::size().unwrap_or((120, 40));
+    // 너무 작은 터미널에서는 로그 공간을 우선한다.
+    if (h as usize) <= PANEL_HEIGHT * 2 {
+        return;
+    }
+    let mut stdout = io::stdout();
+    // 현재 커서가 패널 자리에 있으면 화면을 올려 스크롤 영역 안으로 옮긴다.
+    let _ = write!(
+        stdout,
+        "{}\x1b[{}A",
+        "\n".repeat(PANEL_HEIGHT),
+        PANEL_HEIGHT
+    );
+    set_scroll_region(&mut stdout, h);
+    state.height = h;
+}
+
+/// 패널을 지우고 스크롤 영역을 전체 화면으로 되돌린다.
+fn close_panel(state: &mut PanelState) {
+    if state.height == 0 {
+        return;
+    }
+    let mut stdout = io::stdout();
+    let (_, h) = terminal::size().unwrap_or((120, state.height));
+    let _ = execute!(stdout, cursor::SavePosition);
+    let _ = write!(stdout, "\x1b[r");
+    for row in (h as usize).saturating_sub(PANEL_HEIGHT)..h as usize {
+        let _ = clear_line_at(&mut stdout, row as u16);
+    }
+    let _ = execute!(stdout, cursor::RestorePosition);
+    let _ = stdout.flush();
+    state.height = 0;
+}
+
+/// DECSTBM은 커서를 화면 맨 위로 옮기므로 커서 위치를 저장/복원한다.
+fn set_scroll_region(stdout: &mut io::Stdout, height: u16) {
+    let _ = execute!(stdout, cursor::SavePosition);
+    let _ = write!(stdout, "\x1b[1;{}r", height as usize - PANEL_HEIGHT);
+    let _ = execute!(stdout, cursor::RestorePosition);
+    let _ = stdout.flush();
+}
+
+fn draw_panel(started: Instant, state: &mut PanelState) {
+    let (w, h) = terminal::size().unwrap_or((120, state.height));
+    let mut stdout = io::stdout();
+    if h != state.height {
+        // 창 크기가 바뀌면 새 높이에 맞춰 스크롤 영역을 다시 잡는다.
+        if (h as usize) <= PANEL_HEIGHT * 2 {
+            close_panel(state);
+            return;
+        }
+        set_scroll_region(&mut stdout, h);
+        state.height = h;
+    }
+
+    let width = (w as usize).max(20);
+    let top = h as usize - PANEL_HEIGHT;
+    let _ = execute!(stdout, cursor::SavePosition, cursor::Hide);
+
+    let _ = draw_panel_line_at_with_fg(
+        &mut stdout,
+        top as u16,
+        &"─".repeat(width),
+        width,
+        Color::White,
+    );
+    let section = if state.section.is_empty() {
+        "Starting"
+    } else {
+        state.section.as_str()
+    };
+    let header = format!(
+        " {} Reviewing · {} · {} · Ctrl-C cancel",
+        SPINNER[state.frame % SPINNER.len()],
+        section,
+        format_elapsed(started.elapsed())
+    );
+    let _ = draw_panel_line_at_with_fg(&mut stdout, (top + 1) as u16, &header, width, Color::White);
+
+    let mut rows = state.rows.iter();
+    for idx in 0..PROVIDER_ROWS {
+        let row = (top + 2 + idx) as u16;
+        let Some((provider, entry)) = rows.next() else {
+            let _ = clear_line_at(&mut stdout, row);
+            continue;
+        };
+        let detail = match (&entry.extra, entry.status.as_str()) {
+            (Some(extra), _) => extra.clone(),
+            (None, "running") => format_elapsed(entry.since.elapsed()),
+            (None, _) => "-".to_string(),
+        };
+        let _ = draw_line_at_with_fg(
+            &mut stdout,
+            row,
+            &format!("   {:<14} {:<8} {}", provider, entry.status, detail),
+            width,
+            status_color(&entry.status),
+        );
+    }
+
+    let _ = execute!(stdout, cursor::RestorePosition, cursor::Show);
+    let _ = stdout.flush();
+}
+
+pub(super) fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs_f32();
+    if secs < 60.0 {
+        format!("{secs:.1}s")
+    } else {
+        let secs = elapsed.as_secs();
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
+
+fn status_color(status: &str) -> Color {
+    match status {
+        "running" => Color::Yellow,
+        "done" => Color::Green,
+        "error" => Color::Red,
+        _ => Color::White,
+    }
+}
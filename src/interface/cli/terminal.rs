@@ -0,0 +1,43 @@
+//! 터미널 색상/제어 시퀀스 사용 여부 판단.
+//!
+//! - 제어 시퀀스(커서 이동, 화면 지우기): stdout이 TTY이고 `TERM=dumb`가 아닐 때만 쓴다.
+//! - 색상: 위 조건에 더해 `--no-color`와 `NO_COLOR` 환경변수(비어 있지 않은 값)가 없을 때만 쓴다.
+
+use std::env;
+use std::io::{self, IsTerminal};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static COLOR_DISABLED: AtomicBool = AtomicBool::new(false);
+
+/// `--no-color`가 주어졌을 때 프로세스 전체에서 색상을 끈다.
+pub fn disable_color() {
+    COLOR_DISABLED.store(true, Ordering::Relaxed);
+}
+
+/// 커서 이동 등 ANSI 제어 시퀀스를 쓸 수 있는지.
+pub fn supports_ansi() -> bool {
+    io::stdout().is_terminal() && !is_dumb_terminal()
+}
+
+/// 색상 출력을 써도 되는지.
+pub fn color_enabled() -> bool {
+    !COLOR_DISABLED.load(Ordering::Relaxed) && !no_color_env() && supports_ansi()
+}
+
+/// SGR 코드(`"1;36"` 등)로 텍스트를 칠한다. 색상이 꺼져 있으면 원문을 그대로 반환한다.
+pub fn paint(text: &str, sgr: &str) -> String {
+    if color_enabled() {
+        format!("\x1b[{sgr}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+fn is_dumb_terminal() -> bool {
+    env::var("TERM").is_ok_and(|term| term.eq_ignore_ascii_case("dumb"))
+}
+
+// https://no-color.org: 값이 비어 있지 않으면 색상을 끈다.
+fn no_color_env() -> bool {
+    env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
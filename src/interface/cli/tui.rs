@@ -0,0 +1,767 @@
+//! `--tui` 리뷰 조종석(ratatui 전체 화면 UI).
+//!
+//! 왼쪽 패널에 provider별 상태/소요 시간/토큰 사용량, 오른쪽 패널에 에이전트 응답(없으면 리뷰 로그),
+//! 아래에 명령 입력줄을 둔다. 명령은 REPL과 같은 slash-command를 쓴다.
+//! `/review`는 조종석 안에서 실행하고, 나머지 명령은 화면을 잠시 내려 일반 출력으로 실행한다.
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::io::{self, IsTerminal, Stdout, Write};
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use anyhow::{Result, bail};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, terminal};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio_util::sync::CancellationToken;
+use unicode_width::UnicodeWidthStr;
+
+use crate::application::ports::{ConsoleLogSwitch, Reporter};
+use crate::application::usecases::review_pr::{ReviewCancelled, ReviewOutcome};
+use crate::domain::review::{RunOptions, TokenUsage};
+use crate::interface::cli::composition::AppComposition;
+use crate::interface::cli::repl::{ReplCommand, ReplSession, execute_command, parse_repl_command};
+use crate::interface::cli::repl_input::{ReplHistory, supports_interactive_input};
+use crate::interface::cli::repl_progress::{SPINNER, format_elapsed};
+
+const TICK: Duration = Duration::from_millis(120);
+/// 입력 스레드가 종료 요청을 확인하는 간격
+const EVENT_POLL: Duration = Duration::from_millis(50);
+/// 리뷰 로그 보관 줄 수(넘으면 오래된 줄부터 버린다)
+const MAX_LOG_LINES: usize = 2000;
+const SCROLL_STEP: u16 = 10;
+const PROVIDER_PANE_WIDTH: u16 = 38;
+const PROMPT: &str = "repopilot> ";
+
+struct ProviderEntry {
+    status: String,
+    elapsed_secs: Option<f32>,
+    since: Instant,
+    /// 이번 리뷰에서 이 provider가 쓴 토큰(1차 리뷰/교차 반응/통합 리뷰 합계)
+    usage: TokenUsage,
+}
+
+/// 에이전트 응답 한 건.
+struct AgentOutput {
+    provider: String,
+    stage: String,
+    body: String,
+}
+
+#[derive(Default)]
+struct CockpitState {
+    section: String,
+    providers: BTreeMap<String, ProviderEntry>,
+    outputs: Vec<AgentOutput>,
+    log: Vec<String>,
+}
+
+impl CockpitState {
+    fn log(&mut self, line: impl Into<String>) {
+        self.log.push(line.into());
+        if self.log.len() > MAX_LOG_LINES {
+            let excess = self.log.len() - MAX_LOG_LINES;
+            self.log.drain(..excess);
+        }
+    }
+
+    /// 새 리뷰를 시작할 때 이전 리뷰의 단계/provider 상태/응답을 비운다. 로그는 이어서 남긴다.
+    fn reset(&mut self) {
+        self.section.clear();
+        self.providers.clear();
+        self.outputs.clear();
+    }
+
+    /// 리뷰가 끝나면 단계를 결과로 바꾸고, 아직 `running`인 provider는 `stopped`로 표시한다.
+    fn finish(&mut self, outcome: &str) {
+        self.section = outcome.to_string();
+        for entry in self.providers.values_mut() {
+            if entry.status == "running" {
+                entry.status = "stopped".to_string();
+                entry.elapsed_secs = Some(entry.since.elapsed().as_secs_f32());
+            }
+        }
+    }
+
+    /// 현재 단계 이름. `Providers (Primary Review)`처럼 괄호가 있으면 괄호 안만 쓴다.
+    fn stage(&self) -> &str {
+        self.section
+            .strip_prefix("Providers (")
+            .and_then(|rest| rest.strip_suffix(')'))
+            .unwrap_or(&self.section)
+    }
+}
+
+fn lock(state: &Mutex<CockpitState>) -> MutexGuard<'_, CockpitState> {
+    state
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 리뷰 진행 출력을 조종석 상태에 모으는 리포터. 화면은 조종석 루프가 그린다.
+struct CockpitReporter {
+    state: Arc<Mutex<CockpitState>>,
+}
+
+impl Reporter for CockpitReporter {
+    fn section(&self, name: &str) {
+        let mut state = lock(&self.state);
+        state.section = name.to_string();
+        state.log(format!("== {name}"));
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        lock(&self.state).log(format!("{key:<12}: {value}"));
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        lock(&self.state).log(format!("[{scope:<12}] {message}"));
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, elapsed_secs: Option<f32>) {
+        let mut state = lock(&self.state);
+        let entry = state
+            .providers
+            .entry(provider.to_string())
+            .or_insert_with(|| ProviderEntry {
+                status: String::new(),
+                elapsed_secs: None,
+                since: Instant::now(),
+                usage: TokenUsage::default(),
+            });
+        entry.status = status.to_string();
+        entry.elapsed_secs = elapsed_secs;
+        entry.since = Instant::now();
+        if status != "running" {
+            match elapsed_secs {
+                Some(secs) => {
+                    state.log(format!("[provider:{provider:<12}] {status:<7} {secs:.1}s"))
+                }
+                None => state.log(format!("[provider:{provider:<12}] {status}")),
+            }
+        }
+    }
+
+    fn provider_output(&self, provider: &str, body: &str, usage: &TokenUsage) {
+        let mut state = lock(&self.state);
+        if let Some(entry) = state.providers.get_mut(provider) {
+            entry.usage.add_from(usage);
+        }
+        let stage = state.stage().to_string();
+        state.outputs.push(AgentOutput {
+            provider: provider.to_string(),
+            stage,
+            body: body.to_string(),
+        });
+    }
+
+    fn raw(&self, line: &str) {
+        let mut state = lock(&self.state);
+        for line in line.lines() {
+            state.log(line);
+        }
+    }
+}
+
+/// 오른쪽 패널에 보여 줄 내용.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Pane {
+    /// 가장 최근 에이전트 응답(응답이 없으면 리뷰 로그)을 따라간다.
+    #[default]
+    Follow,
+    Log,
+    Output(usize),
+}
+
+#[derive(Default)]
+struct UiState {
+    input: String,
+    /// `↑`/`↓`로 불러온 입력 기록 위치
+    history_idx: Option<usize>,
+    pane: Pane,
+    /// 로그는 맨 아래에서, 응답은 맨 위에서부터 센 스크롤 줄 수
+    scroll: u16,
+    /// `Follow`에서 마지막으로 본 응답 수. 새 응답이 오면 스크롤을 처음으로 되돌린다.
+    followed: usize,
+    frame: usize,
+}
+
+impl UiState {
+    /// 오른쪽 패널이 리뷰 로그를 보여 주는지.
+    fn shows_log(&self, outputs: usize) -> bool {
+        match self.pane {
+            Pane::Follow => outputs == 0,
+            Pane::Log => true,
+            Pane::Output(_) => false,
+        }
+    }
+
+    /// Tab/Shift-Tab: 따라가기 → 로그 → 응답 1..n 순서로 패널을 바꾼다.
+    fn cycle_pane(&mut self, outputs: usize, forward: bool) {
+        let count = outputs + 2;
+        let current = match self.pane {
+            Pane::Follow => 0,
+            Pane::Log => 1,
+            Pane::Output(idx) => idx.min(outputs.saturating_sub(1)) + 2,
+        };
+        let next = if forward {
+            (current + 1) % count
+        } else {
+            (current + count - 1) % count
+        };
+        self.pane = match next {
+            0 => Pane::Follow,
+            1 => Pane::Log,
+            idx => Pane::Output(idx - 2),
+        };
+        self.scroll = 0;
+    }
+
+    fn recall_history(&mut self, history: &ReplHistory, older: bool) {
+        let entries = history.entries();
+        let next = match (self.history_idx, older) {
+            (None, true) => entries.len().checked_sub(1),
+            (None, false) => return,
+            (Some(idx), true) => Some(idx.saturating_sub(1)),
+            (Some(idx), false) => (idx + 1 < entries.len()).then_some(idx + 1),
+        };
+        self.history_idx = next;
+        self.input = next.map(|idx| entries[idx].clone()).unwrap_or_default();
+    }
+}
+
+enum KeyAction {
+    None,
+    Quit,
+    /// 진행 중인 리뷰 취소
+    Cancel,
+    /// 리뷰 중 Enter. 입력은 그대로 두고 안내만 남긴다.
+    Busy,
+    Submit(String),
+}
+
+fn handle_key(
+    ui: &mut UiState,
+    history: &ReplHistory,
+    key: KeyEvent,
+    outputs: usize,
+    running: bool,
+) -> KeyAction {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    match key.code {
+        KeyCode::Char('c') if ctrl => {
+            if running {
+                KeyAction::Cancel
+            } else {
+                KeyAction::Quit
+            }
+        }
+        KeyCode::Char('d') if ctrl && ui.input.is_empty() && !running => KeyAction::Quit,
+        KeyCode::Char('u') if ctrl => {
+            ui.input.clear();
+            ui.history_idx = None;
+            KeyAction::None
+        }
+        KeyCode::Char(ch) if !ctrl && !key.modifiers.contains(KeyModifiers::ALT) => {
+            ui.input.push(ch);
+            KeyAction::None
+        }
+        KeyCode::Backspace => {
+            ui.input.pop();
+            KeyAction::None
+        }
+        KeyCode::Esc => {
+            ui.input.clear();
+            ui.history_idx = None;
+            KeyAction::None
+        }
+        KeyCode::Enter if running => KeyAction::Busy,
+        KeyCode::Enter => {
+            ui.history_idx = None;
+            let input = std::mem::take(&mut ui.input);
+            let input = input.trim();
+            if input.is_empty() {
+                KeyAction::None
+            } else {
+                KeyAction::Submit(input.to_string())
+            }
+        }
+        KeyCode::Up => {
+            ui.recall_history(history, true);
+            KeyAction::None
+        }
+        KeyCode::Down => {
+            ui.recall_history(history, false);
+            KeyAction::None
+        }
+        KeyCode::Tab => {
+            ui.cycle_pane(outputs, true);
+            KeyAction::None
+        }
+        KeyCode::BackTab => {
+            ui.cycle_pane(outputs, false);
+            KeyAction::None
+        }
+        // 로그는 아래에 붙어 있으므로 PgUp이 스크롤 값을 늘리고, 응답은 반대로 줄인다.
+        KeyCode::PageUp | KeyCode::PageDown => {
+            let up = key.code == KeyCode::PageUp;
+            ui.scroll = if up == ui.shows_log(outputs) {
+                ui.scroll.saturating_add(SCROLL_STEP)
+            } else {
+                ui.scroll.saturating_sub(SCROLL_STEP)
+            };
+            KeyAction::None
+        }
+        _ => KeyAction::None,
+    }
+}
+
+/// 터미널 입력을 별도 스레드에서 읽어 채널로 넘긴다.
+/// drop되면 스레드를 멈추고 기다려, 화면을 내린 동안 다른 입력을 가로채지 않게 한다.
+struct EventPump {
+    stop: Arc<AtomicBool>,
+    reader: Option<JoinHandle<()>>,
+    events: UnboundedReceiver<Event>,
+}
+
+impl EventPump {
+    fn start() -> Self {
+        let (tx, events) = mpsc::unbounded_channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let reader = thread::spawn({
+            let stop = Arc::clone(&stop);
+            move || {
+                while !stop.load(Ordering::Relaxed) {
+                    match event::poll(EVENT_POLL) {
+                        Ok(false) => {}
+                        Ok(true) => {
+                            let Ok(event) = event::read() else {
+                                break;
+                            };
+                            if tx.send(event).is_err() {
+                                break;
+                            }
+                        }
+                        Err(_) => break,
+                    }
+                }
+            }
+        });
+        Self {
+            stop,
+            reader: Some(reader),
+            events,
+        }
+    }
+}
+
+impl Drop for EventPump {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(reader) = self.reader.take() {
+            let _ = reader.join();
+        }
+    }
+}
+
+/// 대체 화면 + raw mode 상태의 터미널. drop되면 원래 화면으로 되돌린다.
+/// 화면이 떠 있는 동안 콘솔 로그는 멈춘다(파일 로그에는 남는다).
+struct Screen<'a> {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+    events: Option<EventPump>,
+    console_log: &'a dyn ConsoleLogSwitch,
+}
+
+impl<'a> Screen<'a> {
+    fn enter(console_log: &'a dyn ConsoleLogSwitch) -> Result<Self> {
+        console_log.set_muted(true);
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen)?;
+        let terminal = match Terminal::new(CrosstermBackend::new(io::stdout())) {
+            Ok(terminal) => terminal,
+            Err(err) => {
+                restore_terminal(console_log);
+                return Err(err.into());
+            }
+        };
+        Ok(Self {
+            terminal,
+            events: Some(EventPump::start()),
+            console_log,
+        })
+    }
+
+    /// 일반 출력을 쓰는 명령을 위해 화면을 내린다.
+    fn suspend(&mut self) {
+        self.events = None;
+        restore_terminal(self.console_log);
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        self.console_log.set_muted(true);
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), terminal::EnterAlternateScreen)?;
+        self.terminal.clear()?;
+        self.events = Some(EventPump::start());
+        Ok(())
+    }
+
+    /// 다음 터미널 이벤트. 입력 스레드가 끝났으면 None.
+    async fn next_event(&mut self) -> Option<Event> {
+        match &mut self.events {
+            Some(pump) => pump.events.recv().await,
+            None => None,
+        }
+    }
+}
+
+impl Drop for Screen<'_> {
+    fn drop(&mut self) {
+        if self.events.take().is_some() {
+            restore_terminal(self.console_log);
+        }
+    }
+}
+
+fn restore_terminal(console_log: &dyn ConsoleLogSwitch) {
+    console_log.set_muted(false);
+    let _ = terminal::disable_raw_mode();
+    let _ = execute!(io::stdout(), terminal::LeaveAlternateScreen, cursor::Show);
+}
+
+/// 조종석 안에서 실행 중인 `/review`.
+struct RunningReview<'a> {
+    review: Pin<Box<dyn Future<Output = Result<ReviewOutcome>> + 'a>>,
+    cancel: CancellationToken,
+    started: Instant,
+}
+
+fn start_review<'a>(
+    composition: &'a AppComposition,
+    reporter: &'a CockpitReporter,
+    options: RunOptions,
+) -> RunningReview<'a> {
+    let cancel = CancellationToken::new();
+    let usecase = composition
+        .review_usecase()
+        .with_cancellation(cancel.clone())
+        .with_reporter(reporter);
+    RunningReview {
+        review: Box::pin(async move { usecase.execute(options).await }),
+        cancel,
+        started: Instant::now(),
+    }
+}
+
+/// `--tui` 조종석을 실행한다. 대화형 터미널이 아니면 실패한다.
+pub async fn run_tui(composition: &AppComposition) -> Result<()> {
+    if !supports_interactive_input() || !io::stdin().is_terminal() {
+        bail!("--tui needs an interactive terminal; run `repopilot` for the line-based shell");
+    }
+
+    let state = Arc::new(Mutex::new(CockpitState::default()));
+    let reporter = CockpitReporter {
+        state: Arc::clone(&state),
+    };
+    lock(&state).log("Type /review <PR_OR_MR_URL> to start a review, /exit to quit.");
+    let mut session = ReplSession::default();
+    let mut history = ReplHistory::load(composition.state_dir());
+    let mut ui = UiState::default();
+    let mut running: Option<RunningReview<'_>> = None;
+    let mut screen = Screen::enter(composition.console_log())?;
+    let mut tick = tokio::time::interval(TICK);
+
+    loop {
+        let started = running.as_ref().map(|review| review.started);
+        screen
+            .terminal
+            .draw(|frame| draw(frame, &mut ui, &lock(&state), started))?;
+
+        tokio::select! {
+            result = async { running.as_mut().expect("guarded by is_some").review.as_mut().await },
+                if running.is_some() =>
+            {
+                running = None;
+                let mut state = lock(&state);
+                let (stage, line) = match result {
+                    Ok(_) => ("Finished", "review finished".to_string()),
+                    Err(err) if err.downcast_ref::<ReviewCancelled>().is_some() => {
+                        ("Cancelled", err.to_string())
+                    }
+                    Err(err) => ("Failed", format!("error: {err:#}")),
+                };
+                state.finish(stage);
+                state.log(line);
+            }
+            event = screen.next_event() => {
+                let Some(event) = event else {
+                    break;
+                };
+                let Event::Key(key) = event else {
+                    continue;
+                };
+                if key.kind != KeyEventKind::Press {
+                    continue;
+                }
+                let outputs = lock(&state).outputs.len();
+                match handle_key(&mut ui, &history, key, outputs, running.is_some()) {
+                    KeyAction::None => {}
+                    KeyAction::Quit => break,
+                    KeyAction::Cancel => {
+                        if let Some(review) = &running {
+                            review.cancel.cancel();
+                            lock(&state).log("cancelling review...");
+                        }
+                    }
+                    KeyAction::Busy => {
+                        lock(&state).log("a review is running; press Ctrl-C to cancel it");
+                    }
+                    KeyAction::Submit(input) => {
+                        history.push(&input);
+                        match parse_repl_command(&input) {
+                            Ok(ReplCommand::Exit) => break,
+                            Ok(ReplCommand::ReviewNeedsArgs) => ui.input = "/review ".to_string(),
+                            Ok(ReplCommand::Review(options)) if options.interactive_publish => {
+                                lock(&state).log(
+                                    "error: --interactive-publish needs the line-based shell (run `repopilot` without --tui)",
+                                );
+                            }
+                            Ok(ReplCommand::Review(mut options)) => {
+                                session.apply_defaults(&mut options);
+                                {
+                                    let mut state = lock(&state);
+                                    state.reset();
+                                    state.log(format!("{PROMPT}{input}"));
+                                }
+                                ui.pane = Pane::Follow;
+                                ui.followed = 0;
+                                ui.scroll = 0;
+                                running = Some(start_review(composition, &reporter, options));
+                            }
+                            Ok(command) => {
+                                screen.suspend();
+                                println!("{PROMPT}{input}");
+                                if let Err(err) =
+                                    execute_command(composition, &mut session, command).await
+                                {
+                                    eprintln!("error: {err:#}");
+                                }
+                                tokio::task::spawn_blocking(wait_for_key).await??;
+                                screen.resume()?;
+                            }
+                            Err(msg) => lock(&state).log(format!("error: {msg}")),
+                        }
+                    }
+                }
+            }
+            _ = tick.tick() => ui.frame += 1,
+        }
+    }
+    Ok(())
+}
+
+/// 화면을 내리고 실행한 명령의 출력을 읽을 수 있도록 키 입력을 기다린다.
+fn wait_for_key() -> io::Result<()> {
+    print!("\npress any key to return to the cockpit");
+    io::stdout().flush()?;
+    terminal::enable_raw_mode()?;
+    let result = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => break Ok(()),
+            Ok(_) => {}
+            Err(err) => break Err(err),
+        }
+    };
+    let _ = terminal::disable_raw_mode();
+    println!();
+    result
+}
+
+fn draw(frame: &mut Frame, ui: &mut UiState, state: &CockpitState, started: Option<Instant>) {
+    if ui.pane == Pane::Follow && ui.followed != state.outputs.len() {
+        ui.followed = state.outputs.len();
+        ui.scroll = 0;
+    }
+    let [main, bar] =
+        Layout::vertical([Constraint::Min(5), Constraint::Length(3)]).areas(frame.area());
+    let [left, right] =
+        Layout::horizontal([Constraint::Length(PROVIDER_PANE_WIDTH), Constraint::Min(20)])
+            .areas(main);
+    draw_providers(frame, left, ui, state, started);
+    draw_output(frame, right, ui, state);
+    draw_command_bar(frame, bar, ui, started.is_some());
+}
+
+fn draw_providers(
+    frame: &mut Frame,
+    area: Rect,
+    ui: &UiState,
+    state: &CockpitState,
+    started: Option<Instant>,
+) {
+    let dim = Style::default().fg(Color::DarkGray);
+    let mut lines = Vec::new();
+    let stage = match (started, state.section.is_empty()) {
+        (Some(_), true) => "Starting",
+        (None, true) => "Idle",
+        _ => state.stage(),
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Stage   ", dim),
+        Span::raw(stage.to_string()),
+    ]));
+    if let Some(started) = started {
+        lines.push(Line::from(vec![
+            Span::styled("Elapsed ", dim),
+            Span::raw(format!(
+                "{} {}",
+                SPINNER[ui.frame % SPINNER.len()],
+                format_elapsed(started.elapsed())
+            )),
+        ]));
+    }
+    lines.push(Line::default());
+
+    let mut total = TokenUsage::default();
+    for (provider, entry) in &state.providers {
+        total.add_from(&entry.usage);
+        let (mark, color) = match entry.status.as_str() {
+            "running" => (SPINNER[ui.frame % SPINNER.len()], Color::Yellow),
+            "done" => ("✔", Color::Green),
+            "error" => ("✘", Color::Red),
+            "cached" => ("↺", Color::Cyan),
+            "stopped" => ("■", Color::DarkGray),
+            _ => ("·", Color::White),
+        };
+        let elapsed = match (entry.elapsed_secs, entry.status.as_str()) {
+            (Some(secs), _) => format!("{secs:.1}s"),
+            (None, "running") => format_elapsed(entry.since.elapsed()),
+            (None, _) => String::new(),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{mark} "), Style::default().fg(color)),
+            Span::styled(
+                format!("{provider:<14}"),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("{:<8}", entry.status), Style::default().fg(color)),
+            Span::raw(elapsed),
+        ]));
+        if let Some(usage) = usage_text(&entry.usage) {
+            lines.push(Line::styled(format!("  {usage}"), dim));
+        }
+    }
+    if let Some(usage) = usage_text(&total) {
+        lines.push(Line::default());
+        lines.push(Line::from(vec![
+            Span::styled("Total ", dim),
+            Span::raw(usage),
+        ]));
+    }
+
+    let block = Block::bordered().title(" Providers ");
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+/// `1,234 tokens (1,000 in / 234 out)` 형식의 사용량. 토큰 수를 모르면 None.
+fn usage_text(usage: &TokenUsage) -> Option<String> {
+    let total = usage.total()?;
+    Some(match (usage.prompt_tokens, usage.completion_tokens) {
+        (Some(prompt), Some(completion)) => {
+            format!("{total} tokens ({prompt} in / {completion} out)")
+        }
+        _ => format!("{total} tokens"),
+    })
+}
+
+fn draw_output(frame: &mut Frame, area: Rect, ui: &mut UiState, state: &CockpitState) {
+    let outputs = state.outputs.len();
+    let output = match ui.pane {
+        Pane::Follow => state.outputs.last().map(|output| (outputs - 1, output)),
+        Pane::Log => None,
+        Pane::Output(idx) => {
+            let idx = idx.min(outputs.saturating_sub(1));
+            state.outputs.get(idx).map(|output| (idx, output))
+        }
+    };
+    let follow = if ui.pane == Pane::Follow {
+        " · following"
+    } else {
+        ""
+    };
+    let (title, lines): (String, Vec<Line>) = match output {
+        Some((idx, output)) => (
+            format!(
+                " {} · {} [{}/{outputs}]{follow} ",
+                output.provider,
+                output.stage,
+                idx + 1
+            ),
+            output.body.lines().map(Line::raw).collect(),
+        ),
+        None => (
+            format!(" Review log{follow} "),
+            state
+                .log
+                .iter()
+                .map(|line| Line::raw(line.as_str()))
+                .collect(),
+        ),
+    };
+
+    let block = Block::bordered().title(title);
+    let inner = block.inner(area);
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+    let max_scroll = paragraph
+        .line_count(inner.width)
+        .saturating_sub(inner.height as usize)
+        .min(u16::MAX as usize) as u16;
+    ui.scroll = ui.scroll.min(max_scroll);
+    let offset = if output.is_none() {
+        max_scroll - ui.scroll
+    } else {
+        ui.scroll
+    };
+    frame.render_widget(paragraph.block(block).scroll((offset, 0)), area);
+}
+
+fn draw_command_bar(frame: &mut Frame, area: Rect, ui: &UiState, running: bool) {
+    let hints = if running {
+        " Ctrl-C cancel review · Tab switch pane · PgUp/PgDn scroll "
+    } else {
+        " Enter run · ↑/↓ history · Tab switch pane · PgUp/PgDn scroll · Ctrl-C quit "
+    };
+    let block = Block::bordered()
+        .title(" Command ")
+        .title_bottom(Line::styled(hints, Style::default().fg(Color::DarkGray)));
+    let inner = block.inner(area);
+
+    // 입력이 입력줄보다 길면 끝부분(커서 쪽)을 보여 준다.
+    let available = (inner.width as usize).saturating_sub(PROMPT.width() + 1);
+    let mut visible = ui.input.as_str();
+    while visible.width() > available {
+        let mut chars = visible.chars();
+        chars.next();
+        visible = chars.as_str();
+    }
+    let line = Line::from(vec![
+        Span::styled(PROMPT, Style::default().fg(Color::Cyan)),
+        Span::raw(visible),
+    ]);
+    frame.render_widget(Paragraph::new(line).block(block), area);
+    let x = inner.x + (PROMPT.width() + visible.width()) as u16;
+    frame.set_cursor_position(Position::new(
+        x.min(inner.right().saturating_sub(1)),
+        inner.y,
+    ));
+}
@@ -1,4 +1,5 @@
 //! Interface layer
 //! 사용자 입력(CLI)을 애플리케이션 유스케이스로 매핑한다.
 
+pub mod builder;
 pub mod cli;
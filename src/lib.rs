@@ -7,14 +7,20 @@ pub mod application;
 pub mod domain;
 pub mod infrastructure;
 pub mod interface;
+pub mod testing;
 
+use application::usecases::review_pr::ReviewOutcome;
 use domain::review::RunOptions;
-use infrastructure::adapters::AutoConfirmer;
+use infrastructure::adapters::{AutoConfirmer, AutoFindingTriage};
 use interface::cli::AppComposition;
 
+pub use interface::builder::ReviewBuilder;
+
 /// 라이브러리 직접 호출용 실행 함수.
-pub async fn run(options: RunOptions) -> Result<()> {
-    let composition = AppComposition::with_confirmer(true, Box::new(AutoConfirmer));
+/// 세부 설정이 필요하면 `ReviewBuilder`를 사용한다.
+pub async fn run(options: RunOptions) -> Result<ReviewOutcome> {
+    let composition = AppComposition::with_confirmer(true, Box::new(AutoConfirmer))
+        .with_finding_triage(Box::new(AutoFindingTriage));
     composition.review_usecase().execute(options).await
 }
 
@@ -1,27 +1,59 @@
 //! `RepoPilot` 바이너리 진입점.
 
-use repopilot::interface::cli::{AppComposition, Cli, CliAction, run_repl};
+use repopilot::application::ports::{MigrationOutput, RunStats};
+use repopilot::application::usecases::review_pr::ReviewOutcome;
+use repopilot::application::usecases::self_update::SelfUpdateOutcome;
+use repopilot::infrastructure::{child_process, logging};
+use repopilot::interface::cli::{
+    AppComposition, AppCompositionBuilder, Cli, CliAction, TrafficMode, TrafficOption,
+    print_config_validation, read_secret_input, run_init, run_mcp_server, run_repl,
+    run_repl_script, run_tui, terminal, write_review_output,
+};
+
+/// 심각도 게이트 실패 종료 코드(런타임 오류 1, 인자 오류 2와 구분).
+const EXIT_SEVERITY_GATE: i32 = 3;
 
 #[tokio::main]
 async fn main() {
-    tracing_subscriber::fmt()
-        .with_env_filter(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "warn".into()),
-        )
-        .init();
+    logging::init();
 
-    let action = match Cli::parse_action() {
-        Ok(action) => action,
+    let (action, settings) = match Cli::parse_action() {
+        Ok(parsed) => parsed,
         Err(msg) => {
             eprintln!("error: {msg}");
             std::process::exit(2);
         }
     };
+    if settings.no_color {
+        terminal::disable_color();
+    }
+    // 종료 신호를 받으면 실행 중인 provider 자식 프로세스를 정리하고 끝낸다.
+    // REPL/조종석/스크립트의 Ctrl-C는 진행 중인 리뷰만 취소하므로 SIGINT는 그쪽에 맡긴다.
+    child_process::install_shutdown_handler(!matches!(
+        action,
+        CliAction::Interactive | CliAction::Cockpit | CliAction::Script { .. }
+    ));
+    let verbosity = settings.verbosity;
+    // 리뷰를 실행하는 명령은 진행 출력 설정(상세도/NDJSON)을 기본 reporter에 반영한다.
+    let reporting = || {
+        AppComposition::builder()
+            .with_verbosity(verbosity)
+            .with_progress_format(settings.progress_format)
+    };
 
-    // 시작 시 최신 버전 알림을 시도한다(실패 시 무시).
+    // 시작 시 최신 버전 알림을 시도한다(실패 시 무시). self-update는 직접 확인하므로 생략하고,
+    // 재생(--replay)은 네트워크를 쓰지 않으므로 생략한다.
+    let replaying = matches!(
+        &action,
+        CliAction::Review { traffic: Some(traffic), .. }
+            | CliAction::BatchReview { traffic: Some(traffic), .. }
+            if traffic.mode == TrafficMode::Replay
+    );
     let update_composition = AppComposition::default();
-    if let Ok(Some(notice)) = update_composition.check_update_usecase().execute().await {
+    if !matches!(action, CliAction::SelfUpdate { .. })
+        && !replaying
+        && let Ok(Some(notice)) = update_composition.check_update_usecase().execute().await
+    {
         eprintln!(
             "update available: {} -> {}",
             notice.current_version, notice.latest_version
@@ -40,9 +72,37 @@ async fn main() {
                 std::process::exit(1);
             }
         }
-        CliAction::InspectConfig => {
+        CliAction::Cockpit => {
+            // 조종석이 화면 전체를 그리므로 provider 상태판과 stdin 확인/분류 프롬프트를 쓰지 않는다.
+            let composition = AppComposition::builder()
+                .with_provider_panel(false)
+                .non_interactive()
+                .build();
+            if let Err(err) = run_tui(&composition).await {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        CliAction::Script { path } => {
+            // 스크립트 실행은 REPL과 같은 명령을 쓰되 하단 패널 없이 줄 단위 로그만 출력한다.
+            let composition = AppComposition::builder()
+                .with_provider_panel(false)
+                .with_verbosity(verbosity)
+                .build();
+            if let Err(err) = run_repl_script(&composition, &path).await {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        CliAction::InspectConfig { show_secrets } => {
             let composition = AppComposition::default();
-            match composition.inspect_config_usecase().execute() {
+            let usecase = composition.inspect_config_usecase();
+            let result = if show_secrets {
+                usecase.execute_with_secrets()
+            } else {
+                usecase.execute()
+            };
+            match result {
                 Ok(json) => println!("{json}"),
                 Err(err) => {
                     eprintln!("error: {err:#}");
@@ -50,13 +110,196 @@ async fn main() {
                 }
             }
         }
-        CliAction::Review(options) => {
+        CliAction::ValidateConfig => {
+            let composition = AppComposition::default();
+            match composition.validate_config_usecase().execute() {
+                Ok(validation) => {
+                    if !print_config_validation(&validation) {
+                        std::process::exit(1);
+                    }
+                }
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::MigrateConfig { force } => {
+            let composition = AppComposition::default();
+            match composition.migrate_config_usecase().execute(force) {
+                Ok(migrations) if migrations.is_empty() => {
+                    println!("config files are already at the current schema version");
+                }
+                Ok(migrations) => {
+                    for migration in migrations {
+                        let written = match &migration.output {
+                            MigrationOutput::Rewritten { backup } => {
+                                format!("backup: {}", backup.display())
+                            }
+                            MigrationOutput::WrittenBeside { path } => format!(
+                                "comments kept; migrated copy written to {}, rerun with --force to rewrite in place",
+                                path.display()
+                            ),
+                        };
+                        println!(
+                            "migrated {} (v{} -> v{}, {written})",
+                            migration.file.display(),
+                            migration.from_version,
+                            migration.to_version,
+                        );
+                        for change in &migration.changes {
+                            println!("  {change}");
+                        }
+                    }
+                }
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::GetConfig { key, show_secrets } => {
             let composition = AppComposition::default();
-            if let Err(err) = composition.review_usecase().execute(options).await {
+            let usecase = composition.config_value_usecase();
+            let result = if show_secrets {
+                usecase.get_with_secrets(&key)
+            } else {
+                usecase.get(&key)
+            };
+            match result {
+                // 문자열은 따옴표 없이 출력해 스크립트에서 바로 쓸 수 있게 한다.
+                Ok(Some(serde_json::Value::String(value))) => println!("{value}"),
+                Ok(Some(value)) => println!("{value}"),
+                Ok(None) => std::process::exit(1),
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::SetConfig { key, value, force } => {
+            let composition = AppComposition::default();
+            match composition.config_value_usecase().set(&key, &value, force) {
+                Ok(path) => println!("{key} updated in {}", path.display()),
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::Review {
+            options,
+            output,
+            traffic,
+        } => {
+            let builder = reporting().with_progress_to_stderr(output.writes_stdout());
+            let composition = with_traffic(builder, traffic).build();
+            let result = composition.review_usecase().execute(options).await;
+            if let Ok(outcome) = &result
+                && let Err(err) = write_review_output(&composition, outcome, &output)
+            {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+            exit_on_review_result(result);
+        }
+        CliAction::Resume(options) => {
+            let composition = reporting().build();
+            exit_on_review_result(composition.review_usecase().resume(options).await);
+        }
+        CliAction::Watch {
+            options,
+            interval_secs,
+        } => {
+            let composition = reporting().build();
+            if let Err(err) = composition
+                .watch_commands_usecase()
+                .execute(options, std::time::Duration::from_secs(interval_secs))
+                .await
+            {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        CliAction::Init {
+            force,
+            assume_defaults,
+        } => {
+            let composition = AppComposition::default();
+            if let Err(err) = run_init(&composition, force, assume_defaults).await {
                 eprintln!("error: {err:#}");
                 std::process::exit(1);
             }
         }
+        CliAction::SelfUpdate { check_only } => {
+            let composition = AppComposition::default();
+            match composition.self_update_usecase().execute(check_only).await {
+                Ok(SelfUpdateOutcome::UpToDate { current }) => {
+                    println!("repopilot {current} is up to date");
+                }
+                Ok(SelfUpdateOutcome::Available {
+                    current,
+                    latest,
+                    asset,
+                }) => {
+                    println!("update available: {current} -> {latest} ({asset})");
+                    println!("run `repopilot self-update` to install it");
+                }
+                Ok(SelfUpdateOutcome::Updated {
+                    from,
+                    to,
+                    path,
+                    signature_verified,
+                }) => {
+                    let verified = if signature_verified {
+                        "checksum and signature verified"
+                    } else {
+                        "checksum verified"
+                    };
+                    println!(
+                        "updated {} from {from} to {to} ({verified})",
+                        path.display()
+                    );
+                }
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::Stats { since_secs } => {
+            let composition = AppComposition::default();
+            match composition.run_stats_usecase().execute(since_secs) {
+                Ok(stats) => print_run_stats(&stats),
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        CliAction::McpServe => {
+            if let Err(err) = run_mcp_server().await {
+                eprintln!("error: {err:#}");
+                std::process::exit(1);
+            }
+        }
+        CliAction::BatchReview {
+            targets,
+            jobs,
+            traffic,
+        } => {
+            let composition = with_traffic(reporting(), traffic).build();
+            let report = composition
+                .batch_review_usecase()
+                .execute(targets, jobs)
+                .await;
+            if report.has_failures() {
+                std::process::exit(1);
+            }
+            if report.has_gate_failures() {
+                std::process::exit(EXIT_SEVERITY_GATE);
+            }
+        }
         CliAction::Auth { kind, host } => {
             let composition = AppComposition::default();
             if let Err(err) = composition.auth_vcs_usecase().execute(kind, &host) {
@@ -71,5 +314,115 @@ async fn main() {
                 std::process::exit(1);
             }
         }
+        CliAction::StoreSecret { entry } => {
+            let composition = AppComposition::default();
+            let result = read_secret_input(&format!("secret for {entry}: ")).and_then(|secret| {
+                composition.store_secret_usecase().execute(&entry, &secret)
+            });
+            match result {
+                Ok(()) => println!("stored secret in keyring: {entry}"),
+                Err(err) => {
+                    eprintln!("error: {err:#}");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+/// `--record`/`--replay` 디렉터리를 열어 조합에 연결한다. 열 수 없으면 종료한다.
+fn with_traffic(
+    builder: AppCompositionBuilder,
+    traffic: Option<TrafficOption>,
+) -> AppCompositionBuilder {
+    let Some(traffic) = traffic else {
+        return builder;
+    };
+    match builder.with_traffic(&traffic) {
+        Ok(builder) => builder,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            std::process::exit(1);
+        }
+    }
+}
+
+/// `repopilot stats` 집계를 저장소/provider 표로 출력한다.
+fn print_run_stats(stats: &RunStats) {
+    println!("history: {}", stats.location);
+    if stats.repositories.is_empty() {
+        println!("no runs recorded");
+        return;
+    }
+
+    println!();
+    println!(
+        "{:<40} {:>6} {:>7} {:>9} {:>12} {:>10}",
+        "REPOSITORY", "RUNS", "FAILED", "AVG TIME", "TOKENS", "COST"
+    );
+    for row in &stats.repositories {
+        println!(
+            "{:<40} {:>6} {:>7} {:>9} {:>12} {:>10}",
+            row.name,
+            row.runs,
+            row.failed,
+            format_secs(row.avg_secs),
+            format_tokens(row.total_tokens),
+            format_cost(row.cost_usd)
+        );
+    }
+
+    if !stats.providers.is_empty() {
+        println!();
+        println!(
+            "{:<40} {:>6} {:>9} {:>12} {:>10}",
+            "PROVIDER", "RUNS", "AVG TIME", "TOKENS", "COST"
+        );
+        for row in &stats.providers {
+            println!(
+                "{:<40} {:>6} {:>9} {:>12} {:>10}",
+                row.name,
+                row.runs,
+                format_secs(row.avg_secs),
+                format_tokens(row.total_tokens),
+                format_cost(row.cost_usd)
+            );
+        }
+    }
+}
+
+fn format_secs(secs: Option<f64>) -> String {
+    secs.map(|secs| format!("{secs:.1}s"))
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_tokens(tokens: Option<u64>) -> String {
+    tokens
+        .map(|tokens| tokens.to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_cost(cost: Option<f64>) -> String {
+    // 리뷰 한 건 비용은 1센트 미만인 경우가 많아 $1 미만은 소수점 넷째 자리까지 보인다.
+    match cost {
+        Some(cost) if cost < 1.0 => format!("${cost:.4}"),
+        Some(cost) => format!("${cost:.2}"),
+        None => "-".to_string(),
+    }
+}
+
+/// 리뷰 실행 결과에 맞춰 종료 코드를 결정한다(오류 1, 심각도 게이트 3).
+fn exit_on_review_result(result: anyhow::Result<ReviewOutcome>) {
+    match result {
+        Ok(outcome) => {
+            if let Some(gate) = outcome.gate {
+                eprintln!("error: {gate}");
+                std::process::exit(EXIT_SEVERITY_GATE);
+            }
+        }
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            std::process::exit(1);
+        }
     }
 }
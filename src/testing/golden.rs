@@ -0,0 +1,97 @@
+//! 골든 파일 비교.
+//!
+//! 기대값 파일과 실제 출력(렌더링된 코멘트, 프롬프트, 진행 출력)을 비교한다.
+//! `REPOPILOT_UPDATE_GOLDEN=1`로 실행하면 비교 대신 실제 출력으로 파일을 다시 쓴다.
+
+use std::fs;
+use std::path::Path;
+
+use crate::domain::review::RunFooter;
+
+/// 설정하면 골든 파일을 실제 출력으로 갱신하는 환경변수.
+pub const UPDATE_GOLDEN_ENV: &str = "REPOPILOT_UPDATE_GOLDEN";
+
+/// 실행마다 달라지는 값을 가릴 때 쓰는 자리표시.
+const MASK: &str = "<masked>";
+
+/// `actual`이 골든 파일 `path`의 내용과 같은지 확인한다. 다르면 첫 번째로 다른 줄을 보여 주며 panic한다.
+/// 파일이 없거나 [`UPDATE_GOLDEN_ENV`]가 설정되어 있으면 `actual`로 파일을 쓴다.
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    let path = path.as_ref();
+    let update = std::env::var_os(UPDATE_GOLDEN_ENV).is_some_and(|v| !v.is_empty());
+    if update || !path.exists() {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .unwrap_or_else(|err| panic!("failed to create {}: {err}", parent.display()));
+        }
+        fs::write(path, actual)
+            .unwrap_or_else(|err| panic!("failed to write {}: {err}", path.display()));
+        return;
+    }
+
+    let expected = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read {}: {err}", path.display()));
+    if expected == actual {
+        return;
+    }
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    let mut line = 1;
+    loop {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => line += 1,
+            (e, a) => panic!(
+                "golden file {} differs at line {line}\n  expected: {}\n  actual:   {}\n(set {UPDATE_GOLDEN_ENV}=1 to update)",
+                path.display(),
+                e.unwrap_or("<end of file>"),
+                a.unwrap_or("<end of output>"),
+            ),
+        }
+    }
+}
+
+/// 실행마다 달라지는 값(claim 마커의 `at=`/`nonce=`, `Run ID`, 코멘트의 실행 정보 footer)을 가린다.
+pub fn mask_volatile(text: &str) -> String {
+    let masked = text
+        .lines()
+        .map(|line| {
+            if line.starts_with("Run ID: ") {
+                format!("Run ID: {MASK}")
+            } else if RunFooter::is_footer_line(line) {
+                format!("{}{MASK}</sub>", RunFooter::LINE_PREFIX)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut masked = ["at=", "nonce="]
+        .into_iter()
+        .fold(masked, |text, prefix| mask_field(&text, prefix));
+    if text.ends_with('\n') {
+        masked.push('\n');
+    }
+    masked
+}
+
+/// `prefix` 뒤에 오는 영숫자 값을 가린다(공백 뒤에서 시작하는 필드만).
+fn mask_field(text: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find(prefix) {
+        let at_field_start = pos == 0 || rest[..pos].ends_with(' ');
+        out.push_str(&rest[..pos + prefix.len()]);
+        rest = &rest[pos + prefix.len()..];
+        if at_field_start {
+            let end = rest
+                .find(|c: char| !c.is_ascii_alphanumeric())
+                .unwrap_or(rest.len());
+            if end > 0 {
+                out.push_str(MASK);
+                rest = &rest[end..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,132 @@
+//! 유스케이스 테스트용 가짜 포트 구현과 골든 파일 비교 도구.
+//!
+//! 라이브러리 사용자와 기여자가 포트 trait을 매번 다시 구현하지 않고 리뷰 흐름을 테스트할 수 있게 한다.
+//! 모든 구현은 메모리에서만 동작하며(네트워크, 파일, 키체인, 터미널 입력을 쓰지 않는다), 상태가 있는
+//! 구현은 복제본끼리 상태를 공유한다. 조합에 넘긴 복제본이 게시한 코멘트나 기록한 출력을 원본으로 확인한다.
+//! 순수 계산만 하는 포트(`TargetResolver`, `MarkdownRenderer`)는 기본 어댑터를 그대로 쓴다.
+//!
+//! ```no_run
+//! use repopilot::domain::review::RunOptions;
+//! use repopilot::testing::{FakeApp, FakeProviderAgent, FakeVcsGateway, assert_golden, mask_volatile};
+//!
+//! # async fn example(options: RunOptions) -> anyhow::Result<()> {
+//! let vcs = FakeVcsGateway::new("abc123", "diff --git a/a.rs b/a.rs\n");
+//! let app = FakeApp::new(vcs.clone(), vec![FakeProviderAgent::new("fake", "Looks good.")]);
+//! app.composition().review_usecase().execute(options).await?;
+//!
+//! let summary = &vcs.comments()[0].body;
+//! assert_golden("tests/golden/summary.md", &mask_volatile(summary));
+//! # Ok(())
+//! # }
+//! ```
+
+mod golden;
+mod ports;
+mod provider;
+mod reporter;
+mod vcs;
+
+pub use golden::{UPDATE_GOLDEN_ENV, assert_golden, mask_volatile};
+pub use ports::{
+    FakeAuthenticator, FakeBinaryInstaller, FakeClipboardReader, FakeConfigRepository,
+    FakeFindingTriage, FakeGitRemoteReader, FakeHostTokenResolver, FakeNotifier, FakeResponseCache,
+    FakeRunHistory, FakeRunJournal, FakeSystemPromptResolver, FakeUpdateCheckCache,
+    FakeUpdateChecker, FakeUserConfirmer,
+};
+pub use provider::{FakeProviderAgent, FakeProviderFactory};
+pub use reporter::FakeReporter;
+pub use vcs::{
+    FakeBranch, FakeFileComment, FakeIssue, FakeLineComment, FakeVcsFactory, FakeVcsGateway,
+};
+
+use crate::application::config::Config;
+use crate::infrastructure::adapters::{MarkdownRendererAdapter, UrlTargetResolver};
+use crate::interface::cli::{AppComposition, AppCompositionBuilder};
+
+/// 모든 포트를 가짜 구현으로 채운 조합과, 결과 확인에 쓸 핸들 묶음.
+/// 필드는 조합에 넘기는 구현과 상태를 공유하므로 실행 후 그대로 확인하면 된다.
+#[derive(Clone)]
+pub struct FakeApp {
+    pub config: Config,
+    pub vcs: FakeVcsFactory,
+    pub providers: FakeProviderFactory,
+    pub reporter: FakeReporter,
+    pub host_tokens: FakeHostTokenResolver,
+    pub prompts: FakeSystemPromptResolver,
+    pub auth: FakeAuthenticator,
+    pub confirmer: FakeUserConfirmer,
+    pub triage: FakeFindingTriage,
+    pub journal: FakeRunJournal,
+    pub history: FakeRunHistory,
+    pub response_cache: FakeResponseCache,
+    pub notifier: FakeNotifier,
+    pub update_checker: FakeUpdateChecker,
+    pub update_cache: FakeUpdateCheckCache,
+    pub installer: FakeBinaryInstaller,
+    pub git_remotes: FakeGitRemoteReader,
+    pub clipboard: FakeClipboardReader,
+}
+
+impl FakeApp {
+    /// 기본 설정으로 PR/MR 하나와 provider 목록을 흉내 내는 조합을 만든다.
+    pub fn new(vcs: FakeVcsGateway, providers: Vec<FakeProviderAgent>) -> Self {
+        Self {
+            config: Config::default(),
+            vcs: FakeVcsFactory::new(vcs),
+            providers: FakeProviderFactory::new(providers),
+            reporter: FakeReporter::new(),
+            host_tokens: FakeHostTokenResolver::default(),
+            prompts: FakeSystemPromptResolver::default(),
+            auth: FakeAuthenticator::default(),
+            confirmer: FakeUserConfirmer::default(),
+            triage: FakeFindingTriage::default(),
+            journal: FakeRunJournal::default(),
+            history: FakeRunHistory::default(),
+            response_cache: FakeResponseCache::default(),
+            notifier: FakeNotifier::default(),
+            update_checker: FakeUpdateChecker::default(),
+            update_cache: FakeUpdateCheckCache::default(),
+            installer: FakeBinaryInstaller::default(),
+            git_remotes: FakeGitRemoteReader::default(),
+            clipboard: FakeClipboardReader::default(),
+        }
+    }
+
+    /// 설정 저장소가 돌려줄 설정.
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// 가짜 구현으로 채운 빌더. 일부 포트만 다시 바꿀 때 쓴다.
+    pub fn builder(&self) -> AppCompositionBuilder {
+        AppComposition::builder()
+            .with_provider_panel(false)
+            .with_config_repository(Box::new(FakeConfigRepository::new(self.config.clone())))
+            .with_host_token_resolver(Box::new(self.host_tokens.clone()))
+            .with_system_prompt_resolver(Box::new(self.prompts.clone()))
+            .with_target_resolver(Box::new(UrlTargetResolver))
+            .with_vcs_authenticator(Box::new(self.auth.clone()))
+            .with_provider_authenticator(Box::new(self.auth.clone()))
+            .with_secret_store(Box::new(self.auth.clone()))
+            .with_vcs_factory(Box::new(self.vcs.clone()))
+            .with_provider_factory(Box::new(self.providers.clone()))
+            .with_renderer(Box::new(MarkdownRendererAdapter))
+            .with_reporter(Box::new(self.reporter.clone()))
+            .with_update_checker(Box::new(self.update_checker.clone()))
+            .with_update_check_cache(Box::new(self.update_cache.clone()))
+            .with_binary_installer(Box::new(self.installer.clone()))
+            .with_run_journal(Box::new(self.journal.clone()))
+            .with_run_history(Box::new(self.history.clone()))
+            .with_response_cache(Box::new(self.response_cache.clone()))
+            .with_notifier(Box::new(self.notifier.clone()))
+            .with_confirmer(Box::new(self.confirmer.clone()))
+            .with_finding_triage(Box::new(self.triage.clone()))
+            .with_git_remote_reader(Box::new(self.git_remotes.clone()))
+            .with_clipboard_reader(Box::new(self.clipboard.clone()))
+    }
+
+    pub fn composition(&self) -> AppComposition {
+        self.builder().build()
+    }
+}
@@ -0,0 +1,536 @@
+//! 설정/인증/저장소/알림/업데이트 등 나머지 포트의 메모리 구현.
+//!
+//! 파일, 키체인, 네트워크, 터미널 입력을 쓰지 않는다. 상태가 있는 구현은 복제본끼리 공유하므로
+//! 조합에 넘긴 뒤에도 기록된 값을 확인할 수 있다.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+use crate::application::config::{Config, HostConfig, NotificationsConfig, ProviderConfig};
+use crate::application::ports::{
+    BinaryInstaller, CachedResponse, ClipboardReader, ConfigMigration, ConfigRepository,
+    ConfigValidation, FindingTriage, GitRemoteReader, HostTokenResolution, HostTokenResolver,
+    InitializedProject, LatestVersionInfo, Notifier, ProjectInit, ProviderAuthKind,
+    ProviderAuthenticator, RecentRun, ResponseCache, ReviewNotification, RunHistory, RunJournal,
+    RunJournalEntry, RunRecord, RunStats, SecretStore, SystemPromptResolver, TriageDecision,
+    UpdateCheckCache, UpdateCheckRecord, UpdateChecker, UserConfirmer, VcsAuthKind,
+    VcsAuthenticator,
+};
+use crate::domain::review::{Finding, PreviousReview};
+use crate::domain::target::RepositoryRef;
+use crate::domain::template::{CommentTemplates, PromptTemplates};
+use crate::infrastructure::adapters::ExeBinaryInstaller;
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// 주어진 [`Config`]를 그대로 돌려주는 설정 저장소. 파일 기록(`set_value`, `init_project`)은 지원하지 않는다.
+#[derive(Clone, Default)]
+pub struct FakeConfigRepository {
+    config: Config,
+}
+
+impl FakeConfigRepository {
+    pub fn new(config: Config) -> Self {
+        Self { config }
+    }
+}
+
+impl ConfigRepository for FakeConfigRepository {
+    fn load(&self) -> Result<Config> {
+        Ok(self.config.clone())
+    }
+
+    fn inspect_pretty_json(&self, _show_secrets: bool) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&self.config)?)
+    }
+
+    fn editable_config_path(&self) -> Result<PathBuf> {
+        bail!("fake config repository has no config file")
+    }
+
+    fn validate(&self) -> Result<ConfigValidation> {
+        Ok(ConfigValidation {
+            checked_paths: Vec::new(),
+            issues: Vec::new(),
+        })
+    }
+
+    fn get_value(&self, key: &str, _show_secrets: bool) -> Result<Option<serde_json::Value>> {
+        let value = serde_json::to_value(&self.config)?;
+        Ok(key
+            .split('.')
+            .try_fold(&value, |value, part| value.get(part))
+            .filter(|value| !value.is_null())
+            .cloned())
+    }
+
+    fn set_value(&self, key: &str, _value: &str, _force: bool) -> Result<PathBuf> {
+        bail!("fake config repository cannot set {key}")
+    }
+
+    fn migrate(&self, _force: bool) -> Result<Vec<ConfigMigration>> {
+        Ok(Vec::new())
+    }
+
+    fn init_project(&self, _init: &ProjectInit) -> Result<InitializedProject> {
+        bail!("fake config repository cannot initialize a project")
+    }
+
+    fn state_dir(&self) -> Option<PathBuf> {
+        None
+    }
+}
+
+/// 모든 호스트에 같은 토큰을 돌려주는 해석기(기본 `fake-token`).
+#[derive(Clone)]
+pub struct FakeHostTokenResolver {
+    token: Option<String>,
+}
+
+impl Default for FakeHostTokenResolver {
+    fn default() -> Self {
+        Self::new(Some("fake-token".to_string()))
+    }
+}
+
+impl FakeHostTokenResolver {
+    /// `None`이면 토큰이 없는 환경을 흉내 낸다(게시하는 리뷰는 토큰 없음 오류로 끝난다).
+    pub fn new(token: Option<String>) -> Self {
+        Self { token }
+    }
+}
+
+impl HostTokenResolver for FakeHostTokenResolver {
+    fn resolve(&self, _host: &str, _host_cfg: Option<&HostConfig>) -> Result<HostTokenResolution> {
+        Ok(HostTokenResolution {
+            token: self.token.clone(),
+            source: Some("fake".to_string()),
+        })
+    }
+}
+
+/// 파일 대신 메모리의 리뷰 가이드/경로별 가이드를 쓰는 시스템 프롬프트 해석기.
+/// 시스템 지시문과 템플릿은 설정값과 기본 템플릿을 그대로 쓴다.
+#[derive(Clone, Default)]
+pub struct FakeSystemPromptResolver {
+    review_guide: Option<String>,
+    path_guides: HashMap<String, String>,
+}
+
+impl FakeSystemPromptResolver {
+    pub fn with_review_guide(mut self, guide: impl Into<String>) -> Self {
+        self.review_guide = Some(guide.into());
+        self
+    }
+
+    /// `path_guides` 항목의 `guide` 경로에 해당하는 본문.
+    pub fn with_path_guide(mut self, path: impl Into<String>, guide: impl Into<String>) -> Self {
+        self.path_guides.insert(path.into(), guide.into());
+        self
+    }
+}
+
+impl SystemPromptResolver for FakeSystemPromptResolver {
+    fn resolve(&self, config: &Config) -> Result<String> {
+        Ok(config.system_prompt())
+    }
+
+    fn review_guide(&self, _config: &Config) -> Result<Option<String>> {
+        Ok(self.review_guide.clone())
+    }
+
+    fn path_guide(&self, path: &str) -> Result<Option<String>> {
+        Ok(self.path_guides.get(path).cloned())
+    }
+
+    fn prompt_templates(&self, _config: &Config) -> Result<PromptTemplates> {
+        Ok(PromptTemplates::default())
+    }
+
+    fn comment_templates(&self, _config: &Config) -> Result<CommentTemplates> {
+        Ok(CommentTemplates::default())
+    }
+}
+
+/// 인증/비밀 저장 호출을 기록만 하는 구현(VCS/provider 로그인, 키체인 저장).
+#[derive(Clone, Default)]
+pub struct FakeAuthenticator {
+    calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl FakeAuthenticator {
+    /// 기록된 호출(`vcs <종류> <호스트>`, `provider <종류>`, `store <entry>`).
+    pub fn calls(&self) -> Vec<String> {
+        lock(&self.calls).clone()
+    }
+
+    fn record(&self, call: String) {
+        lock(&self.calls).push(call);
+    }
+}
+
+impl VcsAuthenticator for FakeAuthenticator {
+    fn authenticate(
+        &self,
+        kind: VcsAuthKind,
+        host: &str,
+        _host_cfg: Option<&HostConfig>,
+    ) -> Result<()> {
+        self.record(format!("vcs {kind:?} {host}"));
+        Ok(())
+    }
+}
+
+impl ProviderAuthenticator for FakeAuthenticator {
+    fn authenticate(
+        &self,
+        kind: ProviderAuthKind,
+        _provider_cfg: Option<&ProviderConfig>,
+    ) -> Result<()> {
+        self.record(format!("provider {kind:?}"));
+        Ok(())
+    }
+}
+
+impl SecretStore for FakeAuthenticator {
+    fn store(&self, entry: &str, _secret: &str) -> Result<()> {
+        self.record(format!("store {entry}"));
+        Ok(())
+    }
+}
+
+/// 고정 원격 URL 목록.
+#[derive(Clone, Default)]
+pub struct FakeGitRemoteReader {
+    pub urls: Vec<String>,
+}
+
+impl GitRemoteReader for FakeGitRemoteReader {
+    fn remote_urls(&self) -> Vec<String> {
+        self.urls.clone()
+    }
+}
+
+/// 고정 클립보드 텍스트.
+#[derive(Clone, Default)]
+pub struct FakeClipboardReader {
+    pub text: Option<String>,
+}
+
+impl ClipboardReader for FakeClipboardReader {
+    fn read_text(&self) -> Option<String> {
+        self.text.clone()
+    }
+}
+
+/// 항상 같은 답을 하고 받은 확인 메시지를 기록하는 확인 입력.
+#[derive(Clone)]
+pub struct FakeUserConfirmer {
+    answer: bool,
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl Default for FakeUserConfirmer {
+    fn default() -> Self {
+        Self::new(true)
+    }
+}
+
+impl FakeUserConfirmer {
+    pub fn new(answer: bool) -> Self {
+        Self {
+            answer,
+            messages: Arc::default(),
+        }
+    }
+
+    pub fn messages(&self) -> Vec<String> {
+        lock(&self.messages).clone()
+    }
+}
+
+impl UserConfirmer for FakeUserConfirmer {
+    fn confirm(&self, message: &str) -> Result<bool> {
+        lock(&self.messages).push(message.to_string());
+        Ok(self.answer)
+    }
+}
+
+/// 모든 지적사항에 같은 결정을 내리고 검토한 지적사항 제목을 기록하는 triage(기본 `Accept`).
+#[derive(Clone)]
+pub struct FakeFindingTriage {
+    decision: TriageDecision,
+    seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl Default for FakeFindingTriage {
+    fn default() -> Self {
+        Self::new(TriageDecision::Accept)
+    }
+}
+
+impl FakeFindingTriage {
+    pub fn new(decision: TriageDecision) -> Self {
+        Self {
+            decision,
+            seen: Arc::default(),
+        }
+    }
+
+    /// 검토한 지적사항 제목(`에이전트: 제목`).
+    pub fn seen(&self) -> Vec<String> {
+        lock(&self.seen).clone()
+    }
+}
+
+impl FindingTriage for FakeFindingTriage {
+    fn triage(
+        &self,
+        agent_name: &str,
+        finding: &Finding,
+        _index: usize,
+        _total: usize,
+    ) -> Result<TriageDecision> {
+        lock(&self.seen).push(format!("{agent_name}: {}", finding.title));
+        Ok(self.decision.clone())
+    }
+}
+
+/// 메모리 실행 저널(대상 URL별 한 건).
+#[derive(Clone, Default)]
+pub struct FakeRunJournal {
+    entries: Arc<Mutex<HashMap<String, RunJournalEntry>>>,
+}
+
+impl FakeRunJournal {
+    /// 남아 있는 저널(실패해 재시도를 기다리는 실행).
+    pub fn entries(&self) -> Vec<RunJournalEntry> {
+        lock(&self.entries).values().cloned().collect()
+    }
+}
+
+impl RunJournal for FakeRunJournal {
+    fn save(&self, entry: &RunJournalEntry) -> Result<String> {
+        lock(&self.entries).insert(entry.target_url.clone(), entry.clone());
+        Ok(format!("memory:{}", entry.target_url))
+    }
+
+    fn load(&self, target_url: &str) -> Result<Option<RunJournalEntry>> {
+        Ok(lock(&self.entries).get(target_url).cloned())
+    }
+
+    fn remove(&self, target_url: &str) -> Result<()> {
+        lock(&self.entries).remove(target_url);
+        Ok(())
+    }
+}
+
+/// 실행 기록을 메모리에 쌓는 저장소. 집계(`stats`)는 빈 결과를 돌려준다.
+#[derive(Clone, Default)]
+pub struct FakeRunHistory {
+    records: Arc<Mutex<Vec<RunRecord>>>,
+}
+
+impl FakeRunHistory {
+    /// 기록된 실행(기록 순).
+    pub fn records(&self) -> Vec<RunRecord> {
+        lock(&self.records).clone()
+    }
+}
+
+impl RunHistory for FakeRunHistory {
+    fn record(&self, run: &RunRecord) -> Result<()> {
+        lock(&self.records).push(run.clone());
+        Ok(())
+    }
+
+    fn stats(&self, _since: Option<u64>) -> Result<RunStats> {
+        Ok(RunStats {
+            location: "memory".to_string(),
+            repositories: Vec::new(),
+            providers: Vec::new(),
+        })
+    }
+
+    fn recent(&self, limit: usize) -> Result<Vec<RecentRun>> {
+        Ok(lock(&self.records)
+            .iter()
+            .rev()
+            .take(limit)
+            .map(|run| RecentRun {
+                started_at: run.started_at,
+                repository: run.repository.clone(),
+                target_url: run.target_url.clone(),
+                head_sha: run.head_sha.clone(),
+                outcome: run.outcome.clone(),
+                duration_secs: run.duration_secs,
+                total_tokens: run
+                    .providers
+                    .iter()
+                    .filter_map(|p| p.total_tokens)
+                    .reduce(|a, b| a + b),
+                cost_usd: run
+                    .providers
+                    .iter()
+                    .filter_map(|p| p.cost_usd)
+                    .reduce(|a, b| a + b),
+            })
+            .collect())
+    }
+
+    fn previous_review(&self, target_url: &str, head_sha: &str) -> Result<Option<PreviousReview>> {
+        Ok(lock(&self.records).iter().rev().find_map(|run| {
+            let sha = run.head_sha.as_deref()?;
+            let findings = run.findings.as_ref()?;
+            (run.target_url == target_url
+                && sha != head_sha
+                && matches!(run.outcome.as_str(), "posted" | "gate-failed"))
+            .then(|| PreviousReview {
+                head_sha: sha.to_string(),
+                findings: findings.clone(),
+            })
+        }))
+    }
+}
+
+/// 알림을 보내지 않고 기록만 하는 알림 채널. 설정과 무관하게 `fake` 채널로 보낸 것으로 보고한다.
+#[derive(Clone, Default)]
+pub struct FakeNotifier {
+    sent: Arc<Mutex<Vec<ReviewNotification>>>,
+}
+
+impl FakeNotifier {
+    pub fn sent(&self) -> Vec<ReviewNotification> {
+        lock(&self.sent).clone()
+    }
+}
+
+#[async_trait]
+impl Notifier for FakeNotifier {
+    async fn notify(
+        &self,
+        _config: &NotificationsConfig,
+        notification: &ReviewNotification,
+    ) -> Result<Vec<String>> {
+        lock(&self.sent).push(notification.clone());
+        Ok(vec!["fake".to_string()])
+    }
+}
+
+/// 고정 최신 버전과 내려받기 내용을 돌려주는 업데이트 확인(기본은 새 버전 없음).
+#[derive(Clone, Default)]
+pub struct FakeUpdateChecker {
+    pub latest: Option<LatestVersionInfo>,
+    pub download: Vec<u8>,
+}
+
+#[async_trait]
+impl UpdateChecker for FakeUpdateChecker {
+    async fn fetch_latest(
+        &self,
+        _url: &str,
+        _token: Option<&str>,
+        _timeout_ms: u64,
+    ) -> Result<Option<LatestVersionInfo>> {
+        Ok(self.latest.clone())
+    }
+
+    async fn fetch_latest_release(
+        &self,
+        _repository: &RepositoryRef,
+        _host_cfg: Option<&HostConfig>,
+        _token: Option<&str>,
+        _timeout_ms: u64,
+    ) -> Result<Option<LatestVersionInfo>> {
+        Ok(self.latest.clone())
+    }
+
+    async fn download(
+        &self,
+        _url: &str,
+        _token: Option<&str>,
+        _timeout_ms: u64,
+    ) -> Result<Vec<u8>> {
+        Ok(self.download.clone())
+    }
+}
+
+/// 메모리 업데이트 확인 캐시.
+#[derive(Clone, Default)]
+pub struct FakeUpdateCheckCache {
+    record: Arc<Mutex<Option<UpdateCheckRecord>>>,
+}
+
+impl UpdateCheckCache for FakeUpdateCheckCache {
+    fn load(&self) -> Option<UpdateCheckRecord> {
+        lock(&self.record).clone()
+    }
+
+    fn save(&self, record: &UpdateCheckRecord) -> Result<()> {
+        *lock(&self.record) = Some(record.clone());
+        Ok(())
+    }
+}
+
+/// 응답을 메모리에 보관하는 캐시.
+#[derive(Clone, Default)]
+pub struct FakeResponseCache {
+    entries: Arc<Mutex<HashMap<String, CachedResponse>>>,
+}
+
+impl FakeResponseCache {
+    /// 보관 중인 응답 수.
+    pub fn len(&self) -> usize {
+        lock(&self.entries).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        lock(&self.entries).is_empty()
+    }
+}
+
+impl ResponseCache for FakeResponseCache {
+    fn load(&self, key: &str) -> Result<Option<CachedResponse>> {
+        Ok(lock(&self.entries).get(key).cloned())
+    }
+
+    fn save(&self, key: &str, response: &CachedResponse) -> Result<()> {
+        lock(&self.entries).insert(key.to_string(), response.clone());
+        Ok(())
+    }
+}
+
+/// 실행 파일을 바꾸지 않고 설치할 바이트만 기록하는 설치기. 서명 검증은 항상 통과한다.
+#[derive(Clone, Default)]
+pub struct FakeBinaryInstaller {
+    installed: Arc<Mutex<Vec<Vec<u8>>>>,
+}
+
+impl FakeBinaryInstaller {
+    pub fn installed(&self) -> Vec<Vec<u8>> {
+        lock(&self.installed).clone()
+    }
+}
+
+impl BinaryInstaller for FakeBinaryInstaller {
+    fn sha256_hex(&self, bytes: &[u8]) -> String {
+        ExeBinaryInstaller.sha256_hex(bytes)
+    }
+
+    fn verify_signature(&self, _bytes: &[u8], _signature: &[u8], _public_key: &str) -> Result<()> {
+        Ok(())
+    }
+
+    fn replace_current_exe(&self, bytes: &[u8]) -> Result<PathBuf> {
+        lock(&self.installed).push(bytes.to_vec());
+        Ok(PathBuf::from("repopilot"))
+    }
+}
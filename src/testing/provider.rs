@@ -0,0 +1,139 @@
+//! 고정 응답을 돌려주고 받은 프롬프트를 기록하는 provider 에이전트/팩토리.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{Result, bail};
+use async_trait::async_trait;
+
+use crate::application::config::Config;
+use crate::application::ports::{ProviderAgent, ProviderFactory};
+use crate::domain::review::{ProviderResponse, ReviewRequest, TokenUsage};
+use crate::infrastructure::providers::{attach_findings, build_primary_prompt};
+
+const DEFAULT_REACTION: &str = "No additional comments.";
+
+/// 고정 응답 provider 에이전트.
+/// 1차 리뷰 응답은 실제 어댑터처럼 출력 계약 블록(```` ```repopilot-findings ````)을 구조화 지적사항으로 바꾼다.
+#[derive(Clone)]
+pub struct FakeProviderAgent {
+    id: String,
+    name: String,
+    /// 1차 리뷰 응답 본문(Err면 그 메시지로 실패)
+    review: Result<String, String>,
+    reaction: String,
+    usage: TokenUsage,
+    prompts: Arc<Mutex<Vec<String>>>,
+}
+
+impl FakeProviderAgent {
+    /// 1차 리뷰에 `content`를 돌려주는 에이전트.
+    pub fn new(id: impl Into<String>, content: impl Into<String>) -> Self {
+        let id = id.into();
+        Self {
+            name: id.clone(),
+            id,
+            review: Ok(content.into()),
+            reaction: DEFAULT_REACTION.to_string(),
+            usage: TokenUsage::default(),
+            prompts: Arc::default(),
+        }
+    }
+
+    /// 1차 리뷰가 `message` 오류로 실패하는 에이전트.
+    pub fn failing(id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            review: Err(message.into()),
+            ..Self::new(id, String::new())
+        }
+    }
+
+    /// 사용자 표시 이름(기본은 id).
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// 2차 상호 코멘트/중재 프롬프트에 돌려줄 본문.
+    pub fn with_reaction(mut self, content: impl Into<String>) -> Self {
+        self.reaction = content.into();
+        self
+    }
+
+    /// 응답마다 보고할 토큰 사용량.
+    pub fn with_usage(mut self, prompt_tokens: u64, completion_tokens: u64) -> Self {
+        self.usage = TokenUsage {
+            prompt_tokens: Some(prompt_tokens),
+            completion_tokens: Some(completion_tokens),
+            total_tokens: Some(prompt_tokens + completion_tokens),
+        };
+        self
+    }
+
+    /// 받은 프롬프트(호출 순). 1차 리뷰는 실제 provider가 받는 프롬프트로 조립해 기록한다.
+    pub fn prompts(&self) -> Vec<String> {
+        self.lock_prompts().clone()
+    }
+
+    fn lock_prompts(&self) -> MutexGuard<'_, Vec<String>> {
+        self.prompts
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn response(&self, content: String) -> ProviderResponse {
+        ProviderResponse {
+            content,
+            usage: self.usage.clone(),
+            findings: Vec::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl ProviderAgent for FakeProviderAgent {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn review(&self, request: &ReviewRequest) -> Result<ProviderResponse> {
+        self.lock_prompts().push(build_primary_prompt(request)?);
+        match &self.review {
+            Ok(content) => Ok(attach_findings(
+                self.response(content.clone()),
+                &request.severity_taxonomy,
+            )),
+            Err(message) => bail!("{message}"),
+        }
+    }
+
+    async fn review_prompt(&self, prompt: &str) -> Result<ProviderResponse> {
+        self.lock_prompts().push(prompt.to_string());
+        Ok(self.response(self.reaction.clone()))
+    }
+}
+
+/// 설정과 무관하게 주어진 에이전트를 그대로 돌려주는 팩토리.
+#[derive(Clone, Default)]
+pub struct FakeProviderFactory {
+    agents: Vec<FakeProviderAgent>,
+}
+
+impl FakeProviderFactory {
+    pub fn new(agents: Vec<FakeProviderAgent>) -> Self {
+        Self { agents }
+    }
+}
+
+impl ProviderFactory for FakeProviderFactory {
+    fn build(&self, _config: &Config) -> Vec<Box<dyn ProviderAgent>> {
+        self.agents
+            .iter()
+            .cloned()
+            .map(|agent| Box::new(agent) as Box<dyn ProviderAgent>)
+            .collect()
+    }
+}
@@ -0,0 +1,89 @@
+//! 진행 출력을 메모리에 쌓는 리포터.
+
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use crate::application::ports::Reporter;
+
+#[derive(Default)]
+struct Transcript {
+    lines: Vec<String>,
+    outcomes: Vec<String>,
+    details: Vec<String>,
+}
+
+/// 진행 출력을 한 줄씩 기록하는 리포터.
+/// 실행마다 달라지는 provider 소요 시간은 남기지 않고, `--verbose` 진단 정보는 따로 모은다.
+#[derive(Clone, Default)]
+pub struct FakeReporter {
+    transcript: Arc<Mutex<Transcript>>,
+}
+
+impl FakeReporter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 진행 출력 전체(`== 섹션`, `키: 값`, `[범위] 메시지`, `[provider] 이름 상태`, 원문/결과 줄).
+    pub fn lines(&self) -> Vec<String> {
+        self.lock().lines.clone()
+    }
+
+    /// 진행 출력 전체를 한 문자열로 합친다(골든 파일 비교용).
+    pub fn transcript(&self) -> String {
+        let mut text = self.lock().lines.join("\n");
+        text.push('\n');
+        text
+    }
+
+    /// 최종 결과 줄(`Reporter::outcome`)만.
+    pub fn outcomes(&self) -> Vec<String> {
+        self.lock().outcomes.clone()
+    }
+
+    /// 진단 정보(`Reporter::detail`)를 `[범위] 메시지` 형식으로.
+    pub fn details(&self) -> Vec<String> {
+        self.lock().details.clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Transcript> {
+        self.transcript
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn push(&self, line: String) {
+        self.lock().lines.push(line);
+    }
+}
+
+impl Reporter for FakeReporter {
+    fn section(&self, name: &str) {
+        self.push(format!("== {name}"));
+    }
+
+    fn kv(&self, key: &str, value: &str) {
+        self.push(format!("{key}: {value}"));
+    }
+
+    fn status(&self, scope: &str, message: &str) {
+        self.push(format!("[{scope}] {message}"));
+    }
+
+    fn provider_status(&self, provider: &str, status: &str, _elapsed_secs: Option<f32>) {
+        self.push(format!("[provider] {provider} {status}"));
+    }
+
+    fn raw(&self, line: &str) {
+        self.push(line.to_string());
+    }
+
+    fn outcome(&self, line: &str) {
+        let mut transcript = self.lock();
+        transcript.lines.push(line.to_string());
+        transcript.outcomes.push(line.to_string());
+    }
+
+    fn detail(&self, scope: &str, message: &str) {
+        self.lock().details.push(format!("[{scope}] {message}"));
+    }
+}
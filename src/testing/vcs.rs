@@ -0,0 +1,531 @@
+//! 메모리 안의 PR/MR 하나로 동작하는 VCS 게이트웨이/팩토리.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use anyhow::{Result, anyhow, bail};
+use async_trait::async_trait;
+
+use crate::application::config::HostConfig;
+use crate::application::ports::{RepositoryGateway, VcsFactory, VcsGateway};
+use crate::domain::diff::LinePosition;
+use crate::domain::patch::PatchedFile;
+use crate::domain::review::{CommentAuthor, IssueRef, ReviewComment};
+use crate::domain::target::{OpenReview, RepositoryRef, ReviewTarget};
+
+/// 파일 단위 리뷰 스레드로 게시된 코멘트.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeFileComment {
+    pub id: String,
+    pub head_sha: String,
+    pub path: String,
+    pub body: String,
+}
+
+/// 줄 구간에 단 인라인 코멘트.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeLineComment {
+    pub id: String,
+    pub head_sha: String,
+    pub path: String,
+    pub position: LinePosition,
+    pub body: String,
+}
+
+/// `push_branch`로 올린 브랜치.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeBranch {
+    pub name: String,
+    pub base_sha: String,
+    pub message: String,
+    pub files: Vec<PatchedFile>,
+}
+
+/// `create_issue`로 만든 이슈.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeIssue {
+    pub number: String,
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Default)]
+struct PullRequestState {
+    head_sha: String,
+    title: String,
+    author: String,
+    labels: Vec<String>,
+    description: String,
+    commit_messages: Vec<String>,
+    diff: String,
+    comments: Vec<ReviewComment>,
+    file_comments: Vec<FakeFileComment>,
+    line_comments: Vec<FakeLineComment>,
+    acknowledged: Vec<String>,
+    issues: Vec<FakeIssue>,
+    branches: Vec<FakeBranch>,
+    files: HashMap<String, String>,
+    head_files: HashMap<String, String>,
+    /// 경로 → 기본 브랜치 최근 커밋 작성자(최신순)
+    recent_authors: HashMap<String, Vec<String>>,
+    requested_reviewers: Vec<String>,
+    /// 호출 이름 → 돌려줄 오류 메시지
+    failures: HashMap<String, String>,
+    last_id: u64,
+}
+
+impl PullRequestState {
+    /// 실제 VCS처럼 생성 순으로 증가하는 숫자 id를 쓴다(claim 순서 판단이 id에 의존한다).
+    fn next_id(&mut self) -> String {
+        self.last_id += 1;
+        self.last_id.to_string()
+    }
+}
+
+/// 메모리 안의 PR/MR을 조회/수정하는 VCS 게이트웨이.
+/// 복제본은 같은 상태를 공유하므로 조합에 넘긴 뒤에도 게시된 코멘트를 확인할 수 있다.
+#[derive(Clone, Default)]
+pub struct FakeVcsGateway {
+    state: Arc<Mutex<PullRequestState>>,
+}
+
+impl FakeVcsGateway {
+    /// HEAD SHA와 diff로 PR/MR을 만든다.
+    pub fn new(head_sha: impl Into<String>, diff: impl Into<String>) -> Self {
+        let gateway = Self::default();
+        {
+            let mut state = gateway.state();
+            state.head_sha = head_sha.into();
+            state.diff = diff.into();
+        }
+        gateway
+    }
+
+    pub fn with_title(self, title: impl Into<String>) -> Self {
+        self.state().title = title.into();
+        self
+    }
+
+    pub fn with_author(self, author: impl Into<String>) -> Self {
+        self.state().author = author.into();
+        self
+    }
+
+    pub fn with_labels(self, labels: &[&str]) -> Self {
+        self.state().labels = labels.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    pub fn with_description(self, description: impl Into<String>) -> Self {
+        self.state().description = description.into();
+        self
+    }
+
+    pub fn with_commit_messages(self, messages: &[&str]) -> Self {
+        self.state().commit_messages = messages.iter().map(ToString::to_string).collect();
+        self
+    }
+
+    /// 이미 달려 있는 코멘트를 추가한다(추가한 순서대로 id가 매겨진다).
+    pub fn with_comment(self, body: impl Into<String>) -> Self {
+        self.push_comment(body.into(), None)
+    }
+
+    /// `username`이 쓴 코멘트를 추가한다. `maintainer`는 작성자의 저장소 관리 권한 여부.
+    pub fn with_comment_by(
+        self,
+        username: impl Into<String>,
+        maintainer: bool,
+        body: impl Into<String>,
+    ) -> Self {
+        let author = CommentAuthor {
+            username: username.into(),
+            maintainer,
+        };
+        self.push_comment(body.into(), Some(author))
+    }
+
+    fn push_comment(self, body: String, author: Option<CommentAuthor>) -> Self {
+        {
+            let mut state = self.state();
+            let id = state.next_id();
+            state.comments.push(ReviewComment { id, body, author });
+        }
+        self
+    }
+
+    /// 기본 브랜치 파일(`fetch_repository_file`)을 추가한다.
+    pub fn with_file(self, path: impl Into<String>, content: impl Into<String>) -> Self {
+        self.state().files.insert(path.into(), content.into());
+        self
+    }
+
+    /// HEAD 커밋 파일(`fetch_file_at`)을 추가한다. 없는 경로는 기본 브랜치 파일에서 찾는다.
+    pub fn with_head_file(self, path: impl Into<String>, content: impl Into<String>) -> Self {
+        self.state().head_files.insert(path.into(), content.into());
+        self
+    }
+
+    /// 기본 브랜치에서 `path`를 바꾼 최근 커밋 작성자(`fetch_recent_authors`, 최신순)를 지정한다.
+    pub fn with_recent_authors(self, path: impl Into<String>, authors: &[&str]) -> Self {
+        self.state().recent_authors.insert(
+            path.into(),
+            authors.iter().map(ToString::to_string).collect(),
+        );
+        self
+    }
+
+    /// `call`(포트 메서드 이름, 예: `fetch_diff`, `create_comment`) 호출을 `message` 오류로 실패시킨다.
+    pub fn with_failure(self, call: &str, message: impl Into<String>) -> Self {
+        self.state()
+            .failures
+            .insert(call.to_string(), message.into());
+        self
+    }
+
+    /// 새 커밋이 push된 것처럼 HEAD SHA(와 diff)를 바꾼다.
+    pub fn push(&self, head_sha: impl Into<String>, diff: impl Into<String>) {
+        let mut state = self.state();
+        state.head_sha = head_sha.into();
+        state.diff = diff.into();
+    }
+
+    /// 현재 코멘트 목록(생성 순).
+    pub fn comments(&self) -> Vec<ReviewComment> {
+        self.state().comments.clone()
+    }
+
+    pub fn file_comments(&self) -> Vec<FakeFileComment> {
+        self.state().file_comments.clone()
+    }
+
+    pub fn line_comments(&self) -> Vec<FakeLineComment> {
+        self.state().line_comments.clone()
+    }
+
+    /// 확인 반응을 단 코멘트 id.
+    pub fn acknowledged(&self) -> Vec<String> {
+        self.state().acknowledged.clone()
+    }
+
+    /// 생성된 이슈(생성 순).
+    pub fn issues(&self) -> Vec<FakeIssue> {
+        self.state().issues.clone()
+    }
+
+    /// 리뷰를 요청한 사용자/팀(요청 순, 중복 제거).
+    pub fn requested_reviewers(&self) -> Vec<String> {
+        self.state().requested_reviewers.clone()
+    }
+
+    /// 올린 브랜치(같은 이름은 마지막 것만 남는다).
+    pub fn branches(&self) -> Vec<FakeBranch> {
+        self.state().branches.clone()
+    }
+
+    fn state(&self) -> MutexGuard<'_, PullRequestState> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// `with_failure`로 지정한 호출이면 오류를 돌려준다.
+    fn enter(&self, call: &str) -> Result<MutexGuard<'_, PullRequestState>> {
+        let state = self.state();
+        if let Some(message) = state.failures.get(call) {
+            return Err(anyhow!("{message}"));
+        }
+        Ok(state)
+    }
+}
+
+#[async_trait]
+impl VcsGateway for FakeVcsGateway {
+    async fn fetch_head_sha(&self) -> Result<String> {
+        Ok(self.enter("fetch_head_sha")?.head_sha.clone())
+    }
+
+    async fn fetch_title(&self) -> Result<String> {
+        Ok(self.enter("fetch_title")?.title.clone())
+    }
+
+    async fn fetch_labels(&self) -> Result<Vec<String>> {
+        Ok(self.enter("fetch_labels")?.labels.clone())
+    }
+
+    async fn fetch_description(&self) -> Result<String> {
+        Ok(self.enter("fetch_description")?.description.clone())
+    }
+
+    async fn fetch_commit_messages(&self) -> Result<Vec<String>> {
+        Ok(self.enter("fetch_commit_messages")?.commit_messages.clone())
+    }
+
+    async fn fetch_author(&self) -> Result<String> {
+        Ok(self.enter("fetch_author")?.author.clone())
+    }
+
+    async fn fetch_diff(&self) -> Result<String> {
+        Ok(self.enter("fetch_diff")?.diff.clone())
+    }
+
+    async fn list_comments(&self) -> Result<Vec<ReviewComment>> {
+        Ok(self.enter("list_comments")?.comments.clone())
+    }
+
+    async fn create_comment(&self, body: &str) -> Result<ReviewComment> {
+        let mut state = self.enter("create_comment")?;
+        let comment = ReviewComment {
+            id: state.next_id(),
+            body: body.to_string(),
+            author: None,
+        };
+        state.comments.push(comment.clone());
+        Ok(comment)
+    }
+
+    async fn update_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        let mut state = self.enter("update_comment")?;
+        let Some(comment) = state.comments.iter_mut().find(|c| c.id == comment_id) else {
+            bail!("fake VCS: comment {comment_id} not found");
+        };
+        comment.body = body.to_string();
+        Ok(comment.clone())
+    }
+
+    async fn create_file_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let mut state = self.enter("create_file_comment")?;
+        let id = state.next_id();
+        state.file_comments.push(FakeFileComment {
+            id: id.clone(),
+            head_sha: head_sha.to_string(),
+            path: path.to_string(),
+            body: body.to_string(),
+        });
+        Ok(ReviewComment {
+            id,
+            body: body.to_string(),
+            author: None,
+        })
+    }
+
+    async fn create_line_comment(
+        &self,
+        head_sha: &str,
+        path: &str,
+        position: &LinePosition,
+        body: &str,
+    ) -> Result<ReviewComment> {
+        let mut state = self.enter("create_line_comment")?;
+        let id = state.next_id();
+        state.line_comments.push(FakeLineComment {
+            id: id.clone(),
+            head_sha: head_sha.to_string(),
+            path: path.to_string(),
+            position: *position,
+            body: body.to_string(),
+        });
+        Ok(ReviewComment {
+            id,
+            body: body.to_string(),
+            author: None,
+        })
+    }
+
+    async fn list_review_comments(&self) -> Result<Vec<ReviewComment>> {
+        let state = self.enter("list_review_comments")?;
+        let files = state
+            .file_comments
+            .iter()
+            .map(|c| (c.id.clone(), c.body.clone()));
+        let lines = state
+            .line_comments
+            .iter()
+            .map(|c| (c.id.clone(), c.body.clone()));
+        Ok(files
+            .chain(lines)
+            .map(|(id, body)| ReviewComment {
+                id,
+                body,
+                author: None,
+            })
+            .collect())
+    }
+
+    async fn update_review_comment(&self, comment_id: &str, body: &str) -> Result<ReviewComment> {
+        let mut state = self.enter("update_review_comment")?;
+        let state = &mut *state;
+        let target = state
+            .file_comments
+            .iter_mut()
+            .find(|c| c.id == comment_id)
+            .map(|c| &mut c.body)
+            .or_else(|| {
+                state
+                    .line_comments
+                    .iter_mut()
+                    .find(|c| c.id == comment_id)
+                    .map(|c| &mut c.body)
+            });
+        let Some(target) = target else {
+            bail!("fake VCS: review comment {comment_id} not found");
+        };
+        *target = body.to_string();
+        Ok(ReviewComment {
+            id: comment_id.to_string(),
+            body: body.to_string(),
+            author: None,
+        })
+    }
+
+    async fn acknowledge_comment(&self, comment_id: &str) -> Result<()> {
+        let mut state = self.enter("acknowledge_comment")?;
+        if !state.acknowledged.iter().any(|id| id == comment_id) {
+            state.acknowledged.push(comment_id.to_string());
+        }
+        Ok(())
+    }
+
+    async fn request_reviewers(&self, usernames: &[String]) -> Result<()> {
+        let mut state = self.enter("request_reviewers")?;
+        for name in usernames {
+            if !state.requested_reviewers.contains(name) {
+                state.requested_reviewers.push(name.clone());
+            }
+        }
+        Ok(())
+    }
+
+    async fn create_issue(&self, title: &str, body: &str) -> Result<IssueRef> {
+        let mut state = self.enter("create_issue")?;
+        let number = (state.issues.len() + 1).to_string();
+        state.issues.push(FakeIssue {
+            number: number.clone(),
+            title: title.to_string(),
+            body: body.to_string(),
+        });
+        Ok(IssueRef {
+            url: format!("fake://issues/{number}"),
+            number,
+        })
+    }
+
+    async fn fetch_repository_file(&self, path: &str) -> Result<Option<String>> {
+        Ok(self
+            .enter("fetch_repository_file")?
+            .files
+            .get(path)
+            .cloned())
+    }
+
+    async fn fetch_file_at(&self, path: &str, _sha: &str) -> Result<Option<String>> {
+        let state = self.enter("fetch_file_at")?;
+        Ok(state
+            .head_files
+            .get(path)
+            .or_else(|| state.files.get(path))
+            .cloned())
+    }
+
+    async fn fetch_recent_authors(&self, path: &str, limit: usize) -> Result<Vec<String>> {
+        let state = self.enter("fetch_recent_authors")?;
+        let mut authors = state.recent_authors.get(path).cloned().unwrap_or_default();
+        authors.truncate(limit);
+        Ok(authors)
+    }
+
+    async fn push_branch(
+        &self,
+        branch: &str,
+        base_sha: &str,
+        message: &str,
+        files: &[PatchedFile],
+    ) -> Result<String> {
+        let mut state = self.enter("push_branch")?;
+        state.branches.retain(|b| b.name != branch);
+        state.branches.push(FakeBranch {
+            name: branch.to_string(),
+            base_sha: base_sha.to_string(),
+            message: message.to_string(),
+            files: files.to_vec(),
+        });
+        Ok(format!("fake://branches/{branch}"))
+    }
+
+    async fn check_token_access(&self, _write: bool) -> Result<()> {
+        self.enter("check_token_access").map(|_| ())
+    }
+}
+
+/// 대상 URL별 [`FakeVcsGateway`]를 돌려주는 팩토리.
+#[derive(Clone, Default)]
+pub struct FakeVcsFactory {
+    default: FakeVcsGateway,
+    targets: HashMap<String, FakeVcsGateway>,
+    open_reviews: Vec<OpenReview>,
+}
+
+impl FakeVcsFactory {
+    /// 모든 대상에 같은 게이트웨이를 쓰는 팩토리.
+    pub fn new(gateway: FakeVcsGateway) -> Self {
+        Self {
+            default: gateway,
+            ..Self::default()
+        }
+    }
+
+    /// 이 URL(`ReviewTarget::url`)에는 따로 지정한 게이트웨이를 쓴다(배치 리뷰용).
+    pub fn with_target(mut self, url: impl Into<String>, gateway: FakeVcsGateway) -> Self {
+        self.targets.insert(url.into(), gateway);
+        self
+    }
+
+    /// 저장소 조회(`list_open_reviews`)에 돌려줄 열린 PR/MR.
+    pub fn with_open_review(mut self, review: OpenReview) -> Self {
+        self.open_reviews.push(review);
+        self
+    }
+}
+
+impl VcsFactory for FakeVcsFactory {
+    fn build(
+        &self,
+        target: &ReviewTarget,
+        _host_cfg: Option<&HostConfig>,
+        _token: Option<String>,
+    ) -> Box<dyn VcsGateway> {
+        Box::new(
+            self.targets
+                .get(target.url())
+                .unwrap_or(&self.default)
+                .clone(),
+        )
+    }
+
+    fn build_repository(
+        &self,
+        _repository: &RepositoryRef,
+        _host_cfg: Option<&HostConfig>,
+        _token: Option<String>,
+    ) -> Box<dyn RepositoryGateway> {
+        Box::new(FakeRepositoryGateway {
+            open_reviews: self.open_reviews.clone(),
+        })
+    }
+}
+
+struct FakeRepositoryGateway {
+    open_reviews: Vec<OpenReview>,
+}
+
+#[async_trait]
+impl RepositoryGateway for FakeRepositoryGateway {
+    async fn list_open_reviews(&self, limit: usize) -> Result<Vec<OpenReview>> {
+        Ok(self.open_reviews.iter().take(limit).cloned().collect())
+    }
+}
@@ -0,0 +1,85 @@
+//! 가짜 포트 조합으로 리뷰 유스케이스 전체를 실행하고 게시된 코멘트를 골든 파일과 비교한다.
+//! 출력이 의도대로 바뀌었으면 `REPOPILOT_UPDATE_GOLDEN=1 cargo test`로 골든 파일을 갱신한다.
+
+use repopilot::domain::review::RunOptions;
+use repopilot::testing::{
+    FakeApp, FakeProviderAgent, FakeVcsGateway, assert_golden, mask_volatile,
+};
+
+const TARGET_URL: &str = "https://github.com/acme/widgets/pull/7";
+
+const DIFF: &str = "diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,4 @@
+ pub fn add(a: i32, b: i32) -> i32 {
+-    a + b
++    let sum = a + b;
++    sum
+ }
+";
+
+const ALPHA_REVIEW: &str = "## Suggestions
+- `src/lib.rs`: the temporary `sum` binding adds nothing.
+
+```json repopilot-findings
+[{\"file\": \"src/lib.rs\", \"line_start\": 2, \"line_end\": 3, \"severity\": \"suggestion\", \"title\": \"Redundant binding\", \"body\": \"Return `a + b` directly.\", \"confidence\": 0.9}]
+```
+";
+
+const BETA_REVIEW: &str = "## Suggestions
+- `src/lib.rs`: `sum` is returned right away.
+
+```json repopilot-findings
+[{\"file\": \"src/lib.rs\", \"line_start\": 2, \"line_end\": 3, \"severity\": \"suggestion\", \"title\": \"Redundant binding\", \"body\": \"Drop the `sum` variable.\", \"confidence\": 0.8}]
+```
+";
+
+fn options() -> RunOptions {
+    RunOptions {
+        url: TARGET_URL.to_string(),
+        dry_run: false,
+        force: false,
+        fail_on: None,
+        create_issues: None,
+        autofix: false,
+        autofix_push: false,
+        request_reviewers: false,
+        exclude: Vec::new(),
+        interactive_publish: false,
+        providers: Vec::new(),
+        model_overrides: Vec::new(),
+        comment_language: None,
+        profile: None,
+    }
+}
+
+#[tokio::test]
+async fn review_publishes_agent_comments_and_summary() {
+    let vcs = FakeVcsGateway::new("abc123", DIFF).with_title("Simplify add");
+    let app = FakeApp::new(
+        vcs.clone(),
+        vec![
+            FakeProviderAgent::new("alpha", ALPHA_REVIEW)
+                .with_reaction("Agree with the redundant binding finding."),
+            FakeProviderAgent::new("beta", BETA_REVIEW)
+                .with_reaction("Agree; the binding should go."),
+        ],
+    );
+
+    let outcome = app
+        .composition()
+        .review_usecase()
+        .execute(options())
+        .await
+        .expect("review should succeed");
+    assert!(!outcome.skipped);
+
+    let comments = vcs.comments();
+    let published = comments
+        .iter()
+        .map(|comment| mask_volatile(&comment.body))
+        .collect::<Vec<_>>()
+        .join("\n\n---\n\n");
+    assert_golden("tests/golden/review_comments.md", &published);
+}